@@ -0,0 +1,3 @@
+DROP INDEX idx_maintenance_priority ON maintenance_requests;
+DROP INDEX idx_maintenance_updated_at ON maintenance_requests;
+DROP TABLE IF EXISTS maintenance_filter_presets;
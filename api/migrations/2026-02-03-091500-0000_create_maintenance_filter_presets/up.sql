@@ -0,0 +1,14 @@
+-- Migration: saved maintenance request filter presets per user
+CREATE TABLE maintenance_filter_presets (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  user_id BIGINT UNSIGNED NOT NULL,
+  name VARCHAR(128) NOT NULL,
+  filters_json TEXT NOT NULL,
+  created_at TIMESTAMP NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_filter_preset_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+  UNIQUE KEY uk_filter_preset_user_name (user_id, name)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- Search and filter support for the triage list
+CREATE INDEX idx_maintenance_updated_at ON maintenance_requests(updated_at);
+CREATE INDEX idx_maintenance_priority ON maintenance_requests(priority);
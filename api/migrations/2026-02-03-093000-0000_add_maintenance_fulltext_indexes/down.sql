@@ -0,0 +1,2 @@
+DROP INDEX ft_maintenance_comment_text ON maintenance_request_comments;
+DROP INDEX ft_maintenance_text ON maintenance_requests;
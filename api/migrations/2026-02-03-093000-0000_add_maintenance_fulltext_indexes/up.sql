@@ -0,0 +1,3 @@
+-- Full-text search over request text and comments (MATCH ... AGAINST)
+CREATE FULLTEXT INDEX ft_maintenance_text ON maintenance_requests(title, description);
+CREATE FULLTEXT INDEX ft_maintenance_comment_text ON maintenance_request_comments(comment_text);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS maintenance_request_feedback;
+ALTER TABLE maintenance_requests DROP COLUMN vendor;
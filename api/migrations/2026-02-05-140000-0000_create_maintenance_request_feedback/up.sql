@@ -0,0 +1,24 @@
+-- Migration: resident satisfaction feedback on resolved maintenance requests
+ALTER TABLE maintenance_requests ADD COLUMN vendor VARCHAR(128) NULL AFTER assigned_to;
+
+CREATE TABLE maintenance_request_feedback (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  request_id BIGINT UNSIGNED NOT NULL,
+  user_id BIGINT UNSIGNED NOT NULL,
+  rating TINYINT UNSIGNED NOT NULL,
+  is_fixed BOOLEAN NOT NULL,
+  comment TEXT NULL,
+  -- Snapshot of who did the work at the time of rating, so later reassignment
+  -- does not move ratings between assignees or vendors.
+  assigned_to BIGINT UNSIGNED NULL,
+  vendor VARCHAR(128) NULL,
+  created_at TIMESTAMP NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_feedback_request FOREIGN KEY (request_id) REFERENCES maintenance_requests(id) ON DELETE CASCADE,
+  CONSTRAINT fk_feedback_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+  CONSTRAINT fk_feedback_assignee FOREIGN KEY (assigned_to) REFERENCES users(id) ON DELETE SET NULL,
+  CONSTRAINT chk_feedback_rating CHECK (rating BETWEEN 1 AND 5)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_feedback_request ON maintenance_request_feedback(request_id, created_at);
+CREATE INDEX idx_feedback_assignee ON maintenance_request_feedback(assigned_to);
+CREATE INDEX idx_feedback_vendor ON maintenance_request_feedback(vendor);
@@ -0,0 +1 @@
+ALTER TABLE maintenance_request_attachments DROP COLUMN has_thumbnail;
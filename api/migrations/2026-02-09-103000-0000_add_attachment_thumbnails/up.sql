@@ -0,0 +1,3 @@
+-- Migration: track generated thumbnails for image attachments
+ALTER TABLE maintenance_request_attachments
+  ADD COLUMN has_thumbnail BOOLEAN NOT NULL DEFAULT FALSE AFTER size_bytes;
@@ -0,0 +1 @@
+ALTER TABLE maintenance_request_attachments DROP COLUMN checksum_sha256;
@@ -0,0 +1,3 @@
+-- Migration: SHA-256 of stored attachment content, used to verify storage backend migrations
+ALTER TABLE maintenance_request_attachments
+  ADD COLUMN checksum_sha256 CHAR(64) NULL AFTER size_bytes;
@@ -0,0 +1,3 @@
+ALTER TABLE maintenance_request_attachments
+  DROP COLUMN scan_signature,
+  DROP COLUMN scan_status;
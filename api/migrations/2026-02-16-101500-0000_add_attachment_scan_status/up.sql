@@ -0,0 +1,4 @@
+-- Migration: malware scan state for attachments; uploads stay 'pending' (quarantined) until scanned
+ALTER TABLE maintenance_request_attachments
+  ADD COLUMN scan_status VARCHAR(16) NOT NULL DEFAULT 'clean' AFTER has_thumbnail,
+  ADD COLUMN scan_signature VARCHAR(255) NULL AFTER scan_status;
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS announcements_reads;
+ALTER TABLE announcements DROP COLUMN requires_ack;
@@ -0,0 +1,15 @@
+-- Migration: per-user read receipts and mandatory acknowledgement for announcements
+ALTER TABLE announcements
+  ADD COLUMN requires_ack BOOLEAN NOT NULL DEFAULT FALSE AFTER comments_enabled;
+
+CREATE TABLE announcements_reads (
+  announcement_id BIGINT UNSIGNED NOT NULL,
+  user_id BIGINT UNSIGNED NOT NULL,
+  read_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  acknowledged_at TIMESTAMP NULL,
+  PRIMARY KEY (announcement_id, user_id),
+  CONSTRAINT fk_read_announcement FOREIGN KEY (announcement_id) REFERENCES announcements(id) ON DELETE CASCADE,
+  CONSTRAINT fk_read_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_announcements_reads_user ON announcements_reads(user_id);
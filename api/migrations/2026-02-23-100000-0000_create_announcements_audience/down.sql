@@ -0,0 +1,27 @@
+ALTER TABLE announcements
+  ADD COLUMN roles_csv TEXT NULL AFTER pinned,
+  ADD COLUMN building_id BIGINT UNSIGNED NULL AFTER roles_csv,
+  ADD COLUMN apartment_id BIGINT UNSIGNED NULL AFTER building_id;
+
+-- Only one building/apartment fits the old columns; the lowest id is kept
+UPDATE announcements a
+SET
+  roles_csv = (
+    SELECT GROUP_CONCAT(r.name ORDER BY r.name SEPARATOR ',')
+    FROM announcements_audience au JOIN roles r ON r.id = au.role_id
+    WHERE au.announcement_id = a.id
+  ),
+  building_id = (
+    SELECT MIN(au.building_id) FROM announcements_audience au WHERE au.announcement_id = a.id
+  ),
+  apartment_id = (
+    SELECT MIN(au.apartment_id) FROM announcements_audience au WHERE au.announcement_id = a.id
+  );
+
+ALTER TABLE announcements
+  ADD CONSTRAINT fk_announcement_building FOREIGN KEY (building_id) REFERENCES buildings(id) ON DELETE SET NULL,
+  ADD CONSTRAINT fk_announcement_apartment FOREIGN KEY (apartment_id) REFERENCES apartments(id) ON DELETE SET NULL;
+CREATE INDEX idx_announcements_building ON announcements(building_id);
+CREATE INDEX idx_announcements_apartment ON announcements(apartment_id);
+
+DROP TABLE IF EXISTS announcements_audience;
@@ -0,0 +1,62 @@
+-- Migration: move announcement targeting (roles_csv, building_id, apartment_id) into an audience table
+-- Each row targets exactly one role, building or apartment. Targets of the same kind are
+-- alternatives; different kinds must all match. Targets cannot be hard-deleted while an
+-- announcement points at them: losing the last target would make the announcement public.
+CREATE TABLE announcements_audience (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  announcement_id BIGINT UNSIGNED NOT NULL,
+  role_id BIGINT UNSIGNED NULL,
+  building_id BIGINT UNSIGNED NULL,
+  apartment_id BIGINT UNSIGNED NULL,
+  CONSTRAINT fk_audience_announcement FOREIGN KEY (announcement_id) REFERENCES announcements(id) ON DELETE CASCADE,
+  CONSTRAINT fk_audience_role FOREIGN KEY (role_id) REFERENCES roles(id) ON DELETE RESTRICT,
+  CONSTRAINT fk_audience_building FOREIGN KEY (building_id) REFERENCES buildings(id) ON DELETE RESTRICT,
+  CONSTRAINT fk_audience_apartment FOREIGN KEY (apartment_id) REFERENCES apartments(id) ON DELETE RESTRICT,
+  CONSTRAINT chk_audience_single_target CHECK (
+    (role_id IS NOT NULL) + (building_id IS NOT NULL) + (apartment_id IS NOT NULL) = 1
+  )
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE UNIQUE INDEX uq_audience_role ON announcements_audience(announcement_id, role_id);
+CREATE UNIQUE INDEX uq_audience_building ON announcements_audience(announcement_id, building_id);
+CREATE UNIQUE INDEX uq_audience_apartment ON announcements_audience(announcement_id, apartment_id);
+
+-- Existing targeting. Role names that no longer exist are held by nobody, so they are
+-- dropped; that changes nothing as long as another listed role remains.
+INSERT INTO announcements_audience (announcement_id, role_id)
+SELECT a.id, r.id
+FROM announcements a
+JOIN roles r ON FIND_IN_SET(r.name, REPLACE(a.roles_csv, ' ', '')) > 0
+WHERE a.roles_csv IS NOT NULL;
+
+-- A roles_csv that is empty or lists only unknown roles hid the announcement from everyone
+-- but managers; keep it that way by targeting the Manager role.
+INSERT INTO roles (name)
+SELECT 'Manager' FROM DUAL
+WHERE NOT EXISTS (SELECT 1 FROM roles WHERE name = 'Manager');
+
+INSERT INTO announcements_audience (announcement_id, role_id)
+SELECT a.id, r.id
+FROM announcements a
+JOIN roles r ON r.name = 'Manager'
+WHERE a.roles_csv IS NOT NULL
+  AND NOT EXISTS (
+    SELECT 1 FROM announcements_audience au
+    WHERE au.announcement_id = a.id AND au.role_id IS NOT NULL
+  );
+
+INSERT INTO announcements_audience (announcement_id, building_id)
+SELECT id, building_id FROM announcements WHERE building_id IS NOT NULL;
+
+INSERT INTO announcements_audience (announcement_id, apartment_id)
+SELECT id, apartment_id FROM announcements WHERE apartment_id IS NOT NULL;
+
+ALTER TABLE announcements
+  DROP FOREIGN KEY fk_announcement_building,
+  DROP FOREIGN KEY fk_announcement_apartment;
+DROP INDEX idx_announcements_building ON announcements;
+DROP INDEX idx_announcements_apartment ON announcements;
+ALTER TABLE announcements
+  DROP COLUMN roles_csv,
+  DROP COLUMN building_id,
+  DROP COLUMN apartment_id;
@@ -0,0 +1,19 @@
+-- Migration: revision history for announcement title and body
+CREATE TABLE announcements_revisions (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  announcement_id BIGINT UNSIGNED NOT NULL,
+  revision INT UNSIGNED NOT NULL,
+  title VARCHAR(255) NOT NULL,
+  body_md TEXT NOT NULL,
+  editor_id BIGINT UNSIGNED NOT NULL,
+  restored_from INT UNSIGNED NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_revision_announcement FOREIGN KEY (announcement_id) REFERENCES announcements(id) ON DELETE CASCADE,
+  CONSTRAINT fk_revision_editor FOREIGN KEY (editor_id) REFERENCES users(id),
+  UNIQUE KEY uq_revision_number (announcement_id, revision)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- Existing announcements start their history with the current content
+INSERT INTO announcements_revisions (announcement_id, revision, title, body_md, editor_id, created_at)
+SELECT id, 1, title, body_md, author_id, COALESCE(created_at, CURRENT_TIMESTAMP)
+FROM announcements;
@@ -0,0 +1,20 @@
+-- Migration: files and inline images attached to announcements
+CREATE TABLE announcements_attachments (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  announcement_id BIGINT UNSIGNED NOT NULL,
+  original_filename VARCHAR(255) NOT NULL,
+  stored_filename VARCHAR(255) NOT NULL,
+  mime_type VARCHAR(128) NOT NULL,
+  size_bytes BIGINT UNSIGNED NOT NULL,
+  checksum_sha256 CHAR(64) NULL,
+  has_thumbnail BOOLEAN NOT NULL DEFAULT FALSE,
+  scan_status VARCHAR(16) NOT NULL DEFAULT 'pending',
+  scan_signature VARCHAR(255) NULL,
+  uploaded_by BIGINT UNSIGNED NOT NULL,
+  is_deleted BOOLEAN NOT NULL DEFAULT FALSE,
+  created_at TIMESTAMP NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_ann_attachment_announcement FOREIGN KEY (announcement_id) REFERENCES announcements(id) ON DELETE CASCADE,
+  CONSTRAINT fk_ann_attachment_uploader FOREIGN KEY (uploaded_by) REFERENCES users(id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_announcements_attachments ON announcements_attachments(announcement_id, is_deleted, scan_status);
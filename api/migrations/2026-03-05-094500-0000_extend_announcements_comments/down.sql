@@ -0,0 +1,12 @@
+DROP TABLE IF EXISTS announcements_comments_reports;
+DROP TABLE IF EXISTS announcements_comments_mentions;
+DROP TABLE IF EXISTS announcements_comments_reactions;
+ALTER TABLE announcements_comments
+  DROP FOREIGN KEY fk_comment_hidden_by,
+  DROP FOREIGN KEY fk_comment_parent,
+  DROP COLUMN hidden_at,
+  DROP COLUMN hidden_by,
+  DROP COLUMN hidden_reason,
+  DROP COLUMN is_hidden,
+  DROP COLUMN edited_at,
+  DROP COLUMN parent_id;
@@ -0,0 +1,48 @@
+-- Migration: reply threading, editing, reactions, @mentions and moderation for announcement comments
+ALTER TABLE announcements_comments
+  ADD COLUMN parent_id BIGINT UNSIGNED NULL,
+  ADD COLUMN edited_at TIMESTAMP NULL,
+  ADD COLUMN is_hidden BOOLEAN NOT NULL DEFAULT FALSE,
+  ADD COLUMN hidden_reason VARCHAR(255) NULL,
+  ADD COLUMN hidden_by BIGINT UNSIGNED NULL,
+  ADD COLUMN hidden_at TIMESTAMP NULL,
+  ADD CONSTRAINT fk_comment_parent FOREIGN KEY (parent_id) REFERENCES announcements_comments(id) ON DELETE CASCADE,
+  ADD CONSTRAINT fk_comment_hidden_by FOREIGN KEY (hidden_by) REFERENCES users(id) ON DELETE SET NULL;
+
+CREATE TABLE announcements_comments_reactions (
+  comment_id BIGINT UNSIGNED NOT NULL,
+  user_id BIGINT UNSIGNED NOT NULL,
+  emoji VARCHAR(16) NOT NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (comment_id, user_id, emoji),
+  CONSTRAINT fk_reaction_comment FOREIGN KEY (comment_id) REFERENCES announcements_comments(id) ON DELETE CASCADE,
+  CONSTRAINT fk_reaction_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE TABLE announcements_comments_mentions (
+  comment_id BIGINT UNSIGNED NOT NULL,
+  user_id BIGINT UNSIGNED NOT NULL,
+  PRIMARY KEY (comment_id, user_id),
+  CONSTRAINT fk_mention_comment FOREIGN KEY (comment_id) REFERENCES announcements_comments(id) ON DELETE CASCADE,
+  CONSTRAINT fk_mention_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_comments_mentions_user ON announcements_comments_mentions(user_id);
+
+CREATE TABLE announcements_comments_reports (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  comment_id BIGINT UNSIGNED NOT NULL,
+  reporter_id BIGINT UNSIGNED NOT NULL,
+  reason VARCHAR(500) NOT NULL,
+  -- open, dismissed or actioned (comment hidden)
+  status VARCHAR(16) NOT NULL DEFAULT 'open',
+  resolved_by BIGINT UNSIGNED NULL,
+  resolved_at TIMESTAMP NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  UNIQUE KEY uq_report_comment_reporter (comment_id, reporter_id),
+  CONSTRAINT fk_report_comment FOREIGN KEY (comment_id) REFERENCES announcements_comments(id) ON DELETE CASCADE,
+  CONSTRAINT fk_report_reporter FOREIGN KEY (reporter_id) REFERENCES users(id) ON DELETE CASCADE,
+  CONSTRAINT fk_report_resolved_by FOREIGN KEY (resolved_by) REFERENCES users(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_comments_reports_status ON announcements_comments_reports(status);
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS announcements_templates_translations;
+DROP TABLE IF EXISTS announcements_templates;
+DROP TABLE IF EXISTS announcements_translations;
+ALTER TABLE announcements DROP COLUMN language;
@@ -0,0 +1,36 @@
+-- Migration: per-language announcement bodies and reusable announcement templates
+ALTER TABLE announcements
+  ADD COLUMN language VARCHAR(8) NOT NULL DEFAULT 'en';
+
+CREATE TABLE announcements_translations (
+  announcement_id BIGINT UNSIGNED NOT NULL,
+  language VARCHAR(8) NOT NULL,
+  title VARCHAR(255) NOT NULL,
+  body_md TEXT NOT NULL,
+  body_html TEXT NOT NULL,
+  updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (announcement_id, language),
+  CONSTRAINT fk_translation_announcement FOREIGN KEY (announcement_id) REFERENCES announcements(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE TABLE announcements_templates (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  name VARCHAR(120) NOT NULL,
+  language VARCHAR(8) NOT NULL DEFAULT 'en',
+  title VARCHAR(255) NOT NULL,
+  body_md TEXT NOT NULL,
+  created_by BIGINT UNSIGNED NOT NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  UNIQUE KEY uq_announcements_templates_name (name),
+  CONSTRAINT fk_template_creator FOREIGN KEY (created_by) REFERENCES users(id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE TABLE announcements_templates_translations (
+  template_id BIGINT UNSIGNED NOT NULL,
+  language VARCHAR(8) NOT NULL,
+  title VARCHAR(255) NOT NULL,
+  body_md TEXT NOT NULL,
+  PRIMARY KEY (template_id, language),
+  CONSTRAINT fk_template_translation_template FOREIGN KEY (template_id) REFERENCES announcements_templates(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
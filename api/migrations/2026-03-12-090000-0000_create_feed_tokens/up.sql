@@ -0,0 +1,9 @@
+-- Migration: secret per-user tokens for private Atom and iCalendar feed URLs
+CREATE TABLE feed_tokens (
+  user_id BIGINT UNSIGNED NOT NULL PRIMARY KEY,
+  token_hash CHAR(64) NOT NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  last_used_at TIMESTAMP NULL,
+  UNIQUE KEY uq_feed_tokens_hash (token_hash),
+  CONSTRAINT fk_feed_token_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
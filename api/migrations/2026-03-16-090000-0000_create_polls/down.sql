@@ -0,0 +1,6 @@
+DROP TABLE IF EXISTS poll_answers;
+DROP TABLE IF EXISTS poll_responses;
+DROP TABLE IF EXISTS poll_participants;
+DROP TABLE IF EXISTS poll_options;
+DROP TABLE IF EXISTS poll_questions;
+DROP TABLE IF EXISTS polls;
@@ -0,0 +1,73 @@
+-- Migration: resident polls and surveys, separate from formal proposals
+CREATE TABLE polls (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  title VARCHAR(255) NOT NULL,
+  description TEXT NOT NULL,
+  created_by BIGINT UNSIGNED NOT NULL,
+  building_id BIGINT UNSIGNED NULL,
+  start_time DATETIME NOT NULL,
+  end_time DATETIME NOT NULL,
+  anonymous BOOLEAN NOT NULL DEFAULT FALSE,
+  results_visibility VARCHAR(16) NOT NULL DEFAULT 'AfterResponse',
+  created_at TIMESTAMP NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_poll_creator FOREIGN KEY (created_by) REFERENCES users(id),
+  CONSTRAINT fk_poll_building FOREIGN KEY (building_id) REFERENCES buildings(id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE TABLE poll_questions (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  poll_id BIGINT UNSIGNED NOT NULL,
+  position INT NOT NULL,
+  prompt VARCHAR(500) NOT NULL,
+  kind VARCHAR(16) NOT NULL,
+  required BOOLEAN NOT NULL DEFAULT TRUE,
+  rating_max INT NULL,
+  CONSTRAINT fk_poll_question_poll FOREIGN KEY (poll_id) REFERENCES polls(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE TABLE poll_options (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  question_id BIGINT UNSIGNED NOT NULL,
+  position INT NOT NULL,
+  label VARCHAR(255) NOT NULL,
+  CONSTRAINT fk_poll_option_question FOREIGN KEY (question_id) REFERENCES poll_questions(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- Who has responded; kept apart from the answers so anonymous responses stay unlinked.
+-- No response time is kept here, so participants cannot be ordered against responses.
+CREATE TABLE poll_participants (
+  poll_id BIGINT UNSIGNED NOT NULL,
+  user_id BIGINT UNSIGNED NOT NULL,
+  PRIMARY KEY (poll_id, user_id),
+  CONSTRAINT fk_poll_participant_poll FOREIGN KEY (poll_id) REFERENCES polls(id) ON DELETE CASCADE,
+  CONSTRAINT fk_poll_participant_user FOREIGN KEY (user_id) REFERENCES users(id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- user_id and submitted_at are NULL for anonymous polls. The id is a random UUID, so row
+-- order carries no submission order either.
+CREATE TABLE poll_responses (
+  id CHAR(36) NOT NULL PRIMARY KEY,
+  poll_id BIGINT UNSIGNED NOT NULL,
+  user_id BIGINT UNSIGNED NULL,
+  submitted_at TIMESTAMP NULL,
+  CONSTRAINT fk_poll_response_poll FOREIGN KEY (poll_id) REFERENCES polls(id) ON DELETE CASCADE,
+  CONSTRAINT fk_poll_response_user FOREIGN KEY (user_id) REFERENCES users(id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- One row per chosen option; free text and rating answers use a single row
+CREATE TABLE poll_answers (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  response_id CHAR(36) NOT NULL,
+  question_id BIGINT UNSIGNED NOT NULL,
+  option_id BIGINT UNSIGNED NULL,
+  text_value TEXT NULL,
+  rating INT NULL,
+  CONSTRAINT fk_poll_answer_response FOREIGN KEY (response_id) REFERENCES poll_responses(id) ON DELETE CASCADE,
+  CONSTRAINT fk_poll_answer_question FOREIGN KEY (question_id) REFERENCES poll_questions(id) ON DELETE CASCADE,
+  CONSTRAINT fk_poll_answer_option FOREIGN KEY (option_id) REFERENCES poll_options(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_polls_building ON polls(building_id);
+CREATE INDEX idx_poll_questions_poll ON poll_questions(poll_id, position);
+CREATE INDEX idx_poll_responses_poll ON poll_responses(poll_id);
+CREATE INDEX idx_poll_answers_question ON poll_answers(question_id);
@@ -0,0 +1,6 @@
+DROP TABLE IF EXISTS proposal_result_rounds;
+ALTER TABLE proposal_results DROP FOREIGN KEY fk_result_winning_option;
+ALTER TABLE proposal_results DROP COLUMN winning_option_id;
+DROP TABLE IF EXISTS vote_selections;
+DROP TABLE IF EXISTS proposal_options;
+ALTER TABLE proposals DROP COLUMN max_selections, DROP COLUMN ballot_type;
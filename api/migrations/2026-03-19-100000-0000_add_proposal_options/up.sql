@@ -0,0 +1,43 @@
+-- Migration: multi-option proposals (single choice, approval, ranked choice)
+ALTER TABLE proposals
+  ADD COLUMN ballot_type VARCHAR(16) NOT NULL DEFAULT 'YesNo' AFTER voting_method,
+  ADD COLUMN max_selections INT NULL AFTER ballot_type;
+
+CREATE TABLE proposal_options (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  proposal_id BIGINT UNSIGNED NOT NULL,
+  position INT NOT NULL,
+  label VARCHAR(255) NOT NULL,
+  CONSTRAINT fk_proposal_option_proposal FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- Options picked on a ballot; rank is the preference order for ranked choice (1 = first)
+CREATE TABLE vote_selections (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  vote_id BIGINT UNSIGNED NOT NULL,
+  option_id BIGINT UNSIGNED NOT NULL,
+  `rank` INT NOT NULL,
+  UNIQUE KEY uq_vote_selection (vote_id, option_id),
+  CONSTRAINT fk_vote_selection_vote FOREIGN KEY (vote_id) REFERENCES votes(id) ON DELETE CASCADE,
+  CONSTRAINT fk_vote_selection_option FOREIGN KEY (option_id) REFERENCES proposal_options(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+ALTER TABLE proposal_results
+  ADD COLUMN winning_option_id BIGINT UNSIGNED NULL AFTER passed,
+  ADD CONSTRAINT fk_result_winning_option FOREIGN KEY (winning_option_id) REFERENCES proposal_options(id);
+
+-- Weight per option in each tally round; single choice and approval have one round
+CREATE TABLE proposal_result_rounds (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  result_id BIGINT UNSIGNED NOT NULL,
+  round INT NOT NULL,
+  option_id BIGINT UNSIGNED NOT NULL,
+  weight DECIMAL(18,6) NOT NULL,
+  eliminated BOOLEAN NOT NULL DEFAULT FALSE,
+  UNIQUE KEY uq_result_round_option (result_id, round, option_id),
+  CONSTRAINT fk_result_round_result FOREIGN KEY (result_id) REFERENCES proposal_results(id) ON DELETE CASCADE,
+  CONSTRAINT fk_result_round_option FOREIGN KEY (option_id) REFERENCES proposal_options(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_proposal_options_proposal ON proposal_options(proposal_id, position);
+CREATE INDEX idx_vote_selections_option ON vote_selections(option_id);
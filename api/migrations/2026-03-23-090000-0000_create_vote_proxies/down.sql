@@ -0,0 +1,6 @@
+ALTER TABLE votes
+  DROP FOREIGN KEY fk_vote_proxy,
+  DROP FOREIGN KEY fk_vote_cast_by,
+  DROP COLUMN proxy_id,
+  DROP COLUMN cast_by;
+DROP TABLE IF EXISTS vote_proxies;
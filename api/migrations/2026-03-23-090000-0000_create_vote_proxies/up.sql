@@ -0,0 +1,27 @@
+-- Migration: proxy voting (delegation of a vote to another user)
+-- A proxy covers one proposal, or every proposal of a building starting within [valid_from, valid_until]
+CREATE TABLE vote_proxies (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  grantor_id BIGINT UNSIGNED NOT NULL,
+  grantee_id BIGINT UNSIGNED NOT NULL,
+  proposal_id BIGINT UNSIGNED NULL,
+  building_id BIGINT UNSIGNED NULL,
+  valid_from DATETIME NULL,
+  valid_until DATETIME NULL,
+  created_at TIMESTAMP NULL DEFAULT CURRENT_TIMESTAMP,
+  revoked_at DATETIME NULL,
+  CONSTRAINT fk_proxy_grantor FOREIGN KEY (grantor_id) REFERENCES users(id),
+  CONSTRAINT fk_proxy_grantee FOREIGN KEY (grantee_id) REFERENCES users(id),
+  CONSTRAINT fk_proxy_proposal FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE,
+  CONSTRAINT fk_proxy_building FOREIGN KEY (building_id) REFERENCES buildings(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_vote_proxies_grantor ON vote_proxies(grantor_id);
+CREATE INDEX idx_vote_proxies_grantee ON vote_proxies(grantee_id);
+
+-- user_id stays the voter whose weight counts; cast_by is set when a proxy holder voted for them
+ALTER TABLE votes
+  ADD COLUMN cast_by BIGINT UNSIGNED NULL AFTER user_id,
+  ADD COLUMN proxy_id BIGINT UNSIGNED NULL AFTER cast_by,
+  ADD CONSTRAINT fk_vote_cast_by FOREIGN KEY (cast_by) REFERENCES users(id),
+  ADD CONSTRAINT fk_vote_proxy FOREIGN KEY (proxy_id) REFERENCES vote_proxies(id);
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS secret_ballot_totals;
+DROP TABLE IF EXISTS secret_ballot_selections;
+DROP TABLE IF EXISTS secret_ballots;
+ALTER TABLE proposals DROP COLUMN secret_ballot;
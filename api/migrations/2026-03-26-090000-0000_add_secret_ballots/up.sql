@@ -0,0 +1,38 @@
+-- Migration: secret ballot proposals with receipt codes
+ALTER TABLE proposals
+  ADD COLUMN secret_ballot BOOLEAN NOT NULL DEFAULT FALSE AFTER max_selections;
+
+-- Ballot contents of secret proposals. The matching `votes` row only records
+-- participation (choice 'Secret'); nothing here references the voter. The random
+-- receipt is the primary key, so row order carries no casting time either. Ballots
+-- carry no weight: a voter's weight would single out their ballot.
+CREATE TABLE secret_ballots (
+  receipt CHAR(14) NOT NULL PRIMARY KEY,
+  proposal_id BIGINT UNSIGNED NOT NULL,
+  choice VARCHAR(16) NOT NULL,
+  CONSTRAINT fk_secret_ballot_proposal FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE TABLE secret_ballot_selections (
+  receipt CHAR(14) NOT NULL,
+  option_id BIGINT UNSIGNED NOT NULL,
+  `rank` INT NOT NULL,
+  PRIMARY KEY (receipt, option_id),
+  CONSTRAINT fk_secret_selection_ballot FOREIGN KEY (receipt) REFERENCES secret_ballots(receipt) ON DELETE CASCADE,
+  CONSTRAINT fk_secret_selection_option FOREIGN KEY (option_id) REFERENCES proposal_options(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- Weight is kept only as a running total per distinct ballot content: the choice and
+-- the selected option ids, first preference first. Identical ballots share a row,
+-- which is all the tally needs, even for instant runoff.
+CREATE TABLE secret_ballot_totals (
+  proposal_id BIGINT UNSIGNED NOT NULL,
+  choice VARCHAR(16) NOT NULL,
+  option_ids VARCHAR(640) CHARACTER SET ascii NOT NULL,
+  weight_total DECIMAL(18,6) NOT NULL,
+  ballots INT NOT NULL,
+  PRIMARY KEY (proposal_id, choice, option_ids),
+  CONSTRAINT fk_secret_total_proposal FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_secret_ballots_proposal ON secret_ballots(proposal_id);
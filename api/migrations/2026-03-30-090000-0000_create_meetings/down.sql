@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS meeting_attendance;
+DROP TABLE IF EXISTS meeting_agenda_items;
+DROP TABLE IF EXISTS meetings;
@@ -0,0 +1,52 @@
+-- Migration: general assembly meetings with agenda, attendance and minutes
+CREATE TABLE meetings (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  building_id BIGINT UNSIGNED NOT NULL,
+  title VARCHAR(255) NOT NULL,
+  description TEXT NOT NULL,
+  scheduled_at DATETIME NOT NULL,
+  location VARCHAR(255) NOT NULL,
+  online_url VARCHAR(500) NULL,
+  -- Share of the building's floor area that must be represented
+  quorum_percent INT NOT NULL DEFAULT 50,
+  status VARCHAR(16) NOT NULL DEFAULT 'Scheduled',
+  started_at DATETIME NULL,
+  ended_at DATETIME NULL,
+  minutes_md TEXT NULL,
+  minutes_announcement_id BIGINT UNSIGNED NULL,
+  created_by BIGINT UNSIGNED NOT NULL,
+  created_at TIMESTAMP NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_meeting_building FOREIGN KEY (building_id) REFERENCES buildings(id),
+  CONSTRAINT fk_meeting_creator FOREIGN KEY (created_by) REFERENCES users(id),
+  CONSTRAINT fk_meeting_minutes FOREIGN KEY (minutes_announcement_id) REFERENCES announcements(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- A proposal is on at most one agenda
+CREATE TABLE meeting_agenda_items (
+  meeting_id BIGINT UNSIGNED NOT NULL,
+  proposal_id BIGINT UNSIGNED NOT NULL,
+  position INT NOT NULL,
+  opened_at DATETIME NULL,
+  closed_at DATETIME NULL,
+  PRIMARY KEY (meeting_id, proposal_id),
+  UNIQUE KEY uq_agenda_proposal (proposal_id),
+  CONSTRAINT fk_agenda_meeting FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+  CONSTRAINT fk_agenda_proposal FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- proxy_holder_id is set when the owner is represented by someone else
+CREATE TABLE meeting_attendance (
+  meeting_id BIGINT UNSIGNED NOT NULL,
+  user_id BIGINT UNSIGNED NOT NULL,
+  mode VARCHAR(16) NOT NULL,
+  proxy_holder_id BIGINT UNSIGNED NULL,
+  registered_by BIGINT UNSIGNED NOT NULL,
+  registered_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (meeting_id, user_id),
+  CONSTRAINT fk_attendance_meeting FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+  CONSTRAINT fk_attendance_user FOREIGN KEY (user_id) REFERENCES users(id),
+  CONSTRAINT fk_attendance_holder FOREIGN KEY (proxy_holder_id) REFERENCES users(id),
+  CONSTRAINT fk_attendance_registrar FOREIGN KEY (registered_by) REFERENCES users(id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_meetings_building ON meetings(building_id, scheduled_at);
@@ -0,0 +1,16 @@
+-- Migration: in-app notifications for residents
+CREATE TABLE notifications (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  user_id BIGINT UNSIGNED NOT NULL,
+  -- What happened, e.g. ProposalTallied; the frontend words the message per kind
+  kind VARCHAR(32) NOT NULL,
+  -- Title of the thing the notification is about
+  subject VARCHAR(255) NOT NULL,
+  -- Frontend path to open, e.g. /voting/12
+  link VARCHAR(255) NULL,
+  read_at TIMESTAMP NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_notification_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_notifications_user ON notifications(user_id, read_at);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS proposal_attachments;
+DROP TABLE IF EXISTS proposal_comments;
@@ -0,0 +1,32 @@
+-- Migration: discussion threads and supporting documents on proposals
+CREATE TABLE proposal_comments (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  proposal_id BIGINT UNSIGNED NOT NULL,
+  user_id BIGINT UNSIGNED NOT NULL,
+  body TEXT NOT NULL,
+  is_deleted BOOLEAN NOT NULL DEFAULT FALSE,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_proposal_comment_proposal FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE,
+  CONSTRAINT fk_proposal_comment_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_proposal_comments ON proposal_comments(proposal_id, is_deleted);
+
+CREATE TABLE proposal_attachments (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  proposal_id BIGINT UNSIGNED NOT NULL,
+  original_filename VARCHAR(255) NOT NULL,
+  stored_filename VARCHAR(255) NOT NULL,
+  mime_type VARCHAR(128) NOT NULL,
+  size_bytes BIGINT UNSIGNED NOT NULL,
+  checksum_sha256 CHAR(64) NULL,
+  scan_status VARCHAR(16) NOT NULL DEFAULT 'pending',
+  scan_signature VARCHAR(255) NULL,
+  uploaded_by BIGINT UNSIGNED NOT NULL,
+  is_deleted BOOLEAN NOT NULL DEFAULT FALSE,
+  created_at TIMESTAMP NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_proposal_attachment_proposal FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE,
+  CONSTRAINT fk_proposal_attachment_uploader FOREIGN KEY (uploaded_by) REFERENCES users(id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_proposal_attachments ON proposal_attachments(proposal_id, is_deleted, scan_status);
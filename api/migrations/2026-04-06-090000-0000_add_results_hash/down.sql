@@ -0,0 +1 @@
+ALTER TABLE proposal_results DROP COLUMN results_hash;
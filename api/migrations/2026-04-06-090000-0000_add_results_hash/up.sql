@@ -0,0 +1,5 @@
+-- Migration: tamper evidence for tallied results
+-- SHA-256 over the stored result, its rounds and every counted ballot, taken at
+-- tally time. The audit report recomputes it to show whether anything changed since.
+ALTER TABLE proposal_results
+  ADD COLUMN results_hash CHAR(64) NULL AFTER method_applied_version;
@@ -0,0 +1,14 @@
+DROP TABLE IF EXISTS apartment_vote_history;
+-- An owner of several apartments may hold one vote per seat; keep their latest one
+DELETE older FROM votes older
+  JOIN votes newer
+    ON newer.proposal_id = older.proposal_id
+   AND newer.user_id = older.user_id
+   AND newer.id > older.id;
+ALTER TABLE votes
+  DROP INDEX uq_vote_seat,
+  ADD UNIQUE KEY uq_vote_once (proposal_id, user_id);
+ALTER TABLE votes
+  DROP FOREIGN KEY fk_vote_apartment,
+  DROP COLUMN seat_key,
+  DROP COLUMN apartment_id;
@@ -0,0 +1,29 @@
+-- Migration: one apartment, one seat
+-- On PerSeat proposals a vote belongs to an apartment: apartment_id is the seat and user_id
+-- the owner whose vote it currently is (the latest one to cast it). Other methods leave
+-- apartment_id NULL and stay one vote per user; seat_key folds both into one unique key.
+ALTER TABLE votes
+  ADD COLUMN apartment_id BIGINT UNSIGNED NULL AFTER user_id,
+  ADD COLUMN seat_key VARCHAR(24) AS (IF(apartment_id IS NULL, CONCAT('u', user_id), CONCAT('a', apartment_id))) STORED,
+  ADD CONSTRAINT fk_vote_apartment FOREIGN KEY (apartment_id) REFERENCES apartments(id);
+ALTER TABLE votes
+  DROP INDEX uq_vote_once,
+  ADD UNIQUE KEY uq_vote_seat (proposal_id, seat_key);
+
+-- Every vote cast for an apartment, so a co-owner overriding another stays traceable
+CREATE TABLE apartment_vote_history (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  proposal_id BIGINT UNSIGNED NOT NULL,
+  apartment_id BIGINT UNSIGNED NOT NULL,
+  user_id BIGINT UNSIGNED NOT NULL,
+  cast_by BIGINT UNSIGNED NULL,
+  choice VARCHAR(16) NOT NULL,
+  option_ids VARCHAR(255) NOT NULL DEFAULT '',
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_vote_history_proposal FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE,
+  CONSTRAINT fk_vote_history_apartment FOREIGN KEY (apartment_id) REFERENCES apartments(id),
+  CONSTRAINT fk_vote_history_user FOREIGN KEY (user_id) REFERENCES users(id),
+  CONSTRAINT fk_vote_history_cast_by FOREIGN KEY (cast_by) REFERENCES users(id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_apartment_vote_history ON apartment_vote_history(proposal_id, apartment_id);
@@ -0,0 +1,33 @@
+-- Migration: meter readings submitted by residents, with a photo of the dial
+-- A submission stays Pending until a manager approves it, which records it as a Manual
+-- reading (reading_id), or rejects it with a note. previous_value is the last counted
+-- reading when it was submitted, for the manager to compare against.
+CREATE TABLE meter_reading_submissions (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  meter_id BIGINT UNSIGNED NOT NULL,
+  submitted_by BIGINT UNSIGNED NOT NULL,
+  reading_value DECIMAL(15,4) NOT NULL,
+  reading_timestamp DATETIME NOT NULL,
+  unit VARCHAR(16) NOT NULL,
+  previous_value DECIMAL(15,4) NULL,
+  previous_timestamp DATETIME NULL,
+  photo_original_filename VARCHAR(255) NOT NULL,
+  photo_stored_filename VARCHAR(255) NOT NULL,
+  photo_mime_type VARCHAR(128) NOT NULL,
+  photo_size_bytes BIGINT UNSIGNED NOT NULL,
+  photo_checksum_sha256 CHAR(64) NULL,
+  scan_status VARCHAR(16) NOT NULL DEFAULT 'pending',
+  status VARCHAR(16) NOT NULL DEFAULT 'Pending',
+  review_note VARCHAR(500) NULL,
+  reviewed_by BIGINT UNSIGNED NULL,
+  reviewed_at DATETIME NULL,
+  reading_id BIGINT UNSIGNED NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_submission_meter FOREIGN KEY (meter_id) REFERENCES meters(id),
+  CONSTRAINT fk_submission_submitter FOREIGN KEY (submitted_by) REFERENCES users(id),
+  CONSTRAINT fk_submission_reviewer FOREIGN KEY (reviewed_by) REFERENCES users(id),
+  CONSTRAINT fk_submission_reading FOREIGN KEY (reading_id) REFERENCES meter_readings(id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_submissions_meter ON meter_reading_submissions(meter_id, status);
+CREATE INDEX idx_submissions_status ON meter_reading_submissions(status, scan_status);
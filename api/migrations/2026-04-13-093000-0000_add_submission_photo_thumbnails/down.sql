@@ -0,0 +1 @@
+ALTER TABLE meter_reading_submissions DROP COLUMN photo_has_thumbnail;
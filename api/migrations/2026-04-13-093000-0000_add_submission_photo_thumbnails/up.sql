@@ -0,0 +1,3 @@
+-- Migration: keep the thumbnail generated for submission photos
+ALTER TABLE meter_reading_submissions
+  ADD COLUMN photo_has_thumbnail BOOLEAN NOT NULL DEFAULT FALSE AFTER photo_checksum_sha256;
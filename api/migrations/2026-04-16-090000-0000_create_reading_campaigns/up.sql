@@ -0,0 +1,20 @@
+-- Migration: periodic reading campaigns per building
+-- A campaign collects a reading of every active meter in the building between
+-- start_date and deadline. Meters still missing one when it is closed get a reading
+-- estimated from their history (source 'Estimated').
+CREATE TABLE meter_reading_campaigns (
+  id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+  building_id BIGINT UNSIGNED NOT NULL,
+  name VARCHAR(255) NOT NULL,
+  start_date DATE NOT NULL,
+  deadline DATE NOT NULL,
+  status VARCHAR(16) NOT NULL DEFAULT 'Open',
+  created_by BIGINT UNSIGNED NOT NULL,
+  last_reminded_at DATETIME NULL,
+  closed_at DATETIME NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_campaign_building FOREIGN KEY (building_id) REFERENCES buildings(id),
+  CONSTRAINT fk_campaign_creator FOREIGN KEY (created_by) REFERENCES users(id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_campaigns_building ON meter_reading_campaigns(building_id, status);
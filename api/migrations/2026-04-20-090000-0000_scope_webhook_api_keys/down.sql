@@ -0,0 +1,15 @@
+DROP TABLE IF EXISTS webhook_api_key_meters;
+DROP TABLE IF EXISTS webhook_api_key_buildings;
+ALTER TABLE webhook_api_keys
+  DROP FOREIGN KEY fk_apikey_rotated_from,
+  DROP INDEX uk_apikey_prefix,
+  DROP COLUMN rotated_from_id,
+  DROP COLUMN rate_window_count,
+  DROP COLUMN rate_window_start,
+  DROP COLUMN last_error_at,
+  DROP COLUMN last_error,
+  DROP COLUMN usage_count,
+  DROP COLUMN scoped,
+  DROP COLUMN rate_limit_per_minute,
+  DROP COLUMN expires_at,
+  DROP COLUMN key_prefix;
@@ -0,0 +1,37 @@
+-- Migration: scoped, rotatable webhook API keys
+-- key_prefix is the public part of a key (hm_<prefix>_<secret>), so a request needs one
+-- hash check instead of one per key; keys created before have none. A key with no
+-- building and no meter rows may write readings for every meter, unless it is scoped:
+-- once its buildings and meters are all deleted, a scoped key is refused rather than
+-- widened to every meter. Rotation creates a
+-- successor (rotated_from_id) and lets the old key run until its expires_at.
+-- rate_window_start/count hold the current minute of the per-key rate limit.
+ALTER TABLE webhook_api_keys
+  ADD COLUMN key_prefix VARCHAR(16) NULL AFTER name,
+  ADD COLUMN expires_at DATETIME NULL AFTER is_active,
+  ADD COLUMN rate_limit_per_minute INT UNSIGNED NULL AFTER expires_at,
+  ADD COLUMN scoped BOOLEAN NOT NULL DEFAULT FALSE AFTER rate_limit_per_minute,
+  ADD COLUMN usage_count BIGINT UNSIGNED NOT NULL DEFAULT 0 AFTER last_used_at,
+  ADD COLUMN last_error VARCHAR(255) NULL AFTER usage_count,
+  ADD COLUMN last_error_at DATETIME NULL AFTER last_error,
+  ADD COLUMN rate_window_start DATETIME NULL AFTER last_error_at,
+  ADD COLUMN rate_window_count INT UNSIGNED NOT NULL DEFAULT 0 AFTER rate_window_start,
+  ADD COLUMN rotated_from_id BIGINT UNSIGNED NULL AFTER rate_window_count,
+  ADD UNIQUE KEY uk_apikey_prefix (key_prefix),
+  ADD CONSTRAINT fk_apikey_rotated_from FOREIGN KEY (rotated_from_id) REFERENCES webhook_api_keys(id);
+
+CREATE TABLE webhook_api_key_buildings (
+  api_key_id BIGINT UNSIGNED NOT NULL,
+  building_id BIGINT UNSIGNED NOT NULL,
+  PRIMARY KEY (api_key_id, building_id),
+  CONSTRAINT fk_apikey_building_key FOREIGN KEY (api_key_id) REFERENCES webhook_api_keys(id) ON DELETE CASCADE,
+  CONSTRAINT fk_apikey_building FOREIGN KEY (building_id) REFERENCES buildings(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE TABLE webhook_api_key_meters (
+  api_key_id BIGINT UNSIGNED NOT NULL,
+  meter_id BIGINT UNSIGNED NOT NULL,
+  PRIMARY KEY (api_key_id, meter_id),
+  CONSTRAINT fk_apikey_meter_key FOREIGN KEY (api_key_id) REFERENCES webhook_api_keys(id) ON DELETE CASCADE,
+  CONSTRAINT fk_apikey_meter FOREIGN KEY (meter_id) REFERENCES meters(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
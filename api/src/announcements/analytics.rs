@@ -0,0 +1,484 @@
+//! Engagement analytics for managers.
+//!
+//! Built from the audience (see [`audience_user_ids`]), the read receipts and the
+//! comments. Times are measured from publication, or from creation for announcements
+//! that were never scheduled.
+
+use super::audience::{audience_user_ids, audiences, residents};
+use super::helpers::{conn, load_visible};
+use super::types::{
+    AnalyticsQuery, AnnouncementAnalytics, AnnouncementEngagement, DailyReads, EngagementSummary,
+    ReachGroup,
+};
+use crate::auth::{
+    building_access::get_user_building_ids, error::AppError, extractor::AuthContext,
+};
+use crate::db::DbPool;
+use crate::models::Announcement;
+use crate::schema::{announcements, announcements_audience, announcements_comments};
+use actix_web::{HttpResponse, web};
+use chrono::{Duration, NaiveDate, NaiveDateTime, Utc};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+const DEFAULT_DAYS: u32 = 90;
+const MAX_DAYS: u32 = 365;
+/// Most announcements covered by one summary.
+const SUMMARY_LIMIT: i64 = 100;
+
+fn read_rate(readers: usize, audience: usize) -> f64 {
+    if audience == 0 {
+        0.0
+    } else {
+        readers as f64 / audience as f64
+    }
+}
+
+fn median(values: &mut [i64]) -> Option<i64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2
+    } else {
+        values[mid]
+    })
+}
+
+/// First reads per day from the first to the last, days without reads included.
+fn daily_reads(dates: &[NaiveDate]) -> Vec<DailyReads> {
+    let mut per_day: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+    for d in dates {
+        *per_day.entry(*d).or_default() += 1;
+    }
+    let (Some(first), Some(last)) = (
+        per_day.keys().next().copied(),
+        per_day.keys().next_back().copied(),
+    ) else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    let mut cumulative = 0;
+    for date in first.iter_days().take_while(|d| *d <= last) {
+        let readers = per_day.get(&date).copied().unwrap_or(0);
+        cumulative += readers;
+        out.push(DailyReads {
+            date,
+            readers,
+            cumulative,
+        });
+    }
+    out
+}
+
+fn published(ann: &Announcement) -> Option<NaiveDateTime> {
+    ann.publish_at.or(ann.created_at)
+}
+
+fn minutes_between(from: NaiveDateTime, to: NaiveDateTime) -> i64 {
+    (to - from).num_minutes().max(0)
+}
+
+/// Buildings each user lives in, as owner or active renter.
+fn user_buildings(
+    user_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<HashMap<u64, Vec<u64>>, AppError> {
+    use crate::schema::apartment_owners::dsl as ao;
+    use crate::schema::apartment_renters::dsl as ar;
+    use crate::schema::apartments::dsl as ap;
+
+    let mut rows: Vec<(u64, u64)> = ao::apartment_owners
+        .inner_join(ap::apartments.on(ap::id.eq(ao::apartment_id)))
+        .filter(ao::user_id.eq_any(user_ids))
+        .filter(ap::is_deleted.eq(false))
+        .select((ao::user_id, ap::building_id))
+        .load(conn)?;
+    rows.extend(
+        ar::apartment_renters
+            .inner_join(ap::apartments.on(ap::id.eq(ar::apartment_id)))
+            .filter(ar::user_id.eq_any(user_ids))
+            .filter(ar::is_active.eq(true))
+            .filter(ap::is_deleted.eq(false))
+            .select((ar::user_id, ap::building_id))
+            .load::<(u64, u64)>(conn)?,
+    );
+    let mut out: HashMap<u64, Vec<u64>> = HashMap::new();
+    for (user_id, building_id) in rows {
+        let list = out.entry(user_id).or_default();
+        if !list.contains(&building_id) {
+            list.push(building_id);
+        }
+    }
+    Ok(out)
+}
+
+type Read = (u64, NaiveDateTime, Option<NaiveDateTime>);
+
+/// Read times per reader of each of the given announcements.
+fn reads(
+    announcement_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<HashMap<u64, Vec<Read>>, AppError> {
+    use crate::schema::announcements_reads::dsl as r;
+    let rows: Vec<(u64, u64, NaiveDateTime, Option<NaiveDateTime>)> = r::announcements_reads
+        .filter(r::announcement_id.eq_any(announcement_ids))
+        .select((
+            r::announcement_id,
+            r::user_id,
+            r::read_at,
+            r::acknowledged_at,
+        ))
+        .load(conn)?;
+    let mut out: HashMap<u64, Vec<Read>> = HashMap::new();
+    for (announcement_id, user_id, read_at, acknowledged_at) in rows {
+        out.entry(announcement_id)
+            .or_default()
+            .push((user_id, read_at, acknowledged_at));
+    }
+    Ok(out)
+}
+
+/// Authors of the live comments on each of the given announcements, one entry per
+/// comment.
+fn comment_authors(
+    announcement_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<HashMap<u64, Vec<u64>>, AppError> {
+    use announcements_comments::dsl as cm;
+    let rows: Vec<(u64, u64)> = cm::announcements_comments
+        .filter(cm::announcement_id.eq_any(announcement_ids))
+        .filter(cm::is_deleted.eq(false))
+        .select((cm::announcement_id, cm::user_id))
+        .load(conn)?;
+    let mut out: HashMap<u64, Vec<u64>> = HashMap::new();
+    for (announcement_id, user_id) in rows {
+        out.entry(announcement_id).or_default().push(user_id);
+    }
+    Ok(out)
+}
+
+/// Engagement analytics for an announcement
+///
+/// Audience size, readers (overall and over time), acknowledgements, comments, time from
+/// publication to reading, and reach broken down by the readers' buildings and roles.
+/// Requires Admin or Manager role.
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/{id}/analytics",
+    params(
+        ("id" = u64, Path, description = "Announcement ID")
+    ),
+    responses(
+        (status = 200, description = "Announcement analytics", body = AnnouncementAnalytics),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Announcement not found or deleted"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn announcement_analytics(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::buildings::dsl as b;
+    use crate::schema::roles::dsl as rl;
+    use crate::schema::user_roles::dsl as ur;
+
+    auth.require_roles(&["Admin", "Manager"])?;
+    let id = path.into_inner();
+    let mut c = conn(&pool)?;
+    let ann = load_visible(id, &auth, &mut c)?;
+    let start = published(&ann);
+
+    let audience: HashSet<u64> = audience_user_ids(id, &mut c)?.into_iter().collect();
+    let reads = reads(&[id], &mut c)?.remove(&id).unwrap_or_default();
+    let audience_reads: Vec<&Read> = reads
+        .iter()
+        .filter(|(user_id, _, _)| audience.contains(user_id))
+        .collect();
+    let readers: HashSet<u64> = audience_reads.iter().map(|r| r.0).collect();
+
+    let mut minutes: Vec<i64> = match start {
+        Some(s) => audience_reads
+            .iter()
+            .map(|r| minutes_between(s, r.1))
+            .collect(),
+        None => Vec::new(),
+    };
+    let minutes_to_first_read = minutes.iter().min().copied();
+    let dates: Vec<NaiveDate> = audience_reads.iter().map(|r| r.1.date()).collect();
+
+    let authors = comment_authors(&[id], &mut c)?
+        .remove(&id)
+        .unwrap_or_default();
+    let commenters: HashSet<u64> = authors.iter().copied().collect();
+
+    // Reach by building
+    let audience_ids: Vec<u64> = audience.iter().copied().collect();
+    let homes = user_buildings(&audience_ids, &mut c)?;
+    let mut by_building: HashMap<Option<u64>, (usize, usize)> = HashMap::new();
+    for user_id in &audience_ids {
+        let read = readers.contains(user_id) as usize;
+        match homes.get(user_id) {
+            Some(list) => {
+                for building_id in list {
+                    let entry = by_building.entry(Some(*building_id)).or_default();
+                    entry.0 += 1;
+                    entry.1 += read;
+                }
+            }
+            None => {
+                let entry = by_building.entry(None).or_default();
+                entry.0 += 1;
+                entry.1 += read;
+            }
+        }
+    }
+    let building_ids: Vec<u64> = by_building.keys().filter_map(|k| *k).collect();
+    let addresses: HashMap<u64, String> = b::buildings
+        .filter(b::id.eq_any(&building_ids))
+        .select((b::id, b::address))
+        .load::<(u64, String)>(&mut c)?
+        .into_iter()
+        .collect();
+    let mut reach_by_building: Vec<ReachGroup> = by_building
+        .into_iter()
+        .map(|(building_id, (audience, readers))| ReachGroup {
+            id: building_id,
+            label: building_id
+                .and_then(|bid| addresses.get(&bid).cloned())
+                .unwrap_or_default(),
+            audience,
+            readers,
+        })
+        .collect();
+    // Residents without an apartment last
+    reach_by_building.sort_by(|x, y| {
+        x.id.is_none()
+            .cmp(&y.id.is_none())
+            .then_with(|| x.label.cmp(&y.label))
+    });
+
+    // Reach by role
+    let roles: Vec<(u64, String)> = ur::user_roles
+        .inner_join(rl::roles.on(rl::id.eq(ur::role_id)))
+        .filter(ur::user_id.eq_any(&audience_ids))
+        .select((ur::user_id, rl::name))
+        .load(&mut c)?;
+    let mut by_role: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    for (user_id, role) in roles {
+        let entry = by_role.entry(role).or_default();
+        entry.0 += 1;
+        entry.1 += readers.contains(&user_id) as usize;
+    }
+    let reach_by_role = by_role
+        .into_iter()
+        .map(|(label, (audience, readers))| ReachGroup {
+            id: None,
+            label,
+            audience,
+            readers,
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(AnnouncementAnalytics {
+        announcement_id: id,
+        title: ann.title,
+        publish_at: ann.publish_at,
+        audience_total: audience.len(),
+        audience_read_count: readers.len(),
+        reader_count: reads.len(),
+        acknowledged_count: audience_reads.iter().filter(|r| r.2.is_some()).count(),
+        read_rate: read_rate(readers.len(), audience.len()),
+        comment_count: authors.len(),
+        commenter_count: commenters.len(),
+        minutes_to_first_read,
+        median_minutes_to_read: median(&mut minutes),
+        readers_over_time: daily_reads(&dates),
+        reach_by_building,
+        reach_by_role,
+    }))
+}
+
+/// Announcement engagement summary
+///
+/// Audience, readers, comments and time to first read for the announcements published
+/// in the last `days` days, newest first. With `building_id`, only announcements
+/// addressed to that building are included and only its residents are counted;
+/// managers without a building see their buildings' residents. Requires Admin or
+/// Manager role.
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/analytics",
+    params(AnalyticsQuery),
+    responses(
+        (status = 200, description = "Engagement summary", body = EngagementSummary),
+        (status = 403, description = "Forbidden - requires Admin or Manager role, or no access to the building"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn engagement_summary(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    query: web::Query<AnalyticsQuery>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::apartments::dsl as ap;
+    use announcements::dsl as a;
+    use announcements_audience::dsl as au;
+
+    auth.require_roles(&["Admin", "Manager"])?;
+    let days = query.days.unwrap_or(DEFAULT_DAYS).clamp(1, MAX_DAYS);
+    let mut c = conn(&pool)?;
+    let managed = get_user_building_ids(auth.user_id()?, auth.has_any_role(&["Admin"]), &mut c)?;
+    if let (Some(building_id), Some(ids)) = (query.building_id, &managed)
+        && !ids.contains(&building_id)
+    {
+        return Err(AppError::Forbidden);
+    }
+    let counted: Option<HashSet<u64>> = match (query.building_id, &managed) {
+        (Some(building_id), _) => Some(residents(Some(&[building_id]), &mut c)?),
+        (None, Some(ids)) => Some(residents(Some(ids), &mut c)?),
+        (None, None) => None,
+    };
+
+    let now = Utc::now().naive_utc();
+    let mut q = a::announcements
+        .filter(a::is_deleted.eq(false))
+        .filter(a::publish_at.is_not_null())
+        .filter(a::publish_at.le(now))
+        .filter(a::publish_at.ge(now - Duration::days(days as i64)))
+        .into_boxed();
+    if let Some(building_id) = query.building_id {
+        let building_targeted = au::announcements_audience
+            .filter(au::building_id.is_not_null())
+            .select(au::announcement_id);
+        let this_building = au::announcements_audience
+            .filter(au::building_id.eq(building_id))
+            .select(au::announcement_id);
+        let apartment_targeted = au::announcements_audience
+            .filter(au::apartment_id.is_not_null())
+            .select(au::announcement_id);
+        let apartments_here = ap::apartments
+            .filter(ap::building_id.eq(building_id))
+            .select(ap::id.nullable());
+        let this_building_apartments = au::announcements_audience
+            .filter(au::apartment_id.eq_any(apartments_here))
+            .select(au::announcement_id);
+        q = q
+            .filter(
+                a::id
+                    .ne_all(building_targeted)
+                    .or(a::id.eq_any(this_building)),
+            )
+            .filter(
+                a::id
+                    .ne_all(apartment_targeted)
+                    .or(a::id.eq_any(this_building_apartments)),
+            );
+    }
+    let anns = q
+        .order(a::publish_at.desc())
+        .limit(SUMMARY_LIMIT)
+        .load::<Announcement>(&mut c)?;
+
+    let ann_ids: Vec<u64> = anns.iter().map(|a| a.id).collect();
+    let mut audience_by_ann = audiences(&ann_ids, &mut c)?;
+    let mut reads_by_ann = reads(&ann_ids, &mut c)?;
+    let mut authors_by_ann = comment_authors(&ann_ids, &mut c)?;
+
+    let mut rows = Vec::with_capacity(anns.len());
+    for ann in anns {
+        let mut audience = audience_by_ann.remove(&ann.id).unwrap_or_default();
+        if let Some(counted) = &counted {
+            audience.retain(|id| counted.contains(id));
+        }
+        let audience: HashSet<u64> = audience.into_iter().collect();
+        let audience_reads: Vec<NaiveDateTime> = reads_by_ann
+            .remove(&ann.id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(user_id, _, _)| audience.contains(user_id))
+            .map(|r| r.1)
+            .collect();
+        let comment_count = authors_by_ann
+            .remove(&ann.id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|id| counted.as_ref().is_none_or(|set| set.contains(id)))
+            .count();
+        let minutes_to_first_read = published(&ann).and_then(|s| {
+            audience_reads
+                .iter()
+                .min()
+                .map(|first| minutes_between(s, *first))
+        });
+        rows.push(AnnouncementEngagement {
+            announcement_id: ann.id,
+            title: ann.title,
+            publish_at: ann.publish_at,
+            audience_total: audience.len(),
+            audience_read_count: audience_reads.len(),
+            read_rate: read_rate(audience_reads.len(), audience.len()),
+            comment_count,
+            minutes_to_first_read,
+        });
+    }
+
+    let audience_total = rows.iter().map(|r| r.audience_total).sum();
+    let audience_read_count = rows.iter().map(|r| r.audience_read_count).sum();
+    let mut firsts: Vec<i64> = rows
+        .iter()
+        .filter_map(|r| r.minutes_to_first_read)
+        .collect();
+    Ok(HttpResponse::Ok().json(EngagementSummary {
+        building_id: query.building_id,
+        days,
+        announcement_count: rows.len(),
+        audience_total,
+        audience_read_count,
+        read_rate: read_rate(audience_read_count, audience_total),
+        comment_count: rows.iter().map(|r| r.comment_count).sum(),
+        median_minutes_to_first_read: median(&mut firsts),
+        announcements: rows,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn median_of_odd_and_even_counts() {
+        assert_eq!(median(&mut []), None);
+        assert_eq!(median(&mut [30, 10, 20]), Some(20));
+        assert_eq!(median(&mut [40, 10, 20, 30]), Some(25));
+    }
+
+    #[test]
+    fn daily_reads_fill_gaps_and_accumulate() {
+        let series = daily_reads(&[date("2026-03-01"), date("2026-03-03"), date("2026-03-01")]);
+        let counts: Vec<(usize, usize)> =
+            series.iter().map(|d| (d.readers, d.cumulative)).collect();
+        assert_eq!(counts, vec![(2, 2), (0, 2), (1, 3)]);
+        assert_eq!(series[1].date, date("2026-03-02"));
+        assert!(daily_reads(&[]).is_empty());
+    }
+
+    #[test]
+    fn read_rate_of_empty_audience_is_zero() {
+        assert_eq!(read_rate(0, 0), 0.0);
+        assert_eq!(read_rate(1, 4), 0.25);
+    }
+}
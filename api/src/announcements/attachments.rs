@@ -0,0 +1,608 @@
+//! Files and inline images attached to announcements.
+//!
+//! Uploads go through the shared [`crate::uploads`] pipeline (size and MIME checks,
+//! quarantine, malware scan, metadata stripping). Markdown bodies reference them as
+//! `attachment:<id>`; rendering turns that into the download path, and every response
+//! appends a short-lived signed `token` so `<img>` tags and plain links work without
+//! an `Authorization` header.
+
+use super::helpers::{conn, load_visible, render_markdown_with};
+use super::translations::refresh_translations;
+use super::types::{AttachmentOut, AttachmentQuery};
+use crate::auth::{AppError, AuthContext, JwtKeys};
+use crate::db::DbPool;
+use crate::models::{Announcement, AnnouncementAttachment};
+use crate::scanning::Scanner;
+use crate::schema::{announcements, announcements_attachments};
+use crate::storage::Storage;
+use crate::uploads::{
+    self, SCAN_CLEAN, SCAN_INFECTED, SCAN_PENDING, ScanOutcome, UploadKeys, images,
+};
+use actix_multipart::Multipart;
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::{HttpRequest, HttpResponse, mime, web};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Lifetime of the signed download links handed out with announcements.
+const LINK_TTL_HOURS: i64 = 6;
+
+/// Markdown link/image target naming an attachment, e.g. `![plan](attachment:12)`.
+pub(super) const ATTACHMENT_SCHEME: &str = "attachment:";
+
+/// Audience of download-link tokens. Login tokens carry no audience, so neither kind
+/// is accepted in place of the other.
+const LINK_AUDIENCE: &str = "attachment-link";
+
+/// Claims of a signed download link; scoped to a single attachment.
+#[derive(Serialize, Deserialize)]
+struct LinkClaims {
+    aud: String,
+    att: u64,
+    exp: usize,
+}
+
+/// Storage key of an announcement attachment's content.
+pub fn object_key(announcement_id: u64, stored_filename: &str) -> String {
+    format!("announcements/{}/{}", announcement_id, stored_filename)
+}
+
+/// Storage key of an announcement upload held in quarantine until it has been scanned.
+pub fn quarantine_key(announcement_id: u64, stored_filename: &str) -> String {
+    format!(
+        "quarantine/announcements/{}/{}",
+        announcement_id, stored_filename
+    )
+}
+
+/// Storage key of an image attachment's thumbnail.
+pub fn thumbnail_key(announcement_id: u64, stored_filename: &str) -> String {
+    format!(
+        "announcements/{}/{}{}",
+        announcement_id,
+        stored_filename,
+        images::THUMBNAIL_SUFFIX
+    )
+}
+
+/// Unsigned download path, as written into the rendered body.
+pub(super) fn download_path(announcement_id: u64, att_id: u64) -> String {
+    format!(
+        "/api/v1/announcements/{}/attachments/{}/download",
+        announcement_id, att_id
+    )
+}
+
+fn thumbnail_path(announcement_id: u64, att_id: u64) -> String {
+    format!(
+        "/api/v1/announcements/{}/attachments/{}/thumbnail",
+        announcement_id, att_id
+    )
+}
+
+fn sign(att_id: u64, keys: &JwtKeys) -> Result<String, AppError> {
+    let exp = chrono::Utc::now() + chrono::Duration::hours(LINK_TTL_HOURS);
+    jsonwebtoken::encode(
+        &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS256),
+        &LinkClaims {
+            aud: LINK_AUDIENCE.to_string(),
+            att: att_id,
+            exp: exp.timestamp() as usize,
+        },
+        &keys.encoding,
+    )
+    .map_err(|_| AppError::Internal("token_encode".into()))
+}
+
+fn verify(token: &str, att_id: u64, keys: &JwtKeys) -> bool {
+    let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::HS256);
+    validation.set_audience(&[LINK_AUDIENCE]);
+    validation.set_required_spec_claims(&["exp", "aud"]);
+    jsonwebtoken::decode::<LinkClaims>(token, &keys.decoding, &validation)
+        .map(|data| data.claims.att == att_id)
+        .unwrap_or(false)
+}
+
+fn to_out(a: AnnouncementAttachment, keys: &JwtKeys) -> Result<AttachmentOut, AppError> {
+    let token = sign(a.id, keys)?;
+    Ok(AttachmentOut {
+        url: format!("{}?token={}", download_path(a.announcement_id, a.id), token),
+        thumbnail_url: a.has_thumbnail.then(|| {
+            format!(
+                "{}?token={}",
+                thumbnail_path(a.announcement_id, a.id),
+                token
+            )
+        }),
+        id: a.id,
+        original_filename: a.original_filename,
+        mime_type: a.mime_type,
+        size_bytes: a.size_bytes,
+        created_at: a.created_at,
+    })
+}
+
+fn visible_attachments(
+    announcement_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<Vec<AnnouncementAttachment>, AppError> {
+    use announcements_attachments::dsl as at;
+    Ok(at::announcements_attachments
+        .filter(at::announcement_id.eq_any(announcement_ids))
+        .filter(at::is_deleted.eq(false))
+        .filter(at::scan_status.eq(SCAN_CLEAN))
+        .order(at::id.asc())
+        .select(AnnouncementAttachment::as_select())
+        .load(conn)?)
+}
+
+/// Released attachments per announcement, with signed links.
+pub(super) fn load_attachments(
+    announcement_ids: &[u64],
+    keys: &JwtKeys,
+    conn: &mut MysqlConnection,
+) -> Result<HashMap<u64, Vec<AttachmentOut>>, AppError> {
+    let mut out: HashMap<u64, Vec<AttachmentOut>> = HashMap::new();
+    if announcement_ids.is_empty() {
+        return Ok(out);
+    }
+    for a in visible_attachments(announcement_ids, conn)? {
+        out.entry(a.announcement_id)
+            .or_default()
+            .push(to_out(a, keys)?);
+    }
+    Ok(out)
+}
+
+/// Add the signed token to every attachment URL in a rendered body.
+pub(super) fn sign_body(announcement_id: u64, html: &str, attachments: &[AttachmentOut]) -> String {
+    let mut html = html.to_string();
+    for a in attachments {
+        // Rendered attributes are always double-quoted by the sanitiser
+        let unsigned = format!("\"{}\"", download_path(announcement_id, a.id));
+        html = html.replace(&unsigned, &format!("\"{}\"", a.url));
+    }
+    html
+}
+
+/// Render an announcement body against its current attachments.
+pub(super) fn render_body(
+    announcement_id: u64,
+    body_md: &str,
+    conn: &mut MysqlConnection,
+) -> Result<String, AppError> {
+    let ids: Vec<u64> = visible_attachments(&[announcement_id], conn)?
+        .into_iter()
+        .map(|a| a.id)
+        .collect();
+    let resolve = |dest: &str| {
+        let id = dest.strip_prefix(ATTACHMENT_SCHEME)?.parse::<u64>().ok()?;
+        ids.contains(&id)
+            .then(|| download_path(announcement_id, id))
+    };
+    Ok(render_markdown_with(body_md, resolve))
+}
+
+/// Re-render the stored bodies after attachments were added or removed, so
+/// references to them start or stop resolving.
+fn refresh_body(announcement_id: u64, conn: &mut MysqlConnection) -> Result<(), AppError> {
+    use announcements::dsl as a;
+    let body_md: String = a::announcements
+        .filter(a::id.eq(announcement_id))
+        .select(a::body_md)
+        .first(conn)?;
+    let html = render_body(announcement_id, &body_md, conn)?;
+    diesel::update(a::announcements.filter(a::id.eq(announcement_id)))
+        .set(a::body_html.eq(html))
+        .execute(conn)?;
+    refresh_translations(announcement_id, conn)
+}
+
+/// Load a live announcement the caller may edit (Admin, Manager or author).
+pub(super) fn load_editable(
+    id: u64,
+    auth: &AuthContext,
+    conn: &mut MysqlConnection,
+) -> Result<Announcement, AppError> {
+    use announcements::dsl as a;
+    let ann = a::announcements
+        .filter(a::id.eq(id))
+        .first::<Announcement>(conn)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+    if ann.is_deleted {
+        return Err(AppError::NotFound);
+    }
+    let is_author = ann.author_id == auth.user_id()?;
+    if !(is_author || auth.has_any_role(&["Admin", "Manager"])) {
+        return Err(AppError::Forbidden);
+    }
+    Ok(ann)
+}
+
+/// Upload an announcement attachment
+///
+/// Attaches a file (e.g. a PDF agenda) or an image to an announcement using
+/// multipart/form-data. The upload goes through the same checks as maintenance
+/// attachments: size and MIME limits, quarantine and malware scan, and metadata
+/// stripping for JPEG/PNG. Reference it from the body as `attachment:<id>`, e.g.
+/// `![Floor plan](attachment:12)`. Accessible by Admin, Manager, or the author.
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/{id}/attachments",
+    params(
+        ("id" = u64, Path, description = "Announcement ID")
+    ),
+    request_body(content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Attachment uploaded", body = AttachmentOut),
+        (status = 400, description = "Bad request - no file, invalid MIME type or undecodable image"),
+        (status = 403, description = "Forbidden - requires Admin, Manager, or author"),
+        (status = 404, description = "Announcement not found or deleted"),
+        (status = 413, description = "File too large"),
+        (status = 422, description = "Malware detected - upload rejected"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn upload_attachment(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+    cfg: web::Data<crate::config::AppConfig>,
+    keys: web::Data<JwtKeys>,
+    storage: web::Data<dyn Storage>,
+    scanner: web::Data<dyn Scanner>,
+    mut payload: Multipart,
+) -> Result<HttpResponse, AppError> {
+    use announcements_attachments::dsl as at;
+    let id = path.into_inner();
+    let user_id = auth.user_id()?;
+    let mut c = conn(&pool)?;
+    load_editable(id, &auth, &mut c)?;
+
+    let upload = uploads::receive(&mut payload, &cfg).await?;
+    let stored_filename = Uuid::new_v4().to_string();
+    let upload_keys = UploadKeys {
+        quarantine: quarantine_key(id, &stored_filename),
+        object: object_key(id, &stored_filename),
+        thumbnail: Some(thumbnail_key(id, &stored_filename)),
+    };
+    let att_id = uploads::store_scanned(
+        upload,
+        &upload_keys,
+        storage.get_ref(),
+        scanner.get_ref(),
+        &mut c,
+        |upload, conn| {
+            diesel::insert_into(at::announcements_attachments)
+                .values((
+                    at::announcement_id.eq(id),
+                    at::original_filename.eq(&upload.original_filename),
+                    at::stored_filename.eq(&stored_filename),
+                    at::mime_type.eq(upload.mime),
+                    at::size_bytes.eq(upload.data.len() as u64),
+                    at::scan_status.eq(SCAN_PENDING),
+                    at::uploaded_by.eq(user_id),
+                ))
+                .execute(conn)
+        },
+        |att_id, outcome, conn| {
+            let row = at::announcements_attachments.filter(at::id.eq(att_id));
+            match outcome {
+                ScanOutcome::Clean(stored) => diesel::update(row)
+                    .set((
+                        at::size_bytes.eq(stored.size_bytes),
+                        at::checksum_sha256.eq(Some(stored.checksum_sha256)),
+                        at::has_thumbnail.eq(stored.has_thumbnail),
+                        at::scan_status.eq(SCAN_CLEAN),
+                    ))
+                    .execute(conn),
+                ScanOutcome::Infected(signature) => diesel::update(row)
+                    .set((
+                        at::scan_status.eq(SCAN_INFECTED),
+                        at::scan_signature.eq(Some(signature)),
+                    ))
+                    .execute(conn),
+                ScanOutcome::Discarded => diesel::delete(row).execute(conn),
+            }
+        },
+    )
+    .await?;
+    refresh_body(id, &mut c)?;
+
+    let row = at::announcements_attachments
+        .filter(at::id.eq(att_id))
+        .select(AnnouncementAttachment::as_select())
+        .first(&mut c)?;
+    Ok(HttpResponse::Created().json(to_out(row, &keys)?))
+}
+
+/// Load a released attachment after checking the signed token or, without one,
+/// that the caller may see the announcement.
+fn load_downloadable(
+    id: u64,
+    att_id: u64,
+    token: Option<&str>,
+    auth: Option<&AuthContext>,
+    keys: &JwtKeys,
+    conn: &mut MysqlConnection,
+) -> Result<AnnouncementAttachment, AppError> {
+    use announcements::dsl as a;
+    use announcements_attachments::dsl as at;
+    match (token, auth) {
+        (Some(token), _) if verify(token, att_id, keys) => {
+            let deleted: bool = a::announcements
+                .filter(a::id.eq(id))
+                .select(a::is_deleted)
+                .first::<bool>(conn)
+                .optional()?
+                .ok_or(AppError::NotFound)?;
+            if deleted {
+                return Err(AppError::NotFound);
+            }
+        }
+        (_, Some(auth)) => {
+            load_visible(id, auth, conn)?;
+        }
+        _ => return Err(AppError::Unauthorized),
+    }
+    at::announcements_attachments
+        .filter(at::id.eq(att_id))
+        .filter(at::announcement_id.eq(id))
+        .filter(at::is_deleted.eq(false))
+        .filter(at::scan_status.eq(SCAN_CLEAN))
+        .select(AnnouncementAttachment::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or(AppError::NotFound)
+}
+
+/// Download an announcement attachment
+///
+/// Streams the attachment with its stored Content-Type; images and PDFs are served
+/// inline so they can be embedded or opened in the browser. Requires either the signed
+/// `token` from the announcement's attachment URL or a bearer token of a user who can
+/// see the announcement. Supports `Range` requests.
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/{id}/attachments/{att_id}/download",
+    params(
+        ("id" = u64, Path, description = "Announcement ID"),
+        ("att_id" = u64, Path, description = "Attachment ID"),
+        AttachmentQuery
+    ),
+    responses(
+        (status = 200, description = "File content", content_type = "application/octet-stream"),
+        (status = 206, description = "Partial file content for a Range request", content_type = "application/octet-stream"),
+        (status = 401, description = "Unauthorized - missing or expired link token"),
+        (status = 403, description = "Forbidden - announcement not addressed to the user"),
+        (status = 404, description = "Attachment not found or deleted"),
+        (status = 416, description = "Requested range not satisfiable"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements"
+)]
+pub async fn download_attachment(
+    pool: web::Data<DbPool>,
+    auth: Option<AuthContext>,
+    http_req: HttpRequest,
+    path: web::Path<(u64, u64)>,
+    query: web::Query<AttachmentQuery>,
+    keys: web::Data<JwtKeys>,
+    storage: web::Data<dyn Storage>,
+) -> Result<HttpResponse, AppError> {
+    let (id, att_id) = path.into_inner();
+    let mut c = conn(&pool)?;
+    let item = load_downloadable(
+        id,
+        att_id,
+        query.token.as_deref(),
+        auth.as_ref(),
+        &keys,
+        &mut c,
+    )?;
+    let mime_type: mime::Mime = item
+        .mime_type
+        .parse()
+        .unwrap_or(mime::APPLICATION_OCTET_STREAM);
+    let inline = mime_type.type_() == mime::IMAGE || mime_type == mime::APPLICATION_PDF;
+    let disposition = ContentDisposition {
+        disposition: if inline {
+            DispositionType::Inline
+        } else {
+            DispositionType::Attachment
+        },
+        parameters: vec![DispositionParam::Filename(item.original_filename)],
+    };
+    uploads::serve(
+        &http_req,
+        storage.get_ref(),
+        &object_key(id, &item.stored_filename),
+        item.size_bytes,
+        mime_type,
+        disposition,
+    )
+    .await
+}
+
+/// Download an announcement attachment thumbnail
+///
+/// Returns a small JPEG preview for image attachments. Same access rules as the download.
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/{id}/attachments/{att_id}/thumbnail",
+    params(
+        ("id" = u64, Path, description = "Announcement ID"),
+        ("att_id" = u64, Path, description = "Attachment ID"),
+        AttachmentQuery
+    ),
+    responses(
+        (status = 200, description = "Thumbnail image", content_type = "image/jpeg"),
+        (status = 401, description = "Unauthorized - missing or expired link token"),
+        (status = 403, description = "Forbidden - announcement not addressed to the user"),
+        (status = 404, description = "Attachment not found, deleted, or has no thumbnail"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements"
+)]
+pub async fn download_attachment_thumbnail(
+    pool: web::Data<DbPool>,
+    auth: Option<AuthContext>,
+    http_req: HttpRequest,
+    path: web::Path<(u64, u64)>,
+    query: web::Query<AttachmentQuery>,
+    keys: web::Data<JwtKeys>,
+    storage: web::Data<dyn Storage>,
+) -> Result<HttpResponse, AppError> {
+    let (id, att_id) = path.into_inner();
+    let mut c = conn(&pool)?;
+    let item = load_downloadable(
+        id,
+        att_id,
+        query.token.as_deref(),
+        auth.as_ref(),
+        &keys,
+        &mut c,
+    )?;
+    if !item.has_thumbnail {
+        return Err(AppError::NotFound);
+    }
+    let key = thumbnail_key(id, &item.stored_filename);
+    uploads::serve_thumbnail(&http_req, storage.get_ref(), &key).await
+}
+
+/// Delete an announcement attachment
+///
+/// Soft-deletes the attachment; references to it in the body stop resolving.
+/// Accessible by Admin, Manager, or the author.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/announcements/{id}/attachments/{att_id}",
+    params(
+        ("id" = u64, Path, description = "Announcement ID"),
+        ("att_id" = u64, Path, description = "Attachment ID")
+    ),
+    responses(
+        (status = 204, description = "Attachment deleted"),
+        (status = 403, description = "Forbidden - requires Admin, Manager, or author"),
+        (status = 404, description = "Announcement or attachment not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_attachment(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<(u64, u64)>,
+) -> Result<HttpResponse, AppError> {
+    use announcements_attachments::dsl as at;
+    let (id, att_id) = path.into_inner();
+    let mut c = conn(&pool)?;
+    load_editable(id, &auth, &mut c)?;
+    let affected = diesel::update(
+        at::announcements_attachments
+            .filter(at::id.eq(att_id))
+            .filter(at::announcement_id.eq(id))
+            .filter(at::is_deleted.eq(false)),
+    )
+    .set(at::is_deleted.eq(true))
+    .execute(&mut c)?;
+    if affected == 0 {
+        return Err(AppError::NotFound);
+    }
+    refresh_body(id, &mut c)?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Remove the stored files of all attachments of an announcement being purged.
+pub(super) async fn purge_files(
+    announcement_id: u64,
+    storage: &dyn Storage,
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use announcements_attachments::dsl as at;
+    let rows: Vec<(String, bool)> = at::announcements_attachments
+        .filter(at::announcement_id.eq(announcement_id))
+        .filter(at::scan_status.eq(SCAN_CLEAN))
+        .select((at::stored_filename, at::has_thumbnail))
+        .load(conn)?;
+    for (stored_filename, has_thumbnail) in rows {
+        storage
+            .delete(&object_key(announcement_id, &stored_filename))
+            .await?;
+        if has_thumbnail {
+            storage
+                .delete(&thumbnail_key(announcement_id, &stored_filename))
+                .await?;
+        }
+    }
+    diesel::delete(at::announcements_attachments.filter(at::announcement_id.eq(announcement_id)))
+        .execute(conn)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn link_tokens_are_scoped_to_one_attachment() {
+        let keys = JwtKeys::from_secret("test-secret");
+        let token = sign(7, &keys).unwrap();
+        assert!(verify(&token, 7, &keys));
+        assert!(!verify(&token, 8, &keys));
+        assert!(!verify(&token, 7, &JwtKeys::from_secret("other")));
+        assert!(!verify("garbage", 7, &keys));
+    }
+
+    #[test]
+    fn link_and_login_tokens_are_not_interchangeable() {
+        let keys = JwtKeys::from_secret("test-secret");
+        let exp = (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize;
+        let header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS256);
+
+        // Same claims without the link audience, as any other token signed with the key
+        let unscoped = jsonwebtoken::encode(
+            &header,
+            &serde_json::json!({ "att": 7, "exp": exp }),
+            &keys.encoding,
+        )
+        .unwrap();
+        assert!(!verify(&unscoped, 7, &keys));
+
+        // The login extractor's validation rejects link tokens
+        let link = sign(7, &keys).unwrap();
+        let login_validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::HS256);
+        let as_login =
+            jsonwebtoken::decode::<serde_json::Value>(&link, &keys.decoding, &login_validation);
+        assert!(as_login.is_err());
+    }
+
+    #[test]
+    fn sign_body_only_touches_known_attachment_urls() {
+        let out = AttachmentOut {
+            id: 3,
+            original_filename: "plan.png".into(),
+            mime_type: "image/png".into(),
+            size_bytes: 10,
+            url: format!("{}?token=abc", download_path(1, 3)),
+            thumbnail_url: None,
+            created_at: None,
+        };
+        let html = format!(
+            r#"<p><img src="{}" alt="plan"><img src="{}"></p>"#,
+            download_path(1, 3),
+            download_path(1, 4)
+        );
+        let signed = sign_body(1, &html, &[out]);
+        assert!(signed.contains("/attachments/3/download?token=abc\""));
+        assert!(signed.contains("/attachments/4/download\""));
+    }
+}
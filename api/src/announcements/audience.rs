@@ -0,0 +1,518 @@
+//! Announcement targeting.
+//!
+//! Each `announcements_audience` row targets one role, building or apartment. Targets of
+//! the same kind are alternatives, different kinds must all match, and a kind without
+//! rows does not restrict anything. Roles, buildings and apartments cannot be hard-deleted
+//! while targeted, so a targeted announcement never silently loses its restriction.
+//! Visibility is expressed as SQL filters so that listings can be counted and paginated
+//! in the database.
+
+use super::types::{AudienceApartment, AudienceBuilding};
+use crate::auth::roles::ensure_role;
+use crate::auth::{error::AppError, extractor::AuthContext};
+use crate::models::Announcement;
+use crate::schema::{announcements, announcements_audience};
+use diesel::mysql::Mysql;
+use diesel::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+type BoxedAnnouncements<'a> = announcements::BoxedQuery<'a, Mysql>;
+
+/// Targeting of one announcement, resolved for display.
+#[derive(Default)]
+pub(super) struct Audience {
+    pub roles: Vec<String>,
+    pub buildings: Vec<AudienceBuilding>,
+    pub apartments: Vec<AudienceApartment>,
+}
+
+/// Requested targeting changes; `None` leaves that kind untouched.
+pub(super) struct AudienceUpdate<'a> {
+    pub roles: Option<&'a [String]>,
+    pub building_ids: Option<&'a [u64]>,
+    pub apartment_ids: Option<&'a [u64]>,
+}
+
+/// Validated targeting changes, ready to be saved.
+pub(super) struct ResolvedAudience {
+    role_ids: Option<Vec<u64>>,
+    building_ids: Option<Vec<u64>>,
+    apartment_ids: Option<Vec<u64>>,
+}
+
+impl ResolvedAudience {
+    pub fn is_empty(&self) -> bool {
+        self.role_ids.is_none() && self.building_ids.is_none() && self.apartment_ids.is_none()
+    }
+}
+
+/// The requesting user, as far as audience filtering is concerned.
+pub(super) struct Viewer {
+    pub is_manager: bool,
+    /// `None` when not restricted to specific buildings (Admin)
+    building_ids: Option<Vec<u64>>,
+    /// `None` for Admin/Manager, who see announcements for any apartment
+    apartment_ids: Option<Vec<u64>>,
+    role_ids: Vec<u64>,
+}
+
+impl Viewer {
+    pub fn load(auth: &AuthContext, conn: &mut MysqlConnection) -> Result<Self, AppError> {
+        use crate::auth::get_user_building_ids;
+        use crate::schema::apartment_owners::dsl as ao;
+        use crate::schema::apartment_renters::dsl as ar;
+        use crate::schema::roles::dsl as rl;
+
+        let user_id = auth.user_id()?;
+        let is_admin = auth.has_any_role(&["Admin"]);
+        let is_manager = auth.has_any_role(&["Admin", "Manager"]);
+        let building_ids = get_user_building_ids(user_id, is_admin, conn)?;
+
+        let (apartment_ids, role_ids) = if is_manager {
+            (None, Vec::new())
+        } else {
+            let mut apartments: Vec<u64> = ao::apartment_owners
+                .filter(ao::user_id.eq(user_id))
+                .select(ao::apartment_id)
+                .load(conn)?;
+            apartments.extend(
+                ar::apartment_renters
+                    .filter(ar::user_id.eq(user_id))
+                    .filter(ar::is_active.eq(true))
+                    .select(ar::apartment_id)
+                    .load::<u64>(conn)?,
+            );
+            let roles: Vec<u64> = rl::roles
+                .filter(rl::name.eq_any(&auth.claims.roles))
+                .select(rl::id)
+                .load(conn)?;
+            (Some(apartments), roles)
+        };
+
+        Ok(Self {
+            is_manager,
+            building_ids,
+            apartment_ids,
+            role_ids,
+        })
+    }
+
+    /// Restrict a query to announcements addressed to this viewer.
+    ///
+    /// Building and apartment targets apply to every announcement; role targets only
+    /// to private ones, since public announcements are readable by anyone.
+    pub fn filter<'a>(&'a self, mut query: BoxedAnnouncements<'a>) -> BoxedAnnouncements<'a> {
+        use announcements::dsl as a;
+        use announcements_audience::dsl as au;
+
+        if let Some(ids) = &self.building_ids {
+            let targeted = au::announcements_audience
+                .filter(au::building_id.is_not_null())
+                .select(au::announcement_id);
+            let matching = au::announcements_audience
+                .filter(au::building_id.eq_any(ids))
+                .select(au::announcement_id);
+            query = query.filter(a::id.ne_all(targeted).or(a::id.eq_any(matching)));
+        }
+        if let Some(ids) = &self.apartment_ids {
+            let targeted = au::announcements_audience
+                .filter(au::apartment_id.is_not_null())
+                .select(au::announcement_id);
+            let matching = au::announcements_audience
+                .filter(au::apartment_id.eq_any(ids))
+                .select(au::announcement_id);
+            query = query.filter(a::id.ne_all(targeted).or(a::id.eq_any(matching)));
+        }
+        if !self.is_manager {
+            let targeted = au::announcements_audience
+                .filter(au::role_id.is_not_null())
+                .select(au::announcement_id);
+            let matching = au::announcements_audience
+                .filter(au::role_id.eq_any(&self.role_ids))
+                .select(au::announcement_id);
+            query = query.filter(
+                a::public
+                    .eq(true)
+                    .or(a::id.ne_all(targeted))
+                    .or(a::id.eq_any(matching)),
+            );
+        }
+        query
+    }
+
+    /// Whether a single announcement is open to this viewer, ignoring publish/expiry.
+    /// Public announcements are always accessible.
+    pub fn can_access(
+        &self,
+        ann: &Announcement,
+        conn: &mut MysqlConnection,
+    ) -> Result<bool, AppError> {
+        use announcements::dsl as a;
+        if ann.public {
+            return Ok(true);
+        }
+        let matches = self
+            .filter(a::announcements.filter(a::id.eq(ann.id)).into_boxed())
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(matches > 0)
+    }
+}
+
+/// Load the resolved audience of each announcement.
+pub(super) fn load_audiences(
+    ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<HashMap<u64, Audience>, AppError> {
+    use crate::schema::apartments::dsl as ap;
+    use crate::schema::buildings::dsl as b;
+    use crate::schema::roles::dsl as rl;
+    use announcements_audience::dsl as au;
+
+    let mut out: HashMap<u64, Audience> = HashMap::new();
+    if ids.is_empty() {
+        return Ok(out);
+    }
+
+    let roles: Vec<(u64, String)> = au::announcements_audience
+        .inner_join(rl::roles)
+        .filter(au::announcement_id.eq_any(ids))
+        .order(rl::name.asc())
+        .select((au::announcement_id, rl::name))
+        .load(conn)?;
+    for (id, name) in roles {
+        out.entry(id).or_default().roles.push(name);
+    }
+
+    let buildings: Vec<(u64, u64, String)> = au::announcements_audience
+        .inner_join(b::buildings)
+        .filter(au::announcement_id.eq_any(ids))
+        .order(b::address.asc())
+        .select((au::announcement_id, b::id, b::address))
+        .load(conn)?;
+    for (id, building_id, address) in buildings {
+        out.entry(id).or_default().buildings.push(AudienceBuilding {
+            id: building_id,
+            address,
+        });
+    }
+
+    let apartments: Vec<(u64, u64, u64, String)> = au::announcements_audience
+        .inner_join(ap::apartments)
+        .filter(au::announcement_id.eq_any(ids))
+        .order(ap::number.asc())
+        .select((au::announcement_id, ap::id, ap::building_id, ap::number))
+        .load(conn)?;
+    for (id, apartment_id, building_id, number) in apartments {
+        out.entry(id)
+            .or_default()
+            .apartments
+            .push(AudienceApartment {
+                id: apartment_id,
+                building_id,
+                number,
+            });
+    }
+
+    Ok(out)
+}
+
+/// Check requested targets and resolve role names to ids.
+///
+/// Called before the announcement is written, so a missing building or apartment
+/// leaves it untouched. Role names that do not exist yet are created.
+pub(super) fn resolve_audience(
+    update: &AudienceUpdate,
+    conn: &mut MysqlConnection,
+) -> Result<ResolvedAudience, AppError> {
+    use crate::schema::apartments::dsl as ap;
+    use crate::schema::buildings::dsl as b;
+
+    let role_ids = match update.roles {
+        Some(names) => {
+            let names: HashSet<&str> = names
+                .iter()
+                .map(|n| n.trim())
+                .filter(|n| !n.is_empty())
+                .collect();
+            // Roles are created lazily, so a role nobody holds yet may still be targeted.
+            let mut ids = Vec::with_capacity(names.len());
+            for name in names {
+                ids.push(ensure_role(name, conn)?);
+            }
+            Some(ids)
+        }
+        None => None,
+    };
+    let building_ids = match update.building_ids {
+        Some(ids) => {
+            let found: Vec<u64> = b::buildings
+                .filter(b::id.eq_any(ids))
+                .filter(b::is_deleted.eq(false))
+                .select(b::id)
+                .load(conn)?;
+            if found.len() != ids.iter().collect::<HashSet<_>>().len() {
+                return Err(AppError::BadRequest("invalid_building".into()));
+            }
+            Some(found)
+        }
+        None => None,
+    };
+    let apartment_ids = match update.apartment_ids {
+        Some(ids) => {
+            let found: Vec<u64> = ap::apartments
+                .filter(ap::id.eq_any(ids))
+                .filter(ap::is_deleted.eq(false))
+                .select(ap::id)
+                .load(conn)?;
+            if found.len() != ids.iter().collect::<HashSet<_>>().len() {
+                return Err(AppError::BadRequest("invalid_apartment".into()));
+            }
+            Some(found)
+        }
+        None => None,
+    };
+
+    Ok(ResolvedAudience {
+        role_ids,
+        building_ids,
+        apartment_ids,
+    })
+}
+
+/// Replace the targets of each kind present in `audience`.
+pub(super) fn save_audience(
+    announcement_id: u64,
+    audience: ResolvedAudience,
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use announcements_audience::dsl as au;
+
+    if let Some(ids) = audience.role_ids {
+        diesel::delete(
+            au::announcements_audience
+                .filter(au::announcement_id.eq(announcement_id))
+                .filter(au::role_id.is_not_null()),
+        )
+        .execute(conn)?;
+        for id in ids {
+            diesel::insert_into(au::announcements_audience)
+                .values((au::announcement_id.eq(announcement_id), au::role_id.eq(id)))
+                .execute(conn)?;
+        }
+    }
+    if let Some(ids) = audience.building_ids {
+        diesel::delete(
+            au::announcements_audience
+                .filter(au::announcement_id.eq(announcement_id))
+                .filter(au::building_id.is_not_null()),
+        )
+        .execute(conn)?;
+        for id in ids {
+            diesel::insert_into(au::announcements_audience)
+                .values((
+                    au::announcement_id.eq(announcement_id),
+                    au::building_id.eq(id),
+                ))
+                .execute(conn)?;
+        }
+    }
+    if let Some(ids) = audience.apartment_ids {
+        diesel::delete(
+            au::announcements_audience
+                .filter(au::announcement_id.eq(announcement_id))
+                .filter(au::apartment_id.is_not_null()),
+        )
+        .execute(conn)?;
+        for id in ids {
+            diesel::insert_into(au::announcements_audience)
+                .values((
+                    au::announcement_id.eq(announcement_id),
+                    au::apartment_id.eq(id),
+                ))
+                .execute(conn)?;
+        }
+    }
+    Ok(())
+}
+
+/// Owners and active renters of live apartments, in the given buildings or in all of
+/// them.
+pub(super) fn residents(
+    building_ids: Option<&[u64]>,
+    conn: &mut MysqlConnection,
+) -> Result<HashSet<u64>, AppError> {
+    use crate::schema::apartment_owners::dsl as ao;
+    use crate::schema::apartment_renters::dsl as ar;
+    use crate::schema::apartments::dsl as ap;
+
+    let mut owners = ao::apartment_owners
+        .inner_join(ap::apartments.on(ap::id.eq(ao::apartment_id)))
+        .filter(ap::is_deleted.eq(false))
+        .select(ao::user_id)
+        .into_boxed();
+    let mut renters = ar::apartment_renters
+        .inner_join(ap::apartments.on(ap::id.eq(ar::apartment_id)))
+        .filter(ar::is_active.eq(true))
+        .filter(ap::is_deleted.eq(false))
+        .select(ar::user_id)
+        .into_boxed();
+    if let Some(ids) = building_ids {
+        owners = owners.filter(ap::building_id.eq_any(ids));
+        renters = renters.filter(ap::building_id.eq_any(ids));
+    }
+    let mut ids: HashSet<u64> = owners.load::<u64>(conn)?.into_iter().collect();
+    ids.extend(renters.load::<u64>(conn)?);
+    Ok(ids)
+}
+
+/// Users an announcement is addressed to.
+///
+/// See [`audiences`].
+pub(super) fn audience_user_ids(
+    announcement_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<u64>, AppError> {
+    Ok(audiences(&[announcement_id], conn)?
+        .remove(&announcement_id)
+        .unwrap_or_default())
+}
+
+/// Users each of the given announcements is addressed to, sorted.
+///
+/// Apartment targets select those apartments' owners and active renters, and building
+/// targets the residents of the buildings' apartments. Role targets restrict the result
+/// to users holding one of the roles; on their own they select every holder. Without any
+/// target the audience is all residents, so staff are never counted as readers.
+pub(super) fn audiences(
+    announcement_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<HashMap<u64, Vec<u64>>, AppError> {
+    use crate::schema::apartment_owners::dsl as ao;
+    use crate::schema::apartment_renters::dsl as ar;
+    use crate::schema::apartments::dsl as ap;
+    use crate::schema::user_roles::dsl as ur;
+    use announcements_audience::dsl as au;
+
+    #[derive(Default)]
+    struct Targets {
+        roles: Vec<u64>,
+        buildings: Vec<u64>,
+        apartments: Vec<u64>,
+    }
+    /// Announcement, role, building and apartment of an audience row.
+    type Row = (u64, Option<u64>, Option<u64>, Option<u64>);
+
+    let rows: Vec<Row> = au::announcements_audience
+        .filter(au::announcement_id.eq_any(announcement_ids))
+        .select((
+            au::announcement_id,
+            au::role_id,
+            au::building_id,
+            au::apartment_id,
+        ))
+        .load(conn)?;
+    let mut targets: HashMap<u64, Targets> = HashMap::new();
+    for (announcement_id, role_id, building_id, apartment_id) in rows {
+        let t = targets.entry(announcement_id).or_default();
+        t.roles.extend(role_id);
+        t.buildings.extend(building_id);
+        t.apartments.extend(apartment_id);
+    }
+    let all_roles: Vec<u64> = targets.values().flat_map(|t| t.roles.clone()).collect();
+    let all_buildings: Vec<u64> = targets.values().flat_map(|t| t.buildings.clone()).collect();
+    let all_apartments: Vec<u64> = targets
+        .values()
+        .flat_map(|t| t.apartments.clone())
+        .collect();
+
+    // Live apartments of every targeted building or apartment, with their residents
+    let apartments: Vec<(u64, u64)> = if all_buildings.is_empty() && all_apartments.is_empty() {
+        Vec::new()
+    } else {
+        ap::apartments
+            .filter(ap::is_deleted.eq(false))
+            .filter(
+                ap::id
+                    .eq_any(&all_apartments)
+                    .or(ap::building_id.eq_any(&all_buildings)),
+            )
+            .select((ap::id, ap::building_id))
+            .load(conn)?
+    };
+    let apartment_ids: Vec<u64> = apartments.iter().map(|a| a.0).collect();
+    let mut occupants: HashMap<u64, Vec<u64>> = HashMap::new();
+    if !apartment_ids.is_empty() {
+        let mut pairs: Vec<(u64, u64)> = ao::apartment_owners
+            .filter(ao::apartment_id.eq_any(&apartment_ids))
+            .select((ao::apartment_id, ao::user_id))
+            .load(conn)?;
+        pairs.extend(
+            ar::apartment_renters
+                .filter(ar::apartment_id.eq_any(&apartment_ids))
+                .filter(ar::is_active.eq(true))
+                .select((ar::apartment_id, ar::user_id))
+                .load::<(u64, u64)>(conn)?,
+        );
+        for (apartment_id, user_id) in pairs {
+            occupants.entry(apartment_id).or_default().push(user_id);
+        }
+    }
+
+    let mut holders: HashMap<u64, HashSet<u64>> = HashMap::new();
+    if !all_roles.is_empty() {
+        let pairs: Vec<(u64, u64)> = ur::user_roles
+            .filter(ur::role_id.eq_any(&all_roles))
+            .select((ur::role_id, ur::user_id))
+            .load(conn)?;
+        for (role_id, user_id) in pairs {
+            holders.entry(role_id).or_default().insert(user_id);
+        }
+    }
+
+    let untargeted = announcement_ids.iter().any(|id| {
+        targets
+            .get(id)
+            .is_none_or(|t| t.buildings.is_empty() && t.apartments.is_empty() && t.roles.is_empty())
+    });
+    let all_residents: Vec<u64> = if untargeted {
+        residents(None, conn)?.into_iter().collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut out = HashMap::with_capacity(announcement_ids.len());
+    for id in announcement_ids {
+        let t = targets.remove(id).unwrap_or_default();
+        let with_role: HashSet<u64> = t
+            .roles
+            .iter()
+            .filter_map(|r| holders.get(r))
+            .flatten()
+            .copied()
+            .collect();
+        let mut ids: Vec<u64> = if !t.buildings.is_empty() || !t.apartments.is_empty() {
+            apartments
+                .iter()
+                .filter(|(apt_id, building_id)| {
+                    (t.apartments.is_empty() || t.apartments.contains(apt_id))
+                        && (t.buildings.is_empty() || t.buildings.contains(building_id))
+                })
+                .filter_map(|(apt_id, _)| occupants.get(apt_id))
+                .flatten()
+                .copied()
+                .collect()
+        } else if !t.roles.is_empty() {
+            with_role.iter().copied().collect()
+        } else {
+            all_residents.clone()
+        };
+        if !t.roles.is_empty() {
+            ids.retain(|user_id| with_role.contains(user_id));
+        }
+        ids.sort_unstable();
+        ids.dedup();
+        out.insert(*id, ids);
+    }
+    Ok(out)
+}
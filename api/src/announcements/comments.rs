@@ -1,18 +1,241 @@
+use super::audience::Viewer;
 use super::helpers::{conn, render_markdown};
-use super::types::{CommentOut, CommentsQuery, CreateCommentRequest};
+use super::mentions::{
+    Mention, emphasize_mentions, find_mentions, load_mentions, mentionable_users, save_mentions,
+};
+use super::types::{
+    CommentOut, CommentsQuery, CreateCommentRequest, MentionOut, ModerationReasonRequest,
+    ReactionRequest, ReactionSummary, ReportOut, ReportedCommentOut, ReportsQuery,
+    UpdateCommentRequest,
+};
 use crate::auth::{error::AppError, extractor::AuthContext};
 use crate::db::DbPool;
-use crate::models::{Announcement, AnnouncementComment, NewAnnouncementComment};
-use crate::schema::{announcements, announcements_comments};
+use crate::models::{
+    Announcement, AnnouncementComment, AnnouncementCommentReport, NewAnnouncementComment,
+};
+use crate::schema::{
+    announcements, announcements_comments, announcements_comments_reactions,
+    announcements_comments_reports,
+};
 use actix_web::{HttpResponse, web};
 use chrono::Utc;
+use diesel::mysql::MysqlConnection;
 use diesel::prelude::*;
 use std::collections::HashMap;
 use utoipa;
 
+/// Reactions a comment can receive, in display order.
+pub const REACTIONS: [&str; 6] = ["👍", "❤️", "😂", "😮", "😢", "🎉"];
+
+const REPORT_OPEN: &str = "open";
+const REPORT_DISMISSED: &str = "dismissed";
+const REPORT_ACTIONED: &str = "actioned";
+
+/// Load an announcement whose comments the caller may read and write.
+///
+/// Public announcements are open to anyone once published; private ones require
+/// authentication and must be addressed to the user.
+fn open_thread(
+    announcement_id: u64,
+    auth_opt: Option<&AuthContext>,
+    c: &mut MysqlConnection,
+) -> Result<Announcement, AppError> {
+    use announcements::dsl as a;
+    let ann = a::announcements
+        .filter(a::id.eq(announcement_id))
+        .first::<Announcement>(c)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+    if ann.is_deleted {
+        return Err(AppError::NotFound);
+    }
+    if !ann.comments_enabled {
+        return Err(AppError::CommentsDisabled);
+    }
+    let now = Utc::now().naive_utc();
+    let is_manager = auth_opt.is_some_and(|a| a.has_any_role(&["Admin", "Manager"]));
+    if !ann.public {
+        let auth = auth_opt.ok_or(AppError::Unauthorized)?;
+        if !Viewer::load(auth, c)?.can_access(&ann, c)? {
+            return Err(AppError::Forbidden);
+        }
+    } else if !is_manager {
+        if ann.publish_at.map(|p| p > now).unwrap_or(true) {
+            return Err(AppError::NotPublished);
+        }
+        if ann.expire_at.map(|e| e <= now).unwrap_or(false) {
+            return Err(AppError::Expired);
+        }
+    }
+    Ok(ann)
+}
+
+fn load_comment(comment_id: u64, c: &mut MysqlConnection) -> Result<AnnouncementComment, AppError> {
+    use announcements_comments::dsl as cmt;
+    cmt::announcements_comments
+        .filter(cmt::id.eq(comment_id))
+        .first::<AnnouncementComment>(c)
+        .optional()?
+        .ok_or(AppError::NotFound)
+}
+
+/// Load a live comment on an announcement the caller can see.
+fn load_open_comment(
+    comment_id: u64,
+    auth: &AuthContext,
+    c: &mut MysqlConnection,
+) -> Result<AnnouncementComment, AppError> {
+    let comment = load_comment(comment_id, c)?;
+    if comment.is_deleted {
+        return Err(AppError::NotFound);
+    }
+    open_thread(comment.announcement_id, Some(auth), c)?;
+    Ok(comment)
+}
+
+/// Render a comment body, emphasising resolved mentions, and return the mentions to store.
+fn render_comment(
+    announcement_id: u64,
+    body_md: &str,
+    auth: &AuthContext,
+    c: &mut MysqlConnection,
+) -> Result<(String, Vec<Mention>), AppError> {
+    let mentions = if body_md.contains('@') {
+        find_mentions(body_md, &mentionable_users(announcement_id, auth, c)?)
+    } else {
+        Vec::new()
+    };
+    let html = render_markdown(&emphasize_mentions(body_md, &mentions));
+    Ok((html, mentions))
+}
+
+/// Reaction counts per comment, in [`REACTIONS`] order.
+fn load_reactions(
+    comment_ids: &[u64],
+    user_id: Option<u64>,
+    c: &mut MysqlConnection,
+) -> Result<HashMap<u64, Vec<ReactionSummary>>, AppError> {
+    use announcements_comments_reactions::dsl as r;
+    let mut out: HashMap<u64, Vec<ReactionSummary>> = HashMap::new();
+    if comment_ids.is_empty() {
+        return Ok(out);
+    }
+    let rows: Vec<(u64, u64, String)> = r::announcements_comments_reactions
+        .filter(r::comment_id.eq_any(comment_ids))
+        .select((r::comment_id, r::user_id, r::emoji))
+        .load(c)?;
+    for (comment_id, reactor, emoji) in rows {
+        let list = out.entry(comment_id).or_default();
+        let mine = Some(reactor) == user_id;
+        match list.iter_mut().find(|s| s.emoji == emoji) {
+            Some(summary) => {
+                summary.count += 1;
+                summary.reacted |= mine;
+            }
+            None => list.push(ReactionSummary {
+                emoji,
+                count: 1,
+                reacted: mine,
+            }),
+        }
+    }
+    let rank = |e: &str| {
+        REACTIONS
+            .iter()
+            .position(|r| *r == e)
+            .unwrap_or(REACTIONS.len())
+    };
+    for list in out.values_mut() {
+        list.sort_by_key(|s| rank(&s.emoji));
+    }
+    Ok(out)
+}
+
+/// Build the API view of comments: author names, reactions and mentions. Bodies of hidden
+/// comments are only returned to managers and to their author.
+fn comment_outs(
+    list: Vec<AnnouncementComment>,
+    auth_opt: Option<&AuthContext>,
+    c: &mut MysqlConnection,
+) -> Result<Vec<CommentOut>, AppError> {
+    use crate::schema::users::dsl as u;
+    let viewer_id = auth_opt.and_then(|a| a.user_id().ok());
+    let is_manager = auth_opt.is_some_and(|a| a.has_any_role(&["Admin", "Manager"]));
+    let user_ids: Vec<u64> = list.iter().map(|c| c.user_id).collect();
+    let comment_ids: Vec<u64> = list.iter().map(|c| c.id).collect();
+    let users = if user_ids.is_empty() {
+        vec![]
+    } else {
+        u::users
+            .filter(u::id.eq_any(&user_ids))
+            .load::<crate::models::User>(c)?
+    };
+    let mut user_map: HashMap<u64, String> = HashMap::new();
+    for usr in users {
+        user_map.insert(usr.id, usr.name);
+    }
+    let mut reactions = load_reactions(&comment_ids, viewer_id, c)?;
+    let mut mentions = load_mentions(&comment_ids, c)?;
+    Ok(list
+        .into_iter()
+        .map(|c| {
+            let show_body = !c.is_hidden || is_manager || viewer_id == Some(c.user_id);
+            CommentOut {
+                id: c.id,
+                announcement_id: c.announcement_id,
+                parent_id: c.parent_id,
+                user_id: c.user_id,
+                user_name: user_map
+                    .get(&c.user_id)
+                    .cloned()
+                    .unwrap_or_else(|| "Unknown".into()),
+                body_md: if show_body { c.body_md } else { String::new() },
+                body_html: if show_body {
+                    c.body_html
+                } else {
+                    String::new()
+                },
+                is_deleted: c.is_deleted,
+                is_hidden: c.is_hidden,
+                hidden_reason: c.hidden_reason,
+                created_at: c.created_at,
+                edited_at: c.edited_at,
+                reactions: reactions.remove(&c.id).unwrap_or_default(),
+                mentions: if show_body {
+                    mentions.remove(&c.id).unwrap_or_default()
+                } else {
+                    Vec::new()
+                },
+            }
+        })
+        .collect())
+}
+
+fn comment_out(
+    comment: AnnouncementComment,
+    auth: &AuthContext,
+    c: &mut MysqlConnection,
+) -> Result<CommentOut, AppError> {
+    comment_outs(vec![comment], Some(auth), c)?
+        .pop()
+        .ok_or_else(|| AppError::Internal("comment_out".into()))
+}
+
+fn moderation_reason(body: &ModerationReasonRequest, max: usize) -> Result<String, AppError> {
+    let reason = body.reason.trim();
+    if reason.is_empty() {
+        return Err(AppError::BadRequest("reason_required".into()));
+    }
+    if reason.chars().count() > max {
+        return Err(AppError::BadRequest("reason_too_long".into()));
+    }
+    Ok(reason.to_string())
+}
+
 /// List comments on an announcement
 ///
-/// Returns all comments on an announcement. For public announcements, no authentication required.
+/// Returns all comments on an announcement, oldest first. Replies carry the `parent_id` of
+/// the comment they answer. For public announcements, no authentication required.
 /// For private announcements, requires authentication and appropriate role.
 /// Admin/Manager can use include_deleted=true query param to view deleted comments.
 #[utoipa::path(
@@ -37,50 +260,13 @@ pub async fn list_comments(
     path: web::Path<u64>,
     q: web::Query<CommentsQuery>,
 ) -> Result<HttpResponse, AppError> {
-    use crate::schema::users::dsl as u;
-    use announcements::dsl as a;
     use announcements_comments::dsl as cmt;
     let announcement_id = path.into_inner();
     let mut c = conn(&pool)?;
-    let ann = a::announcements
-        .filter(a::id.eq(announcement_id))
-        .first::<Announcement>(&mut c)?;
-    if ann.is_deleted {
-        return Err(AppError::NotFound);
-    }
-    if !ann.comments_enabled {
-        return Err(AppError::CommentsDisabled);
-    }
-    let now = Utc::now().naive_utc();
+    open_thread(announcement_id, auth_opt.as_ref(), &mut c)?;
     let is_manager = auth_opt
         .as_ref()
-        .map(|a| a.has_any_role(&["Admin", "Manager"]).then_some(()))
-        .is_some();
-    if !ann.public {
-        let auth = auth_opt.as_ref().ok_or(AppError::Unauthorized)?;
-        if !is_manager && let Some(csv) = &ann.roles_csv {
-            let needed: Vec<&str> = csv
-                .split(',')
-                .map(|s| s.trim())
-                .filter(|s| !s.is_empty())
-                .collect();
-            if !auth
-                .claims
-                .roles
-                .iter()
-                .any(|r| needed.iter().any(|n| r == n))
-            {
-                return Err(AppError::Forbidden);
-            }
-        }
-    } else if !is_manager {
-        if ann.publish_at.map(|p| p > now).unwrap_or(true) {
-            return Err(AppError::NotPublished);
-        }
-        if ann.expire_at.map(|e| e <= now).unwrap_or(false) {
-            return Err(AppError::Expired);
-        }
-    }
+        .is_some_and(|a| a.has_any_role(&["Admin", "Manager"]));
     let include_deleted = q.include_deleted.unwrap_or(false) && is_manager;
     let mut query = cmt::announcements_comments
         .filter(cmt::announcement_id.eq(announcement_id))
@@ -90,41 +276,46 @@ pub async fn list_comments(
     }
     let list = query
         .order(cmt::created_at.asc())
+        .then_order_by(cmt::id.asc())
         .load::<AnnouncementComment>(&mut c)?;
-    let user_ids: Vec<u64> = list.iter().map(|c| c.user_id).collect();
-    let users = if user_ids.is_empty() {
-        vec![]
-    } else {
-        u::users
-            .filter(u::id.eq_any(&user_ids))
-            .load::<crate::models::User>(&mut c)?
-    };
-    let mut user_map: HashMap<u64, String> = HashMap::new();
-    for usr in users {
-        user_map.insert(usr.id, usr.name);
-    }
-    let out: Vec<CommentOut> = list
-        .into_iter()
-        .map(|c| CommentOut {
-            id: c.id,
-            announcement_id: c.announcement_id,
-            user_id: c.user_id,
-            user_name: user_map
-                .get(&c.user_id)
-                .cloned()
-                .unwrap_or_else(|| "Unknown".into()),
-            body_md: c.body_md,
-            body_html: c.body_html,
-            is_deleted: c.is_deleted,
-            created_at: c.created_at,
-        })
-        .collect();
-    Ok(HttpResponse::Ok().json(out))
+    Ok(HttpResponse::Ok().json(comment_outs(list, auth_opt.as_ref(), &mut c)?))
+}
+
+/// List users that can be mentioned
+///
+/// Returns the users a comment on this announcement can @mention: owners, active renters
+/// and managers of the buildings it is for (or of the caller's buildings when it is not
+/// tied to a building). Mention a user by writing `@` followed by their full name.
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/{id}/comments/mentionable",
+    params(
+        ("id" = u64, Path, description = "Announcement ID")
+    ),
+    responses(
+        (status = 200, description = "Users that can be mentioned", body = Vec<MentionOut>),
+        (status = 403, description = "Forbidden - comments disabled or announcement not addressed to the user"),
+        (status = 404, description = "Announcement not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_mentionable(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, AppError> {
+    let announcement_id = path.into_inner();
+    let mut c = conn(&pool)?;
+    open_thread(announcement_id, Some(&auth), &mut c)?;
+    Ok(HttpResponse::Ok().json(mentionable_users(announcement_id, &auth, &mut c)?))
 }
 
 /// Create a comment on an announcement
 ///
-/// Adds a comment to an announcement. Markdown content is automatically rendered to HTML.
+/// Adds a comment, or a reply when `parent_id` is given. Markdown content is automatically
+/// rendered to HTML and `@Name` mentions of users in the building are resolved.
 /// Requires authentication and appropriate permissions for private announcements.
 #[utoipa::path(
     post,
@@ -134,7 +325,8 @@ pub async fn list_comments(
     ),
     request_body = CreateCommentRequest,
     responses(
-        (status = 201, description = "Comment created successfully", body = AnnouncementComment),
+        (status = 201, description = "Comment created successfully", body = CommentOut),
+        (status = 400, description = "Bad request - parent comment not on this announcement"),
         (status = 403, description = "Forbidden - comments disabled or insufficient permissions"),
         (status = 404, description = "Announcement not found"),
         (status = 500, description = "Internal server error")
@@ -161,25 +353,16 @@ pub async fn create_comment(
     if ann.is_deleted {
         return Err(AppError::NotFound);
     }
-    if !ann.public
-        && !auth.has_any_role(&["Admin", "Manager"])
-        && let Some(csv) = &ann.roles_csv
-    {
-        let needed: Vec<&str> = csv
-            .split(',')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .collect();
-        if !auth
-            .claims
-            .roles
-            .iter()
-            .any(|r| needed.iter().any(|n| r == n))
-        {
-            return Err(AppError::Forbidden);
+    if !Viewer::load(&auth, &mut c)?.can_access(&ann, &mut c)? {
+        return Err(AppError::Forbidden);
+    }
+    if let Some(parent_id) = body.parent_id {
+        let parent = load_comment(parent_id, &mut c)?;
+        if parent.announcement_id != announcement_id || parent.is_deleted {
+            return Err(AppError::BadRequest("invalid_parent".into()));
         }
     }
-    let html = render_markdown(&body.body_md);
+    let (html, mentions) = render_comment(announcement_id, &body.body_md, &auth, &mut c)?;
     let new = NewAnnouncementComment {
         announcement_id,
         user_id: auth
@@ -189,6 +372,7 @@ pub async fn create_comment(
             .map_err(|_| AppError::BadRequest("invalid_sub".into()))?,
         body_md: body.body_md.clone(),
         body_html: html,
+        parent_id: body.parent_id,
     };
     diesel::insert_into(cmt::announcements_comments)
         .values(&new)
@@ -196,7 +380,401 @@ pub async fn create_comment(
     let inserted = cmt::announcements_comments
         .order(cmt::id.desc())
         .first::<AnnouncementComment>(&mut c)?;
-    Ok(HttpResponse::Created().json(inserted))
+    save_mentions(inserted.id, &mentions, &mut c)?;
+    Ok(HttpResponse::Created().json(comment_out(inserted, &auth, &mut c)?))
+}
+
+/// Edit a comment
+///
+/// Replaces the text of the caller's own comment and marks it as edited. Mentions are
+/// resolved again. Hidden comments cannot be edited.
+#[utoipa::path(
+    put,
+    path = "/api/v1/announcements/comments/{comment_id}",
+    params(
+        ("comment_id" = u64, Path, description = "Comment ID")
+    ),
+    request_body = UpdateCommentRequest,
+    responses(
+        (status = 200, description = "Updated comment", body = CommentOut),
+        (status = 400, description = "Bad request - empty body or comment hidden by a moderator"),
+        (status = 403, description = "Forbidden - only the author can edit a comment"),
+        (status = 404, description = "Comment not found or deleted"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_comment(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+    body: web::Json<UpdateCommentRequest>,
+) -> Result<HttpResponse, AppError> {
+    use announcements_comments::dsl as cmt;
+    let comment_id = path.into_inner();
+    let mut c = conn(&pool)?;
+    let comment = load_open_comment(comment_id, &auth, &mut c)?;
+    if comment.user_id != auth.user_id()? {
+        return Err(AppError::Forbidden);
+    }
+    if comment.is_hidden {
+        return Err(AppError::BadRequest("comment_hidden".into()));
+    }
+    if body.body_md.trim().is_empty() {
+        return Err(AppError::BadRequest("empty_body".into()));
+    }
+    if body.body_md != comment.body_md {
+        let (html, mentions) =
+            render_comment(comment.announcement_id, &body.body_md, &auth, &mut c)?;
+        diesel::update(cmt::announcements_comments.filter(cmt::id.eq(comment_id)))
+            .set((
+                cmt::body_md.eq(&body.body_md),
+                cmt::body_html.eq(html),
+                cmt::edited_at.eq(Some(Utc::now().naive_utc())),
+            ))
+            .execute(&mut c)?;
+        save_mentions(comment_id, &mentions, &mut c)?;
+    }
+    let updated = load_comment(comment_id, &mut c)?;
+    Ok(HttpResponse::Ok().json(comment_out(updated, &auth, &mut c)?))
+}
+
+/// Toggle a reaction on a comment
+///
+/// Adds the emoji reaction from the current user, or removes it if it was already given.
+/// Returns the comment's updated reaction counts.
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/comments/{comment_id}/reactions",
+    params(
+        ("comment_id" = u64, Path, description = "Comment ID")
+    ),
+    request_body = ReactionRequest,
+    responses(
+        (status = 200, description = "Reaction counts of the comment", body = Vec<ReactionSummary>),
+        (status = 400, description = "Bad request - unsupported emoji"),
+        (status = 403, description = "Forbidden - announcement not addressed to the user"),
+        (status = 404, description = "Comment not found or deleted"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn toggle_reaction(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+    body: web::Json<ReactionRequest>,
+) -> Result<HttpResponse, AppError> {
+    use announcements_comments_reactions::dsl as r;
+    let comment_id = path.into_inner();
+    let user_id = auth.user_id()?;
+    if !REACTIONS.contains(&body.emoji.as_str()) {
+        return Err(AppError::BadRequest("unsupported_reaction".into()));
+    }
+    let mut c = conn(&pool)?;
+    load_open_comment(comment_id, &auth, &mut c)?;
+
+    let mine = r::announcements_comments_reactions
+        .filter(r::comment_id.eq(comment_id))
+        .filter(r::user_id.eq(user_id))
+        .filter(r::emoji.eq(&body.emoji));
+    if diesel::delete(mine).execute(&mut c)? == 0 {
+        diesel::insert_or_ignore_into(r::announcements_comments_reactions)
+            .values((
+                r::comment_id.eq(comment_id),
+                r::user_id.eq(user_id),
+                r::emoji.eq(&body.emoji),
+            ))
+            .execute(&mut c)?;
+    }
+    let summary = load_reactions(&[comment_id], Some(user_id), &mut c)?
+        .remove(&comment_id)
+        .unwrap_or_default();
+    Ok(HttpResponse::Ok().json(summary))
+}
+
+/// Report a comment
+///
+/// Flags a comment for moderation with a short reason. Each user can report a comment
+/// once; repeated reports return the existing one.
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/comments/{comment_id}/report",
+    params(
+        ("comment_id" = u64, Path, description = "Comment ID")
+    ),
+    request_body = ModerationReasonRequest,
+    responses(
+        (status = 201, description = "Report recorded", body = AnnouncementCommentReport),
+        (status = 400, description = "Bad request - missing reason or own comment"),
+        (status = 403, description = "Forbidden - announcement not addressed to the user"),
+        (status = 404, description = "Comment not found or deleted"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn report_comment(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+    body: web::Json<ModerationReasonRequest>,
+) -> Result<HttpResponse, AppError> {
+    use announcements_comments_reports::dsl as rp;
+    let comment_id = path.into_inner();
+    let user_id = auth.user_id()?;
+    let reason = moderation_reason(&body, 500)?;
+    let mut c = conn(&pool)?;
+    let comment = load_open_comment(comment_id, &auth, &mut c)?;
+    if comment.user_id == user_id {
+        return Err(AppError::BadRequest("own_comment".into()));
+    }
+    diesel::insert_or_ignore_into(rp::announcements_comments_reports)
+        .values((
+            rp::comment_id.eq(comment_id),
+            rp::reporter_id.eq(user_id),
+            rp::reason.eq(reason),
+        ))
+        .execute(&mut c)?;
+    let report = rp::announcements_comments_reports
+        .filter(rp::comment_id.eq(comment_id))
+        .filter(rp::reporter_id.eq(user_id))
+        .select(AnnouncementCommentReport::as_select())
+        .first(&mut c)?;
+    Ok(HttpResponse::Created().json(report))
+}
+
+/// Moderation queue
+///
+/// Lists reported comments with their reports, most recently reported first. Defaults to
+/// open reports; use `status=dismissed` or `status=actioned` for the history.
+/// Requires Admin or Manager role.
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/comments/reports",
+    params(ReportsQuery),
+    responses(
+        (status = 200, description = "Reported comments", body = Vec<ReportedCommentOut>),
+        (status = 400, description = "Bad request - unknown status"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_reports(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    q: web::Query<ReportsQuery>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::users::dsl as u;
+    use announcements::dsl as a;
+    use announcements_comments::dsl as cmt;
+    use announcements_comments_reports::dsl as rp;
+    auth.require_roles(&["Admin", "Manager"])?;
+    let status = q.status.as_deref().unwrap_or(REPORT_OPEN);
+    if ![REPORT_OPEN, REPORT_DISMISSED, REPORT_ACTIONED].contains(&status) {
+        return Err(AppError::BadRequest("invalid_status".into()));
+    }
+    let mut c = conn(&pool)?;
+
+    let reports: Vec<(AnnouncementCommentReport, String)> = rp::announcements_comments_reports
+        .inner_join(u::users.on(u::id.eq(rp::reporter_id)))
+        .filter(rp::status.eq(status))
+        .order(rp::created_at.desc())
+        .select((AnnouncementCommentReport::as_select(), u::name))
+        .load(&mut c)?;
+    let mut comment_ids: Vec<u64> = Vec::new();
+    let mut by_comment: HashMap<u64, Vec<ReportOut>> = HashMap::new();
+    for (report, reporter_name) in reports {
+        if !by_comment.contains_key(&report.comment_id) {
+            comment_ids.push(report.comment_id);
+        }
+        by_comment
+            .entry(report.comment_id)
+            .or_default()
+            .push(ReportOut {
+                id: report.id,
+                reporter_id: report.reporter_id,
+                reporter_name,
+                reason: report.reason,
+                status: report.status,
+                created_at: report.created_at,
+            });
+    }
+
+    let comments: Vec<AnnouncementComment> = cmt::announcements_comments
+        .filter(cmt::id.eq_any(&comment_ids))
+        .load(&mut c)?;
+    let ann_ids: Vec<u64> = comments.iter().map(|cm| cm.announcement_id).collect();
+    let titles: HashMap<u64, String> = a::announcements
+        .filter(a::id.eq_any(&ann_ids))
+        .select((a::id, a::title))
+        .load::<(u64, String)>(&mut c)?
+        .into_iter()
+        .collect();
+    let mut outs: HashMap<u64, CommentOut> = comment_outs(comments, Some(&auth), &mut c)?
+        .into_iter()
+        .map(|o| (o.id, o))
+        .collect();
+
+    let queue: Vec<ReportedCommentOut> = comment_ids
+        .into_iter()
+        .filter_map(|id| {
+            let comment = outs.remove(&id)?;
+            Some(ReportedCommentOut {
+                announcement_title: titles
+                    .get(&comment.announcement_id)
+                    .cloned()
+                    .unwrap_or_default(),
+                reports: by_comment.remove(&id).unwrap_or_default(),
+                comment,
+            })
+        })
+        .collect();
+    Ok(HttpResponse::Ok().json(queue))
+}
+
+/// Hide a comment
+///
+/// Hides a comment from residents with a reason that stays visible in its place, and
+/// resolves its open reports. Requires Admin or Manager role.
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/comments/{comment_id}/hide",
+    params(
+        ("comment_id" = u64, Path, description = "Comment ID")
+    ),
+    request_body = ModerationReasonRequest,
+    responses(
+        (status = 200, description = "Comment hidden", body = AnnouncementComment),
+        (status = 400, description = "Bad request - missing reason"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Comment not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn hide_comment(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+    body: web::Json<ModerationReasonRequest>,
+) -> Result<HttpResponse, AppError> {
+    use announcements_comments::dsl as cmt;
+    use announcements_comments_reports::dsl as rp;
+    auth.require_roles(&["Admin", "Manager"])?;
+    let comment_id = path.into_inner();
+    let user_id = auth.user_id()?;
+    let reason = moderation_reason(&body, 255)?;
+    let mut c = conn(&pool)?;
+    load_comment(comment_id, &mut c)?;
+    let now = Utc::now().naive_utc();
+    diesel::update(cmt::announcements_comments.filter(cmt::id.eq(comment_id)))
+        .set((
+            cmt::is_hidden.eq(true),
+            cmt::hidden_reason.eq(Some(reason)),
+            cmt::hidden_by.eq(Some(user_id)),
+            cmt::hidden_at.eq(Some(now)),
+        ))
+        .execute(&mut c)?;
+    diesel::update(
+        rp::announcements_comments_reports
+            .filter(rp::comment_id.eq(comment_id))
+            .filter(rp::status.eq(REPORT_OPEN)),
+    )
+    .set((
+        rp::status.eq(REPORT_ACTIONED),
+        rp::resolved_by.eq(Some(user_id)),
+        rp::resolved_at.eq(Some(now)),
+    ))
+    .execute(&mut c)?;
+    Ok(HttpResponse::Ok().json(load_comment(comment_id, &mut c)?))
+}
+
+/// Unhide a comment
+///
+/// Makes a hidden comment visible again. Requires Admin or Manager role.
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/comments/{comment_id}/unhide",
+    params(
+        ("comment_id" = u64, Path, description = "Comment ID")
+    ),
+    responses(
+        (status = 200, description = "Comment visible again", body = AnnouncementComment),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Comment not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn unhide_comment(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, AppError> {
+    use announcements_comments::dsl as cmt;
+    auth.require_roles(&["Admin", "Manager"])?;
+    let comment_id = path.into_inner();
+    let mut c = conn(&pool)?;
+    load_comment(comment_id, &mut c)?;
+    diesel::update(cmt::announcements_comments.filter(cmt::id.eq(comment_id)))
+        .set((
+            cmt::is_hidden.eq(false),
+            cmt::hidden_reason.eq(None::<String>),
+            cmt::hidden_by.eq(None::<u64>),
+            cmt::hidden_at.eq(None::<chrono::NaiveDateTime>),
+        ))
+        .execute(&mut c)?;
+    Ok(HttpResponse::Ok().json(load_comment(comment_id, &mut c)?))
+}
+
+/// Dismiss reports on a comment
+///
+/// Closes the open reports on a comment without hiding it. Requires Admin or Manager role.
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/comments/{comment_id}/reports/dismiss",
+    params(
+        ("comment_id" = u64, Path, description = "Comment ID")
+    ),
+    responses(
+        (status = 204, description = "Reports dismissed"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Comment not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn dismiss_reports(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, AppError> {
+    use announcements_comments_reports::dsl as rp;
+    auth.require_roles(&["Admin", "Manager"])?;
+    let comment_id = path.into_inner();
+    let user_id = auth.user_id()?;
+    let mut c = conn(&pool)?;
+    load_comment(comment_id, &mut c)?;
+    diesel::update(
+        rp::announcements_comments_reports
+            .filter(rp::comment_id.eq(comment_id))
+            .filter(rp::status.eq(REPORT_OPEN)),
+    )
+    .set((
+        rp::status.eq(REPORT_DISMISSED),
+        rp::resolved_by.eq(Some(user_id)),
+        rp::resolved_at.eq(Some(Utc::now().naive_utc())),
+    ))
+    .execute(&mut c)?;
+    Ok(HttpResponse::NoContent().finish())
 }
 
 /// Soft-delete a comment
@@ -277,7 +855,8 @@ pub async fn restore_comment(
 
 /// Permanently delete a comment (purge)
 ///
-/// Permanently removes a soft-deleted comment from the database. Requires Admin or Manager role.
+/// Permanently removes a soft-deleted comment, its replies, reactions and reports from the
+/// database. Requires Admin or Manager role.
 /// The comment must be soft-deleted first before it can be purged.
 #[utoipa::path(
     delete,
@@ -0,0 +1,87 @@
+//! Announcement selection for the Atom feeds served by [`crate::feeds`].
+
+use super::audience::Viewer;
+use super::helpers::enrich;
+use super::translations::localize;
+use super::types::AnnouncementOut;
+use crate::auth::{AppError, AuthContext, JwtKeys};
+use crate::models::Announcement;
+use crate::schema::{announcements, announcements_audience};
+use chrono::Utc;
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+
+/// Most recent announcements included in a feed.
+pub const FEED_LIMIT: i64 = 50;
+
+/// Live public announcements for the residents of one building, newest first.
+///
+/// Includes announcements without building targets and those targeted at this
+/// building; announcements aimed at particular apartments are left out.
+pub fn public_feed_items(
+    building_id: u64,
+    lang: &str,
+    keys: &JwtKeys,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<AnnouncementOut>, AppError> {
+    use announcements::dsl as a;
+    use announcements_audience::dsl as au;
+    let now = Utc::now().naive_utc();
+
+    let building_targeted = au::announcements_audience
+        .filter(au::building_id.is_not_null())
+        .select(au::announcement_id);
+    let this_building = au::announcements_audience
+        .filter(au::building_id.eq(building_id))
+        .select(au::announcement_id);
+    let apartment_targeted = au::announcements_audience
+        .filter(au::apartment_id.is_not_null())
+        .select(au::announcement_id);
+
+    let items = a::announcements
+        .filter(a::is_deleted.eq(false))
+        .filter(a::public.eq(true))
+        .filter(a::publish_at.is_not_null().and(a::publish_at.le(now)))
+        .filter(a::expire_at.is_null().or(a::expire_at.gt(now)))
+        .filter(
+            a::id
+                .ne_all(building_targeted)
+                .or(a::id.eq_any(this_building)),
+        )
+        .filter(a::id.ne_all(apartment_targeted))
+        .order(a::publish_at.desc())
+        .limit(FEED_LIMIT)
+        .load::<Announcement>(conn)?;
+    let mut enriched = enrich(items, keys, conn)?;
+    localize(&mut enriched, lang, conn)?;
+    Ok(enriched)
+}
+
+/// Live announcements addressed to the user of `auth`, newest first.
+///
+/// Unlike the authenticated listing, drafts and scheduled announcements are left out
+/// for managers too.
+pub fn private_feed_items(
+    auth: &AuthContext,
+    lang: &str,
+    keys: &JwtKeys,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<AnnouncementOut>, AppError> {
+    use announcements::dsl as a;
+    let now = Utc::now().naive_utc();
+    let viewer = Viewer::load(auth, conn)?;
+
+    let query = a::announcements
+        .filter(a::is_deleted.eq(false))
+        .filter(a::publish_at.is_not_null().and(a::publish_at.le(now)))
+        .filter(a::expire_at.is_null().or(a::expire_at.gt(now)))
+        .into_boxed();
+    let items = viewer
+        .filter(query)
+        .order(a::publish_at.desc())
+        .limit(FEED_LIMIT)
+        .load::<Announcement>(conn)?;
+    let mut enriched = enrich(items, keys, conn)?;
+    localize(&mut enriched, lang, conn)?;
+    Ok(enriched)
+}
@@ -1,11 +1,19 @@
-use super::helpers::{conn, enrich, enrich_one, render_markdown};
+use super::attachments::{purge_files, render_body};
+use super::audience::{AudienceUpdate, Viewer, resolve_audience, save_audience};
+use super::helpers::{attach_receipts, conn, enrich, enrich_one, render_markdown};
+use super::revisions::record_revision;
+use super::translations::{
+    localize, reader_language, save_translations, translated_languages, validate_languages,
+};
 use super::types::{CreateAnnouncementRequest, UpdateAnnouncementRequest};
-use crate::auth::{error::AppError, extractor::AuthContext};
+use crate::auth::{JwtKeys, error::AppError, extractor::AuthContext};
 use crate::db::DbPool;
+use crate::i18n::DEFAULT_LANG;
 use crate::models::{Announcement, NewAnnouncement};
 use crate::pagination::{PaginatedResponse, PaginationParams};
 use crate::schema::announcements;
-use actix_web::{HttpResponse, web};
+use crate::storage::Storage;
+use actix_web::{HttpRequest, HttpResponse, web};
 use chrono::Utc;
 use diesel::prelude::*;
 use utoipa;
@@ -14,6 +22,7 @@ use utoipa;
 ///
 /// Returns all published, non-expired, non-deleted public announcements.
 /// No authentication required. Announcements are ordered by pinned status, then creation date.
+/// Each is returned in the language negotiated from `Accept-Language` when it has that version.
 #[utoipa::path(
     get,
     path = "/api/v1/announcements/public",
@@ -25,7 +34,9 @@ use utoipa;
     tag = "Announcements"
 )]
 pub async fn list_public(
+    req: HttpRequest,
     pool: web::Data<DbPool>,
+    keys: web::Data<JwtKeys>,
     query: web::Query<PaginationParams>,
 ) -> Result<HttpResponse, AppError> {
     use announcements::dsl as a;
@@ -45,7 +56,8 @@ pub async fn list_public(
         .limit(query.limit())
         .offset(query.offset())
         .load::<Announcement>(&mut c)?;
-    let enriched = enrich(items, &mut c)?;
+    let mut enriched = enrich(items, &keys, &mut c)?;
+    localize(&mut enriched, &reader_language(&req), &mut c)?;
     Ok(HttpResponse::Ok().json(PaginatedResponse::new(enriched, total, &query)))
 }
 
@@ -53,7 +65,10 @@ pub async fn list_public(
 ///
 /// Returns announcements based on user role:
 /// - Admin/Manager: See all announcements including drafts and scheduled
-/// - Others: See public announcements and role-specific private announcements
+/// - Others: See public announcements and private announcements targeted at their roles
+/// - Building and apartment targets limit announcements to the residents (or managers) concerned
+///
+/// Each is returned in the language negotiated from `Accept-Language` when it has that version.
 #[utoipa::path(
     get,
     path = "/api/v1/announcements",
@@ -66,72 +81,41 @@ pub async fn list_public(
     security(("bearer_auth" = []))
 )]
 pub async fn list_auth(
+    req: HttpRequest,
     pool: web::Data<DbPool>,
+    keys: web::Data<JwtKeys>,
     auth: AuthContext,
     query: web::Query<PaginationParams>,
 ) -> Result<HttpResponse, AppError> {
-    use crate::auth::get_user_building_ids;
     use announcements::dsl as a;
 
     let mut c = conn(&pool)?;
     let now = Utc::now().naive_utc();
-    let roles = auth.claims.roles.clone();
     let user_id = auth.user_id()?;
-    let is_admin = auth.has_any_role(&["Admin"]);
-    let is_manager = auth.has_any_role(&["Admin", "Manager"]);
+    let viewer = Viewer::load(&auth, &mut c)?;
 
-    let building_ids = if !is_admin {
-        get_user_building_ids(user_id, is_admin, &mut c)?
-    } else {
-        None
+    let visible = || {
+        let mut q = a::announcements
+            .filter(a::is_deleted.eq(false))
+            .into_boxed();
+        if !viewer.is_manager {
+            q = q
+                .filter(a::publish_at.is_null().or(a::publish_at.le(now)))
+                .filter(a::expire_at.is_null().or(a::expire_at.gt(now)));
+        }
+        viewer.filter(q)
     };
 
-    let mut db_query = a::announcements
-        .filter(a::is_deleted.eq(false))
-        .into_boxed();
-
-    if !is_manager {
-        db_query = db_query
-            .filter(a::publish_at.is_null().or(a::publish_at.le(now)))
-            .filter(a::expire_at.is_null().or(a::expire_at.gt(now)));
-    }
-
-    if let Some(ref ids) = building_ids {
-        db_query = db_query.filter(a::building_id.eq_any(ids).or(a::building_id.is_null()));
-    }
-
-    let items = db_query
+    let total = visible().count().get_result::<i64>(&mut c)?;
+    let items = visible()
         .order((a::pinned.desc(), a::created_at.desc()))
+        .limit(query.limit())
+        .offset(query.offset())
         .load::<Announcement>(&mut c)?;
 
-    let filtered: Vec<Announcement> = items
-        .into_iter()
-        .filter(|ann| {
-            if ann.public {
-                return true;
-            }
-            if is_manager {
-                return true;
-            }
-            match &ann.roles_csv {
-                Some(csv) => {
-                    let needed: Vec<&str> = csv
-                        .split(',')
-                        .map(|s| s.trim())
-                        .filter(|s| !s.is_empty())
-                        .collect();
-                    roles.iter().any(|r| needed.iter().any(|n| r == n))
-                }
-                None => true,
-            }
-        })
-        .collect();
-
-    let total = filtered.len() as i64;
-    let offset = query.offset() as usize;
-    let limit = query.limit() as usize;
-    let page_items: Vec<Announcement> = filtered.into_iter().skip(offset).take(limit).collect();
-    let enriched = enrich(page_items, &mut c)?;
+    let mut enriched = enrich(items, &keys, &mut c)?;
+    localize(&mut enriched, &reader_language(&req), &mut c)?;
+    attach_receipts(&mut enriched, user_id, &mut c)?;
     Ok(HttpResponse::Ok().json(PaginatedResponse::new(enriched, total, &query)))
 }
 
@@ -139,6 +123,7 @@ pub async fn list_auth(
 ///
 /// Returns details of a specific announcement. Public announcements can be viewed by anyone.
 /// Private announcements require authentication and appropriate role. Admin/Manager can view drafts and scheduled posts.
+/// The title and body are in the language negotiated from `Accept-Language` when available.
 #[utoipa::path(
     get,
     path = "/api/v1/announcements/{id}",
@@ -155,7 +140,9 @@ pub async fn list_auth(
     tag = "Announcements"
 )]
 pub async fn get_one(
+    req: HttpRequest,
     pool: web::Data<DbPool>,
+    keys: web::Data<JwtKeys>,
     auth_opt: Option<AuthContext>,
     path: web::Path<u64>,
 ) -> Result<HttpResponse, AppError> {
@@ -174,24 +161,9 @@ pub async fn get_one(
         .map(|a| a.has_any_role(&["Admin", "Manager"]).then_some(()))
         .is_some();
     if !ann.public {
-        if let Some(auth) = &auth_opt {
-            if !is_manager && let Some(csv) = &ann.roles_csv {
-                let needed: Vec<&str> = csv
-                    .split(',')
-                    .map(|s| s.trim())
-                    .filter(|s| !s.is_empty())
-                    .collect();
-                if !auth
-                    .claims
-                    .roles
-                    .iter()
-                    .any(|r| needed.iter().any(|n| r == n))
-                {
-                    return Err(AppError::Forbidden);
-                }
-            }
-        } else {
-            return Err(AppError::Unauthorized);
+        let auth = auth_opt.as_ref().ok_or(AppError::Unauthorized)?;
+        if !Viewer::load(auth, &mut c)?.can_access(&ann, &mut c)? {
+            return Err(AppError::Forbidden);
         }
     }
     if !is_manager {
@@ -202,19 +174,30 @@ pub async fn get_one(
             return Err(AppError::Expired);
         }
     }
-    Ok(HttpResponse::Ok().json(enrich_one(ann, &mut c)?))
+    let mut out = enrich_one(ann, &keys, &mut c)?;
+    localize(
+        std::slice::from_mut(&mut out),
+        &reader_language(&req),
+        &mut c,
+    )?;
+    if let Some(user_id) = auth_opt.as_ref().and_then(|a| a.user_id().ok()) {
+        attach_receipts(std::slice::from_mut(&mut out), user_id, &mut c)?;
+    }
+    Ok(HttpResponse::Ok().json(out))
 }
 
 /// Create an announcement
 ///
 /// Creates a new announcement with markdown content (automatically rendered to HTML).
 /// Requires Admin or Manager role. Supports drafts (publish_at = NULL), scheduling, and expiration.
+/// `translations` adds versions in languages other than `language`.
 #[utoipa::path(
     post,
     path = "/api/v1/announcements",
     request_body = CreateAnnouncementRequest,
     responses(
         (status = 201, description = "Announcement created successfully", body = super::types::AnnouncementOut),
+        (status = 400, description = "Unsupported or duplicate language"),
         (status = 403, description = "Forbidden - requires Admin or Manager role"),
         (status = 500, description = "Internal server error")
     ),
@@ -223,12 +206,18 @@ pub async fn get_one(
 )]
 pub async fn create(
     pool: web::Data<DbPool>,
+    keys: web::Data<JwtKeys>,
     auth: AuthContext,
     body: web::Json<CreateAnnouncementRequest>,
 ) -> Result<HttpResponse, AppError> {
     auth.require_roles(&["Admin", "Manager"])?;
     use announcements::dsl as a;
     let mut c = conn(&pool)?;
+    let language = body
+        .language
+        .clone()
+        .unwrap_or_else(|| DEFAULT_LANG.to_string());
+    validate_languages(&language, &body.translations)?;
     let html = render_markdown(&body.body_md);
     let new = NewAnnouncement {
         title: body.title.clone(),
@@ -241,26 +230,90 @@ pub async fn create(
             .map_err(|_| AppError::BadRequest("invalid_sub".into()))?,
         public: body.public,
         pinned: body.pinned,
-        roles_csv: body.roles_csv.clone(),
-        building_id: body.building_id,
-        apartment_id: body.apartment_id,
         comments_enabled: body.comments_enabled,
+        requires_ack: body.requires_ack,
         publish_at: body.publish_at,
         expire_at: body.expire_at,
+        language,
+    };
+    let audience = AudienceUpdate {
+        roles: Some(&body.roles),
+        building_ids: Some(&body.building_ids),
+        apartment_ids: Some(&body.apartment_ids),
     };
+    let audience = resolve_audience(&audience, &mut c)?;
     diesel::insert_into(a::announcements)
         .values(&new)
         .execute(&mut c)?;
     let inserted = a::announcements
         .order(a::id.desc())
         .first::<Announcement>(&mut c)?;
-    Ok(HttpResponse::Created().json(enrich_one(inserted, &mut c)?))
+    save_audience(inserted.id, audience, &mut c)?;
+    save_translations(inserted.id, &body.translations, &mut c)?;
+    record_revision(
+        inserted.id,
+        &inserted.title,
+        &inserted.body_md,
+        inserted.author_id,
+        None,
+        &mut c,
+    )?;
+    Ok(HttpResponse::Created().json(enrich_one(inserted, &keys, &mut c)?))
+}
+
+/// Publish an announcement generated by another module, such as meeting minutes, to
+/// the residents of one building. Returns the new announcement's id.
+pub fn publish_to_building(
+    title: &str,
+    body_md: &str,
+    author_id: u64,
+    building_id: u64,
+    c: &mut diesel::MysqlConnection,
+) -> Result<u64, AppError> {
+    use announcements::dsl as a;
+    let new = NewAnnouncement {
+        title: title.to_string(),
+        body_md: body_md.to_string(),
+        body_html: render_markdown(body_md),
+        author_id,
+        public: false,
+        pinned: false,
+        comments_enabled: true,
+        requires_ack: false,
+        publish_at: None,
+        expire_at: None,
+        language: DEFAULT_LANG.to_string(),
+    };
+    let audience = AudienceUpdate {
+        roles: None,
+        building_ids: Some(&[building_id]),
+        apartment_ids: None,
+    };
+    let audience = resolve_audience(&audience, c)?;
+    diesel::insert_into(a::announcements)
+        .values(&new)
+        .execute(c)?;
+    let inserted = a::announcements
+        .order(a::id.desc())
+        .first::<Announcement>(c)?;
+    save_audience(inserted.id, audience, c)?;
+    record_revision(
+        inserted.id,
+        &inserted.title,
+        &inserted.body_md,
+        author_id,
+        None,
+        c,
+    )?;
+    Ok(inserted.id)
 }
 
 /// Update an announcement
 ///
 /// Updates announcement fields. Accessible by Admin, Manager, or the announcement author.
-/// If body_md is updated, body_html is automatically regenerated.
+/// If body_md is updated, body_html is automatically regenerated. A change to the title
+/// or body is stored as a new revision. `translations`, when given, replaces all versions
+/// in other languages.
 #[utoipa::path(
     put,
     path = "/api/v1/announcements/{id}",
@@ -270,6 +323,7 @@ pub async fn create(
     request_body = UpdateAnnouncementRequest,
     responses(
         (status = 200, description = "Announcement updated successfully", body = super::types::AnnouncementOut),
+        (status = 400, description = "Unsupported or duplicate language"),
         (status = 403, description = "Forbidden - requires Admin, Manager, or author"),
         (status = 404, description = "Announcement not found"),
         (status = 500, description = "Internal server error")
@@ -279,6 +333,7 @@ pub async fn create(
 )]
 pub async fn update(
     pool: web::Data<DbPool>,
+    keys: web::Data<JwtKeys>,
     auth: AuthContext,
     path: web::Path<u64>,
     body: web::Json<UpdateAnnouncementRequest>,
@@ -302,20 +357,42 @@ pub async fn update(
         body_html: Option<String>,
         public: Option<bool>,
         pinned: Option<bool>,
-        roles_csv: Option<Option<String>>,
-        building_id: Option<Option<u64>>,
-        apartment_id: Option<Option<u64>>,
         comments_enabled: Option<bool>,
+        requires_ack: Option<bool>,
         publish_at: Option<Option<chrono::NaiveDateTime>>,
         expire_at: Option<Option<chrono::NaiveDateTime>>,
+        updated_at: Option<Option<chrono::NaiveDateTime>>,
+        language: Option<String>,
+    }
+    let audience = resolve_audience(
+        &AudienceUpdate {
+            roles: body.roles.as_deref(),
+            building_ids: body.building_ids.as_deref(),
+            apartment_ids: body.apartment_ids.as_deref(),
+        },
+        &mut c,
+    )?;
+    let language = body.language.as_deref().unwrap_or(&ann.language);
+    validate_languages(language, body.translations.as_deref().unwrap_or_default())?;
+    // A new default language must not clash with a translation that is being kept
+    if body.translations.is_none()
+        && language != ann.language
+        && translated_languages(&[id], &mut c)?
+            .get(&id)
+            .is_some_and(|langs| langs.iter().any(|l| l == language))
+    {
+        return Err(AppError::BadRequest("duplicate_language".into()));
     }
     let mut ch = AnnChanges::default();
+    if let Some(v) = &body.language {
+        ch.language = Some(v.clone());
+    }
     if let Some(v) = &body.title {
         ch.title = Some(v.clone());
     }
     if let Some(v) = &body.body_md {
         ch.body_md = Some(v.clone());
-        ch.body_html = Some(render_markdown(v));
+        ch.body_html = Some(render_body(id, v, &mut c)?);
     }
     if let Some(v) = body.public {
         ch.public = Some(v);
@@ -323,31 +400,45 @@ pub async fn update(
     if let Some(v) = body.pinned {
         ch.pinned = Some(v);
     }
-    if let Some(v) = &body.roles_csv {
-        ch.roles_csv = Some(v.clone());
-    }
-    if let Some(v) = &body.building_id {
-        ch.building_id = Some(*v);
-    }
-    if let Some(v) = &body.apartment_id {
-        ch.apartment_id = Some(*v);
-    }
     if let Some(v) = body.comments_enabled {
         ch.comments_enabled = Some(v);
     }
+    if let Some(v) = body.requires_ack {
+        ch.requires_ack = Some(v);
+    }
     if let Some(v) = &body.publish_at {
         ch.publish_at = Some(*v);
     }
     if let Some(v) = &body.expire_at {
         ch.expire_at = Some(*v);
     }
+    if !audience.is_empty() || body.translations.is_some() {
+        // Also keeps the changeset non-empty when only the audience or translations change
+        ch.updated_at = Some(Some(Utc::now().naive_utc()));
+    }
+    if !audience.is_empty() {
+        save_audience(id, audience, &mut c)?;
+    }
+    if let Some(translations) = &body.translations {
+        save_translations(id, translations, &mut c)?;
+    }
     diesel::update(a::announcements.filter(a::id.eq(id)))
         .set(&ch)
         .execute(&mut c)?;
     let updated = a::announcements
         .filter(a::id.eq(id))
         .first::<Announcement>(&mut c)?;
-    Ok(HttpResponse::Ok().json(enrich_one(updated, &mut c)?))
+    if updated.title != ann.title || updated.body_md != ann.body_md {
+        record_revision(
+            id,
+            &updated.title,
+            &updated.body_md,
+            auth.user_id()?,
+            None,
+            &mut c,
+        )?;
+    }
+    Ok(HttpResponse::Ok().json(enrich_one(updated, &keys, &mut c)?))
 }
 
 /// Soft-delete an announcement
@@ -440,6 +531,7 @@ pub async fn restore(
 )]
 pub async fn toggle_pin(
     pool: web::Data<DbPool>,
+    keys: web::Data<JwtKeys>,
     auth: AuthContext,
     path: web::Path<u64>,
 ) -> Result<HttpResponse, AppError> {
@@ -456,7 +548,7 @@ pub async fn toggle_pin(
     let updated = a::announcements
         .filter(a::id.eq(id))
         .first::<Announcement>(&mut c)?;
-    Ok(HttpResponse::Ok().json(enrich_one(updated, &mut c)?))
+    Ok(HttpResponse::Ok().json(enrich_one(updated, &keys, &mut c)?))
 }
 
 /// List soft-deleted announcements
@@ -476,6 +568,7 @@ pub async fn toggle_pin(
 )]
 pub async fn list_deleted(
     pool: web::Data<DbPool>,
+    keys: web::Data<JwtKeys>,
     auth: AuthContext,
     query: web::Query<PaginationParams>,
 ) -> Result<HttpResponse, AppError> {
@@ -494,14 +587,15 @@ pub async fn list_deleted(
         .limit(query.limit())
         .offset(query.offset())
         .load::<Announcement>(&mut c)?;
-    let enriched = enrich(items, &mut c)?;
+    let enriched = enrich(items, &keys, &mut c)?;
     Ok(HttpResponse::Ok().json(PaginatedResponse::new(enriched, total, &query)))
 }
 
 /// Permanently delete an announcement (purge)
 ///
 /// Permanently removes a soft-deleted announcement from the database.
-/// This also deletes all associated comments. Requires Admin or Manager role.
+/// This also deletes all associated comments, read receipts, revisions, translations and attachments
+/// (including their stored files). Requires Admin or Manager role.
 /// The announcement must be soft-deleted first before it can be purged.
 #[utoipa::path(
     delete,
@@ -523,10 +617,15 @@ pub async fn purge(
     pool: web::Data<DbPool>,
     auth: AuthContext,
     path: web::Path<u64>,
+    storage: web::Data<dyn Storage>,
 ) -> Result<HttpResponse, AppError> {
     auth.require_roles(&["Admin", "Manager"])?;
     use crate::schema::announcements::dsl as a;
+    use crate::schema::announcements_audience::dsl as au;
     use crate::schema::announcements_comments::dsl as cmt;
+    use crate::schema::announcements_reads::dsl as rd;
+    use crate::schema::announcements_revisions::dsl as rv;
+    use crate::schema::announcements_translations::dsl as tr;
     let id = path.into_inner();
     let mut c = conn(&pool)?;
     match a::announcements
@@ -542,6 +641,14 @@ pub async fn purge(
     }
     diesel::delete(cmt::announcements_comments.filter(cmt::announcement_id.eq(id)))
         .execute(&mut c)?;
+    diesel::delete(rd::announcements_reads.filter(rd::announcement_id.eq(id))).execute(&mut c)?;
+    diesel::delete(au::announcements_audience.filter(au::announcement_id.eq(id)))
+        .execute(&mut c)?;
+    diesel::delete(rv::announcements_revisions.filter(rv::announcement_id.eq(id)))
+        .execute(&mut c)?;
+    diesel::delete(tr::announcements_translations.filter(tr::announcement_id.eq(id)))
+        .execute(&mut c)?;
+    purge_files(id, storage.get_ref(), &mut c).await?;
     let affected = diesel::delete(a::announcements.filter(a::id.eq(id))).execute(&mut c)?;
     if affected == 0 {
         return Err(AppError::NotFound);
@@ -570,6 +677,7 @@ pub async fn purge(
 )]
 pub async fn publish_now(
     pool: web::Data<DbPool>,
+    keys: web::Data<JwtKeys>,
     auth: AuthContext,
     path: web::Path<u64>,
 ) -> Result<HttpResponse, AppError> {
@@ -594,5 +702,5 @@ pub async fn publish_now(
     let updated = a::announcements
         .filter(a::id.eq(id))
         .first::<Announcement>(&mut c)?;
-    Ok(HttpResponse::Ok().json(enrich_one(updated, &mut c)?))
+    Ok(HttpResponse::Ok().json(enrich_one(updated, &keys, &mut c)?))
 }
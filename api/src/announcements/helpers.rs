@@ -1,16 +1,52 @@
+use super::attachments::{load_attachments, sign_body};
+use super::audience::{Viewer, load_audiences};
+use super::translations::translated_languages;
 use super::types::AnnouncementOut;
-use crate::auth::error::AppError;
+use crate::auth::{JwtKeys, error::AppError, extractor::AuthContext};
 use crate::db::DbPool;
-use crate::models::Announcement;
+use crate::models::{Announcement, AnnouncementRead};
 use actix_web::web;
+use chrono::Utc;
 use diesel::prelude::*;
 use std::collections::HashMap;
 
 pub(super) fn render_markdown(md: &str) -> String {
-    use pulldown_cmark::{Options, Parser, html};
+    render_markdown_with(md, |_| None)
+}
+
+/// Render Markdown, rewriting link and image targets that `resolve` recognises.
+///
+/// Used for `attachment:<id>` references; unresolved ones keep their scheme,
+/// which the sanitiser then drops.
+pub(super) fn render_markdown_with(md: &str, resolve: impl Fn(&str) -> Option<String>) -> String {
+    use pulldown_cmark::{Event, Options, Parser, Tag, html};
     let mut opts = Options::empty();
     opts.insert(Options::ENABLE_STRIKETHROUGH);
-    let parser = Parser::new_ext(md, opts);
+    let parser = Parser::new_ext(md, opts).map(|event| match event {
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: resolve(&dest_url).map(Into::into).unwrap_or(dest_url),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: resolve(&dest_url).map(Into::into).unwrap_or(dest_url),
+            title,
+            id,
+        }),
+        other => other,
+    });
     let mut html_output = String::new();
     html::push_html(&mut html_output, parser);
     // Ammonia's clean now returns a Document; convert it to String.
@@ -26,12 +62,40 @@ pub(super) fn conn(
     pool.get().map_err(|_| AppError::Internal("db_pool".into()))
 }
 
+/// Load a live announcement the caller is allowed to see.
+pub(super) fn load_visible(
+    id: u64,
+    auth: &AuthContext,
+    c: &mut diesel::MysqlConnection,
+) -> Result<Announcement, AppError> {
+    use crate::schema::announcements::dsl as a;
+
+    let ann = a::announcements
+        .filter(a::id.eq(id))
+        .first::<Announcement>(c)?;
+    if ann.is_deleted {
+        return Err(AppError::NotFound);
+    }
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        let now = Utc::now().naive_utc();
+        if ann.publish_at.map(|p| p > now).unwrap_or(false) {
+            return Err(AppError::NotPublished);
+        }
+        if ann.expire_at.map(|e| e <= now).unwrap_or(false) {
+            return Err(AppError::Expired);
+        }
+    }
+    if !Viewer::load(auth, c)?.can_access(&ann, c)? {
+        return Err(AppError::Forbidden);
+    }
+    Ok(ann)
+}
+
 pub(super) fn enrich(
-    mut anns: Vec<Announcement>,
+    anns: Vec<Announcement>,
+    keys: &JwtKeys,
     conn: &mut diesel::MysqlConnection,
 ) -> Result<Vec<AnnouncementOut>, AppError> {
-    use crate::schema::apartments::dsl as ap;
-    use crate::schema::buildings::dsl as b;
     use crate::schema::users::dsl as u;
     let ids: Vec<u64> = anns.iter().map(|a| a.author_id).collect();
     let users = u::users
@@ -41,128 +105,109 @@ pub(super) fn enrich(
     for usr in users {
         user_map.insert(usr.id, usr.name);
     }
-    let b_ids: Vec<u64> = anns
-        .iter()
-        .filter_map(|a| a.building_id)
-        .collect::<Vec<_>>();
-    let b_ids_uniq: Vec<u64> = {
-        let mut tmp = b_ids.clone();
-        tmp.sort_unstable();
-        std::mem::take(&mut tmp)
-    };
-    let building_rows: Vec<(u64, String)> = if b_ids_uniq.is_empty() {
-        vec![]
-    } else {
-        b::buildings
-            .filter(b::id.eq_any(&b_ids_uniq))
-            .select((b::id, b::address))
-            .load(conn)?
-    };
-    let mut building_map: HashMap<u64, String> = HashMap::new();
-    for (bid, addr) in building_rows {
-        building_map.insert(bid, addr);
-    }
-    let ap_ids: Vec<u64> = anns
-        .iter()
-        .filter_map(|a| a.apartment_id)
-        .collect::<Vec<_>>();
-    let ap_ids_uniq: Vec<u64> = {
-        let mut tmp = ap_ids.clone();
-        tmp.sort_unstable();
-        std::mem::take(&mut tmp)
-    };
-    let apartment_rows: Vec<(u64, String)> = if ap_ids_uniq.is_empty() {
-        vec![]
-    } else {
-        ap::apartments
-            .filter(ap::id.eq_any(&ap_ids_uniq))
-            .select((ap::id, ap::number))
-            .load(conn)?
-    };
-    let mut apartment_map: HashMap<u64, String> = HashMap::new();
-    for (aid, num) in apartment_rows {
-        apartment_map.insert(aid, num);
-    }
+    let ann_ids: Vec<u64> = anns.iter().map(|a| a.id).collect();
+    let mut audiences = load_audiences(&ann_ids, conn)?;
+    let edited = last_edits(&ann_ids, conn)?;
+    let mut attachments = load_attachments(&ann_ids, keys, conn)?;
+    let mut translated = translated_languages(&ann_ids, conn)?;
     Ok(anns
-        .drain(..)
-        .map(|a| AnnouncementOut {
-            author_name: user_map
-                .get(&a.author_id)
-                .cloned()
-                .unwrap_or_else(|| "Unknown".into()),
-            id: a.id,
-            title: a.title,
-            body_md: a.body_md,
-            body_html: a.body_html,
-            author_id: a.author_id,
-            public: a.public,
-            pinned: a.pinned,
-            roles_csv: a.roles_csv,
-            building_id: a.building_id,
-            building_address: a
-                .building_id
-                .and_then(|bid| building_map.get(&bid).cloned()),
-            apartment_id: a.apartment_id,
-            apartment_number: a
-                .apartment_id
-                .and_then(|aid| apartment_map.get(&aid).cloned()),
-            comments_enabled: a.comments_enabled,
-            publish_at: a.publish_at,
-            expire_at: a.expire_at,
-            is_deleted: a.is_deleted,
-            created_at: a.created_at,
-            updated_at: a.updated_at,
+        .into_iter()
+        .map(|a| {
+            let audience = audiences.remove(&a.id).unwrap_or_default();
+            let attachments = attachments.remove(&a.id).unwrap_or_default();
+            let mut languages = vec![a.language.clone()];
+            languages.extend(translated.remove(&a.id).unwrap_or_default());
+            AnnouncementOut {
+                author_name: user_map
+                    .get(&a.author_id)
+                    .cloned()
+                    .unwrap_or_else(|| "Unknown".into()),
+                id: a.id,
+                title: a.title,
+                body_md: a.body_md,
+                body_html: sign_body(a.id, &a.body_html, &attachments),
+                author_id: a.author_id,
+                public: a.public,
+                pinned: a.pinned,
+                roles: audience.roles,
+                buildings: audience.buildings,
+                apartments: audience.apartments,
+                comments_enabled: a.comments_enabled,
+                requires_ack: a.requires_ack,
+                publish_at: a.publish_at,
+                expire_at: a.expire_at,
+                is_deleted: a.is_deleted,
+                created_at: a.created_at,
+                updated_at: a.updated_at,
+                edited_at: edited.get(&a.id).copied(),
+                read_at: None,
+                acknowledged_at: None,
+                attachments,
+                language: a.language,
+                languages,
+            }
+        })
+        .collect())
+}
+
+/// Time of the latest revision for announcements that have more than one.
+fn last_edits(
+    ids: &[u64],
+    conn: &mut diesel::MysqlConnection,
+) -> Result<HashMap<u64, chrono::NaiveDateTime>, AppError> {
+    use crate::schema::announcements_revisions::dsl as rv;
+    use diesel::dsl::max;
+    if ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let rows: Vec<(u64, Option<u32>, Option<chrono::NaiveDateTime>)> = rv::announcements_revisions
+        .filter(rv::announcement_id.eq_any(ids))
+        .group_by(rv::announcement_id)
+        .select((rv::announcement_id, max(rv::revision), max(rv::created_at)))
+        .load(conn)?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|(id, latest, at)| match (latest, at) {
+            (Some(latest), Some(at)) if latest > 1 => Some((id, at)),
+            _ => None,
         })
         .collect())
 }
 
 pub(super) fn enrich_one(
     a: Announcement,
+    keys: &JwtKeys,
     conn: &mut diesel::MysqlConnection,
 ) -> Result<AnnouncementOut, AppError> {
-    use crate::schema::apartments::dsl as ap;
-    use crate::schema::buildings::dsl as b;
-    use crate::schema::users::dsl as u;
-    let name = u::users
-        .filter(u::id.eq(a.author_id))
-        .select(u::name)
-        .first::<String>(conn)
-        .unwrap_or_else(|_| "Unknown".into());
-    let building_address = match a.building_id {
-        Some(bid) => b::buildings
-            .filter(b::id.eq(bid))
-            .select(b::address)
-            .first::<String>(conn)
-            .ok(),
-        None => None,
-    };
-    let apartment_number = match a.apartment_id {
-        Some(aid) => ap::apartments
-            .filter(ap::id.eq(aid))
-            .select(ap::number)
-            .first::<String>(conn)
-            .ok(),
-        None => None,
-    };
-    Ok(AnnouncementOut {
-        author_name: name,
-        id: a.id,
-        title: a.title,
-        body_md: a.body_md,
-        body_html: a.body_html,
-        author_id: a.author_id,
-        public: a.public,
-        pinned: a.pinned,
-        roles_csv: a.roles_csv,
-        building_id: a.building_id,
-        building_address,
-        apartment_id: a.apartment_id,
-        apartment_number,
-        comments_enabled: a.comments_enabled,
-        publish_at: a.publish_at,
-        expire_at: a.expire_at,
-        is_deleted: a.is_deleted,
-        created_at: a.created_at,
-        updated_at: a.updated_at,
-    })
+    enrich(vec![a], keys, conn)?
+        .pop()
+        .ok_or_else(|| AppError::Internal("enrich".into()))
+}
+
+/// Fill in the requesting user's read and acknowledge timestamps.
+pub(super) fn attach_receipts(
+    items: &mut [AnnouncementOut],
+    user_id: u64,
+    conn: &mut diesel::MysqlConnection,
+) -> Result<(), AppError> {
+    use crate::schema::announcements_reads::dsl as r;
+    let ids: Vec<u64> = items.iter().map(|a| a.id).collect();
+    if ids.is_empty() {
+        return Ok(());
+    }
+    let receipts: HashMap<u64, AnnouncementRead> = r::announcements_reads
+        .filter(r::user_id.eq(user_id))
+        .filter(r::announcement_id.eq_any(&ids))
+        .select(AnnouncementRead::as_select())
+        .load(conn)?
+        .into_iter()
+        .map(|rd| (rd.announcement_id, rd))
+        .collect();
+    for item in items.iter_mut() {
+        if let Some(rd) = receipts.get(&item.id) {
+            item.read_at = Some(rd.read_at);
+            item.acknowledged_at = rd.acknowledged_at;
+        }
+    }
+    Ok(())
 }
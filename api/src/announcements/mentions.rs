@@ -0,0 +1,248 @@
+//! `@Name` mentions in announcement comments.
+//!
+//! A mention is `@` followed by the full name of a user living in or managing one of the
+//! buildings the announcement is for. Names may contain spaces, so the longest matching
+//! name wins; a name shared by several candidates is left unresolved.
+
+use super::types::MentionOut;
+use crate::auth::{AppError, AuthContext, get_user_building_ids};
+use crate::schema::announcements_comments_mentions;
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Byte range of a mention in the comment body (including the `@`) and the mentioned user.
+pub(super) type Mention = (Range<usize>, u64);
+
+/// Buildings an announcement is about: targeted buildings plus those of targeted apartments.
+/// `None` when it has no location targets.
+fn announcement_buildings(
+    announcement_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<Option<Vec<u64>>, AppError> {
+    use crate::schema::announcements_audience::dsl as au;
+    use crate::schema::apartments::dsl as ap;
+    let rows: Vec<(Option<u64>, Option<u64>)> = au::announcements_audience
+        .filter(au::announcement_id.eq(announcement_id))
+        .select((au::building_id, au::apartment_id))
+        .load(conn)?;
+    let mut building_ids: Vec<u64> = rows.iter().filter_map(|r| r.0).collect();
+    let apartment_ids: Vec<u64> = rows.iter().filter_map(|r| r.1).collect();
+    if !apartment_ids.is_empty() {
+        building_ids.extend(
+            ap::apartments
+                .filter(ap::id.eq_any(&apartment_ids))
+                .select(ap::building_id)
+                .load::<u64>(conn)?,
+        );
+    }
+    if building_ids.is_empty() {
+        return Ok(None);
+    }
+    building_ids.sort_unstable();
+    building_ids.dedup();
+    Ok(Some(building_ids))
+}
+
+/// Users who can be mentioned under an announcement: owners, active renters and managers
+/// of its buildings. For announcements not tied to a building, the commenter's own
+/// buildings are used instead (everyone for Admins).
+pub(super) fn mentionable_users(
+    announcement_id: u64,
+    auth: &AuthContext,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<MentionOut>, AppError> {
+    use crate::schema::apartment_owners::dsl as ao;
+    use crate::schema::apartment_renters::dsl as ar;
+    use crate::schema::apartments::dsl as ap;
+    use crate::schema::building_managers::dsl as bm;
+    use crate::schema::users::dsl as u;
+
+    let buildings = match announcement_buildings(announcement_id, conn)? {
+        Some(ids) => Some(ids),
+        None => get_user_building_ids(auth.user_id()?, auth.has_any_role(&["Admin"]), conn)?,
+    };
+    let mut query = u::users.into_boxed();
+    if let Some(building_ids) = buildings {
+        let apartment_ids: Vec<u64> = ap::apartments
+            .filter(ap::building_id.eq_any(&building_ids))
+            .filter(ap::is_deleted.eq(false))
+            .select(ap::id)
+            .load(conn)?;
+        let mut user_ids: Vec<u64> = ao::apartment_owners
+            .filter(ao::apartment_id.eq_any(&apartment_ids))
+            .select(ao::user_id)
+            .load(conn)?;
+        user_ids.extend(
+            ar::apartment_renters
+                .filter(ar::apartment_id.eq_any(&apartment_ids))
+                .filter(ar::is_active.eq(true))
+                .select(ar::user_id)
+                .load::<u64>(conn)?,
+        );
+        user_ids.extend(
+            bm::building_managers
+                .filter(bm::building_id.eq_any(&building_ids))
+                .select(bm::user_id)
+                .load::<u64>(conn)?,
+        );
+        user_ids.sort_unstable();
+        user_ids.dedup();
+        query = query.filter(u::id.eq_any(user_ids));
+    }
+    Ok(query
+        .order(u::name.asc())
+        .select((u::id, u::name))
+        .load::<(u64, String)>(conn)?
+        .into_iter()
+        .map(|(user_id, name)| MentionOut { user_id, name })
+        .collect())
+}
+
+/// Locate `@Name` mentions of `candidates` in `text`, as byte ranges (including the `@`)
+/// with the mentioned user.
+pub(super) fn find_mentions(text: &str, candidates: &[MentionOut]) -> Vec<Mention> {
+    let mut found = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = text[pos..].find('@') {
+        let at = pos + offset;
+        pos = at + 1;
+        // Skip e-mail addresses and the like
+        if text[..at]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric())
+        {
+            continue;
+        }
+        let rest = &text[at + 1..];
+        let mut best: Option<(usize, Option<u64>)> = None;
+        for cand in candidates {
+            let len = cand.name.len();
+            let matches = len > 0
+                && rest.is_char_boundary(len.min(rest.len()))
+                && rest.len() >= len
+                && rest[..len].eq_ignore_ascii_case(&cand.name)
+                && !rest[len..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_alphanumeric());
+            if !matches {
+                continue;
+            }
+            best = match best {
+                Some((l, _)) if l > len => best,
+                Some((l, Some(id))) if l == len && id != cand.user_id => Some((l, None)),
+                Some((l, None)) if l == len => best,
+                _ => Some((len, Some(cand.user_id))),
+            };
+        }
+        if let Some((len, Some(user_id))) = best {
+            found.push((at..at + 1 + len, user_id));
+            pos = at + 1 + len;
+        }
+    }
+    found
+}
+
+/// Markdown with each mention emphasised, used for rendering only.
+pub(super) fn emphasize_mentions(text: &str, mentions: &[Mention]) -> String {
+    let mut out = String::with_capacity(text.len() + mentions.len() * 4);
+    let mut last = 0;
+    for (range, _) in mentions {
+        out.push_str(&text[last..range.start]);
+        out.push_str("**");
+        out.push_str(&text[range.clone()]);
+        out.push_str("**");
+        last = range.end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// Replace the stored mentions of a comment.
+pub(super) fn save_mentions(
+    comment_id: u64,
+    mentions: &[Mention],
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use announcements_comments_mentions::dsl as m;
+    diesel::delete(m::announcements_comments_mentions.filter(m::comment_id.eq(comment_id)))
+        .execute(conn)?;
+    let rows: Vec<_> = mentions
+        .iter()
+        .map(|(_, user_id)| (m::comment_id.eq(comment_id), m::user_id.eq(*user_id)))
+        .collect();
+    if !rows.is_empty() {
+        diesel::insert_or_ignore_into(m::announcements_comments_mentions)
+            .values(&rows)
+            .execute(conn)?;
+    }
+    Ok(())
+}
+
+/// Mentioned users of each comment.
+pub(super) fn load_mentions(
+    comment_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<HashMap<u64, Vec<MentionOut>>, AppError> {
+    use crate::schema::users::dsl as u;
+    use announcements_comments_mentions::dsl as m;
+    let mut out: HashMap<u64, Vec<MentionOut>> = HashMap::new();
+    if comment_ids.is_empty() {
+        return Ok(out);
+    }
+    let rows: Vec<(u64, u64, String)> = m::announcements_comments_mentions
+        .inner_join(u::users.on(u::id.eq(m::user_id)))
+        .filter(m::comment_id.eq_any(comment_ids))
+        .order(u::name.asc())
+        .select((m::comment_id, u::id, u::name))
+        .load(conn)?;
+    for (comment_id, user_id, name) in rows {
+        out.entry(comment_id)
+            .or_default()
+            .push(MentionOut { user_id, name });
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn users() -> Vec<MentionOut> {
+        [
+            (1, "Jan"),
+            (2, "Jan Novák"),
+            (3, "Eva Dvořák"),
+            (4, "Petr"),
+            (5, "Petr"),
+        ]
+        .into_iter()
+        .map(|(user_id, name)| MentionOut {
+            user_id,
+            name: name.into(),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn longest_name_wins() {
+        let text = "Thanks @Jan Novák and @jan, see @Eva Dvořák!";
+        let found = find_mentions(text, &users());
+        let ids: Vec<u64> = found.iter().map(|(_, id)| *id).collect();
+        assert_eq!(ids, vec![2, 1, 3]);
+        assert_eq!(&text[found[0].0.clone()], "@Jan Novák");
+        assert_eq!(
+            emphasize_mentions(text, &found),
+            "Thanks **@Jan Novák** and **@jan**, see **@Eva Dvořák**!"
+        );
+    }
+
+    #[test]
+    fn ignores_emails_partial_words_and_ambiguous_names() {
+        let found = find_mentions("mail jan@Jan.cz, @Janek, @Petr", &users());
+        assert!(found.is_empty());
+    }
+}
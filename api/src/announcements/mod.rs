@@ -1,10 +1,26 @@
+mod analytics;
+pub mod attachments;
+mod audience;
 mod comments;
+mod feeds;
 mod handlers;
 mod helpers;
+mod mentions;
+mod receipts;
+mod revisions;
+mod templates;
+mod translations;
 mod types;
 
+pub use analytics::*;
+pub use attachments::*;
 pub use comments::*;
+pub use feeds::*;
 pub use handlers::*;
+pub use receipts::*;
+pub use revisions::*;
+pub use templates::*;
+pub use translations::*;
 pub use types::*;
 
 use actix_web::web;
@@ -16,6 +32,18 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/deleted", web::get().to(list_deleted))
             .route("", web::get().to(list_auth))
             .route("", web::post().to(create))
+            .route("/analytics", web::get().to(engagement_summary))
+            .route("/templates", web::get().to(list_templates))
+            .route("/templates", web::post().to(create_template))
+            .route("/templates/{template_id}", web::put().to(update_template))
+            .route(
+                "/templates/{template_id}",
+                web::delete().to(delete_template),
+            )
+            .route(
+                "/templates/{template_id}/apply",
+                web::post().to(apply_template),
+            )
             .route("/{id}", web::get().to(get_one))
             .route("/{id}", web::put().to(update))
             .route("/{id}", web::delete().to(delete_soft))
@@ -23,7 +51,31 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/{id}/pin", web::post().to(toggle_pin))
             .route("/{id}/comments", web::get().to(list_comments))
             .route("/{id}/comments", web::post().to(create_comment))
+            .route(
+                "/{id}/comments/mentionable",
+                web::get().to(list_mentionable),
+            )
+            // moderation queue (before /comments/{comment_id} so the path isn't taken as an id)
+            .route("/comments/reports", web::get().to(list_reports))
+            .route("/comments/{comment_id}", web::put().to(update_comment))
             .route("/comments/{comment_id}", web::delete().to(delete_comment))
+            .route(
+                "/comments/{comment_id}/reactions",
+                web::post().to(toggle_reaction),
+            )
+            .route(
+                "/comments/{comment_id}/report",
+                web::post().to(report_comment),
+            )
+            .route("/comments/{comment_id}/hide", web::post().to(hide_comment))
+            .route(
+                "/comments/{comment_id}/unhide",
+                web::post().to(unhide_comment),
+            )
+            .route(
+                "/comments/{comment_id}/reports/dismiss",
+                web::post().to(dismiss_reports),
+            )
             .route(
                 "/comments/{comment_id}/restore",
                 web::post().to(restore_comment),
@@ -33,6 +85,30 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                 web::delete().to(purge_comment),
             )
             .route("/{id}/purge", web::delete().to(purge))
-            .route("/{id}/publish", web::post().to(publish_now)),
+            .route("/{id}/publish", web::post().to(publish_now))
+            .route("/{id}/read", web::post().to(mark_read))
+            .route("/{id}/acknowledge", web::post().to(acknowledge))
+            .route("/{id}/receipts", web::get().to(receipt_report))
+            .route("/{id}/analytics", web::get().to(announcement_analytics))
+            .route("/{id}/translations", web::get().to(list_translations))
+            .route("/{id}/revisions", web::get().to(list_revisions))
+            .route("/{id}/revisions/diff", web::get().to(revision_diff))
+            .route(
+                "/{id}/revisions/{revision}/restore",
+                web::post().to(restore_revision),
+            )
+            .route("/{id}/attachments", web::post().to(upload_attachment))
+            .route(
+                "/{id}/attachments/{att_id}",
+                web::delete().to(delete_attachment),
+            )
+            .route(
+                "/{id}/attachments/{att_id}/download",
+                web::get().to(download_attachment),
+            )
+            .route(
+                "/{id}/attachments/{att_id}/thumbnail",
+                web::get().to(download_attachment_thumbnail),
+            ),
     );
 }
@@ -0,0 +1,202 @@
+use super::audience::{audience_user_ids, residents};
+use super::helpers::{conn, load_visible};
+use super::types::{ReceiptEntry, ReceiptReport};
+use crate::auth::{
+    building_access::get_user_building_ids, error::AppError, extractor::AuthContext,
+};
+use crate::db::DbPool;
+use crate::models::AnnouncementRead;
+use crate::schema::announcements_reads;
+use actix_web::{HttpResponse, web};
+use chrono::Utc;
+use diesel::prelude::*;
+use std::collections::HashMap;
+
+fn load_receipt(
+    announcement_id: u64,
+    user_id: u64,
+    c: &mut diesel::MysqlConnection,
+) -> Result<AnnouncementRead, AppError> {
+    use announcements_reads::dsl as r;
+    Ok(r::announcements_reads
+        .filter(r::announcement_id.eq(announcement_id))
+        .filter(r::user_id.eq(user_id))
+        .select(AnnouncementRead::as_select())
+        .first(c)?)
+}
+
+/// Mark an announcement as read
+///
+/// Records that the current user has read the announcement. Repeated calls keep the
+/// time of the first read.
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/{id}/read",
+    params(
+        ("id" = u64, Path, description = "Announcement ID")
+    ),
+    responses(
+        (status = 200, description = "Read receipt", body = AnnouncementRead),
+        (status = 403, description = "Forbidden - announcement not addressed to the user"),
+        (status = 404, description = "Announcement not found or deleted"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn mark_read(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, AppError> {
+    use announcements_reads::dsl as r;
+    let id = path.into_inner();
+    let user_id = auth.user_id()?;
+    let mut c = conn(&pool)?;
+    load_visible(id, &auth, &mut c)?;
+
+    diesel::insert_or_ignore_into(r::announcements_reads)
+        .values((r::announcement_id.eq(id), r::user_id.eq(user_id)))
+        .execute(&mut c)?;
+
+    Ok(HttpResponse::Ok().json(load_receipt(id, user_id, &mut c)?))
+}
+
+/// Acknowledge an announcement
+///
+/// Confirms that the current user has read and understood an announcement that
+/// requires acknowledgement. Also marks it as read. Repeated calls keep the time
+/// of the first acknowledgement.
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/{id}/acknowledge",
+    params(
+        ("id" = u64, Path, description = "Announcement ID")
+    ),
+    responses(
+        (status = 200, description = "Read receipt with acknowledgement", body = AnnouncementRead),
+        (status = 400, description = "Bad request - announcement does not require acknowledgement"),
+        (status = 403, description = "Forbidden - announcement not addressed to the user"),
+        (status = 404, description = "Announcement not found or deleted"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn acknowledge(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, AppError> {
+    use announcements_reads::dsl as r;
+    let id = path.into_inner();
+    let user_id = auth.user_id()?;
+    let mut c = conn(&pool)?;
+    let ann = load_visible(id, &auth, &mut c)?;
+    if !ann.requires_ack {
+        return Err(AppError::BadRequest("ack_not_required".into()));
+    }
+
+    let now = Utc::now().naive_utc();
+    diesel::insert_or_ignore_into(r::announcements_reads)
+        .values((r::announcement_id.eq(id), r::user_id.eq(user_id)))
+        .execute(&mut c)?;
+    diesel::update(
+        r::announcements_reads
+            .filter(r::announcement_id.eq(id))
+            .filter(r::user_id.eq(user_id))
+            .filter(r::acknowledged_at.is_null()),
+    )
+    .set(r::acknowledged_at.eq(Some(now)))
+    .execute(&mut c)?;
+
+    Ok(HttpResponse::Ok().json(load_receipt(id, user_id, &mut c)?))
+}
+
+/// Read receipt report
+///
+/// Summarises how many members of the announcement's target audience (apartment,
+/// building and roles) have read and, where required, acknowledged it, and lists
+/// those who have not yet done so. Managers only see the residents of the buildings
+/// they manage. Requires Admin or Manager role.
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/{id}/receipts",
+    params(
+        ("id" = u64, Path, description = "Announcement ID")
+    ),
+    responses(
+        (status = 200, description = "Receipt report", body = ReceiptReport),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Announcement not found or deleted"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn receipt_report(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::users::dsl as u;
+    use announcements_reads::dsl as r;
+
+    auth.require_roles(&["Admin", "Manager"])?;
+    let id = path.into_inner();
+    let mut c = conn(&pool)?;
+    let ann = load_visible(id, &auth, &mut c)?;
+
+    let mut audience = audience_user_ids(id, &mut c)?;
+    let managed = get_user_building_ids(auth.user_id()?, auth.has_any_role(&["Admin"]), &mut c)?;
+    if let Some(building_ids) = managed {
+        let managed_residents = residents(Some(&building_ids), &mut c)?;
+        audience.retain(|user_id| managed_residents.contains(user_id));
+    }
+    let receipts: HashMap<u64, AnnouncementRead> = r::announcements_reads
+        .filter(r::announcement_id.eq(id))
+        .select(AnnouncementRead::as_select())
+        .load(&mut c)?
+        .into_iter()
+        .map(|rd| (rd.user_id, rd))
+        .collect();
+    let users: Vec<(u64, String, String)> = u::users
+        .filter(u::id.eq_any(&audience))
+        .select((u::id, u::name, u::email))
+        .load(&mut c)?;
+
+    let mut read_count = 0;
+    let mut acknowledged_count = 0;
+    let mut pending = Vec::new();
+    for (user_id, name, email) in users {
+        let receipt = receipts.get(&user_id);
+        let read_at = receipt.map(|rd| rd.read_at);
+        let acknowledged_at = receipt.and_then(|rd| rd.acknowledged_at);
+        if read_at.is_some() {
+            read_count += 1;
+        }
+        if acknowledged_at.is_some() {
+            acknowledged_count += 1;
+        }
+        let done = read_at.is_some() && (!ann.requires_ack || acknowledged_at.is_some());
+        if !done {
+            pending.push(ReceiptEntry {
+                user_id,
+                name,
+                email,
+                read_at,
+                acknowledged_at,
+            });
+        }
+    }
+    pending.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(HttpResponse::Ok().json(ReceiptReport {
+        announcement_id: id,
+        requires_ack: ann.requires_ack,
+        audience_total: audience.len(),
+        read_count,
+        acknowledged_count,
+        pending,
+    }))
+}
@@ -0,0 +1,300 @@
+use super::attachments::render_body;
+use super::helpers::{conn, enrich_one, load_visible};
+use super::types::{DiffLine, DiffOp, DiffQuery, RevisionDiff, RevisionOut};
+use crate::auth::{JwtKeys, error::AppError, extractor::AuthContext};
+use crate::db::DbPool;
+use crate::models::{Announcement, AnnouncementRevision, NewAnnouncementRevision};
+use crate::schema::{announcements, announcements_revisions};
+use actix_web::{HttpResponse, web};
+use chrono::Utc;
+use diesel::prelude::*;
+use std::collections::HashMap;
+
+/// Store the current title and body as the next revision of an announcement.
+pub(super) fn record_revision(
+    announcement_id: u64,
+    title: &str,
+    body_md: &str,
+    editor_id: u64,
+    restored_from: Option<u32>,
+    c: &mut diesel::MysqlConnection,
+) -> Result<u32, AppError> {
+    use announcements_revisions::dsl as rv;
+    let latest: Option<u32> = rv::announcements_revisions
+        .filter(rv::announcement_id.eq(announcement_id))
+        .select(diesel::dsl::max(rv::revision))
+        .first(c)?;
+    let revision = latest.unwrap_or(0) + 1;
+    diesel::insert_into(rv::announcements_revisions)
+        .values(&NewAnnouncementRevision {
+            announcement_id,
+            revision,
+            title: title.to_string(),
+            body_md: body_md.to_string(),
+            editor_id,
+            restored_from,
+        })
+        .execute(c)?;
+    Ok(revision)
+}
+
+fn load_revision(
+    announcement_id: u64,
+    revision: u32,
+    c: &mut diesel::MysqlConnection,
+) -> Result<AnnouncementRevision, AppError> {
+    use announcements_revisions::dsl as rv;
+    rv::announcements_revisions
+        .filter(rv::announcement_id.eq(announcement_id))
+        .filter(rv::revision.eq(revision))
+        .select(AnnouncementRevision::as_select())
+        .first(c)
+        .optional()?
+        .ok_or(AppError::NotFound)
+}
+
+/// Line-based diff of two Markdown bodies (longest common subsequence).
+fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    // lcs[i][j] = length of the common subsequence of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |op, text: &str| DiffLine {
+        op,
+        text: text.to_string(),
+    };
+    let (mut i, mut j) = (0, 0);
+    let mut out = Vec::with_capacity(a.len().max(b.len()));
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            out.push(line(DiffOp::Equal, a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push(line(DiffOp::Delete, a[i]));
+            i += 1;
+        } else {
+            out.push(line(DiffOp::Insert, b[j]));
+            j += 1;
+        }
+    }
+    out.extend(a[i..].iter().map(|t| line(DiffOp::Delete, t)));
+    out.extend(b[j..].iter().map(|t| line(DiffOp::Insert, t)));
+    out
+}
+
+/// List announcement revisions
+///
+/// Returns every stored version of the announcement's title and body, newest first,
+/// with the editor and time of each change. Available to anyone who can see the announcement.
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/{id}/revisions",
+    params(
+        ("id" = u64, Path, description = "Announcement ID")
+    ),
+    responses(
+        (status = 200, description = "Revision history", body = Vec<RevisionOut>),
+        (status = 403, description = "Forbidden - announcement not addressed to the user"),
+        (status = 404, description = "Announcement not found or deleted"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_revisions(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::users::dsl as u;
+    use announcements_revisions::dsl as rv;
+    let id = path.into_inner();
+    let mut c = conn(&pool)?;
+    load_visible(id, &auth, &mut c)?;
+
+    let revisions: Vec<AnnouncementRevision> = rv::announcements_revisions
+        .filter(rv::announcement_id.eq(id))
+        .order(rv::revision.desc())
+        .select(AnnouncementRevision::as_select())
+        .load(&mut c)?;
+    let editor_ids: Vec<u64> = revisions.iter().map(|r| r.editor_id).collect();
+    let names: HashMap<u64, String> = u::users
+        .filter(u::id.eq_any(&editor_ids))
+        .select((u::id, u::name))
+        .load::<(u64, String)>(&mut c)?
+        .into_iter()
+        .collect();
+
+    let out: Vec<RevisionOut> = revisions
+        .into_iter()
+        .map(|r| RevisionOut {
+            editor_name: names
+                .get(&r.editor_id)
+                .cloned()
+                .unwrap_or_else(|| "Unknown".into()),
+            revision: r.revision,
+            title: r.title,
+            body_md: r.body_md,
+            editor_id: r.editor_id,
+            restored_from: r.restored_from,
+            created_at: r.created_at,
+        })
+        .collect();
+    Ok(HttpResponse::Ok().json(out))
+}
+
+/// Compare two announcement revisions
+///
+/// Returns a line diff of the Markdown body and both titles between any two revisions
+/// of the announcement. Available to anyone who can see the announcement.
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/{id}/revisions/diff",
+    params(
+        ("id" = u64, Path, description = "Announcement ID"),
+        DiffQuery
+    ),
+    responses(
+        (status = 200, description = "Diff between the two revisions", body = RevisionDiff),
+        (status = 403, description = "Forbidden - announcement not addressed to the user"),
+        (status = 404, description = "Announcement or revision not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn revision_diff(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+    query: web::Query<DiffQuery>,
+) -> Result<HttpResponse, AppError> {
+    let id = path.into_inner();
+    let mut c = conn(&pool)?;
+    load_visible(id, &auth, &mut c)?;
+
+    let from = load_revision(id, query.from, &mut c)?;
+    let to = load_revision(id, query.to, &mut c)?;
+    Ok(HttpResponse::Ok().json(RevisionDiff {
+        announcement_id: id,
+        from: from.revision,
+        to: to.revision,
+        lines: diff_lines(&from.body_md, &to.body_md),
+        title_from: from.title,
+        title_to: to.title,
+    }))
+}
+
+/// Restore an announcement revision
+///
+/// Brings back the title and body of an older revision. The restore is recorded as a new
+/// revision, so the history is never rewritten. Accessible by Admin, Manager, or the author.
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/{id}/revisions/{revision}/restore",
+    params(
+        ("id" = u64, Path, description = "Announcement ID"),
+        ("revision" = u32, Path, description = "Revision number to restore")
+    ),
+    responses(
+        (status = 200, description = "Announcement with the restored content", body = super::types::AnnouncementOut),
+        (status = 403, description = "Forbidden - requires Admin, Manager, or author"),
+        (status = 404, description = "Announcement or revision not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn restore_revision(
+    pool: web::Data<DbPool>,
+    keys: web::Data<JwtKeys>,
+    auth: AuthContext,
+    path: web::Path<(u64, u32)>,
+) -> Result<HttpResponse, AppError> {
+    use announcements::dsl as a;
+    let (id, revision) = path.into_inner();
+    let user_id = auth.user_id()?;
+    let mut c = conn(&pool)?;
+    let ann = a::announcements
+        .filter(a::id.eq(id))
+        .first::<Announcement>(&mut c)?;
+    let is_author = ann.author_id == user_id;
+    if !(is_author || auth.has_any_role(&["Admin", "Manager"])) {
+        return Err(AppError::Forbidden);
+    }
+    if ann.is_deleted {
+        return Err(AppError::NotFound);
+    }
+
+    let old = load_revision(id, revision, &mut c)?;
+    if old.title != ann.title || old.body_md != ann.body_md {
+        diesel::update(a::announcements.filter(a::id.eq(id)))
+            .set((
+                a::title.eq(&old.title),
+                a::body_md.eq(&old.body_md),
+                a::body_html.eq(render_body(id, &old.body_md, &mut c)?),
+                a::updated_at.eq(Some(Utc::now().naive_utc())),
+            ))
+            .execute(&mut c)?;
+        record_revision(
+            id,
+            &old.title,
+            &old.body_md,
+            user_id,
+            Some(old.revision),
+            &mut c,
+        )?;
+    }
+
+    let restored = a::announcements
+        .filter(a::id.eq(id))
+        .first::<Announcement>(&mut c)?;
+    Ok(HttpResponse::Ok().json(enrich_one(restored, &keys, &mut c)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ops(lines: &[DiffLine]) -> Vec<(&DiffOp, &str)> {
+        lines.iter().map(|l| (&l.op, l.text.as_str())).collect()
+    }
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        let lines = diff_lines(
+            "Water outage\nFrom 8:00\nBring buckets",
+            "Water outage\nFrom 9:00\nBring buckets",
+        );
+        assert_eq!(
+            ops(&lines),
+            vec![
+                (&DiffOp::Equal, "Water outage"),
+                (&DiffOp::Delete, "From 8:00"),
+                (&DiffOp::Insert, "From 9:00"),
+                (&DiffOp::Equal, "Bring buckets"),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_handles_appended_and_removed_tails() {
+        let lines = diff_lines("a\nb", "a\nb\nc");
+        assert_eq!(ops(&lines).last(), Some(&(&DiffOp::Insert, "c")));
+
+        let lines = diff_lines("a\nb\nc", "");
+        assert!(lines.iter().all(|l| l.op == DiffOp::Delete));
+        assert_eq!(lines.len(), 3);
+    }
+}
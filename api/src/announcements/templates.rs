@@ -0,0 +1,494 @@
+//! Reusable announcement templates.
+//!
+//! A template holds a title and body, optionally in several languages, with `{{name}}`
+//! placeholders. Applying it fills the built-in placeholders (`building_address`, `today`,
+//! `publish_date`, `expire_date`) and any values given by the caller, producing a draft the
+//! editor turns into an announcement.
+
+use super::helpers::conn;
+use super::translations::validate_languages;
+use super::types::{
+    ApplyTemplateRequest, LocalizedText, TemplateDraftOut, TemplateOut, TemplateRequest,
+};
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::i18n::{DEFAULT_LANG, SUPPORTED_LANGUAGES};
+use crate::models::{AnnouncementTemplate, AnnouncementTemplateTranslation};
+use crate::schema::{announcements_templates, announcements_templates_translations};
+use actix_web::{HttpResponse, web};
+use chrono::{NaiveDate, Utc};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Copy `text`, replacing each `{{name}}` marker for which `value` returns something.
+/// Other markers are kept as written.
+fn substitute(text: &str, mut value: impl FnMut(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let end = start + 2 + len + 2;
+        let name = rest[start + 2..end - 2].trim();
+        out.push_str(&rest[..start]);
+        match is_placeholder_name(name).then(|| value(name)).flatten() {
+            Some(v) => out.push_str(&v),
+            None => out.push_str(&rest[start..end]),
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Placeholder names used in `texts`, in order of first use.
+fn placeholders<'a>(texts: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for text in texts {
+        substitute(text, |name| {
+            if !names.iter().any(|n| n == name) {
+                names.push(name.to_string());
+            }
+            None
+        });
+    }
+    names
+}
+
+/// Fill placeholders from `values`, recording the names that have none in `missing`.
+fn fill(text: &str, values: &HashMap<String, String>, missing: &mut BTreeSet<String>) -> String {
+    substitute(text, |name| {
+        let v = values.get(name).cloned();
+        if v.is_none() {
+            missing.insert(name.to_string());
+        }
+        v
+    })
+}
+
+/// A date the way it is written in `lang`.
+fn format_date(date: NaiveDate, lang: &str) -> String {
+    match lang {
+        "cs" => date.format("%-d. %-m. %Y").to_string(),
+        _ => date.format("%-d %B %Y").to_string(),
+    }
+}
+
+/// Built-in placeholder values for one language, overridden by the caller's own values.
+fn values_for(
+    lang: &str,
+    building_address: Option<&str>,
+    req: &ApplyTemplateRequest,
+) -> HashMap<String, String> {
+    let mut values = HashMap::new();
+    values.insert(
+        "today".to_string(),
+        format_date(Utc::now().date_naive(), lang),
+    );
+    if let Some(address) = building_address {
+        values.insert("building_address".to_string(), address.to_string());
+    }
+    if let Some(at) = req.publish_at {
+        values.insert("publish_date".to_string(), format_date(at.date(), lang));
+    }
+    if let Some(at) = req.expire_at {
+        values.insert("expire_date".to_string(), format_date(at.date(), lang));
+    }
+    values.extend(req.values.iter().map(|(k, v)| (k.clone(), v.clone())));
+    values
+}
+
+fn validate(body: &TemplateRequest) -> Result<String, AppError> {
+    let name = body.name.trim();
+    if name.is_empty() || name.chars().count() > 120 {
+        return Err(AppError::BadRequest("invalid_template_name".into()));
+    }
+    if body.title.trim().is_empty() {
+        return Err(AppError::BadRequest("title_required".into()));
+    }
+    let language = body.language.as_deref().unwrap_or(DEFAULT_LANG);
+    validate_languages(language, &body.translations)?;
+    Ok(name.to_string())
+}
+
+fn ensure_name_free(
+    name: &str,
+    except: Option<u64>,
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use announcements_templates::dsl as tp;
+    let existing: Option<u64> = tp::announcements_templates
+        .filter(tp::name.eq(name))
+        .select(tp::id)
+        .first(conn)
+        .optional()?;
+    match existing {
+        Some(id) if Some(id) != except => Err(AppError::BadRequest("template_name_taken".into())),
+        _ => Ok(()),
+    }
+}
+
+fn save_template_translations(
+    template_id: u64,
+    translations: &[LocalizedText],
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use announcements_templates_translations::dsl as tt;
+    diesel::delete(
+        tt::announcements_templates_translations.filter(tt::template_id.eq(template_id)),
+    )
+    .execute(conn)?;
+    let rows: Vec<AnnouncementTemplateTranslation> = translations
+        .iter()
+        .map(|t| AnnouncementTemplateTranslation {
+            template_id,
+            language: t.language.clone(),
+            title: t.title.clone(),
+            body_md: t.body_md.clone(),
+        })
+        .collect();
+    if !rows.is_empty() {
+        diesel::insert_into(tt::announcements_templates_translations)
+            .values(&rows)
+            .execute(conn)?;
+    }
+    Ok(())
+}
+
+fn template_outs(
+    templates: Vec<AnnouncementTemplate>,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<TemplateOut>, AppError> {
+    use announcements_templates_translations::dsl as tt;
+    let ids: Vec<u64> = templates.iter().map(|t| t.id).collect();
+    let mut by_template: HashMap<u64, Vec<LocalizedText>> = HashMap::new();
+    if !ids.is_empty() {
+        let mut rows: Vec<AnnouncementTemplateTranslation> =
+            tt::announcements_templates_translations
+                .filter(tt::template_id.eq_any(&ids))
+                .select(AnnouncementTemplateTranslation::as_select())
+                .load(conn)?;
+        rows.sort_by_key(|t| SUPPORTED_LANGUAGES.iter().position(|s| *s == t.language));
+        for t in rows {
+            by_template
+                .entry(t.template_id)
+                .or_default()
+                .push(LocalizedText {
+                    language: t.language,
+                    title: t.title,
+                    body_md: t.body_md,
+                });
+        }
+    }
+    Ok(templates
+        .into_iter()
+        .map(|t| {
+            let translations = by_template.remove(&t.id).unwrap_or_default();
+            let placeholders = placeholders(
+                [t.title.as_str(), t.body_md.as_str()].into_iter().chain(
+                    translations
+                        .iter()
+                        .flat_map(|tr| [tr.title.as_str(), tr.body_md.as_str()]),
+                ),
+            );
+            TemplateOut {
+                id: t.id,
+                name: t.name,
+                language: t.language,
+                title: t.title,
+                body_md: t.body_md,
+                translations,
+                placeholders,
+                created_by: t.created_by,
+                created_at: t.created_at,
+                updated_at: t.updated_at,
+            }
+        })
+        .collect())
+}
+
+fn load_template(id: u64, conn: &mut MysqlConnection) -> Result<TemplateOut, AppError> {
+    use announcements_templates::dsl as tp;
+    let template = tp::announcements_templates
+        .filter(tp::id.eq(id))
+        .select(AnnouncementTemplate::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+    template_outs(vec![template], conn)?
+        .pop()
+        .ok_or_else(|| AppError::Internal("template".into()))
+}
+
+/// List announcement templates
+///
+/// Returns all templates ordered by name, with the placeholders each one uses.
+/// Requires Admin or Manager role.
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/templates",
+    responses(
+        (status = 200, description = "Templates", body = Vec<TemplateOut>),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_templates(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+) -> Result<HttpResponse, AppError> {
+    auth.require_roles(&["Admin", "Manager"])?;
+    use announcements_templates::dsl as tp;
+    let mut c = conn(&pool)?;
+    let templates = tp::announcements_templates
+        .order(tp::name.asc())
+        .select(AnnouncementTemplate::as_select())
+        .load(&mut c)?;
+    Ok(HttpResponse::Ok().json(template_outs(templates, &mut c)?))
+}
+
+/// Create an announcement template
+///
+/// Title and body may contain `{{name}}` placeholders. Template names are unique.
+/// Requires Admin or Manager role.
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/templates",
+    request_body = TemplateRequest,
+    responses(
+        (status = 201, description = "Template created", body = TemplateOut),
+        (status = 400, description = "Invalid or duplicate name, or unsupported language"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_template(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    body: web::Json<TemplateRequest>,
+) -> Result<HttpResponse, AppError> {
+    auth.require_roles(&["Admin", "Manager"])?;
+    use announcements_templates::dsl as tp;
+    let name = validate(&body)?;
+    let mut c = conn(&pool)?;
+    ensure_name_free(&name, None, &mut c)?;
+    diesel::insert_into(tp::announcements_templates)
+        .values((
+            tp::name.eq(&name),
+            tp::language.eq(body.language.as_deref().unwrap_or(DEFAULT_LANG)),
+            tp::title.eq(&body.title),
+            tp::body_md.eq(&body.body_md),
+            tp::created_by.eq(auth.user_id()?),
+        ))
+        .execute(&mut c)?;
+    let id: u64 = tp::announcements_templates
+        .order(tp::id.desc())
+        .select(tp::id)
+        .first(&mut c)?;
+    save_template_translations(id, &body.translations, &mut c)?;
+    Ok(HttpResponse::Created().json(load_template(id, &mut c)?))
+}
+
+/// Update an announcement template
+///
+/// Replaces the name, texts and translations of a template. Requires Admin or Manager role.
+#[utoipa::path(
+    put,
+    path = "/api/v1/announcements/templates/{template_id}",
+    params(
+        ("template_id" = u64, Path, description = "Template ID")
+    ),
+    request_body = TemplateRequest,
+    responses(
+        (status = 200, description = "Template updated", body = TemplateOut),
+        (status = 400, description = "Invalid or duplicate name, or unsupported language"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Template not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_template(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+    body: web::Json<TemplateRequest>,
+) -> Result<HttpResponse, AppError> {
+    auth.require_roles(&["Admin", "Manager"])?;
+    use announcements_templates::dsl as tp;
+    let id = path.into_inner();
+    let name = validate(&body)?;
+    let mut c = conn(&pool)?;
+    load_template(id, &mut c)?;
+    ensure_name_free(&name, Some(id), &mut c)?;
+    diesel::update(tp::announcements_templates.filter(tp::id.eq(id)))
+        .set((
+            tp::name.eq(&name),
+            tp::language.eq(body.language.as_deref().unwrap_or(DEFAULT_LANG)),
+            tp::title.eq(&body.title),
+            tp::body_md.eq(&body.body_md),
+        ))
+        .execute(&mut c)?;
+    save_template_translations(id, &body.translations, &mut c)?;
+    Ok(HttpResponse::Ok().json(load_template(id, &mut c)?))
+}
+
+/// Delete an announcement template
+///
+/// Announcements created from the template are not affected. Requires Admin or Manager role.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/announcements/templates/{template_id}",
+    params(
+        ("template_id" = u64, Path, description = "Template ID")
+    ),
+    responses(
+        (status = 204, description = "Template deleted"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Template not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_template(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, AppError> {
+    auth.require_roles(&["Admin", "Manager"])?;
+    use announcements_templates::dsl as tp;
+    use announcements_templates_translations::dsl as tt;
+    let id = path.into_inner();
+    let mut c = conn(&pool)?;
+    diesel::delete(tt::announcements_templates_translations.filter(tt::template_id.eq(id)))
+        .execute(&mut c)?;
+    let affected =
+        diesel::delete(tp::announcements_templates.filter(tp::id.eq(id))).execute(&mut c)?;
+    if affected == 0 {
+        return Err(AppError::NotFound);
+    }
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Fill in an announcement template
+///
+/// Replaces the placeholders of every language version: `building_address` from
+/// `building_id`, `today`, `publish_date` and `expire_date` (written the way each language
+/// writes dates), then the caller's `values`. Placeholders without a value are kept and
+/// listed in `missing`. Nothing is stored; the result is meant for the editor.
+/// Requires Admin or Manager role.
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/templates/{template_id}/apply",
+    params(
+        ("template_id" = u64, Path, description = "Template ID")
+    ),
+    request_body = ApplyTemplateRequest,
+    responses(
+        (status = 200, description = "Filled-in draft", body = TemplateDraftOut),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Template or building not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn apply_template(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+    body: web::Json<ApplyTemplateRequest>,
+) -> Result<HttpResponse, AppError> {
+    auth.require_roles(&["Admin", "Manager"])?;
+    use crate::schema::buildings::dsl as b;
+    let mut c = conn(&pool)?;
+    let template = load_template(path.into_inner(), &mut c)?;
+    let address: Option<String> = match body.building_id {
+        Some(building_id) => Some(
+            b::buildings
+                .filter(b::id.eq(building_id))
+                .filter(b::is_deleted.eq(false))
+                .select(b::address)
+                .first(&mut c)
+                .optional()?
+                .ok_or(AppError::NotFound)?,
+        ),
+        None => None,
+    };
+
+    let mut missing = BTreeSet::new();
+    let values = values_for(&template.language, address.as_deref(), &body);
+    let title = fill(&template.title, &values, &mut missing);
+    let body_md = fill(&template.body_md, &values, &mut missing);
+    let translations = template
+        .translations
+        .iter()
+        .map(|t| {
+            let values = values_for(&t.language, address.as_deref(), &body);
+            LocalizedText {
+                language: t.language.clone(),
+                title: fill(&t.title, &values, &mut missing),
+                body_md: fill(&t.body_md, &values, &mut missing),
+            }
+        })
+        .collect();
+    Ok(HttpResponse::Ok().json(TemplateDraftOut {
+        language: template.language,
+        title,
+        body_md,
+        translations,
+        missing: missing.into_iter().collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_known_placeholders_and_reports_the_rest() {
+        let values = HashMap::from([
+            ("building_address".to_string(), "Dlouhá 5".to_string()),
+            ("date".to_string(), "1. 10. 2026".to_string()),
+        ]);
+        let mut missing = BTreeSet::new();
+        let out = fill(
+            "Cleaning at {{ building_address }} on {{date}} by {{company}}; {{Not A Name}} {{open",
+            &values,
+            &mut missing,
+        );
+        assert_eq!(
+            out,
+            "Cleaning at Dlouhá 5 on 1. 10. 2026 by {{company}}; {{Not A Name}} {{open"
+        );
+        assert_eq!(missing.into_iter().collect::<Vec<_>>(), vec!["company"]);
+    }
+
+    #[test]
+    fn lists_placeholders_in_order_of_first_use() {
+        let names = placeholders(["{{b}} {{a}}", "{{ a }} {{c}} {{b}}"]);
+        assert_eq!(names, vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn dates_follow_the_language() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        assert_eq!(format_date(date, "cs"), "1. 10. 2026");
+        assert_eq!(format_date(date, "en"), "1 October 2026");
+    }
+}
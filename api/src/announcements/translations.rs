@@ -0,0 +1,227 @@
+//! Announcement bodies in several languages.
+//!
+//! An announcement's own title and body are in its `language`; versions in other
+//! languages live in `announcements_translations`. Readers get the version matching the
+//! language negotiated from their `Accept-Language` header, or the default one.
+
+use super::attachments::{load_editable, render_body, sign_body};
+use super::helpers::conn;
+use super::types::{AnnouncementOut, AnnouncementTranslationsOut, LocalizedText};
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::i18n::{SUPPORTED_LANGUAGES, negotiate_language};
+use crate::models::AnnouncementTranslation;
+use crate::schema::announcements_translations;
+use actix_web::{HttpRequest, HttpResponse, http::header, web};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::HashMap;
+
+/// Language negotiated from the request's `Accept-Language` header.
+pub(super) fn reader_language(req: &HttpRequest) -> String {
+    negotiate_language(
+        req.headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|h| h.to_str().ok()),
+    )
+}
+
+/// Check a default language and the languages of its translations: all supported,
+/// and each language at most once.
+pub(super) fn validate_languages(
+    default: &str,
+    translations: &[LocalizedText],
+) -> Result<(), AppError> {
+    if !SUPPORTED_LANGUAGES.contains(&default) {
+        return Err(AppError::BadRequest("unsupported_language".into()));
+    }
+    let mut seen = vec![default];
+    for t in translations {
+        if !SUPPORTED_LANGUAGES.contains(&t.language.as_str()) {
+            return Err(AppError::BadRequest("unsupported_language".into()));
+        }
+        if seen.contains(&t.language.as_str()) {
+            return Err(AppError::BadRequest("duplicate_language".into()));
+        }
+        if t.title.trim().is_empty() {
+            return Err(AppError::BadRequest("translation_title_required".into()));
+        }
+        seen.push(&t.language);
+    }
+    Ok(())
+}
+
+/// Replace all translations of an announcement.
+pub(super) fn save_translations(
+    announcement_id: u64,
+    translations: &[LocalizedText],
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use announcements_translations::dsl as tr;
+    diesel::delete(tr::announcements_translations.filter(tr::announcement_id.eq(announcement_id)))
+        .execute(conn)?;
+    for t in translations {
+        let html = render_body(announcement_id, &t.body_md, conn)?;
+        diesel::insert_into(tr::announcements_translations)
+            .values((
+                tr::announcement_id.eq(announcement_id),
+                tr::language.eq(&t.language),
+                tr::title.eq(&t.title),
+                tr::body_md.eq(&t.body_md),
+                tr::body_html.eq(html),
+            ))
+            .execute(conn)?;
+    }
+    Ok(())
+}
+
+/// Re-render translated bodies, e.g. after attachments were added or removed.
+pub(super) fn refresh_translations(
+    announcement_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use announcements_translations::dsl as tr;
+    let rows: Vec<(String, String)> = tr::announcements_translations
+        .filter(tr::announcement_id.eq(announcement_id))
+        .select((tr::language, tr::body_md))
+        .load(conn)?;
+    for (language, body_md) in rows {
+        let html = render_body(announcement_id, &body_md, conn)?;
+        diesel::update(
+            tr::announcements_translations
+                .filter(tr::announcement_id.eq(announcement_id))
+                .filter(tr::language.eq(language)),
+        )
+        .set(tr::body_html.eq(html))
+        .execute(conn)?;
+    }
+    Ok(())
+}
+
+/// Translated languages of each announcement, in supported-language order.
+pub(super) fn translated_languages(
+    ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<HashMap<u64, Vec<String>>, AppError> {
+    use announcements_translations::dsl as tr;
+    let mut out: HashMap<u64, Vec<String>> = HashMap::new();
+    if ids.is_empty() {
+        return Ok(out);
+    }
+    let rows: Vec<(u64, String)> = tr::announcements_translations
+        .filter(tr::announcement_id.eq_any(ids))
+        .select((tr::announcement_id, tr::language))
+        .load(conn)?;
+    for (id, language) in rows {
+        out.entry(id).or_default().push(language);
+    }
+    for langs in out.values_mut() {
+        langs.sort_by_key(|l| SUPPORTED_LANGUAGES.iter().position(|s| s == l));
+    }
+    Ok(out)
+}
+
+/// Swap in the `lang` version of each announcement that has one.
+pub(super) fn localize(
+    items: &mut [AnnouncementOut],
+    lang: &str,
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use announcements_translations::dsl as tr;
+    let ids: Vec<u64> = items
+        .iter()
+        .filter(|a| a.language != lang && a.languages.iter().any(|l| l == lang))
+        .map(|a| a.id)
+        .collect();
+    if ids.is_empty() {
+        return Ok(());
+    }
+    let mut found: HashMap<u64, AnnouncementTranslation> = tr::announcements_translations
+        .filter(tr::announcement_id.eq_any(&ids))
+        .filter(tr::language.eq(lang))
+        .select(AnnouncementTranslation::as_select())
+        .load(conn)?
+        .into_iter()
+        .map(|t| (t.announcement_id, t))
+        .collect();
+    for item in items.iter_mut() {
+        if let Some(t) = found.remove(&item.id) {
+            item.body_html = sign_body(item.id, &t.body_html, &item.attachments);
+            item.title = t.title;
+            item.body_md = t.body_md;
+            item.language = t.language;
+        }
+    }
+    Ok(())
+}
+
+/// Get all language versions of an announcement
+///
+/// Returns the default title and body together with every translation, for editing.
+/// Accessible by Admin, Manager, or the announcement author.
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/{id}/translations",
+    params(
+        ("id" = u64, Path, description = "Announcement ID")
+    ),
+    responses(
+        (status = 200, description = "Language versions", body = AnnouncementTranslationsOut),
+        (status = 403, description = "Forbidden - requires Admin, Manager, or author"),
+        (status = 404, description = "Announcement not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Announcements",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_translations(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, AppError> {
+    use announcements_translations::dsl as tr;
+    let id = path.into_inner();
+    let mut c = conn(&pool)?;
+    let ann = load_editable(id, &auth, &mut c)?;
+    let mut translations: Vec<AnnouncementTranslation> = tr::announcements_translations
+        .filter(tr::announcement_id.eq(id))
+        .select(AnnouncementTranslation::as_select())
+        .load(&mut c)?;
+    translations.sort_by_key(|t| SUPPORTED_LANGUAGES.iter().position(|s| *s == t.language));
+    Ok(HttpResponse::Ok().json(AnnouncementTranslationsOut {
+        language: ann.language,
+        title: ann.title,
+        body_md: ann.body_md,
+        translations: translations
+            .into_iter()
+            .map(|t| LocalizedText {
+                language: t.language,
+                title: t.title,
+                body_md: t.body_md,
+            })
+            .collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(language: &str) -> LocalizedText {
+        LocalizedText {
+            language: language.into(),
+            title: "Title".into(),
+            body_md: String::new(),
+        }
+    }
+
+    #[test]
+    fn languages_must_be_supported_and_distinct() {
+        assert!(validate_languages("en", &[text("cs")]).is_ok());
+        assert!(validate_languages("en", &[]).is_ok());
+        assert!(validate_languages("de", &[]).is_err());
+        assert!(validate_languages("en", &[text("de")]).is_err());
+        assert!(validate_languages("en", &[text("en")]).is_err());
+        assert!(validate_languages("en", &[text("cs"), text("cs")]).is_err());
+    }
+}
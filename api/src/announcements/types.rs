@@ -7,12 +7,25 @@ pub struct CreateAnnouncementRequest {
     pub body_md: String,
     pub public: bool,
     pub pinned: bool,
-    pub roles_csv: Option<String>,
-    pub building_id: Option<u64>,
-    pub apartment_id: Option<u64>,
+    /// Role names the announcement is restricted to (private announcements only)
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub building_ids: Vec<u64>,
+    #[serde(default)]
+    pub apartment_ids: Vec<u64>,
     pub comments_enabled: bool,
+    /// Residents must explicitly confirm they have read it
+    #[serde(default)]
+    pub requires_ack: bool,
     pub publish_at: Option<chrono::NaiveDateTime>,
     pub expire_at: Option<chrono::NaiveDateTime>,
+    /// Language of `title` and `body_md`; defaults to the site default
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Versions in other languages
+    #[serde(default)]
+    pub translations: Vec<LocalizedText>,
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -21,12 +34,86 @@ pub struct UpdateAnnouncementRequest {
     pub body_md: Option<String>,
     pub public: Option<bool>,
     pub pinned: Option<bool>,
-    pub roles_csv: Option<Option<String>>, // double option to allow clearing
-    pub building_id: Option<Option<u64>>,
-    pub apartment_id: Option<Option<u64>>,
+    /// Replaces the role targets; an empty list removes the restriction
+    pub roles: Option<Vec<String>>,
+    pub building_ids: Option<Vec<u64>>,
+    pub apartment_ids: Option<Vec<u64>>,
     pub comments_enabled: Option<bool>,
+    pub requires_ack: Option<bool>,
     pub publish_at: Option<Option<chrono::NaiveDateTime>>,
     pub expire_at: Option<Option<chrono::NaiveDateTime>>,
+    pub language: Option<String>,
+    /// Replaces all versions in other languages
+    pub translations: Option<Vec<LocalizedText>>,
+}
+
+/// Title and body in one language.
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct LocalizedText {
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
+}
+
+/// All language versions of an announcement, for editing.
+#[derive(Serialize, ToSchema)]
+pub struct AnnouncementTranslationsOut {
+    /// Default language, used when no version matches the reader's
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
+    pub translations: Vec<LocalizedText>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct TemplateRequest {
+    pub name: String,
+    pub title: String,
+    pub body_md: String,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub translations: Vec<LocalizedText>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct TemplateOut {
+    pub id: u64,
+    pub name: String,
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
+    pub translations: Vec<LocalizedText>,
+    /// Placeholders used anywhere in the template
+    pub placeholders: Vec<String>,
+    pub created_by: u64,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+/// Values for a template's placeholders.
+#[derive(Deserialize, ToSchema)]
+pub struct ApplyTemplateRequest {
+    /// Fills `{{building_address}}`
+    pub building_id: Option<u64>,
+    /// Fills `{{publish_date}}`
+    pub publish_at: Option<chrono::NaiveDateTime>,
+    /// Fills `{{expire_date}}`
+    pub expire_at: Option<chrono::NaiveDateTime>,
+    /// Any other placeholders, by name; also overrides the built-in ones
+    #[serde(default)]
+    pub values: std::collections::HashMap<String, String>,
+}
+
+/// A template with its placeholders filled in, ready to become an announcement.
+#[derive(Serialize, ToSchema)]
+pub struct TemplateDraftOut {
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
+    pub translations: Vec<LocalizedText>,
+    /// Placeholders left in the text because no value was given
+    pub missing: Vec<String>,
 }
 
 #[derive(Deserialize, IntoParams)]
@@ -34,9 +121,64 @@ pub struct CommentsQuery {
     pub include_deleted: Option<bool>,
 }
 
+#[derive(Deserialize, IntoParams)]
+pub struct DiffQuery {
+    /// Older revision number
+    pub from: u32,
+    /// Newer revision number
+    pub to: u32,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct AttachmentQuery {
+    /// Signed link token from an attachment URL; not needed with a bearer token
+    pub token: Option<String>,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct ReportsQuery {
+    /// Report status to list: open (default), dismissed or actioned
+    pub status: Option<String>,
+}
+
 #[derive(Deserialize, ToSchema)]
 pub struct CreateCommentRequest {
     pub body_md: String,
+    /// Comment being replied to; must belong to the same announcement
+    #[serde(default)]
+    pub parent_id: Option<u64>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct UpdateCommentRequest {
+    pub body_md: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ReactionRequest {
+    /// One of the supported reaction emoji
+    pub emoji: String,
+}
+
+/// Reason given when reporting or hiding a comment.
+#[derive(Deserialize, ToSchema)]
+pub struct ModerationReasonRequest {
+    pub reason: String,
+}
+
+/// Building an announcement is targeted at.
+#[derive(Serialize, ToSchema)]
+pub struct AudienceBuilding {
+    pub id: u64,
+    pub address: String,
+}
+
+/// Apartment an announcement is targeted at.
+#[derive(Serialize, ToSchema)]
+pub struct AudienceApartment {
+    pub id: u64,
+    pub building_id: u64,
+    pub number: String,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -49,27 +191,240 @@ pub struct AnnouncementOut {
     pub author_name: String,
     pub public: bool,
     pub pinned: bool,
-    pub roles_csv: Option<String>,
-    pub building_id: Option<u64>,
-    pub building_address: Option<String>,
-    pub apartment_id: Option<u64>,
-    pub apartment_number: Option<String>,
+    pub roles: Vec<String>,
+    pub buildings: Vec<AudienceBuilding>,
+    pub apartments: Vec<AudienceApartment>,
     pub comments_enabled: bool,
+    pub requires_ack: bool,
     pub publish_at: Option<chrono::NaiveDateTime>,
     pub expire_at: Option<chrono::NaiveDateTime>,
     pub is_deleted: bool,
     pub created_at: Option<chrono::NaiveDateTime>,
     pub updated_at: Option<chrono::NaiveDateTime>,
+    /// When the title or body was last changed, if it has been edited since creation
+    pub edited_at: Option<chrono::NaiveDateTime>,
+    /// When the requesting user first read it (`None` for anonymous requests)
+    pub read_at: Option<chrono::NaiveDateTime>,
+    /// When the requesting user acknowledged it
+    pub acknowledged_at: Option<chrono::NaiveDateTime>,
+    /// Released files and images; `body_html` links to them with the same signed URLs
+    pub attachments: Vec<AttachmentOut>,
+    /// Language of `title` and `body_md`, negotiated from the reader's `Accept-Language`
+    pub language: String,
+    /// All languages the announcement is available in, default first
+    pub languages: Vec<String>,
+}
+
+/// A file or image attached to an announcement.
+#[derive(Serialize, ToSchema)]
+pub struct AttachmentOut {
+    pub id: u64,
+    pub original_filename: String,
+    pub mime_type: String,
+    pub size_bytes: u64,
+    /// Download URL carrying a short-lived signed token
+    pub url: String,
+    /// Thumbnail URL for images
+    pub thumbnail_url: Option<String>,
+    pub created_at: Option<chrono::NaiveDateTime>,
 }
 
 #[derive(Serialize, ToSchema)]
 pub struct CommentOut {
     pub id: u64,
     pub announcement_id: u64,
+    /// Comment this one replies to
+    pub parent_id: Option<u64>,
     pub user_id: u64,
     pub user_name: String,
+    /// Empty for hidden comments unless the viewer is a manager or the author
     pub body_md: String,
     pub body_html: String,
     pub is_deleted: bool,
+    pub is_hidden: bool,
+    pub hidden_reason: Option<String>,
     pub created_at: Option<chrono::NaiveDateTime>,
+    pub edited_at: Option<chrono::NaiveDateTime>,
+    pub reactions: Vec<ReactionSummary>,
+    pub mentions: Vec<MentionOut>,
+}
+
+/// Count of one reaction on a comment.
+#[derive(Serialize, ToSchema)]
+pub struct ReactionSummary {
+    pub emoji: String,
+    pub count: i64,
+    /// Whether the requesting user gave this reaction
+    pub reacted: bool,
+}
+
+/// A user that is, or can be, @mentioned in a comment.
+#[derive(Serialize, ToSchema)]
+pub struct MentionOut {
+    pub user_id: u64,
+    pub name: String,
+}
+
+/// A report in the moderation queue.
+#[derive(Serialize, ToSchema)]
+pub struct ReportOut {
+    pub id: u64,
+    pub reporter_id: u64,
+    pub reporter_name: String,
+    pub reason: String,
+    pub status: String,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+/// A reported comment with the reports against it.
+#[derive(Serialize, ToSchema)]
+pub struct ReportedCommentOut {
+    pub announcement_title: String,
+    pub comment: CommentOut,
+    pub reports: Vec<ReportOut>,
+}
+
+/// A member of the target audience in a receipt report.
+#[derive(Serialize, ToSchema)]
+pub struct ReceiptEntry {
+    pub user_id: u64,
+    pub name: String,
+    pub email: String,
+    pub read_at: Option<chrono::NaiveDateTime>,
+    pub acknowledged_at: Option<chrono::NaiveDateTime>,
+}
+
+/// Read/acknowledge progress for one announcement.
+#[derive(Serialize, ToSchema)]
+pub struct ReceiptReport {
+    pub announcement_id: u64,
+    pub requires_ack: bool,
+    pub audience_total: usize,
+    pub read_count: usize,
+    pub acknowledged_count: usize,
+    /// Audience members who have not read it yet, or not acknowledged it when required
+    pub pending: Vec<ReceiptEntry>,
+}
+
+/// One stored version of an announcement's title and body.
+#[derive(Serialize, ToSchema)]
+pub struct RevisionOut {
+    pub revision: u32,
+    pub title: String,
+    pub body_md: String,
+    pub editor_id: u64,
+    pub editor_name: String,
+    /// Set when this revision restored an older one
+    pub restored_from: Option<u32>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Serialize, ToSchema, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+/// A line of the Markdown body, kept, added or removed between two revisions.
+#[derive(Serialize, ToSchema, Debug, PartialEq)]
+pub struct DiffLine {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+/// Line diff of the Markdown body between two revisions.
+#[derive(Serialize, ToSchema)]
+pub struct RevisionDiff {
+    pub announcement_id: u64,
+    pub from: u32,
+    pub to: u32,
+    pub title_from: String,
+    pub title_to: String,
+    pub lines: Vec<DiffLine>,
+}
+
+/// Period and building for the engagement summary.
+#[derive(Deserialize, IntoParams)]
+pub struct AnalyticsQuery {
+    /// Only announcements addressed to this building, counting its residents
+    pub building_id: Option<u64>,
+    /// Announcements published in the last `days` days (default 90, at most 365)
+    pub days: Option<u32>,
+}
+
+/// Audience members who first read an announcement on one day.
+#[derive(Serialize, ToSchema)]
+pub struct DailyReads {
+    pub date: chrono::NaiveDate,
+    pub readers: usize,
+    /// Readers up to and including this day
+    pub cumulative: usize,
+}
+
+/// Audience and readers within one building or role.
+#[derive(Serialize, ToSchema)]
+pub struct ReachGroup {
+    /// Building ID; `None` for residents without an apartment or for roles
+    pub id: Option<u64>,
+    /// Building address or role name
+    pub label: String,
+    pub audience: usize,
+    pub readers: usize,
+}
+
+/// Engagement with one announcement.
+#[derive(Serialize, ToSchema)]
+pub struct AnnouncementAnalytics {
+    pub announcement_id: u64,
+    pub title: String,
+    pub publish_at: Option<chrono::NaiveDateTime>,
+    pub audience_total: usize,
+    /// Audience members who have read it
+    pub audience_read_count: usize,
+    /// Everyone who has read it, including readers outside the audience
+    pub reader_count: usize,
+    pub acknowledged_count: usize,
+    /// `audience_read_count / audience_total`, 0 for an empty audience
+    pub read_rate: f64,
+    pub comment_count: usize,
+    pub commenter_count: usize,
+    /// From publication to the first read by an audience member
+    pub minutes_to_first_read: Option<i64>,
+    /// Median time from publication to reading, over audience readers
+    pub median_minutes_to_read: Option<i64>,
+    pub readers_over_time: Vec<DailyReads>,
+    pub reach_by_building: Vec<ReachGroup>,
+    pub reach_by_role: Vec<ReachGroup>,
+}
+
+/// One announcement's line in the engagement summary.
+#[derive(Serialize, ToSchema)]
+pub struct AnnouncementEngagement {
+    pub announcement_id: u64,
+    pub title: String,
+    pub publish_at: Option<chrono::NaiveDateTime>,
+    pub audience_total: usize,
+    pub audience_read_count: usize,
+    pub read_rate: f64,
+    pub comment_count: usize,
+    pub minutes_to_first_read: Option<i64>,
+}
+
+/// Engagement with the announcements of a period, optionally for one building.
+#[derive(Serialize, ToSchema)]
+pub struct EngagementSummary {
+    pub building_id: Option<u64>,
+    pub days: u32,
+    pub announcement_count: usize,
+    /// Sum of the announcements' audiences
+    pub audience_total: usize,
+    pub audience_read_count: usize,
+    pub read_rate: f64,
+    pub comment_count: usize,
+    /// Median over the announcements' times to first read
+    pub median_minutes_to_first_read: Option<i64>,
+    /// Newest first
+    pub announcements: Vec<AnnouncementEngagement>,
 }
@@ -29,6 +29,10 @@ pub enum AppError {
     Expired,
     #[error("comments_disabled")]
     CommentsDisabled,
+    #[error("malware_detected")]
+    MalwareDetected,
+    #[error("rate_limited")]
+    RateLimited,
 }
 
 impl ResponseError for AppError {
@@ -43,6 +47,8 @@ impl ResponseError for AppError {
             AppError::NotPublished => StatusCode::NOT_FOUND, // hide drafts
             AppError::Expired => StatusCode::GONE,
             AppError::CommentsDisabled => StatusCode::FORBIDDEN,
+            AppError::MalwareDetected => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::RateLimited => StatusCode::TOO_MANY_REQUESTS,
             AppError::Db(_) | AppError::Internal(_) | AppError::Crypto(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
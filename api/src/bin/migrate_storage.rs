@@ -0,0 +1,424 @@
+//! Copy stored uploads between storage backends.
+//!
+//! ```text
+//! migrate-storage --from local --to s3 [--dry-run]
+//! migrate-storage --from local:/old/path --to local:/new/path
+//! ```
+//!
+//! `local` uses `ATTACHMENTS_BASE_PATH` unless a path is given; `s3` reads the
+//! `S3_*` variables. Every table that keeps files in the storage backend is listed in
+//! [`TABLES`]. Every object is checked against its stored checksum before it is
+//! copied and read back from the target afterwards; rows without a checksum get one
+//! computed from the source. Thumbnails are verified against their source copy.
+//! Objects already present with a matching checksum are skipped, so an interrupted
+//! run can simply be restarted.
+
+use api::announcements::attachments as announcement_files;
+use api::config::S3Config;
+use api::maintenance::attachments::{object_key, thumbnail_key};
+use api::meters::{photo_key, photo_thumbnail_key};
+use api::storage::{LocalStorage, S3Storage, Storage, StorageError, sha256_hex};
+use api::uploads::SCAN_CLEAN;
+use api::voting::attachments as proposal_files;
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::env;
+use std::process::ExitCode;
+
+/// A released upload as the migration sees it.
+struct Blob {
+    id: u64,
+    key: String,
+    checksum_sha256: Option<String>,
+    thumbnail_key: Option<String>,
+}
+
+/// A table whose rows point at objects in the storage backend.
+struct BlobTable {
+    name: &'static str,
+    /// Released rows only: quarantined and infected uploads have no content under
+    /// their object key
+    load: fn(&mut MysqlConnection) -> QueryResult<Vec<Blob>>,
+    /// Record a checksum computed from the source on a row that had none
+    set_checksum: fn(u64, &str, &mut MysqlConnection) -> QueryResult<usize>,
+}
+
+const TABLES: &[BlobTable] = &[
+    BlobTable {
+        name: "maintenance_request_attachments",
+        load: load_maintenance_attachments,
+        set_checksum: set_maintenance_attachment_checksum,
+    },
+    BlobTable {
+        name: "announcements_attachments",
+        load: load_announcement_attachments,
+        set_checksum: set_announcement_attachment_checksum,
+    },
+    BlobTable {
+        name: "proposal_attachments",
+        load: load_proposal_attachments,
+        set_checksum: set_proposal_attachment_checksum,
+    },
+    BlobTable {
+        name: "meter_reading_submissions",
+        load: load_submission_photos,
+        set_checksum: set_submission_photo_checksum,
+    },
+];
+
+fn load_maintenance_attachments(conn: &mut MysqlConnection) -> QueryResult<Vec<Blob>> {
+    use api::schema::maintenance_request_attachments::dsl as att;
+    let rows: Vec<(u64, u64, String, Option<String>, bool)> = att::maintenance_request_attachments
+        .filter(att::scan_status.eq(SCAN_CLEAN))
+        .order(att::id.asc())
+        .select((
+            att::id,
+            att::request_id,
+            att::stored_filename,
+            att::checksum_sha256,
+            att::has_thumbnail,
+        ))
+        .load(conn)?;
+    Ok(rows
+        .into_iter()
+        .map(
+            |(id, request_id, stored, checksum_sha256, has_thumbnail)| Blob {
+                id,
+                key: object_key(request_id, &stored),
+                checksum_sha256,
+                thumbnail_key: has_thumbnail.then(|| thumbnail_key(request_id, &stored)),
+            },
+        )
+        .collect())
+}
+
+fn set_maintenance_attachment_checksum(
+    id: u64,
+    checksum: &str,
+    conn: &mut MysqlConnection,
+) -> QueryResult<usize> {
+    use api::schema::maintenance_request_attachments::dsl as att;
+    diesel::update(att::maintenance_request_attachments.filter(att::id.eq(id)))
+        .set(att::checksum_sha256.eq(Some(checksum)))
+        .execute(conn)
+}
+
+fn load_announcement_attachments(conn: &mut MysqlConnection) -> QueryResult<Vec<Blob>> {
+    use api::schema::announcements_attachments::dsl as at;
+    let rows: Vec<(u64, u64, String, Option<String>, bool)> = at::announcements_attachments
+        .filter(at::scan_status.eq(SCAN_CLEAN))
+        .order(at::id.asc())
+        .select((
+            at::id,
+            at::announcement_id,
+            at::stored_filename,
+            at::checksum_sha256,
+            at::has_thumbnail,
+        ))
+        .load(conn)?;
+    Ok(rows
+        .into_iter()
+        .map(
+            |(id, announcement_id, stored, checksum_sha256, has_thumbnail)| Blob {
+                id,
+                key: announcement_files::object_key(announcement_id, &stored),
+                checksum_sha256,
+                thumbnail_key: has_thumbnail
+                    .then(|| announcement_files::thumbnail_key(announcement_id, &stored)),
+            },
+        )
+        .collect())
+}
+
+fn set_announcement_attachment_checksum(
+    id: u64,
+    checksum: &str,
+    conn: &mut MysqlConnection,
+) -> QueryResult<usize> {
+    use api::schema::announcements_attachments::dsl as at;
+    diesel::update(at::announcements_attachments.filter(at::id.eq(id)))
+        .set(at::checksum_sha256.eq(Some(checksum)))
+        .execute(conn)
+}
+
+fn load_proposal_attachments(conn: &mut MysqlConnection) -> QueryResult<Vec<Blob>> {
+    use api::schema::proposal_attachments::dsl as pa;
+    let rows: Vec<(u64, u64, String, Option<String>)> = pa::proposal_attachments
+        .filter(pa::scan_status.eq(SCAN_CLEAN))
+        .order(pa::id.asc())
+        .select((
+            pa::id,
+            pa::proposal_id,
+            pa::stored_filename,
+            pa::checksum_sha256,
+        ))
+        .load(conn)?;
+    // Documents keep no thumbnails
+    Ok(rows
+        .into_iter()
+        .map(|(id, proposal_id, stored, checksum_sha256)| Blob {
+            id,
+            key: proposal_files::object_key(proposal_id, &stored),
+            checksum_sha256,
+            thumbnail_key: None,
+        })
+        .collect())
+}
+
+fn set_proposal_attachment_checksum(
+    id: u64,
+    checksum: &str,
+    conn: &mut MysqlConnection,
+) -> QueryResult<usize> {
+    use api::schema::proposal_attachments::dsl as pa;
+    diesel::update(pa::proposal_attachments.filter(pa::id.eq(id)))
+        .set(pa::checksum_sha256.eq(Some(checksum)))
+        .execute(conn)
+}
+
+fn load_submission_photos(conn: &mut MysqlConnection) -> QueryResult<Vec<Blob>> {
+    use api::schema::meter_reading_submissions::dsl as s;
+    let rows: Vec<(u64, u64, String, Option<String>, bool)> = s::meter_reading_submissions
+        .filter(s::scan_status.eq(SCAN_CLEAN))
+        .order(s::id.asc())
+        .select((
+            s::id,
+            s::meter_id,
+            s::photo_stored_filename,
+            s::photo_checksum_sha256,
+            s::photo_has_thumbnail,
+        ))
+        .load(conn)?;
+    Ok(rows
+        .into_iter()
+        .map(
+            |(id, meter_id, stored, checksum_sha256, has_thumbnail)| Blob {
+                id,
+                key: photo_key(meter_id, &stored),
+                checksum_sha256,
+                thumbnail_key: has_thumbnail.then(|| photo_thumbnail_key(meter_id, &stored)),
+            },
+        )
+        .collect())
+}
+
+fn set_submission_photo_checksum(
+    id: u64,
+    checksum: &str,
+    conn: &mut MysqlConnection,
+) -> QueryResult<usize> {
+    use api::schema::meter_reading_submissions::dsl as s;
+    diesel::update(s::meter_reading_submissions.filter(s::id.eq(id)))
+        .set(s::photo_checksum_sha256.eq(Some(checksum)))
+        .execute(conn)
+}
+
+#[derive(Default)]
+struct Summary {
+    copied: u64,
+    skipped: u64,
+    backfilled: u64,
+    failed: u64,
+}
+
+fn open_backend(spec: &str) -> Result<Box<dyn Storage>, String> {
+    match spec.split_once(':') {
+        Some(("local", path)) => Ok(Box::new(LocalStorage::new(path))),
+        None if spec == "local" => {
+            let path = env::var("ATTACHMENTS_BASE_PATH").unwrap_or_else(|_| "attachments".into());
+            Ok(Box::new(LocalStorage::new(path)))
+        }
+        None if spec == "s3" => {
+            let cfg = S3Config::from_env()?;
+            S3Storage::new(&cfg)
+                .map(|s| Box::new(s) as Box<dyn Storage>)
+                .map_err(|e| e.to_string())
+        }
+        _ => Err(format!(
+            "unknown backend '{}', expected local[:PATH] or s3",
+            spec
+        )),
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("usage: migrate-storage --from <local[:PATH]|s3> --to <local[:PATH]|s3> [--dry-run]");
+    ExitCode::from(2)
+}
+
+/// Copy one object unless the target already holds the expected content.
+/// Returns `true` if a copy was made.
+async fn copy_verified(
+    from: &dyn Storage,
+    to: &dyn Storage,
+    key: &str,
+    data: Vec<u8>,
+    expected: &str,
+    dry_run: bool,
+) -> Result<bool, String> {
+    match to.get(key).await {
+        Ok(existing) if sha256_hex(&existing) == expected => return Ok(false),
+        Ok(_) | Err(StorageError::NotFound) => {}
+        Err(e) => return Err(format!("target read failed: {}", e)),
+    }
+    if dry_run {
+        return Ok(true);
+    }
+    to.put(key, data)
+        .await
+        .map_err(|e| format!("write to {} failed: {}", to.name(), e))?;
+    let written = to
+        .get(key)
+        .await
+        .map_err(|e| format!("read back from {} failed: {}", to.name(), e))?;
+    if sha256_hex(&written) != expected {
+        return Err(format!(
+            "checksum mismatch after copy from {} to {}",
+            from.name(),
+            to.name()
+        ));
+    }
+    Ok(true)
+}
+
+/// Copy one row's object and thumbnail, recording the result in `summary`.
+async fn migrate_blob(
+    table: &BlobTable,
+    row: Blob,
+    from: &dyn Storage,
+    to: &dyn Storage,
+    dry_run: bool,
+    conn: &mut MysqlConnection,
+    summary: &mut Summary,
+) {
+    let label = format!("{}[{}] {}", table.name, row.id, row.key);
+    let data = match from.get(&row.key).await {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("{}: source read failed: {}", label, e);
+            summary.failed += 1;
+            return;
+        }
+    };
+    let checksum = sha256_hex(&data);
+    match &row.checksum_sha256 {
+        Some(stored) if *stored != checksum => {
+            eprintln!("{}: source does not match stored checksum, skipping", label);
+            summary.failed += 1;
+            return;
+        }
+        Some(_) => {}
+        None => {
+            if !dry_run && let Err(e) = (table.set_checksum)(row.id, &checksum, conn) {
+                eprintln!("{}: storing checksum failed: {}", label, e);
+                summary.failed += 1;
+                return;
+            }
+            summary.backfilled += 1;
+        }
+    }
+
+    match copy_verified(from, to, &row.key, data, &checksum, dry_run).await {
+        Ok(true) => summary.copied += 1,
+        Ok(false) => summary.skipped += 1,
+        Err(e) => {
+            eprintln!("{}: {}", label, e);
+            summary.failed += 1;
+            return;
+        }
+    }
+
+    // Thumbnails carry no stored checksum; verify against the source copy instead
+    if let Some(thumb) = row.thumbnail_key {
+        let result = match from.get(&thumb).await {
+            Ok(data) => {
+                let expected = sha256_hex(&data);
+                copy_verified(from, to, &thumb, data, &expected, dry_run).await
+            }
+            Err(e) => Err(format!("source read failed: {}", e)),
+        };
+        if let Err(e) = result {
+            eprintln!("{}[{}] {}: {}", table.name, row.id, thumb, e);
+            summary.failed += 1;
+        }
+    }
+}
+
+#[actix_web::main]
+async fn main() -> ExitCode {
+    dotenvy::dotenv().ok();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut from_spec = None;
+    let mut to_spec = None;
+    let mut dry_run = false;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--from" => from_spec = iter.next().cloned(),
+            "--to" => to_spec = iter.next().cloned(),
+            "--dry-run" => dry_run = true,
+            _ => return usage(),
+        }
+    }
+    let (Some(from_spec), Some(to_spec)) = (from_spec, to_spec) else {
+        return usage();
+    };
+    if from_spec == to_spec {
+        eprintln!("--from and --to must differ");
+        return ExitCode::from(2);
+    }
+    let (from, to) = match (open_backend(&from_spec), open_backend(&to_spec)) {
+        (Ok(f), Ok(t)) => (f, t),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("{}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let mut conn =
+        MysqlConnection::establish(&database_url).expect("Failed to connect to database");
+
+    let mut summary = Summary::default();
+    for table in TABLES {
+        let rows = match (table.load)(&mut conn) {
+            Ok(rows) => rows,
+            Err(e) => {
+                eprintln!("{}: loading rows failed: {}", table.name, e);
+                summary.failed += 1;
+                continue;
+            }
+        };
+        println!(
+            "Migrating {} objects of {} from {} to {}{}",
+            rows.len(),
+            table.name,
+            from_spec,
+            to_spec,
+            if dry_run { " (dry run)" } else { "" }
+        );
+        for row in rows {
+            migrate_blob(
+                table,
+                row,
+                from.as_ref(),
+                to.as_ref(),
+                dry_run,
+                &mut conn,
+                &mut summary,
+            )
+            .await;
+        }
+    }
+
+    println!(
+        "copied: {}, already present: {}, checksums backfilled: {}, failed: {}",
+        summary.copied, summary.skipped, summary.backfilled, summary.failed
+    );
+    if summary.failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
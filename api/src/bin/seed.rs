@@ -0,0 +1,200 @@
+//! Populate the database with sample data for local development.
+//!
+//! ```text
+//! seed
+//! ```
+//!
+//! Runs pending migrations, then creates the sample users listed by
+//! `scripts/seed.sh` (all with password `password123`) and three buildings with
+//! a few apartments each. Users that already exist are left untouched and
+//! buildings are only created on an empty database, so running it twice is safe.
+
+use api::MIGRATIONS;
+use api::auth::crypto::hash_password;
+use api::auth::roles::{assign_role, ensure_role};
+use api::models::{NewApartment, NewBuilding, NewUser};
+use api::schema::{
+    apartment_owners, apartment_renters, apartments, building_managers, buildings, users,
+};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use diesel_migrations::MigrationHarness;
+use std::env;
+use std::process::ExitCode;
+
+const PASSWORD: &str = "password123";
+
+const USERS: &[(&str, &str, &str)] = &[
+    ("admin@example.com", "Admin User", "Admin"),
+    ("manager@example.com", "Manager User", "Manager"),
+    ("owner1@example.com", "Olivia Owner", "Homeowner"),
+    ("owner2@example.com", "Oscar Owner", "Homeowner"),
+    ("renter1@example.com", "Rita Renter", "Renter"),
+];
+
+/// Apartment number and size in square metres.
+type Units = &'static [(&'static str, f64)];
+
+const BUILDINGS: &[(&str, i32, Units)] = &[
+    (
+        "12 Linden Street",
+        1998,
+        &[("1", 54.0), ("2", 72.5), ("3", 88.0)],
+    ),
+    ("5 Harbour Road", 2010, &[("101", 61.0), ("102", 64.5)]),
+    (
+        "40 Chestnut Avenue",
+        1975,
+        &[("A1", 45.0), ("A2", 47.5), ("B1", 80.0), ("B2", 95.5)],
+    ),
+];
+
+/// Create the user with the given role unless the email is already taken.
+fn seed_user(
+    conn: &mut MysqlConnection,
+    email: &str,
+    name: &str,
+    role: &str,
+) -> Result<u64, String> {
+    let existing = users::table
+        .filter(users::email.eq(email))
+        .select(users::id)
+        .first::<u64>(conn)
+        .optional()
+        .map_err(|e| e.to_string())?;
+    let id = match existing {
+        Some(id) => id,
+        None => {
+            let password_hash = hash_password(PASSWORD).map_err(|e| e.to_string())?;
+            diesel::insert_into(users::table)
+                .values(&NewUser {
+                    email: email.to_string(),
+                    name: name.to_string(),
+                    password_hash,
+                })
+                .execute(conn)
+                .map_err(|e| e.to_string())?;
+            users::table
+                .filter(users::email.eq(email))
+                .select(users::id)
+                .first::<u64>(conn)
+                .map_err(|e| e.to_string())?
+        }
+    };
+    let role_id = ensure_role(role, conn).map_err(|e| e.to_string())?;
+    assign_role(id, role_id, conn).map_err(|e| e.to_string())?;
+    Ok(id)
+}
+
+fn seed(conn: &mut MysqlConnection) -> Result<(), String> {
+    let mut user_ids = Vec::with_capacity(USERS.len());
+    for (email, name, role) in USERS {
+        user_ids.push(seed_user(conn, email, name, role)?);
+        println!("user {} ({})", email, role);
+    }
+    let [_, manager, owner1, owner2, renter1] = user_ids[..] else {
+        unreachable!("USERS has five entries");
+    };
+
+    let building_count: i64 = buildings::table
+        .count()
+        .get_result(conn)
+        .map_err(|e| e.to_string())?;
+    if building_count > 0 {
+        println!("buildings already present, skipping");
+        return Ok(());
+    }
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        let mut apartment_ids = Vec::new();
+        for (address, year, units) in BUILDINGS {
+            diesel::insert_into(buildings::table)
+                .values(&NewBuilding {
+                    address: address.to_string(),
+                    construction_year: Some(*year),
+                })
+                .execute(conn)?;
+            let building_id: u64 = buildings::table
+                .filter(buildings::address.eq(address))
+                .select(buildings::id)
+                .first(conn)?;
+            diesel::insert_into(building_managers::table)
+                .values((
+                    building_managers::building_id.eq(building_id),
+                    building_managers::user_id.eq(manager),
+                ))
+                .execute(conn)?;
+            for (number, size) in *units {
+                diesel::insert_into(apartments::table)
+                    .values(&NewApartment {
+                        building_id,
+                        number: number.to_string(),
+                        size_sq_m: Some(*size),
+                        bedrooms: Some(if *size > 70.0 { 3 } else { 2 }),
+                        bathrooms: Some(1),
+                    })
+                    .execute(conn)?;
+                let apartment_id: u64 = apartments::table
+                    .filter(apartments::building_id.eq(building_id))
+                    .filter(apartments::number.eq(number))
+                    .select(apartments::id)
+                    .first(conn)?;
+                apartment_ids.push(apartment_id);
+            }
+            println!("building {} with {} apartments", address, units.len());
+        }
+
+        // Owners split the first building; the first owner also rents out one unit
+        let owners = [
+            (apartment_ids[0], owner1),
+            (apartment_ids[1], owner1),
+            (apartment_ids[2], owner2),
+            (apartment_ids[3], owner2),
+        ];
+        for (apartment_id, user_id) in owners {
+            diesel::insert_into(apartment_owners::table)
+                .values((
+                    apartment_owners::apartment_id.eq(apartment_id),
+                    apartment_owners::user_id.eq(user_id),
+                ))
+                .execute(conn)?;
+        }
+        diesel::insert_into(apartment_renters::table)
+            .values((
+                apartment_renters::apartment_id.eq(apartment_ids[1]),
+                apartment_renters::user_id.eq(renter1),
+                apartment_renters::start_date.eq(chrono::Utc::now().date_naive()),
+                apartment_renters::is_active.eq(true),
+            ))
+            .execute(conn)?;
+        Ok(())
+    })
+    .map_err(|e| e.to_string())
+}
+
+fn main() -> ExitCode {
+    dotenvy::dotenv().ok();
+
+    let Ok(database_url) = env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL must be set");
+        return ExitCode::from(2);
+    };
+    let mut conn = match MysqlConnection::establish(&database_url) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("database connection failed: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(e) = conn.run_pending_migrations(MIGRATIONS) {
+        eprintln!("migrations failed: {}", e);
+        return ExitCode::FAILURE;
+    }
+    match seed(&mut conn) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("seeding failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -1,10 +1,321 @@
+use std::collections::HashMap;
 use std::env;
+use std::fmt;
+use std::time::Duration;
 
 #[derive(Clone, Debug)]
 pub struct AppConfig {
     pub attachments_base_path: String,
     pub max_attachment_size_bytes: u64,
     pub allowed_mime_types: Vec<String>,
+    pub storage: StorageBackend,
+    pub scanner: ScannerBackend,
+    /// Broker to take meter readings from; `None` leaves MQTT ingestion off
+    pub mqtt: Option<MqttConfig>,
+    pub wmbus_keys: WmbusKeys,
+    pub sla: SlaHours,
+    /// Reminders and closing of reading campaigns; `None` leaves it to managers
+    pub campaign_schedule: Option<CampaignSchedule>,
+}
+
+/// Hours a maintenance request of each priority may stay unresolved before it
+/// counts as an SLA breach, from `SLA_HOURS_<PRIORITY>` env vars.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SlaHours {
+    pub urgent: i64,
+    pub high: i64,
+    pub medium: i64,
+    pub low: i64,
+}
+
+impl Default for SlaHours {
+    fn default() -> Self {
+        SlaHours {
+            urgent: 24,
+            high: 72,
+            medium: 168,
+            low: 336,
+        }
+    }
+}
+
+impl SlaHours {
+    pub fn from_env() -> Result<Self, String> {
+        let defaults = SlaHours::default();
+        let hours = |name: &str, default: i64| match env::var(name) {
+            Ok(v) => v
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .filter(|h| *h > 0)
+                .ok_or_else(|| format!("invalid {}: {}", name, v)),
+            Err(_) => Ok(default),
+        };
+        Ok(SlaHours {
+            urgent: hours("SLA_HOURS_URGENT", defaults.urgent)?,
+            high: hours("SLA_HOURS_HIGH", defaults.high)?,
+            medium: hours("SLA_HOURS_MEDIUM", defaults.medium)?,
+            low: hours("SLA_HOURS_LOW", defaults.low)?,
+        })
+    }
+
+    /// Threshold for a priority name; unknown priorities get the `Low` threshold.
+    pub fn for_priority(&self, priority: &str) -> i64 {
+        match priority {
+            "Urgent" => self.urgent,
+            "High" => self.high,
+            "Medium" => self.medium,
+            _ => self.low,
+        }
+    }
+}
+
+/// Background processing of reading campaigns, from `CAMPAIGN_CHECK_INTERVAL_SECS`
+/// (unset or `0` to turn it off) and `CAMPAIGN_REMINDER_DAYS` (default 7).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CampaignSchedule {
+    /// Time between two passes over the open campaigns
+    pub interval: Duration,
+    /// Days between reminders of the same campaign
+    pub reminder_days: i64,
+}
+
+impl CampaignSchedule {
+    pub fn from_env() -> Result<Option<Self>, String> {
+        let interval = match env::var("CAMPAIGN_CHECK_INTERVAL_SECS") {
+            Ok(v) => v
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| format!("invalid CAMPAIGN_CHECK_INTERVAL_SECS: {}", v))?,
+            Err(_) => 0,
+        };
+        if interval == 0 {
+            return Ok(None);
+        }
+        let reminder_days = match env::var("CAMPAIGN_REMINDER_DAYS") {
+            Ok(v) => v
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .filter(|d| *d > 0)
+                .ok_or_else(|| format!("invalid CAMPAIGN_REMINDER_DAYS: {}", v))?,
+            Err(_) => 7,
+        };
+        Ok(Some(CampaignSchedule {
+            interval: Duration::from_secs(interval),
+            reminder_days,
+        }))
+    }
+}
+
+/// Where uploaded files are kept (`STORAGE_BACKEND`, default `local`).
+#[derive(Clone, Debug)]
+pub enum StorageBackend {
+    /// Local filesystem under `attachments_base_path`
+    Local,
+    S3(S3Config),
+}
+
+/// Settings for an S3-compatible bucket, read from `S3_*` env vars.
+#[derive(Clone)]
+pub struct S3Config {
+    pub bucket: String,
+    pub region: String,
+    /// Custom endpoint, e.g. `http://localhost:9000` for MinIO; `None` means AWS
+    pub endpoint: Option<String>,
+    pub access_key: String,
+    pub secret_key: String,
+    pub path_style: bool,
+    /// Key prefix inside the bucket, so one bucket can be shared
+    pub prefix: String,
+}
+
+impl fmt::Debug for S3Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("S3Config")
+            .field("bucket", &self.bucket)
+            .field("region", &self.region)
+            .field("endpoint", &self.endpoint)
+            .field("access_key", &self.access_key)
+            .field("secret_key", &"<redacted>")
+            .field("path_style", &self.path_style)
+            .field("prefix", &self.prefix)
+            .finish()
+    }
+}
+
+impl S3Config {
+    pub fn from_env() -> Result<Self, String> {
+        let required = |name: &str| env::var(name).map_err(|_| format!("{} must be set", name));
+        let endpoint = env::var("S3_ENDPOINT").ok().filter(|s| !s.is_empty());
+        let path_style = env::var("S3_PATH_STYLE")
+            .ok()
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(endpoint.is_some());
+        Ok(S3Config {
+            bucket: required("S3_BUCKET")?,
+            region: env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".into()),
+            endpoint,
+            access_key: required("S3_ACCESS_KEY_ID")?,
+            secret_key: required("S3_SECRET_ACCESS_KEY")?,
+            path_style,
+            prefix: env::var("S3_PREFIX").unwrap_or_else(|_| "attachments".into()),
+        })
+    }
+}
+
+impl StorageBackend {
+    pub fn from_env() -> Result<Self, String> {
+        match env::var("STORAGE_BACKEND")
+            .unwrap_or_else(|_| "local".into())
+            .as_str()
+        {
+            "local" => Ok(StorageBackend::Local),
+            "s3" => Ok(StorageBackend::S3(S3Config::from_env()?)),
+            other => Err(format!("unknown STORAGE_BACKEND: {}", other)),
+        }
+    }
+}
+
+/// Malware scanner for uploads (`SCANNER_BACKEND`, default `none`).
+#[derive(Clone, Debug)]
+pub enum ScannerBackend {
+    /// Accept everything; for development and tests
+    None,
+    /// ClamAV daemon at `CLAMAV_ADDRESS` (default `127.0.0.1:3310`)
+    ClamAv { address: String, timeout: Duration },
+}
+
+impl ScannerBackend {
+    pub fn from_env() -> Result<Self, String> {
+        match env::var("SCANNER_BACKEND")
+            .unwrap_or_else(|_| "none".into())
+            .as_str()
+        {
+            "none" => Ok(ScannerBackend::None),
+            "clamav" => Ok(ScannerBackend::ClamAv {
+                address: env::var("CLAMAV_ADDRESS").unwrap_or_else(|_| "127.0.0.1:3310".into()),
+                timeout: Duration::from_secs(
+                    env::var("CLAMAV_TIMEOUT_SECS")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(30),
+                ),
+            }),
+            other => Err(format!("unknown SCANNER_BACKEND: {}", other)),
+        }
+    }
+}
+
+/// Format of the messages on the MQTT topic (`MQTT_PAYLOAD`, default `json`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayloadFormat {
+    /// A reading, a list of readings or `{"readings": [...]}` as sent to the webhooks
+    Json,
+    /// Wireless M-Bus / OMS telegrams, raw or hex encoded
+    Wmbus,
+}
+
+/// MQTT broker with meter readings, read from `MQTT_*` env vars. Enabled by setting
+/// `MQTT_BROKER` to `host` or `host:port`.
+#[derive(Clone)]
+pub struct MqttConfig {
+    pub host: String,
+    pub port: u16,
+    pub client_id: String,
+    /// Topic filter to subscribe to; wildcards allowed
+    pub topic: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub payload: PayloadFormat,
+}
+
+impl fmt::Debug for MqttConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MqttConfig")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("client_id", &self.client_id)
+            .field("topic", &self.topic)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "<redacted>"))
+            .field("payload", &self.payload)
+            .finish()
+    }
+}
+
+impl MqttConfig {
+    pub fn from_env() -> Result<Option<Self>, String> {
+        let Some(broker) = env::var("MQTT_BROKER").ok().filter(|s| !s.is_empty()) else {
+            return Ok(None);
+        };
+        let (host, port) = match broker.rsplit_once(':') {
+            Some((host, port)) => (
+                host.to_string(),
+                port.parse()
+                    .map_err(|_| format!("invalid MQTT_BROKER port: {}", port))?,
+            ),
+            None => (broker, 1883),
+        };
+        let payload = match env::var("MQTT_PAYLOAD")
+            .unwrap_or_else(|_| "json".into())
+            .as_str()
+        {
+            "json" => PayloadFormat::Json,
+            "wmbus" => PayloadFormat::Wmbus,
+            other => return Err(format!("unknown MQTT_PAYLOAD: {}", other)),
+        };
+        Ok(Some(MqttConfig {
+            host,
+            port,
+            client_id: env::var("MQTT_CLIENT_ID").unwrap_or_else(|_| "house-management".into()),
+            topic: env::var("MQTT_TOPIC").unwrap_or_else(|_| "meters/readings".into()),
+            username: env::var("MQTT_USERNAME").ok().filter(|s| !s.is_empty()),
+            password: env::var("MQTT_PASSWORD").ok().filter(|s| !s.is_empty()),
+            payload,
+        }))
+    }
+}
+
+/// AES-128 keys of encrypted wireless M-Bus meters by identification number, from
+/// `WMBUS_KEYS` as `id:hexkey` pairs separated by commas.
+#[derive(Clone, Default)]
+pub struct WmbusKeys(pub HashMap<String, [u8; 16]>);
+
+impl fmt::Debug for WmbusKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+impl WmbusKeys {
+    pub fn from_env() -> Result<Self, String> {
+        let mut keys = HashMap::new();
+        for pair in env::var("WMBUS_KEYS").unwrap_or_default().split(',') {
+            let pair = pair.trim();
+            if pair.is_empty() {
+                continue;
+            }
+            let (id, hex) = pair
+                .split_once(':')
+                .ok_or_else(|| format!("invalid WMBUS_KEYS entry: {}", pair))?;
+            let key = parse_key(hex.trim())
+                .ok_or_else(|| format!("invalid WMBUS_KEYS key for meter {}", id))?;
+            keys.insert(id.trim().to_string(), key);
+        }
+        Ok(WmbusKeys(keys))
+    }
+}
+
+fn parse_key(hex: &str) -> Option<[u8; 16]> {
+    if hex.len() != 32 || !hex.is_ascii() {
+        return None;
+    }
+    let mut key = [0u8; 16];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(key)
 }
 
 impl AppConfig {
@@ -21,10 +332,23 @@ impl AppConfig {
             .map(|s| s.trim().to_string())
             .filter(|s| !s.is_empty())
             .collect();
+        let storage = StorageBackend::from_env().expect("Invalid storage configuration");
+        let scanner = ScannerBackend::from_env().expect("Invalid scanner configuration");
+        let mqtt = MqttConfig::from_env().expect("Invalid MQTT configuration");
+        let wmbus_keys = WmbusKeys::from_env().expect("Invalid wireless M-Bus keys");
+        let sla = SlaHours::from_env().expect("Invalid SLA configuration");
+        let campaign_schedule =
+            CampaignSchedule::from_env().expect("Invalid reading campaign schedule");
         AppConfig {
             attachments_base_path,
             max_attachment_size_bytes,
             allowed_mime_types,
+            storage,
+            scanner,
+            mqtt,
+            wmbus_keys,
+            sla,
+            campaign_schedule,
         }
     }
 }
@@ -0,0 +1,333 @@
+//! Minimal PDF writer for printable reports.
+//!
+//! Lays out lines of text on A4 pages using the standard Helvetica and Courier fonts,
+//! so no font files are embedded. Text is encoded as WinAnsi; characters outside it
+//! fall back to their base letter where there is one ("č" is printed as "c").
+
+/// A4 in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+
+/// Characters per line of body text; Helvetica averages about half its size in width
+const TEXT_CHARS: usize = 95;
+/// Characters per table row; Courier 8pt is 4.8pt per character
+const ROW_CHARS: usize = 103;
+
+#[derive(Clone, Copy)]
+enum Font {
+    Regular,
+    Bold,
+    Mono,
+}
+
+impl Font {
+    fn resource(self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+            Font::Mono => "F3",
+        }
+    }
+}
+
+/// A text document laid out top to bottom, with a page footer carrying the title.
+pub struct PdfDocument {
+    title: String,
+    pages: Vec<String>,
+    current: String,
+    y: f32,
+}
+
+impl PdfDocument {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            pages: Vec::new(),
+            current: String::new(),
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    /// Bold section heading.
+    pub fn heading(&mut self, text: &str) {
+        self.space(6.0);
+        self.line(Font::Bold, 13.0, 18.0, text);
+    }
+
+    /// Body text, wrapped at word boundaries.
+    pub fn text(&mut self, text: &str) {
+        for line in wrap(text, TEXT_CHARS) {
+            self.line(Font::Regular, 10.0, 14.0, &line);
+        }
+    }
+
+    /// `label: value` pair of body text.
+    pub fn field(&mut self, label: &str, value: &str) {
+        self.text(&format!("{}: {}", label, value));
+    }
+
+    /// Monospaced table row; cells are padded to `widths` and the row is cut at the
+    /// page width.
+    pub fn row(&mut self, cells: &[String], widths: &[usize]) {
+        let mut line = String::new();
+        for (cell, width) in cells.iter().zip(widths) {
+            let cell: String = cell.chars().take(*width).collect();
+            line.push_str(&format!("{:<width$} ", cell, width = *width));
+        }
+        let line: String = line.trim_end().chars().take(ROW_CHARS).collect();
+        self.line(Font::Mono, 8.0, 11.0, &line);
+    }
+
+    /// Vertical gap of `points`.
+    pub fn space(&mut self, points: f32) {
+        self.y -= points;
+    }
+
+    fn line(&mut self, font: Font, size: f32, leading: f32, text: &str) {
+        if self.y - leading < MARGIN {
+            self.break_page();
+        }
+        self.y -= leading;
+        self.current
+            .push_str(&show_text(font, size, MARGIN, self.y, text));
+    }
+
+    fn break_page(&mut self) {
+        self.pages.push(std::mem::take(&mut self.current));
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    /// Serialize the document.
+    pub fn finish(mut self) -> Vec<u8> {
+        if !self.current.is_empty() || self.pages.is_empty() {
+            self.break_page();
+        }
+        let page_count = self.pages.len();
+
+        // Objects 1-5 are fixed; each page then takes a page and a content object
+        let mut objects: Vec<String> = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                (0..page_count)
+                    .map(|i| format!("{} 0 R", 6 + i * 2))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                page_count
+            ),
+            font_object("Helvetica"),
+            font_object("Helvetica-Bold"),
+            font_object("Courier"),
+        ];
+        for (i, content) in self.pages.iter().enumerate() {
+            let footer = format!("{} - {}/{}", self.title, i + 1, page_count);
+            let stream = format!(
+                "{}{}",
+                content,
+                show_text(Font::Regular, 8.0, MARGIN, MARGIN / 2.0, &footer)
+            );
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R /F3 5 0 R >> >> \
+                 /Contents {} 0 R >>",
+                PAGE_WIDTH,
+                PAGE_HEIGHT,
+                7 + i * 2
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{}endstream",
+                stream.len(),
+                stream
+            ));
+        }
+        objects.push(format!(
+            "<< /Title ({}) /Producer (House Management) >>",
+            escape(&self.title)
+        ));
+
+        let mut out = String::from("%PDF-1.4\n");
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.push_str(&format!("{} 0 obj\n{}\nendobj\n", i + 1, object));
+        }
+        let xref = out.len();
+        out.push_str(&format!(
+            "xref\n0 {}\n0000000000 65535 f \n",
+            objects.len() + 1
+        ));
+        for offset in offsets {
+            out.push_str(&format!("{:010} 00000 n \n", offset));
+        }
+        out.push_str(&format!(
+            "trailer\n<< /Size {} /Root 1 0 R /Info {} 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            objects.len(),
+            xref
+        ));
+        out.into_bytes()
+    }
+}
+
+fn font_object(base: &str) -> String {
+    format!(
+        "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+        base
+    )
+}
+
+fn show_text(font: Font, size: f32, x: f32, y: f32, text: &str) -> String {
+    format!(
+        "BT /{} {} Tf {} {} Td ({}) Tj ET\n",
+        font.resource(),
+        size,
+        x,
+        y,
+        escape(text)
+    )
+}
+
+/// Greedy word wrap at `width` characters; longer words are split.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let mut word: Vec<char> = word.chars().collect();
+            while word.len() > width {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                lines.push(word.drain(..width).collect());
+            }
+            let word: String = word.into_iter().collect();
+            if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&word);
+        }
+        lines.push(line);
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// PDF string literal body: WinAnsi bytes with delimiters escaped and anything
+/// outside printable ASCII written as an octal escape.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match win_ansi(c) {
+            b'(' | b')' | b'\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            b @ 0x20..=0x7e => out.push(b as char),
+            b => out.push_str(&format!("\\{:03o}", b)),
+        }
+    }
+    out
+}
+
+/// WinAnsi code of `c`, its base letter for common Central European accents, or `?`.
+fn win_ansi(c: char) -> u8 {
+    match c {
+        ' '..='~' => c as u8,
+        '\u{a0}'..='\u{ff}' => c as u32 as u8,
+        '€' => 0x80,
+        '‚' => 0x82,
+        '„' => 0x84,
+        '…' => 0x85,
+        'Š' => 0x8a,
+        'Œ' => 0x8c,
+        'Ž' => 0x8e,
+        '‘' => 0x91,
+        '’' => 0x92,
+        '“' => 0x93,
+        '”' => 0x94,
+        '•' => 0x95,
+        '–' => 0x96,
+        '—' => 0x97,
+        'š' => 0x9a,
+        'œ' => 0x9c,
+        'ž' => 0x9e,
+        'Ÿ' => 0x9f,
+        'Ā' | 'Ă' | 'Ą' => b'A',
+        'ā' | 'ă' | 'ą' => b'a',
+        'Ć' | 'Č' => b'C',
+        'ć' | 'č' => b'c',
+        'Ď' | 'Đ' => b'D',
+        'ď' | 'đ' => b'd',
+        'Ē' | 'Ė' | 'Ę' | 'Ě' => b'E',
+        'ē' | 'ė' | 'ę' | 'ě' => b'e',
+        'Ĺ' | 'Ľ' | 'Ł' => b'L',
+        'ĺ' | 'ľ' | 'ł' => b'l',
+        'Ń' | 'Ň' => b'N',
+        'ń' | 'ň' => b'n',
+        'Ő' => b'O',
+        'ő' => b'o',
+        'Ŕ' | 'Ř' => b'R',
+        'ŕ' | 'ř' => b'r',
+        'Ś' | 'Ş' => b'S',
+        'ś' | 'ş' => b's',
+        'Ţ' | 'Ť' => b'T',
+        'ţ' | 'ť' => b't',
+        'Ū' | 'Ů' | 'Ű' => b'U',
+        'ū' | 'ů' | 'ű' => b'u',
+        'Ź' | 'Ż' => b'Z',
+        'ź' | 'ż' => b'z',
+        _ => b'?',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_is_escaped_and_encoded() {
+        assert_eq!(escape("a (b) \\"), "a \\(b\\) \\\\");
+        assert_eq!(escape("Dvořák"), "Dvor\\341k");
+        assert_eq!(escape("Šimek – 5 €"), "\\212imek \\226 5 \\200");
+        assert_eq!(escape("日"), "?");
+    }
+
+    #[test]
+    fn long_text_is_wrapped_at_words() {
+        assert_eq!(wrap("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap("a\nb", 10), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn document_has_pages_and_a_valid_xref() {
+        let mut doc = PdfDocument::new("Report");
+        doc.heading("Title");
+        for i in 0..100 {
+            doc.row(&[i.to_string(), "x".into()], &[4, 10]);
+        }
+        let bytes = doc.finish();
+        let pdf = String::from_utf8(bytes).unwrap();
+
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.ends_with("%%EOF\n"));
+        assert!(pdf.contains("/Count 2"));
+        assert!(pdf.contains("(Report - 2/2) Tj"));
+
+        let start: usize = pdf
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|n| n.parse().ok())
+            .unwrap();
+        assert!(pdf[start..].starts_with("xref\n"));
+        let catalog: usize = pdf[start..].lines().nth(3).unwrap()[..10].parse().unwrap();
+        assert!(pdf[catalog..].starts_with("1 0 obj\n<< /Type /Catalog"));
+    }
+}
@@ -0,0 +1,422 @@
+use super::render::{AtomEntry, AtomFeed, CalendarEvent, escape_xml, render_atom, render_ics};
+use super::types::{FeedQuery, FeedTokenOut, FeedTokenStatus};
+use crate::announcements::{AnnouncementOut, FEED_LIMIT, private_feed_items, public_feed_items};
+use crate::auth::types::Claims;
+use crate::auth::{AppError, AuthContext, JwtKeys, get_user_building_ids, roles};
+use crate::db::DbPool;
+use crate::i18n::{SUPPORTED_LANGUAGES, negotiate_language};
+use crate::models::Proposal;
+use crate::schema::feed_tokens;
+use actix_web::{HttpRequest, HttpResponse, http::header, web};
+use chrono::{NaiveDateTime, Utc};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+const ATOM_CONTENT_TYPE: &str = "application/atom+xml; charset=utf-8";
+const ICS_CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+/// Most voting windows included in the calendar.
+const CALENDAR_LIMIT: i64 = 200;
+
+fn conn(
+    pool: &web::Data<DbPool>,
+) -> Result<
+    diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<MysqlConnection>>,
+    AppError,
+> {
+    pool.get().map_err(|_| AppError::Internal("db_pool".into()))
+}
+
+fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+/// `scheme://host` the request was made to, for absolute links in feeds.
+fn origin(req: &HttpRequest) -> String {
+    let info = req.connection_info();
+    format!("{}://{}", info.scheme(), info.host())
+}
+
+/// Language from `?lang=`, falling back to the `Accept-Language` header.
+fn feed_language(req: &HttpRequest, query: &FeedQuery) -> String {
+    match query.lang.as_deref() {
+        Some(lang) if SUPPORTED_LANGUAGES.contains(&lang) => lang.to_string(),
+        _ => negotiate_language(
+            req.headers()
+                .get(header::ACCEPT_LANGUAGE)
+                .and_then(|h| h.to_str().ok()),
+        ),
+    }
+}
+
+/// Feed and calendar titles: (announcements, voting).
+fn labels(lang: &str) -> (&'static str, &'static str) {
+    match lang {
+        "cs" => ("Oznámení", "Hlasování"),
+        _ => ("Announcements", "Voting"),
+    }
+}
+
+fn announcement_entry(a: AnnouncementOut, base: &str) -> AtomEntry {
+    let published = a.publish_at.or(a.created_at).unwrap_or_default();
+    AtomEntry {
+        id: format!("{}/api/v1/announcements/{}", base, a.id),
+        title: a.title,
+        link: format!("{}/", base),
+        author: Some(a.author_name),
+        published,
+        updated: a.edited_at.filter(|e| *e > published).unwrap_or(published),
+        content_html: a.body_html,
+        language: Some(a.language),
+    }
+}
+
+fn proposal_entry(p: &Proposal, base: &str, voting: &str) -> AtomEntry {
+    let published = p.created_at.unwrap_or(p.start_time);
+    AtomEntry {
+        id: format!("{}/api/v1/proposals/{}", base, p.id),
+        title: format!("{}: {}", voting, p.title),
+        link: format!("{}/voting/{}", base, p.id),
+        author: None,
+        published,
+        updated: published,
+        content_html: format!(
+            "<p><strong>{} – {}</strong></p><p>{}</p>",
+            p.start_time.format("%Y-%m-%d %H:%M"),
+            p.end_time.format("%Y-%m-%d %H:%M"),
+            escape_xml(&p.description).replace('\n', "<br>")
+        ),
+        language: None,
+    }
+}
+
+fn atom_response(feed: AtomFeed) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(ATOM_CONTENT_TYPE)
+        .body(render_atom(&feed))
+}
+
+/// Resolve a private feed token to the user it belongs to, with their current roles.
+fn feed_user(token: &str, conn: &mut MysqlConnection) -> Result<AuthContext, AppError> {
+    use crate::schema::users::dsl as u;
+    use feed_tokens::dsl as ft;
+
+    let user_id: u64 = ft::feed_tokens
+        .filter(ft::token_hash.eq(hash_token(token)))
+        .select(ft::user_id)
+        .first(conn)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+    let (email, name): (String, String) = u::users
+        .filter(u::id.eq(user_id))
+        .select((u::email, u::name))
+        .first(conn)?;
+    diesel::update(ft::feed_tokens.filter(ft::user_id.eq(user_id)))
+        .set(ft::last_used_at.eq(Utc::now().naive_utc()))
+        .execute(conn)?;
+    Ok(AuthContext {
+        claims: Claims {
+            sub: user_id.to_string(),
+            email,
+            name,
+            roles: roles::get_user_roles(user_id, conn),
+            exp: 0,
+        },
+    })
+}
+
+/// Proposals in the user's buildings (or without a building), latest voting window first.
+fn visible_proposals(
+    auth: &AuthContext,
+    limit: i64,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<Proposal>, AppError> {
+    use crate::schema::proposals::dsl as p;
+    let building_ids = get_user_building_ids(auth.user_id()?, auth.has_any_role(&["Admin"]), conn)?;
+    let mut query = p::proposals.into_boxed();
+    if let Some(ids) = building_ids {
+        query = query.filter(p::building_id.eq_any(ids).or(p::building_id.is_null()));
+    }
+    Ok(query
+        .select(Proposal::as_select())
+        .order(p::start_time.desc())
+        .limit(limit)
+        .load(conn)?)
+}
+
+/// Public Atom feed of a building
+///
+/// Live public announcements for the residents of one building: those not targeted at
+/// any building and those targeted at this one. No authentication required.
+#[utoipa::path(
+    get,
+    path = "/api/v1/feeds/buildings/{id}/atom",
+    params(
+        ("id" = u64, Path, description = "Building ID"),
+        FeedQuery
+    ),
+    responses(
+        (status = 200, description = "Atom feed", content_type = "application/atom+xml", body = String),
+        (status = 404, description = "Building not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Feeds"
+)]
+pub async fn building_atom(
+    req: HttpRequest,
+    pool: web::Data<DbPool>,
+    keys: web::Data<JwtKeys>,
+    path: web::Path<u64>,
+    query: web::Query<FeedQuery>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::buildings::dsl as b;
+    let building_id = path.into_inner();
+    let mut c = conn(&pool)?;
+    let address: String = b::buildings
+        .filter(b::id.eq(building_id))
+        .filter(b::is_deleted.eq(false))
+        .select(b::address)
+        .first(&mut c)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+
+    let lang = feed_language(&req, &query);
+    let base = origin(&req);
+    let entries: Vec<AtomEntry> = public_feed_items(building_id, &lang, &keys, &mut c)?
+        .into_iter()
+        .map(|a| announcement_entry(a, &base))
+        .collect();
+    let self_url = format!("{}{}", base, req.uri());
+    Ok(atom_response(AtomFeed {
+        id: format!("{}/api/v1/feeds/buildings/{}/atom", base, building_id),
+        title: format!("{} – {}", labels(&lang).0, address),
+        self_url,
+        updated: entries
+            .iter()
+            .map(|e| e.updated)
+            .max()
+            .unwrap_or_else(|| Utc::now().naive_utc()),
+        base: format!("{}/", base),
+        entries,
+    }))
+}
+
+/// Private Atom feed
+///
+/// Announcements addressed to the token's owner and the voting windows of proposals
+/// in their buildings. The token takes the place of authentication, so feed readers
+/// can subscribe.
+#[utoipa::path(
+    get,
+    path = "/api/v1/feeds/{token}/atom",
+    params(
+        ("token" = String, Path, description = "Private feed token"),
+        FeedQuery
+    ),
+    responses(
+        (status = 200, description = "Atom feed", content_type = "application/atom+xml", body = String),
+        (status = 404, description = "Unknown or revoked token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Feeds"
+)]
+pub async fn private_atom(
+    req: HttpRequest,
+    pool: web::Data<DbPool>,
+    keys: web::Data<JwtKeys>,
+    path: web::Path<String>,
+    query: web::Query<FeedQuery>,
+) -> Result<HttpResponse, AppError> {
+    let token = path.into_inner();
+    let mut c = conn(&pool)?;
+    let auth = feed_user(&token, &mut c)?;
+
+    let lang = feed_language(&req, &query);
+    let (announcements_label, voting_label) = labels(&lang);
+    let base = origin(&req);
+    let mut entries: Vec<AtomEntry> = private_feed_items(&auth, &lang, &keys, &mut c)?
+        .into_iter()
+        .map(|a| announcement_entry(a, &base))
+        .collect();
+    entries.extend(
+        visible_proposals(&auth, FEED_LIMIT, &mut c)?
+            .iter()
+            .map(|p| proposal_entry(p, &base, voting_label)),
+    );
+    entries.sort_by_key(|e| std::cmp::Reverse(e.published));
+    entries.truncate(FEED_LIMIT as usize);
+
+    Ok(atom_response(AtomFeed {
+        // Stable across token rotation
+        id: format!("{}/api/v1/feeds/users/{}", base, auth.claims.sub),
+        title: format!(
+            "{}, {} – {}",
+            announcements_label, voting_label, auth.claims.name
+        ),
+        self_url: format!("{}{}", base, req.uri()),
+        updated: entries
+            .iter()
+            .map(|e| e.updated)
+            .max()
+            .unwrap_or_else(|| Utc::now().naive_utc()),
+        base: format!("{}/", base),
+        entries,
+    }))
+}
+
+/// Private voting calendar
+///
+/// The voting windows of proposals in the token owner's buildings as an iCalendar
+/// file, for subscribing from calendar applications.
+#[utoipa::path(
+    get,
+    path = "/api/v1/feeds/{token}/calendar.ics",
+    params(
+        ("token" = String, Path, description = "Private feed token"),
+        FeedQuery
+    ),
+    responses(
+        (status = 200, description = "iCalendar", content_type = "text/calendar", body = String),
+        (status = 404, description = "Unknown or revoked token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Feeds"
+)]
+pub async fn private_calendar(
+    req: HttpRequest,
+    pool: web::Data<DbPool>,
+    path: web::Path<String>,
+    query: web::Query<FeedQuery>,
+) -> Result<HttpResponse, AppError> {
+    let token = path.into_inner();
+    let mut c = conn(&pool)?;
+    let auth = feed_user(&token, &mut c)?;
+
+    let lang = feed_language(&req, &query);
+    let voting_label = labels(&lang).1;
+    let base = origin(&req);
+    let host = req.connection_info().host().to_string();
+    let events: Vec<CalendarEvent> = visible_proposals(&auth, CALENDAR_LIMIT, &mut c)?
+        .into_iter()
+        .map(|p| CalendarEvent {
+            uid: format!("proposal-{}@{}", p.id, host),
+            summary: format!("{}: {}", voting_label, p.title),
+            description: p.description,
+            url: format!("{}/voting/{}", base, p.id),
+            start: p.start_time,
+            end: p.end_time,
+            created: p.created_at,
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok()
+        .content_type(ICS_CONTENT_TYPE)
+        .body(render_ics(voting_label, Utc::now().naive_utc(), &events)))
+}
+
+/// Get private feed status
+///
+/// Whether the current user has private feed URLs. The URLs themselves are only
+/// returned when created.
+#[utoipa::path(
+    get,
+    path = "/api/v1/feeds/token",
+    responses(
+        (status = 200, description = "Feed token status", body = FeedTokenStatus),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Feeds",
+    security(("bearer_auth" = []))
+)]
+pub async fn token_status(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+) -> Result<HttpResponse, AppError> {
+    use feed_tokens::dsl as ft;
+    let user_id = auth.user_id()?;
+    let mut c = conn(&pool)?;
+    let row: Option<(NaiveDateTime, Option<NaiveDateTime>)> = ft::feed_tokens
+        .filter(ft::user_id.eq(user_id))
+        .select((ft::created_at, ft::last_used_at))
+        .first(&mut c)
+        .optional()?;
+    Ok(HttpResponse::Ok().json(FeedTokenStatus {
+        active: row.is_some(),
+        created_at: row.map(|r| r.0),
+        last_used_at: row.and_then(|r| r.1),
+    }))
+}
+
+/// Create private feed URLs
+///
+/// Issues a new secret token for the current user's private Atom feed and voting
+/// calendar. An existing token is replaced, so previously shared URLs stop working.
+#[utoipa::path(
+    post,
+    path = "/api/v1/feeds/token",
+    responses(
+        (status = 201, description = "New feed URLs", body = FeedTokenOut),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Feeds",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_token(
+    req: HttpRequest,
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+) -> Result<HttpResponse, AppError> {
+    use feed_tokens::dsl as ft;
+    let user_id = auth.user_id()?;
+    let token: String = rand::thread_rng()
+        .sample_iter(&rand::distributions::Alphanumeric)
+        .take(40)
+        .map(char::from)
+        .collect();
+    let now = Utc::now().naive_utc();
+    let mut c = conn(&pool)?;
+    diesel::delete(ft::feed_tokens.filter(ft::user_id.eq(user_id))).execute(&mut c)?;
+    diesel::insert_into(ft::feed_tokens)
+        .values((
+            ft::user_id.eq(user_id),
+            ft::token_hash.eq(hash_token(&token)),
+            ft::created_at.eq(now),
+        ))
+        .execute(&mut c)?;
+
+    let base = origin(&req);
+    Ok(HttpResponse::Created().json(FeedTokenOut {
+        atom_url: format!("{}/api/v1/feeds/{}/atom", base, token),
+        calendar_url: format!("{}/api/v1/feeds/{}/calendar.ics", base, token),
+        created_at: now,
+    }))
+}
+
+/// Revoke private feed URLs
+///
+/// Deletes the current user's feed token; subscribed readers get 404 from then on.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/feeds/token",
+    responses(
+        (status = 204, description = "Token revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Feeds",
+    security(("bearer_auth" = []))
+)]
+pub async fn revoke_token(
+    pool: web::Data<DbPool>,
+    auth: AuthContext,
+) -> Result<HttpResponse, AppError> {
+    use feed_tokens::dsl as ft;
+    let user_id = auth.user_id()?;
+    let mut c = conn(&pool)?;
+    diesel::delete(ft::feed_tokens.filter(ft::user_id.eq(user_id))).execute(&mut c)?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,26 @@
+//! Atom feeds and iCalendar subscriptions.
+//!
+//! Public feeds are per building. Private feeds are addressed by a secret per-user
+//! token in the URL instead of a bearer token, since feed readers and calendar
+//! applications can't log in; only a hash of the token is stored.
+
+mod handlers;
+mod render;
+mod types;
+
+pub use handlers::*;
+pub use types::*;
+
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/feeds")
+            .route("/token", web::get().to(token_status))
+            .route("/token", web::post().to(create_token))
+            .route("/token", web::delete().to(revoke_token))
+            .route("/buildings/{id}/atom", web::get().to(building_atom))
+            .route("/{token}/atom", web::get().to(private_atom))
+            .route("/{token}/calendar.ics", web::get().to(private_calendar)),
+    );
+}
@@ -0,0 +1,224 @@
+//! Atom (RFC 4287) and iCalendar (RFC 5545) serialisation.
+
+use chrono::NaiveDateTime;
+
+pub struct AtomFeed {
+    pub id: String,
+    pub title: String,
+    /// URL the feed itself is served from
+    pub self_url: String,
+    /// Base for relative links in entry content
+    pub base: String,
+    pub updated: NaiveDateTime,
+    pub entries: Vec<AtomEntry>,
+}
+
+pub struct AtomEntry {
+    pub id: String,
+    pub title: String,
+    pub link: String,
+    pub author: Option<String>,
+    pub published: NaiveDateTime,
+    pub updated: NaiveDateTime,
+    /// Sanitised HTML
+    pub content_html: String,
+    pub language: Option<String>,
+}
+
+pub struct CalendarEvent {
+    pub uid: String,
+    pub summary: String,
+    pub description: String,
+    pub url: String,
+    /// Floating local times, as proposals store them
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    pub created: Option<NaiveDateTime>,
+}
+
+pub fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn rfc3339(dt: &NaiveDateTime) -> String {
+    dt.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+pub fn render_atom(feed: &AtomFeed) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str(&format!(
+        "<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:base=\"{}\">\n",
+        escape_xml(&feed.base)
+    ));
+    xml.push_str(&format!("  <id>{}</id>\n", escape_xml(&feed.id)));
+    xml.push_str(&format!("  <title>{}</title>\n", escape_xml(&feed.title)));
+    xml.push_str(&format!(
+        "  <link rel=\"self\" href=\"{}\"/>\n",
+        escape_xml(&feed.self_url)
+    ));
+    xml.push_str(&format!(
+        "  <updated>{}</updated>\n",
+        rfc3339(&feed.updated)
+    ));
+    for e in &feed.entries {
+        match &e.language {
+            Some(lang) => xml.push_str(&format!("  <entry xml:lang=\"{}\">\n", escape_xml(lang))),
+            None => xml.push_str("  <entry>\n"),
+        }
+        xml.push_str(&format!("    <id>{}</id>\n", escape_xml(&e.id)));
+        xml.push_str(&format!("    <title>{}</title>\n", escape_xml(&e.title)));
+        xml.push_str(&format!(
+            "    <link rel=\"alternate\" href=\"{}\"/>\n",
+            escape_xml(&e.link)
+        ));
+        if let Some(author) = &e.author {
+            xml.push_str(&format!(
+                "    <author><name>{}</name></author>\n",
+                escape_xml(author)
+            ));
+        }
+        xml.push_str(&format!(
+            "    <published>{}</published>\n",
+            rfc3339(&e.published)
+        ));
+        xml.push_str(&format!("    <updated>{}</updated>\n", rfc3339(&e.updated)));
+        xml.push_str(&format!(
+            "    <content type=\"html\">{}</content>\n",
+            escape_xml(&e.content_html)
+        ));
+        xml.push_str("  </entry>\n");
+    }
+    xml.push_str("</feed>\n");
+    xml
+}
+
+fn escape_ics(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line to at most 75 octets per line, without splitting characters.
+fn fold_ics(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + line.len() / 74 * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out.push_str("\r\n");
+    out
+}
+
+fn ics_local(dt: &NaiveDateTime) -> String {
+    dt.format("%Y%m%dT%H%M%S").to_string()
+}
+
+fn ics_utc(dt: &NaiveDateTime) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+pub fn render_ics(name: &str, stamp: NaiveDateTime, events: &[CalendarEvent]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//House Management System//Feeds//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_ics(name)),
+    ];
+    for e in events {
+        lines.push("BEGIN:VEVENT".into());
+        lines.push(format!("UID:{}", e.uid));
+        lines.push(format!("DTSTAMP:{}", ics_utc(&stamp)));
+        if let Some(created) = &e.created {
+            lines.push(format!("CREATED:{}", ics_utc(created)));
+        }
+        lines.push(format!("DTSTART:{}", ics_local(&e.start)));
+        lines.push(format!("DTEND:{}", ics_local(&e.end)));
+        lines.push(format!("SUMMARY:{}", escape_ics(&e.summary)));
+        if !e.description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape_ics(&e.description)));
+        }
+        lines.push(format!("URL:{}", e.url));
+        lines.push("END:VEVENT".into());
+    }
+    lines.push("END:VCALENDAR".into());
+    lines.iter().map(|l| fold_ics(l)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn atom_escapes_titles_and_content() {
+        let xml = render_atom(&AtomFeed {
+            id: "https://example.org/feed".into(),
+            title: "Main St & Co".into(),
+            self_url: "https://example.org/feed".into(),
+            base: "https://example.org/".into(),
+            updated: dt("2026-03-01 10:00"),
+            entries: vec![AtomEntry {
+                id: "https://example.org/a/1".into(),
+                title: "<Water> outage".into(),
+                link: "https://example.org/".into(),
+                author: Some("Jane".into()),
+                published: dt("2026-03-01 09:00"),
+                updated: dt("2026-03-01 10:00"),
+                content_html: "<p>Off &amp; on</p>".into(),
+                language: Some("cs".into()),
+            }],
+        });
+        assert!(xml.contains("<title>Main St &amp; Co</title>"));
+        assert!(xml.contains("<title>&lt;Water&gt; outage</title>"));
+        assert!(xml.contains("&lt;p&gt;Off &amp;amp; on&lt;/p&gt;"));
+        assert!(xml.contains("<entry xml:lang=\"cs\">"));
+        assert!(xml.contains("<updated>2026-03-01T10:00:00Z</updated>"));
+    }
+
+    #[test]
+    fn ics_escapes_and_folds_long_lines() {
+        let ics = render_ics(
+            "Votes",
+            dt("2026-03-01 10:00"),
+            &[CalendarEvent {
+                uid: "proposal-1@example.org".into(),
+                summary: "Roof; windows, doors".into(),
+                description: "Žluťoučký kůň ".repeat(10),
+                url: "https://example.org/voting/1".into(),
+                start: dt("2026-03-02 18:00"),
+                end: dt("2026-03-09 18:00"),
+                created: None,
+            }],
+        );
+        assert!(ics.contains("SUMMARY:Roof\\; windows\\, doors\r\n"));
+        assert!(ics.contains("DTSTART:20260302T180000\r\n"));
+        assert!(ics.lines().all(|l| l.len() <= 75));
+        assert!(ics.contains("\r\n "));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+}
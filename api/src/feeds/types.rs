@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, IntoParams)]
+pub struct FeedQuery {
+    /// Language of the entries (`en`, `cs`); negotiated from `Accept-Language` when omitted
+    pub lang: Option<String>,
+}
+
+/// Private feed URLs; the token in them is shown only once.
+#[derive(Serialize, ToSchema)]
+pub struct FeedTokenOut {
+    /// Atom feed of the user's announcements and voting windows
+    pub atom_url: String,
+    /// iCalendar of voting windows
+    pub calendar_url: String,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+/// Whether the user has private feed URLs, without revealing them.
+#[derive(Serialize, ToSchema)]
+pub struct FeedTokenStatus {
+    pub active: bool,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub last_used_at: Option<chrono::NaiveDateTime>,
+}
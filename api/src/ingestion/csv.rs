@@ -0,0 +1,266 @@
+//! Vendor CSV exports, read with a column mapping.
+
+use super::{ParseError, ReadingParser, ReadingRecord, parse_timestamp};
+use bigdecimal::BigDecimal;
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::Deserialize;
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// Where the reading's parts are in a vendor's CSV export. Columns are given by
+/// header name or by 1-based position.
+#[derive(Deserialize, Debug, Clone, ToSchema)]
+pub struct CsvMapping {
+    #[serde(default = "default_delimiter")]
+    #[schema(value_type = String, example = ";")]
+    pub delimiter: char,
+    #[serde(default = "default_has_header")]
+    pub has_header: bool,
+    pub serial_column: String,
+    pub value_column: String,
+    pub timestamp_column: String,
+    /// Column with the unit; without one, `unit` applies to every row
+    pub unit_column: Option<String>,
+    pub unit: Option<String>,
+    /// chrono format of the timestamps, e.g. `%d.%m.%Y %H:%M`; dates alone are taken
+    /// as midnight. Defaults to the formats the webhooks accept.
+    pub timestamp_format: Option<String>,
+    /// Values use a decimal comma, e.g. `123,45`
+    #[serde(default)]
+    pub decimal_comma: bool,
+}
+
+fn default_delimiter() -> char {
+    ','
+}
+
+fn default_has_header() -> bool {
+    true
+}
+
+/// Split one CSV line, honouring quoted fields with doubled quotes inside.
+fn split_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Readings from a CSV export, one per row.
+pub struct CsvParser {
+    mapping: CsvMapping,
+}
+
+impl CsvParser {
+    pub fn new(mapping: CsvMapping) -> Self {
+        Self { mapping }
+    }
+
+    fn column(&self, column: &str, header: Option<&[String]>) -> Result<usize, ParseError> {
+        let column = column.trim();
+        if let Ok(position) = column.parse::<usize>() {
+            return position
+                .checked_sub(1)
+                .ok_or_else(|| ParseError::Invalid("columns are numbered from 1".into()));
+        }
+        header
+            .and_then(|names| {
+                names
+                    .iter()
+                    .position(|name| name.trim().eq_ignore_ascii_case(column))
+            })
+            .ok_or_else(|| ParseError::Invalid(format!("no column named {}", column)))
+    }
+
+    fn timestamp(&self, value: &str) -> Option<NaiveDateTime> {
+        let value = value.trim();
+        match &self.mapping.timestamp_format {
+            Some(format) => NaiveDateTime::parse_from_str(value, format)
+                .ok()
+                .or_else(|| {
+                    NaiveDate::parse_from_str(value, format)
+                        .ok()
+                        .and_then(|date| date.and_hms_opt(0, 0, 0))
+                }),
+            None => parse_timestamp(value),
+        }
+    }
+
+    fn value(&self, value: &str) -> Option<BigDecimal> {
+        let value = value.trim();
+        if self.mapping.decimal_comma {
+            BigDecimal::from_str(&value.replace('.', "").replace(',', ".")).ok()
+        } else {
+            BigDecimal::from_str(value).ok()
+        }
+    }
+}
+
+impl ReadingParser for CsvParser {
+    fn name(&self) -> &'static str {
+        "csv"
+    }
+
+    fn parse(&self, payload: &[u8]) -> Result<Vec<ReadingRecord>, ParseError> {
+        let text = std::str::from_utf8(payload)
+            .map_err(|_| ParseError::Invalid("the file is not UTF-8 text".into()))?;
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let delimiter = self.mapping.delimiter;
+        let header = if self.mapping.has_header {
+            lines.next().map(|(_, line)| split_line(line, delimiter))
+        } else {
+            None
+        };
+        let serial = self.column(&self.mapping.serial_column, header.as_deref())?;
+        let value = self.column(&self.mapping.value_column, header.as_deref())?;
+        let timestamp = self.column(&self.mapping.timestamp_column, header.as_deref())?;
+        let unit = match &self.mapping.unit_column {
+            Some(column) => Some(self.column(column, header.as_deref())?),
+            None => None,
+        };
+        if unit.is_none() && self.mapping.unit.is_none() {
+            return Err(ParseError::Invalid(
+                "a unit or a unit column is required".into(),
+            ));
+        }
+
+        lines
+            .map(|(index, line)| {
+                let line_no = index + 1;
+                let fields = split_line(line, delimiter);
+                let field = |column: usize| {
+                    fields.get(column).map(String::as_str).ok_or_else(|| {
+                        ParseError::Invalid(format!(
+                            "line {}: missing column {}",
+                            line_no,
+                            column + 1
+                        ))
+                    })
+                };
+                let reading_value = self.value(field(value)?).ok_or_else(|| {
+                    ParseError::Invalid(format!("line {}: invalid reading value", line_no))
+                })?;
+                let reading_timestamp = self.timestamp(field(timestamp)?).ok_or_else(|| {
+                    ParseError::Invalid(format!("line {}: invalid timestamp", line_no))
+                })?;
+                let reading_unit = match unit {
+                    Some(column) => field(column)?,
+                    None => self.mapping.unit.as_deref().unwrap_or_default(),
+                };
+                ReadingRecord::new(
+                    field(serial)?,
+                    reading_value,
+                    reading_timestamp,
+                    reading_unit,
+                )
+                .map_err(|e| ParseError::Invalid(format!("line {}: {}", line_no, e)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping() -> CsvMapping {
+        CsvMapping {
+            delimiter: ';',
+            has_header: true,
+            serial_column: "Meter ID".into(),
+            value_column: "Value".into(),
+            timestamp_column: "Date".into(),
+            unit_column: None,
+            unit: Some("m3".into()),
+            timestamp_format: Some("%d.%m.%Y %H:%M".into()),
+            decimal_comma: true,
+        }
+    }
+
+    #[test]
+    fn maps_columns_by_header() {
+        let csv = "\u{feff}Date;Meter ID;Value\n01.03.2026 08:00;WM-1;1.234,5\n\n01.03.2026 08:00;\"WM;2\";7\n";
+        let records = CsvParser::new(mapping()).parse(csv.as_bytes()).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].serial_number, "WM-1");
+        assert_eq!(
+            records[0].reading_value,
+            BigDecimal::from_str("1234.5").unwrap()
+        );
+        assert_eq!(
+            records[0].timestamp,
+            NaiveDate::from_ymd_opt(2026, 3, 1)
+                .unwrap()
+                .and_hms_opt(8, 0, 0)
+                .unwrap()
+        );
+        assert_eq!(records[0].unit, "m3");
+        assert_eq!(records[1].serial_number, "WM;2");
+    }
+
+    #[test]
+    fn maps_columns_by_position() {
+        let mapping = CsvMapping {
+            delimiter: ',',
+            has_header: false,
+            serial_column: "2".into(),
+            value_column: "3".into(),
+            timestamp_column: "1".into(),
+            unit_column: Some("4".into()),
+            unit: None,
+            timestamp_format: Some("%Y-%m-%d".into()),
+            decimal_comma: false,
+        };
+        let records = CsvParser::new(mapping)
+            .parse(b"2026-03-01,HM-9,42.5,kWh\n")
+            .unwrap();
+        assert_eq!(records[0].serial_number, "HM-9");
+        assert_eq!(records[0].unit, "kWh");
+        assert_eq!(
+            records[0].timestamp,
+            NaiveDate::from_ymd_opt(2026, 3, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_the_bad_line() {
+        let csv = "Date;Meter ID;Value\n01.03.2026 08:00;WM-1;12\n01.03.2026;WM-2;13\n";
+        let err = CsvParser::new(mapping())
+            .parse(csv.as_bytes())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("line 3"), "{}", err);
+    }
+
+    #[test]
+    fn unknown_column_is_rejected() {
+        let mut mapping = mapping();
+        mapping.value_column = "Reading".into();
+        assert!(
+            CsvParser::new(mapping)
+                .parse(b"Date;Meter ID;Value\n")
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Readings as JSON, in the shape the webhooks accept.
+
+use super::{ParseError, ReadingParser, ReadingRecord, parse_timestamp};
+use bigdecimal::BigDecimal;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct JsonReading {
+    serial_number: String,
+    reading_value: BigDecimal,
+    timestamp: String,
+    unit: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonPayload {
+    Batch { readings: Vec<JsonReading> },
+    List(Vec<JsonReading>),
+    Single(JsonReading),
+}
+
+/// A single reading, a list of readings or `{"readings": [...]}`.
+pub struct JsonParser;
+
+impl ReadingParser for JsonParser {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+
+    fn parse(&self, payload: &[u8]) -> Result<Vec<ReadingRecord>, ParseError> {
+        let readings = match serde_json::from_slice(payload)
+            .map_err(|e| ParseError::Invalid(e.to_string()))?
+        {
+            JsonPayload::Batch { readings } | JsonPayload::List(readings) => readings,
+            JsonPayload::Single(reading) => vec![reading],
+        };
+        readings
+            .into_iter()
+            .map(|r| {
+                let timestamp = parse_timestamp(&r.timestamp).ok_or_else(|| {
+                    ParseError::Invalid(format!("invalid timestamp: {}", r.timestamp))
+                })?;
+                ReadingRecord::new(&r.serial_number, r.reading_value, timestamp, &r.unit)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_reading_and_batches() {
+        let single = br#"{"serial_number":"WM-1","reading_value":"12.5","timestamp":"2026-03-01T08:00:00Z","unit":"m3"}"#;
+        let records = JsonParser.parse(single).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].serial_number, "WM-1");
+        assert_eq!(
+            records[0].reading_value,
+            "12.5".parse::<BigDecimal>().unwrap()
+        );
+
+        let batch = br#"{"readings":[
+            {"serial_number":"WM-1","reading_value":1,"timestamp":"2026-03-01 08:00:00","unit":"m3"},
+            {"serial_number":"WM-2","reading_value":2,"timestamp":"2026-03-01T08:00:00","unit":"m3"}
+        ]}"#;
+        assert_eq!(JsonParser.parse(batch).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn rejects_bad_timestamps() {
+        let payload = br#"[{"serial_number":"WM-1","reading_value":"1","timestamp":"yesterday","unit":"m3"}]"#;
+        assert!(matches!(
+            JsonParser.parse(payload),
+            Err(ParseError::Invalid(_))
+        ));
+    }
+}
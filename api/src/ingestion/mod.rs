@@ -0,0 +1,222 @@
+//! Meter readings pushed by smart meters and their vendors.
+//!
+//! Each transport hands its raw payload to a [`ReadingParser`]: JSON as sent to the
+//! webhooks ([`JsonParser`]), vendor CSV exports with a column mapping
+//! ([`CsvParser`]) or wireless M-Bus / OMS telegrams ([`WmbusDecoder`]). The parsed
+//! records then all go through [`ingest`], which resolves meters by serial number
+//! and skips readings the meter already has at the same time, so a reading
+//! delivered twice, or by two transports, is stored once.
+
+mod csv;
+mod json;
+pub mod mqtt;
+mod wmbus;
+
+pub use csv::{CsvMapping, CsvParser};
+pub use json::JsonParser;
+pub use wmbus::WmbusDecoder;
+
+use crate::auth::AppError;
+use crate::config::{AppConfig, PayloadFormat};
+use crate::models::ReadingSource;
+use bigdecimal::{BigDecimal, Signed};
+use chrono::NaiveDateTime;
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// A reading decoded from a payload, not yet matched to a meter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadingRecord {
+    pub serial_number: String,
+    pub reading_value: BigDecimal,
+    pub timestamp: NaiveDateTime,
+    pub unit: String,
+}
+
+impl ReadingRecord {
+    /// Build a record, checking its parts the same way for every parser.
+    pub fn new(
+        serial_number: &str,
+        reading_value: BigDecimal,
+        timestamp: NaiveDateTime,
+        unit: &str,
+    ) -> Result<Self, ParseError> {
+        let serial_number = serial_number.trim();
+        if serial_number.is_empty() || serial_number.len() > 128 {
+            return Err(ParseError::Invalid("invalid serial number".into()));
+        }
+        if reading_value.is_negative() {
+            return Err(ParseError::Invalid(format!(
+                "negative reading for meter {}",
+                serial_number
+            )));
+        }
+        let unit = unit.trim();
+        if unit.is_empty() || unit.len() > 16 {
+            return Err(ParseError::Invalid(format!(
+                "invalid unit for meter {}",
+                serial_number
+            )));
+        }
+        Ok(Self {
+            serial_number: serial_number.to_string(),
+            reading_value,
+            timestamp,
+            unit: unit.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("invalid_payload: {0}")]
+    Invalid(String),
+    #[error("unsupported_payload: {0}")]
+    Unsupported(String),
+    /// The telegram is encrypted and no key is configured for the meter
+    #[error("missing_key: {0}")]
+    MissingKey(String),
+}
+
+impl From<ParseError> for AppError {
+    fn from(e: ParseError) -> Self {
+        AppError::BadRequest(e.to_string())
+    }
+}
+
+pub trait ReadingParser: Send + Sync {
+    /// Short parser name for logs.
+    fn name(&self) -> &'static str;
+
+    /// Decode every reading in a payload. Errors reject the whole payload.
+    fn parse(&self, payload: &[u8]) -> Result<Vec<ReadingRecord>, ParseError>;
+}
+
+/// Build the parser for a push format.
+pub fn parser_for(format: PayloadFormat, cfg: &AppConfig) -> Arc<dyn ReadingParser> {
+    match format {
+        PayloadFormat::Json => Arc::new(JsonParser),
+        PayloadFormat::Wmbus => Arc::new(WmbusDecoder::new(cfg.wmbus_keys.clone())),
+    }
+}
+
+/// Parse a timestamp in one of the formats the webhooks accept, taken as UTC.
+pub fn parse_timestamp(value: &str) -> Option<NaiveDateTime> {
+    [
+        "%Y-%m-%dT%H:%M:%SZ",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M:%S",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(value.trim(), format).ok())
+}
+
+/// Meters a source may write readings for.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum MeterScope {
+    #[default]
+    All,
+    /// Meters in these buildings, and these meters
+    Limited {
+        building_ids: Vec<u64>,
+        meter_ids: Vec<u64>,
+    },
+}
+
+impl MeterScope {
+    fn allows(&self, meter_id: u64, building_id: u64) -> bool {
+        match self {
+            MeterScope::All => true,
+            MeterScope::Limited {
+                building_ids,
+                meter_ids,
+            } => building_ids.contains(&building_id) || meter_ids.contains(&meter_id),
+        }
+    }
+}
+
+/// Outcome of storing a batch of readings
+#[derive(Serialize, Debug, Default, PartialEq, ToSchema)]
+pub struct IngestSummary {
+    pub received: usize,
+    pub inserted: usize,
+    /// Readings the meter already had at the same time
+    pub duplicates: usize,
+    /// Serial numbers matching no active meter in the source's scope
+    pub unknown_meters: Vec<String>,
+}
+
+/// Store readings for the active meters with their serial numbers.
+///
+/// Meters outside `scope` are reported as unknown, so a restricted source cannot tell
+/// them from serial numbers that do not exist.
+///
+/// A meter has one reading per timestamp: a record for a time the meter already has
+/// a reading for counts as a duplicate and is skipped, whichever transport delivered
+/// the first one.
+pub fn ingest(
+    records: &[ReadingRecord],
+    source: ReadingSource,
+    scope: &MeterScope,
+    conn: &mut MysqlConnection,
+) -> Result<IngestSummary, AppError> {
+    use crate::schema::apartments::dsl as apt;
+    use crate::schema::meter_readings::dsl as mr;
+    use crate::schema::meters::dsl as m;
+
+    let mut summary = IngestSummary {
+        received: records.len(),
+        ..Default::default()
+    };
+    let mut meter_ids: HashMap<&str, Option<u64>> = HashMap::new();
+    for record in records {
+        let meter_id = match meter_ids.get(record.serial_number.as_str()) {
+            Some(id) => *id,
+            None => {
+                let id = m::meters
+                    .inner_join(apt::apartments)
+                    .filter(m::serial_number.eq(&record.serial_number))
+                    .filter(m::is_active.eq(true))
+                    .select((m::id, apt::building_id))
+                    .first::<(u64, u64)>(conn)
+                    .optional()?
+                    .filter(|(meter_id, building_id)| scope.allows(*meter_id, *building_id))
+                    .map(|(meter_id, _)| meter_id);
+                meter_ids.insert(&record.serial_number, id);
+                if id.is_none() {
+                    summary.unknown_meters.push(record.serial_number.clone());
+                }
+                id
+            }
+        };
+        let Some(meter_id) = meter_id else {
+            continue;
+        };
+
+        // The unique key on (meter_id, reading_timestamp) settles duplicates, also
+        // between concurrent deliveries
+        match diesel::insert_into(mr::meter_readings)
+            .values((
+                mr::meter_id.eq(meter_id),
+                mr::reading_value.eq(&record.reading_value),
+                mr::reading_timestamp.eq(record.timestamp),
+                mr::unit.eq(&record.unit),
+                mr::source.eq(source.to_string()),
+            ))
+            .execute(conn)
+        {
+            Ok(_) => summary.inserted += 1,
+            Err(DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => {
+                summary.duplicates += 1
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(summary)
+}
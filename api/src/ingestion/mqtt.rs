@@ -0,0 +1,72 @@
+//! Subscriber taking meter readings from an MQTT broker.
+
+use super::{MeterScope, ReadingParser, ingest};
+use crate::config::MqttConfig;
+use crate::db::DbPool;
+use crate::models::ReadingSource;
+use actix_web::web;
+use rumqttc::{AsyncClient, Event, MqttOptions, Packet, QoS};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Subscribe to the configured topic and store the readings of every message.
+///
+/// Runs until the process exits. Connection errors are retried; the subscription is
+/// renewed on every (re)connect since the broker may not keep the session.
+pub async fn run(cfg: MqttConfig, parser: Arc<dyn ReadingParser>, pool: DbPool) {
+    let mut options = MqttOptions::new(&cfg.client_id, &cfg.host, cfg.port);
+    options.set_keep_alive(Duration::from_secs(30));
+    if let Some(username) = &cfg.username {
+        options.set_credentials(username, cfg.password.as_deref().unwrap_or_default());
+    }
+    let (client, mut eventloop) = AsyncClient::new(options, 16);
+
+    loop {
+        match eventloop.poll().await {
+            Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                info!(broker = %cfg.host, topic = %cfg.topic, "Connected to MQTT broker");
+                if let Err(e) = client.try_subscribe(&cfg.topic, QoS::AtLeastOnce) {
+                    warn!(error = %e, "MQTT subscribe failed");
+                }
+            }
+            Ok(Event::Incoming(Packet::Publish(message))) => {
+                handle_message(&message.topic, &message.payload, parser.as_ref(), &pool).await;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!(error = %e, "MQTT connection error, retrying");
+                actix_web::rt::time::sleep(Duration::from_secs(5)).await;
+            }
+        }
+    }
+}
+
+async fn handle_message(topic: &str, payload: &[u8], parser: &dyn ReadingParser, pool: &DbPool) {
+    let records = match parser.parse(payload) {
+        Ok(records) => records,
+        Err(e) => {
+            warn!(topic, parser = parser.name(), error = %e, "Rejected MQTT message");
+            return;
+        }
+    };
+    let pool = pool.clone();
+    let result = web::block(move || {
+        let mut conn = pool.get().map_err(|e| e.to_string())?;
+        ingest(&records, ReadingSource::Mqtt, &MeterScope::All, &mut conn)
+            .map_err(|e| e.to_string())
+    })
+    .await;
+    match result {
+        Ok(Ok(summary)) => info!(
+            topic,
+            received = summary.received,
+            inserted = summary.inserted,
+            duplicates = summary.duplicates,
+            unknown_meters = ?summary.unknown_meters,
+            "Stored MQTT readings"
+        ),
+        Ok(Err(e)) => warn!(topic, error = %e, "Failed to store MQTT readings"),
+        Err(e) => warn!(topic, error = %e, "Failed to store MQTT readings"),
+    }
+}
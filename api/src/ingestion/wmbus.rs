@@ -0,0 +1,489 @@
+//! Wireless M-Bus (EN 13757-4) and OMS telegrams.
+//!
+//! Telegrams are expected without the link layer CRCs, as receivers and gateways pass
+//! them on, either raw or hex encoded, one per line. The meter is identified by the
+//! 8-digit identification number of its address, which must match the meter's
+//! serial number. Unencrypted telegrams and OMS security mode 5 (AES-128-CBC with a
+//! key per meter) are decoded; the reading is the meter's current volume (m3),
+//! energy (kWh) or heat cost allocator units, timestamped with the meter's own
+//! clock when the telegram carries it.
+
+use super::{ParseError, ReadingParser, ReadingRecord};
+use crate::config::WmbusKeys;
+use aes::cipher::{BlockDecryptMut, KeyIvInit, block_padding::NoPadding};
+use bigdecimal::BigDecimal;
+use bigdecimal::num_bigint::BigInt;
+use chrono::{NaiveDate, NaiveDateTime};
+use std::str::FromStr;
+
+type Aes128CbcDec = cbc::Decryptor<aes::Aes128>;
+
+/// Application layer without header
+const CI_NO_HEADER: u8 = 0x78;
+/// Application layer with the short header: access number, status, configuration
+const CI_SHORT_HEADER: u8 = 0x7A;
+/// Application layer with the long header, which repeats the meter's address
+const CI_LONG_HEADER: u8 = 0x72;
+
+/// Offset of the CI field after the length, control and address fields
+const CI_OFFSET: usize = 10;
+
+/// Decoded address of the meter that sent a telegram
+struct Address {
+    /// Manufacturer code and identification number, version and device type as they
+    /// appear in the telegram; the start of the AES initialisation vector
+    raw: [u8; 8],
+}
+
+impl Address {
+    fn from_link_layer(bytes: &[u8]) -> Self {
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&bytes[2..10]);
+        Self { raw }
+    }
+
+    /// The long header holds the identification number before the manufacturer
+    fn from_long_header(bytes: &[u8]) -> Self {
+        let mut raw = [0u8; 8];
+        raw[..2].copy_from_slice(&bytes[4..6]);
+        raw[2..6].copy_from_slice(&bytes[..4]);
+        raw[6..].copy_from_slice(&bytes[6..8]);
+        Self { raw }
+    }
+
+    /// Identification number: 8 BCD digits, least significant byte first
+    fn id(&self) -> String {
+        self.raw[2..6]
+            .iter()
+            .rev()
+            .map(|b| format!("{:02X}", b))
+            .collect()
+    }
+}
+
+/// Decoder for wireless M-Bus / OMS telegrams.
+pub struct WmbusDecoder {
+    keys: WmbusKeys,
+}
+
+impl WmbusDecoder {
+    pub fn new(keys: WmbusKeys) -> Self {
+        Self { keys }
+    }
+
+    /// Decode one telegram. `received_at` stands in for the time when the telegram
+    /// does not carry the meter's clock.
+    pub fn decode(
+        &self,
+        telegram: &[u8],
+        received_at: NaiveDateTime,
+    ) -> Result<ReadingRecord, ParseError> {
+        if telegram.len() <= CI_OFFSET || telegram[0] as usize != telegram.len() - 1 {
+            return Err(ParseError::Invalid(
+                "telegram length does not match its L-field".into(),
+            ));
+        }
+        let mut address = Address::from_link_layer(telegram);
+        let ci = telegram[CI_OFFSET];
+        let (header, data_start) = match ci {
+            CI_NO_HEADER => (None, CI_OFFSET + 1),
+            CI_SHORT_HEADER => (telegram.get(CI_OFFSET + 1..CI_OFFSET + 5), CI_OFFSET + 5),
+            CI_LONG_HEADER => {
+                let long = telegram
+                    .get(CI_OFFSET + 1..CI_OFFSET + 13)
+                    .ok_or_else(|| ParseError::Invalid("truncated header".into()))?;
+                address = Address::from_long_header(long);
+                (long.get(8..12), CI_OFFSET + 13)
+            }
+            other => {
+                return Err(ParseError::Unsupported(format!("CI field {:#04x}", other)));
+            }
+        };
+        let id = address.id();
+
+        let mut data = telegram
+            .get(data_start..)
+            .ok_or_else(|| ParseError::Invalid("truncated header".into()))?
+            .to_vec();
+        if let Some(header) = header {
+            let (access_number, config) = match header {
+                [access_number, _status, low, high] => {
+                    (*access_number, u16::from_le_bytes([*low, *high]))
+                }
+                _ => return Err(ParseError::Invalid("truncated header".into())),
+            };
+            match (config >> 8) & 0x1F {
+                0 => {}
+                5 => {
+                    let blocks = ((config >> 4) & 0x0F) as usize;
+                    self.decrypt(&id, &address, access_number, &mut data, blocks)?;
+                }
+                mode => {
+                    return Err(ParseError::Unsupported(format!("security mode {}", mode)));
+                }
+            }
+        }
+
+        let (value, unit, timestamp) = read_records(&data)?;
+        ReadingRecord::new(&id, value, timestamp.unwrap_or(received_at), unit)
+    }
+
+    fn decrypt(
+        &self,
+        id: &str,
+        address: &Address,
+        access_number: u8,
+        data: &mut [u8],
+        blocks: usize,
+    ) -> Result<(), ParseError> {
+        let key = self
+            .keys
+            .0
+            .get(id)
+            .ok_or_else(|| ParseError::MissingKey(id.to_string()))?;
+        let encrypted = data
+            .get_mut(..blocks * 16)
+            .ok_or_else(|| ParseError::Invalid("truncated encrypted data".into()))?;
+        let mut iv = [access_number; 16];
+        iv[..8].copy_from_slice(&address.raw);
+        Aes128CbcDec::new_from_slices(key, &iv)
+            .map_err(|_| ParseError::Invalid("invalid key".into()))?
+            .decrypt_padded_mut::<NoPadding>(encrypted)
+            .map_err(|_| ParseError::Invalid("invalid encrypted data".into()))?;
+        // Decrypted data starts with two filler bytes; anything else means a wrong key
+        if !encrypted.starts_with(&[0x2F, 0x2F]) {
+            return Err(ParseError::Invalid(format!(
+                "telegram of meter {} could not be decrypted, check its key",
+                id
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Length of the data of a record by the data field of its DIF; `None` for variable
+/// length and special functions.
+fn data_length(data_field: u8) -> Option<usize> {
+    match data_field {
+        0x0 | 0x8 => Some(0),
+        0x1 | 0x9 => Some(1),
+        0x2 | 0xA => Some(2),
+        0x3 | 0xB => Some(3),
+        0x4 | 0x5 | 0xC => Some(4),
+        0x6 | 0xE => Some(6),
+        0x7 => Some(8),
+        _ => None,
+    }
+}
+
+/// Integer value of a record: binary little endian, or BCD for data fields 9 to E.
+fn integer(data_field: u8, bytes: &[u8]) -> Option<i64> {
+    if (0x9..=0xE).contains(&data_field) {
+        let mut value: i64 = 0;
+        for byte in bytes.iter().rev() {
+            let (high, low) = (byte >> 4, byte & 0x0F);
+            if high > 9 || low > 9 {
+                return None;
+            }
+            value = value * 100 + (high * 10 + low) as i64;
+        }
+        return Some(value);
+    }
+    let mut buf = [0u8; 8];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    // Sign-extend from the record's width
+    let shift = 64 - 8 * bytes.len() as u32;
+    Some(
+        i64::from_le_bytes(buf)
+            .wrapping_shl(shift)
+            .wrapping_shr(shift),
+    )
+}
+
+/// Date and time of type F (compound CP32)
+fn date_time(bytes: &[u8]) -> Option<NaiveDateTime> {
+    let [minute, hour, day, month] = bytes else {
+        return None;
+    };
+    if minute & 0x80 != 0 {
+        return None; // marked invalid
+    }
+    let year = ((day & 0xE0) >> 5) | ((month & 0xF0) >> 1);
+    NaiveDate::from_ymd_opt(
+        2000 + year as i32,
+        (month & 0x0F) as u32,
+        (day & 0x1F) as u32,
+    )?
+    .and_hms_opt((hour & 0x1F) as u32, (minute & 0x3F) as u32, 0)
+}
+
+/// The current reading and, if present, the meter's clock from the data records.
+fn read_records(
+    data: &[u8],
+) -> Result<(BigDecimal, &'static str, Option<NaiveDateTime>), ParseError> {
+    let truncated = || ParseError::Invalid("truncated data record".into());
+    let mut reading = None;
+    let mut timestamp = None;
+    let mut pos = 0;
+    while pos < data.len() {
+        let dif = data[pos];
+        pos += 1;
+        match dif {
+            0x2F => continue,     // filler
+            0x0F | 0x1F => break, // manufacturer specific data follows
+            _ => {}
+        }
+        let data_field = dif & 0x0F;
+        let function = (dif >> 4) & 0x03;
+        let mut storage = u32::from((dif >> 6) & 0x01);
+        let mut extended = dif & 0x80 != 0;
+        let mut shift = 1;
+        while extended {
+            let dife = *data.get(pos).ok_or_else(truncated)?;
+            pos += 1;
+            storage |= u32::from(dife & 0x0F) << shift;
+            shift += 4;
+            extended = dife & 0x80 != 0;
+        }
+
+        let vif = *data.get(pos).ok_or_else(truncated)?;
+        pos += 1;
+        if vif & 0x7F == 0x7C {
+            // Plain-text unit, skipped with the record
+            let length = *data.get(pos).ok_or_else(truncated)? as usize;
+            pos += 1 + length;
+        }
+        let mut has_vife = vif & 0x80 != 0;
+        let with_vife = has_vife;
+        while has_vife {
+            has_vife = data.get(pos).ok_or_else(truncated)? & 0x80 != 0;
+            pos += 1;
+        }
+
+        let length = match data_length(data_field) {
+            Some(length) => length,
+            None if data_field == 0xD => {
+                let length = *data.get(pos).ok_or_else(truncated)? as usize;
+                pos += 1;
+                length & 0x3F
+            }
+            None => {
+                return Err(ParseError::Unsupported(format!(
+                    "data field {:#x}",
+                    data_field
+                )));
+            }
+        };
+        let bytes = data.get(pos..pos + length).ok_or_else(truncated)?;
+        pos += length;
+
+        // Only current, instantaneous values with a plain VIF count
+        if storage != 0 || function != 0 || with_vife {
+            continue;
+        }
+        let code = vif & 0x7F;
+        if code == 0x6D && data_field == 0x4 {
+            timestamp = date_time(bytes);
+            continue;
+        }
+        if reading.is_some() {
+            continue;
+        }
+        // Units and the power of ten relative to them
+        let (unit, exponent) = match code {
+            0x00..=0x07 => ("kWh", i64::from(code) - 6), // Wh × 10^(n-3)
+            0x10..=0x17 => ("m3", i64::from(code & 0x07) - 6), // m3 × 10^(n-6)
+            0x6E => ("HCA", 0),
+            _ => continue,
+        };
+        let value = if data_field == 0x5 {
+            let raw = f32::from_le_bytes(bytes.try_into().map_err(|_| truncated())?);
+            BigDecimal::from_str(&raw.to_string())
+                .map_err(|_| ParseError::Invalid("invalid value".into()))?
+                * BigDecimal::new(BigInt::from(1), -exponent)
+        } else {
+            let raw = integer(data_field, bytes)
+                .ok_or_else(|| ParseError::Invalid("invalid BCD value".into()))?;
+            BigDecimal::new(BigInt::from(raw), -exponent)
+        };
+        reading = Some((value.round(4), unit));
+    }
+
+    let (value, unit) = reading.ok_or_else(|| {
+        ParseError::Unsupported("no volume, energy or allocator reading in the telegram".into())
+    })?;
+    Ok((value, unit, timestamp))
+}
+
+/// Telegrams as raw bytes or hex, one per line.
+fn telegrams(payload: &[u8]) -> Result<Vec<Vec<u8>>, ParseError> {
+    let Ok(text) = std::str::from_utf8(payload) else {
+        return Ok(vec![payload.to_vec()]);
+    };
+    if !text
+        .trim()
+        .chars()
+        .all(|c| c.is_ascii_hexdigit() || c.is_whitespace())
+    {
+        return Ok(vec![payload.to_vec()]);
+    }
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<String>())
+        .filter(|line| !line.is_empty())
+        .map(|hex| {
+            if hex.len() % 2 != 0 {
+                return Err(ParseError::Invalid("odd number of hex digits".into()));
+            }
+            (0..hex.len())
+                .step_by(2)
+                .map(|i| {
+                    u8::from_str_radix(&hex[i..i + 2], 16)
+                        .map_err(|_| ParseError::Invalid("invalid hex".into()))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+impl ReadingParser for WmbusDecoder {
+    fn name(&self) -> &'static str {
+        "wmbus"
+    }
+
+    fn parse(&self, payload: &[u8]) -> Result<Vec<ReadingRecord>, ParseError> {
+        let received_at = chrono::Utc::now().naive_utc();
+        telegrams(payload)?
+            .iter()
+            .map(|telegram| self.decode(telegram, received_at))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes::cipher::BlockEncryptMut;
+    use std::collections::HashMap;
+
+    const KEY: [u8; 16] = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+        0xFF,
+    ];
+
+    /// Link layer of a Kamstrup water meter 12345678 followed by `rest`
+    fn telegram(rest: &[u8]) -> Vec<u8> {
+        let mut t = vec![0, 0x44, 0x2D, 0x2C, 0x78, 0x56, 0x34, 0x12, 0x1B, 0x07];
+        t.extend_from_slice(rest);
+        t[0] = (t.len() - 1) as u8;
+        t
+    }
+
+    fn received_at() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 4, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    fn decoder() -> WmbusDecoder {
+        WmbusDecoder::new(WmbusKeys(HashMap::from([("12345678".to_string(), KEY)])))
+    }
+
+    #[test]
+    fn decodes_volume_and_meter_clock() {
+        let t = telegram(
+            &[
+                &[CI_NO_HEADER][..],
+                &[0x04, 0x13, 0x39, 0x30, 0x00, 0x00], // 12345 l
+                &[0x04, 0x6D, 0x1E, 0x0A, 0x4F, 0x33], // 2026-03-15 10:30
+            ]
+            .concat(),
+        );
+        let record = decoder().decode(&t, received_at()).unwrap();
+        assert_eq!(record.serial_number, "12345678");
+        assert_eq!(
+            record.reading_value,
+            BigDecimal::from_str("12.345").unwrap()
+        );
+        assert_eq!(record.unit, "m3");
+        assert_eq!(
+            record.timestamp,
+            NaiveDate::from_ymd_opt(2026, 3, 15)
+                .unwrap()
+                .and_hms_opt(10, 30, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn decodes_bcd_energy_and_skips_historic_values() {
+        let t = telegram(
+            &[
+                &[CI_SHORT_HEADER, 0x01, 0x00, 0x00, 0x00][..],
+                &[0x4C, 0x06, 0x00, 0x10, 0x00, 0x00], // storage 1: 1000 kWh
+                &[0x0C, 0x06, 0x45, 0x23, 0x01, 0x00], // 12345 kWh
+            ]
+            .concat(),
+        );
+        let record = decoder().decode(&t, received_at()).unwrap();
+        assert_eq!(record.reading_value, BigDecimal::from(12345));
+        assert_eq!(record.unit, "kWh");
+        assert_eq!(record.timestamp, received_at());
+    }
+
+    #[test]
+    fn decrypts_security_mode_5() {
+        let access_number = 0x2A;
+        let mut plain = vec![0x2F, 0x2F, 0x04, 0x13, 0x39, 0x30, 0x00, 0x00];
+        plain.resize(16, 0x2F);
+        let mut iv = [access_number; 16];
+        iv[..8].copy_from_slice(&[0x2D, 0x2C, 0x78, 0x56, 0x34, 0x12, 0x1B, 0x07]);
+        cbc::Encryptor::<aes::Aes128>::new_from_slices(&KEY, &iv)
+            .unwrap()
+            .encrypt_padded_mut::<NoPadding>(&mut plain, 16)
+            .unwrap();
+        // One encrypted block, mode 5
+        let mut rest = vec![CI_SHORT_HEADER, access_number, 0x00, 0x10, 0x05];
+        rest.extend_from_slice(&plain);
+        let t = telegram(&rest);
+
+        let record = decoder().decode(&t, received_at()).unwrap();
+        assert_eq!(
+            record.reading_value,
+            BigDecimal::from_str("12.345").unwrap()
+        );
+
+        let no_keys = WmbusDecoder::new(WmbusKeys::default());
+        assert!(matches!(
+            no_keys.decode(&t, received_at()),
+            Err(ParseError::MissingKey(id)) if id == "12345678"
+        ));
+        let wrong_key = WmbusDecoder::new(WmbusKeys(HashMap::from([(
+            "12345678".to_string(),
+            [0u8; 16],
+        )])));
+        assert!(wrong_key.decode(&t, received_at()).is_err());
+    }
+
+    #[test]
+    fn parses_hex_lines() {
+        let t = telegram(&[CI_NO_HEADER, 0x04, 0x13, 0x39, 0x30, 0x00, 0x00]);
+        let hex: String = t.iter().map(|b| format!("{:02x}", b)).collect();
+        let records = decoder()
+            .parse(format!("{}\n{}\n", hex, hex).as_bytes())
+            .unwrap();
+        assert_eq!(records.len(), 2);
+    }
+
+    #[test]
+    fn rejects_bad_length_and_unknown_ci() {
+        let mut t = telegram(&[CI_NO_HEADER, 0x04, 0x13, 0x39, 0x30, 0x00, 0x00]);
+        t[0] += 1;
+        assert!(decoder().decode(&t, received_at()).is_err());
+        let t = telegram(&[0x51, 0x00]);
+        assert!(matches!(
+            decoder().decode(&t, received_at()),
+            Err(ParseError::Unsupported(_))
+        ));
+    }
+}
@@ -5,14 +5,23 @@ pub mod buildings;
 pub mod config;
 pub mod dashboard;
 pub mod db;
+pub mod export;
+pub mod feeds;
 pub mod i18n;
+pub mod ingestion;
 pub mod invitations;
 pub mod maintenance;
+pub mod meetings;
 pub mod meters;
 pub mod models;
+pub mod notifications;
 pub mod openapi;
 pub mod pagination;
+pub mod polls;
+pub mod scanning;
 pub mod schema;
+pub mod storage;
+pub mod uploads;
 pub mod users;
 pub mod voting;
 
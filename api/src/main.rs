@@ -4,7 +4,8 @@ use actix_web::{App, HttpRequest, HttpServer, Responder, web};
 use api::i18n::{get_message, init_translations, negotiate_language};
 use api::{
     AppConfig, DbPool, JwtKeys, MIGRATIONS, announcements, apartments, auth, buildings, dashboard,
-    invitations, maintenance, meters, openapi::ApiDoc, users, voting,
+    feeds, ingestion, invitations, maintenance, meetings, meters, notifications, openapi::ApiDoc,
+    polls, scanning, storage, users, voting,
 };
 use diesel::mysql::MysqlConnection;
 use diesel::r2d2::ConnectionManager;
@@ -128,6 +129,19 @@ async fn main() -> std::io::Result<()> {
         mime_types = ?app_config.allowed_mime_types,
         "AppConfig loaded"
     );
+    let storage = storage::from_config(&app_config).expect("Failed to initialise storage backend");
+    info!(backend = storage.name(), "Attachment storage ready");
+    let scanner = scanning::from_config(&app_config);
+    info!(backend = scanner.name(), "Upload scanner ready");
+    if let Some(mqtt) = app_config.mqtt.clone() {
+        let parser = ingestion::parser_for(mqtt.payload, &app_config);
+        info!(broker = %mqtt.host, topic = %mqtt.topic, parser = parser.name(), "Starting MQTT ingestion");
+        actix_web::rt::spawn(ingestion::mqtt::run(mqtt, parser, pool.clone()));
+    }
+    if let Some(schedule) = app_config.campaign_schedule {
+        info!(interval = ?schedule.interval, reminder_days = schedule.reminder_days, "Starting reading campaign schedule");
+        actix_web::rt::spawn(meters::run_campaign_schedule(schedule, pool.clone()));
+    }
 
     let openapi = ApiDoc::openapi();
 
@@ -137,6 +151,8 @@ async fn main() -> std::io::Result<()> {
             .app_data(web::Data::new(pool.clone()))
             .app_data(web::Data::new(keys.clone()))
             .app_data(web::Data::new(app_config.clone()))
+            .app_data(web::Data::from(storage.clone()))
+            .app_data(web::Data::from(scanner.clone()))
             .service(
                 SwaggerUi::new("/swagger-ui/{_:.*}").url("/api-docs/openapi.json", openapi.clone()),
             )
@@ -151,8 +167,12 @@ async fn main() -> std::io::Result<()> {
                     .configure(maintenance::configure)
                     .configure(announcements::configure)
                     .configure(voting::configure)
+                    .configure(polls::configure)
+                    .configure(meetings::configure)
                     .configure(meters::configure)
                     .configure(dashboard::configure)
+                    .configure(notifications::configure)
+                    .configure(feeds::configure)
                     .configure(invitations::configure),
             )
     })
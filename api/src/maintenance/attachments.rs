@@ -3,15 +3,17 @@ use crate::config::AppConfig;
 use crate::db::DbPool;
 use crate::models::MaintenanceRequest; // for RBAC checks
 use crate::models::MaintenanceRequestAttachment;
+use crate::scanning::Scanner;
 use crate::schema::{apartment_owners as ao, maintenance_requests as mr};
+use crate::storage::Storage;
+use crate::uploads::{
+    self, SCAN_CLEAN, SCAN_INFECTED, SCAN_PENDING, ScanOutcome, UploadKeys, images,
+};
 use actix_multipart::Multipart;
-use actix_web::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
-use actix_web::{HttpResponse, web};
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::{HttpRequest, HttpResponse, mime, web};
 use diesel::mysql::MysqlConnection;
 use diesel::prelude::*;
-use futures_util::StreamExt;
-use std::fs;
-use std::io::Write;
 use utoipa;
 use uuid::Uuid;
 
@@ -19,6 +21,8 @@ use uuid::Uuid;
 ///
 /// Uploads a file attachment to a maintenance request using multipart/form-data.
 /// File size limit and MIME type restrictions apply (configured in AppConfig).
+/// The file is quarantined and scanned for malware before it becomes visible;
+/// JPEG/PNG images then have their metadata stripped and get a thumbnail.
 /// Accessible by Admin, Manager, request creator, assigned user, or apartment owner.
 #[utoipa::path(
     post,
@@ -29,9 +33,10 @@ use uuid::Uuid;
     request_body(content_type = "multipart/form-data"),
     responses(
         (status = 201, description = "Attachment uploaded successfully"),
-        (status = 400, description = "Bad request - no file or invalid MIME type"),
+        (status = 400, description = "Bad request - no file, invalid MIME type or undecodable image"),
         (status = 403, description = "Forbidden - cannot modify this request"),
         (status = 413, description = "File too large"),
+        (status = 422, description = "Malware detected - upload rejected"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Maintenance",
@@ -42,6 +47,8 @@ pub async fn upload_attachment(
     path: web::Path<u64>,
     pool: web::Data<DbPool>,
     cfg: web::Data<AppConfig>,
+    storage: web::Data<dyn Storage>,
+    scanner: web::Data<dyn Scanner>,
     mut payload: Multipart,
 ) -> Result<HttpResponse, AppError> {
     use crate::schema::maintenance_request_attachments::dsl as att;
@@ -59,67 +66,75 @@ pub async fn upload_attachment(
     if !perms.can_modify {
         return Err(AppError::Forbidden);
     }
-    let mut file_bytes: Vec<u8> = Vec::new();
-    let mut original_filename = None;
-    while let Some(item) = payload.next().await {
-        let mut field = item.map_err(|e| AppError::Internal(format!("multipart: {}", e)))?;
-        let name = field.name().to_string();
-        if name != "file" {
-            continue;
-        }
-        if let Some(fname) = field.content_disposition().get_filename() {
-            original_filename = Some(fname.to_string());
-        }
-        while let Some(chunk_res) = field.next().await {
-            let chunk = chunk_res.map_err(|e| AppError::Internal(format!("chunk: {}", e)))?;
-            file_bytes.extend_from_slice(&chunk);
-            if file_bytes.len() as u64 > cfg.max_attachment_size_bytes {
-                return Err(AppError::AttachmentTooLarge);
-            }
-        }
-        break; // only first file
-    }
-    let original_filename = original_filename.unwrap_or_else(|| "upload.bin".into());
-    if file_bytes.is_empty() {
-        return Err(AppError::BadRequest("no_file".into()));
-    }
-    let detected = infer::get(&file_bytes).map(|t| t.mime_type());
-    let mime = detected.unwrap_or("application/octet-stream");
-    if !cfg.allowed_mime_types.iter().any(|m| m == mime) {
-        return Err(AppError::InvalidMimeType);
-    }
+    let upload = uploads::receive(&mut payload, &cfg).await?;
     let stored_filename = format!("{}", Uuid::new_v4());
-    let dir_path = std::path::Path::new(&cfg.attachments_base_path).join(request_id.to_string());
-    fs::create_dir_all(&dir_path)
-        .map_err(|e| AppError::Internal(format!("fs_create_dir: {}", e)))?;
-    let tmp_path = dir_path.join(format!("{}.tmp", stored_filename));
-    let final_path = dir_path.join(&stored_filename);
-    {
-        let mut f = std::fs::File::create(&tmp_path)
-            .map_err(|e| AppError::Internal(format!("file_create: {}", e)))?;
-        f.write_all(&file_bytes)
-            .map_err(|e| AppError::Internal(format!("file_write: {}", e)))?;
-    }
-    fs::rename(&tmp_path, &final_path)
-        .map_err(|e| AppError::Internal(format!("file_rename: {}", e)))?;
-    let mut conn = pool
-        .get()
-        .map_err(|_| AppError::Internal("db_pool".into()))?;
-    diesel::insert_into(att::maintenance_request_attachments)
-        .values((
-            att::request_id.eq(request_id),
-            att::original_filename.eq(sanitize_filename(&original_filename)),
-            att::stored_filename.eq(stored_filename.clone()),
-            att::mime_type.eq(mime.to_string()),
-            att::size_bytes.eq(file_bytes.len() as u64),
-            att::is_deleted.eq(false),
-        ))
-        .execute(&mut conn)?;
+    let upload_keys = UploadKeys {
+        quarantine: quarantine_key(request_id, &stored_filename),
+        object: object_key(request_id, &stored_filename),
+        thumbnail: Some(thumbnail_key(request_id, &stored_filename)),
+    };
+    uploads::store_scanned(
+        upload,
+        &upload_keys,
+        storage.get_ref(),
+        scanner.get_ref(),
+        &mut conn,
+        |upload, conn| {
+            diesel::insert_into(att::maintenance_request_attachments)
+                .values((
+                    att::request_id.eq(request_id),
+                    att::original_filename.eq(&upload.original_filename),
+                    att::stored_filename.eq(&stored_filename),
+                    att::mime_type.eq(upload.mime),
+                    att::size_bytes.eq(upload.data.len() as u64),
+                    att::is_deleted.eq(false),
+                    att::scan_status.eq(SCAN_PENDING),
+                ))
+                .execute(conn)
+        },
+        |att_id, outcome, conn| {
+            let row = att::maintenance_request_attachments.filter(att::id.eq(att_id));
+            match outcome {
+                ScanOutcome::Clean(stored) => diesel::update(row)
+                    .set((
+                        att::size_bytes.eq(stored.size_bytes),
+                        att::checksum_sha256.eq(Some(stored.checksum_sha256)),
+                        att::has_thumbnail.eq(stored.has_thumbnail),
+                        att::scan_status.eq(SCAN_CLEAN),
+                    ))
+                    .execute(conn),
+                ScanOutcome::Infected(signature) => diesel::update(row)
+                    .set((
+                        att::scan_status.eq(SCAN_INFECTED),
+                        att::scan_signature.eq(Some(signature)),
+                    ))
+                    .execute(conn),
+                ScanOutcome::Discarded => diesel::delete(row).execute(conn),
+            }
+        },
+    )
+    .await?;
     Ok(HttpResponse::Created().finish())
 }
 
-fn sanitize_filename(name: &str) -> String {
-    name.replace(['/', '\\'], "_")
+/// Storage key of an attachment's content.
+pub fn object_key(request_id: u64, stored_filename: &str) -> String {
+    format!("{}/{}", request_id, stored_filename)
+}
+
+/// Storage key of an upload held in quarantine until it has been scanned.
+pub fn quarantine_key(request_id: u64, stored_filename: &str) -> String {
+    format!("quarantine/{}/{}", request_id, stored_filename)
+}
+
+/// Storage key of an image attachment's thumbnail.
+pub fn thumbnail_key(request_id: u64, stored_filename: &str) -> String {
+    format!(
+        "{}/{}{}",
+        request_id,
+        stored_filename,
+        images::THUMBNAIL_SUFFIX
+    )
 }
 
 fn load_request(
@@ -208,6 +223,7 @@ pub async fn list_attachments(
     let rows = att::maintenance_request_attachments
         .filter(att::request_id.eq(request_id))
         .filter(att::is_deleted.eq(false))
+        .filter(att::scan_status.eq(SCAN_CLEAN))
         .select(MaintenanceRequestAttachment::as_select())
         .load(&mut conn)?;
     Ok(HttpResponse::Ok().json(rows))
@@ -253,6 +269,7 @@ pub async fn list_deleted_attachments(
     let rows = att::maintenance_request_attachments
         .filter(att::request_id.eq(request_id))
         .filter(att::is_deleted.eq(true))
+        .filter(att::scan_status.eq(SCAN_CLEAN))
         .select(MaintenanceRequestAttachment::as_select())
         .load(&mut conn)?;
     Ok(HttpResponse::Ok().json(rows))
@@ -298,6 +315,7 @@ pub async fn get_attachment_metadata(
     let item: MaintenanceRequestAttachment = att::maintenance_request_attachments
         .filter(att::id.eq(att_id))
         .filter(att::request_id.eq(request_id))
+        .filter(att::scan_status.eq(SCAN_CLEAN))
         .select(MaintenanceRequestAttachment::as_select())
         .first(&mut conn)?;
     Ok(HttpResponse::Ok().json(item))
@@ -305,8 +323,9 @@ pub async fn get_attachment_metadata(
 
 /// Download attachment file
 ///
-/// Downloads the actual file content for an attachment. Returns the file with appropriate
-/// Content-Type and Content-Disposition headers. Only non-deleted attachments can be downloaded.
+/// Streams the file content for an attachment with appropriate Content-Type and
+/// Content-Disposition headers. Supports `Range` requests for partial content.
+/// Only non-deleted attachments can be downloaded.
 /// Accessible by Admin, Manager, request creator, assigned user, or apartment owner.
 #[utoipa::path(
     get,
@@ -317,8 +336,10 @@ pub async fn get_attachment_metadata(
     ),
     responses(
         (status = 200, description = "File content", content_type = "application/octet-stream"),
+        (status = 206, description = "Partial file content for a Range request", content_type = "application/octet-stream"),
         (status = 403, description = "Forbidden - cannot view this request"),
         (status = 404, description = "Attachment not found or deleted"),
+        (status = 416, description = "Requested range not satisfiable"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Maintenance",
@@ -326,19 +347,85 @@ pub async fn get_attachment_metadata(
 )]
 pub async fn download_attachment(
     auth: AuthContext,
+    http_req: HttpRequest,
     path: web::Path<(u64, u64)>,
     pool: web::Data<DbPool>,
-    cfg: web::Data<AppConfig>,
+    storage: web::Data<dyn Storage>,
 ) -> Result<HttpResponse, AppError> {
-    use crate::schema::maintenance_request_attachments::dsl as att;
     let (request_id, att_id) = path.into_inner();
+    let item = load_viewable_attachment(&auth, request_id, att_id, &pool)?;
+    let key = object_key(request_id, &item.stored_filename);
+    let mime_type: mime::Mime = item
+        .mime_type
+        .parse()
+        .unwrap_or(mime::APPLICATION_OCTET_STREAM);
+    let disposition = ContentDisposition {
+        disposition: DispositionType::Attachment,
+        parameters: vec![DispositionParam::Filename(item.original_filename)],
+    };
+
+    uploads::serve(
+        &http_req,
+        storage.get_ref(),
+        &key,
+        item.size_bytes,
+        mime_type,
+        disposition,
+    )
+    .await
+}
+
+/// Download attachment thumbnail
+///
+/// Returns a small JPEG preview (at most 320px on the longest edge) for image attachments.
+/// Accessible by Admin, Manager, request creator, assigned user, or apartment owner.
+#[utoipa::path(
+    get,
+    path = "/api/v1/requests/{id}/attachments/{att_id}/thumbnail",
+    params(
+        ("id" = u64, Path, description = "Maintenance request ID"),
+        ("att_id" = u64, Path, description = "Attachment ID")
+    ),
+    responses(
+        (status = 200, description = "Thumbnail image", content_type = "image/jpeg"),
+        (status = 403, description = "Forbidden - cannot view this request"),
+        (status = 404, description = "Attachment not found, deleted, or has no thumbnail"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Maintenance",
+    security(("bearer_auth" = []))
+)]
+pub async fn download_thumbnail(
+    auth: AuthContext,
+    http_req: HttpRequest,
+    path: web::Path<(u64, u64)>,
+    pool: web::Data<DbPool>,
+    storage: web::Data<dyn Storage>,
+) -> Result<HttpResponse, AppError> {
+    let (request_id, att_id) = path.into_inner();
+    let item = load_viewable_attachment(&auth, request_id, att_id, &pool)?;
+    if !item.has_thumbnail {
+        return Err(AppError::NotFound);
+    }
+    let key = thumbnail_key(request_id, &item.stored_filename);
+    uploads::serve_thumbnail(&http_req, storage.get_ref(), &key).await
+}
+
+/// Load a non-deleted attachment after checking the caller may view its request.
+fn load_viewable_attachment(
+    auth: &AuthContext,
+    request_id: u64,
+    att_id: u64,
+    pool: &DbPool,
+) -> Result<MaintenanceRequestAttachment, AppError> {
+    use crate::schema::maintenance_request_attachments::dsl as att;
     let mut conn = pool
         .get()
         .map_err(|_| AppError::Internal("db_pool".into()))?;
     let req = load_request(request_id, &mut conn)?;
     let user_id = auth.claims.sub.parse().unwrap_or(0);
     let owns = user_owns_apartment(user_id, req.apartment_id, &mut conn)?;
-    let perms = compute_perms(&auth, &req, owns, user_id);
+    let perms = compute_perms(auth, &req, owns, user_id);
     if !perms.can_view {
         return Err(AppError::Forbidden);
     }
@@ -346,19 +433,10 @@ pub async fn download_attachment(
         .filter(att::id.eq(att_id))
         .filter(att::request_id.eq(request_id))
         .filter(att::is_deleted.eq(false))
+        .filter(att::scan_status.eq(SCAN_CLEAN))
         .select(MaintenanceRequestAttachment::as_select())
         .first(&mut conn)?;
-    let file_path = std::path::Path::new(&cfg.attachments_base_path)
-        .join(request_id.to_string())
-        .join(&item.stored_filename);
-    let data = std::fs::read(&file_path).map_err(|_| AppError::NotFound)?;
-    Ok(HttpResponse::Ok()
-        .insert_header((CONTENT_TYPE, item.mime_type.clone()))
-        .insert_header((
-            CONTENT_DISPOSITION,
-            format!("attachment; filename=\"{}\"", item.original_filename),
-        ))
-        .body(data))
+    Ok(item)
 }
 
 /// Soft-delete attachment
@@ -0,0 +1,317 @@
+use super::types::{
+    FeedbackAggregate, FeedbackPayload, FeedbackSummary, FeedbackSummaryParams, FeedbackSummaryRow,
+};
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::models::{
+    MaintenanceRequest, MaintenanceRequestFeedback, NewMaintenanceRequestFeedback,
+};
+use actix_web::{HttpResponse, Responder, web};
+use diesel::prelude::*;
+use std::collections::HashMap;
+
+/// Statuses in which the creator is asked to rate the outcome.
+const FEEDBACK_STATUSES: [&str; 2] = ["Resolved", "Closed"];
+
+/// Submit satisfaction feedback
+///
+/// Lets the creator of a Resolved or Closed request rate the outcome and confirm whether
+/// it is actually fixed. Reporting it as not fixed reopens the request and records a
+/// history entry. Once the creator confirms a fix, no further feedback is accepted.
+#[utoipa::path(
+    post,
+    path = "/api/v1/requests/{id}/feedback",
+    params(
+        ("id" = u64, Path, description = "Maintenance request ID")
+    ),
+    request_body = FeedbackPayload,
+    responses(
+        (status = 201, description = "Feedback recorded", body = MaintenanceRequestFeedback),
+        (status = 400, description = "Invalid rating, request not resolved, or feedback already given"),
+        (status = 403, description = "Forbidden - only the request creator can give feedback"),
+        (status = 404, description = "Request not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Maintenance",
+    security(("bearer_auth" = []))
+)]
+pub async fn submit_feedback(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+    payload: web::Json<FeedbackPayload>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::maintenance_request_feedback::dsl as fb;
+    use crate::schema::maintenance_request_history::dsl as hist;
+    use crate::schema::maintenance_requests::dsl as mr;
+
+    let request_id = path.into_inner();
+    let user_id = auth.user_id()?;
+    let FeedbackPayload {
+        rating,
+        is_fixed,
+        comment,
+    } = payload.into_inner();
+    if !(1..=5).contains(&rating) {
+        return Err(AppError::BadRequest("invalid_rating".into()));
+    }
+    let comment = comment
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty());
+
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    // The request row stays locked until the feedback and any reopening are saved,
+    // so two submissions cannot both pass the check below.
+    let feedback = conn.transaction::<_, AppError, _>(|conn| {
+        let request: MaintenanceRequest = mr::maintenance_requests
+            .filter(mr::id.eq(request_id))
+            .select(MaintenanceRequest::as_select())
+            .for_update()
+            .first(conn)?;
+
+        if request.created_by != user_id {
+            return Err(AppError::Forbidden);
+        }
+        if !FEEDBACK_STATUSES.contains(&request.status.as_str()) {
+            return Err(AppError::BadRequest("request_not_resolved".into()));
+        }
+
+        // A "not fixed" answer reopens the request, so a later resolution asks again;
+        // a confirmed fix is final.
+        let last_fixed: Option<bool> = fb::maintenance_request_feedback
+            .filter(fb::request_id.eq(request_id))
+            .order(fb::id.desc())
+            .select(fb::is_fixed)
+            .first(conn)
+            .optional()?;
+        if last_fixed == Some(true) {
+            return Err(AppError::BadRequest("feedback_already_submitted".into()));
+        }
+
+        diesel::insert_into(fb::maintenance_request_feedback)
+            .values(&NewMaintenanceRequestFeedback {
+                request_id,
+                user_id,
+                rating,
+                is_fixed,
+                comment: comment.clone(),
+                assigned_to: request.assigned_to,
+                vendor: request.vendor.clone(),
+            })
+            .execute(conn)?;
+
+        let feedback_id: u64 = diesel::select(diesel::dsl::sql::<
+            diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+        >("LAST_INSERT_ID()"))
+        .first(conn)?;
+
+        if !is_fixed {
+            diesel::update(mr::maintenance_requests.filter(mr::id.eq(request_id)))
+                .set(mr::status.eq("Open"))
+                .execute(conn)?;
+
+            let note = match &comment {
+                Some(c) => format!("Reopened by resident: not fixed ({})", c),
+                None => "Reopened by resident: not fixed".to_string(),
+            };
+            diesel::insert_into(hist::maintenance_request_history)
+                .values((
+                    hist::request_id.eq(request_id),
+                    hist::from_status.eq(Some(request.status)),
+                    hist::to_status.eq("Open"),
+                    hist::note.eq(Some(note)),
+                    hist::changed_by.eq(user_id),
+                ))
+                .execute(conn)?;
+        }
+
+        let feedback: MaintenanceRequestFeedback = fb::maintenance_request_feedback
+            .filter(fb::id.eq(feedback_id))
+            .select(MaintenanceRequestFeedback::as_select())
+            .first(conn)?;
+
+        Ok(feedback)
+    })?;
+
+    Ok(HttpResponse::Created().json(feedback))
+}
+
+/// List feedback for a request
+///
+/// Returns all satisfaction feedback given on a request, oldest first.
+/// Accessible by Admin, Manager, the request creator, or the assignee.
+#[utoipa::path(
+    get,
+    path = "/api/v1/requests/{id}/feedback",
+    params(
+        ("id" = u64, Path, description = "Maintenance request ID")
+    ),
+    responses(
+        (status = 200, description = "Feedback entries", body = Vec<MaintenanceRequestFeedback>),
+        (status = 403, description = "Forbidden - cannot view this request"),
+        (status = 404, description = "Request not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Maintenance",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_feedback(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::maintenance_request_feedback::dsl as fb;
+    use crate::schema::maintenance_requests::dsl as mr;
+
+    let request_id = path.into_inner();
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let request: MaintenanceRequest = mr::maintenance_requests
+        .filter(mr::id.eq(request_id))
+        .select(MaintenanceRequest::as_select())
+        .first(&mut conn)?;
+
+    if !(auth.has_any_role(&["Admin", "Manager"])
+        || request.created_by == user_id
+        || request.assigned_to == Some(user_id))
+    {
+        return Err(AppError::Forbidden);
+    }
+
+    let entries: Vec<MaintenanceRequestFeedback> = fb::maintenance_request_feedback
+        .filter(fb::request_id.eq(request_id))
+        .order(fb::id.asc())
+        .select(MaintenanceRequestFeedback::as_select())
+        .load(&mut conn)?;
+
+    Ok(HttpResponse::Ok().json(entries))
+}
+
+/// Aggregate satisfaction ratings
+///
+/// Returns average ratings and "not fixed" counts grouped by assignee, vendor and
+/// building, best rated first. Assignee and vendor are taken as they were when the
+/// feedback was given. Admins see all buildings, Managers only the buildings they manage.
+#[utoipa::path(
+    get,
+    path = "/api/v1/requests/feedback/summary",
+    params(FeedbackSummaryParams),
+    responses(
+        (status = 200, description = "Aggregated ratings", body = FeedbackSummary),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Maintenance",
+    security(("bearer_auth" = []))
+)]
+pub async fn feedback_summary(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+    query: web::Query<FeedbackSummaryParams>,
+) -> Result<impl Responder, AppError> {
+    use crate::auth::get_user_building_ids;
+    use crate::schema::apartments::dsl as apt;
+    use crate::schema::buildings::dsl as bld;
+    use crate::schema::maintenance_request_feedback::dsl as fb;
+    use crate::schema::maintenance_requests::dsl as mr;
+    use crate::schema::users::dsl as usr;
+
+    auth.require_roles(&["Admin", "Manager"])?;
+    let user_id = auth.user_id()?;
+    let is_admin = auth.has_any_role(&["Admin"]);
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let mut rows_query = fb::maintenance_request_feedback
+        .inner_join(mr::maintenance_requests.on(mr::id.eq(fb::request_id)))
+        .inner_join(apt::apartments.on(apt::id.eq(mr::apartment_id)))
+        .inner_join(bld::buildings.on(bld::id.eq(apt::building_id)))
+        .into_boxed();
+
+    if let Some(ids) = get_user_building_ids(user_id, is_admin, &mut conn)? {
+        rows_query = rows_query.filter(apt::building_id.eq_any(ids));
+    }
+    if let Some(building_id) = query.building_id {
+        rows_query = rows_query.filter(apt::building_id.eq(building_id));
+    }
+
+    let rows: Vec<FeedbackSummaryRow> = rows_query
+        .select((
+            fb::rating,
+            fb::is_fixed,
+            fb::assigned_to,
+            fb::vendor,
+            apt::building_id,
+            bld::address,
+        ))
+        .load(&mut conn)?;
+
+    let assignee_ids: Vec<u64> = rows.iter().filter_map(|r| r.2).collect();
+    let assignee_names: HashMap<u64, String> = usr::users
+        .filter(usr::id.eq_any(&assignee_ids))
+        .select((usr::id, usr::name))
+        .load::<(u64, String)>(&mut conn)?
+        .into_iter()
+        .collect();
+
+    let by_assignee = aggregate(rows.iter().filter_map(|r| {
+        r.2.map(|id| {
+            let label = assignee_names
+                .get(&id)
+                .cloned()
+                .unwrap_or_else(|| format!("User #{}", id));
+            ((Some(id), label), r.0, r.1)
+        })
+    }));
+    let by_vendor = aggregate(
+        rows.iter()
+            .filter_map(|r| r.3.clone().map(|v| ((None, v), r.0, r.1))),
+    );
+    let by_building = aggregate(rows.iter().map(|r| ((Some(r.4), r.5.clone()), r.0, r.1)));
+
+    Ok(HttpResponse::Ok().json(FeedbackSummary {
+        by_assignee,
+        by_vendor,
+        by_building,
+    }))
+}
+
+/// Group `(key, rating, is_fixed)` entries into per-key aggregates, best average first.
+fn aggregate(
+    entries: impl Iterator<Item = ((Option<u64>, String), u8, bool)>,
+) -> Vec<FeedbackAggregate> {
+    let mut groups: HashMap<(Option<u64>, String), (i64, i64, i64)> = HashMap::new();
+    for (key, rating, is_fixed) in entries {
+        let group = groups.entry(key).or_default();
+        group.0 += 1;
+        group.1 += i64::from(rating);
+        if !is_fixed {
+            group.2 += 1;
+        }
+    }
+
+    let mut out: Vec<FeedbackAggregate> = groups
+        .into_iter()
+        .map(|((id, label), (count, sum, not_fixed))| FeedbackAggregate {
+            id,
+            label,
+            ratings: count,
+            average_rating: sum as f64 / count as f64,
+            not_fixed,
+        })
+        .collect();
+    out.sort_by(|a, b| {
+        b.average_rating
+            .total_cmp(&a.average_rating)
+            .then_with(|| b.ratings.cmp(&a.ratings))
+            .then_with(|| a.label.cmp(&b.label))
+    });
+    out
+}
@@ -1,9 +1,11 @@
 use super::types::{
     AssignPayload, EscalatePayload, MaintenanceRequestDetail, MaintenanceRequestDetailRow,
     MaintenanceRequestEnriched, MaintenanceRequestHistoryEnriched, MaintenanceRequestQueryRow,
-    StatusUpdatePayload, UpdateRequestPayload,
+    RequestFilters, RequestSort, SLA_CLOSED_STATUSES, SearchTerm, StatusUpdatePayload,
+    UpdateRequestPayload,
 };
 use crate::auth::{AppError, AuthContext};
+use crate::config::AppConfig;
 use crate::db::DbPool;
 use crate::models::{MaintenanceRequest, MaintenanceRequestHistory, NewMaintenanceRequest};
 use crate::pagination::{PaginatedResponse, PaginationParams};
@@ -13,15 +15,19 @@ use diesel::prelude::*;
 /// List maintenance requests
 ///
 /// Returns maintenance requests with enriched data (apartment number and building address).
-/// Based on user role:
-/// - Admin/Manager: See all requests
+/// Supports full-text search (`q`, FULLTEXT indexed) across title, description and comments,
+/// filtering by status/priority sets, type, building, apartment, assignee, creator, date
+/// ranges and SLA breach, and sorting. Visibility is based on user role:
+/// - Admin: See all requests
+/// - Manager: See requests in buildings they manage
 /// - Others: See only requests they created or are assigned to
 #[utoipa::path(
     get,
     path = "/api/v1/requests",
-    params(PaginationParams),
+    params(PaginationParams, RequestFilters),
     responses(
         (status = 200, description = "Paginated list of maintenance requests with enriched data", body = PaginatedResponse<MaintenanceRequestEnriched>),
+        (status = 400, description = "Invalid sort or order value"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Maintenance",
@@ -31,10 +37,13 @@ pub async fn list_requests(
     auth: AuthContext,
     pool: web::Data<DbPool>,
     query: web::Query<PaginationParams>,
+    filters: web::Query<RequestFilters>,
+    config: web::Data<AppConfig>,
 ) -> Result<impl Responder, AppError> {
     use crate::auth::get_user_building_ids;
     use crate::schema::apartments::dsl as apt;
     use crate::schema::buildings::dsl as bld;
+    use crate::schema::maintenance_request_comments::dsl as mrc;
     use crate::schema::maintenance_requests::dsl as mr;
 
     let mut conn = pool
@@ -50,45 +59,154 @@ pub async fn list_requests(
         None
     };
 
-    let mut count_query = mr::maintenance_requests
-        .inner_join(apt::apartments.on(apt::id.eq(mr::apartment_id)))
-        .inner_join(bld::buildings.on(bld::id.eq(apt::building_id)))
-        .into_boxed();
+    let search = filters.search_term();
+    let statuses = filters.statuses();
+    let priorities = filters.priorities();
+    let sort = filters.sort_key()?;
+    let ascending = filters.ascending()?;
+    let day_start = |d: chrono::NaiveDate| d.and_hms_opt(0, 0, 0).unwrap_or_default();
+    let next_day_start = |d: chrono::NaiveDate| day_start(d) + chrono::Duration::days(1);
+    let now = chrono::Utc::now().naive_utc();
+    let sla_cutoff =
+        |priority: &str| now - chrono::Duration::hours(config.sla.for_priority(priority));
+    let matches = |columns: &str, term: &str| {
+        diesel::dsl::sql::<diesel::sql_types::Bool>(&format!("MATCH({}) AGAINST (", columns))
+            .bind::<diesel::sql_types::Text, _>(term.to_string())
+            .sql(" IN BOOLEAN MODE)")
+    };
 
-    if !is_admin {
-        if is_manager {
-            if let Some(ref ids) = building_ids {
-                count_query = count_query.filter(apt::building_id.eq_any(ids));
+    // Count and data queries share the same visibility and filter conditions;
+    // the boxed join type is unwieldy to name, so apply them through a macro.
+    macro_rules! filtered {
+        () => {{
+            let mut q = mr::maintenance_requests
+                .inner_join(apt::apartments.on(apt::id.eq(mr::apartment_id)))
+                .inner_join(bld::buildings.on(bld::id.eq(apt::building_id)))
+                .into_boxed();
+
+            if !is_admin {
+                if is_manager {
+                    if let Some(ref ids) = building_ids {
+                        q = q.filter(apt::building_id.eq_any(ids.clone()));
+                    }
+                } else {
+                    q = q.filter(
+                        mr::created_by
+                            .eq(user_id)
+                            .or(mr::assigned_to.eq(Some(user_id))),
+                    );
+                }
             }
-        } else {
-            count_query = count_query.filter(
-                mr::created_by
-                    .eq(user_id)
-                    .or(mr::assigned_to.eq(Some(user_id))),
-            );
-        }
-    }
 
-    let total = count_query.count().get_result::<i64>(&mut conn)?;
+            match search {
+                Some(SearchTerm::FullText(ref term)) => {
+                    let commented = mrc::maintenance_request_comments
+                        .filter(mrc::is_deleted.eq(false))
+                        .filter(matches("maintenance_request_comments.comment_text", term))
+                        .select(mrc::request_id);
+                    q = q.filter(
+                        matches(
+                            "maintenance_requests.title, maintenance_requests.description",
+                            term,
+                        )
+                        .or(mr::id.eq_any(commented)),
+                    );
+                }
+                Some(SearchTerm::Like(ref pattern)) => {
+                    let commented = mrc::maintenance_request_comments
+                        .filter(mrc::is_deleted.eq(false))
+                        .filter(mrc::comment_text.like(pattern.clone()))
+                        .select(mrc::request_id);
+                    q = q.filter(
+                        mr::title
+                            .like(pattern.clone())
+                            .or(mr::description.like(pattern.clone()))
+                            .or(mr::id.eq_any(commented)),
+                    );
+                }
+                None => {}
+            }
+            if !statuses.is_empty() {
+                q = q.filter(mr::status.eq_any(statuses.clone()));
+            }
+            if !priorities.is_empty() {
+                q = q.filter(mr::priority.eq_any(priorities.clone()));
+            }
+            if let Some(ref request_type) = filters.request_type {
+                q = q.filter(mr::request_type.eq(request_type.clone()));
+            }
+            if let Some(building_id) = filters.building_id {
+                q = q.filter(apt::building_id.eq(building_id));
+            }
+            if let Some(apartment_id) = filters.apartment_id {
+                q = q.filter(mr::apartment_id.eq(apartment_id));
+            }
+            if let Some(assignee) = filters.assigned_to {
+                q = q.filter(mr::assigned_to.eq(Some(assignee)));
+            }
+            if filters.unassigned == Some(true) {
+                q = q.filter(mr::assigned_to.is_null());
+            }
+            if let Some(creator) = filters.created_by {
+                q = q.filter(mr::created_by.eq(creator));
+            }
+            if let Some(from) = filters.created_from {
+                q = q.filter(mr::created_at.ge(day_start(from)));
+            }
+            if let Some(to) = filters.created_to {
+                q = q.filter(mr::created_at.lt(next_day_start(to)));
+            }
+            if let Some(from) = filters.updated_from {
+                q = q.filter(mr::updated_at.ge(day_start(from)));
+            }
+            if let Some(to) = filters.updated_to {
+                q = q.filter(mr::updated_at.lt(next_day_start(to)));
+            }
+            if let Some(breached) = filters.sla_breached {
+                let overdue = mr::priority
+                    .eq("Urgent")
+                    .and(mr::created_at.lt(sla_cutoff("Urgent")))
+                    .or(mr::priority
+                        .eq("High")
+                        .and(mr::created_at.lt(sla_cutoff("High"))))
+                    .or(mr::priority
+                        .eq("Medium")
+                        .and(mr::created_at.lt(sla_cutoff("Medium"))))
+                    .or(mr::priority
+                        .eq("Low")
+                        .and(mr::created_at.lt(sla_cutoff("Low"))));
+                let open = mr::status.ne_all(SLA_CLOSED_STATUSES.to_vec());
+                q = if breached {
+                    q.filter(open.and(overdue))
+                } else {
+                    q.filter(diesel::dsl::not(open.and(overdue)))
+                };
+            }
+            q
+        }};
+    }
 
-    let mut data_query = mr::maintenance_requests
-        .inner_join(apt::apartments.on(apt::id.eq(mr::apartment_id)))
-        .inner_join(bld::buildings.on(bld::id.eq(apt::building_id)))
-        .into_boxed();
+    let total = filtered!().count().get_result::<i64>(&mut conn)?;
 
-    if !is_admin {
-        if is_manager {
-            if let Some(ref ids) = building_ids {
-                data_query = data_query.filter(apt::building_id.eq_any(ids));
+    let mut data_query = filtered!();
+    macro_rules! sorted {
+        ($col:expr) => {
+            if ascending {
+                data_query.order($col.asc()).then_order_by(mr::id.asc())
+            } else {
+                data_query.order($col.desc()).then_order_by(mr::id.desc())
             }
-        } else {
-            data_query = data_query.filter(
-                mr::created_by
-                    .eq(user_id)
-                    .or(mr::assigned_to.eq(Some(user_id))),
-            );
-        }
+        };
     }
+    data_query = match sort {
+        RequestSort::CreatedAt => sorted!(mr::created_at),
+        RequestSort::UpdatedAt => sorted!(mr::updated_at),
+        RequestSort::Status => sorted!(mr::status),
+        RequestSort::Title => sorted!(mr::title),
+        RequestSort::Priority => sorted!(diesel::dsl::sql::<diesel::sql_types::Integer>(
+            "FIELD(maintenance_requests.priority, 'Low', 'Medium', 'High', 'Urgent')"
+        )),
+    };
 
     let results: Vec<MaintenanceRequestQueryRow> = data_query
         .select((
@@ -194,6 +312,7 @@ pub async fn get_request(
             mr::apartment_id,
             mr::created_by,
             mr::assigned_to,
+            mr::vendor,
             mr::request_type,
             mr::priority,
             mr::title,
@@ -214,6 +333,7 @@ pub async fn get_request(
         apartment_id,
         created_by,
         assigned_to,
+        vendor,
         request_type,
         priority,
         title,
@@ -262,6 +382,7 @@ pub async fn get_request(
         created_by_name: creator_name,
         assigned_to,
         assigned_to_name: assigned_name,
+        vendor,
         created_at: created_at
             .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
             .unwrap_or_default(),
@@ -406,7 +527,7 @@ pub async fn update_status(
 
 /// Update maintenance request fields
 ///
-/// General update endpoint that allows updating status, priority, and/or assignment.
+/// General update endpoint that allows updating status, priority, assignment and/or vendor.
 /// Status changes are recorded in the history table. Requires Admin or Manager role.
 #[utoipa::path(
     put,
@@ -534,6 +655,36 @@ pub async fn update_request(
             .execute(&mut conn)?;
     }
 
+    if let Some(new_vendor) = &payload.vendor {
+        let new_vendor = new_vendor.trim();
+        let new_vendor = (!new_vendor.is_empty()).then(|| new_vendor.to_string());
+        if new_vendor
+            .as_deref()
+            .is_some_and(|v| v.chars().count() > 128)
+        {
+            return Err(AppError::BadRequest("vendor_too_long".into()));
+        }
+        if new_vendor != current.vendor {
+            diesel::update(mr::maintenance_requests.filter(mr::id.eq(id)))
+                .set(mr::vendor.eq(&new_vendor))
+                .execute(&mut conn)?;
+
+            let note = match &new_vendor {
+                Some(v) => format!("Vendor set to {}", v),
+                None => "Vendor cleared".to_string(),
+            };
+            diesel::insert_into(hist::maintenance_request_history)
+                .values((
+                    hist::request_id.eq(id),
+                    hist::from_status.eq::<Option<String>>(None),
+                    hist::to_status.eq(&current_status),
+                    hist::note.eq(Some(note)),
+                    hist::changed_by.eq(user_id),
+                ))
+                .execute(&mut conn)?;
+        }
+    }
+
     use crate::schema::apartments::dsl as apt;
     use crate::schema::buildings::dsl as bld;
     use crate::schema::users::dsl as usr;
@@ -548,6 +699,7 @@ pub async fn update_request(
             mr::apartment_id,
             mr::created_by,
             mr::assigned_to,
+            mr::vendor,
             mr::request_type,
             mr::priority,
             mr::title,
@@ -568,6 +720,7 @@ pub async fn update_request(
         apartment_id,
         created_by,
         assigned_to,
+        vendor,
         request_type,
         priority,
         title,
@@ -608,6 +761,7 @@ pub async fn update_request(
         created_by_name: creator_name,
         assigned_to,
         assigned_to_name: assigned_name,
+        vendor,
         created_at: created_at
             .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
             .unwrap_or_default(),
@@ -1,10 +1,14 @@
 pub mod attachments;
 mod comments;
+mod feedback;
 mod handlers;
+mod presets;
 mod types;
 
 pub use comments::*;
+pub use feedback::*;
 pub use handlers::*;
+pub use presets::*;
 pub use types::*;
 
 use actix_web::web;
@@ -12,6 +16,23 @@ use actix_web::web;
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.route("/requests", web::get().to(list_requests))
         .route("/requests", web::post().to(create_request))
+        // saved filter presets (before /requests/{id} so the path isn't taken as an id)
+        .route(
+            "/requests/filter-presets",
+            web::get().to(list_filter_presets),
+        )
+        .route(
+            "/requests/filter-presets",
+            web::post().to(save_filter_preset),
+        )
+        .route(
+            "/requests/filter-presets/{id}",
+            web::delete().to(delete_filter_preset),
+        )
+        .route(
+            "/requests/feedback/summary",
+            web::get().to(feedback_summary),
+        )
         .route("/requests/{id}", web::get().to(get_request))
         .route("/requests/{id}", web::put().to(update_request))
         .route("/requests/{id}/status", web::put().to(update_status))
@@ -19,6 +40,8 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .route("/requests/{id}/assign", web::put().to(assign_request))
         .route("/requests/{id}/assign", web::delete().to(unassign_request))
         .route("/requests/{id}/escalate", web::post().to(escalate_request))
+        .route("/requests/{id}/feedback", web::get().to(list_feedback))
+        .route("/requests/{id}/feedback", web::post().to(submit_feedback))
         // attachment endpoints
         .route(
             "/requests/{id}/attachments",
@@ -40,6 +63,10 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             "/requests/{id}/attachments/{att_id}/download",
             web::get().to(attachments::download_attachment),
         )
+        .route(
+            "/requests/{id}/attachments/{att_id}/thumbnail",
+            web::get().to(attachments::download_thumbnail),
+        )
         .route(
             "/requests/{id}/attachments/{att_id}",
             web::delete().to(attachments::delete_attachment),
@@ -0,0 +1,173 @@
+use super::types::{FilterPresetOut, FilterPresetPayload};
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::models::{MaintenanceFilterPreset, NewMaintenanceFilterPreset};
+use actix_web::{HttpResponse, Responder, web};
+use diesel::prelude::*;
+
+fn to_out(preset: MaintenanceFilterPreset) -> FilterPresetOut {
+    FilterPresetOut {
+        id: preset.id,
+        name: preset.name,
+        // Presets are only written by `save_filter_preset`; fall back to an empty
+        // filter set rather than failing the whole list if one ever stops parsing.
+        filters: serde_json::from_str(&preset.filters_json).unwrap_or_default(),
+        created_at: preset
+            .created_at
+            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string()),
+    }
+}
+
+/// List saved filter presets
+///
+/// Returns the current user's saved maintenance request filter presets, ordered by name.
+#[utoipa::path(
+    get,
+    path = "/api/v1/requests/filter-presets",
+    responses(
+        (status = 200, description = "Saved filter presets", body = Vec<FilterPresetOut>),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Maintenance",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_filter_presets(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::maintenance_filter_presets::dsl as fp;
+
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let presets: Vec<MaintenanceFilterPreset> = fp::maintenance_filter_presets
+        .filter(fp::user_id.eq(user_id))
+        .order(fp::name.asc())
+        .select(MaintenanceFilterPreset::as_select())
+        .load(&mut conn)?;
+
+    let out: Vec<FilterPresetOut> = presets.into_iter().map(to_out).collect();
+    Ok(HttpResponse::Ok().json(out))
+}
+
+/// Save a filter preset
+///
+/// Stores the given filters under a name for the current user. Saving under an
+/// existing name replaces that preset's filters.
+#[utoipa::path(
+    post,
+    path = "/api/v1/requests/filter-presets",
+    request_body = FilterPresetPayload,
+    responses(
+        (status = 200, description = "Preset saved", body = FilterPresetOut),
+        (status = 400, description = "Missing or too long name"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Maintenance",
+    security(("bearer_auth" = []))
+)]
+pub async fn save_filter_preset(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+    payload: web::Json<FilterPresetPayload>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::maintenance_filter_presets::dsl as fp;
+
+    let user_id = auth.user_id()?;
+    let FilterPresetPayload { name, filters } = payload.into_inner();
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > 128 {
+        return Err(AppError::BadRequest("invalid_preset_name".into()));
+    }
+    // Validate sort options up front so a broken preset can't be saved.
+    filters.sort_key()?;
+    filters.ascending()?;
+    let filters_json =
+        serde_json::to_string(&filters).map_err(|_| AppError::Internal("serialize".into()))?;
+
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let existing: Option<u64> = fp::maintenance_filter_presets
+        .filter(fp::user_id.eq(user_id))
+        .filter(fp::name.eq(&name))
+        .select(fp::id)
+        .first(&mut conn)
+        .optional()?;
+
+    let preset_id = match existing {
+        Some(id) => {
+            diesel::update(fp::maintenance_filter_presets.filter(fp::id.eq(id)))
+                .set(fp::filters_json.eq(&filters_json))
+                .execute(&mut conn)?;
+            id
+        }
+        None => {
+            diesel::insert_into(fp::maintenance_filter_presets)
+                .values(&NewMaintenanceFilterPreset {
+                    user_id,
+                    name,
+                    filters_json,
+                })
+                .execute(&mut conn)?;
+            diesel::select(diesel::dsl::sql::<
+                diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+            >("LAST_INSERT_ID()"))
+            .first(&mut conn)?
+        }
+    };
+
+    let preset: MaintenanceFilterPreset = fp::maintenance_filter_presets
+        .filter(fp::id.eq(preset_id))
+        .select(MaintenanceFilterPreset::as_select())
+        .first(&mut conn)?;
+
+    Ok(HttpResponse::Ok().json(to_out(preset)))
+}
+
+/// Delete a filter preset
+///
+/// Removes one of the current user's saved filter presets.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/requests/filter-presets/{id}",
+    params(
+        ("id" = u64, Path, description = "Filter preset ID")
+    ),
+    responses(
+        (status = 204, description = "Preset deleted"),
+        (status = 404, description = "Preset not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Maintenance",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_filter_preset(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::maintenance_filter_presets::dsl as fp;
+
+    let user_id = auth.user_id()?;
+    let preset_id = path.into_inner();
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let deleted = diesel::delete(
+        fp::maintenance_filter_presets
+            .filter(fp::id.eq(preset_id))
+            .filter(fp::user_id.eq(user_id)),
+    )
+    .execute(&mut conn)?;
+
+    if deleted == 0 {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -1,5 +1,6 @@
+use crate::auth::AppError;
 use serde::Serialize;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 pub(super) type MaintenanceRequestQueryRow = (
     u64,                           // id
@@ -33,6 +34,7 @@ pub(super) type MaintenanceRequestDetailRow = (
     u64,                           // apartment_id
     u64,                           // created_by
     Option<u64>,                   // assigned_to
+    Option<String>,                // vendor
     String,                        // request_type
     String,                        // priority
     String,                        // title
@@ -81,6 +83,7 @@ pub struct MaintenanceRequestDetail {
     pub created_by_name: String,
     pub assigned_to: Option<u64>,
     pub assigned_to_name: Option<String>,
+    pub vendor: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -109,6 +112,8 @@ pub struct UpdateRequestPayload {
     pub status: Option<String>,
     pub priority: Option<String>,
     pub assigned_to: Option<u64>,
+    /// External contractor doing the work; an empty string clears it
+    pub vendor: Option<String>,
 }
 
 #[derive(serde::Deserialize, ToSchema)]
@@ -120,3 +125,227 @@ pub struct AssignPayload {
 pub struct EscalatePayload {
     pub manager_id: u64,
 }
+
+/// Search, filter and sort options for `GET /requests`.
+///
+/// Also stored verbatim as the body of a saved filter preset.
+#[derive(serde::Deserialize, Serialize, ToSchema, IntoParams, Default, Clone, Debug)]
+#[into_params(parameter_in = Query)]
+pub struct RequestFilters {
+    /// Full-text search across title, description and comments; every indexed word must
+    /// match (as a prefix). Words under three letters and stopwords are not indexed and
+    /// are skipped; input made only of such words is matched as a substring instead
+    pub q: Option<String>,
+    /// Comma-separated status set, e.g. `Open,InProgress`
+    pub status: Option<String>,
+    /// Comma-separated priority set, e.g. `High,Urgent`
+    pub priority: Option<String>,
+    pub request_type: Option<String>,
+    pub building_id: Option<u64>,
+    pub apartment_id: Option<u64>,
+    pub assigned_to: Option<u64>,
+    /// Only requests without an assignee
+    pub unassigned: Option<bool>,
+    pub created_by: Option<u64>,
+    /// Inclusive lower bound on creation date (YYYY-MM-DD)
+    pub created_from: Option<chrono::NaiveDate>,
+    /// Inclusive upper bound on creation date (YYYY-MM-DD)
+    pub created_to: Option<chrono::NaiveDate>,
+    /// Inclusive lower bound on last update date (YYYY-MM-DD)
+    pub updated_from: Option<chrono::NaiveDate>,
+    /// Inclusive upper bound on last update date (YYYY-MM-DD)
+    pub updated_to: Option<chrono::NaiveDate>,
+    /// `true` for requests past their SLA, `false` for those still within it
+    pub sla_breached: Option<bool>,
+    /// One of `created_at` (default), `updated_at`, `priority`, `status`, `title`
+    #[schema(example = "created_at")]
+    pub sort: Option<String>,
+    /// `asc` or `desc` (default)
+    #[schema(example = "desc")]
+    pub order: Option<String>,
+}
+
+impl RequestFilters {
+    /// Trimmed, non-empty search term, as a full-text query where the index can serve it.
+    pub(super) fn search_term(&self) -> Option<SearchTerm> {
+        let term = self.q.as_deref().map(str::trim).filter(|s| !s.is_empty())?;
+        Some(match fulltext_query(term) {
+            Some(query) => SearchTerm::FullText(query),
+            None => SearchTerm::Like(format!("%{}%", escape_like(term))),
+        })
+    }
+
+    pub(super) fn statuses(&self) -> Vec<String> {
+        split_csv(self.status.as_deref())
+    }
+
+    pub(super) fn priorities(&self) -> Vec<String> {
+        split_csv(self.priority.as_deref())
+    }
+
+    pub(super) fn sort_key(&self) -> Result<RequestSort, AppError> {
+        match self.sort.as_deref().unwrap_or("created_at") {
+            "created_at" => Ok(RequestSort::CreatedAt),
+            "updated_at" => Ok(RequestSort::UpdatedAt),
+            "priority" => Ok(RequestSort::Priority),
+            "status" => Ok(RequestSort::Status),
+            "title" => Ok(RequestSort::Title),
+            _ => Err(AppError::BadRequest("invalid_sort".into())),
+        }
+    }
+
+    pub(super) fn ascending(&self) -> Result<bool, AppError> {
+        match self.order.as_deref().unwrap_or("desc") {
+            "asc" => Ok(true),
+            "desc" => Ok(false),
+            _ => Err(AppError::BadRequest("invalid_order".into())),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(super) enum RequestSort {
+    CreatedAt,
+    UpdatedAt,
+    Priority,
+    Status,
+    Title,
+}
+
+/// How `q` is matched against request text and comments.
+pub(super) enum SearchTerm {
+    /// Boolean-mode `MATCH ... AGAINST` query
+    FullText(String),
+    /// `LIKE` pattern, for terms with no word the FULLTEXT index holds
+    Like(String),
+}
+
+/// Shortest word InnoDB indexes (`innodb_ft_min_token_size`).
+const FULLTEXT_MIN_WORD: usize = 3;
+
+/// InnoDB's default full-text stopword list; these words never match.
+const FULLTEXT_STOPWORDS: [&str; 35] = [
+    "a", "about", "an", "are", "as", "at", "be", "by", "com", "de", "en", "for", "from", "how",
+    "i", "in", "is", "it", "la", "of", "on", "or", "that", "the", "this", "to", "was", "what",
+    "when", "where", "who", "will", "with", "und", "www",
+];
+
+/// Statuses that stop the SLA clock.
+pub(super) const SLA_CLOSED_STATUSES: [&str; 2] = ["Resolved", "Closed"];
+
+fn split_csv(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn escape_like(term: &str) -> String {
+    term.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Require every indexed word of `term` as a prefix (`+word*`). Anything but letters and
+/// digits separates words, so user input cannot inject boolean operators. Short words and
+/// stopwords are left out, as requiring them would match nothing.
+fn fulltext_query(term: &str) -> Option<String> {
+    let words: Vec<String> = term
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= FULLTEXT_MIN_WORD)
+        .map(str::to_lowercase)
+        .filter(|w| !FULLTEXT_STOPWORDS.contains(&w.as_str()))
+        .map(|w| format!("+{}*", w))
+        .collect();
+    (!words.is_empty()).then(|| words.join(" "))
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct FilterPresetOut {
+    pub id: u64,
+    pub name: String,
+    pub filters: RequestFilters,
+    pub created_at: Option<String>,
+}
+
+#[derive(serde::Deserialize, ToSchema)]
+pub struct FilterPresetPayload {
+    #[schema(example = "Urgent open in my buildings")]
+    pub name: String,
+    pub filters: RequestFilters,
+}
+
+#[derive(serde::Deserialize, ToSchema)]
+pub struct FeedbackPayload {
+    /// Satisfaction rating from 1 (poor) to 5 (excellent)
+    #[schema(example = 4)]
+    pub rating: u8,
+    /// `false` reopens the request
+    pub is_fixed: bool,
+    pub comment: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct FeedbackAggregate {
+    /// User or building ID; absent when grouping by vendor
+    pub id: Option<u64>,
+    pub label: String,
+    pub ratings: i64,
+    pub average_rating: f64,
+    pub not_fixed: i64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct FeedbackSummary {
+    pub by_assignee: Vec<FeedbackAggregate>,
+    pub by_vendor: Vec<FeedbackAggregate>,
+    pub by_building: Vec<FeedbackAggregate>,
+}
+
+#[derive(serde::Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct FeedbackSummaryParams {
+    /// Restrict the summary to a single building
+    pub building_id: Option<u64>,
+}
+
+pub(super) type FeedbackSummaryRow = (
+    u8,             // rating
+    bool,           // is_fixed
+    Option<u64>,    // assigned_to
+    Option<String>, // vendor
+    u64,            // building_id
+    String,         // building_address
+);
+
+#[cfg(test)]
+mod tests {
+    use super::fulltext_query;
+
+    #[test]
+    fn every_word_is_required_as_prefix() {
+        assert_eq!(
+            fulltext_query("broken  heater").as_deref(),
+            Some("+broken* +heater*")
+        );
+    }
+
+    #[test]
+    fn boolean_operators_are_stripped() {
+        assert_eq!(
+            fulltext_query("-door +\"heat\" (noise)~").as_deref(),
+            Some("+door* +heat* +noise*")
+        );
+        assert_eq!(fulltext_query(" *@ "), None);
+    }
+
+    #[test]
+    fn short_words_and_stopwords_are_skipped() {
+        assert_eq!(fulltext_query("AC leak").as_deref(), Some("+leak*"));
+        assert_eq!(fulltext_query("The door").as_deref(), Some("+door*"));
+        assert_eq!(fulltext_query("on the AC"), None);
+    }
+}
@@ -0,0 +1,298 @@
+//! Attendance registration and quorum.
+//!
+//! An owner attends in person, online or through a proxy holder, and represents the
+//! floor area of the apartments they own in the building. The quorum compares that
+//! area with the building's total; when no apartment has a recorded size every
+//! apartment counts equally.
+
+use super::handlers::{load_accessible, load_detail, meeting_status};
+use super::types::{QuorumStatus, RegisterAttendancePayload};
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::models::{AttendanceMode, Meeting, MeetingStatus};
+use actix_web::{HttpResponse, Responder, web};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::HashSet;
+
+/// The apartments of a building with their size and owners.
+pub(super) struct BuildingShares {
+    apartments: Vec<(Option<f64>, Vec<u64>)>,
+}
+
+impl BuildingShares {
+    pub fn load(building_id: u64, conn: &mut MysqlConnection) -> Result<Self, AppError> {
+        use crate::schema::apartment_owners::dsl as ao;
+        use crate::schema::apartments::dsl as apt;
+
+        let apartments: Vec<(u64, Option<f64>)> = apt::apartments
+            .filter(apt::building_id.eq(building_id))
+            .filter(apt::is_deleted.eq(false))
+            .select((apt::id, apt::size_sq_m))
+            .load(conn)?;
+        let ids: Vec<u64> = apartments.iter().map(|(id, _)| *id).collect();
+        let owners: Vec<(u64, u64)> = ao::apartment_owners
+            .filter(ao::apartment_id.eq_any(&ids))
+            .select((ao::apartment_id, ao::user_id))
+            .load(conn)?;
+
+        Ok(Self {
+            apartments: apartments
+                .into_iter()
+                .map(|(id, size)| {
+                    let owned_by = owners
+                        .iter()
+                        .filter(|(apartment_id, _)| *apartment_id == id)
+                        .map(|(_, user_id)| *user_id)
+                        .collect();
+                    (size, owned_by)
+                })
+                .collect(),
+        })
+    }
+
+    pub fn is_owner(&self, user_id: u64) -> bool {
+        self.apartments
+            .iter()
+            .any(|(_, owners)| owners.contains(&user_id))
+    }
+
+    /// Floor area of the building owned by `user_id`.
+    pub fn area_of(&self, user_id: u64) -> f64 {
+        self.apartments
+            .iter()
+            .filter(|(_, owners)| owners.contains(&user_id))
+            .filter_map(|(size, _)| *size)
+            .sum()
+    }
+
+    /// Share of the building represented by `present`; an apartment counts once
+    /// any of its owners is registered.
+    pub fn quorum(&self, present: &HashSet<u64>, required_percent: i32) -> QuorumStatus {
+        let by_area = self.apartments.iter().any(|(size, _)| size.is_some());
+        let weight = |size: &Option<f64>| {
+            if by_area { size.unwrap_or(0.0) } else { 1.0 }
+        };
+        let total: f64 = self.apartments.iter().map(|(size, _)| weight(size)).sum();
+        let represented: f64 = self
+            .apartments
+            .iter()
+            .filter(|(_, owners)| owners.iter().any(|id| present.contains(id)))
+            .map(|(size, _)| weight(size))
+            .sum();
+        let percent = if total > 0.0 {
+            represented / total * 100.0
+        } else {
+            0.0
+        };
+        QuorumStatus {
+            represented,
+            total,
+            percent,
+            required_percent,
+            reached: total > 0.0 && percent >= f64::from(required_percent),
+        }
+    }
+}
+
+/// Whether `grantor_id` has a building-wide proxy for `grantee_id` that covers the
+/// meeting date.
+fn holds_building_proxy(
+    meeting: &Meeting,
+    grantor_id: u64,
+    grantee_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<bool, AppError> {
+    use crate::schema::vote_proxies::dsl as vp;
+
+    let count: i64 = vp::vote_proxies
+        .filter(vp::grantor_id.eq(grantor_id))
+        .filter(vp::grantee_id.eq(grantee_id))
+        .filter(vp::building_id.eq(meeting.building_id))
+        .filter(vp::revoked_at.is_null())
+        .filter(vp::valid_from.le(meeting.scheduled_at))
+        .filter(vp::valid_until.ge(meeting.scheduled_at))
+        .count()
+        .get_result(conn)?;
+    Ok(count > 0)
+}
+
+/// Register attendance
+///
+/// Registers an owner of the building as attending in person, online or by proxy.
+/// Owners register themselves; Admin and Manager can register anyone, e.g. at the
+/// door. Attending by proxy needs a building-wide proxy covering the meeting date.
+/// Registering again replaces the earlier registration.
+#[utoipa::path(
+    post,
+    path = "/api/v1/meetings/{id}/attendance",
+    params(
+        ("id" = u64, Path, description = "Meeting ID")
+    ),
+    request_body = RegisterAttendancePayload,
+    responses(
+        (status = 200, description = "Attendance registered", body = super::types::MeetingDetail),
+        (status = 400, description = "Meeting closed, not an owner or no valid proxy"),
+        (status = 403, description = "Forbidden - registering someone else requires Admin or Manager"),
+        (status = 404, description = "Meeting not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn register_attendance(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+    payload: web::Json<RegisterAttendancePayload>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::meeting_attendance::dsl as ma;
+
+    let caller = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let meeting = load_accessible(path.into_inner(), &auth, &mut conn)?;
+    if meeting_status(&meeting)? == MeetingStatus::Closed {
+        return Err(AppError::BadRequest("Meeting has ended".into()));
+    }
+
+    let user_id = payload.user_id.unwrap_or(caller);
+    if user_id != caller && !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+    if !BuildingShares::load(meeting.building_id, &mut conn)?.is_owner(user_id) {
+        return Err(AppError::BadRequest(
+            "Only owners of the building can attend".into(),
+        ));
+    }
+
+    let proxy_holder_id = match payload.mode {
+        AttendanceMode::Proxy => {
+            let holder = payload.proxy_holder_id.ok_or_else(|| {
+                AppError::BadRequest("proxy_holder_id is required when attending by proxy".into())
+            })?;
+            if !holds_building_proxy(&meeting, user_id, holder, &mut conn)? {
+                return Err(AppError::BadRequest(
+                    "No building-wide proxy covers this meeting".into(),
+                ));
+            }
+            Some(holder)
+        }
+        AttendanceMode::InPerson | AttendanceMode::Online => None,
+    };
+
+    diesel::replace_into(ma::meeting_attendance)
+        .values((
+            ma::meeting_id.eq(meeting.id),
+            ma::user_id.eq(user_id),
+            ma::mode.eq(payload.mode.to_string()),
+            ma::proxy_holder_id.eq(proxy_holder_id),
+            ma::registered_by.eq(caller),
+        ))
+        .execute(&mut conn)?;
+
+    Ok(HttpResponse::Ok().json(load_detail(meeting, &auth, &mut conn)?))
+}
+
+/// Remove attendance
+///
+/// Withdraws a registration. Owners can withdraw their own; Admin and Manager can
+/// withdraw anyone's, e.g. when someone leaves the meeting.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/meetings/{id}/attendance/{user_id}",
+    params(
+        ("id" = u64, Path, description = "Meeting ID"),
+        ("user_id" = u64, Path, description = "Attending owner")
+    ),
+    responses(
+        (status = 204, description = "Attendance removed"),
+        (status = 400, description = "Meeting has ended"),
+        (status = 403, description = "Forbidden - removing someone else requires Admin or Manager"),
+        (status = 404, description = "Meeting or registration not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn remove_attendance(
+    auth: AuthContext,
+    path: web::Path<(u64, u64)>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::meeting_attendance::dsl as ma;
+
+    let (meeting_id, user_id) = path.into_inner();
+    if user_id != auth.user_id()? && !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let meeting = load_accessible(meeting_id, &auth, &mut conn)?;
+    if meeting_status(&meeting)? == MeetingStatus::Closed {
+        return Err(AppError::BadRequest("Meeting has ended".into()));
+    }
+
+    let deleted = diesel::delete(
+        ma::meeting_attendance
+            .filter(ma::meeting_id.eq(meeting.id))
+            .filter(ma::user_id.eq(user_id)),
+    )
+    .execute(&mut conn)?;
+    if deleted == 0 {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shares(apartments: &[(Option<f64>, &[u64])]) -> BuildingShares {
+        BuildingShares {
+            apartments: apartments
+                .iter()
+                .map(|(size, owners)| (*size, owners.to_vec()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn quorum_weighs_apartments_by_floor_area() {
+        let building = shares(&[
+            (Some(60.0), &[1]),
+            (Some(40.0), &[2, 3]),
+            (Some(100.0), &[4]),
+        ]);
+        let present = HashSet::from([1, 3]);
+
+        let quorum = building.quorum(&present, 50);
+        assert_eq!(quorum.represented, 100.0);
+        assert_eq!(quorum.total, 200.0);
+        assert_eq!(quorum.percent, 50.0);
+        assert!(quorum.reached);
+        assert!(!building.quorum(&present, 51).reached);
+    }
+
+    #[test]
+    fn apartments_count_equally_without_sizes() {
+        let building = shares(&[(None, &[1]), (None, &[2]), (None, &[3]), (None, &[])]);
+        let quorum = building.quorum(&HashSet::from([1]), 50);
+        assert_eq!(quorum.represented, 1.0);
+        assert_eq!(quorum.total, 4.0);
+        assert!(!quorum.reached);
+    }
+
+    #[test]
+    fn empty_building_never_reaches_quorum() {
+        let quorum = shares(&[]).quorum(&HashSet::new(), 1);
+        assert_eq!(quorum.percent, 0.0);
+        assert!(!quorum.reached);
+    }
+}
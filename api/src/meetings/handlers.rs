@@ -0,0 +1,853 @@
+use super::attendance::BuildingShares;
+use super::types::{
+    AgendaItemOut, AttendeeOut, CreateMeetingPayload, MeetingDetail, MeetingSummary,
+    UpdateMeetingPayload,
+};
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::models::{
+    Meeting, MeetingAgendaItem, MeetingAttendance, MeetingStatus, Proposal, ProposalResult,
+};
+use crate::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::{HttpResponse, Responder, web};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+pub(super) fn now() -> chrono::NaiveDateTime {
+    chrono::Local::now().naive_local()
+}
+
+/// Load a meeting of one of the caller's buildings.
+pub(super) fn load_accessible(
+    id: u64,
+    auth: &AuthContext,
+    conn: &mut MysqlConnection,
+) -> Result<Meeting, AppError> {
+    use crate::auth::get_user_building_ids;
+    use crate::schema::meetings::dsl as m;
+
+    let meeting: Meeting = m::meetings
+        .filter(m::id.eq(id))
+        .select(Meeting::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+    let is_admin = auth.has_any_role(&["Admin"]);
+    if let Some(ids) = get_user_building_ids(auth.user_id()?, is_admin, conn)?
+        && !ids.contains(&meeting.building_id)
+    {
+        return Err(AppError::Forbidden);
+    }
+    Ok(meeting)
+}
+
+pub(super) fn require_manager(auth: &AuthContext) -> Result<(), AppError> {
+    if auth.has_any_role(&["Admin", "Manager"]) {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden)
+    }
+}
+
+pub(super) fn meeting_status(meeting: &Meeting) -> Result<MeetingStatus, AppError> {
+    meeting
+        .status
+        .parse()
+        .map_err(|_| AppError::Internal(format!("invalid meeting status: {}", meeting.status)))
+}
+
+/// A meeting with its agenda, attendance and quorum, as seen by the caller.
+pub(super) fn load_detail(
+    meeting: Meeting,
+    auth: &AuthContext,
+    conn: &mut MysqlConnection,
+) -> Result<MeetingDetail, AppError> {
+    use crate::schema::buildings::dsl as b;
+    use crate::schema::meeting_agenda_items::dsl as ai;
+    use crate::schema::meeting_attendance::dsl as ma;
+    use crate::schema::proposal_options::dsl as po;
+    use crate::schema::proposal_results::dsl as pr;
+    use crate::schema::proposals::dsl as p;
+    use crate::schema::users::dsl as u;
+
+    let user_id = auth.user_id()?;
+    let building_address: String = b::buildings
+        .filter(b::id.eq(meeting.building_id))
+        .select(b::address)
+        .first(conn)?;
+
+    // Agenda, with each proposal's state and result
+    let items: Vec<MeetingAgendaItem> = ai::meeting_agenda_items
+        .filter(ai::meeting_id.eq(meeting.id))
+        .select(MeetingAgendaItem::as_select())
+        .order(ai::position.asc())
+        .load(conn)?;
+    let proposal_ids: Vec<u64> = items.iter().map(|i| i.proposal_id).collect();
+    let proposals: HashMap<u64, (String, String, String)> = p::proposals
+        .filter(p::id.eq_any(&proposal_ids))
+        .select((p::id, p::title, p::status, p::ballot_type))
+        .load::<(u64, String, String, String)>(conn)?
+        .into_iter()
+        .map(|(id, title, status, ballot_type)| (id, (title, status, ballot_type)))
+        .collect();
+    let mut results: HashMap<u64, ProposalResult> = pr::proposal_results
+        .filter(pr::proposal_id.eq_any(&proposal_ids))
+        .select(ProposalResult::as_select())
+        .load(conn)?
+        .into_iter()
+        .map(|r| (r.proposal_id, r))
+        .collect();
+    let winner_ids: Vec<u64> = results
+        .values()
+        .filter_map(|r| r.winning_option_id)
+        .collect();
+    let labels: HashMap<u64, String> = po::proposal_options
+        .filter(po::id.eq_any(&winner_ids))
+        .select((po::id, po::label))
+        .load::<(u64, String)>(conn)?
+        .into_iter()
+        .collect();
+    let agenda = items
+        .into_iter()
+        .map(|item| {
+            let (title, status, ballot_type) = proposals
+                .get(&item.proposal_id)
+                .cloned()
+                .unwrap_or_default();
+            let result = results.remove(&item.proposal_id);
+            let winning_option = result
+                .as_ref()
+                .and_then(|r| r.winning_option_id)
+                .and_then(|id| labels.get(&id).cloned());
+            AgendaItemOut {
+                item,
+                title,
+                status,
+                ballot_type,
+                result,
+                winning_option,
+            }
+        })
+        .collect();
+
+    // Attendance and the share of the building it represents
+    let records: Vec<MeetingAttendance> = ma::meeting_attendance
+        .filter(ma::meeting_id.eq(meeting.id))
+        .select(MeetingAttendance::as_select())
+        .order(ma::registered_at.asc())
+        .load(conn)?;
+    let mut user_ids: Vec<u64> = records.iter().map(|a| a.user_id).collect();
+    user_ids.extend(records.iter().filter_map(|a| a.proxy_holder_id));
+    let names: HashMap<u64, String> = u::users
+        .filter(u::id.eq_any(&user_ids))
+        .select((u::id, u::name))
+        .load::<(u64, String)>(conn)?
+        .into_iter()
+        .collect();
+    let shares = BuildingShares::load(meeting.building_id, conn)?;
+    let present: HashSet<u64> = records.iter().map(|a| a.user_id).collect();
+    let quorum = shares.quorum(&present, meeting.quorum_percent);
+
+    let my_attendance = records
+        .iter()
+        .find(|a| a.user_id == user_id)
+        .map(|a| a.mode.clone());
+    let attendance = records
+        .into_iter()
+        .map(|attendance| AttendeeOut {
+            name: names.get(&attendance.user_id).cloned().unwrap_or_default(),
+            proxy_holder_name: attendance
+                .proxy_holder_id
+                .and_then(|id| names.get(&id).cloned()),
+            represented_area: shares.area_of(attendance.user_id),
+            attendance,
+        })
+        .collect();
+
+    Ok(MeetingDetail {
+        building_address,
+        agenda,
+        attendance,
+        quorum,
+        my_attendance,
+        can_attend: shares.is_owner(user_id),
+        can_manage: auth.has_any_role(&["Admin", "Manager"]),
+        meeting,
+    })
+}
+
+/// Parse and check the fields shared by create and update.
+fn parse_schedule(
+    title: &str,
+    scheduled_at: &str,
+    location: &str,
+    quorum_percent: i32,
+) -> Result<chrono::NaiveDateTime, AppError> {
+    if title.trim().is_empty() {
+        return Err(AppError::BadRequest("Title is required".into()));
+    }
+    if location.trim().is_empty() {
+        return Err(AppError::BadRequest("Location is required".into()));
+    }
+    if !(1..=100).contains(&quorum_percent) {
+        return Err(AppError::BadRequest(
+            "quorum_percent must be between 1 and 100".into(),
+        ));
+    }
+    chrono::NaiveDateTime::parse_from_str(scheduled_at, "%Y-%m-%dT%H:%M")
+        .map_err(|_| AppError::BadRequest("Invalid scheduled_at format".into()))
+}
+
+/// Agenda proposals must be scheduled proposals of the building that are not on
+/// another meeting's agenda.
+fn validate_agenda(
+    meeting_id: Option<u64>,
+    building_id: u64,
+    proposal_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use crate::schema::meeting_agenda_items::dsl as ai;
+    use crate::schema::proposals::dsl as p;
+
+    if proposal_ids.iter().collect::<HashSet<_>>().len() != proposal_ids.len() {
+        return Err(AppError::BadRequest(
+            "A proposal can be on the agenda only once".into(),
+        ));
+    }
+    let proposals: Vec<Proposal> = p::proposals
+        .filter(p::id.eq_any(proposal_ids))
+        .select(Proposal::as_select())
+        .load(conn)?;
+    if proposals.len() != proposal_ids.len() {
+        return Err(AppError::BadRequest(
+            "Unknown proposal on the agenda".into(),
+        ));
+    }
+    for proposal in &proposals {
+        if proposal.building_id != Some(building_id) {
+            return Err(AppError::BadRequest(format!(
+                "Proposal {} does not belong to the meeting's building",
+                proposal.id
+            )));
+        }
+        if proposal.status != "Scheduled" {
+            return Err(AppError::BadRequest(format!(
+                "Proposal {} is not scheduled",
+                proposal.id
+            )));
+        }
+    }
+
+    let mut taken = ai::meeting_agenda_items
+        .select(ai::proposal_id)
+        .filter(ai::proposal_id.eq_any(proposal_ids))
+        .into_boxed();
+    if let Some(id) = meeting_id {
+        taken = taken.filter(ai::meeting_id.ne(id));
+    }
+    if let Some(proposal_id) = taken.first::<u64>(conn).optional()? {
+        return Err(AppError::BadRequest(format!(
+            "Proposal {} is already on another agenda",
+            proposal_id
+        )));
+    }
+    Ok(())
+}
+
+fn save_agenda(
+    meeting_id: u64,
+    proposal_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use crate::schema::meeting_agenda_items::dsl as ai;
+
+    diesel::delete(ai::meeting_agenda_items.filter(ai::meeting_id.eq(meeting_id))).execute(conn)?;
+    if !proposal_ids.is_empty() {
+        let rows: Vec<_> = proposal_ids
+            .iter()
+            .enumerate()
+            .map(|(i, proposal_id)| {
+                (
+                    ai::meeting_id.eq(meeting_id),
+                    ai::proposal_id.eq(*proposal_id),
+                    ai::position.eq(i as i32),
+                )
+            })
+            .collect();
+        diesel::insert_into(ai::meeting_agenda_items)
+            .values(rows)
+            .execute(conn)?;
+    }
+    Ok(())
+}
+
+/// List general assembly meetings
+///
+/// Returns the meetings of the caller's buildings, most recently scheduled first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/meetings",
+    params(PaginationParams),
+    responses(
+        (status = 200, description = "Paginated list of meetings", body = PaginatedResponse<MeetingSummary>),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_meetings(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+    query: web::Query<PaginationParams>,
+) -> Result<impl Responder, AppError> {
+    use crate::auth::get_user_building_ids;
+    use crate::schema::buildings::dsl as b;
+    use crate::schema::meeting_agenda_items::dsl as ai;
+    use crate::schema::meeting_attendance::dsl as ma;
+    use crate::schema::meetings::dsl as m;
+
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let user_id = auth.user_id()?;
+    let is_admin = auth.has_any_role(&["Admin"]);
+    let building_ids = get_user_building_ids(user_id, is_admin, &mut conn)?;
+
+    let mut count_query = m::meetings.into_boxed();
+    if let Some(ref ids) = building_ids {
+        count_query = count_query.filter(m::building_id.eq_any(ids));
+    }
+    let total = count_query.count().get_result::<i64>(&mut conn)?;
+
+    let mut data_query = m::meetings.into_boxed();
+    if let Some(ref ids) = building_ids {
+        data_query = data_query.filter(m::building_id.eq_any(ids));
+    }
+    let meetings: Vec<Meeting> = data_query
+        .select(Meeting::as_select())
+        .order(m::scheduled_at.desc())
+        .then_order_by(m::id.desc())
+        .limit(query.limit())
+        .offset(query.offset())
+        .load(&mut conn)?;
+
+    let ids: Vec<u64> = meetings.iter().map(|m| m.id).collect();
+    let building_of: Vec<u64> = meetings.iter().map(|m| m.building_id).collect();
+    let addresses: HashMap<u64, String> = b::buildings
+        .filter(b::id.eq_any(&building_of))
+        .select((b::id, b::address))
+        .load::<(u64, String)>(&mut conn)?
+        .into_iter()
+        .collect();
+    let agenda_counts: HashMap<u64, i64> = ai::meeting_agenda_items
+        .filter(ai::meeting_id.eq_any(&ids))
+        .group_by(ai::meeting_id)
+        .select((ai::meeting_id, diesel::dsl::count_star()))
+        .load::<(u64, i64)>(&mut conn)?
+        .into_iter()
+        .collect();
+    let attendee_counts: HashMap<u64, i64> = ma::meeting_attendance
+        .filter(ma::meeting_id.eq_any(&ids))
+        .group_by(ma::meeting_id)
+        .select((ma::meeting_id, diesel::dsl::count_star()))
+        .load::<(u64, i64)>(&mut conn)?
+        .into_iter()
+        .collect();
+
+    let data = meetings
+        .into_iter()
+        .map(|meeting| MeetingSummary {
+            building_address: addresses
+                .get(&meeting.building_id)
+                .cloned()
+                .unwrap_or_default(),
+            agenda_count: agenda_counts.get(&meeting.id).copied().unwrap_or(0),
+            attendee_count: attendee_counts.get(&meeting.id).copied().unwrap_or(0),
+            meeting,
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(PaginatedResponse::new(data, total, &query)))
+}
+
+/// Get a meeting
+///
+/// Returns the meeting with its agenda, the registered attendance and the quorum it
+/// currently represents.
+#[utoipa::path(
+    get,
+    path = "/api/v1/meetings/{id}",
+    params(
+        ("id" = u64, Path, description = "Meeting ID")
+    ),
+    responses(
+        (status = 200, description = "Meeting details", body = MeetingDetail),
+        (status = 403, description = "Forbidden - meeting of another building"),
+        (status = 404, description = "Meeting not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_meeting(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let meeting = load_accessible(path.into_inner(), &auth, &mut conn)?;
+    Ok(HttpResponse::Ok().json(load_detail(meeting, &auth, &mut conn)?))
+}
+
+/// Schedule a meeting
+///
+/// Creates a general assembly of one building. The agenda is made of scheduled
+/// proposals of that building, which are opened and closed during the meeting.
+#[utoipa::path(
+    post,
+    path = "/api/v1/meetings",
+    request_body = CreateMeetingPayload,
+    responses(
+        (status = 201, description = "Meeting scheduled", body = MeetingDetail),
+        (status = 400, description = "Invalid input or agenda"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_meeting(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+    payload: web::Json<CreateMeetingPayload>,
+) -> Result<impl Responder, AppError> {
+    use crate::auth::get_user_building_ids;
+    use crate::schema::meetings::dsl as m;
+
+    require_manager(&auth)?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let created_by = auth.user_id()?;
+
+    let is_admin = auth.has_any_role(&["Admin"]);
+    if let Some(buildings) = get_user_building_ids(created_by, is_admin, &mut conn)?
+        && !buildings.contains(&payload.building_id)
+    {
+        return Err(AppError::Forbidden);
+    }
+
+    let scheduled_at = parse_schedule(
+        &payload.title,
+        &payload.scheduled_at,
+        &payload.location,
+        payload.quorum_percent,
+    )?;
+    validate_agenda(None, payload.building_id, &payload.proposal_ids, &mut conn)?;
+
+    diesel::insert_into(m::meetings)
+        .values((
+            m::building_id.eq(payload.building_id),
+            m::title.eq(payload.title.trim()),
+            m::description.eq(&payload.description),
+            m::scheduled_at.eq(scheduled_at),
+            m::location.eq(payload.location.trim()),
+            m::online_url.eq(&payload.online_url),
+            m::quorum_percent.eq(payload.quorum_percent),
+            m::status.eq(MeetingStatus::Scheduled.to_string()),
+            m::created_by.eq(created_by),
+        ))
+        .execute(&mut conn)?;
+    let meeting_id: u64 = diesel::select(diesel::dsl::sql::<
+        diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+    >("LAST_INSERT_ID()"))
+    .first(&mut conn)?;
+    save_agenda(meeting_id, &payload.proposal_ids, &mut conn)?;
+
+    let meeting = load_accessible(meeting_id, &auth, &mut conn)?;
+    Ok(HttpResponse::Created().json(load_detail(meeting, &auth, &mut conn)?))
+}
+
+/// Update a meeting
+///
+/// Changes the schedule, location, quorum and agenda of a meeting that has not started.
+#[utoipa::path(
+    put,
+    path = "/api/v1/meetings/{id}",
+    params(
+        ("id" = u64, Path, description = "Meeting ID")
+    ),
+    request_body = UpdateMeetingPayload,
+    responses(
+        (status = 200, description = "Meeting updated", body = MeetingDetail),
+        (status = 400, description = "Invalid input, invalid agenda or meeting already started"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Meeting not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_meeting(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+    payload: web::Json<UpdateMeetingPayload>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::meetings::dsl as m;
+
+    require_manager(&auth)?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let meeting = load_accessible(path.into_inner(), &auth, &mut conn)?;
+    if meeting_status(&meeting)? != MeetingStatus::Scheduled {
+        return Err(AppError::BadRequest(
+            "Only meetings that have not started can be changed".into(),
+        ));
+    }
+
+    let scheduled_at = parse_schedule(
+        &payload.title,
+        &payload.scheduled_at,
+        &payload.location,
+        payload.quorum_percent,
+    )?;
+    validate_agenda(
+        Some(meeting.id),
+        meeting.building_id,
+        &payload.proposal_ids,
+        &mut conn,
+    )?;
+
+    diesel::update(m::meetings.filter(m::id.eq(meeting.id)))
+        .set((
+            m::title.eq(payload.title.trim()),
+            m::description.eq(&payload.description),
+            m::scheduled_at.eq(scheduled_at),
+            m::location.eq(payload.location.trim()),
+            m::online_url.eq(&payload.online_url),
+            m::quorum_percent.eq(payload.quorum_percent),
+        ))
+        .execute(&mut conn)?;
+    save_agenda(meeting.id, &payload.proposal_ids, &mut conn)?;
+
+    let meeting = load_accessible(meeting.id, &auth, &mut conn)?;
+    Ok(HttpResponse::Ok().json(load_detail(meeting, &auth, &mut conn)?))
+}
+
+/// Delete a meeting
+///
+/// Removes a meeting that has not started. Its proposals are kept.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/meetings/{id}",
+    params(
+        ("id" = u64, Path, description = "Meeting ID")
+    ),
+    responses(
+        (status = 204, description = "Meeting deleted"),
+        (status = 400, description = "Meeting already started"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Meeting not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_meeting(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::meetings::dsl as m;
+
+    require_manager(&auth)?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let meeting = load_accessible(path.into_inner(), &auth, &mut conn)?;
+    if meeting_status(&meeting)? != MeetingStatus::Scheduled {
+        return Err(AppError::BadRequest(
+            "Only meetings that have not started can be deleted".into(),
+        ));
+    }
+    diesel::delete(m::meetings.filter(m::id.eq(meeting.id))).execute(&mut conn)?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Start a meeting
+///
+/// Opens the meeting so its agenda items can be put to the vote.
+#[utoipa::path(
+    post,
+    path = "/api/v1/meetings/{id}/start",
+    params(
+        ("id" = u64, Path, description = "Meeting ID")
+    ),
+    responses(
+        (status = 200, description = "Meeting started", body = MeetingDetail),
+        (status = 400, description = "Meeting already started"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Meeting not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn start_meeting(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::meetings::dsl as m;
+
+    require_manager(&auth)?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let meeting = load_accessible(path.into_inner(), &auth, &mut conn)?;
+    if meeting_status(&meeting)? != MeetingStatus::Scheduled {
+        return Err(AppError::BadRequest("Meeting has already started".into()));
+    }
+    diesel::update(m::meetings.filter(m::id.eq(meeting.id)))
+        .set((
+            m::status.eq(MeetingStatus::InProgress.to_string()),
+            m::started_at.eq(now()),
+        ))
+        .execute(&mut conn)?;
+
+    let meeting = load_accessible(meeting.id, &auth, &mut conn)?;
+    Ok(HttpResponse::Ok().json(load_detail(meeting, &auth, &mut conn)?))
+}
+
+/// End a meeting
+///
+/// Closes the meeting. Agenda items still open for voting are closed and tallied.
+#[utoipa::path(
+    post,
+    path = "/api/v1/meetings/{id}/end",
+    params(
+        ("id" = u64, Path, description = "Meeting ID")
+    ),
+    responses(
+        (status = 200, description = "Meeting ended", body = MeetingDetail),
+        (status = 400, description = "Meeting is not in progress"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Meeting not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn end_meeting(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::meeting_agenda_items::dsl as ai;
+    use crate::schema::meetings::dsl as m;
+
+    require_manager(&auth)?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let meeting = load_accessible(path.into_inner(), &auth, &mut conn)?;
+    if meeting_status(&meeting)? != MeetingStatus::InProgress {
+        return Err(AppError::BadRequest("Meeting is not in progress".into()));
+    }
+
+    let still_open: Vec<u64> = ai::meeting_agenda_items
+        .filter(ai::meeting_id.eq(meeting.id))
+        .filter(ai::opened_at.is_not_null())
+        .filter(ai::closed_at.is_null())
+        .select(ai::proposal_id)
+        .load(&mut conn)?;
+    for proposal_id in still_open {
+        close_item(meeting.id, proposal_id, &mut conn)?;
+    }
+
+    diesel::update(m::meetings.filter(m::id.eq(meeting.id)))
+        .set((
+            m::status.eq(MeetingStatus::Closed.to_string()),
+            m::ended_at.eq(now()),
+        ))
+        .execute(&mut conn)?;
+
+    let meeting = load_accessible(meeting.id, &auth, &mut conn)?;
+    Ok(HttpResponse::Ok().json(load_detail(meeting, &auth, &mut conn)?))
+}
+
+/// Open an agenda item for voting
+///
+/// Puts one proposal of a running meeting to the vote. Requires the quorum to be met.
+#[utoipa::path(
+    post,
+    path = "/api/v1/meetings/{id}/agenda/{proposal_id}/open",
+    params(
+        ("id" = u64, Path, description = "Meeting ID"),
+        ("proposal_id" = u64, Path, description = "Proposal ID")
+    ),
+    responses(
+        (status = 200, description = "Voting opened", body = MeetingDetail),
+        (status = 400, description = "Meeting not in progress, quorum not reached or item already opened"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Meeting or agenda item not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn open_agenda_item(
+    auth: AuthContext,
+    path: web::Path<(u64, u64)>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::meeting_agenda_items::dsl as ai;
+    use crate::schema::meeting_attendance::dsl as ma;
+    use crate::schema::proposals::dsl as p;
+
+    require_manager(&auth)?;
+    let (meeting_id, proposal_id) = path.into_inner();
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let meeting = load_accessible(meeting_id, &auth, &mut conn)?;
+    if meeting_status(&meeting)? != MeetingStatus::InProgress {
+        return Err(AppError::BadRequest("Meeting is not in progress".into()));
+    }
+    let item = load_item(meeting.id, proposal_id, &mut conn)?;
+    if item.opened_at.is_some() {
+        return Err(AppError::BadRequest(
+            "Voting on this item has already been opened".into(),
+        ));
+    }
+
+    let present: HashSet<u64> = ma::meeting_attendance
+        .filter(ma::meeting_id.eq(meeting.id))
+        .select(ma::user_id)
+        .load::<u64>(&mut conn)?
+        .into_iter()
+        .collect();
+    let quorum = BuildingShares::load(meeting.building_id, &mut conn)?
+        .quorum(&present, meeting.quorum_percent);
+    if !quorum.reached {
+        return Err(AppError::BadRequest("Quorum not reached".into()));
+    }
+
+    diesel::update(p::proposals.filter(p::id.eq(proposal_id)))
+        .set(p::status.eq("Open"))
+        .execute(&mut conn)?;
+    diesel::update(
+        ai::meeting_agenda_items
+            .filter(ai::meeting_id.eq(meeting.id))
+            .filter(ai::proposal_id.eq(proposal_id)),
+    )
+    .set(ai::opened_at.eq(now()))
+    .execute(&mut conn)?;
+
+    let meeting = load_accessible(meeting.id, &auth, &mut conn)?;
+    Ok(HttpResponse::Ok().json(load_detail(meeting, &auth, &mut conn)?))
+}
+
+/// Close an agenda item
+///
+/// Ends voting on a proposal of a running meeting and tallies its result.
+#[utoipa::path(
+    post,
+    path = "/api/v1/meetings/{id}/agenda/{proposal_id}/close",
+    params(
+        ("id" = u64, Path, description = "Meeting ID"),
+        ("proposal_id" = u64, Path, description = "Proposal ID")
+    ),
+    responses(
+        (status = 200, description = "Voting closed and tallied", body = MeetingDetail),
+        (status = 400, description = "Meeting not in progress or item not open"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Meeting or agenda item not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn close_agenda_item(
+    auth: AuthContext,
+    path: web::Path<(u64, u64)>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    require_manager(&auth)?;
+    let (meeting_id, proposal_id) = path.into_inner();
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let meeting = load_accessible(meeting_id, &auth, &mut conn)?;
+    if meeting_status(&meeting)? != MeetingStatus::InProgress {
+        return Err(AppError::BadRequest("Meeting is not in progress".into()));
+    }
+    let item = load_item(meeting.id, proposal_id, &mut conn)?;
+    if item.opened_at.is_none() || item.closed_at.is_some() {
+        return Err(AppError::BadRequest(
+            "Voting on this item is not open".into(),
+        ));
+    }
+    close_item(meeting.id, proposal_id, &mut conn)?;
+
+    let meeting = load_accessible(meeting.id, &auth, &mut conn)?;
+    Ok(HttpResponse::Ok().json(load_detail(meeting, &auth, &mut conn)?))
+}
+
+fn load_item(
+    meeting_id: u64,
+    proposal_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<MeetingAgendaItem, AppError> {
+    use crate::schema::meeting_agenda_items::dsl as ai;
+
+    ai::meeting_agenda_items
+        .filter(ai::meeting_id.eq(meeting_id))
+        .filter(ai::proposal_id.eq(proposal_id))
+        .select(MeetingAgendaItem::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or(AppError::NotFound)
+}
+
+/// Stop voting on an agenda proposal and store its result.
+fn close_item(
+    meeting_id: u64,
+    proposal_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use crate::schema::meeting_agenda_items::dsl as ai;
+    use crate::schema::proposals::dsl as p;
+
+    diesel::update(p::proposals.filter(p::id.eq(proposal_id)))
+        .set(p::status.eq("Closed"))
+        .execute(conn)?;
+    diesel::update(
+        ai::meeting_agenda_items
+            .filter(ai::meeting_id.eq(meeting_id))
+            .filter(ai::proposal_id.eq(proposal_id)),
+    )
+    .set(ai::closed_at.eq(now()))
+    .execute(conn)?;
+
+    let proposal: Proposal = p::proposals
+        .filter(p::id.eq(proposal_id))
+        .select(Proposal::as_select())
+        .first(conn)?;
+    crate::voting::tally_proposal(&proposal, conn)?;
+    Ok(())
+}
@@ -0,0 +1,327 @@
+use super::handlers::{load_accessible, load_detail, meeting_status, require_manager};
+use super::types::{MeetingDetail, MinutesOut, PublishMinutesPayload};
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::models::{AttendanceMode, MeetingStatus};
+use actix_web::{HttpResponse, Responder, web};
+use diesel::prelude::*;
+use std::fmt::Write;
+
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// Markdown minutes of a meeting: attendance, quorum and the result of every agenda
+/// item as stored in `proposal_results`.
+pub(super) fn render_minutes(detail: &MeetingDetail) -> String {
+    let meeting = &detail.meeting;
+    let mut md = String::new();
+
+    let _ = writeln!(md, "# Minutes: {}\n", meeting.title);
+    let _ = writeln!(md, "- **Building:** {}", detail.building_address);
+    let _ = writeln!(md, "- **Location:** {}", meeting.location);
+    let _ = writeln!(
+        md,
+        "- **Scheduled:** {}",
+        meeting.scheduled_at.format(DATE_FORMAT)
+    );
+    if let Some(started) = meeting.started_at {
+        let _ = writeln!(md, "- **Opened:** {}", started.format(DATE_FORMAT));
+    }
+    if let Some(ended) = meeting.ended_at {
+        let _ = writeln!(md, "- **Closed:** {}", ended.format(DATE_FORMAT));
+    }
+
+    let quorum = &detail.quorum;
+    let _ = writeln!(md, "\n## Attendance\n");
+    let _ = writeln!(
+        md,
+        "Owners representing {:.2} % of the building attended (quorum {} %: {}).\n",
+        quorum.percent,
+        quorum.required_percent,
+        if quorum.reached {
+            "reached"
+        } else {
+            "not reached"
+        }
+    );
+    if detail.attendance.is_empty() {
+        let _ = writeln!(md, "No attendance was registered.");
+    }
+    for attendee in &detail.attendance {
+        let mode = match attendee.attendance.mode.parse::<AttendanceMode>() {
+            Ok(AttendanceMode::InPerson) => "in person".to_string(),
+            Ok(AttendanceMode::Online) => "online".to_string(),
+            Ok(AttendanceMode::Proxy) => format!(
+                "by proxy ({})",
+                attendee.proxy_holder_name.as_deref().unwrap_or("?")
+            ),
+            Err(()) => attendee.attendance.mode.clone(),
+        };
+        let _ = writeln!(md, "- {}, {}", attendee.name, mode);
+    }
+
+    let _ = writeln!(md, "\n## Resolutions");
+    if detail.agenda.is_empty() {
+        let _ = writeln!(md, "\nThe agenda was empty.");
+    }
+    for (i, item) in detail.agenda.iter().enumerate() {
+        let _ = writeln!(md, "\n### {}. {}\n", i + 1, item.title);
+        let Some(result) = &item.result else {
+            let _ = writeln!(md, "Not put to the vote.");
+            continue;
+        };
+        let _ = writeln!(
+            md,
+            "**{}**",
+            if result.passed { "Passed" } else { "Rejected" }
+        );
+        if let Some(option) = &item.winning_option {
+            let _ = writeln!(md, "\nWinning option: {}", option);
+        }
+        if item.ballot_type == "YesNo" {
+            let _ = writeln!(
+                md,
+                "\nYes {:.2}, No {:.2}, Abstain {:.2} (total weight {:.2})",
+                result.yes_weight, result.no_weight, result.abstain_weight, result.total_weight
+            );
+        } else {
+            let _ = writeln!(md, "\nTotal weight cast {:.2}", result.total_weight);
+        }
+    }
+
+    md
+}
+
+/// Get the minutes
+///
+/// Returns the published minutes, or for Admin and Manager a draft generated from the
+/// attendance and the stored results.
+#[utoipa::path(
+    get,
+    path = "/api/v1/meetings/{id}/minutes",
+    params(
+        ("id" = u64, Path, description = "Meeting ID")
+    ),
+    responses(
+        (status = 200, description = "Minutes in Markdown", body = MinutesOut),
+        (status = 403, description = "Forbidden - drafts require Admin or Manager role"),
+        (status = 404, description = "Meeting not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_minutes(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let meeting = load_accessible(path.into_inner(), &auth, &mut conn)?;
+    if let Some(minutes_md) = meeting.minutes_md.clone() {
+        return Ok(HttpResponse::Ok().json(MinutesOut {
+            minutes_md,
+            announcement_id: meeting.minutes_announcement_id,
+        }));
+    }
+
+    require_manager(&auth)?;
+    let detail = load_detail(meeting, &auth, &mut conn)?;
+    Ok(HttpResponse::Ok().json(MinutesOut {
+        minutes_md: render_minutes(&detail),
+        announcement_id: None,
+    }))
+}
+
+/// Publish the minutes
+///
+/// Stores the minutes of an ended meeting and publishes them as an announcement to
+/// the building's residents. The generated draft is used unless edited minutes are
+/// supplied.
+#[utoipa::path(
+    post,
+    path = "/api/v1/meetings/{id}/minutes",
+    params(
+        ("id" = u64, Path, description = "Meeting ID")
+    ),
+    request_body = PublishMinutesPayload,
+    responses(
+        (status = 201, description = "Minutes published", body = MinutesOut),
+        (status = 400, description = "Meeting has not ended or minutes already published"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Meeting not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meetings",
+    security(("bearer_auth" = []))
+)]
+pub async fn publish_minutes(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+    payload: web::Json<PublishMinutesPayload>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::meetings::dsl as m;
+
+    require_manager(&auth)?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let meeting = load_accessible(path.into_inner(), &auth, &mut conn)?;
+    if meeting_status(&meeting)? != MeetingStatus::Closed {
+        return Err(AppError::BadRequest(
+            "Minutes can be published once the meeting has ended".into(),
+        ));
+    }
+    if meeting.minutes_md.is_some() {
+        return Err(AppError::BadRequest(
+            "Minutes have already been published".into(),
+        ));
+    }
+
+    let meeting_id = meeting.id;
+    let building_id = meeting.building_id;
+    let title = format!("Minutes: {}", meeting.title);
+    let minutes_md = match payload
+        .into_inner()
+        .minutes_md
+        .filter(|md| !md.trim().is_empty())
+    {
+        Some(md) => md,
+        None => render_minutes(&load_detail(meeting, &auth, &mut conn)?),
+    };
+
+    let announcement_id = crate::announcements::publish_to_building(
+        &title,
+        &minutes_md,
+        auth.user_id()?,
+        building_id,
+        &mut conn,
+    )?;
+    diesel::update(m::meetings.filter(m::id.eq(meeting_id)))
+        .set((
+            m::minutes_md.eq(&minutes_md),
+            m::minutes_announcement_id.eq(announcement_id),
+        ))
+        .execute(&mut conn)?;
+
+    Ok(HttpResponse::Created().json(MinutesOut {
+        minutes_md,
+        announcement_id: Some(announcement_id),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meetings::types::{AgendaItemOut, AttendeeOut, QuorumStatus};
+    use crate::models::{Meeting, MeetingAgendaItem, MeetingAttendance, ProposalResult};
+    use bigdecimal::BigDecimal;
+
+    fn at(s: &str) -> chrono::NaiveDateTime {
+        chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M").unwrap()
+    }
+
+    fn detail() -> MeetingDetail {
+        MeetingDetail {
+            meeting: Meeting {
+                id: 1,
+                building_id: 1,
+                title: "Annual assembly".into(),
+                description: String::new(),
+                scheduled_at: at("2026-05-12T18:00"),
+                location: "Lobby".into(),
+                online_url: None,
+                quorum_percent: 50,
+                status: "Closed".into(),
+                started_at: Some(at("2026-05-12T18:05")),
+                ended_at: Some(at("2026-05-12T19:30")),
+                minutes_md: None,
+                minutes_announcement_id: None,
+                created_by: 1,
+                created_at: None,
+            },
+            building_address: "Main Street 1".into(),
+            agenda: vec![
+                AgendaItemOut {
+                    item: MeetingAgendaItem {
+                        meeting_id: 1,
+                        proposal_id: 7,
+                        position: 0,
+                        opened_at: Some(at("2026-05-12T18:10")),
+                        closed_at: Some(at("2026-05-12T18:20")),
+                    },
+                    title: "New roof".into(),
+                    status: "Tallied".into(),
+                    ballot_type: "YesNo".into(),
+                    result: Some(ProposalResult {
+                        id: 3,
+                        proposal_id: 7,
+                        passed: true,
+                        winning_option_id: None,
+                        yes_weight: BigDecimal::from(3),
+                        no_weight: BigDecimal::from(1),
+                        abstain_weight: BigDecimal::from(0),
+                        total_weight: BigDecimal::from(4),
+                        tallied_at: None,
+                        method_applied_version: "v1".into(),
+                        results_hash: None,
+                    }),
+                    winning_option: None,
+                },
+                AgendaItemOut {
+                    item: MeetingAgendaItem {
+                        meeting_id: 1,
+                        proposal_id: 8,
+                        position: 1,
+                        opened_at: None,
+                        closed_at: None,
+                    },
+                    title: "Bike shed".into(),
+                    status: "Scheduled".into(),
+                    ballot_type: "YesNo".into(),
+                    result: None,
+                    winning_option: None,
+                },
+            ],
+            attendance: vec![AttendeeOut {
+                attendance: MeetingAttendance {
+                    meeting_id: 1,
+                    user_id: 2,
+                    mode: "Proxy".into(),
+                    proxy_holder_id: Some(5),
+                    registered_by: 1,
+                    registered_at: at("2026-05-12T17:55"),
+                },
+                name: "Jana".into(),
+                proxy_holder_name: Some("Petr".into()),
+                represented_area: 60.0,
+            }],
+            quorum: QuorumStatus {
+                represented: 60.0,
+                total: 100.0,
+                percent: 60.0,
+                required_percent: 50,
+                reached: true,
+            },
+            my_attendance: None,
+            can_attend: false,
+            can_manage: true,
+        }
+    }
+
+    #[test]
+    fn minutes_list_attendance_and_stored_results() {
+        let md = render_minutes(&detail());
+        assert!(md.starts_with("# Minutes: Annual assembly\n"));
+        assert!(md.contains("- **Opened:** 2026-05-12 18:05"));
+        assert!(md.contains("60.00 % of the building attended (quorum 50 %: reached)"));
+        assert!(md.contains("- Jana, by proxy (Petr)"));
+        assert!(md.contains("### 1. New roof\n\n**Passed**"));
+        assert!(md.contains("Yes 3.00, No 1.00, Abstain 0.00 (total weight 4.00)"));
+        assert!(md.contains("### 2. Bike shed\n\nNot put to the vote."));
+    }
+}
@@ -0,0 +1,39 @@
+mod attendance;
+mod handlers;
+mod minutes;
+mod types;
+
+pub use attendance::*;
+pub use handlers::*;
+pub use minutes::*;
+pub use types::*;
+
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/meetings", web::get().to(list_meetings))
+        .route("/meetings", web::post().to(create_meeting))
+        .route("/meetings/{id}", web::get().to(get_meeting))
+        .route("/meetings/{id}", web::put().to(update_meeting))
+        .route("/meetings/{id}", web::delete().to(delete_meeting))
+        .route("/meetings/{id}/start", web::post().to(start_meeting))
+        .route("/meetings/{id}/end", web::post().to(end_meeting))
+        .route(
+            "/meetings/{id}/attendance",
+            web::post().to(register_attendance),
+        )
+        .route(
+            "/meetings/{id}/attendance/{user_id}",
+            web::delete().to(remove_attendance),
+        )
+        .route(
+            "/meetings/{id}/agenda/{proposal_id}/open",
+            web::post().to(open_agenda_item),
+        )
+        .route(
+            "/meetings/{id}/agenda/{proposal_id}/close",
+            web::post().to(close_agenda_item),
+        )
+        .route("/meetings/{id}/minutes", web::get().to(get_minutes))
+        .route("/meetings/{id}/minutes", web::post().to(publish_minutes));
+}
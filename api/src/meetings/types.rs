@@ -0,0 +1,125 @@
+use crate::models::{
+    AttendanceMode, Meeting, MeetingAgendaItem, MeetingAttendance, ProposalResult,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+fn default_quorum() -> i32 {
+    50
+}
+
+/// Schedule a general assembly (Admin/Manager only)
+#[derive(Deserialize, ToSchema)]
+pub struct CreateMeetingPayload {
+    pub building_id: u64,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[schema(example = "2026-05-12T18:00")]
+    pub scheduled_at: String, // ISO datetime string (YYYY-MM-DDTHH:MM)
+    pub location: String,
+    pub online_url: Option<String>,
+    /// Share of the building's floor area that must be represented, in percent
+    #[serde(default = "default_quorum")]
+    pub quorum_percent: i32,
+    /// Scheduled proposals of the building, in agenda order
+    #[serde(default)]
+    pub proposal_ids: Vec<u64>,
+}
+
+/// Change a meeting that has not started; `proposal_ids` replaces the agenda
+#[derive(Deserialize, ToSchema)]
+pub struct UpdateMeetingPayload {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[schema(example = "2026-05-12T18:00")]
+    pub scheduled_at: String,
+    pub location: String,
+    pub online_url: Option<String>,
+    #[serde(default = "default_quorum")]
+    pub quorum_percent: i32,
+    #[serde(default)]
+    pub proposal_ids: Vec<u64>,
+}
+
+/// Register an owner's attendance. `user_id` defaults to the caller; only Admin and
+/// Manager may register someone else. `proxy_holder_id` is required for Proxy.
+#[derive(Deserialize, ToSchema)]
+pub struct RegisterAttendancePayload {
+    pub user_id: Option<u64>,
+    pub mode: AttendanceMode,
+    pub proxy_holder_id: Option<u64>,
+}
+
+/// Publish the minutes; the generated draft is used when `minutes_md` is omitted
+#[derive(Deserialize, ToSchema)]
+pub struct PublishMinutesPayload {
+    pub minutes_md: Option<String>,
+}
+
+/// A meeting in the list
+#[derive(Serialize, ToSchema)]
+pub struct MeetingSummary {
+    #[serde(flatten)]
+    pub meeting: Meeting,
+    pub building_address: String,
+    pub agenda_count: i64,
+    pub attendee_count: i64,
+}
+
+/// An agenda item with the proposal's title, status and result
+#[derive(Serialize, ToSchema)]
+pub struct AgendaItemOut {
+    #[serde(flatten)]
+    pub item: MeetingAgendaItem,
+    pub title: String,
+    pub status: String,
+    pub ballot_type: String,
+    pub result: Option<ProposalResult>,
+    /// Label of the winning option on option ballots
+    pub winning_option: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct AttendeeOut {
+    #[serde(flatten)]
+    pub attendance: MeetingAttendance,
+    pub name: String,
+    pub proxy_holder_name: Option<String>,
+    /// Floor area of the building owned by the attendee, in m²
+    pub represented_area: f64,
+}
+
+/// How much of the building the registered attendees represent
+#[derive(Serialize, ToSchema, Debug, PartialEq)]
+pub struct QuorumStatus {
+    pub represented: f64,
+    pub total: f64,
+    pub percent: f64,
+    pub required_percent: i32,
+    pub reached: bool,
+}
+
+/// A meeting with its agenda, attendance and quorum
+#[derive(Serialize, ToSchema)]
+pub struct MeetingDetail {
+    #[serde(flatten)]
+    pub meeting: Meeting,
+    pub building_address: String,
+    pub agenda: Vec<AgendaItemOut>,
+    pub attendance: Vec<AttendeeOut>,
+    pub quorum: QuorumStatus,
+    /// The caller's own registration
+    pub my_attendance: Option<String>,
+    /// Whether the caller owns an apartment in the building and may register
+    pub can_attend: bool,
+    pub can_manage: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct MinutesOut {
+    pub minutes_md: String,
+    /// Set once published
+    pub announcement_id: Option<u64>,
+}
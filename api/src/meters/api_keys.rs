@@ -1,16 +1,203 @@
-use super::types::{ApiKeyResponse, CreateApiKeyRequest};
+use super::types::{
+    ApiKeyDetails, ApiKeyResponse, CreateApiKeyRequest, RotateApiKeyRequest, UpdateApiKeyRequest,
+};
 use crate::auth::{AppError, AuthContext};
 use crate::db::DbPool;
-use crate::models::WebhookApiKey;
+use crate::models::{NewWebhookApiKey, WebhookApiKey};
 use actix_web::{HttpResponse, Responder, web};
+use diesel::mysql::MysqlConnection;
 use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use rand::Rng;
+
+/// Start of every key issued with a prefix: `hm_<prefix>_<secret>`
+const KEY_PREFIX: &str = "hm_";
+const PREFIX_LENGTH: usize = 8;
+const SECRET_LENGTH: usize = 32;
+/// New prefixes to try when one is already taken
+const PREFIX_ATTEMPTS: usize = 5;
+/// How long a rotated key keeps working by default
+const DEFAULT_OVERLAP_HOURS: u32 = 24;
+const MAX_OVERLAP_HOURS: u32 = 720;
+
+fn random_string(length: usize) -> String {
+    rand::thread_rng()
+        .sample_iter(&rand::distributions::Alphanumeric)
+        .take(length)
+        .map(char::from)
+        .collect()
+}
+
+/// Split a key into its prefix and secret; `None` for keys issued without a prefix.
+pub(super) fn split_key(api_key: &str) -> Option<(&str, &str)> {
+    let (prefix, secret) = api_key.strip_prefix(KEY_PREFIX)?.split_once('_')?;
+    (prefix.len() == PREFIX_LENGTH && !secret.is_empty()).then_some((prefix, secret))
+}
+
+/// Insert a new key and return it in full; only its secret's hash is stored. A prefix
+/// that is already taken is replaced by a fresh one.
+fn issue_key(
+    name: &str,
+    expires_at: Option<chrono::NaiveDateTime>,
+    rate_limit_per_minute: Option<u32>,
+    rotated_from_id: Option<u64>,
+    created_by: u64,
+    conn: &mut MysqlConnection,
+) -> Result<(u64, String), AppError> {
+    use crate::auth::crypto::hash_password;
+    use crate::schema::webhook_api_keys::dsl as wak;
+
+    let secret = random_string(SECRET_LENGTH);
+    let api_key_hash = hash_password(&secret)?;
+
+    let mut attempts = 0;
+    let prefix = loop {
+        let prefix = random_string(PREFIX_LENGTH).to_ascii_lowercase();
+        match diesel::insert_into(wak::webhook_api_keys)
+            .values(NewWebhookApiKey {
+                name: name.to_string(),
+                key_prefix: Some(prefix.clone()),
+                api_key_hash: api_key_hash.clone(),
+                expires_at,
+                rate_limit_per_minute,
+                created_by,
+                rotated_from_id,
+            })
+            .execute(conn)
+        {
+            Ok(_) => break prefix,
+            Err(DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _))
+                if attempts + 1 < PREFIX_ATTEMPTS =>
+            {
+                attempts += 1
+            }
+            Err(e) => return Err(e.into()),
+        }
+    };
+
+    let inserted_id: u64 = diesel::select(diesel::dsl::sql::<
+        diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+    >("LAST_INSERT_ID()"))
+    .first(conn)?;
+
+    Ok((inserted_id, format!("{}{}_{}", KEY_PREFIX, prefix, secret)))
+}
+
+fn unique(ids: &[u64]) -> Vec<u64> {
+    let mut ids = ids.to_vec();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+/// Check a key's settings and that the buildings and meters of its scope exist.
+fn validate_settings(
+    name: &str,
+    rate_limit_per_minute: Option<u32>,
+    building_ids: &[u64],
+    meter_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use crate::schema::buildings::dsl as b;
+    use crate::schema::meters::dsl as m;
+
+    let name = name.trim();
+    if name.is_empty() || name.len() > 128 {
+        return Err(AppError::BadRequest("invalid_name".into()));
+    }
+    if rate_limit_per_minute == Some(0) {
+        return Err(AppError::BadRequest("invalid_rate_limit".into()));
+    }
+    let buildings: i64 = b::buildings
+        .filter(b::id.eq_any(building_ids))
+        .filter(b::is_deleted.eq(false))
+        .count()
+        .get_result(conn)?;
+    if buildings as usize != building_ids.len() {
+        return Err(AppError::BadRequest("unknown_building".into()));
+    }
+    let meters: i64 = m::meters
+        .filter(m::id.eq_any(meter_ids))
+        .count()
+        .get_result(conn)?;
+    if meters as usize != meter_ids.len() {
+        return Err(AppError::BadRequest("unknown_meter".into()));
+    }
+    Ok(())
+}
+
+/// Replace the buildings and meters a key is restricted to, and mark it scoped if any.
+fn save_scope(
+    key_id: u64,
+    building_ids: &[u64],
+    meter_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use crate::schema::webhook_api_key_buildings::dsl as kb;
+    use crate::schema::webhook_api_key_meters::dsl as km;
+    use crate::schema::webhook_api_keys::dsl as wak;
+
+    diesel::update(wak::webhook_api_keys.filter(wak::id.eq(key_id)))
+        .set(wak::scoped.eq(!building_ids.is_empty() || !meter_ids.is_empty()))
+        .execute(conn)?;
+    diesel::delete(kb::webhook_api_key_buildings.filter(kb::api_key_id.eq(key_id)))
+        .execute(conn)?;
+    diesel::delete(km::webhook_api_key_meters.filter(km::api_key_id.eq(key_id))).execute(conn)?;
+    let buildings: Vec<_> = building_ids
+        .iter()
+        .map(|id| (kb::api_key_id.eq(key_id), kb::building_id.eq(*id)))
+        .collect();
+    if !buildings.is_empty() {
+        diesel::insert_into(kb::webhook_api_key_buildings)
+            .values(&buildings)
+            .execute(conn)?;
+    }
+    let meters: Vec<_> = meter_ids
+        .iter()
+        .map(|id| (km::api_key_id.eq(key_id), km::meter_id.eq(*id)))
+        .collect();
+    if !meters.is_empty() {
+        diesel::insert_into(km::webhook_api_key_meters)
+            .values(&meters)
+            .execute(conn)?;
+    }
+    Ok(())
+}
+
+/// Buildings and meters a key is restricted to
+pub(super) fn load_scope(
+    key_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<(Vec<u64>, Vec<u64>), AppError> {
+    use crate::schema::webhook_api_key_buildings::dsl as kb;
+    use crate::schema::webhook_api_key_meters::dsl as km;
+
+    let building_ids = kb::webhook_api_key_buildings
+        .filter(kb::api_key_id.eq(key_id))
+        .select(kb::building_id)
+        .load(conn)?;
+    let meter_ids = km::webhook_api_key_meters
+        .filter(km::api_key_id.eq(key_id))
+        .select(km::meter_id)
+        .load(conn)?;
+    Ok((building_ids, meter_ids))
+}
+
+fn details(key: WebhookApiKey, conn: &mut MysqlConnection) -> Result<ApiKeyDetails, AppError> {
+    let (building_ids, meter_ids) = load_scope(key.id, conn)?;
+    Ok(ApiKeyDetails {
+        key,
+        building_ids,
+        meter_ids,
+    })
+}
 
 /// List API keys (Admin only)
 #[utoipa::path(
     get,
     path = "/api/v1/admin/api-keys",
     responses(
-        (status = 200, description = "List of API keys", body = Vec<WebhookApiKey>),
+        (status = 200, description = "List of API keys with their scope and usage", body = Vec<ApiKeyDetails>),
         (status = 403, description = "Forbidden")
     ),
     tag = "Admin",
@@ -31,19 +218,28 @@ pub async fn list_api_keys(
     use crate::schema::webhook_api_keys::dsl as wak;
 
     let keys: Vec<WebhookApiKey> = wak::webhook_api_keys
+        .order(wak::id.desc())
         .select(WebhookApiKey::as_select())
         .load(&mut conn)?;
+    let keys = keys
+        .into_iter()
+        .map(|key| details(key, &mut conn))
+        .collect::<Result<Vec<_>, _>>()?;
 
     Ok(HttpResponse::Ok().json(keys))
 }
 
 /// Create API key (Admin only)
+///
+/// The key is only returned here. Its prefix identifies it in the list; requests are
+/// limited to the key's buildings and meters, if it has any.
 #[utoipa::path(
     post,
     path = "/api/v1/admin/api-keys",
     request_body = CreateApiKeyRequest,
     responses(
         (status = 201, description = "API key created", body = ApiKeyResponse),
+        (status = 400, description = "Invalid settings"),
         (status = 403, description = "Forbidden")
     ),
     tag = "Admin",
@@ -59,43 +255,189 @@ pub async fn create_api_key(
     }
 
     let user_id = auth.user_id()?;
+    let now = chrono::Utc::now().naive_utc();
+    if payload.expires_at.is_some_and(|at| at <= now) {
+        return Err(AppError::BadRequest("expiry_in_past".into()));
+    }
 
-    // Generate random API key
-    use rand::Rng;
-    let api_key: String = rand::thread_rng()
-        .sample_iter(&rand::distributions::Alphanumeric)
-        .take(32)
-        .map(char::from)
-        .collect();
+    let building_ids = unique(&payload.building_ids);
+    let meter_ids = unique(&payload.meter_ids);
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    validate_settings(
+        &payload.name,
+        payload.rate_limit_per_minute,
+        &building_ids,
+        &meter_ids,
+        &mut conn,
+    )?;
 
-    // Hash the API key
-    use crate::auth::crypto::hash_password;
-    let api_key_hash = hash_password(&api_key)?;
+    let name = payload.name.trim();
+    let (id, api_key) = conn.transaction(|conn| {
+        let (id, api_key) = issue_key(
+            name,
+            payload.expires_at,
+            payload.rate_limit_per_minute,
+            None,
+            user_id,
+            conn,
+        )?;
+        save_scope(id, &building_ids, &meter_ids, conn)?;
+        Ok::<_, AppError>((id, api_key))
+    })?;
+
+    Ok(HttpResponse::Created().json(ApiKeyResponse {
+        id,
+        name: name.to_string(),
+        api_key, // Only returned on creation
+    }))
+}
+
+/// Update API key (Admin only)
+///
+/// Changes the name, expiry, rate limit and scope; the key itself stays the same.
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/api-keys/{id}",
+    params(
+        ("id" = u64, Path, description = "API key ID")
+    ),
+    request_body = UpdateApiKeyRequest,
+    responses(
+        (status = 200, description = "API key updated", body = ApiKeyDetails),
+        (status = 400, description = "Invalid settings"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found")
+    ),
+    tag = "Admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_api_key(
+    auth: AuthContext,
+    key_id: web::Path<u64>,
+    payload: web::Json<UpdateApiKeyRequest>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    if !auth.has_any_role(&["Admin"]) {
+        return Err(AppError::Forbidden);
+    }
 
+    let key_id = key_id.into_inner();
+    let building_ids = unique(&payload.building_ids);
+    let meter_ids = unique(&payload.meter_ids);
     let mut conn = pool
         .get()
         .map_err(|_| AppError::Internal("db_pool".into()))?;
+    validate_settings(
+        &payload.name,
+        payload.rate_limit_per_minute,
+        &building_ids,
+        &meter_ids,
+        &mut conn,
+    )?;
 
     use crate::schema::webhook_api_keys::dsl as wak;
 
-    diesel::insert_into(wak::webhook_api_keys)
-        .values((
-            wak::name.eq(&payload.name),
-            wak::api_key_hash.eq(&api_key_hash),
-            wak::created_by.eq(user_id),
-        ))
-        .execute(&mut conn)?;
+    let key = conn.transaction(|conn| {
+        let updated = diesel::update(wak::webhook_api_keys.filter(wak::id.eq(key_id)))
+            .set((
+                wak::name.eq(payload.name.trim()),
+                wak::expires_at.eq(payload.expires_at),
+                wak::rate_limit_per_minute.eq(payload.rate_limit_per_minute),
+            ))
+            .execute(conn)?;
+        if updated == 0 {
+            return Err(AppError::NotFound);
+        }
+        save_scope(key_id, &building_ids, &meter_ids, conn)?;
+        let key: WebhookApiKey = wak::webhook_api_keys
+            .filter(wak::id.eq(key_id))
+            .select(WebhookApiKey::as_select())
+            .first(conn)?;
+        details(key, conn)
+    })?;
 
-    let inserted_id: u64 = diesel::select(diesel::dsl::sql::<
-        diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
-    >("LAST_INSERT_ID()"))
-    .first(&mut conn)?;
+    Ok(HttpResponse::Ok().json(key))
+}
 
-    Ok(HttpResponse::Created().json(ApiKeyResponse {
-        id: inserted_id,
-        name: payload.name.clone(),
-        api_key, // Only returned on creation
-    }))
+/// Rotate API key (Admin only)
+///
+/// Issues a new key with the same name, expiry, rate limit and scope. The old key keeps
+/// working for the overlap window, so clients can switch over, and then expires.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/api-keys/{id}/rotate",
+    params(
+        ("id" = u64, Path, description = "API key ID")
+    ),
+    request_body = RotateApiKeyRequest,
+    responses(
+        (status = 201, description = "New API key", body = ApiKeyResponse),
+        (status = 400, description = "Key revoked or overlap too long"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found")
+    ),
+    tag = "Admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn rotate_api_key(
+    auth: AuthContext,
+    key_id: web::Path<u64>,
+    payload: web::Json<RotateApiKeyRequest>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    if !auth.has_any_role(&["Admin"]) {
+        return Err(AppError::Forbidden);
+    }
+
+    let user_id = auth.user_id()?;
+    let key_id = key_id.into_inner();
+    let overlap_hours = payload.overlap_hours.unwrap_or(DEFAULT_OVERLAP_HOURS);
+    if overlap_hours > MAX_OVERLAP_HOURS {
+        return Err(AppError::BadRequest("overlap_too_long".into()));
+    }
+
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    use crate::schema::webhook_api_keys::dsl as wak;
+
+    let (id, name, api_key) = conn.transaction(|conn| {
+        let old: WebhookApiKey = wak::webhook_api_keys
+            .filter(wak::id.eq(key_id))
+            .select(WebhookApiKey::as_select())
+            .for_update()
+            .first(conn)
+            .optional()?
+            .ok_or(AppError::NotFound)?;
+        if !old.is_active {
+            return Err(AppError::BadRequest("key_revoked".into()));
+        }
+
+        let (id, api_key) = issue_key(
+            &old.name,
+            old.expires_at,
+            old.rate_limit_per_minute,
+            Some(old.id),
+            user_id,
+            conn,
+        )?;
+        let (building_ids, meter_ids) = load_scope(old.id, conn)?;
+        save_scope(id, &building_ids, &meter_ids, conn)?;
+
+        // The old key never outlives its own expiry
+        let overlap_end =
+            chrono::Utc::now().naive_utc() + chrono::Duration::hours(overlap_hours.into());
+        let expires_at = old.expires_at.map_or(overlap_end, |at| at.min(overlap_end));
+        diesel::update(wak::webhook_api_keys.filter(wak::id.eq(old.id)))
+            .set(wak::expires_at.eq(expires_at))
+            .execute(conn)?;
+        Ok::<_, AppError>((id, old.name, api_key))
+    })?;
+
+    Ok(HttpResponse::Created().json(ApiKeyResponse { id, name, api_key }))
 }
 
 /// Revoke API key (Admin only)
@@ -134,3 +476,20 @@ pub async fn revoke_api_key(
 
     Ok(HttpResponse::NoContent().finish())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_prefixed_keys_only() {
+        assert_eq!(
+            split_key("hm_ab12cd34_SecretPart"),
+            Some(("ab12cd34", "SecretPart"))
+        );
+        assert_eq!(split_key("hm_short_secret"), None);
+        assert_eq!(split_key("hm_ab12cd34_"), None);
+        // Keys issued before prefixes are plain alphanumerics
+        assert_eq!(split_key("Zx81Kq0mPl2Wn7Rt5Yv3Bc9Df4Gh6Jk1"), None);
+    }
+}
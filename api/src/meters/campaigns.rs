@@ -0,0 +1,809 @@
+//! Reading campaigns: periodic collection of every meter's reading in a building.
+//!
+//! A manager opens a campaign for a building with a window from `start_date` to
+//! `deadline`. Every active meter of the building's apartments should get a reading
+//! in that window, be it from a webhook, a manager or an approved resident
+//! submission. Residents of apartments still missing a reading are notified when the
+//! campaign starts and whenever the manager sends a reminder. Once the deadline has
+//! passed the manager closes the campaign, and the meters nobody read get a reading
+//! estimated from their history (source `Estimated`).
+//!
+//! When `CAMPAIGN_CHECK_INTERVAL_SECS` is set, a background task does the same on its
+//! own: it reminds the residents of started campaigns every `CAMPAIGN_REMINDER_DAYS`
+//! and closes campaigns past their deadline, unless submissions still await review.
+
+use super::estimation::estimate;
+use super::types::{
+    CampaignDetail, CampaignMeter, CampaignMeterStatus, CampaignSummary, CreateCampaignRequest,
+    ReminderResult,
+};
+use crate::auth::{AppError, AuthContext, get_user_building_ids};
+use crate::config::CampaignSchedule;
+use crate::db::DbPool;
+use crate::models::{
+    CampaignStatus, NotificationKind, ReadingCampaign, ReadingSource, SubmissionStatus,
+};
+use crate::notifications::notify;
+use crate::uploads::SCAN_CLEAN;
+use actix_web::{HttpResponse, web};
+use bigdecimal::BigDecimal;
+use chrono::{NaiveDateTime, NaiveTime};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Text, Unsigned};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use tracing::{info, warn};
+
+fn require_manager(auth: &AuthContext) -> Result<(), AppError> {
+    if auth.has_any_role(&["Admin", "Manager"]) {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden)
+    }
+}
+
+/// Fail unless the caller has access to the building.
+fn check_building_access(
+    building_id: u64,
+    auth: &AuthContext,
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    let is_admin = auth.has_any_role(&["Admin"]);
+    if let Some(ids) = get_user_building_ids(auth.user_id()?, is_admin, conn)?
+        && !ids.contains(&building_id)
+    {
+        return Err(AppError::Forbidden);
+    }
+    Ok(())
+}
+
+/// Load a campaign of one of the caller's buildings.
+fn load_campaign(
+    id: u64,
+    auth: &AuthContext,
+    conn: &mut MysqlConnection,
+) -> Result<ReadingCampaign, AppError> {
+    use crate::schema::meter_reading_campaigns::dsl as c;
+
+    let campaign: ReadingCampaign = c::meter_reading_campaigns
+        .filter(c::id.eq(id))
+        .select(ReadingCampaign::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+    check_building_access(campaign.building_id, auth, conn)?;
+    Ok(campaign)
+}
+
+/// First and last moment of the campaign window.
+fn window(campaign: &ReadingCampaign) -> (NaiveDateTime, NaiveDateTime) {
+    (
+        campaign.start_date.and_time(NaiveTime::MIN),
+        campaign
+            .deadline
+            .and_hms_opt(23, 59, 59)
+            .expect("valid time of day"),
+    )
+}
+
+/// Status of every active meter in the campaign's building, by apartment.
+fn meter_statuses(
+    campaign: &ReadingCampaign,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<CampaignMeter>, AppError> {
+    use crate::schema::apartments::dsl as apt;
+    use crate::schema::meter_reading_submissions::dsl as s;
+    use crate::schema::meter_readings::dsl as mr;
+    use crate::schema::meters::dsl as m;
+
+    let (from, to) = window(campaign);
+    let meters: Vec<(u64, String, String, u64, String)> = m::meters
+        .inner_join(apt::apartments.on(apt::id.eq(m::apartment_id)))
+        .filter(apt::building_id.eq(campaign.building_id))
+        .filter(apt::is_deleted.eq(false))
+        .filter(m::is_active.eq(true))
+        .order((apt::number.asc(), m::id.asc()))
+        .select((
+            m::id,
+            m::meter_type,
+            m::serial_number,
+            m::apartment_id,
+            apt::number,
+        ))
+        .load(conn)?;
+    let meter_ids: Vec<u64> = meters.iter().map(|(id, ..)| *id).collect();
+
+    // Ascending, so the latest reading of each meter in the window wins
+    let readings: Vec<(u64, BigDecimal, NaiveDateTime, String, String)> = mr::meter_readings
+        .filter(mr::meter_id.eq_any(&meter_ids))
+        .filter(mr::reading_timestamp.between(from, to))
+        .order(mr::reading_timestamp.asc())
+        .select((
+            mr::meter_id,
+            mr::reading_value,
+            mr::reading_timestamp,
+            mr::unit,
+            mr::source,
+        ))
+        .load(conn)?;
+    let latest: HashMap<u64, (BigDecimal, NaiveDateTime, String, String)> = readings
+        .into_iter()
+        .map(|(meter_id, value, ts, unit, source)| (meter_id, (value, ts, unit, source)))
+        .collect();
+    let submitted: HashSet<u64> = s::meter_reading_submissions
+        .filter(s::meter_id.eq_any(&meter_ids))
+        .filter(s::status.eq(SubmissionStatus::Pending.to_string()))
+        .filter(s::scan_status.eq(SCAN_CLEAN))
+        .filter(s::reading_timestamp.between(from, to))
+        .select(s::meter_id)
+        .load::<u64>(conn)?
+        .into_iter()
+        .collect();
+
+    Ok(meters
+        .into_iter()
+        .map(
+            |(meter_id, meter_type, serial_number, apartment_id, apartment_number)| {
+                let reading = latest.get(&meter_id);
+                let status = match reading {
+                    Some((.., source)) if *source == ReadingSource::Estimated.to_string() => {
+                        CampaignMeterStatus::Estimated
+                    }
+                    Some(_) => CampaignMeterStatus::Read,
+                    None if submitted.contains(&meter_id) => CampaignMeterStatus::Submitted,
+                    None => CampaignMeterStatus::Missing,
+                };
+                CampaignMeter {
+                    meter_id,
+                    meter_type,
+                    serial_number,
+                    apartment_id,
+                    apartment_number,
+                    status,
+                    reading_value: reading.map(|(value, ..)| value.to_string()),
+                    reading_timestamp: reading.map(|(_, ts, ..)| *ts),
+                    unit: reading.map(|(_, _, unit, _)| unit.clone()),
+                }
+            },
+        )
+        .collect())
+}
+
+fn summarize(
+    campaign: ReadingCampaign,
+    meters: &[CampaignMeter],
+    conn: &mut MysqlConnection,
+) -> Result<CampaignSummary, AppError> {
+    use crate::schema::buildings::dsl as b;
+
+    let building_address: Option<String> = b::buildings
+        .filter(b::id.eq(campaign.building_id))
+        .select(b::address)
+        .first(conn)
+        .optional()?;
+    let count = |status| meters.iter().filter(|m| m.status == status).count();
+    Ok(CampaignSummary {
+        campaign,
+        building_address,
+        total_meters: meters.len(),
+        read: count(CampaignMeterStatus::Read),
+        submitted: count(CampaignMeterStatus::Submitted),
+        estimated: count(CampaignMeterStatus::Estimated),
+        missing: count(CampaignMeterStatus::Missing),
+    })
+}
+
+/// Meter counts of one campaign, as [`meter_statuses`] would report them
+#[derive(QueryableByName, Default)]
+struct CampaignCounts {
+    #[diesel(sql_type = Unsigned<BigInt>)]
+    campaign_id: u64,
+    #[diesel(sql_type = BigInt)]
+    total_meters: i64,
+    #[diesel(sql_type = BigInt)]
+    read_meters: i64,
+    #[diesel(sql_type = BigInt)]
+    submitted_meters: i64,
+    #[diesel(sql_type = BigInt)]
+    estimated_meters: i64,
+    #[diesel(sql_type = BigInt)]
+    missing_meters: i64,
+}
+
+/// Counts of every given campaign in one grouped query: each active meter's latest
+/// reading in the window decides between read and estimated, a pending clean
+/// submission makes an unread meter submitted. Campaigns without meters are left out.
+fn campaign_counts(
+    campaign_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<HashMap<u64, CampaignCounts>, AppError> {
+    if campaign_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    // Ids come from the database, so listing them inline is safe
+    let ids = campaign_ids
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let query = format!(
+        "SELECT campaign_id, \
+           COUNT(*) AS total_meters, \
+           CAST(COALESCE(SUM(source IS NOT NULL AND source <> ?), 0) AS SIGNED) AS read_meters, \
+           CAST(COALESCE(SUM(source IS NULL AND submitted), 0) AS SIGNED) AS submitted_meters, \
+           CAST(COALESCE(SUM(source = ?), 0) AS SIGNED) AS estimated_meters, \
+           CAST(COALESCE(SUM(source IS NULL AND NOT submitted), 0) AS SIGNED) AS missing_meters \
+         FROM ( \
+           SELECT c.id AS campaign_id, \
+             (SELECT r.source FROM meter_readings r \
+               WHERE r.meter_id = m.id \
+                 AND r.reading_timestamp BETWEEN c.start_date AND TIMESTAMP(c.deadline, '23:59:59') \
+               ORDER BY r.reading_timestamp DESC LIMIT 1) AS source, \
+             EXISTS (SELECT 1 FROM meter_reading_submissions s \
+               WHERE s.meter_id = m.id AND s.status = ? AND s.scan_status = ? \
+                 AND s.reading_timestamp BETWEEN c.start_date AND TIMESTAMP(c.deadline, '23:59:59')) \
+               AS submitted \
+           FROM meter_reading_campaigns c \
+           JOIN apartments a ON a.building_id = c.building_id AND a.is_deleted = FALSE \
+           JOIN meters m ON m.apartment_id = a.id AND m.is_active = TRUE \
+           WHERE c.id IN ({}) \
+         ) AS campaign_meters \
+         GROUP BY campaign_id",
+        ids
+    );
+    let estimated = ReadingSource::Estimated.to_string();
+    let counts: Vec<CampaignCounts> = diesel::sql_query(query)
+        .bind::<Text, _>(&estimated)
+        .bind::<Text, _>(&estimated)
+        .bind::<Text, _>(SubmissionStatus::Pending.to_string())
+        .bind::<Text, _>(SCAN_CLEAN)
+        .load(conn)?;
+    Ok(counts.into_iter().map(|c| (c.campaign_id, c)).collect())
+}
+
+fn detail(
+    campaign: ReadingCampaign,
+    conn: &mut MysqlConnection,
+) -> Result<CampaignDetail, AppError> {
+    let meters = meter_statuses(&campaign, conn)?;
+    Ok(CampaignDetail {
+        summary: summarize(campaign, &meters, conn)?,
+        meters,
+    })
+}
+
+/// Notify the residents of apartments with a missing reading: owners always, active
+/// renters when a missing meter is visible to renters. Returns how many users were
+/// notified.
+fn remind_residents(
+    campaign: &ReadingCampaign,
+    meters: &[CampaignMeter],
+    conn: &mut MysqlConnection,
+) -> Result<usize, AppError> {
+    use crate::schema::apartment_owners::dsl as ao;
+    use crate::schema::apartment_renters::dsl as ar;
+    use crate::schema::meter_reading_campaigns::dsl as c;
+    use crate::schema::meters::dsl as m;
+
+    let missing: Vec<u64> = meters
+        .iter()
+        .filter(|m| m.status == CampaignMeterStatus::Missing)
+        .map(|m| m.meter_id)
+        .collect();
+    let apartments: Vec<(u64, bool)> = m::meters
+        .filter(m::id.eq_any(&missing))
+        .select((m::apartment_id, m::is_visible_to_renters))
+        .load(conn)?;
+
+    let mut users: BTreeSet<u64> = BTreeSet::new();
+    let all: Vec<u64> = apartments.iter().map(|(id, _)| *id).collect();
+    users.extend(
+        ao::apartment_owners
+            .filter(ao::apartment_id.eq_any(&all))
+            .select(ao::user_id)
+            .load::<u64>(conn)?,
+    );
+    let visible: Vec<u64> = apartments
+        .iter()
+        .filter(|(_, visible)| *visible)
+        .map(|(id, _)| *id)
+        .collect();
+    users.extend(
+        ar::apartment_renters
+            .filter(ar::apartment_id.eq_any(&visible))
+            .filter(ar::is_active.eq(true))
+            .select(ar::user_id)
+            .load::<u64>(conn)?,
+    );
+
+    // One notification per user, linking to the first apartment they need to read
+    for user_id in &users {
+        let owned: Vec<u64> = ao::apartment_owners
+            .filter(ao::user_id.eq(*user_id))
+            .filter(ao::apartment_id.eq_any(&all))
+            .select(ao::apartment_id)
+            .load(conn)?;
+        let apartment_id = match owned.first() {
+            Some(id) => *id,
+            None => ar::apartment_renters
+                .filter(ar::user_id.eq(*user_id))
+                .filter(ar::apartment_id.eq_any(&visible))
+                .filter(ar::is_active.eq(true))
+                .select(ar::apartment_id)
+                .first(conn)?,
+        };
+        notify(
+            &[*user_id],
+            NotificationKind::ReadingCampaignReminder,
+            &campaign.name,
+            Some(&format!("/apartments/{}/meters", apartment_id)),
+            conn,
+        )?;
+    }
+
+    diesel::update(c::meter_reading_campaigns.filter(c::id.eq(campaign.id)))
+        .set(c::last_reminded_at.eq(Some(chrono::Utc::now().naive_utc())))
+        .execute(conn)?;
+    Ok(users.len())
+}
+
+/// List reading campaigns
+///
+/// Returns the campaigns of the caller's buildings, newest first, with how many
+/// meters have been read, submitted, estimated or are still missing. Optionally
+/// filtered by building. Requires Admin or Manager.
+#[utoipa::path(
+    get,
+    path = "/api/v1/reading-campaigns",
+    params(
+        ("building_id" = Option<u64>, Query, description = "Only campaigns of this building")
+    ),
+    responses(
+        (status = 200, description = "Reading campaigns", body = Vec<CampaignSummary>),
+        (status = 403, description = "Forbidden - requires Admin or Manager"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_campaigns(
+    auth: AuthContext,
+    query: web::Query<HashMap<String, String>>,
+    pool: web::Data<DbPool>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::buildings::dsl as b;
+    use crate::schema::meter_reading_campaigns::dsl as c;
+
+    require_manager(&auth)?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let is_admin = auth.has_any_role(&["Admin"]);
+    let building_ids = get_user_building_ids(auth.user_id()?, is_admin, &mut conn)?;
+
+    let mut campaigns_query = c::meter_reading_campaigns
+        .left_join(b::buildings.on(b::id.eq(c::building_id)))
+        .into_boxed();
+    if let Some(ref ids) = building_ids {
+        campaigns_query = campaigns_query.filter(c::building_id.eq_any(ids));
+    }
+    if let Some(building_id) = query.get("building_id") {
+        let building_id: u64 = building_id
+            .parse()
+            .map_err(|_| AppError::BadRequest("Invalid building_id".into()))?;
+        campaigns_query = campaigns_query.filter(c::building_id.eq(building_id));
+    }
+    let campaigns: Vec<(ReadingCampaign, Option<String>)> = campaigns_query
+        .order((c::created_at.desc(), c::id.desc()))
+        .select((ReadingCampaign::as_select(), b::address.nullable()))
+        .load(&mut conn)?;
+
+    let ids: Vec<u64> = campaigns.iter().map(|(campaign, _)| campaign.id).collect();
+    let mut counts = campaign_counts(&ids, &mut conn)?;
+    let result: Vec<CampaignSummary> = campaigns
+        .into_iter()
+        .map(|(campaign, building_address)| {
+            let counts = counts.remove(&campaign.id).unwrap_or_default();
+            CampaignSummary {
+                campaign,
+                building_address,
+                total_meters: counts.total_meters as usize,
+                read: counts.read_meters as usize,
+                submitted: counts.submitted_meters as usize,
+                estimated: counts.estimated_meters as usize,
+                missing: counts.missing_meters as usize,
+            }
+        })
+        .collect();
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Start a reading campaign
+///
+/// Opens a campaign collecting a reading of every active meter in the building
+/// between the start date and the deadline. If the window has already begun, the
+/// residents of apartments missing a reading are notified right away. Requires Admin
+/// or Manager of the building.
+#[utoipa::path(
+    post,
+    path = "/api/v1/reading-campaigns",
+    request_body = CreateCampaignRequest,
+    responses(
+        (status = 201, description = "Campaign started", body = CampaignDetail),
+        (status = 400, description = "Bad request - missing name or deadline before the start date"),
+        (status = 403, description = "Forbidden - requires Admin or Manager of the building"),
+        (status = 404, description = "Building not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_campaign(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+    payload: web::Json<CreateCampaignRequest>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::buildings::dsl as b;
+    use crate::schema::meter_reading_campaigns::dsl as c;
+
+    require_manager(&auth)?;
+    let user_id = auth.user_id()?;
+    let name = payload.name.trim();
+    if name.is_empty() || name.len() > 255 {
+        return Err(AppError::BadRequest(
+            "Name must be between 1 and 255 characters".into(),
+        ));
+    }
+    if payload.deadline < payload.start_date {
+        return Err(AppError::BadRequest(
+            "Deadline cannot be before the start date".into(),
+        ));
+    }
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    b::buildings
+        .filter(b::id.eq(payload.building_id))
+        .filter(b::is_deleted.eq(false))
+        .select(b::id)
+        .first::<u64>(&mut conn)?;
+    check_building_access(payload.building_id, &auth, &mut conn)?;
+
+    diesel::insert_into(c::meter_reading_campaigns)
+        .values((
+            c::building_id.eq(payload.building_id),
+            c::name.eq(name),
+            c::start_date.eq(payload.start_date),
+            c::deadline.eq(payload.deadline),
+            c::status.eq(CampaignStatus::Open.to_string()),
+            c::created_by.eq(user_id),
+        ))
+        .execute(&mut conn)?;
+    let campaign_id: u64 = diesel::select(diesel::dsl::sql::<
+        diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+    >("LAST_INSERT_ID()"))
+    .first(&mut conn)?;
+
+    let campaign = load_campaign(campaign_id, &auth, &mut conn)?;
+    if campaign.start_date <= chrono::Utc::now().date_naive() {
+        let meters = meter_statuses(&campaign, &mut conn)?;
+        remind_residents(&campaign, &meters, &mut conn)?;
+    }
+    let campaign = load_campaign(campaign_id, &auth, &mut conn)?;
+    Ok(HttpResponse::Created().json(detail(campaign, &mut conn)?))
+}
+
+/// Get a reading campaign
+///
+/// Returns the campaign's progress with the status of every meter in the building:
+/// read in the window, submitted by a resident and awaiting review, estimated, or
+/// missing. Requires Admin or Manager of the building.
+#[utoipa::path(
+    get,
+    path = "/api/v1/reading-campaigns/{id}",
+    params(
+        ("id" = u64, Path, description = "Campaign ID")
+    ),
+    responses(
+        (status = 200, description = "Campaign with its meters", body = CampaignDetail),
+        (status = 403, description = "Forbidden - requires Admin or Manager of the building"),
+        (status = 404, description = "Campaign not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_campaign(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<HttpResponse, AppError> {
+    require_manager(&auth)?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let campaign = load_campaign(path.into_inner(), &auth, &mut conn)?;
+    Ok(HttpResponse::Ok().json(detail(campaign, &mut conn)?))
+}
+
+/// Remind residents of missing readings
+///
+/// Notifies the residents of every apartment whose meters still miss a reading in
+/// the campaign window. Only for open campaigns whose window has begun. Requires
+/// Admin or Manager of the building.
+#[utoipa::path(
+    post,
+    path = "/api/v1/reading-campaigns/{id}/remind",
+    params(
+        ("id" = u64, Path, description = "Campaign ID")
+    ),
+    responses(
+        (status = 200, description = "Residents reminded", body = ReminderResult),
+        (status = 400, description = "Bad request - campaign closed or not started yet"),
+        (status = 403, description = "Forbidden - requires Admin or Manager of the building"),
+        (status = 404, description = "Campaign not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn remind_campaign(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<HttpResponse, AppError> {
+    require_manager(&auth)?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let campaign = load_campaign(path.into_inner(), &auth, &mut conn)?;
+    if campaign.status != CampaignStatus::Open.to_string() {
+        return Err(AppError::BadRequest("The campaign is closed".into()));
+    }
+    if campaign.start_date > chrono::Utc::now().date_naive() {
+        return Err(AppError::BadRequest(
+            "The campaign has not started yet".into(),
+        ));
+    }
+    let meters = meter_statuses(&campaign, &mut conn)?;
+    let notified_users = remind_residents(&campaign, &meters, &mut conn)?;
+    Ok(HttpResponse::Ok().json(ReminderResult { notified_users }))
+}
+
+/// Close a reading campaign
+///
+/// Closes a campaign after its deadline. Every meter still missing a reading gets
+/// one estimated at the end of the deadline from its average consumption over the
+/// last year, recorded with source `Estimated`; meters without any earlier reading
+/// stay missing. Submissions in the window must be reviewed first. Requires Admin or
+/// Manager of the building.
+#[utoipa::path(
+    post,
+    path = "/api/v1/reading-campaigns/{id}/close",
+    params(
+        ("id" = u64, Path, description = "Campaign ID")
+    ),
+    responses(
+        (status = 200, description = "Campaign closed", body = CampaignDetail),
+        (status = 400, description = "Bad request - already closed, deadline not passed, or submissions awaiting review"),
+        (status = 403, description = "Forbidden - requires Admin or Manager of the building"),
+        (status = 404, description = "Campaign not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn close_campaign(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<HttpResponse, AppError> {
+    require_manager(&auth)?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let campaign = load_campaign(path.into_inner(), &auth, &mut conn)?;
+    if campaign.status != CampaignStatus::Open.to_string() {
+        return Err(AppError::BadRequest(
+            "The campaign is already closed".into(),
+        ));
+    }
+    if chrono::Utc::now().date_naive() <= campaign.deadline {
+        return Err(AppError::BadRequest(
+            "The campaign can be closed after its deadline".into(),
+        ));
+    }
+    let meters = meter_statuses(&campaign, &mut conn)?;
+    if meters
+        .iter()
+        .any(|m| m.status == CampaignMeterStatus::Submitted)
+    {
+        return Err(AppError::BadRequest(
+            "Submitted readings must be reviewed first".into(),
+        ));
+    }
+    close_with_estimates(&campaign, &meters, &mut conn)?;
+
+    let campaign = load_campaign(campaign.id, &auth, &mut conn)?;
+    Ok(HttpResponse::Ok().json(detail(campaign, &mut conn)?))
+}
+
+/// Estimate a reading at the end of the deadline for every missing meter with
+/// history, then mark the campaign closed.
+fn close_with_estimates(
+    campaign: &ReadingCampaign,
+    meters: &[CampaignMeter],
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use crate::schema::meter_reading_campaigns::dsl as c;
+    use crate::schema::meter_readings::dsl as mr;
+
+    let (_, end) = window(campaign);
+    let mut estimates = Vec::new();
+    for meter in meters
+        .iter()
+        .filter(|m| m.status == CampaignMeterStatus::Missing)
+    {
+        let history: Vec<(BigDecimal, NaiveDateTime, String)> = mr::meter_readings
+            .filter(mr::meter_id.eq(meter.meter_id))
+            .filter(mr::reading_timestamp.le(end))
+            .order(mr::reading_timestamp.asc())
+            .select((mr::reading_value, mr::reading_timestamp, mr::unit))
+            .load(conn)?;
+        let Some((.., unit)) = history.last() else {
+            continue;
+        };
+        let unit = unit.clone();
+        let values: Vec<(BigDecimal, NaiveDateTime)> = history
+            .into_iter()
+            .map(|(value, ts, _)| (value, ts))
+            .collect();
+        if let Some(value) = estimate(&values, end) {
+            estimates.push((meter.meter_id, value, unit));
+        }
+    }
+
+    conn.transaction::<_, AppError, _>(|conn| {
+        for (meter_id, value, unit) in &estimates {
+            diesel::insert_into(mr::meter_readings)
+                .values((
+                    mr::meter_id.eq(meter_id),
+                    mr::reading_value.eq(value),
+                    mr::reading_timestamp.eq(end),
+                    mr::unit.eq(unit),
+                    mr::source.eq(ReadingSource::Estimated.to_string()),
+                ))
+                .execute(conn)?;
+        }
+        diesel::update(c::meter_reading_campaigns.filter(c::id.eq(campaign.id)))
+            .set((
+                c::status.eq(CampaignStatus::Closed.to_string()),
+                c::closed_at.eq(Some(chrono::Utc::now().naive_utc())),
+            ))
+            .execute(conn)?;
+        Ok(())
+    })
+}
+
+/// What one pass of the campaign schedule did
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ScheduleRun {
+    /// Campaigns whose residents were reminded
+    pub reminded: usize,
+    pub closed: usize,
+    /// Campaigns past their deadline left open for submissions awaiting review
+    pub awaiting_review: usize,
+}
+
+/// Whether a started campaign is due for a reminder: never reminded, or last
+/// reminded at least `reminder_days` ago.
+fn reminder_due(campaign: &ReadingCampaign, reminder_days: i64, now: NaiveDateTime) -> bool {
+    campaign
+        .last_reminded_at
+        .is_none_or(|at| at <= now - chrono::Duration::days(reminder_days))
+}
+
+/// Remind and close open campaigns as their dates come: residents of a started
+/// campaign with missing readings are reminded every `reminder_days`, and a campaign
+/// past its deadline is closed with estimates once no submission awaits review.
+pub fn run_due_campaigns(
+    reminder_days: i64,
+    conn: &mut MysqlConnection,
+) -> Result<ScheduleRun, AppError> {
+    use crate::schema::meter_reading_campaigns::dsl as c;
+
+    let now = chrono::Utc::now().naive_utc();
+    let today = now.date();
+    let campaigns: Vec<ReadingCampaign> = c::meter_reading_campaigns
+        .filter(c::status.eq(CampaignStatus::Open.to_string()))
+        .filter(c::start_date.le(today))
+        .order(c::id.asc())
+        .select(ReadingCampaign::as_select())
+        .load(conn)?;
+
+    let mut run = ScheduleRun::default();
+    for campaign in campaigns {
+        if campaign.deadline < today {
+            let meters = meter_statuses(&campaign, conn)?;
+            if meters
+                .iter()
+                .any(|m| m.status == CampaignMeterStatus::Submitted)
+            {
+                run.awaiting_review += 1;
+                continue;
+            }
+            close_with_estimates(&campaign, &meters, conn)?;
+            run.closed += 1;
+        } else if reminder_due(&campaign, reminder_days, now) {
+            let meters = meter_statuses(&campaign, conn)?;
+            if meters
+                .iter()
+                .any(|m| m.status == CampaignMeterStatus::Missing)
+            {
+                remind_residents(&campaign, &meters, conn)?;
+                run.reminded += 1;
+            }
+        }
+    }
+    Ok(run)
+}
+
+/// Run [`run_due_campaigns`] every `schedule.interval` until the process exits.
+pub async fn run_schedule(schedule: CampaignSchedule, pool: DbPool) {
+    loop {
+        let pool = pool.clone();
+        let reminder_days = schedule.reminder_days;
+        let result = web::block(move || {
+            let mut conn = pool.get().map_err(|e| e.to_string())?;
+            run_due_campaigns(reminder_days, &mut conn).map_err(|e| e.to_string())
+        })
+        .await;
+        match result {
+            Ok(Ok(run)) if run != ScheduleRun::default() => info!(
+                reminded = run.reminded,
+                closed = run.closed,
+                awaiting_review = run.awaiting_review,
+                "Processed reading campaigns"
+            ),
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => warn!(error = %e, "Failed to process reading campaigns"),
+            Err(e) => warn!(error = %e, "Failed to process reading campaigns"),
+        }
+        actix_web::rt::time::sleep(schedule.interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn campaign(last_reminded_at: Option<NaiveDateTime>) -> ReadingCampaign {
+        ReadingCampaign {
+            id: 1,
+            building_id: 1,
+            name: "Spring".into(),
+            start_date: NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+            deadline: NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+            status: CampaignStatus::Open.to_string(),
+            created_by: 1,
+            last_reminded_at,
+            closed_at: None,
+            created_at: None,
+        }
+    }
+
+    fn at(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, day)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn reminders_repeat_after_the_configured_days() {
+        assert!(reminder_due(&campaign(None), 7, at(2)));
+        assert!(!reminder_due(&campaign(Some(at(2))), 7, at(8)));
+        assert!(reminder_due(&campaign(Some(at(2))), 7, at(9)));
+    }
+}
@@ -0,0 +1,95 @@
+//! Estimated readings for meters nobody read.
+//!
+//! The estimate extrapolates the meter's average consumption over the last year of
+//! its readings to the requested time. Consumption never goes negative: a meter whose
+//! value dropped (e.g. it was replaced) is assumed to have consumed nothing.
+
+use bigdecimal::BigDecimal;
+use chrono::{Duration, NaiveDateTime};
+
+/// Readings of the past year considered for the average consumption
+const HISTORY_DAYS: i64 = 365;
+
+/// Estimate a meter's value at `at` from its earlier readings, oldest first.
+/// When the last reading is the only one of its year, the one before it is used for
+/// the average. A single reading is carried over unchanged; without readings there
+/// is no estimate.
+pub(super) fn estimate(
+    history: &[(BigDecimal, NaiveDateTime)],
+    at: NaiveDateTime,
+) -> Option<BigDecimal> {
+    let (last_value, last_at) = history.last()?;
+    let since = *last_at - Duration::days(HISTORY_DAYS);
+    let first = history
+        .iter()
+        .position(|(_, ts)| *ts >= since)?
+        .min(history.len().saturating_sub(2));
+    let (first_value, first_at) = &history[first];
+
+    let span = (*last_at - *first_at).num_seconds();
+    let elapsed = (at - *last_at).num_seconds().max(0);
+    if span <= 0 || elapsed == 0 {
+        return Some(last_value.round(4));
+    }
+    let consumed = (last_value - first_value).max(BigDecimal::from(0));
+    let projected = consumed * BigDecimal::from(elapsed) / BigDecimal::from(span);
+    Some((last_value + projected).round(4))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn day(n: i64) -> NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            + Duration::days(n)
+    }
+
+    fn value(v: &str) -> BigDecimal {
+        BigDecimal::from_str(v).unwrap()
+    }
+
+    #[test]
+    fn extrapolates_average_consumption() {
+        let history = [(value("100"), day(0)), (value("200"), day(100))];
+        assert_eq!(estimate(&history, day(150)), Some(value("250.0000")));
+    }
+
+    #[test]
+    fn only_the_last_year_counts() {
+        // A lower consumption rate before the last year
+        let history = [
+            (value("0"), day(0)),
+            (value("300"), day(400)),
+            (value("500"), day(600)),
+        ];
+        assert_eq!(estimate(&history, day(700)), Some(value("600.0000")));
+    }
+
+    #[test]
+    fn falls_back_to_the_reading_before_the_last_year() {
+        let history = [(value("100"), day(0)), (value("500"), day(400))];
+        assert_eq!(estimate(&history, day(500)), Some(value("600.0000")));
+    }
+
+    #[test]
+    fn single_reading_is_carried_over() {
+        let history = [(value("42.5"), day(0))];
+        assert_eq!(estimate(&history, day(30)), Some(value("42.5000")));
+    }
+
+    #[test]
+    fn replaced_meter_consumes_nothing() {
+        let history = [(value("900"), day(0)), (value("10"), day(100))];
+        assert_eq!(estimate(&history, day(200)), Some(value("10.0000")));
+    }
+
+    #[test]
+    fn no_history_no_estimate() {
+        assert_eq!(estimate(&[], day(0)), None);
+    }
+}
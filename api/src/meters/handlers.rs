@@ -1,4 +1,4 @@
-use super::helpers::user_owns_apartment;
+use super::helpers::{user_can_access_meter, user_owns_apartment, user_rents_apartment};
 use super::types::{
     CreateMeterRequest, MeterWithApartment, MeterWithLastReading, UpdateMeterRequest,
 };
@@ -36,18 +36,27 @@ pub async fn list_apartment_meters(
         .get()
         .map_err(|_| AppError::Internal("db_pool".into()))?;
 
-    // Check access: Admin/Manager can see all, others only if they own the apartment
-    if !is_admin_or_manager && !user_owns_apartment(user_id, apartment_id, &mut conn)? {
-        return Err(AppError::Forbidden);
-    }
+    // Check access: Admin/Manager can see all, owners their apartment's meters and
+    // active renters only the meters visible to renters
+    let renter_only =
+        if is_admin_or_manager || user_owns_apartment(user_id, apartment_id, &mut conn)? {
+            false
+        } else if user_rents_apartment(user_id, apartment_id, &mut conn)? {
+            true
+        } else {
+            return Err(AppError::Forbidden);
+        };
 
     use crate::schema::meters::dsl as m;
 
-    let meters_list: Vec<Meter> = m::meters
+    let mut meters_query = m::meters
         .filter(m::apartment_id.eq(apartment_id))
         .filter(m::is_active.eq(true))
-        .select(Meter::as_select())
-        .load(&mut conn)?;
+        .into_boxed();
+    if renter_only {
+        meters_query = meters_query.filter(m::is_visible_to_renters.eq(true));
+    }
+    let meters_list: Vec<Meter> = meters_query.select(Meter::as_select()).load(&mut conn)?;
 
     // For each meter, get the last reading
     let mut result = Vec::new();
@@ -111,7 +120,7 @@ pub async fn get_meter(
         .first(&mut conn)?;
 
     // Check access
-    if !is_admin_or_manager && !user_owns_apartment(user_id, meter.apartment_id, &mut conn)? {
+    if !is_admin_or_manager && !user_can_access_meter(user_id, &meter, &mut conn)? {
         return Err(AppError::Forbidden);
     }
 
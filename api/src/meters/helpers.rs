@@ -1,4 +1,5 @@
 use crate::auth::AppError;
+use crate::models::Meter;
 use diesel::prelude::*;
 
 /// Check if a user owns a specific apartment
@@ -17,3 +18,34 @@ pub(super) fn user_owns_apartment(
 
     Ok(count > 0)
 }
+
+/// Check if a user is an active renter of a specific apartment
+pub(super) fn user_rents_apartment(
+    user_id: u64,
+    apartment_id: u64,
+    conn: &mut diesel::MysqlConnection,
+) -> Result<bool, AppError> {
+    use crate::schema::apartment_renters::dsl as ar;
+
+    let count: i64 = ar::apartment_renters
+        .filter(ar::apartment_id.eq(apartment_id))
+        .filter(ar::user_id.eq(user_id))
+        .filter(ar::is_active.eq(true))
+        .count()
+        .get_result(conn)?;
+
+    Ok(count > 0)
+}
+
+/// Check if a resident may see a meter and submit its readings: owners of its
+/// apartment always, active renters when the meter is visible to renters
+pub(super) fn user_can_access_meter(
+    user_id: u64,
+    meter: &Meter,
+    conn: &mut diesel::MysqlConnection,
+) -> Result<bool, AppError> {
+    if user_owns_apartment(user_id, meter.apartment_id, conn)? {
+        return Ok(true);
+    }
+    Ok(meter.is_visible_to_renters && user_rents_apartment(user_id, meter.apartment_id, conn)?)
+}
@@ -0,0 +1,41 @@
+use super::types::CsvImportRequest;
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::ingestion::{CsvParser, IngestSummary, MeterScope, ReadingParser, ingest};
+use crate::models::ReadingSource;
+use actix_web::{HttpResponse, Responder, web};
+
+/// Import readings from a vendor's CSV export
+///
+/// Rows are matched to meters by serial number; readings a meter already has at the
+/// same time are skipped, so an export can be imported again safely.
+#[utoipa::path(
+    post,
+    path = "/api/v1/meters/import/csv",
+    request_body = CsvImportRequest,
+    responses(
+        (status = 200, description = "Import summary", body = IngestSummary),
+        (status = 400, description = "Invalid file or mapping"),
+        (status = 403, description = "Forbidden")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn import_readings_csv(
+    auth: AuthContext,
+    payload: web::Json<CsvImportRequest>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    if !auth.has_any_role(&["Admin"]) {
+        return Err(AppError::Forbidden);
+    }
+    let CsvImportRequest { mapping, content } = payload.into_inner();
+    let records = CsvParser::new(mapping).parse(content.as_bytes())?;
+
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let summary = ingest(&records, ReadingSource::Import, &MeterScope::All, &mut conn)?;
+
+    Ok(HttpResponse::Ok().json(summary))
+}
@@ -3,15 +3,22 @@
 // This module handles all meter-related functionality including:
 // - Meter registration and management (handlers.rs)
 // - Meter readings and exports (readings.rs)
+// - Resident reading submissions with photo proof and manager review (submissions.rs)
+// - Periodic reading campaigns per building, with estimates for missed meters (campaigns.rs, estimation.rs)
 // - Calibration tracking (calibration.rs)
-// - Webhook integration for automated data ingestion (webhooks.rs)
-// - API key management for webhook authentication (api_keys.rs)
+// - Imports of vendor CSV exports (imports.rs), parsed by crate::ingestion
+// - Webhook integration for automated data ingestion, JSON and wireless M-Bus (webhooks.rs)
+// - API key management for webhook authentication: scope, expiry, rotation, rate limits (api_keys.rs)
 
 mod api_keys;
 mod calibration;
+mod campaigns;
+mod estimation;
 mod handlers;
 mod helpers;
+mod imports;
 mod readings;
+mod submissions;
 mod types;
 mod webhooks;
 
@@ -27,11 +34,27 @@ pub use handlers::{
 
 pub use readings::{create_reading, export_readings_csv, list_readings};
 
+pub use submissions::{
+    approve_submission, download_submission_photo, download_submission_thumbnail,
+    list_meter_submissions, list_submissions, photo_key, photo_thumbnail_key, reject_submission,
+    submit_reading,
+};
+
+pub use campaigns::{
+    ScheduleRun, close_campaign, create_campaign, get_campaign, list_campaigns, remind_campaign,
+    run_due_campaigns, run_schedule as run_campaign_schedule,
+};
+
 pub use calibration::{calibrate_meter, list_calibration_due};
 
-pub use webhooks::{webhook_meter_reading, webhook_meter_reading_batch};
+pub use imports::import_readings_csv;
+
+pub use webhooks::{webhook_meter_reading, webhook_meter_reading_batch, webhook_wmbus};
+
+pub use api_keys::{create_api_key, list_api_keys, revoke_api_key, rotate_api_key, update_api_key};
 
-pub use api_keys::{create_api_key, list_api_keys, revoke_api_key};
+/// Largest CSV import request body
+const CSV_IMPORT_LIMIT: usize = 10 * 1024 * 1024;
 
 /// Configure routes for the meters module
 pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -48,6 +71,12 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             "/meters/calibration-due",
             web::get().to(list_calibration_due),
         )
+        // Vendor CSV exports can be large
+        .service(
+            web::resource("/meters/import/csv")
+                .app_data(web::JsonConfig::default().limit(CSV_IMPORT_LIMIT))
+                .route(web::post().to(import_readings_csv)),
+        )
         .route("/meters/{id}", web::get().to(get_meter))
         .route("/meters/{id}", web::put().to(update_meter))
         .route("/meters/{id}", web::delete().to(deactivate_meter))
@@ -58,6 +87,41 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         )
         .route("/meters/{id}/readings", web::get().to(list_readings))
         .route("/meters/{id}/readings", web::post().to(create_reading))
+        // Resident submissions and their review
+        .route(
+            "/meters/{id}/submissions",
+            web::get().to(list_meter_submissions),
+        )
+        .route("/meters/{id}/submissions", web::post().to(submit_reading))
+        .route(
+            "/meters/{id}/submissions/{submission_id}/photo",
+            web::get().to(download_submission_photo),
+        )
+        .route(
+            "/meters/{id}/submissions/{submission_id}/thumbnail",
+            web::get().to(download_submission_thumbnail),
+        )
+        .route("/meter-submissions", web::get().to(list_submissions))
+        .route(
+            "/meter-submissions/{id}/approve",
+            web::post().to(approve_submission),
+        )
+        .route(
+            "/meter-submissions/{id}/reject",
+            web::post().to(reject_submission),
+        )
+        // Reading campaigns
+        .route("/reading-campaigns", web::get().to(list_campaigns))
+        .route("/reading-campaigns", web::post().to(create_campaign))
+        .route("/reading-campaigns/{id}", web::get().to(get_campaign))
+        .route(
+            "/reading-campaigns/{id}/remind",
+            web::post().to(remind_campaign),
+        )
+        .route(
+            "/reading-campaigns/{id}/close",
+            web::post().to(close_campaign),
+        )
         // Calibration
         .route("/meters/{id}/calibrate", web::post().to(calibrate_meter))
         // Webhooks (no authentication required, uses API key)
@@ -69,8 +133,14 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             "/webhooks/meter-reading-batch",
             web::post().to(webhook_meter_reading_batch),
         )
+        .route("/webhooks/wmbus", web::post().to(webhook_wmbus))
         // API key management (Admin only)
         .route("/admin/api-keys", web::get().to(list_api_keys))
         .route("/admin/api-keys", web::post().to(create_api_key))
-        .route("/admin/api-keys/{id}", web::delete().to(revoke_api_key));
+        .route("/admin/api-keys/{id}", web::put().to(update_api_key))
+        .route("/admin/api-keys/{id}", web::delete().to(revoke_api_key))
+        .route(
+            "/admin/api-keys/{id}/rotate",
+            web::post().to(rotate_api_key),
+        );
 }
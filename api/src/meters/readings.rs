@@ -1,4 +1,4 @@
-use super::helpers::user_owns_apartment;
+use super::helpers::user_can_access_meter;
 use super::types::CreateReadingRequest;
 use crate::auth::{AppError, AuthContext};
 use crate::db::DbPool;
@@ -47,7 +47,7 @@ pub async fn list_readings(
         .select(Meter::as_select())
         .first(&mut conn)?;
 
-    if !is_admin_or_manager && !user_owns_apartment(user_id, meter.apartment_id, &mut conn)? {
+    if !is_admin_or_manager && !user_can_access_meter(user_id, &meter, &mut conn)? {
         return Err(AppError::Forbidden);
     }
 
@@ -203,7 +203,7 @@ pub async fn export_readings_csv(
         .select(Meter::as_select())
         .first(&mut conn)?;
 
-    if !is_admin_or_manager && !user_owns_apartment(user_id, meter.apartment_id, &mut conn)? {
+    if !is_admin_or_manager && !user_can_access_meter(user_id, &meter, &mut conn)? {
         return Err(AppError::Forbidden);
     }
 
@@ -0,0 +1,715 @@
+//! Meter readings submitted by residents.
+//!
+//! Owners, and active renters of meters visible to renters, send in their reading with
+//! a photo of the meter dial. The photo goes through the shared [`crate::uploads`]
+//! pipeline; the submission then waits for a manager, who compares it with the
+//! previous reading and the photo. Only an approved submission becomes a `Manual`
+//! reading, so consumption figures never include unreviewed values.
+
+use super::helpers::user_can_access_meter;
+use super::types::{RejectSubmissionRequest, SubmissionWithMeter};
+use crate::auth::{AppError, AuthContext};
+use crate::config::AppConfig;
+use crate::db::DbPool;
+use crate::models::{
+    Meter, MeterReadingSubmission, NotificationKind, ReadingSource, SubmissionStatus,
+};
+use crate::notifications::notify;
+use crate::scanning::Scanner;
+use crate::storage::Storage;
+use crate::uploads::{
+    self, SCAN_CLEAN, SCAN_INFECTED, SCAN_PENDING, ScanOutcome, UploadKeys, images,
+};
+use actix_multipart::Multipart;
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::{HttpRequest, HttpResponse, mime, web};
+use bigdecimal::{BigDecimal, Signed};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::HashMap;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Storage key of a submission photo.
+pub fn photo_key(meter_id: u64, stored_filename: &str) -> String {
+    format!("meters/{}/submissions/{}", meter_id, stored_filename)
+}
+
+/// Storage key of a submission photo's thumbnail.
+pub fn photo_thumbnail_key(meter_id: u64, stored_filename: &str) -> String {
+    format!(
+        "meters/{}/submissions/{}{}",
+        meter_id,
+        stored_filename,
+        images::THUMBNAIL_SUFFIX
+    )
+}
+
+/// Storage key of a submission photo held in quarantine until it has been scanned.
+pub fn quarantine_key(meter_id: u64, stored_filename: &str) -> String {
+    format!(
+        "quarantine/meters/{}/submissions/{}",
+        meter_id, stored_filename
+    )
+}
+
+fn load_meter(meter_id: u64, conn: &mut MysqlConnection) -> Result<Meter, AppError> {
+    use crate::schema::meters::dsl as m;
+
+    Ok(m::meters
+        .filter(m::id.eq(meter_id))
+        .filter(m::is_active.eq(true))
+        .select(Meter::as_select())
+        .first(conn)?)
+}
+
+fn parse_timestamp(value: &str) -> Result<chrono::NaiveDateTime, AppError> {
+    ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M"]
+        .iter()
+        .find_map(|format| chrono::NaiveDateTime::parse_from_str(value, format).ok())
+        .ok_or_else(|| AppError::BadRequest("Invalid timestamp format".into()))
+}
+
+/// The last counted reading before `timestamp`, after checking that `value` can
+/// follow it: meters only count up, and a meter has one reading per timestamp.
+fn check_against_previous(
+    meter_id: u64,
+    value: &BigDecimal,
+    timestamp: chrono::NaiveDateTime,
+    conn: &mut MysqlConnection,
+) -> Result<Option<(BigDecimal, chrono::NaiveDateTime)>, AppError> {
+    use crate::schema::meter_readings::dsl as mr;
+
+    let same_time: i64 = mr::meter_readings
+        .filter(mr::meter_id.eq(meter_id))
+        .filter(mr::reading_timestamp.eq(timestamp))
+        .count()
+        .get_result(conn)?;
+    if same_time > 0 {
+        return Err(AppError::BadRequest(
+            "The meter already has a reading at this time".into(),
+        ));
+    }
+
+    let previous: Option<(BigDecimal, chrono::NaiveDateTime)> = mr::meter_readings
+        .filter(mr::meter_id.eq(meter_id))
+        .filter(mr::reading_timestamp.lt(timestamp))
+        .order(mr::reading_timestamp.desc())
+        .select((mr::reading_value, mr::reading_timestamp))
+        .first(conn)
+        .optional()?;
+    if let Some((previous_value, _)) = &previous
+        && value < previous_value
+    {
+        return Err(AppError::BadRequest(format!(
+            "Reading is lower than the previous reading ({})",
+            previous_value
+        )));
+    }
+    Ok(previous)
+}
+
+/// Attach meter, apartment and submitter details to submissions.
+fn with_meter(
+    submissions: Vec<MeterReadingSubmission>,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<SubmissionWithMeter>, AppError> {
+    use crate::schema::apartments::dsl as apt;
+    use crate::schema::buildings::dsl as bld;
+    use crate::schema::meters::dsl as m;
+    use crate::schema::users::dsl as u;
+
+    type MeterDetails = (String, String, u64, Option<String>, Option<String>);
+
+    let meter_ids: Vec<u64> = submissions.iter().map(|s| s.meter_id).collect();
+    let submitter_ids: Vec<u64> = submissions.iter().map(|s| s.submitted_by).collect();
+    let meters: HashMap<u64, MeterDetails> = m::meters
+        .left_join(apt::apartments.on(apt::id.eq(m::apartment_id)))
+        .left_join(bld::buildings.on(bld::id.eq(apt::building_id)))
+        .filter(m::id.eq_any(&meter_ids))
+        .select((
+            m::id,
+            m::meter_type,
+            m::serial_number,
+            m::apartment_id,
+            apt::number.nullable(),
+            bld::address.nullable(),
+        ))
+        .load::<(u64, String, String, u64, Option<String>, Option<String>)>(conn)?
+        .into_iter()
+        .map(|(id, meter_type, serial, apartment_id, number, address)| {
+            (id, (meter_type, serial, apartment_id, number, address))
+        })
+        .collect();
+    let names: HashMap<u64, String> = u::users
+        .filter(u::id.eq_any(&submitter_ids))
+        .select((u::id, u::name))
+        .load::<(u64, String)>(conn)?
+        .into_iter()
+        .collect();
+
+    let mut result = Vec::with_capacity(submissions.len());
+    for submission in submissions {
+        let Some((meter_type, serial_number, apartment_id, apartment_number, building_address)) =
+            meters.get(&submission.meter_id).cloned()
+        else {
+            continue;
+        };
+        let consumption = submission
+            .previous_value
+            .as_ref()
+            .map(|previous| (&submission.reading_value - previous).to_string());
+        let submitted_by_name = names.get(&submission.submitted_by).cloned();
+
+        result.push(SubmissionWithMeter {
+            submission,
+            meter_type,
+            serial_number,
+            apartment_id,
+            apartment_number,
+            building_address,
+            submitted_by_name,
+            consumption,
+        });
+    }
+    Ok(result)
+}
+
+/// Submit a meter reading
+///
+/// Sends in a reading with a photo of the meter dial using multipart/form-data. The
+/// form fields `reading_value`, `unit` and optionally `timestamp` (default now) must
+/// come before the `file` field holding the photo. The reading may not be lower than
+/// the previous one, and a meter has at most one submission awaiting review. It
+/// counts only once a manager approves it. Available to owners of the apartment and
+/// to its active renters when the meter is visible to renters.
+#[utoipa::path(
+    post,
+    path = "/api/v1/meters/{id}/submissions",
+    params(
+        ("id" = u64, Path, description = "Meter ID")
+    ),
+    request_body(content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Reading submitted for review", body = MeterReadingSubmission),
+        (status = 400, description = "Bad request - missing or invalid value, lower than the previous reading, timestamp in the future, photo missing, or a submission already pending"),
+        (status = 403, description = "Forbidden - no access to the meter"),
+        (status = 404, description = "Meter not found"),
+        (status = 413, description = "File too large"),
+        (status = 422, description = "Malware detected - upload rejected"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn submit_reading(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+    cfg: web::Data<AppConfig>,
+    storage: web::Data<dyn Storage>,
+    scanner: web::Data<dyn Scanner>,
+    mut payload: Multipart,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::meter_reading_submissions::dsl as s;
+
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let meter = load_meter(path.into_inner(), &mut conn)?;
+    if !user_can_access_meter(user_id, &meter, &mut conn)? {
+        return Err(AppError::Forbidden);
+    }
+
+    let pending: i64 = s::meter_reading_submissions
+        .filter(s::meter_id.eq(meter.id))
+        .filter(s::status.eq(SubmissionStatus::Pending.to_string()))
+        .filter(s::scan_status.eq(SCAN_CLEAN))
+        .count()
+        .get_result(&mut conn)?;
+    if pending > 0 {
+        return Err(AppError::BadRequest(
+            "A reading for this meter is already awaiting review".into(),
+        ));
+    }
+
+    let upload = uploads::receive(&mut payload, &cfg).await?;
+    if !upload.mime.starts_with("image/") {
+        return Err(AppError::InvalidMimeType);
+    }
+    let reading_value = upload
+        .fields
+        .get("reading_value")
+        .and_then(|v| BigDecimal::from_str(v.trim()).ok())
+        .filter(|v| !v.is_negative())
+        .ok_or_else(|| AppError::BadRequest("Invalid reading value".into()))?;
+    let unit = upload
+        .fields
+        .get("unit")
+        .map(|u| u.trim().to_string())
+        .filter(|u| !u.is_empty() && u.len() <= 16)
+        .ok_or_else(|| AppError::BadRequest("Invalid unit".into()))?;
+    let now = chrono::Utc::now().naive_utc();
+    let reading_timestamp = match upload.fields.get("timestamp").filter(|t| !t.is_empty()) {
+        Some(ts) => parse_timestamp(ts)?,
+        None => now,
+    };
+    if reading_timestamp > now {
+        return Err(AppError::BadRequest(
+            "Reading time cannot be in the future".into(),
+        ));
+    }
+    let previous = check_against_previous(meter.id, &reading_value, reading_timestamp, &mut conn)?;
+    let (previous_value, previous_timestamp) = previous.unzip();
+
+    let stored_filename = Uuid::new_v4().to_string();
+    let upload_keys = UploadKeys {
+        quarantine: quarantine_key(meter.id, &stored_filename),
+        object: photo_key(meter.id, &stored_filename),
+        thumbnail: Some(photo_thumbnail_key(meter.id, &stored_filename)),
+    };
+    let submission_id = uploads::store_scanned(
+        upload,
+        &upload_keys,
+        storage.get_ref(),
+        scanner.get_ref(),
+        &mut conn,
+        |upload, conn| {
+            diesel::insert_into(s::meter_reading_submissions)
+                .values((
+                    s::meter_id.eq(meter.id),
+                    s::submitted_by.eq(user_id),
+                    s::reading_value.eq(&reading_value),
+                    s::reading_timestamp.eq(reading_timestamp),
+                    s::unit.eq(&unit),
+                    s::previous_value.eq(previous_value),
+                    s::previous_timestamp.eq(previous_timestamp),
+                    s::photo_original_filename.eq(&upload.original_filename),
+                    s::photo_stored_filename.eq(&stored_filename),
+                    s::photo_mime_type.eq(upload.mime),
+                    s::photo_size_bytes.eq(upload.data.len() as u64),
+                    s::scan_status.eq(SCAN_PENDING),
+                    s::status.eq(SubmissionStatus::Pending.to_string()),
+                ))
+                .execute(conn)
+        },
+        |submission_id, outcome, conn| {
+            let row = s::meter_reading_submissions.filter(s::id.eq(submission_id));
+            match outcome {
+                ScanOutcome::Clean(stored) => diesel::update(row)
+                    .set((
+                        s::photo_size_bytes.eq(stored.size_bytes),
+                        s::photo_checksum_sha256.eq(Some(stored.checksum_sha256)),
+                        s::photo_has_thumbnail.eq(stored.has_thumbnail),
+                        s::scan_status.eq(SCAN_CLEAN),
+                    ))
+                    .execute(conn),
+                ScanOutcome::Infected(_) => diesel::update(row)
+                    .set(s::scan_status.eq(SCAN_INFECTED))
+                    .execute(conn),
+                ScanOutcome::Discarded => diesel::delete(row).execute(conn),
+            }
+        },
+    )
+    .await?;
+
+    let row = s::meter_reading_submissions
+        .filter(s::id.eq(submission_id))
+        .select(MeterReadingSubmission::as_select())
+        .first(&mut conn)?;
+    Ok(HttpResponse::Created().json(row))
+}
+
+/// List readings submitted for a meter
+///
+/// Returns the meter's submissions with their review status, newest first.
+/// Available to residents with access to the meter and to Admin or Manager.
+#[utoipa::path(
+    get,
+    path = "/api/v1/meters/{id}/submissions",
+    params(
+        ("id" = u64, Path, description = "Meter ID")
+    ),
+    responses(
+        (status = 200, description = "Submitted readings", body = Vec<SubmissionWithMeter>),
+        (status = 403, description = "Forbidden - no access to the meter"),
+        (status = 404, description = "Meter not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_meter_submissions(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::meter_reading_submissions::dsl as s;
+
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let meter = load_meter(path.into_inner(), &mut conn)?;
+    if !auth.has_any_role(&["Admin", "Manager"])
+        && !user_can_access_meter(user_id, &meter, &mut conn)?
+    {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows: Vec<MeterReadingSubmission> = s::meter_reading_submissions
+        .filter(s::meter_id.eq(meter.id))
+        .filter(s::scan_status.eq(SCAN_CLEAN))
+        .order((s::created_at.desc(), s::id.desc()))
+        .select(MeterReadingSubmission::as_select())
+        .load(&mut conn)?;
+    Ok(HttpResponse::Ok().json(with_meter(rows, &mut conn)?))
+}
+
+/// List submitted readings for review
+///
+/// Returns submitted readings of all meters in the given status (default Pending),
+/// oldest first, with the previous reading and the resulting consumption. Requires
+/// Admin or Manager.
+#[utoipa::path(
+    get,
+    path = "/api/v1/meter-submissions",
+    params(
+        ("status" = Option<String>, Query, description = "Pending (default), Approved or Rejected")
+    ),
+    responses(
+        (status = 200, description = "Submitted readings", body = Vec<SubmissionWithMeter>),
+        (status = 400, description = "Bad request - unknown status"),
+        (status = 403, description = "Forbidden - requires Admin or Manager"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_submissions(
+    auth: AuthContext,
+    query: web::Query<HashMap<String, String>>,
+    pool: web::Data<DbPool>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::meter_reading_submissions::dsl as s;
+
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+    let status = match query.get("status") {
+        Some(status) => SubmissionStatus::from_str(status)
+            .map_err(|_| AppError::BadRequest("Unknown status".into()))?,
+        None => SubmissionStatus::Pending,
+    };
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let rows: Vec<MeterReadingSubmission> = s::meter_reading_submissions
+        .filter(s::status.eq(status.to_string()))
+        .filter(s::scan_status.eq(SCAN_CLEAN))
+        .order((s::created_at.asc(), s::id.asc()))
+        .select(MeterReadingSubmission::as_select())
+        .load(&mut conn)?;
+    Ok(HttpResponse::Ok().json(with_meter(rows, &mut conn)?))
+}
+
+/// Download the photo of a submitted reading
+///
+/// Streams the photo of the meter dial inline. Supports `Range` requests. Available
+/// to residents with access to the meter and to Admin or Manager.
+#[utoipa::path(
+    get,
+    path = "/api/v1/meters/{id}/submissions/{submission_id}/photo",
+    params(
+        ("id" = u64, Path, description = "Meter ID"),
+        ("submission_id" = u64, Path, description = "Submission ID")
+    ),
+    responses(
+        (status = 200, description = "Photo content", content_type = "image/jpeg"),
+        (status = 206, description = "Partial photo content for a Range request", content_type = "image/jpeg"),
+        (status = 403, description = "Forbidden - no access to the meter"),
+        (status = 404, description = "Meter or submission not found"),
+        (status = 416, description = "Requested range not satisfiable"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn download_submission_photo(
+    auth: AuthContext,
+    http_req: HttpRequest,
+    path: web::Path<(u64, u64)>,
+    pool: web::Data<DbPool>,
+    storage: web::Data<dyn Storage>,
+) -> Result<HttpResponse, AppError> {
+    let (meter_id, submission_id) = path.into_inner();
+    let item = load_viewable(&auth, meter_id, submission_id, &pool)?;
+
+    let mime_type: mime::Mime = item
+        .photo_mime_type
+        .parse()
+        .unwrap_or(mime::APPLICATION_OCTET_STREAM);
+    let disposition = ContentDisposition {
+        disposition: DispositionType::Inline,
+        parameters: vec![DispositionParam::Filename(item.photo_original_filename)],
+    };
+    uploads::serve(
+        &http_req,
+        storage.get_ref(),
+        &photo_key(meter_id, &item.photo_stored_filename),
+        item.photo_size_bytes,
+        mime_type,
+        disposition,
+    )
+    .await
+}
+
+/// Download the thumbnail of a submission photo
+///
+/// Returns a small JPEG preview of the photo. Same access rules as the photo.
+#[utoipa::path(
+    get,
+    path = "/api/v1/meters/{id}/submissions/{submission_id}/thumbnail",
+    params(
+        ("id" = u64, Path, description = "Meter ID"),
+        ("submission_id" = u64, Path, description = "Submission ID")
+    ),
+    responses(
+        (status = 200, description = "Thumbnail image", content_type = "image/jpeg"),
+        (status = 403, description = "Forbidden - no access to the meter"),
+        (status = 404, description = "Meter or submission not found, or the photo has no thumbnail"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn download_submission_thumbnail(
+    auth: AuthContext,
+    http_req: HttpRequest,
+    path: web::Path<(u64, u64)>,
+    pool: web::Data<DbPool>,
+    storage: web::Data<dyn Storage>,
+) -> Result<HttpResponse, AppError> {
+    let (meter_id, submission_id) = path.into_inner();
+    let item = load_viewable(&auth, meter_id, submission_id, &pool)?;
+    if !item.photo_has_thumbnail {
+        return Err(AppError::NotFound);
+    }
+    let key = photo_thumbnail_key(meter_id, &item.photo_stored_filename);
+    uploads::serve_thumbnail(&http_req, storage.get_ref(), &key).await
+}
+
+/// A released submission of a meter the caller may see
+fn load_viewable(
+    auth: &AuthContext,
+    meter_id: u64,
+    submission_id: u64,
+    pool: &DbPool,
+) -> Result<MeterReadingSubmission, AppError> {
+    use crate::schema::meter_reading_submissions::dsl as s;
+
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let meter = load_meter(meter_id, &mut conn)?;
+    if !auth.has_any_role(&["Admin", "Manager"])
+        && !user_can_access_meter(user_id, &meter, &mut conn)?
+    {
+        return Err(AppError::Forbidden);
+    }
+    s::meter_reading_submissions
+        .filter(s::id.eq(submission_id))
+        .filter(s::meter_id.eq(meter_id))
+        .filter(s::scan_status.eq(SCAN_CLEAN))
+        .select(MeterReadingSubmission::as_select())
+        .first(&mut conn)
+        .optional()?
+        .ok_or(AppError::NotFound)
+}
+
+/// A submission awaiting review, with its meter
+fn load_pending(
+    submission_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<(MeterReadingSubmission, Meter), AppError> {
+    use crate::schema::meter_reading_submissions::dsl as s;
+
+    let submission: MeterReadingSubmission = s::meter_reading_submissions
+        .filter(s::id.eq(submission_id))
+        .filter(s::scan_status.eq(SCAN_CLEAN))
+        .select(MeterReadingSubmission::as_select())
+        .first(conn)?;
+    if submission.status != SubmissionStatus::Pending.to_string() {
+        return Err(AppError::BadRequest(
+            "The reading has already been reviewed".into(),
+        ));
+    }
+    let meter = load_meter(submission.meter_id, conn)?;
+    Ok((submission, meter))
+}
+
+fn notify_submitter(
+    submission: &MeterReadingSubmission,
+    meter: &Meter,
+    kind: NotificationKind,
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    notify(
+        &[submission.submitted_by],
+        kind,
+        &meter.serial_number,
+        Some(&format!("/meters/{}", meter.id)),
+        conn,
+    )
+}
+
+/// Approve a submitted reading
+///
+/// Records the submitted value as a `Manual` reading of the meter and notifies the
+/// resident. The value is checked again against the previous reading, which may have
+/// changed since it was submitted. Requires Admin or Manager.
+#[utoipa::path(
+    post,
+    path = "/api/v1/meter-submissions/{id}/approve",
+    params(
+        ("id" = u64, Path, description = "Submission ID")
+    ),
+    responses(
+        (status = 200, description = "Reading approved", body = MeterReadingSubmission),
+        (status = 400, description = "Bad request - already reviewed, or lower than the previous reading"),
+        (status = 403, description = "Forbidden - requires Admin or Manager"),
+        (status = 404, description = "Submission not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn approve_submission(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::meter_reading_submissions::dsl as s;
+    use crate::schema::meter_readings::dsl as mr;
+
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+    let reviewer_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let (submission, meter) = load_pending(path.into_inner(), &mut conn)?;
+    check_against_previous(
+        meter.id,
+        &submission.reading_value,
+        submission.reading_timestamp,
+        &mut conn,
+    )?;
+
+    conn.transaction::<_, AppError, _>(|conn| {
+        diesel::insert_into(mr::meter_readings)
+            .values((
+                mr::meter_id.eq(meter.id),
+                mr::reading_value.eq(&submission.reading_value),
+                mr::reading_timestamp.eq(submission.reading_timestamp),
+                mr::unit.eq(&submission.unit),
+                mr::source.eq(ReadingSource::Manual.to_string()),
+            ))
+            .execute(conn)?;
+        let reading_id: u64 = diesel::select(diesel::dsl::sql::<
+            diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+        >("LAST_INSERT_ID()"))
+        .first(conn)?;
+        diesel::update(s::meter_reading_submissions.filter(s::id.eq(submission.id)))
+            .set((
+                s::status.eq(SubmissionStatus::Approved.to_string()),
+                s::reading_id.eq(Some(reading_id)),
+                s::reviewed_by.eq(Some(reviewer_id)),
+                s::reviewed_at.eq(Some(chrono::Utc::now().naive_utc())),
+            ))
+            .execute(conn)?;
+        Ok(())
+    })?;
+    notify_submitter(
+        &submission,
+        &meter,
+        NotificationKind::MeterReadingApproved,
+        &mut conn,
+    )?;
+
+    let row = s::meter_reading_submissions
+        .filter(s::id.eq(submission.id))
+        .select(MeterReadingSubmission::as_select())
+        .first(&mut conn)?;
+    Ok(HttpResponse::Ok().json(row))
+}
+
+/// Reject a submitted reading
+///
+/// Rejects the reading with a reason shown to the resident, who is notified and may
+/// submit a new one. Requires Admin or Manager.
+#[utoipa::path(
+    post,
+    path = "/api/v1/meter-submissions/{id}/reject",
+    params(
+        ("id" = u64, Path, description = "Submission ID")
+    ),
+    request_body = RejectSubmissionRequest,
+    responses(
+        (status = 200, description = "Reading rejected", body = MeterReadingSubmission),
+        (status = 400, description = "Bad request - already reviewed, or reason missing or too long"),
+        (status = 403, description = "Forbidden - requires Admin or Manager"),
+        (status = 404, description = "Submission not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Meters",
+    security(("bearer_auth" = []))
+)]
+pub async fn reject_submission(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    payload: web::Json<RejectSubmissionRequest>,
+    pool: web::Data<DbPool>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::meter_reading_submissions::dsl as s;
+
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+    let reason = payload.reason.trim();
+    if reason.is_empty() || reason.chars().count() > 500 {
+        return Err(AppError::BadRequest(
+            "A reason of up to 500 characters is required".into(),
+        ));
+    }
+    let reviewer_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let (submission, meter) = load_pending(path.into_inner(), &mut conn)?;
+
+    diesel::update(s::meter_reading_submissions.filter(s::id.eq(submission.id)))
+        .set((
+            s::status.eq(SubmissionStatus::Rejected.to_string()),
+            s::review_note.eq(Some(reason)),
+            s::reviewed_by.eq(Some(reviewer_id)),
+            s::reviewed_at.eq(Some(chrono::Utc::now().naive_utc())),
+        ))
+        .execute(&mut conn)?;
+    notify_submitter(
+        &submission,
+        &meter,
+        NotificationKind::MeterReadingRejected,
+        &mut conn,
+    )?;
+
+    let row = s::meter_reading_submissions
+        .filter(s::id.eq(submission.id))
+        .select(MeterReadingSubmission::as_select())
+        .first(&mut conn)?;
+    Ok(HttpResponse::Ok().json(row))
+}
@@ -1,4 +1,5 @@
-use crate::models::Meter;
+use crate::ingestion::CsvMapping;
+use crate::models::{Meter, MeterReadingSubmission, ReadingCampaign, WebhookApiKey};
 use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -94,12 +95,155 @@ pub struct WebhookBatchPayload {
 #[derive(Deserialize, ToSchema)]
 pub struct CreateApiKeyRequest {
     pub name: String,
+    /// The key stops working at this time (UTC)
+    #[serde(default)]
+    pub expires_at: Option<chrono::NaiveDateTime>,
+    /// Requests allowed per minute; unlimited when empty
+    #[serde(default)]
+    pub rate_limit_per_minute: Option<u32>,
+    /// Buildings whose meters the key may write readings for
+    #[serde(default)]
+    pub building_ids: Vec<u64>,
+    /// Further meters the key may write readings for. Without buildings and meters the
+    /// key may write readings for every meter.
+    #[serde(default)]
+    pub meter_ids: Vec<u64>,
 }
 
-/// Response containing API key (only returned on creation)
+/// Request to change a webhook API key's name, expiry, rate limit and scope
+#[derive(Deserialize, ToSchema)]
+pub struct UpdateApiKeyRequest {
+    pub name: String,
+    #[serde(default)]
+    pub expires_at: Option<chrono::NaiveDateTime>,
+    #[serde(default)]
+    pub rate_limit_per_minute: Option<u32>,
+    #[serde(default)]
+    pub building_ids: Vec<u64>,
+    #[serde(default)]
+    pub meter_ids: Vec<u64>,
+}
+
+/// Request to rotate a webhook API key
+#[derive(Deserialize, ToSchema)]
+pub struct RotateApiKeyRequest {
+    /// Hours the old key keeps working next to the new one (default 24, at most 720)
+    #[serde(default)]
+    pub overlap_hours: Option<u32>,
+}
+
+/// Response containing API key (only returned on creation and rotation)
 #[derive(Serialize, ToSchema)]
 pub struct ApiKeyResponse {
     pub id: u64,
     pub name: String,
     pub api_key: String,
 }
+
+/// Webhook API key with its scope
+#[derive(Serialize, ToSchema)]
+pub struct ApiKeyDetails {
+    #[serde(flatten)]
+    pub key: WebhookApiKey,
+    pub building_ids: Vec<u64>,
+    pub meter_ids: Vec<u64>,
+}
+
+/// Reading submitted by a resident, with its meter and apartment
+#[derive(Serialize, ToSchema)]
+pub struct SubmissionWithMeter {
+    #[serde(flatten)]
+    pub submission: MeterReadingSubmission,
+    pub meter_type: String,
+    pub serial_number: String,
+    pub apartment_id: u64,
+    pub apartment_number: Option<String>,
+    pub building_address: Option<String>,
+    pub submitted_by_name: Option<String>,
+    /// Difference to the previous counted reading, if there is one
+    pub consumption: Option<String>,
+}
+
+/// Request to reject a submitted reading
+#[derive(Deserialize, ToSchema)]
+pub struct RejectSubmissionRequest {
+    pub reason: String,
+}
+
+/// Request to start a reading campaign
+#[derive(Deserialize, ToSchema)]
+pub struct CreateCampaignRequest {
+    pub building_id: u64,
+    pub name: String,
+    pub start_date: chrono::NaiveDate,
+    pub deadline: chrono::NaiveDate,
+}
+
+/// Where a meter stands in a reading campaign
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, ToSchema)]
+pub enum CampaignMeterStatus {
+    /// Read within the campaign window
+    Read,
+    /// A resident's reading awaits review
+    Submitted,
+    /// Estimated when the campaign closed
+    Estimated,
+    /// No reading yet
+    Missing,
+}
+
+/// An active meter of the campaign's building, with its reading in the window
+#[derive(Serialize, ToSchema)]
+pub struct CampaignMeter {
+    pub meter_id: u64,
+    pub meter_type: String,
+    pub serial_number: String,
+    pub apartment_id: u64,
+    pub apartment_number: String,
+    pub status: CampaignMeterStatus,
+    pub reading_value: Option<String>,
+    pub reading_timestamp: Option<chrono::NaiveDateTime>,
+    pub unit: Option<String>,
+}
+
+/// Reading campaign with its progress
+#[derive(Serialize, ToSchema)]
+pub struct CampaignSummary {
+    #[serde(flatten)]
+    pub campaign: ReadingCampaign,
+    pub building_address: Option<String>,
+    pub total_meters: usize,
+    pub read: usize,
+    pub submitted: usize,
+    pub estimated: usize,
+    pub missing: usize,
+}
+
+/// Reading campaign with the status of each meter
+#[derive(Serialize, ToSchema)]
+pub struct CampaignDetail {
+    #[serde(flatten)]
+    pub summary: CampaignSummary,
+    pub meters: Vec<CampaignMeter>,
+}
+
+/// Residents reminded of missing readings
+#[derive(Serialize, ToSchema)]
+pub struct ReminderResult {
+    pub notified_users: usize,
+}
+
+/// Vendor CSV export to import, with the mapping of its columns
+#[derive(Deserialize, ToSchema)]
+pub struct CsvImportRequest {
+    pub mapping: CsvMapping,
+    /// File contents
+    pub content: String,
+}
+
+/// Wireless M-Bus / OMS telegrams pushed by a gateway
+#[derive(Deserialize, ToSchema)]
+pub struct WmbusWebhookPayload {
+    /// Hex-encoded telegrams without link layer CRCs
+    pub telegrams: Vec<String>,
+}
@@ -1,12 +1,101 @@
-use super::types::{WebhookBatchPayload, WebhookReadingPayload};
+use super::api_keys::{load_scope, split_key};
+use super::types::{WebhookBatchPayload, WebhookReadingPayload, WmbusWebhookPayload};
 use crate::auth::AppError;
+use crate::config::AppConfig;
 use crate::db::DbPool;
-use crate::models::{Meter, WebhookApiKey};
+use crate::ingestion::{
+    IngestSummary, MeterScope, ParseError, ReadingParser, ReadingRecord, WmbusDecoder, ingest,
+    parse_timestamp,
+};
+use crate::models::{ReadingSource, WebhookApiKey};
 use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use diesel::mysql::MysqlConnection;
 use diesel::prelude::*;
 
+/// Window of the per-key rate limit
+const RATE_WINDOW_SECS: i64 = 60;
+
+/// The API key a webhook request was authenticated with
+struct WebhookKey {
+    id: u64,
+    scope: MeterScope,
+}
+
+impl WebhookKey {
+    /// Keep the error of a failed request on the key, for the admin API keys screen.
+    fn finish<T>(&self, result: Result<T, AppError>, pool: &DbPool) -> Result<T, AppError> {
+        if let Err(e) = &result
+            && let Ok(mut conn) = pool.get()
+        {
+            record_error(self.id, &e.to_string(), &mut conn);
+        }
+        result
+    }
+}
+
+/// Best effort: a failure is logged, as the request's own error matters more.
+fn record_error(key_id: u64, error: &str, conn: &mut MysqlConnection) {
+    use crate::schema::webhook_api_keys::dsl as wak;
+
+    let error: String = error.chars().take(255).collect();
+    if let Err(e) = diesel::update(wak::webhook_api_keys.filter(wak::id.eq(key_id)))
+        .set((
+            wak::last_error.eq(error),
+            wak::last_error_at.eq(chrono::Utc::now().naive_utc()),
+        ))
+        .execute(conn)
+    {
+        tracing::warn!(key_id, error = %e, "Failed to record webhook key error");
+    }
+}
+
+/// Count a request against the key's rate limit; `false` when the limit is reached.
+///
+/// The window lives in the key's row, locked while it is counted, so the limit holds
+/// across API replicas.
+fn count_request(key: &WebhookApiKey, conn: &mut MysqlConnection) -> Result<bool, AppError> {
+    use crate::schema::webhook_api_keys::dsl as wak;
+
+    conn.transaction(|conn| {
+        let (window_start, window_count): (Option<chrono::NaiveDateTime>, u32) =
+            wak::webhook_api_keys
+                .filter(wak::id.eq(key.id))
+                .select((wak::rate_window_start, wak::rate_window_count))
+                .for_update()
+                .first(conn)?;
+        let now = chrono::Utc::now().naive_utc();
+        let (window_start, window_count) = match window_start {
+            Some(start) if (now - start).num_seconds() < RATE_WINDOW_SECS => {
+                (start, window_count.saturating_add(1))
+            }
+            _ => (now, 1),
+        };
+        if key
+            .rate_limit_per_minute
+            .is_some_and(|limit| window_count > limit)
+        {
+            return Ok(false);
+        }
+        diesel::update(wak::webhook_api_keys.filter(wak::id.eq(key.id)))
+            .set((
+                wak::rate_window_start.eq(window_start),
+                wak::rate_window_count.eq(window_count),
+                wak::usage_count.eq(wak::usage_count + 1),
+                wak::last_used_at.eq(now),
+            ))
+            .execute(conn)?;
+        Ok(true)
+    })
+}
+
 /// Authenticate webhook requests via API key
-async fn authenticate_webhook(req: &HttpRequest, pool: &web::Data<DbPool>) -> Result<(), AppError> {
+///
+/// Keys are found by their prefix, so only one hash is checked per request. Keys from
+/// before prefixes are still checked one by one until they are rotated.
+async fn authenticate_webhook(
+    req: &HttpRequest,
+    pool: &web::Data<DbPool>,
+) -> Result<WebhookKey, AppError> {
     let api_key = req
         .headers()
         .get("X-API-Key")
@@ -20,24 +109,83 @@ async fn authenticate_webhook(req: &HttpRequest, pool: &web::Data<DbPool>) -> Re
     use crate::auth::crypto::verify_password;
     use crate::schema::webhook_api_keys::dsl as wak;
 
-    let keys: Vec<WebhookApiKey> = wak::webhook_api_keys
-        .filter(wak::is_active.eq(true))
-        .select(WebhookApiKey::as_select())
-        .load(&mut conn)?;
-
-    // Check if any key matches
-    for key in keys {
-        if verify_password(api_key, &key.api_key_hash) {
-            // Update last_used_at
-            let now = chrono::Utc::now().naive_utc();
-            diesel::update(wak::webhook_api_keys.filter(wak::id.eq(key.id)))
-                .set(wak::last_used_at.eq(now))
-                .execute(&mut conn)?;
-            return Ok(());
+    let key = match split_key(api_key) {
+        Some((prefix, secret)) => {
+            let key: WebhookApiKey = wak::webhook_api_keys
+                .filter(wak::key_prefix.eq(prefix))
+                .filter(wak::is_active.eq(true))
+                .select(WebhookApiKey::as_select())
+                .first(&mut conn)
+                .optional()?
+                .ok_or(AppError::Unauthorized)?;
+            if !verify_password(secret, &key.api_key_hash) {
+                record_error(key.id, "invalid_secret", &mut conn);
+                return Err(AppError::Unauthorized);
+            }
+            key
         }
+        None => wak::webhook_api_keys
+            .filter(wak::key_prefix.is_null())
+            .filter(wak::is_active.eq(true))
+            .select(WebhookApiKey::as_select())
+            .load(&mut conn)?
+            .into_iter()
+            .find(|key| verify_password(api_key, &key.api_key_hash))
+            .ok_or(AppError::Unauthorized)?,
+    };
+
+    if key
+        .expires_at
+        .is_some_and(|at| at <= chrono::Utc::now().naive_utc())
+    {
+        record_error(key.id, "key_expired", &mut conn);
+        return Err(AppError::Unauthorized);
     }
+    if !count_request(&key, &mut conn)? {
+        record_error(key.id, "rate_limited", &mut conn);
+        return Err(AppError::RateLimited);
+    }
+
+    let (building_ids, meter_ids) = load_scope(key.id, &mut conn)?;
+    let scope = if building_ids.is_empty() && meter_ids.is_empty() {
+        // Everything the key was scoped to is gone; it must not widen to every meter
+        if key.scoped {
+            record_error(key.id, "scope_deleted", &mut conn);
+            return Err(AppError::Forbidden);
+        }
+        MeterScope::All
+    } else {
+        MeterScope::Limited {
+            building_ids,
+            meter_ids,
+        }
+    };
+    Ok(WebhookKey { id: key.id, scope })
+}
+
+/// Store readings within the key's scope.
+fn store(
+    records: &[ReadingRecord],
+    key: &WebhookKey,
+    pool: &DbPool,
+) -> Result<IngestSummary, AppError> {
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    ingest(records, ReadingSource::Webhook, &key.scope, &mut conn)
+}
 
-    Err(AppError::Unauthorized)
+impl WebhookReadingPayload {
+    fn to_record(&self) -> Result<ReadingRecord, ParseError> {
+        let timestamp = parse_timestamp(&self.timestamp)
+            .ok_or_else(|| ParseError::Invalid("Invalid timestamp format".into()))?;
+        ReadingRecord::new(
+            &self.serial_number,
+            self.reading_value.clone(),
+            timestamp,
+            &self.unit,
+        )
+    }
 }
 
 /// Webhook endpoint for external systems
@@ -46,9 +194,12 @@ async fn authenticate_webhook(req: &HttpRequest, pool: &web::Data<DbPool>) -> Re
     path = "/api/v1/webhooks/meter-reading",
     request_body = WebhookReadingPayload,
     responses(
-        (status = 201, description = "Reading recorded"),
+        (status = 201, description = "Reading recorded, or already present"),
+        (status = 400, description = "Invalid reading"),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "Meter not found")
+        (status = 403, description = "Every building and meter of the key's scope was deleted"),
+        (status = 404, description = "Meter not found or outside the key's scope"),
+        (status = 429, description = "Rate limit of the API key reached")
     ),
     tag = "Webhooks"
 )]
@@ -58,57 +209,39 @@ pub async fn webhook_meter_reading(
     pool: web::Data<DbPool>,
 ) -> Result<impl Responder, AppError> {
     // Authenticate via API key
-    authenticate_webhook(&req, &pool).await?;
-
-    let mut conn = pool
-        .get()
-        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let key = authenticate_webhook(&req, &pool).await?;
 
-    // Find meter by serial number
-    use crate::schema::meters::dsl as m;
-    let meter: Meter = m::meters
-        .filter(m::serial_number.eq(&payload.serial_number))
-        .filter(m::is_active.eq(true))
-        .select(Meter::as_select())
-        .first(&mut conn)
-        .map_err(|_| AppError::NotFound)?;
-
-    // Parse timestamp
-    let reading_timestamp =
-        chrono::NaiveDateTime::parse_from_str(&payload.timestamp, "%Y-%m-%dT%H:%M:%SZ")
-            .or_else(|_| {
-                chrono::NaiveDateTime::parse_from_str(&payload.timestamp, "%Y-%m-%dT%H:%M:%S")
-            })
-            .or_else(|_| {
-                chrono::NaiveDateTime::parse_from_str(&payload.timestamp, "%Y-%m-%d %H:%M:%S")
-            })
-            .map_err(|_| AppError::BadRequest("Invalid timestamp format".into()))?;
-
-    use crate::schema::meter_readings::dsl as mr;
-
-    // Use INSERT IGNORE to handle duplicates gracefully (idempotent)
-    diesel::insert_into(mr::meter_readings)
-        .values((
-            mr::meter_id.eq(meter.id),
-            mr::reading_value.eq(&payload.reading_value),
-            mr::reading_timestamp.eq(reading_timestamp),
-            mr::unit.eq(&payload.unit),
-            mr::source.eq("Webhook"),
-        ))
-        .execute(&mut conn)
-        .ok(); // Ignore errors from duplicate readings
+    // A reading the meter already has is accepted again (idempotent)
+    let result = payload
+        .to_record()
+        .map_err(AppError::from)
+        .and_then(|record| store(&[record], &key, &pool))
+        .and_then(|summary| {
+            if summary.unknown_meters.is_empty() {
+                Ok(())
+            } else {
+                Err(AppError::NotFound)
+            }
+        });
+    key.finish(result, &pool)?;
 
     Ok(HttpResponse::Created().finish())
 }
 
 /// Webhook endpoint for batch readings
+///
+/// Readings for unknown meters and readings already present are skipped; the
+/// summary lists both.
 #[utoipa::path(
     post,
     path = "/api/v1/webhooks/meter-reading-batch",
     request_body = WebhookBatchPayload,
     responses(
-        (status = 201, description = "Readings recorded"),
-        (status = 401, description = "Unauthorized")
+        (status = 201, description = "Readings recorded", body = IngestSummary),
+        (status = 400, description = "Invalid reading in the batch"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Every building and meter of the key's scope was deleted"),
+        (status = 429, description = "Rate limit of the API key reached")
     ),
     tag = "Webhooks"
 )]
@@ -118,53 +251,55 @@ pub async fn webhook_meter_reading_batch(
     pool: web::Data<DbPool>,
 ) -> Result<impl Responder, AppError> {
     // Authenticate via API key
-    authenticate_webhook(&req, &pool).await?;
+    let key = authenticate_webhook(&req, &pool).await?;
 
-    let mut conn = pool
-        .get()
-        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let result = payload
+        .readings
+        .iter()
+        .map(WebhookReadingPayload::to_record)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(AppError::from)
+        .and_then(|records| store(&records, &key, &pool));
+    let summary = key.finish(result, &pool)?;
 
-    for reading_payload in &payload.readings {
-        // Find meter by serial number
-        use crate::schema::meters::dsl as m;
-        let meter_result: Result<Meter, _> = m::meters
-            .filter(m::serial_number.eq(&reading_payload.serial_number))
-            .filter(m::is_active.eq(true))
-            .select(Meter::as_select())
-            .first(&mut conn);
-
-        if let Ok(meter) = meter_result {
-            // Parse timestamp
-            if let Ok(reading_timestamp) = chrono::NaiveDateTime::parse_from_str(
-                &reading_payload.timestamp,
-                "%Y-%m-%dT%H:%M:%SZ",
-            )
-            .or_else(|_| {
-                chrono::NaiveDateTime::parse_from_str(
-                    &reading_payload.timestamp,
-                    "%Y-%m-%dT%H:%M:%S",
-                )
-            })
-            .or_else(|_| {
-                chrono::NaiveDateTime::parse_from_str(
-                    &reading_payload.timestamp,
-                    "%Y-%m-%d %H:%M:%S",
-                )
-            }) {
-                use crate::schema::meter_readings::dsl as mr;
-                diesel::insert_into(mr::meter_readings)
-                    .values((
-                        mr::meter_id.eq(meter.id),
-                        mr::reading_value.eq(&reading_payload.reading_value),
-                        mr::reading_timestamp.eq(reading_timestamp),
-                        mr::unit.eq(&reading_payload.unit),
-                        mr::source.eq("Webhook"),
-                    ))
-                    .execute(&mut conn)
-                    .ok(); // Ignore errors from duplicate readings
-            }
-        }
-    }
+    Ok(HttpResponse::Created().json(summary))
+}
 
-    Ok(HttpResponse::Created().finish())
+/// Webhook endpoint for wireless M-Bus / OMS telegrams
+///
+/// Meters are identified by the identification number in the telegram. Encrypted
+/// telegrams need the meter's key in `WMBUS_KEYS`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/webhooks/wmbus",
+    request_body = WmbusWebhookPayload,
+    responses(
+        (status = 201, description = "Readings recorded", body = IngestSummary),
+        (status = 400, description = "Telegram could not be decoded"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Every building and meter of the key's scope was deleted"),
+        (status = 429, description = "Rate limit of the API key reached")
+    ),
+    tag = "Webhooks"
+)]
+pub async fn webhook_wmbus(
+    req: HttpRequest,
+    payload: web::Json<WmbusWebhookPayload>,
+    pool: web::Data<DbPool>,
+    config: web::Data<AppConfig>,
+) -> Result<impl Responder, AppError> {
+    // Authenticate via API key
+    let key = authenticate_webhook(&req, &pool).await?;
+
+    let decoder = WmbusDecoder::new(config.wmbus_keys.clone());
+    let result = payload
+        .telegrams
+        .iter()
+        .map(|telegram| decoder.parse(telegram.as_bytes()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(AppError::from)
+        .and_then(|records| store(&records.concat(), &key, &pool));
+    let summary = key.finish(result, &pool)?;
+
+    Ok(HttpResponse::Created().json(summary))
 }
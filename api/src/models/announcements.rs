@@ -1,4 +1,8 @@
-use crate::schema::{announcements, announcements_comments};
+use crate::schema::{
+    announcements, announcements_attachments, announcements_comments,
+    announcements_comments_reports, announcements_reads, announcements_revisions,
+    announcements_templates, announcements_templates_translations, announcements_translations,
+};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -14,15 +18,15 @@ pub struct Announcement {
     pub author_id: u64,
     pub public: bool,
     pub pinned: bool,
-    pub roles_csv: Option<String>,
-    pub building_id: Option<u64>,
-    pub apartment_id: Option<u64>,
     pub comments_enabled: bool,
+    pub requires_ack: bool,
     pub publish_at: Option<chrono::NaiveDateTime>,
     pub expire_at: Option<chrono::NaiveDateTime>,
     pub is_deleted: bool,
     pub created_at: Option<chrono::NaiveDateTime>,
     pub updated_at: Option<chrono::NaiveDateTime>,
+    /// Language of `title` and `body_md`; other languages live in `announcements_translations`
+    pub language: String,
 }
 
 #[derive(Insertable, Deserialize, ToSchema)]
@@ -34,12 +38,11 @@ pub struct NewAnnouncement {
     pub author_id: u64,
     pub public: bool,
     pub pinned: bool,
-    pub roles_csv: Option<String>,
-    pub building_id: Option<u64>,
-    pub apartment_id: Option<u64>,
     pub comments_enabled: bool,
+    pub requires_ack: bool,
     pub publish_at: Option<chrono::NaiveDateTime>,
     pub expire_at: Option<chrono::NaiveDateTime>,
+    pub language: String,
 }
 
 #[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
@@ -53,6 +56,14 @@ pub struct AnnouncementComment {
     pub body_html: String,
     pub is_deleted: bool,
     pub created_at: Option<chrono::NaiveDateTime>,
+    /// Comment this one replies to
+    pub parent_id: Option<u64>,
+    pub edited_at: Option<chrono::NaiveDateTime>,
+    /// Hidden by a moderator; unlike deletion the reason stays visible
+    pub is_hidden: bool,
+    pub hidden_reason: Option<String>,
+    pub hidden_by: Option<u64>,
+    pub hidden_at: Option<chrono::NaiveDateTime>,
 }
 
 #[derive(Insertable, Deserialize, ToSchema)]
@@ -62,4 +73,118 @@ pub struct NewAnnouncementComment {
     pub user_id: u64,
     pub body_md: String,
     pub body_html: String,
+    pub parent_id: Option<u64>,
+}
+
+/// A resident's report of an inappropriate comment.
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = announcements_comments_reports)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct AnnouncementCommentReport {
+    pub id: u64,
+    pub comment_id: u64,
+    pub reporter_id: u64,
+    pub reason: String,
+    /// "open", "dismissed" or "actioned" (comment hidden)
+    pub status: String,
+    pub resolved_by: Option<u64>,
+    pub resolved_at: Option<chrono::NaiveDateTime>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+/// Per-user read receipt; `acknowledged_at` is set by the explicit confirm action.
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = announcements_reads)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct AnnouncementRead {
+    pub announcement_id: u64,
+    pub user_id: u64,
+    pub read_at: chrono::NaiveDateTime,
+    pub acknowledged_at: Option<chrono::NaiveDateTime>,
+}
+
+/// Snapshot of an announcement's title and body, numbered per announcement from 1.
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = announcements_revisions)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct AnnouncementRevision {
+    pub id: u64,
+    pub announcement_id: u64,
+    pub revision: u32,
+    pub title: String,
+    pub body_md: String,
+    pub editor_id: u64,
+    /// Revision whose content was brought back, when this one is a restore
+    pub restored_from: Option<u32>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = announcements_revisions)]
+pub struct NewAnnouncementRevision {
+    pub announcement_id: u64,
+    pub revision: u32,
+    pub title: String,
+    pub body_md: String,
+    pub editor_id: u64,
+    pub restored_from: Option<u32>,
+}
+
+/// File or inline image attached to an announcement; same lifecycle as maintenance attachments.
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = announcements_attachments)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct AnnouncementAttachment {
+    pub id: u64,
+    pub announcement_id: u64,
+    pub original_filename: String,
+    pub stored_filename: String,
+    pub mime_type: String,
+    pub size_bytes: u64,
+    pub checksum_sha256: Option<String>,
+    pub has_thumbnail: bool,
+    /// "pending" while quarantined for scanning, then "clean" or "infected"
+    pub scan_status: String,
+    pub scan_signature: Option<String>,
+    pub uploaded_by: u64,
+    pub is_deleted: bool,
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+/// Title and body of an announcement in a language other than its default one.
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = announcements_translations)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct AnnouncementTranslation {
+    pub announcement_id: u64,
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
+    pub body_html: String,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+/// Reusable announcement text with `{{placeholder}}` markers.
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = announcements_templates)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct AnnouncementTemplate {
+    pub id: u64,
+    pub name: String,
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
+    pub created_by: u64,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+#[derive(Queryable, Selectable, Insertable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = announcements_templates_translations)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct AnnouncementTemplateTranslation {
+    pub template_id: u64,
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
 }
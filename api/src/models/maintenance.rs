@@ -1,6 +1,6 @@
 use crate::schema::{
-    maintenance_request_attachments, maintenance_request_comments, maintenance_request_history,
-    maintenance_requests,
+    maintenance_filter_presets, maintenance_request_attachments, maintenance_request_comments,
+    maintenance_request_feedback, maintenance_request_history, maintenance_requests,
 };
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -14,6 +14,7 @@ pub struct MaintenanceRequest {
     pub apartment_id: u64,
     pub created_by: u64,
     pub assigned_to: Option<u64>,
+    pub vendor: Option<String>,
     pub request_type: String,
     pub priority: String,
     pub title: String,
@@ -44,6 +45,13 @@ pub struct MaintenanceRequestAttachment {
     pub stored_filename: String,
     pub mime_type: String,
     pub size_bytes: u64,
+    /// Hex SHA-256 of the stored content; `None` for uploads that predate checksums
+    pub checksum_sha256: Option<String>,
+    pub has_thumbnail: bool,
+    /// "pending" while quarantined for scanning, then "clean" or "infected"
+    pub scan_status: String,
+    /// Signature reported by the scanner for infected uploads
+    pub scan_signature: Option<String>,
     pub is_deleted: bool,
     pub created_at: Option<chrono::NaiveDateTime>,
 }
@@ -98,3 +106,49 @@ pub struct MaintenanceRequestCommentWithUser {
 pub struct CreateCommentRequest {
     pub comment_text: String,
 }
+
+#[derive(Queryable, Selectable, Debug)]
+#[diesel(table_name = maintenance_filter_presets)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct MaintenanceFilterPreset {
+    pub id: u64,
+    pub user_id: u64,
+    pub name: String,
+    pub filters_json: String,
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = maintenance_filter_presets)]
+pub struct NewMaintenanceFilterPreset {
+    pub user_id: u64,
+    pub name: String,
+    pub filters_json: String,
+}
+
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = maintenance_request_feedback)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct MaintenanceRequestFeedback {
+    pub id: u64,
+    pub request_id: u64,
+    pub user_id: u64,
+    pub rating: u8,
+    pub is_fixed: bool,
+    pub comment: Option<String>,
+    pub assigned_to: Option<u64>,
+    pub vendor: Option<String>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = maintenance_request_feedback)]
+pub struct NewMaintenanceRequestFeedback {
+    pub request_id: u64,
+    pub user_id: u64,
+    pub rating: u8,
+    pub is_fixed: bool,
+    pub comment: Option<String>,
+    pub assigned_to: Option<u64>,
+    pub vendor: Option<String>,
+}
@@ -0,0 +1,118 @@
+use crate::schema::{meeting_agenda_items, meeting_attendance, meetings};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = meetings)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct Meeting {
+    pub id: u64,
+    pub building_id: u64,
+    pub title: String,
+    pub description: String,
+    pub scheduled_at: chrono::NaiveDateTime,
+    pub location: String,
+    /// Link for attendees joining online
+    pub online_url: Option<String>,
+    /// Share of the building's floor area that must be represented to decide
+    pub quorum_percent: i32,
+    pub status: String,
+    pub started_at: Option<chrono::NaiveDateTime>,
+    pub ended_at: Option<chrono::NaiveDateTime>,
+    pub minutes_md: Option<String>,
+    /// Announcement the minutes were published as
+    pub minutes_announcement_id: Option<u64>,
+    pub created_by: u64,
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+/// A proposal on a meeting's agenda
+#[derive(Queryable, Selectable, Serialize, Debug, Clone, ToSchema)]
+#[diesel(table_name = meeting_agenda_items)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct MeetingAgendaItem {
+    pub meeting_id: u64,
+    pub proposal_id: u64,
+    pub position: i32,
+    /// When voting on the item was opened during the meeting
+    pub opened_at: Option<chrono::NaiveDateTime>,
+    pub closed_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Queryable, Selectable, Serialize, Debug, Clone, ToSchema)]
+#[diesel(table_name = meeting_attendance)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct MeetingAttendance {
+    pub meeting_id: u64,
+    pub user_id: u64,
+    pub mode: String,
+    /// Who represents the owner when attending by proxy
+    pub proxy_holder_id: Option<u64>,
+    pub registered_by: u64,
+    pub registered_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum MeetingStatus {
+    Scheduled,
+    /// Opened by the chair; agenda items can be voted on
+    InProgress,
+    Closed,
+}
+impl std::fmt::Display for MeetingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Scheduled => "Scheduled",
+                Self::InProgress => "InProgress",
+                Self::Closed => "Closed",
+            }
+        )
+    }
+}
+impl std::str::FromStr for MeetingStatus {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Scheduled" => Self::Scheduled,
+            "InProgress" => Self::InProgress,
+            "Closed" => Self::Closed,
+            _ => return Err(()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum AttendanceMode {
+    InPerson,
+    /// Represented by a proxy holder
+    Proxy,
+    Online,
+}
+impl std::fmt::Display for AttendanceMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::InPerson => "InPerson",
+                Self::Proxy => "Proxy",
+                Self::Online => "Online",
+            }
+        )
+    }
+}
+impl std::str::FromStr for AttendanceMode {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "InPerson" => Self::InPerson,
+            "Proxy" => Self::Proxy,
+            "Online" => Self::Online,
+            _ => return Err(()),
+        })
+    }
+}
@@ -1,4 +1,6 @@
-use crate::schema::{meter_readings, meters, webhook_api_keys};
+use crate::schema::{
+    meter_reading_campaigns, meter_reading_submissions, meter_readings, meters, webhook_api_keys,
+};
 use bigdecimal::BigDecimal;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -98,6 +100,12 @@ pub struct NewMeterReading {
 pub enum ReadingSource {
     Webhook,
     Manual,
+    /// Derived from the meter's past consumption when no reading was collected
+    Estimated,
+    /// Received from the MQTT broker
+    Mqtt,
+    /// Imported from a vendor's CSV export
+    Import,
 }
 
 impl std::fmt::Display for ReadingSource {
@@ -108,6 +116,9 @@ impl std::fmt::Display for ReadingSource {
             match self {
                 Self::Webhook => "Webhook",
                 Self::Manual => "Manual",
+                Self::Estimated => "Estimated",
+                Self::Mqtt => "Mqtt",
+                Self::Import => "Import",
             }
         )
     }
@@ -119,11 +130,114 @@ impl std::str::FromStr for ReadingSource {
         Ok(match s {
             "Webhook" => Self::Webhook,
             "Manual" => Self::Manual,
+            "Estimated" => Self::Estimated,
+            "Mqtt" => Self::Mqtt,
+            "Import" => Self::Import,
             _ => return Err(()),
         })
     }
 }
 
+// Meter reading submissions: readings sent in by residents, pending a manager's review
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = meter_reading_submissions)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct MeterReadingSubmission {
+    pub id: u64,
+    pub meter_id: u64,
+    pub submitted_by: u64,
+    #[schema(value_type = String, example = "123.456")]
+    pub reading_value: BigDecimal,
+    pub reading_timestamp: chrono::NaiveDateTime,
+    pub unit: String,
+    #[schema(value_type = Option<String>, example = "120.000")]
+    pub previous_value: Option<BigDecimal>,
+    pub previous_timestamp: Option<chrono::NaiveDateTime>,
+    pub photo_original_filename: String,
+    pub photo_stored_filename: String,
+    pub photo_mime_type: String,
+    pub photo_size_bytes: u64,
+    pub photo_checksum_sha256: Option<String>,
+    pub photo_has_thumbnail: bool,
+    pub scan_status: String,
+    pub status: String,
+    pub review_note: Option<String>,
+    pub reviewed_by: Option<u64>,
+    pub reviewed_at: Option<chrono::NaiveDateTime>,
+    pub reading_id: Option<u64>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum SubmissionStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl std::fmt::Display for SubmissionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Pending => "Pending",
+                Self::Approved => "Approved",
+                Self::Rejected => "Rejected",
+            }
+        )
+    }
+}
+
+impl std::str::FromStr for SubmissionStatus {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Pending" => Self::Pending,
+            "Approved" => Self::Approved,
+            "Rejected" => Self::Rejected,
+            _ => return Err(()),
+        })
+    }
+}
+
+// Reading campaigns: periodic collection of every meter's reading in a building
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = meter_reading_campaigns)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct ReadingCampaign {
+    pub id: u64,
+    pub building_id: u64,
+    pub name: String,
+    pub start_date: chrono::NaiveDate,
+    pub deadline: chrono::NaiveDate,
+    pub status: String,
+    pub created_by: u64,
+    pub last_reminded_at: Option<chrono::NaiveDateTime>,
+    pub closed_at: Option<chrono::NaiveDateTime>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum CampaignStatus {
+    Open,
+    /// Past the deadline, with missing readings estimated
+    Closed,
+}
+
+impl std::fmt::Display for CampaignStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Open => "Open",
+                Self::Closed => "Closed",
+            }
+        )
+    }
+}
+
 // Webhook API Key models
 #[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
 #[diesel(table_name = webhook_api_keys)]
@@ -131,17 +245,38 @@ impl std::str::FromStr for ReadingSource {
 pub struct WebhookApiKey {
     pub id: u64,
     pub name: String,
+    /// Public part of the key, `hm_<prefix>_…`; `None` for keys from before prefixes
+    pub key_prefix: Option<String>,
+    #[serde(skip_serializing)]
     pub api_key_hash: String,
     pub is_active: bool,
+    pub expires_at: Option<chrono::NaiveDateTime>,
+    pub rate_limit_per_minute: Option<u32>,
+    /// Whether the key was given buildings or meters; such a key never falls back to
+    /// every meter
+    pub scoped: bool,
     pub created_by: u64,
     pub created_at: Option<chrono::NaiveDateTime>,
     pub last_used_at: Option<chrono::NaiveDateTime>,
+    pub usage_count: u64,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<chrono::NaiveDateTime>,
+    #[serde(skip_serializing)]
+    pub rate_window_start: Option<chrono::NaiveDateTime>,
+    #[serde(skip_serializing)]
+    pub rate_window_count: u32,
+    /// Key this one replaced when it was rotated
+    pub rotated_from_id: Option<u64>,
 }
 
 #[derive(Insertable)]
 #[diesel(table_name = webhook_api_keys)]
 pub struct NewWebhookApiKey {
     pub name: String,
+    pub key_prefix: Option<String>,
     pub api_key_hash: String,
+    pub expires_at: Option<chrono::NaiveDateTime>,
+    pub rate_limit_per_minute: Option<u32>,
     pub created_by: u64,
+    pub rotated_from_id: Option<u64>,
 }
@@ -1,7 +1,10 @@
 // Domain-specific model modules
 pub mod announcements;
 pub mod maintenance;
+pub mod meetings;
 pub mod meters;
+pub mod notifications;
+pub mod polls;
 pub mod properties;
 pub mod users;
 pub mod voting;
@@ -9,7 +12,10 @@ pub mod voting;
 // Re-export all types for convenient importing
 pub use announcements::*;
 pub use maintenance::*;
+pub use meetings::*;
 pub use meters::*;
+pub use notifications::*;
+pub use polls::*;
 pub use properties::*;
 pub use users::*;
 pub use voting::*;
@@ -0,0 +1,47 @@
+use crate::schema::notifications;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Something a user should know about, shown in the notification menu
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = notifications)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct Notification {
+    pub id: u64,
+    pub user_id: u64,
+    pub kind: String,
+    /// Title of the thing the notification is about
+    pub subject: String,
+    /// Frontend path to open
+    pub link: Option<String>,
+    pub read_at: Option<chrono::NaiveDateTime>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum NotificationKind {
+    /// Voting closed on a proposal the user discussed
+    ProposalTallied,
+    /// A manager approved the user's meter reading
+    MeterReadingApproved,
+    /// A manager rejected the user's meter reading
+    MeterReadingRejected,
+    /// A reading campaign still misses a reading of the user's meter
+    ReadingCampaignReminder,
+}
+
+impl std::fmt::Display for NotificationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::ProposalTallied => "ProposalTallied",
+                Self::MeterReadingApproved => "MeterReadingApproved",
+                Self::MeterReadingRejected => "MeterReadingRejected",
+                Self::ReadingCampaignReminder => "ReadingCampaignReminder",
+            }
+        )
+    }
+}
@@ -0,0 +1,183 @@
+use crate::schema::{poll_answers, poll_options, poll_questions, poll_responses, polls};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = polls)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct Poll {
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub created_by: u64,
+    pub building_id: Option<u64>,
+    pub start_time: chrono::NaiveDateTime,
+    pub end_time: chrono::NaiveDateTime,
+    pub anonymous: bool,
+    pub results_visibility: String,
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Queryable, Selectable, Serialize, Debug, Clone, ToSchema)]
+#[diesel(table_name = poll_questions)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct PollQuestion {
+    pub id: u64,
+    pub poll_id: u64,
+    pub position: i32,
+    pub prompt: String,
+    pub kind: String,
+    pub required: bool,
+    pub rating_max: Option<i32>,
+}
+
+#[derive(Queryable, Selectable, Serialize, Debug, Clone, ToSchema)]
+#[diesel(table_name = poll_options)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct PollOption {
+    pub id: u64,
+    pub question_id: u64,
+    pub position: i32,
+    pub label: String,
+}
+
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = poll_responses)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct PollResponse {
+    /// Random UUID
+    pub id: String,
+    pub poll_id: u64,
+    /// `None` for anonymous polls
+    pub user_id: Option<u64>,
+    /// `None` for anonymous polls
+    pub submitted_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = poll_answers)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct PollAnswer {
+    pub id: u64,
+    pub response_id: String,
+    pub question_id: u64,
+    pub option_id: Option<u64>,
+    pub text_value: Option<String>,
+    pub rating: Option<i32>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = poll_answers)]
+pub struct NewPollAnswer {
+    pub response_id: String,
+    pub question_id: u64,
+    pub option_id: Option<u64>,
+    pub text_value: Option<String>,
+    pub rating: Option<i32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum PollQuestionKind {
+    SingleChoice,
+    MultipleChoice,
+    FreeText,
+    Rating,
+}
+impl std::fmt::Display for PollQuestionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::SingleChoice => "SingleChoice",
+                Self::MultipleChoice => "MultipleChoice",
+                Self::FreeText => "FreeText",
+                Self::Rating => "Rating",
+            }
+        )
+    }
+}
+impl std::str::FromStr for PollQuestionKind {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SingleChoice" => Self::SingleChoice,
+            "MultipleChoice" => Self::MultipleChoice,
+            "FreeText" => Self::FreeText,
+            "Rating" => Self::Rating,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Who may see a poll's results. Admins and managers always can.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum PollResultsVisibility {
+    /// Everyone who can see the poll, at any time
+    Always,
+    /// Residents who have responded, and everyone once the poll is closed
+    AfterResponse,
+    /// Everyone once the poll is closed
+    AfterClose,
+    /// Admins and managers only
+    Managers,
+}
+impl std::fmt::Display for PollResultsVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Always => "Always",
+                Self::AfterResponse => "AfterResponse",
+                Self::AfterClose => "AfterClose",
+                Self::Managers => "Managers",
+            }
+        )
+    }
+}
+impl std::str::FromStr for PollResultsVisibility {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Always" => Self::Always,
+            "AfterResponse" => Self::AfterResponse,
+            "AfterClose" => Self::AfterClose,
+            "Managers" => Self::Managers,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Derived from the poll's window; polls closed early get their end time moved to now.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum PollStatus {
+    Scheduled,
+    Open,
+    Closed,
+}
+impl std::fmt::Display for PollStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Scheduled => "Scheduled",
+                Self::Open => "Open",
+                Self::Closed => "Closed",
+            }
+        )
+    }
+}
+impl Poll {
+    pub fn status_at(&self, now: chrono::NaiveDateTime) -> PollStatus {
+        if now < self.start_time {
+            PollStatus::Scheduled
+        } else if now < self.end_time {
+            PollStatus::Open
+        } else {
+            PollStatus::Closed
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use crate::schema::{proposal_results, proposals, votes};
+use crate::schema::{
+    apartment_vote_history, proposal_attachments, proposal_comments, proposal_options,
+    proposal_result_rounds, proposal_results, proposals, secret_ballot_selections,
+    secret_ballot_totals, secret_ballots, vote_proxies, vote_selections, votes,
+};
 use bigdecimal::BigDecimal;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -15,6 +19,11 @@ pub struct Proposal {
     pub start_time: chrono::NaiveDateTime,
     pub end_time: chrono::NaiveDateTime,
     pub voting_method: String,
+    pub ballot_type: String,
+    /// Most options an approval ballot may select; unlimited when `None`
+    pub max_selections: Option<i32>,
+    /// Ballot contents are kept apart from voters; see [`SecretBallot`]
+    pub secret_ballot: bool,
     pub eligible_roles: String,
     pub status: String,
     pub created_at: Option<chrono::NaiveDateTime>,
@@ -29,6 +38,9 @@ pub struct NewProposal {
     pub start_time: chrono::NaiveDateTime,
     pub end_time: chrono::NaiveDateTime,
     pub voting_method: String,
+    pub ballot_type: String,
+    pub max_selections: Option<i32>,
+    pub secret_ballot: bool,
     pub eligible_roles: String,
     pub status: String,
 }
@@ -39,18 +51,80 @@ pub struct Vote {
     pub id: u64,
     pub proposal_id: u64,
     pub user_id: u64,
+    /// Apartment whose seat this vote is, on PerSeat proposals; `user_id` is then the
+    /// owner who cast it last
+    pub apartment_id: Option<u64>,
+    /// Proxy holder who cast the vote for `user_id`; `None` when voted in person
+    pub cast_by: Option<u64>,
+    pub proxy_id: Option<u64>,
     #[schema(value_type = String, example = "1.5")]
     pub weight_decimal: BigDecimal,
     pub choice: String,
     pub created_at: Option<chrono::NaiveDateTime>,
 }
 
+/// A vote cast for an apartment on a PerSeat proposal; the latest one per apartment
+/// is what `votes` holds
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = apartment_vote_history)]
+pub struct ApartmentVoteHistory {
+    pub id: u64,
+    pub proposal_id: u64,
+    pub apartment_id: u64,
+    pub user_id: u64,
+    pub cast_by: Option<u64>,
+    pub choice: String,
+    /// Selected option ids, comma separated, first preference first
+    pub option_ids: String,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+/// A delegation of `grantor_id`'s vote to `grantee_id`, either for one proposal or
+/// for every proposal of a building starting within `valid_from..=valid_until`
+#[derive(Queryable, Selectable, Serialize, Debug, Clone, ToSchema)]
+#[diesel(table_name = vote_proxies)]
+pub struct VoteProxy {
+    pub id: u64,
+    pub grantor_id: u64,
+    pub grantee_id: u64,
+    pub proposal_id: Option<u64>,
+    pub building_id: Option<u64>,
+    pub valid_from: Option<chrono::NaiveDateTime>,
+    pub valid_until: Option<chrono::NaiveDateTime>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub revoked_at: Option<chrono::NaiveDateTime>,
+}
+
+impl VoteProxy {
+    /// Whether this proxy, if not revoked, lets the grantee vote on `proposal`.
+    pub fn covers(&self, proposal: &Proposal) -> bool {
+        if self.revoked_at.is_some() {
+            return false;
+        }
+        match (self.proposal_id, self.building_id) {
+            (Some(id), _) => id == proposal.id,
+            (None, Some(building)) => {
+                proposal.building_id == Some(building)
+                    && self
+                        .valid_from
+                        .is_some_and(|from| proposal.start_time >= from)
+                    && self
+                        .valid_until
+                        .is_some_and(|until| proposal.start_time <= until)
+            }
+            (None, None) => false,
+        }
+    }
+}
+
 #[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
 #[diesel(table_name = proposal_results)]
 pub struct ProposalResult {
     pub id: u64,
     pub proposal_id: u64,
     pub passed: bool,
+    /// Winner of an option ballot
+    pub winning_option_id: Option<u64>,
     #[schema(value_type = String, example = "5.0")]
     pub yes_weight: BigDecimal,
     #[schema(value_type = String, example = "3.0")]
@@ -61,6 +135,105 @@ pub struct ProposalResult {
     pub total_weight: BigDecimal,
     pub tallied_at: Option<chrono::NaiveDateTime>,
     pub method_applied_version: String,
+    /// SHA-256 of the result and the counted ballots, taken at tally time
+    pub results_hash: Option<String>,
+}
+
+#[derive(Queryable, Selectable, Serialize, Debug, Clone, ToSchema)]
+#[diesel(table_name = proposal_options)]
+pub struct ProposalOption {
+    pub id: u64,
+    pub proposal_id: u64,
+    pub position: i32,
+    pub label: String,
+}
+
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = vote_selections)]
+pub struct VoteSelection {
+    pub id: u64,
+    pub vote_id: u64,
+    pub option_id: u64,
+    /// Preference order on ranked ballots, 1 being the first choice
+    pub rank: i32,
+}
+
+/// A ballot of a secret proposal, identified only by the receipt handed to the voter.
+/// The voter's `votes` row records participation but not this ballot, and the ballot
+/// holds no weight; see [`SecretBallotTotal`].
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = secret_ballots)]
+pub struct SecretBallot {
+    pub receipt: String,
+    pub proposal_id: u64,
+    pub choice: String,
+}
+
+/// Summed weight of the identical secret ballots of a proposal
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = secret_ballot_totals)]
+pub struct SecretBallotTotal {
+    pub proposal_id: u64,
+    pub choice: String,
+    /// Selected option ids, comma separated, first preference first
+    pub option_ids: String,
+    #[schema(value_type = String, example = "3.0")]
+    pub weight_total: BigDecimal,
+    /// Number of ballots behind the total
+    pub ballots: i32,
+}
+
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = secret_ballot_selections)]
+pub struct SecretBallotSelection {
+    pub receipt: String,
+    pub option_id: u64,
+    pub rank: i32,
+}
+
+/// One option's weight in one tally round
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = proposal_result_rounds)]
+pub struct ProposalResultRound {
+    pub id: u64,
+    pub result_id: u64,
+    pub round: i32,
+    pub option_id: u64,
+    #[schema(value_type = String, example = "4.0")]
+    pub weight: BigDecimal,
+    /// Whether the option dropped out at the end of this round
+    pub eliminated: bool,
+}
+
+/// A message in a proposal's discussion thread
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = proposal_comments)]
+pub struct ProposalComment {
+    pub id: u64,
+    pub proposal_id: u64,
+    pub user_id: u64,
+    pub body: String,
+    pub is_deleted: bool,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+/// Supporting document of a proposal (quote, drawing); same lifecycle as maintenance attachments.
+#[derive(Queryable, Selectable, Serialize, Debug, ToSchema)]
+#[diesel(table_name = proposal_attachments)]
+pub struct ProposalAttachment {
+    pub id: u64,
+    pub proposal_id: u64,
+    pub original_filename: String,
+    pub stored_filename: String,
+    pub mime_type: String,
+    pub size_bytes: u64,
+    pub checksum_sha256: Option<String>,
+    /// "pending" while quarantined for scanning, then "clean" or "infected"
+    pub scan_status: String,
+    pub scan_signature: Option<String>,
+    pub uploaded_by: u64,
+    pub is_deleted: bool,
+    pub created_at: Option<chrono::NaiveDateTime>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -128,6 +301,51 @@ impl std::str::FromStr for VoteChoice {
     }
 }
 
+/// What a ballot asks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub enum BallotType {
+    /// Yes / No / Abstain on the proposal itself
+    #[default]
+    YesNo,
+    /// One option out of several
+    SingleChoice,
+    /// Any number of options (up to `max_selections`)
+    Approval,
+    /// Options in order of preference, tallied by instant runoff
+    RankedChoice,
+}
+impl BallotType {
+    pub fn has_options(self) -> bool {
+        self != Self::YesNo
+    }
+}
+impl std::fmt::Display for BallotType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::YesNo => "YesNo",
+                Self::SingleChoice => "SingleChoice",
+                Self::Approval => "Approval",
+                Self::RankedChoice => "RankedChoice",
+            }
+        )
+    }
+}
+impl std::str::FromStr for BallotType {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "YesNo" => Self::YesNo,
+            "SingleChoice" => Self::SingleChoice,
+            "Approval" => Self::Approval,
+            "RankedChoice" => Self::RankedChoice,
+            _ => return Err(()),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub enum ProposalStatus {
     Scheduled,
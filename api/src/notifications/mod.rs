@@ -0,0 +1,181 @@
+//! In-app notifications.
+//!
+//! Features call [`notify`] when something happens that users should hear about; the
+//! frontend polls [`list_notifications`] for the menu in the navbar and words each
+//! message from its `kind` and `subject`.
+
+use actix_web::{HttpResponse, Responder, web};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::models::{Notification, NotificationKind};
+
+/// How many of the latest notifications the menu shows
+const LIST_LIMIT: i64 = 50;
+
+/// The caller's latest notifications
+#[derive(Serialize, ToSchema)]
+pub struct NotificationList {
+    pub unread_count: i64,
+    pub notifications: Vec<Notification>,
+}
+
+/// Notify every user in `user_ids` about `subject`.
+pub fn notify(
+    user_ids: &[u64],
+    kind: NotificationKind,
+    subject: &str,
+    link: Option<&str>,
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use crate::schema::notifications::dsl as n;
+
+    if user_ids.is_empty() {
+        return Ok(());
+    }
+    let rows: Vec<_> = user_ids
+        .iter()
+        .map(|user_id| {
+            (
+                n::user_id.eq(*user_id),
+                n::kind.eq(kind.to_string()),
+                n::subject.eq(subject),
+                n::link.eq(link),
+            )
+        })
+        .collect();
+    diesel::insert_into(n::notifications)
+        .values(rows)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// List notifications
+///
+/// Returns the caller's latest notifications, newest first, with the number of
+/// unread ones.
+#[utoipa::path(
+    get,
+    path = "/api/v1/notifications",
+    responses(
+        (status = 200, description = "Latest notifications", body = NotificationList),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Notifications",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_notifications(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::notifications::dsl as n;
+
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let unread_count = n::notifications
+        .filter(n::user_id.eq(user_id))
+        .filter(n::read_at.is_null())
+        .count()
+        .get_result(&mut conn)?;
+    let notifications = n::notifications
+        .filter(n::user_id.eq(user_id))
+        .order(n::id.desc())
+        .limit(LIST_LIMIT)
+        .select(Notification::as_select())
+        .load(&mut conn)?;
+
+    Ok(HttpResponse::Ok().json(NotificationList {
+        unread_count,
+        notifications,
+    }))
+}
+
+/// Mark a notification read
+#[utoipa::path(
+    post,
+    path = "/api/v1/notifications/{id}/read",
+    params(
+        ("id" = u64, Path, description = "Notification ID")
+    ),
+    responses(
+        (status = 204, description = "Notification marked read"),
+        (status = 404, description = "Notification not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Notifications",
+    security(("bearer_auth" = []))
+)]
+pub async fn mark_read(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::notifications::dsl as n;
+
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let notification: Notification = n::notifications
+        .filter(n::id.eq(path.into_inner()))
+        .filter(n::user_id.eq(user_id))
+        .select(Notification::as_select())
+        .first(&mut conn)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+    if notification.read_at.is_none() {
+        diesel::update(n::notifications.filter(n::id.eq(notification.id)))
+            .set(n::read_at.eq(Some(chrono::Local::now().naive_local())))
+            .execute(&mut conn)?;
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Mark all notifications read
+#[utoipa::path(
+    post,
+    path = "/api/v1/notifications/read-all",
+    responses(
+        (status = 204, description = "All notifications marked read"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Notifications",
+    security(("bearer_auth" = []))
+)]
+pub async fn mark_all_read(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::notifications::dsl as n;
+
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    diesel::update(
+        n::notifications
+            .filter(n::user_id.eq(user_id))
+            .filter(n::read_at.is_null()),
+    )
+    .set(n::read_at.eq(Some(chrono::Local::now().naive_local())))
+    .execute(&mut conn)?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/notifications", web::get().to(list_notifications))
+        .route("/notifications/read-all", web::post().to(mark_all_read))
+        .route("/notifications/{id}/read", web::post().to(mark_read));
+}
@@ -56,6 +56,46 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
         crate::voting::create_proposal,
         crate::voting::cast_vote,
         crate::voting::tally_results,
+        crate::voting::list_receipts,
+        crate::voting::get_audit_report,
+        crate::voting::get_seat_history,
+        crate::voting::grant_proxy,
+        crate::voting::my_proxies,
+        crate::voting::list_proxies,
+        crate::voting::revoke_proxy,
+        crate::voting::list_proposal_comments,
+        crate::voting::create_proposal_comment,
+        crate::voting::delete_proposal_comment,
+        crate::voting::upload_proposal_attachment,
+        crate::voting::list_proposal_attachments,
+        crate::voting::download_proposal_attachment,
+        crate::voting::delete_proposal_attachment,
+        crate::polls::list_polls,
+        crate::polls::get_poll,
+        crate::polls::create_poll,
+        crate::polls::submit_response,
+        crate::polls::close_poll,
+        crate::polls::poll_results,
+        crate::polls::export_poll_csv,
+
+        // Meetings
+        crate::meetings::list_meetings,
+        crate::meetings::get_meeting,
+        crate::meetings::create_meeting,
+        crate::meetings::update_meeting,
+        crate::meetings::delete_meeting,
+        crate::meetings::start_meeting,
+        crate::meetings::end_meeting,
+        crate::meetings::register_attendance,
+        crate::meetings::remove_attendance,
+        crate::meetings::open_agenda_item,
+        crate::meetings::close_agenda_item,
+        crate::meetings::get_minutes,
+        crate::meetings::publish_minutes,
+        // Notifications
+        crate::notifications::list_notifications,
+        crate::notifications::mark_read,
+        crate::notifications::mark_all_read,
 
         // Maintenance
         crate::maintenance::list_requests,
@@ -66,11 +106,18 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
         crate::maintenance::list_history,
         crate::maintenance::assign_request,
         crate::maintenance::unassign_request,
+        crate::maintenance::list_filter_presets,
+        crate::maintenance::save_filter_preset,
+        crate::maintenance::delete_filter_preset,
+        crate::maintenance::submit_feedback,
+        crate::maintenance::list_feedback,
+        crate::maintenance::feedback_summary,
         crate::maintenance::attachments::upload_attachment,
         crate::maintenance::attachments::list_attachments,
         crate::maintenance::attachments::list_deleted_attachments,
         crate::maintenance::attachments::get_attachment_metadata,
         crate::maintenance::attachments::download_attachment,
+        crate::maintenance::attachments::download_thumbnail,
         crate::maintenance::attachments::delete_attachment,
         crate::maintenance::attachments::restore_attachment,
 
@@ -85,12 +132,46 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
         crate::announcements::toggle_pin,
         crate::announcements::list_comments,
         crate::announcements::create_comment,
+        crate::announcements::list_mentionable,
+        crate::announcements::update_comment,
+        crate::announcements::toggle_reaction,
+        crate::announcements::report_comment,
+        crate::announcements::list_reports,
+        crate::announcements::hide_comment,
+        crate::announcements::unhide_comment,
+        crate::announcements::dismiss_reports,
         crate::announcements::delete_comment,
         crate::announcements::restore_comment,
         crate::announcements::list_deleted,
         crate::announcements::purge,
         crate::announcements::publish_now,
         crate::announcements::purge_comment,
+        crate::announcements::mark_read,
+        crate::announcements::acknowledge,
+        crate::announcements::receipt_report,
+        crate::announcements::announcement_analytics,
+        crate::announcements::engagement_summary,
+        crate::announcements::list_revisions,
+        crate::announcements::revision_diff,
+        crate::announcements::restore_revision,
+        crate::announcements::list_translations,
+        crate::announcements::list_templates,
+        crate::announcements::create_template,
+        crate::announcements::update_template,
+        crate::announcements::delete_template,
+        crate::announcements::apply_template,
+        crate::announcements::upload_attachment,
+        crate::announcements::delete_attachment,
+        crate::announcements::download_attachment,
+        crate::announcements::download_attachment_thumbnail,
+
+        // Feeds
+        crate::feeds::building_atom,
+        crate::feeds::private_atom,
+        crate::feeds::private_calendar,
+        crate::feeds::token_status,
+        crate::feeds::create_token,
+        crate::feeds::revoke_token,
 
         // Users
         crate::users::list_users,
@@ -117,9 +198,17 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
             crate::models::NewProposal,
             crate::models::Vote,
             crate::models::ProposalResult,
+            crate::models::ProposalOption,
+            crate::models::ProposalResultRound,
+            crate::models::VoteProxy,
+            crate::models::BallotType,
             crate::models::VotingMethod,
             crate::models::VoteChoice,
             crate::models::ProposalStatus,
+            crate::models::ProposalComment,
+            crate::models::ProposalAttachment,
+            crate::models::Notification,
+            crate::models::NotificationKind,
             crate::models::MaintenanceRequest,
             crate::models::NewMaintenanceRequest,
             crate::models::MaintenanceRequestAttachment,
@@ -131,22 +220,110 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
 
             // Voting-specific types
             crate::voting::ProposalWithVotes,
+            crate::voting::OptionWithVotes,
             crate::voting::CreateProposalPayload,
             crate::voting::CastVotePayload,
+            crate::voting::ProxyVoter,
+            crate::voting::SeatVote,
+            crate::voting::SeatHistoryEntry,
+            crate::voting::GrantProxyPayload,
+            crate::voting::ProxyWithNames,
+            crate::voting::MyProxies,
+            crate::voting::PublishedBallot,
+            crate::voting::ReportFormat,
+            crate::voting::CreateProposalCommentPayload,
+            crate::voting::ProposalCommentOut,
+            crate::notifications::NotificationList,
+
+            // Poll types
+            crate::models::Poll,
+            crate::models::PollOption,
+            crate::models::PollQuestionKind,
+            crate::models::PollResultsVisibility,
+            crate::models::PollStatus,
+            crate::polls::CreatePollPayload,
+            crate::polls::QuestionPayload,
+            crate::polls::PollSummary,
+            crate::polls::PollDetail,
+            crate::polls::QuestionOut,
+            crate::polls::SubmitResponsePayload,
+            crate::polls::AnswerPayload,
+            crate::polls::PollResults,
+            crate::polls::QuestionResults,
+            crate::polls::OptionCount,
+            crate::polls::RatingCount,
+            crate::polls::TextAnswer,
+
+            // Meeting types
+            crate::models::Meeting,
+            crate::models::MeetingAgendaItem,
+            crate::models::MeetingAttendance,
+            crate::models::MeetingStatus,
+            crate::models::AttendanceMode,
+            crate::meetings::CreateMeetingPayload,
+            crate::meetings::UpdateMeetingPayload,
+            crate::meetings::RegisterAttendancePayload,
+            crate::meetings::PublishMinutesPayload,
+            crate::meetings::MeetingSummary,
+            crate::meetings::MeetingDetail,
+            crate::meetings::AgendaItemOut,
+            crate::meetings::AttendeeOut,
+            crate::meetings::QuorumStatus,
+            crate::meetings::MinutesOut,
 
             // Maintenance-specific types
             crate::maintenance::MaintenanceRequestEnriched,
             crate::maintenance::StatusUpdatePayload,
             crate::maintenance::UpdateRequestPayload,
             crate::maintenance::AssignPayload,
+            crate::maintenance::RequestFilters,
+            crate::maintenance::FilterPresetOut,
+            crate::maintenance::FilterPresetPayload,
+            crate::maintenance::FeedbackPayload,
+            crate::maintenance::FeedbackAggregate,
+            crate::maintenance::FeedbackSummary,
+            crate::models::MaintenanceRequestFeedback,
 
             // Announcements types
             crate::models::AnnouncementComment,
+            crate::models::AnnouncementCommentReport,
             crate::announcements::CreateAnnouncementRequest,
             crate::announcements::UpdateAnnouncementRequest,
             crate::announcements::CreateCommentRequest,
             crate::announcements::AnnouncementOut,
+            crate::announcements::AudienceBuilding,
+            crate::announcements::AudienceApartment,
             crate::announcements::CommentOut,
+            crate::announcements::UpdateCommentRequest,
+            crate::announcements::ReactionRequest,
+            crate::announcements::ModerationReasonRequest,
+            crate::announcements::ReactionSummary,
+            crate::announcements::MentionOut,
+            crate::announcements::ReportOut,
+            crate::announcements::ReportedCommentOut,
+            crate::models::AnnouncementRead,
+            crate::announcements::ReceiptEntry,
+            crate::announcements::ReceiptReport,
+            crate::announcements::DailyReads,
+            crate::announcements::ReachGroup,
+            crate::announcements::AnnouncementAnalytics,
+            crate::announcements::AnnouncementEngagement,
+            crate::announcements::EngagementSummary,
+            crate::announcements::RevisionOut,
+            crate::announcements::RevisionDiff,
+            crate::announcements::DiffLine,
+            crate::announcements::DiffOp,
+            crate::announcements::AttachmentOut,
+            crate::announcements::LocalizedText,
+            crate::announcements::AnnouncementTranslationsOut,
+            crate::announcements::TemplateRequest,
+            crate::announcements::TemplateOut,
+            crate::announcements::ApplyTemplateRequest,
+            crate::announcements::TemplateDraftOut,
+
+            // Feeds types
+            crate::feeds::FeedTokenOut,
+            crate::feeds::FeedTokenStatus,
 
             // Users types
             crate::users::SetRolesRequest,
@@ -162,8 +339,12 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
         (name = "Buildings", description = "Building management (Admin/Manager)"),
         (name = "Apartments", description = "Apartment management"),
         (name = "Voting", description = "Proposals and voting system"),
+        (name = "Polls", description = "Resident polls and surveys"),
+        (name = "Meetings", description = "General assembly meetings, attendance and minutes"),
         (name = "Maintenance", description = "Maintenance request management"),
         (name = "Announcements", description = "Community announcements"),
+        (name = "Feeds", description = "Atom feeds and voting calendars"),
+        (name = "Notifications", description = "In-app notifications"),
         (name = "Users", description = "User management (Admin only)"),
     ),
     modifiers(&SecurityAddon)
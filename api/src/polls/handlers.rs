@@ -0,0 +1,501 @@
+use super::results::results_visible;
+use super::types::{
+    CreatePollPayload, PollDetail, PollSummary, QuestionOut, SubmitResponsePayload,
+};
+use super::validate::{validate_answers, validate_questions};
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::models::{NewPollAnswer, Poll, PollOption, PollQuestion, PollStatus};
+use crate::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::{HttpResponse, Responder, web};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub(super) fn now() -> chrono::NaiveDateTime {
+    chrono::Local::now().naive_local()
+}
+
+fn last_insert_id(conn: &mut MysqlConnection) -> Result<u64, AppError> {
+    Ok(diesel::select(diesel::dsl::sql::<
+        diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+    >("LAST_INSERT_ID()"))
+    .first(conn)?)
+}
+
+/// Load a poll the caller may see: global polls, and polls of the caller's buildings.
+pub(super) fn load_accessible(
+    id: u64,
+    auth: &AuthContext,
+    conn: &mut MysqlConnection,
+) -> Result<Poll, AppError> {
+    use crate::auth::get_user_building_ids;
+    use crate::schema::polls::dsl as p;
+
+    let poll: Poll = p::polls
+        .filter(p::id.eq(id))
+        .select(Poll::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+    if let Some(building_id) = poll.building_id {
+        let is_admin = auth.has_any_role(&["Admin"]);
+        if let Some(ids) = get_user_building_ids(auth.user_id()?, is_admin, conn)?
+            && !ids.contains(&building_id)
+        {
+            return Err(AppError::Forbidden);
+        }
+    }
+    Ok(poll)
+}
+
+/// A poll's questions in order, with their options in order.
+pub(super) fn load_questions(
+    poll_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<(Vec<PollQuestion>, Vec<PollOption>), AppError> {
+    use crate::schema::poll_options::dsl as o;
+    use crate::schema::poll_questions::dsl as q;
+
+    let questions: Vec<PollQuestion> = q::poll_questions
+        .filter(q::poll_id.eq(poll_id))
+        .order(q::position.asc())
+        .select(PollQuestion::as_select())
+        .load(conn)?;
+    let ids: Vec<u64> = questions.iter().map(|q| q.id).collect();
+    let options: Vec<PollOption> = o::poll_options
+        .filter(o::question_id.eq_any(&ids))
+        .order((o::question_id.asc(), o::position.asc()))
+        .select(PollOption::as_select())
+        .load(conn)?;
+    Ok((questions, options))
+}
+
+pub(super) fn has_responded(
+    poll_id: u64,
+    user_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<bool, AppError> {
+    use crate::schema::poll_participants::dsl as pp;
+    Ok(pp::poll_participants
+        .filter(pp::poll_id.eq(poll_id))
+        .filter(pp::user_id.eq(user_id))
+        .count()
+        .get_result::<i64>(conn)?
+        > 0)
+}
+
+/// List polls and surveys
+///
+/// Returns global polls and the polls of the caller's buildings, most recent first,
+/// with their status and whether the caller has responded.
+#[utoipa::path(
+    get,
+    path = "/api/v1/polls",
+    params(PaginationParams),
+    responses(
+        (status = 200, description = "Paginated list of polls", body = PaginatedResponse<PollSummary>),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Polls",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_polls(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+    query: web::Query<PaginationParams>,
+) -> Result<impl Responder, AppError> {
+    use crate::auth::get_user_building_ids;
+    use crate::schema::poll_participants::dsl as pp;
+    use crate::schema::poll_questions::dsl as q;
+    use crate::schema::polls::dsl as p;
+
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let user_id = auth.user_id()?;
+    let is_admin = auth.has_any_role(&["Admin"]);
+    let building_ids = get_user_building_ids(user_id, is_admin, &mut conn)?;
+
+    let mut count_query = p::polls.into_boxed();
+    if let Some(ref ids) = building_ids {
+        count_query = count_query.filter(p::building_id.eq_any(ids).or(p::building_id.is_null()));
+    }
+    let total = count_query.count().get_result::<i64>(&mut conn)?;
+
+    let mut data_query = p::polls.into_boxed();
+    if let Some(ref ids) = building_ids {
+        data_query = data_query.filter(p::building_id.eq_any(ids).or(p::building_id.is_null()));
+    }
+    let polls: Vec<Poll> = data_query
+        .select(Poll::as_select())
+        .order(p::created_at.desc())
+        .then_order_by(p::id.desc())
+        .limit(query.limit())
+        .offset(query.offset())
+        .load(&mut conn)?;
+
+    let ids: Vec<u64> = polls.iter().map(|p| p.id).collect();
+    let question_counts: HashMap<u64, i64> = q::poll_questions
+        .filter(q::poll_id.eq_any(&ids))
+        .group_by(q::poll_id)
+        .select((q::poll_id, diesel::dsl::count_star()))
+        .load::<(u64, i64)>(&mut conn)?
+        .into_iter()
+        .collect();
+    let response_counts: HashMap<u64, i64> = pp::poll_participants
+        .filter(pp::poll_id.eq_any(&ids))
+        .group_by(pp::poll_id)
+        .select((pp::poll_id, diesel::dsl::count_star()))
+        .load::<(u64, i64)>(&mut conn)?
+        .into_iter()
+        .collect();
+    let responded: Vec<u64> = pp::poll_participants
+        .filter(pp::poll_id.eq_any(&ids))
+        .filter(pp::user_id.eq(user_id))
+        .select(pp::poll_id)
+        .load(&mut conn)?;
+
+    let now = now();
+    let data = polls
+        .into_iter()
+        .map(|poll| PollSummary {
+            status: poll.status_at(now),
+            question_count: question_counts.get(&poll.id).copied().unwrap_or(0) as usize,
+            response_count: response_counts.get(&poll.id).copied().unwrap_or(0),
+            has_responded: responded.contains(&poll.id),
+            poll,
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(PaginatedResponse::new(data, total, &query)))
+}
+
+/// Get a poll with its questions
+///
+/// Includes whether the caller has responded, can still respond, and may see the results.
+#[utoipa::path(
+    get,
+    path = "/api/v1/polls/{id}",
+    params(
+        ("id" = u64, Path, description = "Poll ID")
+    ),
+    responses(
+        (status = 200, description = "Poll with questions", body = PollDetail),
+        (status = 403, description = "Forbidden - poll of another building"),
+        (status = 404, description = "Poll not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Polls",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_poll(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let poll = load_accessible(path.into_inner(), &auth, &mut conn)?;
+    Ok(HttpResponse::Ok().json(poll_detail(poll, &auth, &mut conn)?))
+}
+
+fn poll_detail(
+    poll: Poll,
+    auth: &AuthContext,
+    conn: &mut MysqlConnection,
+) -> Result<PollDetail, AppError> {
+    use crate::schema::poll_participants::dsl as pp;
+
+    let (questions, options) = load_questions(poll.id, conn)?;
+    let response_count = pp::poll_participants
+        .filter(pp::poll_id.eq(poll.id))
+        .count()
+        .get_result::<i64>(conn)?;
+    let has_responded = has_responded(poll.id, auth.user_id()?, conn)?;
+    let status = poll.status_at(now());
+    let can_view_results = results_visible(&poll, status, has_responded, auth);
+
+    let mut options_by_question: HashMap<u64, Vec<PollOption>> = HashMap::new();
+    for o in options {
+        options_by_question
+            .entry(o.question_id)
+            .or_default()
+            .push(o);
+    }
+    let questions = questions
+        .into_iter()
+        .map(|q| QuestionOut {
+            options: options_by_question.remove(&q.id).unwrap_or_default(),
+            id: q.id,
+            prompt: q.prompt,
+            kind: q.kind,
+            required: q.required,
+            rating_max: q.rating_max,
+        })
+        .collect();
+
+    Ok(PollDetail {
+        poll,
+        status,
+        questions,
+        response_count,
+        has_responded,
+        can_respond: status == PollStatus::Open && !has_responded,
+        can_view_results,
+    })
+}
+
+/// Create a poll or survey
+///
+/// Creates a poll with one or more single choice, multiple choice, free text or rating
+/// questions. Only Admin or Manager roles can create polls; managers only for their
+/// buildings.
+#[utoipa::path(
+    post,
+    path = "/api/v1/polls",
+    request_body = CreatePollPayload,
+    responses(
+        (status = 201, description = "Poll created", body = PollDetail),
+        (status = 400, description = "Invalid input (e.g., datetime format or questions)"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Polls",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_poll(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+    payload: web::Json<CreatePollPayload>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::poll_options::dsl as o;
+    use crate::schema::poll_questions::dsl as q;
+    use crate::schema::polls::dsl as p;
+
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+    let mut payload = payload.into_inner();
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let created_by = auth.user_id()?;
+
+    let title = payload.title.trim().to_string();
+    if title.is_empty() || title.chars().count() > 255 {
+        return Err(AppError::BadRequest(
+            "Title must be 1-255 characters".into(),
+        ));
+    }
+    let start_time = chrono::NaiveDateTime::parse_from_str(&payload.start_time, "%Y-%m-%dT%H:%M")
+        .map_err(|_| AppError::BadRequest("Invalid start_time format".into()))?;
+    let end_time = chrono::NaiveDateTime::parse_from_str(&payload.end_time, "%Y-%m-%dT%H:%M")
+        .map_err(|_| AppError::BadRequest("Invalid end_time format".into()))?;
+    if end_time <= start_time {
+        return Err(AppError::BadRequest(
+            "end_time must be after start_time".into(),
+        ));
+    }
+    validate_questions(&mut payload.questions).map_err(AppError::BadRequest)?;
+
+    if let Some(building_id) = payload.building_id {
+        use crate::auth::get_user_building_ids;
+        let is_admin = auth.has_any_role(&["Admin"]);
+        if let Some(buildings) = get_user_building_ids(created_by, is_admin, &mut conn)?
+            && !buildings.contains(&building_id)
+        {
+            return Err(AppError::Forbidden);
+        }
+    }
+
+    diesel::insert_into(p::polls)
+        .values((
+            p::title.eq(&title),
+            p::description.eq(payload.description.trim()),
+            p::created_by.eq(created_by),
+            p::building_id.eq(payload.building_id),
+            p::start_time.eq(start_time),
+            p::end_time.eq(end_time),
+            p::anonymous.eq(payload.anonymous),
+            p::results_visibility.eq(payload.results_visibility.to_string()),
+        ))
+        .execute(&mut conn)?;
+    let poll_id = last_insert_id(&mut conn)?;
+
+    for (position, question) in payload.questions.iter().enumerate() {
+        diesel::insert_into(q::poll_questions)
+            .values((
+                q::poll_id.eq(poll_id),
+                q::position.eq(position as i32),
+                q::prompt.eq(&question.prompt),
+                q::kind.eq(question.kind.to_string()),
+                q::required.eq(question.required),
+                q::rating_max.eq(question.rating_max),
+            ))
+            .execute(&mut conn)?;
+        let question_id = last_insert_id(&mut conn)?;
+        let rows: Vec<_> = question
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, label)| {
+                (
+                    o::question_id.eq(question_id),
+                    o::position.eq(i as i32),
+                    o::label.eq(label),
+                )
+            })
+            .collect();
+        if !rows.is_empty() {
+            diesel::insert_into(o::poll_options)
+                .values(&rows)
+                .execute(&mut conn)?;
+        }
+    }
+
+    let poll = load_accessible(poll_id, &auth, &mut conn)?;
+    Ok(HttpResponse::Created().json(poll_detail(poll, &auth, &mut conn)?))
+}
+
+/// Respond to a poll
+///
+/// Records the caller's answers to an open poll. Each resident responds once; answers
+/// cannot be changed afterwards. For anonymous polls the answers are stored without
+/// the respondent.
+#[utoipa::path(
+    post,
+    path = "/api/v1/polls/{id}/responses",
+    params(
+        ("id" = u64, Path, description = "Poll ID")
+    ),
+    request_body = SubmitResponsePayload,
+    responses(
+        (status = 201, description = "Response recorded"),
+        (status = 400, description = "Invalid answers, already responded, or poll not open"),
+        (status = 403, description = "Forbidden - poll of another building"),
+        (status = 404, description = "Poll not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Polls",
+    security(("bearer_auth" = []))
+)]
+pub async fn submit_response(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+    payload: web::Json<SubmitResponsePayload>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::poll_answers::dsl as pa;
+    use crate::schema::poll_participants::dsl as pp;
+    use crate::schema::poll_responses::dsl as pr;
+
+    let id = path.into_inner();
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let poll = load_accessible(id, &auth, &mut conn)?;
+    if poll.status_at(now()) != PollStatus::Open {
+        return Err(AppError::BadRequest("Poll is not open".into()));
+    }
+    if has_responded(id, user_id, &mut conn)? {
+        return Err(AppError::BadRequest(
+            "You have already responded to this poll".into(),
+        ));
+    }
+    let (questions, options) = load_questions(id, &mut conn)?;
+    let rows =
+        validate_answers(&questions, &options, &payload.answers).map_err(AppError::BadRequest)?;
+
+    let (respondent, submitted_at) = if poll.anonymous {
+        (None, None)
+    } else {
+        (Some(user_id), Some(chrono::Utc::now().naive_utc()))
+    };
+    // Random id: an auto-increment one would give away the order of anonymous responses
+    let response_id = Uuid::new_v4().to_string();
+    let answers: Vec<NewPollAnswer> = rows
+        .into_iter()
+        .map(
+            |(question_id, option_id, text_value, rating)| NewPollAnswer {
+                response_id: response_id.clone(),
+                question_id,
+                option_id,
+                text_value,
+                rating,
+            },
+        )
+        .collect();
+    conn.transaction(|conn| {
+        // The participant row guards against a second response (primary key)
+        diesel::insert_into(pp::poll_participants)
+            .values((pp::poll_id.eq(id), pp::user_id.eq(user_id)))
+            .execute(conn)?;
+        diesel::insert_into(pr::poll_responses)
+            .values((
+                pr::id.eq(&response_id),
+                pr::poll_id.eq(id),
+                pr::user_id.eq(respondent),
+                pr::submitted_at.eq(submitted_at),
+            ))
+            .execute(conn)?;
+        diesel::insert_into(pa::poll_answers)
+            .values(&answers)
+            .execute(conn)?;
+        Ok::<_, AppError>(())
+    })?;
+
+    Ok(HttpResponse::Created().json(serde_json::json!({ "success": true })))
+}
+
+/// Close a poll early
+///
+/// Ends an open or scheduled poll now. Only Admin or Manager roles can close polls.
+#[utoipa::path(
+    post,
+    path = "/api/v1/polls/{id}/close",
+    params(
+        ("id" = u64, Path, description = "Poll ID")
+    ),
+    responses(
+        (status = 200, description = "Poll closed", body = PollDetail),
+        (status = 400, description = "Poll is already closed"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Poll not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Polls",
+    security(("bearer_auth" = []))
+)]
+pub async fn close_poll(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::polls::dsl as p;
+
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+    let id = path.into_inner();
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let poll = load_accessible(id, &auth, &mut conn)?;
+    let now = now();
+    if poll.status_at(now) == PollStatus::Closed {
+        return Err(AppError::BadRequest("Poll is already closed".into()));
+    }
+    let start_time = poll.start_time.min(now);
+    diesel::update(p::polls.filter(p::id.eq(id)))
+        .set((p::start_time.eq(start_time), p::end_time.eq(now)))
+        .execute(&mut conn)?;
+
+    let poll = load_accessible(id, &auth, &mut conn)?;
+    Ok(HttpResponse::Ok().json(poll_detail(poll, &auth, &mut conn)?))
+}
@@ -0,0 +1,20 @@
+mod handlers;
+mod results;
+mod types;
+mod validate;
+
+pub use handlers::*;
+pub use results::*;
+pub use types::*;
+
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/polls", web::get().to(list_polls))
+        .route("/polls", web::post().to(create_poll))
+        .route("/polls/{id}", web::get().to(get_poll))
+        .route("/polls/{id}/responses", web::post().to(submit_response))
+        .route("/polls/{id}/results", web::get().to(poll_results))
+        .route("/polls/{id}/export", web::get().to(export_poll_csv))
+        .route("/polls/{id}/close", web::post().to(close_poll));
+}
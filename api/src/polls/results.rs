@@ -0,0 +1,374 @@
+use super::handlers::{has_responded, load_accessible, load_questions, now};
+use super::types::{OptionCount, PollResults, QuestionResults, RatingCount, TextAnswer};
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::export::csv_line;
+use crate::models::{
+    Poll, PollAnswer, PollOption, PollQuestion, PollResponse, PollResultsVisibility, PollStatus,
+};
+use actix_web::{HttpResponse, Responder, web};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Whether the caller may see a poll's results under its visibility setting.
+pub(super) fn results_visible(
+    poll: &Poll,
+    status: PollStatus,
+    has_responded: bool,
+    auth: &AuthContext,
+) -> bool {
+    if auth.has_any_role(&["Admin", "Manager"]) {
+        return true;
+    }
+    let closed = status == PollStatus::Closed;
+    match PollResultsVisibility::from_str(&poll.results_visibility) {
+        Ok(PollResultsVisibility::Always) => true,
+        Ok(PollResultsVisibility::AfterResponse) => has_responded || closed,
+        Ok(PollResultsVisibility::AfterClose) => closed,
+        Ok(PollResultsVisibility::Managers) | Err(_) => false,
+    }
+}
+
+/// Aggregate the answers per question. `respondent` names the author of a response,
+/// if it may be shown.
+fn tally(
+    questions: &[PollQuestion],
+    options: &[PollOption],
+    answers: &[PollAnswer],
+    respondent: impl Fn(&str) -> Option<String>,
+) -> Vec<QuestionResults> {
+    questions
+        .iter()
+        .map(|q| {
+            let own: Vec<&PollAnswer> = answers.iter().filter(|a| a.question_id == q.id).collect();
+            let mut responses: Vec<&str> = own.iter().map(|a| a.response_id.as_str()).collect();
+            responses.sort_unstable();
+            responses.dedup();
+
+            let option_counts = options
+                .iter()
+                .filter(|o| o.question_id == q.id)
+                .map(|o| OptionCount {
+                    option_id: o.id,
+                    label: o.label.clone(),
+                    count: own.iter().filter(|a| a.option_id == Some(o.id)).count(),
+                })
+                .collect();
+
+            let values: Vec<i32> = own.iter().filter_map(|a| a.rating).collect();
+            let ratings = match q.rating_max {
+                Some(max) => (1..=max)
+                    .map(|value| RatingCount {
+                        value,
+                        count: values.iter().filter(|v| **v == value).count(),
+                    })
+                    .collect(),
+                None => Vec::new(),
+            };
+            let average_rating = (!values.is_empty())
+                .then(|| values.iter().map(|v| *v as f64).sum::<f64>() / values.len() as f64);
+
+            let texts = own
+                .iter()
+                .filter_map(|a| {
+                    a.text_value.as_ref().map(|text| TextAnswer {
+                        text: text.clone(),
+                        respondent: respondent(&a.response_id),
+                    })
+                })
+                .collect();
+
+            QuestionResults {
+                question_id: q.id,
+                prompt: q.prompt.clone(),
+                kind: q.kind.clone(),
+                answered: responses.len(),
+                options: option_counts,
+                ratings,
+                average_rating,
+                texts,
+            }
+        })
+        .collect()
+}
+
+fn load_responses(
+    poll_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<(Vec<PollResponse>, Vec<PollAnswer>), AppError> {
+    use crate::schema::poll_answers::dsl as pa;
+    use crate::schema::poll_responses::dsl as pr;
+
+    // Anonymous responses have no submission time and a random id, so they come out
+    // in no particular order
+    let responses: Vec<PollResponse> = pr::poll_responses
+        .filter(pr::poll_id.eq(poll_id))
+        .order((pr::submitted_at.asc(), pr::id.asc()))
+        .select(PollResponse::as_select())
+        .load(conn)?;
+    let ids: Vec<&str> = responses.iter().map(|r| r.id.as_str()).collect();
+    let answers: Vec<PollAnswer> = pa::poll_answers
+        .filter(pa::response_id.eq_any(&ids))
+        .order(pa::id.asc())
+        .select(PollAnswer::as_select())
+        .load(conn)?;
+    Ok((responses, answers))
+}
+
+/// Names of the users behind the given responses.
+fn respondent_names(
+    responses: &[PollResponse],
+    conn: &mut MysqlConnection,
+) -> Result<HashMap<String, String>, AppError> {
+    use crate::schema::users::dsl as u;
+
+    let user_ids: Vec<u64> = responses.iter().filter_map(|r| r.user_id).collect();
+    let names: HashMap<u64, String> = u::users
+        .filter(u::id.eq_any(&user_ids))
+        .select((u::id, u::name))
+        .load::<(u64, String)>(conn)?
+        .into_iter()
+        .collect();
+    Ok(responses
+        .iter()
+        .filter_map(|r| Some((r.id.clone(), names.get(&r.user_id?)?.clone())))
+        .collect())
+}
+
+/// Get poll results
+///
+/// Returns option counts, rating distributions and averages, and free text answers
+/// per question. Visibility follows the poll's `results_visibility`; admins and
+/// managers can always see results and, for polls that are not anonymous, who wrote
+/// each free text answer.
+#[utoipa::path(
+    get,
+    path = "/api/v1/polls/{id}/results",
+    params(
+        ("id" = u64, Path, description = "Poll ID")
+    ),
+    responses(
+        (status = 200, description = "Aggregated results", body = PollResults),
+        (status = 403, description = "Forbidden - results not visible to the caller"),
+        (status = 404, description = "Poll not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Polls",
+    security(("bearer_auth" = []))
+)]
+pub async fn poll_results(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    let id = path.into_inner();
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let poll = load_accessible(id, &auth, &mut conn)?;
+    let status = poll.status_at(now());
+    let responded = has_responded(id, auth.user_id()?, &mut conn)?;
+    if !results_visible(&poll, status, responded, &auth) {
+        return Err(AppError::Forbidden);
+    }
+
+    let (questions, options) = load_questions(id, &mut conn)?;
+    let (responses, answers) = load_responses(id, &mut conn)?;
+    let names = if !poll.anonymous && auth.has_any_role(&["Admin", "Manager"]) {
+        respondent_names(&responses, &mut conn)?
+    } else {
+        HashMap::new()
+    };
+
+    Ok(HttpResponse::Ok().json(PollResults {
+        poll_id: id,
+        status,
+        response_count: responses.len(),
+        questions: tally(&questions, &options, &answers, |response_id| {
+            names.get(response_id).cloned()
+        }),
+    }))
+}
+
+/// Export poll responses as CSV
+///
+/// One row per response and one column per question; multiple choices are joined
+/// with "; ". Polls that are not anonymous also get the respondent and the time of
+/// the response. Only Admin or Manager roles can export.
+#[utoipa::path(
+    get,
+    path = "/api/v1/polls/{id}/export",
+    params(
+        ("id" = u64, Path, description = "Poll ID")
+    ),
+    responses(
+        (status = 200, description = "CSV export", content_type = "text/csv"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Poll not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Polls",
+    security(("bearer_auth" = []))
+)]
+pub async fn export_poll_csv(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+    let id = path.into_inner();
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let poll = load_accessible(id, &auth, &mut conn)?;
+    let (questions, options) = load_questions(id, &mut conn)?;
+    let (responses, answers) = load_responses(id, &mut conn)?;
+    let names = if poll.anonymous {
+        HashMap::new()
+    } else {
+        respondent_names(&responses, &mut conn)?
+    };
+    let labels: HashMap<u64, &str> = options.iter().map(|o| (o.id, o.label.as_str())).collect();
+
+    let mut header = vec!["Response".to_string()];
+    if !poll.anonymous {
+        header.push("Respondent".into());
+        header.push("Submitted at".into());
+    }
+    header.extend(questions.iter().map(|q| q.prompt.clone()));
+    let mut csv_content = csv_line(&header);
+
+    for (n, response) in responses.iter().enumerate() {
+        let mut row = vec![(n + 1).to_string()];
+        if !poll.anonymous {
+            row.push(names.get(&response.id).cloned().unwrap_or_default());
+            row.push(
+                response
+                    .submitted_at
+                    .map(|t| t.to_string())
+                    .unwrap_or_default(),
+            );
+        }
+        for q in &questions {
+            let cell = answers
+                .iter()
+                .filter(|a| a.response_id == response.id && a.question_id == q.id)
+                .map(|a| match (a.option_id, &a.text_value, a.rating) {
+                    (Some(option_id), _, _) => {
+                        labels.get(&option_id).copied().unwrap_or("").to_string()
+                    }
+                    (None, Some(text), _) => text.clone(),
+                    (None, None, Some(rating)) => rating.to_string(),
+                    (None, None, None) => String::new(),
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+            row.push(cell);
+        }
+        csv_content.push_str(&csv_line(&row));
+    }
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/csv")
+        .insert_header((
+            "Content-Disposition",
+            format!("attachment; filename=\"poll_{}_responses.csv\"", id),
+        ))
+        .body(csv_content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(
+        id: u64,
+        response_id: &str,
+        question_id: u64,
+        option_id: Option<u64>,
+        text: Option<&str>,
+        rating: Option<i32>,
+    ) -> PollAnswer {
+        PollAnswer {
+            id,
+            response_id: response_id.into(),
+            question_id,
+            option_id,
+            text_value: text.map(Into::into),
+            rating,
+        }
+    }
+
+    #[test]
+    fn tally_counts_options_ratings_and_texts() {
+        let questions = vec![
+            PollQuestion {
+                id: 1,
+                poll_id: 1,
+                position: 0,
+                prompt: "Days".into(),
+                kind: "MultipleChoice".into(),
+                required: true,
+                rating_max: None,
+            },
+            PollQuestion {
+                id: 2,
+                poll_id: 1,
+                position: 1,
+                prompt: "Rating".into(),
+                kind: "Rating".into(),
+                required: false,
+                rating_max: Some(3),
+            },
+            PollQuestion {
+                id: 3,
+                poll_id: 1,
+                position: 2,
+                prompt: "Comments".into(),
+                kind: "FreeText".into(),
+                required: false,
+                rating_max: None,
+            },
+        ];
+        let options = vec![
+            PollOption {
+                id: 10,
+                question_id: 1,
+                position: 0,
+                label: "Sat".into(),
+            },
+            PollOption {
+                id: 11,
+                question_id: 1,
+                position: 1,
+                label: "Sun".into(),
+            },
+        ];
+        let answers = vec![
+            answer(1, "r1", 1, Some(10), None, None),
+            answer(2, "r1", 1, Some(11), None, None),
+            answer(3, "r2", 1, Some(11), None, None),
+            answer(4, "r1", 2, None, None, Some(3)),
+            answer(5, "r2", 2, None, None, Some(2)),
+            answer(6, "r2", 3, None, Some("Bring gloves"), None),
+        ];
+        let results = tally(&questions, &options, &answers, |r| {
+            (r == "r2").then(|| "Jane".to_string())
+        });
+
+        assert_eq!(results[0].answered, 2);
+        let counts: Vec<usize> = results[0].options.iter().map(|o| o.count).collect();
+        assert_eq!(counts, vec![1, 2]);
+        let ratings: Vec<usize> = results[1].ratings.iter().map(|r| r.count).collect();
+        assert_eq!(ratings, vec![0, 1, 1]);
+        assert_eq!(results[1].average_rating, Some(2.5));
+        assert_eq!(results[2].texts[0].text, "Bring gloves");
+        assert_eq!(results[2].texts[0].respondent.as_deref(), Some("Jane"));
+    }
+}
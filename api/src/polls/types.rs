@@ -0,0 +1,138 @@
+use crate::models::{Poll, PollOption, PollQuestionKind, PollResultsVisibility, PollStatus};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A question of a new poll
+#[derive(Deserialize, ToSchema)]
+pub struct QuestionPayload {
+    pub prompt: String,
+    pub kind: PollQuestionKind,
+    #[serde(default = "default_required")]
+    pub required: bool,
+    /// Choices of SingleChoice and MultipleChoice questions, in display order
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// Highest value of a Rating question (1 to `rating_max`, default 5)
+    pub rating_max: Option<i32>,
+}
+
+fn default_required() -> bool {
+    true
+}
+
+fn default_visibility() -> PollResultsVisibility {
+    PollResultsVisibility::AfterResponse
+}
+
+/// Create a poll or survey (Admin/Manager only)
+#[derive(Deserialize, ToSchema)]
+pub struct CreatePollPayload {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    pub building_id: Option<u64>, // If None, the poll is open to all residents
+    #[schema(example = "2026-04-01T08:00")]
+    pub start_time: String, // ISO datetime string (YYYY-MM-DDTHH:MM)
+    #[schema(example = "2026-04-08T20:00")]
+    pub end_time: String, // ISO datetime string (YYYY-MM-DDTHH:MM)
+    /// Answers are stored without the respondent
+    #[serde(default)]
+    pub anonymous: bool,
+    #[serde(default = "default_visibility")]
+    pub results_visibility: PollResultsVisibility,
+    pub questions: Vec<QuestionPayload>,
+}
+
+/// A poll in the list
+#[derive(Serialize, ToSchema)]
+pub struct PollSummary {
+    #[serde(flatten)]
+    pub poll: Poll,
+    pub status: PollStatus,
+    pub question_count: usize,
+    pub response_count: i64,
+    pub has_responded: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct QuestionOut {
+    pub id: u64,
+    pub prompt: String,
+    pub kind: String,
+    pub required: bool,
+    pub rating_max: Option<i32>,
+    pub options: Vec<PollOption>,
+}
+
+/// A poll with its questions
+#[derive(Serialize, ToSchema)]
+pub struct PollDetail {
+    #[serde(flatten)]
+    pub poll: Poll,
+    pub status: PollStatus,
+    pub questions: Vec<QuestionOut>,
+    pub response_count: i64,
+    pub has_responded: bool,
+    pub can_respond: bool,
+    pub can_view_results: bool,
+}
+
+/// The answer to one question. Which field is used depends on the question kind.
+#[derive(Deserialize, ToSchema)]
+pub struct AnswerPayload {
+    pub question_id: u64,
+    /// One option for SingleChoice, any number for MultipleChoice
+    #[serde(default)]
+    pub option_ids: Vec<u64>,
+    pub text: Option<String>,
+    pub rating: Option<i32>,
+}
+
+/// Respond to a poll; a resident responds once
+#[derive(Deserialize, ToSchema)]
+pub struct SubmitResponsePayload {
+    pub answers: Vec<AnswerPayload>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct OptionCount {
+    pub option_id: u64,
+    pub label: String,
+    pub count: usize,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct RatingCount {
+    pub value: i32,
+    pub count: usize,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct TextAnswer {
+    pub text: String,
+    /// Respondent's name; only for admins and managers, and never for anonymous polls
+    pub respondent: Option<String>,
+}
+
+/// Aggregated answers to one question
+#[derive(Serialize, ToSchema)]
+pub struct QuestionResults {
+    pub question_id: u64,
+    pub prompt: String,
+    pub kind: String,
+    /// Responses that answered this question
+    pub answered: usize,
+    pub options: Vec<OptionCount>,
+    /// Count per value from 1 to `rating_max`
+    pub ratings: Vec<RatingCount>,
+    pub average_rating: Option<f64>,
+    pub texts: Vec<TextAnswer>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PollResults {
+    pub poll_id: u64,
+    pub status: PollStatus,
+    pub response_count: usize,
+    pub questions: Vec<QuestionResults>,
+}
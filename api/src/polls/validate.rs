@@ -0,0 +1,245 @@
+//! Checks on poll definitions and submitted answers that need no database.
+
+use super::types::{AnswerPayload, QuestionPayload};
+use crate::models::{PollOption, PollQuestion, PollQuestionKind};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub const MAX_QUESTIONS: usize = 50;
+pub const MAX_OPTIONS: usize = 30;
+pub const MAX_TEXT_LEN: usize = 5000;
+const DEFAULT_RATING_MAX: i32 = 5;
+
+/// Checks a new poll's questions and fills in the default rating scale.
+pub fn validate_questions(questions: &mut [QuestionPayload]) -> Result<(), String> {
+    if questions.is_empty() {
+        return Err("A poll needs at least one question".into());
+    }
+    if questions.len() > MAX_QUESTIONS {
+        return Err(format!("At most {} questions are allowed", MAX_QUESTIONS));
+    }
+    for (i, q) in questions.iter_mut().enumerate() {
+        let n = i + 1;
+        q.prompt = q.prompt.trim().to_string();
+        if q.prompt.is_empty() || q.prompt.chars().count() > 500 {
+            return Err(format!("Question {}: prompt must be 1-500 characters", n));
+        }
+        match q.kind {
+            PollQuestionKind::SingleChoice | PollQuestionKind::MultipleChoice => {
+                q.options = q.options.iter().map(|o| o.trim().to_string()).collect();
+                if q.options.len() < 2 || q.options.len() > MAX_OPTIONS {
+                    return Err(format!(
+                        "Question {}: between 2 and {} options are required",
+                        n, MAX_OPTIONS
+                    ));
+                }
+                if q.options
+                    .iter()
+                    .any(|o| o.is_empty() || o.chars().count() > 255)
+                {
+                    return Err(format!("Question {}: options must be 1-255 characters", n));
+                }
+                let unique: HashSet<&str> = q.options.iter().map(String::as_str).collect();
+                if unique.len() != q.options.len() {
+                    return Err(format!("Question {}: options must be unique", n));
+                }
+                q.rating_max = None;
+            }
+            PollQuestionKind::FreeText => {
+                q.options.clear();
+                q.rating_max = None;
+            }
+            PollQuestionKind::Rating => {
+                q.options.clear();
+                let max = q.rating_max.unwrap_or(DEFAULT_RATING_MAX);
+                if !(2..=10).contains(&max) {
+                    return Err(format!("Question {}: rating_max must be 2-10", n));
+                }
+                q.rating_max = Some(max);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// An answer ready to store: `(question_id, option_id, text, rating)`.
+pub type AnswerRow = (u64, Option<u64>, Option<String>, Option<i32>);
+
+/// Checks answers against the poll's questions, one answer per question at most.
+/// Blank text and empty choices count as unanswered.
+pub fn validate_answers(
+    questions: &[PollQuestion],
+    options: &[PollOption],
+    answers: &[AnswerPayload],
+) -> Result<Vec<AnswerRow>, String> {
+    let mut seen = HashSet::new();
+    let mut rows = Vec::new();
+    for a in answers {
+        let q = questions
+            .iter()
+            .find(|q| q.id == a.question_id)
+            .ok_or_else(|| format!("Unknown question {}", a.question_id))?;
+        if !seen.insert(q.id) {
+            return Err(format!("Question {} answered twice", q.id));
+        }
+        let kind = PollQuestionKind::from_str(&q.kind)
+            .map_err(|_| format!("Question {} has an invalid kind", q.id))?;
+        match kind {
+            PollQuestionKind::SingleChoice | PollQuestionKind::MultipleChoice => {
+                let mut chosen = HashSet::new();
+                for option_id in &a.option_ids {
+                    if !options
+                        .iter()
+                        .any(|o| o.id == *option_id && o.question_id == q.id)
+                    {
+                        return Err(format!("Question {}: unknown option {}", q.id, option_id));
+                    }
+                    chosen.insert(*option_id);
+                }
+                if kind == PollQuestionKind::SingleChoice && chosen.len() > 1 {
+                    return Err(format!("Question {}: choose one option", q.id));
+                }
+                let mut chosen: Vec<u64> = chosen.into_iter().collect();
+                chosen.sort_unstable();
+                rows.extend(chosen.into_iter().map(|o| (q.id, Some(o), None, None)));
+            }
+            PollQuestionKind::FreeText => {
+                let text = a.text.as_deref().unwrap_or("").trim();
+                if text.chars().count() > MAX_TEXT_LEN {
+                    return Err(format!(
+                        "Question {}: at most {} characters",
+                        q.id, MAX_TEXT_LEN
+                    ));
+                }
+                if !text.is_empty() {
+                    rows.push((q.id, None, Some(text.to_string()), None));
+                }
+            }
+            PollQuestionKind::Rating => {
+                if let Some(rating) = a.rating {
+                    let max = q.rating_max.unwrap_or(DEFAULT_RATING_MAX);
+                    if !(1..=max).contains(&rating) {
+                        return Err(format!("Question {}: rating must be 1-{}", q.id, max));
+                    }
+                    rows.push((q.id, None, None, Some(rating)));
+                }
+            }
+        }
+    }
+    if let Some(q) = questions
+        .iter()
+        .find(|q| q.required && !rows.iter().any(|r| r.0 == q.id))
+    {
+        return Err(format!("Question {} is required", q.id));
+    }
+    if rows.is_empty() {
+        return Err("The response has no answers".into());
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn question(id: u64, kind: &str, required: bool) -> PollQuestion {
+        PollQuestion {
+            id,
+            poll_id: 1,
+            position: id as i32,
+            prompt: format!("Q{}", id),
+            kind: kind.into(),
+            required,
+            rating_max: (kind == "Rating").then_some(5),
+        }
+    }
+
+    fn option(id: u64, question_id: u64) -> PollOption {
+        PollOption {
+            id,
+            question_id,
+            position: id as i32,
+            label: format!("O{}", id),
+        }
+    }
+
+    fn answer(question_id: u64, option_ids: &[u64]) -> AnswerPayload {
+        AnswerPayload {
+            question_id,
+            option_ids: option_ids.to_vec(),
+            text: None,
+            rating: None,
+        }
+    }
+
+    #[test]
+    fn choice_questions_need_distinct_options() {
+        let mut qs = vec![QuestionPayload {
+            prompt: " Which date? ".into(),
+            kind: PollQuestionKind::SingleChoice,
+            required: true,
+            options: vec!["Sat".into(), "Sat ".into()],
+            rating_max: None,
+        }];
+        assert!(validate_questions(&mut qs).is_err());
+        qs[0].options = vec!["Sat".into(), "Sun".into()];
+        assert!(validate_questions(&mut qs).is_ok());
+        assert_eq!(qs[0].prompt, "Which date?");
+    }
+
+    #[test]
+    fn rating_scale_defaults_and_is_bounded() {
+        let mut qs = vec![QuestionPayload {
+            prompt: "How satisfied are you?".into(),
+            kind: PollQuestionKind::Rating,
+            required: true,
+            options: vec![],
+            rating_max: None,
+        }];
+        validate_questions(&mut qs).unwrap();
+        assert_eq!(qs[0].rating_max, Some(5));
+        qs[0].rating_max = Some(11);
+        assert!(validate_questions(&mut qs).is_err());
+    }
+
+    #[test]
+    fn single_choice_accepts_one_option_of_its_own() {
+        let qs = [
+            question(1, "SingleChoice", true),
+            question(2, "SingleChoice", false),
+        ];
+        let opts = [option(10, 1), option(11, 1), option(20, 2)];
+        assert!(validate_answers(&qs, &opts, &[answer(1, &[10, 11])]).is_err());
+        assert!(validate_answers(&qs, &opts, &[answer(1, &[20])]).is_err());
+        let rows = validate_answers(&qs, &opts, &[answer(1, &[11])]).unwrap();
+        assert_eq!(rows, vec![(1, Some(11), None, None)]);
+    }
+
+    #[test]
+    fn required_questions_must_be_answered() {
+        let qs = [
+            question(1, "MultipleChoice", true),
+            question(2, "FreeText", false),
+        ];
+        let opts = [option(10, 1), option(11, 1)];
+        let mut text = answer(2, &[]);
+        text.text = Some("  ".into());
+        assert!(validate_answers(&qs, &opts, &[text]).is_err());
+        let rows = validate_answers(&qs, &opts, &[answer(1, &[11, 10, 11])]).unwrap();
+        assert_eq!(rows.len(), 2);
+    }
+
+    #[test]
+    fn ratings_stay_on_the_scale() {
+        let qs = [question(1, "Rating", true)];
+        let mut a = answer(1, &[]);
+        a.rating = Some(6);
+        assert!(validate_answers(&qs, &[], &[a]).is_err());
+        let mut a = answer(1, &[]);
+        a.rating = Some(4);
+        assert_eq!(
+            validate_answers(&qs, &[], &[a]).unwrap(),
+            vec![(1, None, None, Some(4))]
+        );
+    }
+}
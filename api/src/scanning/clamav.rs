@@ -0,0 +1,106 @@
+use super::{ScanError, ScanVerdict, Scanner};
+use actix_web::web;
+use async_trait::async_trait;
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// Chunk size for the INSTREAM command; well below clamd's default StreamMaxLength.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Client for a ClamAV daemon (`clamd`) reachable over TCP.
+#[derive(Clone, Debug)]
+pub struct ClamAvScanner {
+    address: String,
+    timeout: Duration,
+}
+
+impl ClamAvScanner {
+    pub fn new(address: impl Into<String>, timeout: Duration) -> Self {
+        Self {
+            address: address.into(),
+            timeout,
+        }
+    }
+}
+
+/// Send `data` with the INSTREAM command and return clamd's reply line.
+fn instream(address: &str, timeout: Duration, data: &[u8]) -> std::io::Result<String> {
+    let addr = address.to_socket_addrs()?.next().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::NotFound, "clamd address not resolved")
+    })?;
+    let mut stream = TcpStream::connect_timeout(&addr, timeout)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+
+    stream.write_all(b"zINSTREAM\0")?;
+    for chunk in data.chunks(CHUNK_SIZE) {
+        stream.write_all(&(chunk.len() as u32).to_be_bytes())?;
+        stream.write_all(chunk)?;
+    }
+    // A zero-length chunk terminates the stream
+    stream.write_all(&[0, 0, 0, 0])?;
+
+    let mut reply = Vec::new();
+    stream.read_to_end(&mut reply)?;
+    Ok(String::from_utf8_lossy(&reply)
+        .trim_end_matches('\0')
+        .trim()
+        .to_string())
+}
+
+/// Interpret a clamd reply such as `stream: OK` or `stream: Eicar-Signature FOUND`.
+fn parse_reply(reply: &str) -> Result<ScanVerdict, ScanError> {
+    let body = reply
+        .strip_prefix("stream:")
+        .map(str::trim)
+        .unwrap_or(reply);
+    if body == "OK" {
+        Ok(ScanVerdict::Clean)
+    } else if let Some(signature) = body.strip_suffix(" FOUND") {
+        Ok(ScanVerdict::Infected(signature.trim().to_string()))
+    } else {
+        Err(ScanError::Scanner(reply.to_string()))
+    }
+}
+
+#[async_trait]
+impl Scanner for ClamAvScanner {
+    fn name(&self) -> &'static str {
+        "clamav"
+    }
+
+    async fn scan(&self, data: &[u8]) -> Result<ScanVerdict, ScanError> {
+        let address = self.address.clone();
+        let timeout = self.timeout;
+        let data = data.to_vec();
+        let reply = web::block(move || instream(&address, timeout, &data))
+            .await
+            .map_err(|e| ScanError::Scanner(format!("blocking: {}", e)))??;
+        parse_reply(&reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_clean_reply() {
+        assert_eq!(parse_reply("stream: OK").unwrap(), ScanVerdict::Clean);
+    }
+
+    #[test]
+    fn parses_infected_reply() {
+        assert_eq!(
+            parse_reply("stream: Win.Test.EICAR_HDB-1 FOUND").unwrap(),
+            ScanVerdict::Infected("Win.Test.EICAR_HDB-1".into())
+        );
+    }
+
+    #[test]
+    fn errors_are_not_clean() {
+        assert!(parse_reply("INSTREAM size limit exceeded. ERROR").is_err());
+        assert!(parse_reply("").is_err());
+    }
+}
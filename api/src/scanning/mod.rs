@@ -0,0 +1,58 @@
+//! Malware scanning for uploaded files.
+//!
+//! Uploads are held in quarantine until a [`Scanner`] clears them. The backend is
+//! chosen at startup from `AppConfig::scanner`: a ClamAV daemon in production, or
+//! the no-op scanner for development and tests.
+
+mod clamav;
+mod noop;
+
+pub use clamav::ClamAvScanner;
+pub use noop::NoopScanner;
+
+use crate::auth::AppError;
+use crate::config::{AppConfig, ScannerBackend};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanVerdict {
+    Clean,
+    /// Name of the matched signature
+    Infected(String),
+}
+
+#[derive(Debug, Error)]
+pub enum ScanError {
+    #[error("scanner_unavailable: {0}")]
+    Unavailable(#[from] std::io::Error),
+    #[error("scanner_error: {0}")]
+    Scanner(String),
+}
+
+impl From<ScanError> for AppError {
+    fn from(e: ScanError) -> Self {
+        AppError::Internal(e.to_string())
+    }
+}
+
+#[async_trait]
+pub trait Scanner: Send + Sync {
+    /// Short backend name for logs.
+    fn name(&self) -> &'static str;
+
+    /// Scan a complete file. Errors mean the file could not be checked and must
+    /// not be released from quarantine.
+    async fn scan(&self, data: &[u8]) -> Result<ScanVerdict, ScanError>;
+}
+
+/// Build the scanner selected in the configuration.
+pub fn from_config(cfg: &AppConfig) -> Arc<dyn Scanner> {
+    match &cfg.scanner {
+        ScannerBackend::None => Arc::new(NoopScanner),
+        ScannerBackend::ClamAv { address, timeout } => {
+            Arc::new(ClamAvScanner::new(address.clone(), *timeout))
+        }
+    }
+}
@@ -16,15 +16,49 @@ diesel::table! {
         author_id -> Unsigned<Bigint>,
         public -> Bool,
         pinned -> Bool,
-        roles_csv -> Nullable<Text>,
-        building_id -> Nullable<Unsigned<Bigint>>,
-        apartment_id -> Nullable<Unsigned<Bigint>>,
         comments_enabled -> Bool,
+        requires_ack -> Bool,
         publish_at -> Nullable<Timestamp>,
         expire_at -> Nullable<Timestamp>,
         is_deleted -> Bool,
         created_at -> Nullable<Timestamp>,
         updated_at -> Nullable<Timestamp>,
+        #[max_length = 8]
+        language -> Varchar,
+    }
+}
+
+diesel::table! {
+    announcements_attachments (id) {
+        id -> Unsigned<Bigint>,
+        announcement_id -> Unsigned<Bigint>,
+        #[max_length = 255]
+        original_filename -> Varchar,
+        #[max_length = 255]
+        stored_filename -> Varchar,
+        #[max_length = 128]
+        mime_type -> Varchar,
+        size_bytes -> Unsigned<Bigint>,
+        #[max_length = 64]
+        checksum_sha256 -> Nullable<Char>,
+        has_thumbnail -> Bool,
+        #[max_length = 16]
+        scan_status -> Varchar,
+        #[max_length = 255]
+        scan_signature -> Nullable<Varchar>,
+        uploaded_by -> Unsigned<Bigint>,
+        is_deleted -> Bool,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    announcements_audience (id) {
+        id -> Unsigned<Bigint>,
+        announcement_id -> Unsigned<Bigint>,
+        role_id -> Nullable<Unsigned<Bigint>>,
+        building_id -> Nullable<Unsigned<Bigint>>,
+        apartment_id -> Nullable<Unsigned<Bigint>>,
     }
 }
 
@@ -37,6 +71,108 @@ diesel::table! {
         body_html -> Text,
         is_deleted -> Bool,
         created_at -> Nullable<Timestamp>,
+        parent_id -> Nullable<Unsigned<Bigint>>,
+        edited_at -> Nullable<Timestamp>,
+        is_hidden -> Bool,
+        #[max_length = 255]
+        hidden_reason -> Nullable<Varchar>,
+        hidden_by -> Nullable<Unsigned<Bigint>>,
+        hidden_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    announcements_comments_mentions (comment_id, user_id) {
+        comment_id -> Unsigned<Bigint>,
+        user_id -> Unsigned<Bigint>,
+    }
+}
+
+diesel::table! {
+    announcements_comments_reactions (comment_id, user_id, emoji) {
+        comment_id -> Unsigned<Bigint>,
+        user_id -> Unsigned<Bigint>,
+        #[max_length = 16]
+        emoji -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    announcements_comments_reports (id) {
+        id -> Unsigned<Bigint>,
+        comment_id -> Unsigned<Bigint>,
+        reporter_id -> Unsigned<Bigint>,
+        #[max_length = 500]
+        reason -> Varchar,
+        #[max_length = 16]
+        status -> Varchar,
+        resolved_by -> Nullable<Unsigned<Bigint>>,
+        resolved_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    announcements_reads (announcement_id, user_id) {
+        announcement_id -> Unsigned<Bigint>,
+        user_id -> Unsigned<Bigint>,
+        read_at -> Timestamp,
+        acknowledged_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    announcements_revisions (id) {
+        id -> Unsigned<Bigint>,
+        announcement_id -> Unsigned<Bigint>,
+        revision -> Unsigned<Integer>,
+        #[max_length = 255]
+        title -> Varchar,
+        body_md -> Text,
+        editor_id -> Unsigned<Bigint>,
+        restored_from -> Nullable<Unsigned<Integer>>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    announcements_templates (id) {
+        id -> Unsigned<Bigint>,
+        #[max_length = 120]
+        name -> Varchar,
+        #[max_length = 8]
+        language -> Varchar,
+        #[max_length = 255]
+        title -> Varchar,
+        body_md -> Text,
+        created_by -> Unsigned<Bigint>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    announcements_templates_translations (template_id, language) {
+        template_id -> Unsigned<Bigint>,
+        #[max_length = 8]
+        language -> Varchar,
+        #[max_length = 255]
+        title -> Varchar,
+        body_md -> Text,
+    }
+}
+
+diesel::table! {
+    announcements_translations (announcement_id, language) {
+        announcement_id -> Unsigned<Bigint>,
+        #[max_length = 8]
+        language -> Varchar,
+        #[max_length = 255]
+        title -> Varchar,
+        body_md -> Text,
+        body_html -> Text,
+        updated_at -> Timestamp,
     }
 }
 
@@ -59,6 +195,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    apartment_vote_history (id) {
+        id -> Unsigned<Bigint>,
+        proposal_id -> Unsigned<Bigint>,
+        apartment_id -> Unsigned<Bigint>,
+        user_id -> Unsigned<Bigint>,
+        cast_by -> Nullable<Unsigned<Bigint>>,
+        #[max_length = 16]
+        choice -> Varchar,
+        #[max_length = 255]
+        option_ids -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     apartments (id) {
         id -> Unsigned<Bigint>,
@@ -92,6 +243,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    feed_tokens (user_id) {
+        user_id -> Unsigned<Bigint>,
+        #[max_length = 64]
+        token_hash -> Char,
+        created_at -> Timestamp,
+        last_used_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    maintenance_filter_presets (id) {
+        id -> Unsigned<Bigint>,
+        user_id -> Unsigned<Bigint>,
+        #[max_length = 128]
+        name -> Varchar,
+        filters_json -> Text,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     maintenance_request_attachments (id) {
         id -> Unsigned<Bigint>,
@@ -103,6 +275,13 @@ diesel::table! {
         #[max_length = 128]
         mime_type -> Varchar,
         size_bytes -> Unsigned<Bigint>,
+        #[max_length = 64]
+        checksum_sha256 -> Nullable<Char>,
+        has_thumbnail -> Bool,
+        #[max_length = 16]
+        scan_status -> Varchar,
+        #[max_length = 255]
+        scan_signature -> Nullable<Varchar>,
         is_deleted -> Bool,
         created_at -> Nullable<Timestamp>,
     }
@@ -120,6 +299,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    maintenance_request_feedback (id) {
+        id -> Unsigned<Bigint>,
+        request_id -> Unsigned<Bigint>,
+        user_id -> Unsigned<Bigint>,
+        rating -> Unsigned<Tinyint>,
+        is_fixed -> Bool,
+        comment -> Nullable<Text>,
+        assigned_to -> Nullable<Unsigned<Bigint>>,
+        #[max_length = 128]
+        vendor -> Nullable<Varchar>,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     maintenance_request_history (id) {
         id -> Unsigned<Bigint>,
@@ -140,6 +334,8 @@ diesel::table! {
         apartment_id -> Unsigned<Bigint>,
         created_by -> Unsigned<Bigint>,
         assigned_to -> Nullable<Unsigned<Bigint>>,
+        #[max_length = 128]
+        vendor -> Nullable<Varchar>,
         #[max_length = 32]
         request_type -> Varchar,
         #[max_length = 16]
@@ -155,6 +351,103 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    meeting_agenda_items (meeting_id, proposal_id) {
+        meeting_id -> Unsigned<Bigint>,
+        proposal_id -> Unsigned<Bigint>,
+        position -> Integer,
+        opened_at -> Nullable<Datetime>,
+        closed_at -> Nullable<Datetime>,
+    }
+}
+
+diesel::table! {
+    meeting_attendance (meeting_id, user_id) {
+        meeting_id -> Unsigned<Bigint>,
+        user_id -> Unsigned<Bigint>,
+        #[max_length = 16]
+        mode -> Varchar,
+        proxy_holder_id -> Nullable<Unsigned<Bigint>>,
+        registered_by -> Unsigned<Bigint>,
+        registered_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    meetings (id) {
+        id -> Unsigned<Bigint>,
+        building_id -> Unsigned<Bigint>,
+        #[max_length = 255]
+        title -> Varchar,
+        description -> Text,
+        scheduled_at -> Datetime,
+        #[max_length = 255]
+        location -> Varchar,
+        #[max_length = 500]
+        online_url -> Nullable<Varchar>,
+        quorum_percent -> Integer,
+        #[max_length = 16]
+        status -> Varchar,
+        started_at -> Nullable<Datetime>,
+        ended_at -> Nullable<Datetime>,
+        minutes_md -> Nullable<Text>,
+        minutes_announcement_id -> Nullable<Unsigned<Bigint>>,
+        created_by -> Unsigned<Bigint>,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    meter_reading_campaigns (id) {
+        id -> Unsigned<Bigint>,
+        building_id -> Unsigned<Bigint>,
+        #[max_length = 255]
+        name -> Varchar,
+        start_date -> Date,
+        deadline -> Date,
+        #[max_length = 16]
+        status -> Varchar,
+        created_by -> Unsigned<Bigint>,
+        last_reminded_at -> Nullable<Datetime>,
+        closed_at -> Nullable<Datetime>,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    meter_reading_submissions (id) {
+        id -> Unsigned<Bigint>,
+        meter_id -> Unsigned<Bigint>,
+        submitted_by -> Unsigned<Bigint>,
+        reading_value -> Decimal,
+        reading_timestamp -> Datetime,
+        #[max_length = 16]
+        unit -> Varchar,
+        previous_value -> Nullable<Decimal>,
+        previous_timestamp -> Nullable<Datetime>,
+        #[max_length = 255]
+        photo_original_filename -> Varchar,
+        #[max_length = 255]
+        photo_stored_filename -> Varchar,
+        #[max_length = 128]
+        photo_mime_type -> Varchar,
+        photo_size_bytes -> Unsigned<Bigint>,
+        #[max_length = 64]
+        photo_checksum_sha256 -> Nullable<Char>,
+        photo_has_thumbnail -> Bool,
+        #[max_length = 16]
+        scan_status -> Varchar,
+        #[max_length = 16]
+        status -> Varchar,
+        #[max_length = 500]
+        review_note -> Nullable<Varchar>,
+        reviewed_by -> Nullable<Unsigned<Bigint>>,
+        reviewed_at -> Nullable<Datetime>,
+        reading_id -> Nullable<Unsigned<Bigint>>,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     meter_readings (id) {
         id -> Unsigned<Bigint>,
@@ -186,6 +479,91 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    notifications (id) {
+        id -> Unsigned<Bigint>,
+        user_id -> Unsigned<Bigint>,
+        #[max_length = 32]
+        kind -> Varchar,
+        #[max_length = 255]
+        subject -> Varchar,
+        #[max_length = 255]
+        link -> Nullable<Varchar>,
+        read_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    poll_answers (id) {
+        id -> Unsigned<Bigint>,
+        #[max_length = 36]
+        response_id -> Char,
+        question_id -> Unsigned<Bigint>,
+        option_id -> Nullable<Unsigned<Bigint>>,
+        text_value -> Nullable<Text>,
+        rating -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    poll_options (id) {
+        id -> Unsigned<Bigint>,
+        question_id -> Unsigned<Bigint>,
+        position -> Integer,
+        #[max_length = 255]
+        label -> Varchar,
+    }
+}
+
+diesel::table! {
+    poll_participants (poll_id, user_id) {
+        poll_id -> Unsigned<Bigint>,
+        user_id -> Unsigned<Bigint>,
+    }
+}
+
+diesel::table! {
+    poll_questions (id) {
+        id -> Unsigned<Bigint>,
+        poll_id -> Unsigned<Bigint>,
+        position -> Integer,
+        #[max_length = 500]
+        prompt -> Varchar,
+        #[max_length = 16]
+        kind -> Varchar,
+        required -> Bool,
+        rating_max -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    poll_responses (id) {
+        #[max_length = 36]
+        id -> Char,
+        poll_id -> Unsigned<Bigint>,
+        user_id -> Nullable<Unsigned<Bigint>>,
+        submitted_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    polls (id) {
+        id -> Unsigned<Bigint>,
+        #[max_length = 255]
+        title -> Varchar,
+        description -> Text,
+        created_by -> Unsigned<Bigint>,
+        building_id -> Nullable<Unsigned<Bigint>>,
+        start_time -> Datetime,
+        end_time -> Datetime,
+        anonymous -> Bool,
+        #[max_length = 16]
+        results_visibility -> Varchar,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     property_history (id) {
         id -> Unsigned<Bigint>,
@@ -200,11 +578,67 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    proposal_attachments (id) {
+        id -> Unsigned<Bigint>,
+        proposal_id -> Unsigned<Bigint>,
+        #[max_length = 255]
+        original_filename -> Varchar,
+        #[max_length = 255]
+        stored_filename -> Varchar,
+        #[max_length = 128]
+        mime_type -> Varchar,
+        size_bytes -> Unsigned<Bigint>,
+        #[max_length = 64]
+        checksum_sha256 -> Nullable<Char>,
+        #[max_length = 16]
+        scan_status -> Varchar,
+        #[max_length = 255]
+        scan_signature -> Nullable<Varchar>,
+        uploaded_by -> Unsigned<Bigint>,
+        is_deleted -> Bool,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    proposal_comments (id) {
+        id -> Unsigned<Bigint>,
+        proposal_id -> Unsigned<Bigint>,
+        user_id -> Unsigned<Bigint>,
+        body -> Text,
+        is_deleted -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    proposal_options (id) {
+        id -> Unsigned<Bigint>,
+        proposal_id -> Unsigned<Bigint>,
+        position -> Integer,
+        #[max_length = 255]
+        label -> Varchar,
+    }
+}
+
+diesel::table! {
+    proposal_result_rounds (id) {
+        id -> Unsigned<Bigint>,
+        result_id -> Unsigned<Bigint>,
+        round -> Integer,
+        option_id -> Unsigned<Bigint>,
+        weight -> Decimal,
+        eliminated -> Bool,
+    }
+}
+
 diesel::table! {
     proposal_results (id) {
         id -> Unsigned<Bigint>,
         proposal_id -> Unsigned<Bigint>,
         passed -> Bool,
+        winning_option_id -> Nullable<Unsigned<Bigint>>,
         yes_weight -> Decimal,
         no_weight -> Decimal,
         abstain_weight -> Decimal,
@@ -212,6 +646,8 @@ diesel::table! {
         tallied_at -> Nullable<Timestamp>,
         #[max_length = 16]
         method_applied_version -> Varchar,
+        #[max_length = 64]
+        results_hash -> Nullable<Char>,
     }
 }
 
@@ -227,6 +663,10 @@ diesel::table! {
         end_time -> Datetime,
         #[max_length = 32]
         voting_method -> Varchar,
+        #[max_length = 16]
+        ballot_type -> Varchar,
+        max_selections -> Nullable<Integer>,
+        secret_ballot -> Bool,
         #[max_length = 255]
         eligible_roles -> Varchar,
         #[max_length = 16]
@@ -235,6 +675,37 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    secret_ballot_selections (receipt, option_id) {
+        #[max_length = 14]
+        receipt -> Char,
+        option_id -> Unsigned<Bigint>,
+        rank -> Integer,
+    }
+}
+
+diesel::table! {
+    secret_ballot_totals (proposal_id, choice, option_ids) {
+        proposal_id -> Unsigned<Bigint>,
+        #[max_length = 16]
+        choice -> Varchar,
+        #[max_length = 640]
+        option_ids -> Varchar,
+        weight_total -> Decimal,
+        ballots -> Integer,
+    }
+}
+
+diesel::table! {
+    secret_ballots (receipt) {
+        #[max_length = 14]
+        receipt -> Char,
+        proposal_id -> Unsigned<Bigint>,
+        #[max_length = 16]
+        choice -> Varchar,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::RenterInvitationsStatusEnum;
@@ -285,15 +756,57 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    vote_proxies (id) {
+        id -> Unsigned<Bigint>,
+        grantor_id -> Unsigned<Bigint>,
+        grantee_id -> Unsigned<Bigint>,
+        proposal_id -> Nullable<Unsigned<Bigint>>,
+        building_id -> Nullable<Unsigned<Bigint>>,
+        valid_from -> Nullable<Datetime>,
+        valid_until -> Nullable<Datetime>,
+        created_at -> Nullable<Timestamp>,
+        revoked_at -> Nullable<Datetime>,
+    }
+}
+
+diesel::table! {
+    vote_selections (id) {
+        id -> Unsigned<Bigint>,
+        vote_id -> Unsigned<Bigint>,
+        option_id -> Unsigned<Bigint>,
+        rank -> Integer,
+    }
+}
+
 diesel::table! {
     votes (id) {
         id -> Unsigned<Bigint>,
         proposal_id -> Unsigned<Bigint>,
         user_id -> Unsigned<Bigint>,
+        apartment_id -> Nullable<Unsigned<Bigint>>,
+        cast_by -> Nullable<Unsigned<Bigint>>,
+        proxy_id -> Nullable<Unsigned<Bigint>>,
         weight_decimal -> Decimal,
         #[max_length = 16]
         choice -> Varchar,
         created_at -> Nullable<Timestamp>,
+        #[max_length = 24]
+        seat_key -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    webhook_api_key_buildings (api_key_id, building_id) {
+        api_key_id -> Unsigned<Bigint>,
+        building_id -> Unsigned<Bigint>,
+    }
+}
+
+diesel::table! {
+    webhook_api_key_meters (api_key_id, meter_id) {
+        api_key_id -> Unsigned<Bigint>,
+        meter_id -> Unsigned<Bigint>,
     }
 }
 
@@ -302,60 +815,171 @@ diesel::table! {
         id -> Unsigned<Bigint>,
         #[max_length = 128]
         name -> Varchar,
+        #[max_length = 16]
+        key_prefix -> Nullable<Varchar>,
         #[max_length = 255]
         api_key_hash -> Varchar,
         is_active -> Bool,
+        expires_at -> Nullable<Datetime>,
+        rate_limit_per_minute -> Nullable<Unsigned<Integer>>,
+        scoped -> Bool,
         created_by -> Unsigned<Bigint>,
         created_at -> Nullable<Timestamp>,
         last_used_at -> Nullable<Timestamp>,
+        usage_count -> Unsigned<Bigint>,
+        #[max_length = 255]
+        last_error -> Nullable<Varchar>,
+        last_error_at -> Nullable<Datetime>,
+        rate_window_start -> Nullable<Datetime>,
+        rate_window_count -> Unsigned<Integer>,
+        rotated_from_id -> Nullable<Unsigned<Bigint>>,
     }
 }
 
-diesel::joinable!(announcements -> apartments (apartment_id));
-diesel::joinable!(announcements -> buildings (building_id));
 diesel::joinable!(announcements -> users (author_id));
+diesel::joinable!(announcements_attachments -> announcements (announcement_id));
+diesel::joinable!(announcements_attachments -> users (uploaded_by));
+diesel::joinable!(announcements_audience -> announcements (announcement_id));
+diesel::joinable!(announcements_audience -> apartments (apartment_id));
+diesel::joinable!(announcements_audience -> buildings (building_id));
+diesel::joinable!(announcements_audience -> roles (role_id));
 diesel::joinable!(announcements_comments -> announcements (announcement_id));
 diesel::joinable!(announcements_comments -> users (user_id));
+diesel::joinable!(announcements_comments_mentions -> announcements_comments (comment_id));
+diesel::joinable!(announcements_comments_mentions -> users (user_id));
+diesel::joinable!(announcements_comments_reactions -> announcements_comments (comment_id));
+diesel::joinable!(announcements_comments_reactions -> users (user_id));
+diesel::joinable!(announcements_comments_reports -> announcements_comments (comment_id));
+diesel::joinable!(announcements_reads -> announcements (announcement_id));
+diesel::joinable!(announcements_reads -> users (user_id));
+diesel::joinable!(announcements_revisions -> announcements (announcement_id));
+diesel::joinable!(announcements_revisions -> users (editor_id));
+diesel::joinable!(announcements_templates -> users (created_by));
+diesel::joinable!(announcements_templates_translations -> announcements_templates (template_id));
+diesel::joinable!(announcements_translations -> announcements (announcement_id));
 diesel::joinable!(apartment_owners -> apartments (apartment_id));
 diesel::joinable!(apartment_owners -> users (user_id));
+diesel::joinable!(apartment_vote_history -> apartments (apartment_id));
+diesel::joinable!(apartment_vote_history -> proposals (proposal_id));
 diesel::joinable!(apartments -> buildings (building_id));
+diesel::joinable!(feed_tokens -> users (user_id));
+diesel::joinable!(maintenance_filter_presets -> users (user_id));
 diesel::joinable!(maintenance_request_attachments -> maintenance_requests (request_id));
 diesel::joinable!(maintenance_request_comments -> maintenance_requests (request_id));
 diesel::joinable!(maintenance_request_comments -> users (user_id));
+diesel::joinable!(maintenance_request_feedback -> maintenance_requests (request_id));
 diesel::joinable!(maintenance_request_history -> maintenance_requests (request_id));
 diesel::joinable!(maintenance_request_history -> users (changed_by));
 diesel::joinable!(maintenance_requests -> apartments (apartment_id));
+diesel::joinable!(meeting_agenda_items -> meetings (meeting_id));
+diesel::joinable!(meeting_agenda_items -> proposals (proposal_id));
+diesel::joinable!(meeting_attendance -> meetings (meeting_id));
+diesel::joinable!(meetings -> announcements (minutes_announcement_id));
+diesel::joinable!(meetings -> buildings (building_id));
+diesel::joinable!(meter_reading_campaigns -> buildings (building_id));
+diesel::joinable!(meter_reading_campaigns -> users (created_by));
+diesel::joinable!(meter_reading_submissions -> meter_readings (reading_id));
+diesel::joinable!(meter_reading_submissions -> meters (meter_id));
 diesel::joinable!(meter_readings -> meters (meter_id));
 diesel::joinable!(meters -> apartments (apartment_id));
+diesel::joinable!(notifications -> users (user_id));
+diesel::joinable!(poll_answers -> poll_options (option_id));
+diesel::joinable!(poll_answers -> poll_questions (question_id));
+diesel::joinable!(poll_answers -> poll_responses (response_id));
+diesel::joinable!(poll_options -> poll_questions (question_id));
+diesel::joinable!(poll_participants -> polls (poll_id));
+diesel::joinable!(poll_participants -> users (user_id));
+diesel::joinable!(poll_questions -> polls (poll_id));
+diesel::joinable!(poll_responses -> polls (poll_id));
+diesel::joinable!(poll_responses -> users (user_id));
+diesel::joinable!(polls -> buildings (building_id));
+diesel::joinable!(polls -> users (created_by));
 diesel::joinable!(property_history -> users (changed_by));
+diesel::joinable!(proposal_attachments -> proposals (proposal_id));
+diesel::joinable!(proposal_attachments -> users (uploaded_by));
+diesel::joinable!(proposal_comments -> proposals (proposal_id));
+diesel::joinable!(proposal_comments -> users (user_id));
+diesel::joinable!(proposal_options -> proposals (proposal_id));
+diesel::joinable!(proposal_result_rounds -> proposal_options (option_id));
+diesel::joinable!(proposal_result_rounds -> proposal_results (result_id));
 diesel::joinable!(proposal_results -> proposals (proposal_id));
 diesel::joinable!(proposals -> buildings (building_id));
 diesel::joinable!(proposals -> users (created_by));
+diesel::joinable!(secret_ballot_selections -> proposal_options (option_id));
+diesel::joinable!(secret_ballot_selections -> secret_ballots (receipt));
+diesel::joinable!(secret_ballot_totals -> proposals (proposal_id));
+diesel::joinable!(secret_ballots -> proposals (proposal_id));
+diesel::joinable!(vote_proxies -> buildings (building_id));
+diesel::joinable!(vote_proxies -> proposals (proposal_id));
+diesel::joinable!(vote_selections -> proposal_options (option_id));
+diesel::joinable!(vote_selections -> votes (vote_id));
+diesel::joinable!(votes -> apartments (apartment_id));
 diesel::joinable!(votes -> proposals (proposal_id));
 diesel::joinable!(votes -> users (user_id));
+diesel::joinable!(webhook_api_key_buildings -> buildings (building_id));
+diesel::joinable!(webhook_api_key_buildings -> webhook_api_keys (api_key_id));
+diesel::joinable!(webhook_api_key_meters -> meters (meter_id));
+diesel::joinable!(webhook_api_key_meters -> webhook_api_keys (api_key_id));
 diesel::joinable!(webhook_api_keys -> users (created_by));
 
 diesel::allow_tables_to_appear_in_same_query!(
     announcements,
+    announcements_attachments,
+    announcements_audience,
     announcements_comments,
+    announcements_comments_mentions,
+    announcements_comments_reactions,
+    announcements_comments_reports,
+    announcements_reads,
+    announcements_revisions,
+    announcements_templates,
+    announcements_templates_translations,
+    announcements_translations,
     apartment_owners,
     apartment_renters,
+    apartment_vote_history,
     apartments,
     building_managers,
     buildings,
+    feed_tokens,
+    maintenance_filter_presets,
     maintenance_request_attachments,
     maintenance_request_comments,
+    maintenance_request_feedback,
     maintenance_request_history,
     maintenance_requests,
+    meeting_agenda_items,
+    meeting_attendance,
+    meetings,
+    meter_reading_campaigns,
+    meter_reading_submissions,
     meter_readings,
     meters,
+    notifications,
+    poll_answers,
+    poll_options,
+    poll_participants,
+    poll_questions,
+    poll_responses,
+    polls,
     property_history,
+    proposal_attachments,
+    proposal_comments,
+    proposal_options,
+    proposal_result_rounds,
     proposal_results,
     proposals,
     renter_invitations,
     roles,
+    secret_ballot_selections,
+    secret_ballot_totals,
+    secret_ballots,
     user_roles,
     users,
+    vote_proxies,
+    vote_selections,
     votes,
+    webhook_api_key_buildings,
+    webhook_api_key_meters,
     webhook_api_keys,
 );
@@ -0,0 +1,95 @@
+use super::{Storage, StorageError, StorageResult, validate_key};
+use actix_web::web;
+use async_trait::async_trait;
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Files on the local filesystem under a base directory.
+#[derive(Clone, Debug)]
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_for(&self, key: &str) -> StorageResult<PathBuf> {
+        validate_key(key)?;
+        Ok(self.root.join(key))
+    }
+}
+
+/// Run blocking filesystem work off the async workers.
+async fn blocking<T, F>(f: F) -> StorageResult<T>
+where
+    F: FnOnce() -> std::io::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    web::block(f)
+        .await
+        .map_err(|e| StorageError::Backend(format!("blocking: {}", e)))?
+        .map_err(|e| match e.kind() {
+            ErrorKind::NotFound => StorageError::NotFound,
+            _ => StorageError::Io(e),
+        })
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>) -> StorageResult<()> {
+        let path = self.path_for(key)?;
+        blocking(move || {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            // Write to a temp file first so readers never see a partial object
+            let tmp = path.with_file_name(format!("{}.tmp", Uuid::new_v4()));
+            let mut f = std::fs::File::create(&tmp)?;
+            f.write_all(&data)?;
+            f.sync_all()?;
+            std::fs::rename(&tmp, &path)
+        })
+        .await
+    }
+
+    async fn get(&self, key: &str) -> StorageResult<Vec<u8>> {
+        let path = self.path_for(key)?;
+        blocking(move || std::fs::read(path)).await
+    }
+
+    async fn get_range(&self, key: &str, offset: u64, length: u64) -> StorageResult<Vec<u8>> {
+        let path = self.path_for(key)?;
+        blocking(move || {
+            let mut f = std::fs::File::open(path)?;
+            f.seek(SeekFrom::Start(offset))?;
+            let mut buf = Vec::new();
+            f.take(length).read_to_end(&mut buf)?;
+            Ok(buf)
+        })
+        .await
+    }
+
+    async fn exists(&self, key: &str) -> StorageResult<bool> {
+        let path = self.path_for(key)?;
+        blocking(move || path.try_exists()).await
+    }
+
+    async fn delete(&self, key: &str) -> StorageResult<()> {
+        let path = self.path_for(key)?;
+        match blocking(move || std::fs::remove_file(path)).await {
+            Err(StorageError::NotFound) => Ok(()),
+            other => other,
+        }
+    }
+
+    fn local_path(&self, key: &str) -> Option<PathBuf> {
+        self.path_for(key).ok()
+    }
+}
@@ -0,0 +1,143 @@
+//! Pluggable blob storage for uploaded files.
+//!
+//! Handlers only deal with keys (e.g. `"{request_id}/{stored_filename}"`); where the
+//! bytes live is decided once at startup from `AppConfig::storage`. The local backend
+//! keeps the historical on-disk layout under `attachments_base_path`, so switching
+//! backends is a matter of copying keys with the `migrate-storage` command.
+
+mod local;
+mod s3;
+
+pub use local::LocalStorage;
+pub use s3::S3Storage;
+
+use crate::auth::AppError;
+use crate::config::{AppConfig, StorageBackend};
+use actix_web::web::Bytes;
+use async_trait::async_trait;
+use futures_util::Stream;
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum StorageError {
+    #[error("storage_not_found")]
+    NotFound,
+    #[error("storage_io: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("storage_backend: {0}")]
+    Backend(String),
+    #[error("storage_config: {0}")]
+    Config(String),
+}
+
+impl From<StorageError> for AppError {
+    fn from(e: StorageError) -> Self {
+        match e {
+            StorageError::NotFound => AppError::NotFound,
+            other => AppError::Internal(other.to_string()),
+        }
+    }
+}
+
+pub type StorageResult<T> = Result<T, StorageError>;
+
+/// Object content delivered in chunks.
+pub type ByteStream = Pin<Box<dyn Stream<Item = StorageResult<Bytes>> + Send>>;
+
+/// A flat key/value blob store.
+///
+/// Keys are `/`-separated relative paths; implementations must reject keys that
+/// could escape their root (see [`validate_key`]).
+#[async_trait]
+pub trait Storage: Send + Sync {
+    /// Short backend name for logs and the migration command.
+    fn name(&self) -> &'static str;
+
+    /// Store `data` under `key`, replacing any existing object.
+    async fn put(&self, key: &str, data: Vec<u8>) -> StorageResult<()>;
+
+    /// Read the whole object.
+    async fn get(&self, key: &str) -> StorageResult<Vec<u8>>;
+
+    /// Read the whole object as a stream, so it can be served without buffering it.
+    async fn get_stream(&self, key: &str) -> StorageResult<ByteStream> {
+        let data = self.get(key).await?;
+        Ok(Box::pin(futures_util::stream::once(async move {
+            Ok(Bytes::from(data))
+        })))
+    }
+
+    /// Read `length` bytes starting at `offset`.
+    async fn get_range(&self, key: &str, offset: u64, length: u64) -> StorageResult<Vec<u8>> {
+        let data = self.get(key).await?;
+        let start = (offset as usize).min(data.len());
+        let end = start.saturating_add(length as usize).min(data.len());
+        Ok(data[start..end].to_vec())
+    }
+
+    async fn exists(&self, key: &str) -> StorageResult<bool>;
+
+    /// Remove the object; deleting a missing key is not an error.
+    async fn delete(&self, key: &str) -> StorageResult<()>;
+
+    /// Filesystem path of the object, if the backend keeps it on local disk.
+    /// Lets handlers stream files directly instead of buffering them.
+    fn local_path(&self, _key: &str) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// Build the backend selected in the configuration.
+pub fn from_config(cfg: &AppConfig) -> StorageResult<Arc<dyn Storage>> {
+    match &cfg.storage {
+        StorageBackend::Local => Ok(Arc::new(LocalStorage::new(&cfg.attachments_base_path))),
+        StorageBackend::S3(s3_cfg) => Ok(Arc::new(S3Storage::new(s3_cfg)?)),
+    }
+}
+
+/// Reject empty keys, absolute paths and `.`/`..` segments.
+pub fn validate_key(key: &str) -> StorageResult<()> {
+    let bad = key.is_empty()
+        || key.starts_with('/')
+        || key.contains('\\')
+        || key
+            .split('/')
+            .any(|seg| seg.is_empty() || seg == "." || seg == "..");
+    if bad {
+        return Err(StorageError::Backend(format!("invalid_key: {}", key)));
+    }
+    Ok(())
+}
+
+/// Hex-encoded SHA-256, as stored in `checksum_sha256` columns.
+pub fn sha256_hex(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_key_rejects_traversal() {
+        assert!(validate_key("12/abc").is_ok());
+        assert!(validate_key("12/abc.thumb.jpg").is_ok());
+        assert!(validate_key("").is_err());
+        assert!(validate_key("/etc/passwd").is_err());
+        assert!(validate_key("12/../../etc").is_err());
+        assert!(validate_key("12//abc").is_err());
+        assert!(validate_key("12\\abc").is_err());
+    }
+
+    #[test]
+    fn sha256_hex_matches_known_digest() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use super::{ByteStream, Storage, StorageError, StorageResult, validate_key};
+use crate::config::S3Config;
+use async_trait::async_trait;
+use futures_util::TryStreamExt;
+use s3::creds::Credentials;
+use s3::error::S3Error;
+use s3::{Bucket, Region};
+
+/// Objects in an S3-compatible bucket (AWS, MinIO, Ceph RGW, ...).
+pub struct S3Storage {
+    bucket: Box<Bucket>,
+    prefix: String,
+}
+
+impl S3Storage {
+    pub fn new(cfg: &S3Config) -> StorageResult<Self> {
+        let region = match &cfg.endpoint {
+            Some(endpoint) => Region::Custom {
+                region: cfg.region.clone(),
+                endpoint: endpoint.clone(),
+            },
+            None => cfg
+                .region
+                .parse()
+                .map_err(|e| StorageError::Config(format!("s3_region: {}", e)))?,
+        };
+        let credentials = Credentials::new(
+            Some(&cfg.access_key),
+            Some(&cfg.secret_key),
+            None,
+            None,
+            None,
+        )
+        .map_err(|e| StorageError::Config(format!("s3_credentials: {}", e)))?;
+        let mut bucket = Bucket::new(&cfg.bucket, region, credentials)
+            .map_err(|e| StorageError::Config(format!("s3_bucket: {}", e)))?;
+        if cfg.path_style {
+            // MinIO and most self-hosted stores don't do virtual-host buckets
+            bucket = bucket.with_path_style();
+        }
+        Ok(Self {
+            bucket,
+            prefix: cfg.prefix.trim_matches('/').to_string(),
+        })
+    }
+
+    fn object_key(&self, key: &str) -> StorageResult<String> {
+        validate_key(key)?;
+        Ok(if self.prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}/{}", self.prefix, key)
+        })
+    }
+}
+
+fn map_err(e: S3Error) -> StorageError {
+    match e {
+        S3Error::HttpFailWithBody(404, _) => StorageError::NotFound,
+        other => StorageError::Backend(other.to_string()),
+    }
+}
+
+#[async_trait]
+impl Storage for S3Storage {
+    fn name(&self) -> &'static str {
+        "s3"
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>) -> StorageResult<()> {
+        let key = self.object_key(key)?;
+        self.bucket.put_object(key, &data).await.map_err(map_err)?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> StorageResult<Vec<u8>> {
+        let key = self.object_key(key)?;
+        let resp = self.bucket.get_object(key).await.map_err(map_err)?;
+        Ok(resp.bytes().to_vec())
+    }
+
+    async fn get_stream(&self, key: &str) -> StorageResult<ByteStream> {
+        let key = self.object_key(key)?;
+        let resp = self.bucket.get_object_stream(key).await.map_err(map_err)?;
+        Ok(Box::pin(resp.bytes.map_err(map_err)))
+    }
+
+    async fn get_range(&self, key: &str, offset: u64, length: u64) -> StorageResult<Vec<u8>> {
+        if length == 0 {
+            return Ok(Vec::new());
+        }
+        let key = self.object_key(key)?;
+        // S3 ranges are inclusive on both ends
+        let resp = self
+            .bucket
+            .get_object_range(key, offset, Some(offset + length - 1))
+            .await
+            .map_err(map_err)?;
+        Ok(resp.bytes().to_vec())
+    }
+
+    async fn exists(&self, key: &str) -> StorageResult<bool> {
+        let key = self.object_key(key)?;
+        match self.bucket.head_object(key).await.map_err(map_err) {
+            Ok(_) => Ok(true),
+            Err(StorageError::NotFound) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> StorageResult<()> {
+        let key = self.object_key(key)?;
+        match self.bucket.delete_object(key).await.map_err(map_err) {
+            Ok(_) | Err(StorageError::NotFound) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Image post-processing for uploaded attachments.
+//!
+//! Uploaded photos are re-encoded so that EXIF/XMP metadata (camera details, GPS
+//! location) never reaches disk, and a small JPEG thumbnail is produced for list views.
+
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader, ImageResult};
+use std::io::Cursor;
+
+/// Longest edge of generated thumbnails, in pixels.
+pub const THUMBNAIL_MAX_EDGE: u32 = 320;
+
+const JPEG_QUALITY: u8 = 88;
+const THUMBNAIL_QUALITY: u8 = 80;
+
+/// Suffix appended to an attachment's stored filename for its thumbnail.
+pub const THUMBNAIL_SUFFIX: &str = ".thumb.jpg";
+
+pub struct ProcessedImage {
+    /// Re-encoded image without metadata, in the original format
+    pub data: Vec<u8>,
+    /// JPEG thumbnail no larger than `THUMBNAIL_MAX_EDGE` on either side
+    pub thumbnail: Vec<u8>,
+}
+
+/// Whether `process_image` handles the given (sniffed) MIME type.
+pub fn is_processable(mime: &str) -> bool {
+    matches!(mime, "image/jpeg" | "image/png")
+}
+
+/// Strip metadata from a JPEG/PNG upload and build its thumbnail.
+///
+/// The EXIF orientation is applied to the pixels before re-encoding, so photos
+/// taken in portrait keep displaying upright once the tag is gone.
+pub fn process_image(bytes: &[u8], mime: &str) -> ImageResult<ProcessedImage> {
+    let format = if mime == "image/png" {
+        ImageFormat::Png
+    } else {
+        ImageFormat::Jpeg
+    };
+
+    let mut decoder = ImageReader::with_format(Cursor::new(bytes), format).into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let mut img = DynamicImage::from_decoder(decoder)?;
+    img.apply_orientation(orientation);
+
+    let data = match format {
+        ImageFormat::Png => {
+            let mut out = Vec::new();
+            img.write_to(&mut Cursor::new(&mut out), ImageFormat::Png)?;
+            out
+        }
+        _ => encode_jpeg(&img, JPEG_QUALITY)?,
+    };
+    let thumbnail = encode_jpeg(
+        &img.thumbnail(THUMBNAIL_MAX_EDGE, THUMBNAIL_MAX_EDGE),
+        THUMBNAIL_QUALITY,
+    )?;
+
+    Ok(ProcessedImage { data, thumbnail })
+}
+
+fn encode_jpeg(img: &DynamicImage, quality: u8) -> ImageResult<Vec<u8>> {
+    let mut out = Vec::new();
+    // JPEG has no alpha channel; flatten to RGB first.
+    DynamicImage::ImageRgb8(img.to_rgb8())
+        .write_with_encoder(JpegEncoder::new_with_quality(&mut out, quality))?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, RgbImage};
+
+    fn sample_jpeg(width: u32, height: u32) -> Vec<u8> {
+        let img = DynamicImage::ImageRgb8(RgbImage::new(width, height));
+        encode_jpeg(&img, 90).unwrap()
+    }
+
+    /// Insert an APP1 EXIF segment carrying a fake GPS marker right after SOI.
+    fn with_exif(jpeg: &[u8]) -> Vec<u8> {
+        let payload = b"Exif\0\0MM\0*\0\0\0\x08GPSLatitude";
+        let len = (payload.len() + 2) as u16;
+        let mut out = jpeg[..2].to_vec();
+        out.extend_from_slice(&[0xFF, 0xE1]);
+        out.extend_from_slice(&len.to_be_bytes());
+        out.extend_from_slice(payload);
+        out.extend_from_slice(&jpeg[2..]);
+        out
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|w| w == needle)
+    }
+
+    #[test]
+    fn strips_exif_from_jpeg() {
+        let input = with_exif(&sample_jpeg(64, 48));
+        assert!(contains(&input, b"GPSLatitude"));
+
+        let out = process_image(&input, "image/jpeg").unwrap();
+        assert!(!contains(&out.data, b"Exif"));
+        assert!(!contains(&out.data, b"GPSLatitude"));
+        assert_eq!(infer::get(&out.data).unwrap().mime_type(), "image/jpeg");
+    }
+
+    #[test]
+    fn thumbnail_fits_bounds_and_keeps_aspect() {
+        let out = process_image(&sample_jpeg(1600, 800), "image/jpeg").unwrap();
+        let thumb = image::load_from_memory(&out.thumbnail).unwrap();
+        assert_eq!(
+            thumb.dimensions(),
+            (THUMBNAIL_MAX_EDGE, THUMBNAIL_MAX_EDGE / 2)
+        );
+    }
+
+    #[test]
+    fn png_stays_png() {
+        let mut png = Vec::new();
+        DynamicImage::ImageRgb8(RgbImage::new(10, 10))
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+        let out = process_image(&png, "image/png").unwrap();
+        assert_eq!(infer::get(&out.data).unwrap().mime_type(), "image/png");
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert!(process_image(b"not an image", "image/jpeg").is_err());
+    }
+}
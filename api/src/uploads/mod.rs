@@ -0,0 +1,335 @@
+//! Validated upload pipeline shared by every feature that accepts files.
+//!
+//! [`receive`] reads the multipart `file` field within `max_attachment_size_bytes` and
+//! checks the MIME type sniffed from the content against `allowed_mime_types`; short
+//! text fields sent before the file are kept alongside it. The
+//! caller then hands it to [`store_scanned`], which keeps the raw bytes in quarantine
+//! behind a pending row and calls [`release`] to scan them for malware and re-encode
+//! JPEG/PNG images without metadata. Only a [`Released::Clean`] upload is stored under
+//! its final key.
+
+pub mod images;
+
+use crate::auth::AppError;
+use crate::config::AppConfig;
+use crate::scanning::{ScanVerdict, Scanner};
+use crate::storage::{Storage, sha256_hex};
+use actix_files::{HttpRange, NamedFile};
+use actix_multipart::Multipart;
+use actix_web::http::header::{
+    ACCEPT_RANGES, CACHE_CONTROL, CONTENT_RANGE, ContentDisposition, HeaderValue, RANGE,
+};
+use actix_web::{HttpRequest, HttpResponse, mime, web};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use futures_util::StreamExt;
+use std::collections::HashMap;
+
+/// Limit on each text field sent along with a file
+const MAX_FIELD_BYTES: usize = 1024;
+
+/// Upload stored in quarantine and waiting for the malware scan.
+pub const SCAN_PENDING: &str = "pending";
+/// Scanned and released; the only state visible through the API.
+pub const SCAN_CLEAN: &str = "clean";
+/// Rejected by the scanner; the content is deleted and the row kept for audit.
+pub const SCAN_INFECTED: &str = "infected";
+
+/// An upload that passed the size and MIME checks but has not been scanned yet.
+pub struct Upload {
+    /// Client-supplied name with path separators replaced
+    pub original_filename: String,
+    /// MIME type sniffed from the content
+    pub mime: &'static str,
+    pub data: Vec<u8>,
+    /// Text fields that preceded the file, by name
+    pub fields: HashMap<String, String>,
+}
+
+/// Final content of an upload cleared by the scanner.
+pub struct CleanUpload {
+    /// Content to store; images are re-encoded without metadata
+    pub data: Vec<u8>,
+    /// JPEG thumbnail for images
+    pub thumbnail: Option<Vec<u8>>,
+    pub checksum_sha256: String,
+}
+
+pub enum Released {
+    Clean(CleanUpload),
+    /// Name of the matched signature
+    Infected(String),
+}
+
+/// Storage keys of one upload.
+pub struct UploadKeys {
+    /// Where the raw bytes wait for the scan
+    pub quarantine: String,
+    pub object: String,
+    /// `None` if the feature keeps no image thumbnails
+    pub thumbnail: Option<String>,
+}
+
+/// What was stored for a released upload.
+pub struct StoredUpload {
+    pub size_bytes: u64,
+    pub checksum_sha256: String,
+    pub has_thumbnail: bool,
+}
+
+/// How a pending row ends, as passed to the update closure of [`store_scanned`].
+pub enum ScanOutcome {
+    /// Stored under its final key; the row becomes `SCAN_CLEAN`
+    Clean(StoredUpload),
+    /// Rejected with the named signature; the row becomes `SCAN_INFECTED`
+    Infected(String),
+    /// Could not be scanned or decoded; the row is deleted
+    Discarded,
+}
+
+/// Read the first `file` field of a multipart body and validate it.
+///
+/// Other fields up to the file are collected as text; forms carrying values with a
+/// file must append them first.
+pub async fn receive(payload: &mut Multipart, cfg: &AppConfig) -> Result<Upload, AppError> {
+    let mut data: Vec<u8> = Vec::new();
+    let mut original_filename = None;
+    let mut fields = HashMap::new();
+    while let Some(item) = payload.next().await {
+        let mut field = item.map_err(|e| AppError::Internal(format!("multipart: {}", e)))?;
+        if field.name() != "file" {
+            let name = field.name().to_string();
+            let mut value: Vec<u8> = Vec::new();
+            while let Some(chunk_res) = field.next().await {
+                let chunk = chunk_res.map_err(|e| AppError::Internal(format!("chunk: {}", e)))?;
+                value.extend_from_slice(&chunk);
+                if value.len() > MAX_FIELD_BYTES {
+                    return Err(AppError::BadRequest(format!("{} is too long", name)));
+                }
+            }
+            if let Ok(value) = String::from_utf8(value) {
+                fields.insert(name, value);
+            }
+            continue;
+        }
+        if let Some(fname) = field.content_disposition().get_filename() {
+            original_filename = Some(fname.to_string());
+        }
+        while let Some(chunk_res) = field.next().await {
+            let chunk = chunk_res.map_err(|e| AppError::Internal(format!("chunk: {}", e)))?;
+            data.extend_from_slice(&chunk);
+            if data.len() as u64 > cfg.max_attachment_size_bytes {
+                return Err(AppError::AttachmentTooLarge);
+            }
+        }
+        break; // only first file
+    }
+    if data.is_empty() {
+        return Err(AppError::BadRequest("no_file".into()));
+    }
+    let mime = infer::get(&data)
+        .map(|t| t.mime_type())
+        .unwrap_or("application/octet-stream");
+    if !cfg.allowed_mime_types.iter().any(|m| m == mime) {
+        return Err(AppError::InvalidMimeType);
+    }
+    Ok(Upload {
+        original_filename: sanitize_filename(original_filename.as_deref().unwrap_or("upload.bin")),
+        mime,
+        data,
+        fields,
+    })
+}
+
+/// Scan a quarantined upload and prepare its final content.
+///
+/// Errors mean the file could not be scanned or decoded; it must then be
+/// discarded, never released.
+pub async fn release(
+    data: Vec<u8>,
+    mime: &'static str,
+    scanner: &dyn Scanner,
+) -> Result<Released, AppError> {
+    if let ScanVerdict::Infected(signature) = scanner.scan(&data).await? {
+        return Ok(Released::Infected(signature));
+    }
+
+    // Photos are re-encoded to drop EXIF (incl. GPS) and get a thumbnail; decoding
+    // is CPU-bound so keep it off the async workers.
+    let (data, thumbnail) = if images::is_processable(mime) {
+        let processed = web::block(move || images::process_image(&data, mime))
+            .await
+            .map_err(|e| AppError::Internal(format!("image_block: {}", e)))?
+            .map_err(|_| AppError::BadRequest("invalid_image".into()))?;
+        (processed.data, Some(processed.thumbnail))
+    } else {
+        (data, None)
+    };
+    Ok(Released::Clean(CleanUpload {
+        checksum_sha256: sha256_hex(&data),
+        data,
+        thumbnail,
+    }))
+}
+
+/// Quarantine, scan and store an upload; returns the id of its row.
+///
+/// `insert` adds the row with `SCAN_PENDING`, so nothing is visible while the scan
+/// runs, and `update` records how it ended. Infected uploads fail with
+/// [`AppError::MalwareDetected`]; files that cannot be scanned or decoded are never
+/// released (fail closed).
+pub async fn store_scanned(
+    upload: Upload,
+    keys: &UploadKeys,
+    storage: &dyn Storage,
+    scanner: &dyn Scanner,
+    conn: &mut MysqlConnection,
+    insert: impl FnOnce(&Upload, &mut MysqlConnection) -> QueryResult<usize>,
+    update: impl FnOnce(u64, ScanOutcome, &mut MysqlConnection) -> QueryResult<usize>,
+) -> Result<u64, AppError> {
+    storage.put(&keys.quarantine, upload.data.clone()).await?;
+    insert(&upload, conn)?;
+    let row_id: u64 = diesel::select(diesel::dsl::sql::<
+        diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+    >("LAST_INSERT_ID()"))
+    .first(conn)?;
+
+    let clean = match release(upload.data, upload.mime, scanner).await {
+        Ok(Released::Clean(clean)) => clean,
+        Ok(Released::Infected(signature)) => {
+            tracing::warn!(
+                key = %keys.object,
+                row_id,
+                signature = %signature,
+                "Infected upload rejected"
+            );
+            storage.delete(&keys.quarantine).await?;
+            update(row_id, ScanOutcome::Infected(signature), conn)?;
+            return Err(AppError::MalwareDetected);
+        }
+        Err(e) => {
+            // Best effort: the row is still pending, so nothing is exposed if the
+            // cleanup itself fails
+            if let Err(err) = storage.delete(&keys.quarantine).await {
+                tracing::warn!(key = %keys.quarantine, error = %err, "Failed to remove quarantined upload");
+            }
+            let _ = update(row_id, ScanOutcome::Discarded, conn);
+            return Err(e);
+        }
+    };
+
+    let size_bytes = clean.data.len() as u64;
+    storage.put(&keys.object, clean.data).await?;
+    let has_thumbnail = match (&keys.thumbnail, clean.thumbnail) {
+        (Some(key), Some(thumb)) => {
+            storage.put(key, thumb).await?;
+            true
+        }
+        _ => false,
+    };
+    storage.delete(&keys.quarantine).await?;
+    update(
+        row_id,
+        ScanOutcome::Clean(StoredUpload {
+            size_bytes,
+            checksum_sha256: clean.checksum_sha256,
+            has_thumbnail,
+        }),
+        conn,
+    )?;
+    Ok(row_id)
+}
+
+/// Serve a stored upload. Local files are streamed by actix-files, which also
+/// handles Range/If-* headers; remote objects are streamed from the backend and
+/// honour a single-range `Range` header.
+pub async fn serve(
+    http_req: &HttpRequest,
+    storage: &dyn Storage,
+    key: &str,
+    size: u64,
+    content_type: mime::Mime,
+    disposition: ContentDisposition,
+) -> Result<HttpResponse, AppError> {
+    if let Some(file_path) = storage.local_path(key) {
+        let file = NamedFile::open_async(&file_path)
+            .await
+            .map_err(|_| AppError::NotFound)?
+            .set_content_type(content_type)
+            .set_content_disposition(disposition);
+        return Ok(file.into_response(http_req));
+    }
+    ranged_response(http_req, storage, key, size, content_type, disposition).await
+}
+
+/// Serve an object from a remote backend, honouring a single-range `Range` header.
+async fn ranged_response(
+    http_req: &HttpRequest,
+    storage: &dyn Storage,
+    key: &str,
+    size: u64,
+    content_type: mime::Mime,
+    disposition: ContentDisposition,
+) -> Result<HttpResponse, AppError> {
+    let range_header = http_req.headers().get(RANGE).and_then(|v| v.to_str().ok());
+    let Some(range_header) = range_header else {
+        let body = storage.get_stream(key).await?;
+        return Ok(HttpResponse::Ok()
+            .content_type(content_type)
+            .insert_header(disposition)
+            .insert_header((ACCEPT_RANGES, "bytes"))
+            .no_chunking(size)
+            .streaming(body));
+    };
+    match HttpRange::parse(range_header, size) {
+        Ok(ranges) if !ranges.is_empty() => {
+            let range = ranges[0];
+            let data = storage.get_range(key, range.start, range.length).await?;
+            Ok(HttpResponse::PartialContent()
+                .content_type(content_type)
+                .insert_header(disposition)
+                .insert_header((ACCEPT_RANGES, "bytes"))
+                .insert_header((
+                    CONTENT_RANGE,
+                    format!(
+                        "bytes {}-{}/{}",
+                        range.start,
+                        range.start + range.length - 1,
+                        size
+                    ),
+                ))
+                .body(data))
+        }
+        _ => Ok(HttpResponse::RangeNotSatisfiable()
+            .insert_header((CONTENT_RANGE, format!("bytes */{}", size)))
+            .finish()),
+    }
+}
+
+/// Serve an image thumbnail; it may be cached privately for a day.
+pub async fn serve_thumbnail(
+    http_req: &HttpRequest,
+    storage: &dyn Storage,
+    key: &str,
+) -> Result<HttpResponse, AppError> {
+    let mut resp = match storage.local_path(key) {
+        Some(thumb_path) => NamedFile::open_async(&thumb_path)
+            .await
+            .map_err(|_| AppError::NotFound)?
+            .set_content_type(mime::IMAGE_JPEG)
+            .disable_content_disposition()
+            .into_response(http_req),
+        None => HttpResponse::Ok()
+            .content_type(mime::IMAGE_JPEG)
+            .body(storage.get(key).await?),
+    };
+    resp.headers_mut().insert(
+        CACHE_CONTROL,
+        HeaderValue::from_static("private, max-age=86400"),
+    );
+    Ok(resp)
+}
+
+fn sanitize_filename(name: &str) -> String {
+    name.replace(['/', '\\'], "_")
+}
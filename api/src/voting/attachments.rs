@@ -0,0 +1,293 @@
+//! Supporting documents attached to proposals (contractor quotes, drawings).
+//!
+//! Uploads go through the shared [`crate::uploads`] pipeline (size and MIME checks,
+//! quarantine, malware scan, metadata stripping). Documents are listed and downloaded
+//! rather than previewed, so image thumbnails are not kept.
+
+use super::discussion::{is_open_for_discussion, load_visible};
+use crate::auth::{AppError, AuthContext};
+use crate::config::AppConfig;
+use crate::db::DbPool;
+use crate::models::ProposalAttachment;
+use crate::scanning::Scanner;
+use crate::storage::Storage;
+use crate::uploads::{self, SCAN_CLEAN, SCAN_INFECTED, SCAN_PENDING, ScanOutcome, UploadKeys};
+use actix_multipart::Multipart;
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::{HttpRequest, HttpResponse, mime, web};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Storage key of a proposal document's content.
+pub fn object_key(proposal_id: u64, stored_filename: &str) -> String {
+    format!("proposals/{}/{}", proposal_id, stored_filename)
+}
+
+/// Storage key of a proposal upload held in quarantine until it has been scanned.
+pub fn quarantine_key(proposal_id: u64, stored_filename: &str) -> String {
+    format!("quarantine/proposals/{}/{}", proposal_id, stored_filename)
+}
+
+/// Upload a proposal document
+///
+/// Attaches a supporting document (e.g. a contractor quote or a drawing) to a
+/// proposal using multipart/form-data. The upload goes through the same checks as
+/// maintenance attachments: size and MIME limits, quarantine and malware scan, and
+/// metadata stripping for JPEG/PNG. Only while the proposal is Scheduled or Open.
+/// Requires Admin or Manager.
+#[utoipa::path(
+    post,
+    path = "/api/v1/proposals/{id}/attachments",
+    params(
+        ("id" = u64, Path, description = "Proposal ID")
+    ),
+    request_body(content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Document uploaded", body = ProposalAttachment),
+        (status = 400, description = "Bad request - no file, invalid MIME type, undecodable image or voting ended"),
+        (status = 403, description = "Forbidden - requires Admin or Manager"),
+        (status = 404, description = "Proposal not found"),
+        (status = 413, description = "File too large"),
+        (status = 422, description = "Malware detected - upload rejected"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn upload_proposal_attachment(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+    cfg: web::Data<AppConfig>,
+    storage: web::Data<dyn Storage>,
+    scanner: web::Data<dyn Scanner>,
+    mut payload: Multipart,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::proposal_attachments::dsl as pa;
+
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let proposal = load_visible(path.into_inner(), &auth, &mut conn)?;
+    if !is_open_for_discussion(&proposal) {
+        return Err(AppError::BadRequest(
+            "Documents can only be added before voting ends".into(),
+        ));
+    }
+    let id = proposal.id;
+
+    let upload = uploads::receive(&mut payload, &cfg).await?;
+    let stored_filename = Uuid::new_v4().to_string();
+    let upload_keys = UploadKeys {
+        quarantine: quarantine_key(id, &stored_filename),
+        object: object_key(id, &stored_filename),
+        thumbnail: None,
+    };
+    let att_id = uploads::store_scanned(
+        upload,
+        &upload_keys,
+        storage.get_ref(),
+        scanner.get_ref(),
+        &mut conn,
+        |upload, conn| {
+            diesel::insert_into(pa::proposal_attachments)
+                .values((
+                    pa::proposal_id.eq(id),
+                    pa::original_filename.eq(&upload.original_filename),
+                    pa::stored_filename.eq(&stored_filename),
+                    pa::mime_type.eq(upload.mime),
+                    pa::size_bytes.eq(upload.data.len() as u64),
+                    pa::scan_status.eq(SCAN_PENDING),
+                    pa::uploaded_by.eq(user_id),
+                ))
+                .execute(conn)
+        },
+        |att_id, outcome, conn| {
+            let row = pa::proposal_attachments.filter(pa::id.eq(att_id));
+            match outcome {
+                ScanOutcome::Clean(stored) => diesel::update(row)
+                    .set((
+                        pa::size_bytes.eq(stored.size_bytes),
+                        pa::checksum_sha256.eq(Some(stored.checksum_sha256)),
+                        pa::scan_status.eq(SCAN_CLEAN),
+                    ))
+                    .execute(conn),
+                ScanOutcome::Infected(signature) => diesel::update(row)
+                    .set((
+                        pa::scan_status.eq(SCAN_INFECTED),
+                        pa::scan_signature.eq(Some(signature)),
+                    ))
+                    .execute(conn),
+                ScanOutcome::Discarded => diesel::delete(row).execute(conn),
+            }
+        },
+    )
+    .await?;
+
+    let row = pa::proposal_attachments
+        .filter(pa::id.eq(att_id))
+        .select(ProposalAttachment::as_select())
+        .first(&mut conn)?;
+    Ok(HttpResponse::Created().json(row))
+}
+
+/// List proposal documents
+///
+/// Returns the released, non-deleted documents of a proposal.
+#[utoipa::path(
+    get,
+    path = "/api/v1/proposals/{id}/attachments",
+    params(
+        ("id" = u64, Path, description = "Proposal ID")
+    ),
+    responses(
+        (status = 200, description = "Documents", body = Vec<ProposalAttachment>),
+        (status = 403, description = "Forbidden - proposal of another building"),
+        (status = 404, description = "Proposal not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_proposal_attachments(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::proposal_attachments::dsl as pa;
+
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let proposal = load_visible(path.into_inner(), &auth, &mut conn)?;
+    let rows = pa::proposal_attachments
+        .filter(pa::proposal_id.eq(proposal.id))
+        .filter(pa::is_deleted.eq(false))
+        .filter(pa::scan_status.eq(SCAN_CLEAN))
+        .order(pa::id.asc())
+        .select(ProposalAttachment::as_select())
+        .load(&mut conn)?;
+    Ok(HttpResponse::Ok().json(rows))
+}
+
+/// Download a proposal document
+///
+/// Streams the document with its stored Content-Type; images and PDFs are served
+/// inline. Supports `Range` requests.
+#[utoipa::path(
+    get,
+    path = "/api/v1/proposals/{id}/attachments/{att_id}/download",
+    params(
+        ("id" = u64, Path, description = "Proposal ID"),
+        ("att_id" = u64, Path, description = "Attachment ID")
+    ),
+    responses(
+        (status = 200, description = "File content", content_type = "application/octet-stream"),
+        (status = 206, description = "Partial file content for a Range request", content_type = "application/octet-stream"),
+        (status = 403, description = "Forbidden - proposal of another building"),
+        (status = 404, description = "Proposal or document not found"),
+        (status = 416, description = "Requested range not satisfiable"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn download_proposal_attachment(
+    auth: AuthContext,
+    http_req: HttpRequest,
+    path: web::Path<(u64, u64)>,
+    pool: web::Data<DbPool>,
+    storage: web::Data<dyn Storage>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::proposal_attachments::dsl as pa;
+
+    let (id, att_id) = path.into_inner();
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    load_visible(id, &auth, &mut conn)?;
+    let item: ProposalAttachment = pa::proposal_attachments
+        .filter(pa::id.eq(att_id))
+        .filter(pa::proposal_id.eq(id))
+        .filter(pa::is_deleted.eq(false))
+        .filter(pa::scan_status.eq(SCAN_CLEAN))
+        .select(ProposalAttachment::as_select())
+        .first(&mut conn)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+
+    let mime_type: mime::Mime = item
+        .mime_type
+        .parse()
+        .unwrap_or(mime::APPLICATION_OCTET_STREAM);
+    let inline = mime_type.type_() == mime::IMAGE || mime_type == mime::APPLICATION_PDF;
+    let disposition = ContentDisposition {
+        disposition: if inline {
+            DispositionType::Inline
+        } else {
+            DispositionType::Attachment
+        },
+        parameters: vec![DispositionParam::Filename(item.original_filename)],
+    };
+    uploads::serve(
+        &http_req,
+        storage.get_ref(),
+        &object_key(id, &item.stored_filename),
+        item.size_bytes,
+        mime_type,
+        disposition,
+    )
+    .await
+}
+
+/// Delete a proposal document
+///
+/// Soft-deletes the document. Requires Admin or Manager.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/proposals/{id}/attachments/{att_id}",
+    params(
+        ("id" = u64, Path, description = "Proposal ID"),
+        ("att_id" = u64, Path, description = "Attachment ID")
+    ),
+    responses(
+        (status = 204, description = "Document deleted"),
+        (status = 403, description = "Forbidden - requires Admin or Manager"),
+        (status = 404, description = "Proposal or document not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_proposal_attachment(
+    auth: AuthContext,
+    path: web::Path<(u64, u64)>,
+    pool: web::Data<DbPool>,
+) -> Result<HttpResponse, AppError> {
+    use crate::schema::proposal_attachments::dsl as pa;
+
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+    let (id, att_id) = path.into_inner();
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    load_visible(id, &auth, &mut conn)?;
+    let affected = diesel::update(
+        pa::proposal_attachments
+            .filter(pa::id.eq(att_id))
+            .filter(pa::proposal_id.eq(id))
+            .filter(pa::is_deleted.eq(false)),
+    )
+    .set(pa::is_deleted.eq(true))
+    .execute(&mut conn)?;
+    if affected == 0 {
+        return Err(AppError::NotFound);
+    }
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,789 @@
+//! Audit report of a tallied proposal, for the board and for legal challenges.
+//!
+//! The report lists every eligible voter with their weight and whether and when they
+//! voted, the stored totals, the voting method and the rule version the tally applied.
+//! Choices are shown only on open ballots; on secret ballots the report records
+//! participation alone, without per-voter weights, which could be matched against the
+//! published ballots; the weight then only appears as totals. When a proposal is tallied a SHA-256 over the result, its
+//! rounds and every counted ballot is stored with the result; the report recomputes
+//! it, so a row changed after the tally shows up as a mismatch. On PerSeat proposals
+//! the report lists apartments rather than voters.
+
+use super::handlers::{
+    load_options, load_secret_ballots, load_secret_totals, load_selections, voter_weight,
+};
+use super::seats;
+use super::types::{AuditReportQuery, ReportFormat};
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::export::csv_line;
+use crate::export::pdf::PdfDocument;
+use crate::models::{
+    BallotType, Proposal, ProposalOption, ProposalResult, ProposalResultRound, Vote, VoteSelection,
+    VotingMethod,
+};
+use crate::storage::sha256_hex;
+use actix_web::{HttpResponse, Responder, web};
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Canonical text of a weight, independent of the column's scale.
+fn canonical(weight: &BigDecimal) -> String {
+    weight.normalized().to_string()
+}
+
+/// SHA-256 over the stored result of `proposal`, its rounds and every counted ballot.
+pub(super) fn results_hash(
+    proposal: &Proposal,
+    result: &ProposalResult,
+    conn: &mut MysqlConnection,
+) -> Result<String, AppError> {
+    use crate::schema::proposal_result_rounds::dsl as rr;
+    use crate::schema::votes::dsl as v;
+
+    let mut lines = vec![
+        format!(
+            "proposal|{}|{}|{}|{}",
+            proposal.id, proposal.voting_method, proposal.ballot_type, proposal.secret_ballot
+        ),
+        format!(
+            "result|{}|{}|{}|{}|{}|{}|{}",
+            result.passed,
+            result
+                .winning_option_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            canonical(&result.yes_weight),
+            canonical(&result.no_weight),
+            canonical(&result.abstain_weight),
+            canonical(&result.total_weight),
+            result.method_applied_version
+        ),
+    ];
+
+    let rounds: Vec<ProposalResultRound> = rr::proposal_result_rounds
+        .filter(rr::result_id.eq(result.id))
+        .order((rr::round.asc(), rr::option_id.asc()))
+        .select(ProposalResultRound::as_select())
+        .load(conn)?;
+    lines.extend(rounds.iter().map(|r| {
+        format!(
+            "round|{}|{}|{}|{}",
+            r.round,
+            r.option_id,
+            canonical(&r.weight),
+            r.eliminated
+        )
+    }));
+
+    let votes: Vec<Vote> = v::votes
+        .filter(v::proposal_id.eq(proposal.id))
+        .order(v::id.asc())
+        .select(Vote::as_select())
+        .load(conn)?;
+    let selections = load_selections(&votes, conn)?;
+    lines.extend(votes.iter().map(|vote| {
+        let line = format!(
+            "vote|{}|{}|{}|{}|{}",
+            vote.user_id,
+            vote.cast_by.map(|id| id.to_string()).unwrap_or_default(),
+            canonical(&vote.weight_decimal),
+            vote.choice,
+            join_ids(
+                selections
+                    .iter()
+                    .filter(|s| s.vote_id == vote.id)
+                    .map(|s| s.option_id)
+            )
+        );
+        // Seat votes name their apartment; lines of other votes are unchanged
+        match vote.apartment_id {
+            Some(apartment_id) => format!("{}|a{}", line, apartment_id),
+            None => line,
+        }
+    }));
+    if proposal.secret_ballot {
+        lines.extend(load_secret_ballots(proposal.id, conn)?.into_iter().map(
+            |(ballot, option_ids)| {
+                format!(
+                    "ballot|{}|{}|{}",
+                    ballot.receipt,
+                    ballot.choice,
+                    join_ids(option_ids.into_iter())
+                )
+            },
+        ));
+        lines.extend(
+            load_secret_totals(proposal.id, conn)?
+                .into_iter()
+                .map(|total| {
+                    format!(
+                        "total|{}|{}|{}|{}",
+                        total.choice,
+                        total.option_ids,
+                        canonical(&total.weight_total),
+                        total.ballots
+                    )
+                }),
+        );
+    }
+
+    Ok(sha256_hex(lines.join("\n").as_bytes()))
+}
+
+fn join_ids(ids: impl Iterator<Item = u64>) -> String {
+    ids.map(|id| id.to_string()).collect::<Vec<_>>().join(",")
+}
+
+/// Store the hash of a freshly tallied result.
+pub(super) fn seal(
+    proposal: &Proposal,
+    result_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use crate::schema::proposal_results::dsl as pr;
+
+    let result: ProposalResult = pr::proposal_results
+        .filter(pr::id.eq(result_id))
+        .select(ProposalResult::as_select())
+        .first(conn)?;
+    let hash = results_hash(proposal, &result, conn)?;
+    diesel::update(pr::proposal_results.filter(pr::id.eq(result_id)))
+        .set(pr::results_hash.eq(Some(hash)))
+        .execute(conn)?;
+    Ok(())
+}
+
+/// An eligible voter, or someone who voted, as listed in the report; on PerSeat
+/// proposals an apartment, named with the owner whose vote it is
+#[derive(Debug)]
+pub(super) struct AuditVoter {
+    pub name: String,
+    /// `None` on secret ballots
+    pub weight: Option<BigDecimal>,
+    pub voted_at: Option<NaiveDateTime>,
+    /// Proxy holder who cast the vote
+    pub cast_by: Option<String>,
+    /// What the ballot said; `None` for non-voters and on secret ballots
+    pub choice: Option<String>,
+}
+
+/// Everything the report shows about a tallied proposal
+pub(super) struct AuditReport {
+    pub proposal: Proposal,
+    pub building: Option<String>,
+    pub result: ProposalResult,
+    pub options: Vec<ProposalOption>,
+    pub rounds: Vec<ProposalResultRound>,
+    pub voters: Vec<AuditVoter>,
+    /// Hash of the data as stored now, to compare with `result.results_hash`
+    pub current_hash: String,
+    pub generated_at: NaiveDateTime,
+}
+
+/// Users holding one of the proposal's eligible roles; on building proposals only the
+/// building's owners and active renters.
+fn eligible_voters(
+    proposal: &Proposal,
+    conn: &mut MysqlConnection,
+) -> Result<HashSet<u64>, AppError> {
+    use crate::schema::apartment_owners::dsl as ao;
+    use crate::schema::apartment_renters::dsl as ar;
+    use crate::schema::apartments::dsl as ap;
+    use crate::schema::roles::dsl as r;
+    use crate::schema::user_roles::dsl as ur;
+
+    let roles: Vec<&str> = proposal.eligible_roles.split(',').collect();
+    let mut ids: HashSet<u64> = ur::user_roles
+        .inner_join(r::roles.on(r::id.eq(ur::role_id)))
+        .filter(r::name.eq_any(roles))
+        .select(ur::user_id)
+        .load::<u64>(conn)?
+        .into_iter()
+        .collect();
+
+    if let Some(building_id) = proposal.building_id {
+        let mut residents: HashSet<u64> = ao::apartment_owners
+            .inner_join(ap::apartments.on(ap::id.eq(ao::apartment_id)))
+            .filter(ap::building_id.eq(building_id))
+            .filter(ap::is_deleted.eq(false))
+            .select(ao::user_id)
+            .load::<u64>(conn)?
+            .into_iter()
+            .collect();
+        residents.extend(
+            ar::apartment_renters
+                .inner_join(ap::apartments.on(ap::id.eq(ar::apartment_id)))
+                .filter(ap::building_id.eq(building_id))
+                .filter(ar::is_active.eq(true))
+                .filter(ap::is_deleted.eq(false))
+                .select(ar::user_id)
+                .load::<u64>(conn)?,
+        );
+        ids.retain(|id| residents.contains(id));
+    }
+    Ok(ids)
+}
+
+/// What an open ballot said: the choice, or the selected option labels.
+fn describe_choice(
+    vote: &Vote,
+    selections: &[VoteSelection],
+    labels: &HashMap<u64, &str>,
+    ballot_type: BallotType,
+) -> String {
+    let picks: Vec<&str> = selections
+        .iter()
+        .filter(|s| s.vote_id == vote.id)
+        .map(|s| labels.get(&s.option_id).copied().unwrap_or("?"))
+        .collect();
+    if picks.is_empty() {
+        return vote.choice.clone();
+    }
+    let separator = if ballot_type == BallotType::RankedChoice {
+        " > "
+    } else {
+        "; "
+    };
+    picks.join(separator)
+}
+
+impl AuditReport {
+    pub fn load(proposal: Proposal, conn: &mut MysqlConnection) -> Result<Self, AppError> {
+        use crate::schema::buildings::dsl as b;
+        use crate::schema::proposal_result_rounds::dsl as rr;
+        use crate::schema::proposal_results::dsl as pr;
+        use crate::schema::users::dsl as u;
+        use crate::schema::votes::dsl as v;
+
+        let result: ProposalResult = pr::proposal_results
+            .filter(pr::proposal_id.eq(proposal.id))
+            .select(ProposalResult::as_select())
+            .first(conn)
+            .optional()?
+            .ok_or_else(|| {
+                AppError::BadRequest("The audit report is available once tallied".into())
+            })?;
+        let building = match proposal.building_id {
+            Some(id) => b::buildings
+                .filter(b::id.eq(id))
+                .select(b::address)
+                .first::<String>(conn)
+                .optional()?,
+            None => None,
+        };
+        let options = load_options(proposal.id, conn)?;
+        let rounds: Vec<ProposalResultRound> = rr::proposal_result_rounds
+            .filter(rr::result_id.eq(result.id))
+            .order((rr::round.asc(), rr::option_id.asc()))
+            .select(ProposalResultRound::as_select())
+            .load(conn)?;
+
+        let (votes, voter_names): (Vec<Vote>, Vec<String>) = v::votes
+            .inner_join(u::users)
+            .filter(v::proposal_id.eq(proposal.id))
+            .select((Vote::as_select(), u::name))
+            .load::<(Vote, String)>(conn)?
+            .into_iter()
+            .unzip();
+        let selections = if proposal.secret_ballot {
+            Vec::new()
+        } else {
+            load_selections(&votes, conn)?
+        };
+
+        let voting_method = VotingMethod::from_str(&proposal.voting_method).map_err(|_| {
+            AppError::Internal(format!("invalid voting method: {}", proposal.voting_method))
+        })?;
+
+        // Who did not vote: eligible users, or apartments on PerSeat proposals
+        let (mut non_voters, empty_seats) = if matches!(voting_method, VotingMethod::PerSeat) {
+            let voted: HashSet<u64> = votes.iter().filter_map(|vote| vote.apartment_id).collect();
+            let empty: Vec<seats::Seat> = seats::eligible_seats(&proposal, conn)?
+                .into_iter()
+                .filter(|seat| !voted.contains(&seat.apartment_id))
+                .collect();
+            (Vec::new(), empty)
+        } else {
+            let voted: HashSet<u64> = votes.iter().map(|vote| vote.user_id).collect();
+            let non_voters: Vec<u64> = eligible_voters(&proposal, conn)?
+                .into_iter()
+                .filter(|id| !voted.contains(id))
+                .collect();
+            (non_voters, Vec::new())
+        };
+        let seat_ids: Vec<u64> = votes.iter().filter_map(|vote| vote.apartment_id).collect();
+        let seat_labels = seats::seat_labels(&seat_ids, conn)?;
+
+        let mut names: HashMap<u64, String> = HashMap::new();
+        let mut missing: Vec<u64> = votes.iter().filter_map(|vote| vote.cast_by).collect();
+        missing.extend(&non_voters);
+        if !missing.is_empty() {
+            names.extend(
+                u::users
+                    .filter(u::id.eq_any(&missing))
+                    .select((u::id, u::name))
+                    .load::<(u64, String)>(conn)?,
+            );
+        }
+
+        let ballot_type = BallotType::from_str(&proposal.ballot_type).map_err(|_| {
+            AppError::Internal(format!("invalid ballot type: {}", proposal.ballot_type))
+        })?;
+        let labels: HashMap<u64, &str> = options.iter().map(|o| (o.id, o.label.as_str())).collect();
+
+        let mut voters: Vec<AuditVoter> = votes
+            .iter()
+            .zip(voter_names)
+            .map(|(vote, name)| AuditVoter {
+                name: match vote.apartment_id.and_then(|id| seat_labels.get(&id)) {
+                    Some(label) => format!("{} ({})", label, name),
+                    None => name,
+                },
+                weight: (!proposal.secret_ballot).then(|| vote.weight_decimal.clone()),
+                voted_at: vote.created_at,
+                cast_by: vote.cast_by.and_then(|id| names.get(&id).cloned()),
+                choice: (!proposal.secret_ballot)
+                    .then(|| describe_choice(vote, &selections, &labels, ballot_type)),
+            })
+            .collect();
+
+        // Non-voters are listed with the weight they would have voted with
+        non_voters.sort_unstable();
+        for user_id in non_voters {
+            let weight = if proposal.secret_ballot {
+                None
+            } else {
+                Some(voter_weight(&voting_method, user_id, conn)?)
+            };
+            voters.push(AuditVoter {
+                name: names.get(&user_id).cloned().unwrap_or_default(),
+                weight,
+                voted_at: None,
+                cast_by: None,
+                choice: None,
+            });
+        }
+        for seat in empty_seats {
+            voters.push(AuditVoter {
+                name: seat.label(),
+                weight: (!proposal.secret_ballot).then(|| BigDecimal::from(1)),
+                voted_at: None,
+                cast_by: None,
+                choice: None,
+            });
+        }
+        voters.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let current_hash = results_hash(&proposal, &result, conn)?;
+        Ok(Self {
+            proposal,
+            building,
+            result,
+            options,
+            rounds,
+            voters,
+            current_hash,
+            generated_at: chrono::Local::now().naive_local(),
+        })
+    }
+
+    /// Whether the data still hashes to what was stored at tally time.
+    pub fn hash_matches(&self) -> bool {
+        self.result.results_hash.as_deref() == Some(self.current_hash.as_str())
+    }
+
+    fn per_seat(&self) -> bool {
+        self.proposal.voting_method == VotingMethod::PerSeat.to_string()
+    }
+
+    fn option_label(&self, option_id: u64) -> &str {
+        self.options
+            .iter()
+            .find(|o| o.id == option_id)
+            .map(|o| o.label.as_str())
+            .unwrap_or("?")
+    }
+
+    /// `(label, value)` pairs describing the proposal and its result.
+    fn summary(&self) -> Vec<(&'static str, String)> {
+        let p = &self.proposal;
+        let r = &self.result;
+        let mut rows = vec![
+            ("Proposal", p.title.clone()),
+            ("Proposal ID", p.id.to_string()),
+            (
+                "Building",
+                self.building
+                    .clone()
+                    .unwrap_or_else(|| "All buildings".into()),
+            ),
+            (
+                "Voting period",
+                format!(
+                    "{} - {}",
+                    p.start_time.format(DATE_FORMAT),
+                    p.end_time.format(DATE_FORMAT)
+                ),
+            ),
+            ("Voting method", p.voting_method.clone()),
+            ("Ballot type", p.ballot_type.clone()),
+            (
+                "Secret ballot",
+                if p.secret_ballot { "Yes" } else { "No" }.into(),
+            ),
+            ("Eligible roles", p.eligible_roles.replace(',', ", ")),
+            ("Rule version", r.method_applied_version.clone()),
+            (
+                "Tallied at",
+                r.tallied_at
+                    .map(|t| t.format(DATE_FORMAT).to_string())
+                    .unwrap_or_default(),
+            ),
+            (
+                "Outcome",
+                if r.passed { "Passed" } else { "Rejected" }.into(),
+            ),
+        ];
+        if let Some(option_id) = r.winning_option_id {
+            rows.push(("Winning option", self.option_label(option_id).to_string()));
+        }
+        if p.ballot_type == BallotType::YesNo.to_string() {
+            rows.push(("Yes weight", r.yes_weight.with_scale(2).to_string()));
+            rows.push(("No weight", r.no_weight.with_scale(2).to_string()));
+        } else {
+            rows.push(("Winner weight", r.yes_weight.with_scale(2).to_string()));
+            rows.push(("Other weight", r.no_weight.with_scale(2).to_string()));
+        }
+        rows.push(("Abstain weight", r.abstain_weight.with_scale(2).to_string()));
+        rows.push((
+            "Total weight cast",
+            r.total_weight.with_scale(2).to_string(),
+        ));
+
+        let voted = self.voters.iter().filter(|v| v.voted_at.is_some()).count();
+        let eligible = if self.per_seat() {
+            "Eligible apartments"
+        } else {
+            "Eligible voters"
+        };
+        rows.push((eligible, self.voters.len().to_string()));
+        rows.push(("Voted", voted.to_string()));
+        rows.push((
+            "Results hash (at tally)",
+            r.results_hash.clone().unwrap_or_default(),
+        ));
+        rows.push(("Results hash (now)", self.current_hash.clone()));
+        rows.push((
+            "Hash verified",
+            if self.hash_matches() { "Yes" } else { "No" }.into(),
+        ));
+        rows.push((
+            "Generated at",
+            self.generated_at.format(DATE_FORMAT).to_string(),
+        ));
+        rows
+    }
+
+    fn voter_cells(&self, voter: &AuditVoter) -> Vec<String> {
+        let mut cells = vec![voter.name.clone()];
+        if !self.proposal.secret_ballot {
+            cells.push(
+                voter
+                    .weight
+                    .as_ref()
+                    .map(|w| w.with_scale(2).to_string())
+                    .unwrap_or_default(),
+            );
+        }
+        cells.extend([
+            if voter.voted_at.is_some() {
+                "Yes"
+            } else {
+                "No"
+            }
+            .into(),
+            voter
+                .voted_at
+                .map(|t| t.format(DATE_FORMAT).to_string())
+                .unwrap_or_default(),
+            voter.cast_by.clone().unwrap_or_default(),
+        ]);
+        if !self.proposal.secret_ballot {
+            cells.push(voter.choice.clone().unwrap_or_default());
+        }
+        cells
+    }
+
+    fn voter_header(&self) -> Vec<String> {
+        let first = if self.per_seat() {
+            "Apartment"
+        } else {
+            "Voter"
+        };
+        if self.proposal.secret_ballot {
+            return [first, "Voted", "Voted at", "Cast by proxy"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect();
+        }
+        [
+            first,
+            "Weight",
+            "Voted",
+            "Voted at",
+            "Cast by proxy",
+            "Choice",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+    }
+
+    /// Summary block, then the tally rounds, then one row per voter.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for (label, value) in self.summary() {
+            csv.push_str(&csv_line(&[label.to_string(), value]));
+        }
+        if !self.rounds.is_empty() {
+            csv.push('\n');
+            csv.push_str(&csv_line(&[
+                "Round".into(),
+                "Option".into(),
+                "Weight".into(),
+                "Eliminated".into(),
+            ]));
+            for round in &self.rounds {
+                csv.push_str(&csv_line(&[
+                    round.round.to_string(),
+                    self.option_label(round.option_id).to_string(),
+                    round.weight.with_scale(2).to_string(),
+                    if round.eliminated { "Yes" } else { "No" }.into(),
+                ]));
+            }
+        }
+        csv.push('\n');
+        csv.push_str(&csv_line(&self.voter_header()));
+        for voter in &self.voters {
+            csv.push_str(&csv_line(&self.voter_cells(voter)));
+        }
+        csv
+    }
+
+    pub fn to_pdf(&self) -> Vec<u8> {
+        let mut doc = PdfDocument::new(&format!("Vote audit report #{}", self.proposal.id));
+        doc.heading(&format!("Vote audit report: {}", self.proposal.title));
+        for (label, value) in self.summary() {
+            doc.field(label, &value);
+        }
+        if self.proposal.secret_ballot {
+            doc.space(4.0);
+            doc.text(
+                "Secret ballot: the report records who took part but not how anyone voted \
+                 or with what weight.",
+            );
+        }
+
+        if !self.rounds.is_empty() {
+            doc.heading("Tally rounds");
+            let widths = [6, 60, 14, 10];
+            doc.row(
+                &[
+                    "Round".into(),
+                    "Option".into(),
+                    "Weight".into(),
+                    "Eliminated".into(),
+                ],
+                &widths,
+            );
+            for round in &self.rounds {
+                doc.row(
+                    &[
+                        round.round.to_string(),
+                        self.option_label(round.option_id).to_string(),
+                        round.weight.with_scale(2).to_string(),
+                        if round.eliminated { "Yes" } else { "" }.into(),
+                    ],
+                    &widths,
+                );
+            }
+        }
+
+        doc.heading("Voters");
+        let widths = if self.proposal.secret_ballot {
+            vec![40, 6, 21, 30]
+        } else {
+            vec![24, 10, 5, 19, 18, 21]
+        };
+        doc.row(&self.voter_header(), &widths);
+        for voter in &self.voters {
+            doc.row(&self.voter_cells(voter), &widths);
+        }
+        doc.finish()
+    }
+}
+
+/// Download the vote audit report
+///
+/// Generates the audit report of a tallied proposal: eligible voters, who voted and
+/// when (weights and choices only on open ballots), the stored totals,
+/// voting method and rule version, and the results hash taken at tally time next to
+/// a freshly computed one. Returned as PDF (default) or CSV. Requires Admin or Manager.
+#[utoipa::path(
+    get,
+    path = "/api/v1/proposals/{id}/audit-report",
+    params(
+        ("id" = u64, Path, description = "Proposal ID"),
+        AuditReportQuery
+    ),
+    responses(
+        (status = 200, description = "Audit report", content(
+            (String = "application/pdf"),
+            (String = "text/csv")
+        )),
+        (status = 400, description = "Proposal not tallied yet"),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 404, description = "Proposal not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_audit_report(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    query: web::Query<AuditReportQuery>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::proposals::dsl as p;
+
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+    let id = path.into_inner();
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let proposal: Proposal = p::proposals
+        .filter(p::id.eq(id))
+        .select(Proposal::as_select())
+        .first(&mut conn)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+    let report = AuditReport::load(proposal, &mut conn)?;
+
+    let (content_type, extension, body) = match query.format.unwrap_or_default() {
+        ReportFormat::Pdf => ("application/pdf", "pdf", report.to_pdf()),
+        ReportFormat::Csv => ("text/csv", "csv", report.to_csv().into_bytes()),
+    };
+    Ok(HttpResponse::Ok()
+        .content_type(content_type)
+        .insert_header((
+            "Content-Disposition",
+            format!(
+                "attachment; filename=\"proposal_{}_audit.{}\"",
+                id, extension
+            ),
+        ))
+        .body(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn report(secret_ballot: bool) -> AuditReport {
+        AuditReport {
+            proposal: Proposal {
+                id: 7,
+                title: "New roof".into(),
+                description: String::new(),
+                created_by: 1,
+                building_id: Some(2),
+                start_time: at("2026-03-01 08:00:00"),
+                end_time: at("2026-03-08 20:00:00"),
+                voting_method: "WeightedArea".into(),
+                ballot_type: "YesNo".into(),
+                max_selections: None,
+                secret_ballot,
+                eligible_roles: "Homeowner,HOA Member".into(),
+                status: "Tallied".into(),
+                created_at: None,
+            },
+            building: Some("Main Street 1".into()),
+            result: ProposalResult {
+                id: 3,
+                proposal_id: 7,
+                passed: true,
+                winning_option_id: None,
+                yes_weight: BigDecimal::from(80),
+                no_weight: BigDecimal::from(0),
+                abstain_weight: BigDecimal::from(0),
+                total_weight: BigDecimal::from(80),
+                tallied_at: Some(at("2026-03-09 09:00:00")),
+                method_applied_version: "v1".into(),
+                results_hash: Some("abc".into()),
+            },
+            options: Vec::new(),
+            rounds: Vec::new(),
+            voters: vec![
+                AuditVoter {
+                    name: "Alice".into(),
+                    weight: (!secret_ballot).then(|| BigDecimal::from(80)),
+                    voted_at: Some(at("2026-03-02 10:00:00")),
+                    cast_by: Some("Bob".into()),
+                    choice: (!secret_ballot).then(|| "Yes".into()),
+                },
+                AuditVoter {
+                    name: "Carol".into(),
+                    weight: (!secret_ballot).then(|| BigDecimal::from(60)),
+                    voted_at: None,
+                    cast_by: None,
+                    choice: None,
+                },
+            ],
+            current_hash: "abc".into(),
+            generated_at: at("2026-03-10 12:00:00"),
+        }
+    }
+
+    #[test]
+    fn csv_lists_summary_and_voters() {
+        let csv = report(false).to_csv();
+        assert!(csv.contains("Rule version,v1\n"));
+        assert!(csv.contains("Hash verified,Yes\n"));
+        assert!(csv.contains("Eligible roles,\"Homeowner, HOA Member\"\n"));
+        assert!(csv.contains("Voter,Weight,Voted,Voted at,Cast by proxy,Choice\n"));
+        assert!(csv.contains("Alice,80.00,Yes,2026-03-02 10:00:00,Bob,Yes\n"));
+        assert!(csv.contains("Carol,60.00,No,,,\n"));
+    }
+
+    #[test]
+    fn secret_report_has_no_choices_or_voter_weights() {
+        let mut report = report(true);
+        report.current_hash = "changed".into();
+        let csv = report.to_csv();
+        assert!(csv.contains("Voter,Voted,Voted at,Cast by proxy\n"));
+        assert!(csv.contains("Alice,Yes,2026-03-02 10:00:00,Bob\n"));
+        assert!(csv.contains("Carol,No,,\n"));
+        assert!(csv.contains("Total weight cast,80.00\n"));
+        assert!(!csv.contains("60.00"));
+        assert!(csv.contains("Hash verified,No\n"));
+
+        let pdf = String::from_utf8(report.to_pdf()).unwrap();
+        assert!(pdf.starts_with("%PDF-1.4"));
+        assert!(pdf.contains("Secret ballot: the report records who took part"));
+    }
+}
@@ -0,0 +1,478 @@
+//! Option ballots: checking a selection and tallying single choice, approval
+//! and ranked-choice (instant runoff) proposals, plus secret ballot receipts.
+//! No database access.
+
+use crate::models::{BallotType, ProposalOption, VotingMethod};
+use bigdecimal::{BigDecimal, Zero};
+use rand::Rng;
+use std::collections::HashSet;
+
+pub const MIN_OPTIONS: usize = 2;
+pub const MAX_OPTIONS: usize = 30;
+
+/// Choice stored on `votes` rows for option ballots; the picks live in `vote_selections`.
+pub const BALLOT_CHOICE: &str = "Ballot";
+
+/// Choice stored on `votes` rows of secret proposals; the ballot lives in `secret_ballots`.
+pub const SECRET_CHOICE: &str = "Secret";
+
+/// Receipt alphabet without look-alike characters (0/O, 1/I).
+const RECEIPT_CHARSET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// A random secret ballot receipt such as `K7QM-2XDA-4HPW`.
+pub fn new_receipt() -> String {
+    let mut rng = rand::thread_rng();
+    (0..3)
+        .map(|_| {
+            (0..4)
+                .map(|_| RECEIPT_CHARSET[rng.gen_range(0..RECEIPT_CHARSET.len())] as char)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Selected option ids as stored on a secret ballot total, e.g. `4,2,7`.
+pub fn join_option_ids(option_ids: &[u64]) -> String {
+    option_ids
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Parses what [`join_option_ids`] wrote; an empty string is no selection.
+pub fn split_option_ids(text: &str) -> Vec<u64> {
+    text.split(',').filter_map(|id| id.parse().ok()).collect()
+}
+
+/// A cast option ballot: its weight and the selected option ids, first preference first.
+/// An empty selection is an abstention.
+#[derive(Debug, Clone)]
+pub struct Ballot {
+    pub weight: BigDecimal,
+    pub selections: Vec<u64>,
+}
+
+/// One option's weight in one round.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundEntry {
+    pub round: i32,
+    pub option_id: u64,
+    pub weight: BigDecimal,
+    pub eliminated: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct OptionTally {
+    pub winner: Option<u64>,
+    pub passed: bool,
+    /// Weight behind the winner in the final round
+    pub winner_weight: BigDecimal,
+    /// Weight of non-abstaining ballots that did not back the winner
+    pub other_weight: BigDecimal,
+    pub abstain_weight: BigDecimal,
+    pub total_weight: BigDecimal,
+    pub rounds: Vec<RoundEntry>,
+}
+
+/// Checks option labels for a new proposal, trimming them in place.
+pub fn validate_options(
+    ballot_type: BallotType,
+    options: &mut [String],
+    max_selections: Option<i32>,
+) -> Result<(), String> {
+    if !ballot_type.has_options() {
+        if !options.is_empty() || max_selections.is_some() {
+            return Err("Yes/No proposals take no options".into());
+        }
+        return Ok(());
+    }
+    for o in options.iter_mut() {
+        *o = o.trim().to_string();
+    }
+    if options.len() < MIN_OPTIONS || options.len() > MAX_OPTIONS {
+        return Err(format!(
+            "Between {} and {} options are required",
+            MIN_OPTIONS, MAX_OPTIONS
+        ));
+    }
+    if options
+        .iter()
+        .any(|o| o.is_empty() || o.chars().count() > 255)
+    {
+        return Err("Options must be 1-255 characters".into());
+    }
+    let unique: HashSet<&str> = options.iter().map(String::as_str).collect();
+    if unique.len() != options.len() {
+        return Err("Options must be unique".into());
+    }
+    if let Some(max) = max_selections
+        && (ballot_type != BallotType::Approval || max < 1 || max as usize > options.len())
+    {
+        return Err("max_selections applies to approval ballots, 1 up to the option count".into());
+    }
+    Ok(())
+}
+
+/// Checks a ballot's selection against the proposal's options.
+pub fn validate_selection(
+    ballot_type: BallotType,
+    max_selections: Option<i32>,
+    options: &[ProposalOption],
+    selection: &[u64],
+) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for id in selection {
+        if !options.iter().any(|o| o.id == *id) {
+            return Err(format!("Unknown option {}", id));
+        }
+        if !seen.insert(*id) {
+            return Err(format!("Option {} selected twice", id));
+        }
+    }
+    match ballot_type {
+        BallotType::YesNo => Err("This proposal has no options".into()),
+        BallotType::SingleChoice if selection.len() > 1 => Err("Choose one option".into()),
+        BallotType::Approval
+            if max_selections.is_some_and(|max| selection.len() > max as usize) =>
+        {
+            Err(format!(
+                "Select at most {} options",
+                max_selections.unwrap_or_default()
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Tallies option ballots. `options` are the proposal's option ids in display order.
+///
+/// Single choice and approval take a single round and the option with the most
+/// weight wins; a tie for first place has no winner. Ranked choice runs instant
+/// runoff: each round counts every ballot for its highest continuing preference and
+/// the weakest option is eliminated until one holds a majority of the continuing
+/// weight. Ties for last place drop the option with less first-round weight, then
+/// the one listed later. Under `Consensus` the winner also has to be backed by every
+/// non-abstaining ballot.
+pub fn tally_options(
+    ballot_type: BallotType,
+    method: &VotingMethod,
+    options: &[u64],
+    ballots: &[Ballot],
+) -> OptionTally {
+    let cast: Vec<&Ballot> = ballots
+        .iter()
+        .filter(|b| !b.selections.is_empty())
+        .collect();
+    let cast_weight: BigDecimal = cast.iter().map(|b| b.weight.clone()).sum();
+    let abstain_weight: BigDecimal = ballots
+        .iter()
+        .filter(|b| b.selections.is_empty())
+        .map(|b| b.weight.clone())
+        .sum();
+    let total_weight = &cast_weight + &abstain_weight;
+
+    let (winner, rounds) = match ballot_type {
+        BallotType::RankedChoice => instant_runoff(options, &cast),
+        _ => {
+            let counts = count_round(options, &cast, |b, o| b.selections.contains(&o));
+            let winner = unique_leader(&counts);
+            let rounds = counts
+                .into_iter()
+                .map(|(option_id, weight)| RoundEntry {
+                    round: 1,
+                    option_id,
+                    weight,
+                    eliminated: false,
+                })
+                .collect();
+            (winner, rounds)
+        }
+    };
+
+    let last_round = rounds.last().map(|r| r.round).unwrap_or(1);
+    let winner_weight = winner
+        .and_then(|w| {
+            rounds
+                .iter()
+                .find(|r| r.round == last_round && r.option_id == w)
+                .map(|r| r.weight.clone())
+        })
+        .unwrap_or_else(BigDecimal::zero);
+    let other_weight = &cast_weight - &winner_weight;
+
+    let passed = match (winner, method) {
+        (None, _) => false,
+        (Some(w), VotingMethod::Consensus) => cast.iter().all(|b| match ballot_type {
+            BallotType::Approval => b.selections.contains(&w),
+            _ => b.selections.first() == Some(&w),
+        }),
+        (Some(_), _) => true,
+    };
+
+    OptionTally {
+        winner,
+        passed,
+        winner_weight,
+        other_weight,
+        abstain_weight,
+        total_weight,
+        rounds,
+    }
+}
+
+/// Sums ballot weight per option for ballots where `counts(ballot, option)` holds.
+fn count_round(
+    options: &[u64],
+    ballots: &[&Ballot],
+    counts: impl Fn(&Ballot, u64) -> bool,
+) -> Vec<(u64, BigDecimal)> {
+    options
+        .iter()
+        .map(|&o| {
+            let weight = ballots
+                .iter()
+                .filter(|b| counts(b, o))
+                .map(|b| b.weight.clone())
+                .sum();
+            (o, weight)
+        })
+        .collect()
+}
+
+/// The option with strictly the most weight, if any weight was cast.
+fn unique_leader(counts: &[(u64, BigDecimal)]) -> Option<u64> {
+    let max = counts.iter().map(|(_, w)| w).max()?;
+    if max.is_zero() || counts.iter().filter(|(_, w)| w == max).count() > 1 {
+        return None;
+    }
+    counts.iter().find(|(_, w)| w == max).map(|(o, _)| *o)
+}
+
+fn instant_runoff(options: &[u64], ballots: &[&Ballot]) -> (Option<u64>, Vec<RoundEntry>) {
+    let mut continuing: Vec<u64> = options.to_vec();
+    let mut first_round: Vec<(u64, BigDecimal)> = Vec::new();
+    let mut rounds = Vec::new();
+    let mut round = 1;
+
+    loop {
+        let counts = count_round(&continuing, ballots, |b, o| {
+            b.selections.iter().find(|s| continuing.contains(s)) == Some(&o)
+        });
+        if round == 1 {
+            first_round = counts.clone();
+        }
+        let active: BigDecimal = counts.iter().map(|(_, w)| w.clone()).sum();
+        let leader = counts
+            .iter()
+            .find(|(_, w)| w * BigDecimal::from(2) > active)
+            .map(|(o, _)| *o);
+
+        // A majority, nothing left to count, or a dead heat between the last two
+        if leader.is_some() || active.is_zero() || continuing.len() <= 2 {
+            rounds.extend(counts.into_iter().map(|(option_id, weight)| RoundEntry {
+                round,
+                option_id,
+                weight,
+                eliminated: false,
+            }));
+            return (leader, rounds);
+        }
+
+        let first_weight = |o: u64| {
+            first_round
+                .iter()
+                .find(|(id, _)| *id == o)
+                .map(|(_, w)| w.clone())
+                .unwrap_or_default()
+        };
+        let position = |o: u64| options.iter().position(|id| *id == o).unwrap_or(0);
+        let loser = counts
+            .iter()
+            .min_by(|(a, wa), (b, wb)| {
+                wa.cmp(wb)
+                    .then_with(|| first_weight(*a).cmp(&first_weight(*b)))
+                    .then_with(|| position(*b).cmp(&position(*a)))
+            })
+            .map(|(o, _)| *o)
+            .unwrap_or_default();
+
+        rounds.extend(counts.into_iter().map(|(option_id, weight)| RoundEntry {
+            round,
+            option_id,
+            weight,
+            eliminated: option_id == loser,
+        }));
+        continuing.retain(|o| *o != loser);
+        round += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ballot(weight: i32, selections: &[u64]) -> Ballot {
+        Ballot {
+            weight: BigDecimal::from(weight),
+            selections: selections.to_vec(),
+        }
+    }
+
+    fn option(id: u64) -> ProposalOption {
+        ProposalOption {
+            id,
+            proposal_id: 1,
+            position: id as i32,
+            label: format!("Option {}", id),
+        }
+    }
+
+    #[test]
+    fn single_choice_plurality_and_ties() {
+        let ballots = [
+            ballot(1, &[1]),
+            ballot(1, &[2]),
+            ballot(1, &[1]),
+            ballot(1, &[]),
+        ];
+        let t = tally_options(
+            BallotType::SingleChoice,
+            &VotingMethod::SimpleMajority,
+            &[1, 2, 3],
+            &ballots,
+        );
+        assert_eq!(t.winner, Some(1));
+        assert!(t.passed);
+        assert_eq!(t.winner_weight, BigDecimal::from(2));
+        assert_eq!(t.other_weight, BigDecimal::from(1));
+        assert_eq!(t.abstain_weight, BigDecimal::from(1));
+        assert_eq!(t.rounds.len(), 3);
+
+        let tied = [ballot(1, &[1]), ballot(1, &[2])];
+        let t = tally_options(
+            BallotType::SingleChoice,
+            &VotingMethod::SimpleMajority,
+            &[1, 2],
+            &tied,
+        );
+        assert_eq!(t.winner, None);
+        assert!(!t.passed);
+    }
+
+    #[test]
+    fn approval_counts_every_selected_option_with_weight() {
+        let ballots = [ballot(3, &[1, 2]), ballot(2, &[2]), ballot(4, &[3])];
+        let t = tally_options(
+            BallotType::Approval,
+            &VotingMethod::WeightedArea,
+            &[1, 2, 3],
+            &ballots,
+        );
+        assert_eq!(t.winner, Some(2));
+        assert_eq!(t.winner_weight, BigDecimal::from(5));
+        assert_eq!(t.other_weight, BigDecimal::from(4));
+        assert_eq!(t.total_weight, BigDecimal::from(9));
+    }
+
+    #[test]
+    fn instant_runoff_transfers_eliminated_preferences() {
+        // First round A 4, B 3, C 2: no majority, C goes and its ballots move to B.
+        let ballots = [ballot(4, &[1]), ballot(3, &[2, 1]), ballot(2, &[3, 2])];
+        let t = tally_options(
+            BallotType::RankedChoice,
+            &VotingMethod::WeightedArea,
+            &[1, 2, 3],
+            &ballots,
+        );
+        assert_eq!(t.winner, Some(2));
+        assert!(t.passed);
+        assert_eq!(t.winner_weight, BigDecimal::from(5));
+        let eliminated: Vec<_> = t.rounds.iter().filter(|r| r.eliminated).collect();
+        assert_eq!(eliminated.len(), 1);
+        assert_eq!((eliminated[0].round, eliminated[0].option_id), (1, 3));
+        assert_eq!(t.rounds.iter().filter(|r| r.round == 2).count(), 2);
+    }
+
+    #[test]
+    fn instant_runoff_ties_for_last_drop_the_later_option() {
+        let ballots = [ballot(2, &[1]), ballot(1, &[2, 1]), ballot(1, &[3, 1])];
+        let t = tally_options(
+            BallotType::RankedChoice,
+            &VotingMethod::SimpleMajority,
+            &[1, 2, 3],
+            &ballots,
+        );
+        let first_out = t.rounds.iter().find(|r| r.eliminated).unwrap();
+        assert_eq!(first_out.option_id, 3);
+        assert_eq!(t.winner, Some(1));
+    }
+
+    #[test]
+    fn consensus_needs_every_ballot_behind_the_winner() {
+        let ballots = [ballot(1, &[1, 2]), ballot(1, &[2])];
+        let t = tally_options(
+            BallotType::Approval,
+            &VotingMethod::Consensus,
+            &[1, 2],
+            &ballots,
+        );
+        assert_eq!(t.winner, Some(2));
+        assert!(t.passed);
+        let ballots = [ballot(2, &[1]), ballot(1, &[2])];
+        let t = tally_options(
+            BallotType::SingleChoice,
+            &VotingMethod::Consensus,
+            &[1, 2],
+            &ballots,
+        );
+        assert_eq!(t.winner, Some(1));
+        assert!(!t.passed);
+    }
+
+    #[test]
+    fn selections_are_checked_against_the_ballot_type() {
+        let opts = [option(1), option(2), option(3)];
+        assert!(validate_selection(BallotType::SingleChoice, None, &opts, &[1, 2]).is_err());
+        assert!(validate_selection(BallotType::RankedChoice, None, &opts, &[2, 2]).is_err());
+        assert!(validate_selection(BallotType::RankedChoice, None, &opts, &[9]).is_err());
+        assert!(validate_selection(BallotType::Approval, Some(2), &opts, &[1, 2, 3]).is_err());
+        assert!(validate_selection(BallotType::Approval, Some(2), &opts, &[3, 1]).is_ok());
+        assert!(validate_selection(BallotType::SingleChoice, None, &opts, &[]).is_ok());
+    }
+
+    #[test]
+    fn options_are_required_only_for_option_ballots() {
+        let mut none: Vec<String> = vec![];
+        assert!(validate_options(BallotType::YesNo, &mut none, None).is_ok());
+        let mut one = vec!["Only".to_string()];
+        assert!(validate_options(BallotType::SingleChoice, &mut one, None).is_err());
+        let mut quotes = vec![" Quote A ".to_string(), "Quote B".to_string()];
+        assert!(validate_options(BallotType::SingleChoice, &mut quotes, Some(1)).is_err());
+        assert!(validate_options(BallotType::Approval, &mut quotes, Some(2)).is_ok());
+        assert_eq!(quotes[0], "Quote A");
+    }
+
+    #[test]
+    fn receipts_use_three_groups_of_unambiguous_characters() {
+        let receipt = new_receipt();
+        assert_eq!(receipt.len(), 14);
+        let groups: Vec<&str> = receipt.split('-').collect();
+        assert_eq!(groups.len(), 3);
+        assert!(
+            groups
+                .iter()
+                .all(|g| g.len() == 4 && g.bytes().all(|b| RECEIPT_CHARSET.contains(&b)))
+        );
+        assert_ne!(receipt, new_receipt());
+    }
+
+    #[test]
+    fn option_ids_round_trip_in_preference_order() {
+        assert_eq!(join_option_ids(&[4, 2, 7]), "4,2,7");
+        assert_eq!(split_option_ids("4,2,7"), vec![4, 2, 7]);
+        assert_eq!(join_option_ids(&[]), "");
+        assert!(split_option_ids("").is_empty());
+    }
+}
@@ -0,0 +1,250 @@
+//! Discussion threads on proposals.
+//!
+//! Residents who can see a proposal can discuss it while it is Scheduled or Open;
+//! once voting has closed the thread stays readable but takes no new messages.
+//! Everyone who took part is notified when the proposal is tallied.
+
+use super::types::{CreateProposalCommentPayload, ProposalCommentOut};
+use crate::auth::{AppError, AuthContext, get_user_building_ids};
+use crate::db::DbPool;
+use crate::models::{NotificationKind, Proposal, ProposalComment};
+use crate::notifications::notify;
+use actix_web::{HttpResponse, Responder, web};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+
+/// Longest accepted message, in characters
+const MAX_BODY_LEN: usize = 5000;
+
+/// Load a proposal the caller can see: global ones, or ones of their buildings.
+pub(super) fn load_visible(
+    id: u64,
+    auth: &AuthContext,
+    conn: &mut MysqlConnection,
+) -> Result<Proposal, AppError> {
+    use crate::schema::proposals::dsl as p;
+
+    let proposal: Proposal = p::proposals
+        .filter(p::id.eq(id))
+        .select(Proposal::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+    if let Some(building_id) = proposal.building_id {
+        let is_admin = auth.has_any_role(&["Admin"]);
+        if let Some(ids) = get_user_building_ids(auth.user_id()?, is_admin, conn)?
+            && !ids.contains(&building_id)
+        {
+            return Err(AppError::Forbidden);
+        }
+    }
+    Ok(proposal)
+}
+
+/// Whether the proposal still takes messages and documents.
+pub(super) fn is_open_for_discussion(proposal: &Proposal) -> bool {
+    proposal.status == "Scheduled" || proposal.status == "Open"
+}
+
+/// Tell everyone who discussed `proposal` that it was tallied.
+pub(super) fn notify_commenters(
+    proposal: &Proposal,
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use crate::schema::proposal_comments::dsl as pc;
+
+    let user_ids: Vec<u64> = pc::proposal_comments
+        .filter(pc::proposal_id.eq(proposal.id))
+        .filter(pc::is_deleted.eq(false))
+        .select(pc::user_id)
+        .distinct()
+        .load(conn)?;
+    notify(
+        &user_ids,
+        NotificationKind::ProposalTallied,
+        &proposal.title,
+        Some(&format!("/voting/{}", proposal.id)),
+        conn,
+    )
+}
+
+/// List proposal comments
+///
+/// Returns the discussion of a proposal, oldest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/proposals/{id}/comments",
+    params(
+        ("id" = u64, Path, description = "Proposal ID")
+    ),
+    responses(
+        (status = 200, description = "Discussion messages", body = Vec<ProposalCommentOut>),
+        (status = 403, description = "Forbidden - proposal of another building"),
+        (status = 404, description = "Proposal not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_proposal_comments(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::proposal_comments::dsl as pc;
+    use crate::schema::users::dsl as u;
+
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let proposal = load_visible(path.into_inner(), &auth, &mut conn)?;
+    let is_manager = auth.has_any_role(&["Admin", "Manager"]);
+
+    let rows: Vec<(ProposalComment, String)> = pc::proposal_comments
+        .inner_join(u::users)
+        .filter(pc::proposal_id.eq(proposal.id))
+        .filter(pc::is_deleted.eq(false))
+        .order(pc::id.asc())
+        .select((ProposalComment::as_select(), u::name))
+        .load(&mut conn)?;
+
+    let comments: Vec<ProposalCommentOut> = rows
+        .into_iter()
+        .map(|(comment, user_name)| ProposalCommentOut {
+            can_delete: is_manager || comment.user_id == user_id,
+            comment,
+            user_name,
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(comments))
+}
+
+/// Comment on a proposal
+///
+/// Adds a message to the discussion. Only possible while the proposal is Scheduled or
+/// Open.
+#[utoipa::path(
+    post,
+    path = "/api/v1/proposals/{id}/comments",
+    params(
+        ("id" = u64, Path, description = "Proposal ID")
+    ),
+    request_body = CreateProposalCommentPayload,
+    responses(
+        (status = 201, description = "Comment added", body = ProposalCommentOut),
+        (status = 400, description = "Empty or too long message, or discussion closed"),
+        (status = 403, description = "Forbidden - proposal of another building"),
+        (status = 404, description = "Proposal not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_proposal_comment(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+    payload: web::Json<CreateProposalCommentPayload>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::proposal_comments::dsl as pc;
+    use crate::schema::users::dsl as u;
+
+    let user_id = auth.user_id()?;
+    let body = payload.body.trim();
+    if body.is_empty() || body.chars().count() > MAX_BODY_LEN {
+        return Err(AppError::BadRequest(format!(
+            "Comment must be 1-{} characters",
+            MAX_BODY_LEN
+        )));
+    }
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let proposal = load_visible(path.into_inner(), &auth, &mut conn)?;
+    if !is_open_for_discussion(&proposal) {
+        return Err(AppError::BadRequest(
+            "Discussion is closed once voting has ended".into(),
+        ));
+    }
+
+    diesel::insert_into(pc::proposal_comments)
+        .values((
+            pc::proposal_id.eq(proposal.id),
+            pc::user_id.eq(user_id),
+            pc::body.eq(body),
+        ))
+        .execute(&mut conn)?;
+    let comment_id: u64 = diesel::select(diesel::dsl::sql::<
+        diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+    >("LAST_INSERT_ID()"))
+    .first(&mut conn)?;
+
+    let (comment, user_name): (ProposalComment, String) = pc::proposal_comments
+        .inner_join(u::users)
+        .filter(pc::id.eq(comment_id))
+        .select((ProposalComment::as_select(), u::name))
+        .first(&mut conn)?;
+
+    Ok(HttpResponse::Created().json(ProposalCommentOut {
+        comment,
+        user_name,
+        can_delete: true,
+    }))
+}
+
+/// Delete a proposal comment
+///
+/// Removes a message from the discussion. Authors can delete their own messages;
+/// Admin and Manager can delete any.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/proposals/{id}/comments/{comment_id}",
+    params(
+        ("id" = u64, Path, description = "Proposal ID"),
+        ("comment_id" = u64, Path, description = "Comment ID")
+    ),
+    responses(
+        (status = 204, description = "Comment deleted"),
+        (status = 403, description = "Forbidden - not the author"),
+        (status = 404, description = "Proposal or comment not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_proposal_comment(
+    auth: AuthContext,
+    path: web::Path<(u64, u64)>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::proposal_comments::dsl as pc;
+
+    let (proposal_id, comment_id) = path.into_inner();
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    load_visible(proposal_id, &auth, &mut conn)?;
+    let author: u64 = pc::proposal_comments
+        .filter(pc::id.eq(comment_id))
+        .filter(pc::proposal_id.eq(proposal_id))
+        .filter(pc::is_deleted.eq(false))
+        .select(pc::user_id)
+        .first(&mut conn)
+        .optional()?
+        .ok_or(AppError::NotFound)?;
+    if author != user_id && !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+
+    diesel::update(pc::proposal_comments.filter(pc::id.eq(comment_id)))
+        .set(pc::is_deleted.eq(true))
+        .execute(&mut conn)?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
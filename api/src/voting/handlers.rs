@@ -1,10 +1,23 @@
-use super::types::{CastVotePayload, CreateProposalPayload, ProposalWithVotes};
+use super::audit;
+use super::ballots::{self, BALLOT_CHOICE, Ballot, SECRET_CHOICE};
+use super::discussion;
+use super::proxies;
+use super::seats;
+use super::types::{
+    CastVotePayload, CreateProposalPayload, OptionWithVotes, ProposalWithVotes, ProxyVoter,
+    PublishedBallot, SeatVote,
+};
 use crate::auth::{AppError, AuthContext};
 use crate::db::DbPool;
-use crate::models::{NewProposal, Proposal, ProposalResult, Vote, VoteChoice, VotingMethod};
+use crate::models::{
+    BallotType, NewProposal, Proposal, ProposalOption, ProposalResult, ProposalResultRound,
+    SecretBallot, SecretBallotSelection, SecretBallotTotal, Vote, VoteChoice, VoteSelection,
+    VotingMethod,
+};
 use crate::pagination::{PaginatedResponse, PaginationParams};
 use actix_web::{HttpResponse, Responder, web};
 use bigdecimal::{BigDecimal, FromPrimitive, Zero};
+use diesel::mysql::MysqlConnection;
 use diesel::prelude::*;
 use std::str::FromStr;
 
@@ -104,20 +117,90 @@ pub async fn get_proposal(
         .select(Vote::as_select())
         .load(&mut conn)?;
 
-    let yes_count = votes.iter().filter(|v| v.choice == "Yes").count() as i64;
-    let no_count = votes.iter().filter(|v| v.choice == "No").count() as i64;
-    let abstain_count = votes.iter().filter(|v| v.choice == "Abstain").count() as i64;
+    // Ballot contents; secret ones are only revealed by the tally
+    let cast = if proposal.secret_ballot && proposal.status != "Tallied" {
+        Vec::new()
+    } else {
+        load_cast(&proposal, &votes, &mut conn)?
+    };
+
+    let count_of = |choice: &str| -> i64 {
+        cast.iter()
+            .filter(|c| c.choice == choice)
+            .map(|c| c.ballots)
+            .sum()
+    };
+    let yes_count = count_of("Yes");
+    let no_count = count_of("No");
+    let abstain_count = count_of("Abstain");
     let total_votes = votes.len() as i64;
 
-    // Check if user has voted
-    let user_vote = votes
-        .iter()
-        .find(|v| v.user_id == user_id)
-        .map(|v| v.choice.clone());
+    // On PerSeat proposals the caller votes for each apartment they own
+    let per_seat = proposal.voting_method == VotingMethod::PerSeat.to_string();
+    let own_seats = if per_seat {
+        seats::seats_of(user_id, &proposal, &mut conn)?
+    } else {
+        Vec::new()
+    };
+
+    // Check if user has voted, in person or through a proxy
+    let own_vote = if per_seat {
+        match own_seats.as_slice() {
+            [seat] => votes
+                .iter()
+                .find(|v| v.apartment_id == Some(seat.apartment_id)),
+            _ => None,
+        }
+    } else {
+        votes.iter().find(|v| v.user_id == user_id)
+    };
+    let user_vote = own_vote.map(|v| v.choice.clone());
+    let user_vote_by_proxy = own_vote.is_some_and(|v| v.cast_by.is_some());
+
+    // Delegators the caller can vote for
+    let held = if proposal.secret_ballot {
+        Vec::new()
+    } else {
+        proxies::proxies_held(user_id, &proposal, &mut conn)?
+    };
+    let proxies_held = if held.is_empty() {
+        Vec::new()
+    } else {
+        use crate::schema::users::dsl as u;
+        let grantor_ids: Vec<u64> = held.iter().map(|x| x.grantor_id).collect();
+        let names: std::collections::HashMap<u64, String> = u::users
+            .filter(u::id.eq_any(&grantor_ids))
+            .select((u::id, u::name))
+            .load::<(u64, String)>(&mut conn)?
+            .into_iter()
+            .collect();
+        let mut out = Vec::with_capacity(held.len());
+        for x in &held {
+            // On PerSeat proposals the vote belongs to the grantor's seat, whichever
+            // co-owner cast it last, just like the caller's own vote above
+            let vote = if per_seat {
+                match seats::seats_of(x.grantor_id, &proposal, &mut conn)?.as_slice() {
+                    [seat] => votes
+                        .iter()
+                        .find(|v| v.apartment_id == Some(seat.apartment_id)),
+                    _ => None,
+                }
+            } else {
+                votes.iter().find(|v| v.user_id == x.grantor_id)
+            };
+            out.push(ProxyVoter {
+                user_id: x.grantor_id,
+                name: names.get(&x.grantor_id).cloned().unwrap_or_default(),
+                vote: vote.map(|v| v.choice.clone()),
+                voted_in_person: vote.is_some_and(|v| v.cast_by.is_none()),
+            });
+        }
+        out
+    };
 
     // Check if user is eligible to vote
     let eligible_roles: Vec<&str> = proposal.eligible_roles.split(',').collect();
-    let user_eligible = auth.has_any_role(&eligible_roles);
+    let user_eligible = auth.has_any_role(&eligible_roles) && (!per_seat || !own_seats.is_empty());
 
     // Get result if tallied
     let result: Option<ProposalResult> = pr::proposal_results
@@ -126,6 +209,91 @@ pub async fn get_proposal(
         .first(&mut conn)
         .ok();
 
+    // Option ballots: per-option counts, the caller's picks and the stored rounds
+    let mut options = Vec::new();
+    let mut user_selection = Vec::new();
+    let mut rounds = Vec::new();
+    let ballot_type = BallotType::from_str(&proposal.ballot_type).unwrap_or_default();
+    if ballot_type.has_options() {
+        let approval = ballot_type == BallotType::Approval;
+        let proposal_options = load_options(id, &mut conn)?;
+
+        options = proposal_options
+            .into_iter()
+            .map(|option| {
+                let count = cast
+                    .iter()
+                    .filter(|c| {
+                        if approval {
+                            c.selections.contains(&option.id)
+                        } else {
+                            c.selections.first() == Some(&option.id)
+                        }
+                    })
+                    .map(|c| c.ballots)
+                    .sum();
+                OptionWithVotes {
+                    option,
+                    votes: count,
+                }
+            })
+            .collect();
+
+        if let Some(own) = own_vote {
+            user_selection = selection_of(&cast, own.id);
+        }
+
+        if let Some(ref r) = result {
+            use crate::schema::proposal_result_rounds::dsl as rr;
+            rounds = rr::proposal_result_rounds
+                .filter(rr::result_id.eq(r.id))
+                .select(ProposalResultRound::as_select())
+                .order((rr::round.asc(), rr::id.asc()))
+                .load(&mut conn)?;
+        }
+    }
+
+    let seats = if own_seats.is_empty() {
+        Vec::new()
+    } else {
+        use crate::schema::users::dsl as u;
+        let seat_votes: Vec<&Vote> = own_seats
+            .iter()
+            .filter_map(|s| {
+                votes
+                    .iter()
+                    .find(|v| v.apartment_id == Some(s.apartment_id))
+            })
+            .collect();
+        let voter_ids: Vec<u64> = seat_votes.iter().map(|v| v.user_id).collect();
+        let names: std::collections::HashMap<u64, String> = if voter_ids.is_empty() {
+            Default::default()
+        } else {
+            u::users
+                .filter(u::id.eq_any(&voter_ids))
+                .select((u::id, u::name))
+                .load::<(u64, String)>(&mut conn)?
+                .into_iter()
+                .collect()
+        };
+        own_seats
+            .into_iter()
+            .map(|seat| {
+                let vote = seat_votes
+                    .iter()
+                    .find(|v| v.apartment_id == Some(seat.apartment_id));
+                SeatVote {
+                    apartment_id: seat.apartment_id,
+                    apartment_number: seat.number,
+                    building_address: seat.address,
+                    vote: vote.map(|v| v.choice.clone()),
+                    voted_by: vote.and_then(|v| names.get(&v.user_id).cloned()),
+                    selection: vote.map(|v| selection_of(&cast, v.id)).unwrap_or_default(),
+                }
+            })
+            .collect()
+    };
+
     Ok(HttpResponse::Ok().json(ProposalWithVotes {
         proposal,
         yes_count,
@@ -133,11 +301,25 @@ pub async fn get_proposal(
         abstain_count,
         total_votes,
         user_vote,
+        user_vote_by_proxy,
+        proxies_held,
         user_eligible,
         result,
+        options,
+        user_selection,
+        rounds,
+        seats,
     }))
 }
 
+/// Selected option ids of the vote `vote_id`, first preference first.
+fn selection_of(cast: &[CastBallot], vote_id: u64) -> Vec<u64> {
+    cast.iter()
+        .find(|c| c.vote_id == Some(vote_id))
+        .map(|c| c.selections.clone())
+        .unwrap_or_default()
+}
+
 /// Create a new proposal
 ///
 /// Creates a new voting proposal. Only Admin or Manager roles can create proposals.
@@ -178,8 +360,22 @@ pub async fn create_proposal(
         .map_err(|_| AppError::BadRequest("Invalid end_time format".into()))?;
 
     // Validate voting method
-    VotingMethod::from_str(&payload.voting_method)
+    let voting_method = VotingMethod::from_str(&payload.voting_method)
         .map_err(|_| AppError::BadRequest("Invalid voting_method".into()))?;
+    // A voter's weight would single out their ballot in the secret totals
+    if payload.secret_ballot && matches!(voting_method, VotingMethod::WeightedArea) {
+        return Err(AppError::BadRequest(
+            "Secret ballots are not available for weighted voting".into(),
+        ));
+    }
+
+    let mut option_labels = payload.options.clone();
+    ballots::validate_options(
+        payload.ballot_type,
+        &mut option_labels,
+        payload.max_selections,
+    )
+    .map_err(AppError::BadRequest)?;
 
     // Validate building access (if building_id is specified)
     if let Some(building_id) = payload.building_id {
@@ -214,6 +410,9 @@ pub async fn create_proposal(
         start_time,
         end_time,
         voting_method: payload.voting_method.clone(),
+        ballot_type: payload.ballot_type.to_string(),
+        max_selections: payload.max_selections,
+        secret_ballot: payload.secret_ballot,
         eligible_roles,
         status: status.to_string(),
     };
@@ -226,6 +425,9 @@ pub async fn create_proposal(
             p::start_time.eq(new_proposal.start_time),
             p::end_time.eq(new_proposal.end_time),
             p::voting_method.eq(new_proposal.voting_method),
+            p::ballot_type.eq(new_proposal.ballot_type),
+            p::max_selections.eq(new_proposal.max_selections),
+            p::secret_ballot.eq(new_proposal.secret_ballot),
             p::eligible_roles.eq(new_proposal.eligible_roles),
             p::status.eq(new_proposal.status),
         ))
@@ -237,6 +439,24 @@ pub async fn create_proposal(
     >("LAST_INSERT_ID()"))
     .first(&mut conn)?;
 
+    if !option_labels.is_empty() {
+        use crate::schema::proposal_options::dsl as po;
+        let rows: Vec<_> = option_labels
+            .into_iter()
+            .enumerate()
+            .map(|(i, label)| {
+                (
+                    po::proposal_id.eq(inserted_id),
+                    po::position.eq(i as i32),
+                    po::label.eq(label),
+                )
+            })
+            .collect();
+        diesel::insert_into(po::proposal_options)
+            .values(rows)
+            .execute(&mut conn)?;
+    }
+
     // Fetch and return the created proposal
     let created_proposal: Proposal = p::proposals
         .filter(p::id.eq(inserted_id))
@@ -251,6 +471,15 @@ pub async fn create_proposal(
 /// Allows eligible users to vote on an open proposal. If the user has already voted,
 /// this endpoint updates their existing vote. Vote weight is calculated based on the
 /// proposal's voting method (SimpleMajority, WeightedArea, PerSeat, or Consensus).
+/// Multi-option proposals take `option_ids` instead of `choice`: one option for
+/// SingleChoice, several for Approval, or a preference order for RankedChoice.
+/// A proxy holder passes `on_behalf_of` to vote with the delegator's weight; the
+/// delegator can still override that vote in person until the proposal closes.
+/// On secret proposals the ballot is final, proxies are not accepted and the
+/// response carries the voter's receipt code.
+/// On PerSeat proposals the vote is the apartment's: `apartment_id` names which of the
+/// voter's apartments it is for, any of its owners may cast it and the latest vote
+/// replaces the previous one, which stays in the apartment's history.
 #[utoipa::path(
     post,
     path = "/api/v1/proposals/{id}/vote",
@@ -260,8 +489,8 @@ pub async fn create_proposal(
     request_body = CastVotePayload,
     responses(
         (status = 200, description = "Vote cast successfully"),
-        (status = 400, description = "Invalid choice or proposal not open for voting"),
-        (status = 403, description = "Forbidden - user not eligible to vote on this proposal"),
+        (status = 400, description = "Invalid choice, proposal not open for voting or apartment not chosen"),
+        (status = 403, description = "Forbidden - user not eligible, holds no proxy for the delegator or does not own the apartment"),
         (status = 404, description = "Proposal not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -274,8 +503,6 @@ pub async fn cast_vote(
     pool: web::Data<DbPool>,
     payload: web::Json<CastVotePayload>,
 ) -> Result<impl Responder, AppError> {
-    use crate::schema::apartment_owners::dsl as ao;
-    use crate::schema::apartments::dsl as apt;
     use crate::schema::proposals::dsl as p;
     use crate::schema::votes::dsl as v;
 
@@ -285,16 +512,37 @@ pub async fn cast_vote(
         .get()
         .map_err(|_| AppError::Internal("db_pool".into()))?;
 
-    // Validate vote choice
-    VoteChoice::from_str(&payload.choice)
-        .map_err(|_| AppError::BadRequest("Invalid choice".into()))?;
-
     // Get proposal
     let proposal: Proposal = p::proposals
         .filter(p::id.eq(proposal_id))
         .select(Proposal::as_select())
         .first(&mut conn)?;
 
+    let ballot_type = BallotType::from_str(&proposal.ballot_type).map_err(|_| {
+        AppError::Internal(format!("invalid ballot type: {}", proposal.ballot_type))
+    })?;
+
+    // Validate the choice, or the selection on option ballots
+    let choice = if ballot_type.has_options() {
+        let options = load_options(proposal_id, &mut conn)?;
+        ballots::validate_selection(
+            ballot_type,
+            proposal.max_selections,
+            &options,
+            &payload.option_ids,
+        )
+        .map_err(AppError::BadRequest)?;
+        if payload.option_ids.is_empty() {
+            VoteChoice::Abstain.to_string()
+        } else {
+            BALLOT_CHOICE.to_string()
+        }
+    } else {
+        VoteChoice::from_str(&payload.choice)
+            .map_err(|_| AppError::BadRequest("Invalid choice".into()))?;
+        payload.choice.clone()
+    };
+
     // Check if proposal is open
     if proposal.status != "Open" {
         return Err(AppError::BadRequest(
@@ -302,75 +550,292 @@ pub async fn cast_vote(
         ));
     }
 
-    // Check if user is eligible
-    let eligible_roles: Vec<&str> = proposal.eligible_roles.split(',').collect();
-    if !auth.has_any_role(&eligible_roles) {
-        return Err(AppError::Forbidden);
-    }
+    // A proxy holder votes as the delegator, who must be eligible themselves
+    let (voter_id, proxy_id) = match payload.on_behalf_of {
+        Some(grantor_id) if grantor_id != user_id => {
+            let proxy = proxies::proxies_held(user_id, &proposal, &mut conn)?
+                .into_iter()
+                .find(|x| x.grantor_id == grantor_id)
+                .ok_or(AppError::Forbidden)?;
+            if !proxies::user_is_eligible(grantor_id, &proposal, &mut conn) {
+                return Err(AppError::Forbidden);
+            }
+            (grantor_id, Some(proxy.id))
+        }
+        _ => {
+            let eligible_roles: Vec<&str> = proposal.eligible_roles.split(',').collect();
+            if !auth.has_any_role(&eligible_roles) {
+                return Err(AppError::Forbidden);
+            }
+            (user_id, None)
+        }
+    };
+    let cast_by = proxy_id.map(|_| user_id);
 
     let voting_method = VotingMethod::from_str(&proposal.voting_method).map_err(|_| {
         AppError::Internal(format!("invalid voting method: {}", proposal.voting_method))
     })?;
 
-    // Calculate vote weight based on voting method
-    let weight = match voting_method {
-        VotingMethod::SimpleMajority | VotingMethod::PerSeat => BigDecimal::from(1),
-        VotingMethod::WeightedArea => {
-            // Weight by total apartment area owned by user
-            let areas: Vec<Option<f64>> = ao::apartment_owners
-                .filter(ao::user_id.eq(user_id))
-                .inner_join(apt::apartments.on(apt::id.eq(ao::apartment_id)))
-                .select(apt::size_sq_m)
-                .load(&mut conn)?;
+    let weight = voter_weight(&voting_method, voter_id, &mut conn)?;
 
-            let total: f64 = areas.into_iter().flatten().sum();
-            BigDecimal::from_f64(total).unwrap_or_default()
-        }
-        VotingMethod::Consensus => BigDecimal::from(1),
+    // One apartment, one seat: the vote is cast for one of the voter's apartments
+    let apartment_id = match voting_method {
+        VotingMethod::PerSeat => Some(seats::resolve_seat(
+            voter_id,
+            &proposal,
+            payload.apartment_id,
+            &mut conn,
+        )?),
+        _ => None,
     };
 
+    if proposal.secret_ballot {
+        if proxy_id.is_some() {
+            return Err(AppError::BadRequest(
+                "Proxy voting is not available on secret ballots".into(),
+            ));
+        }
+        let receipt = cast_secret_ballot(
+            proposal_id,
+            voter_id,
+            apartment_id,
+            &weight,
+            &choice,
+            &payload.option_ids,
+            &mut conn,
+        )?;
+        return Ok(HttpResponse::Ok().json(VoteResponse {
+            success: true,
+            choice,
+            receipt: Some(receipt),
+        }));
+    }
+
     // Check if vote already exists
-    let existing_vote: Option<Vote> = v::votes
-        .filter(v::proposal_id.eq(proposal_id))
-        .filter(v::user_id.eq(user_id))
-        .select(Vote::as_select())
-        .first(&mut conn)
-        .ok();
+    let existing_vote = find_vote(proposal_id, voter_id, apartment_id, &mut conn)?;
 
-    if let Some(existing) = existing_vote {
-        // Update existing vote
+    let vote_id = if let Some(existing) = existing_vote {
+        // A vote cast in person always wins over the proxy
+        if proxy_id.is_some() && existing.cast_by.is_none() {
+            return Err(AppError::BadRequest(
+                "The owner has already voted in person".into(),
+            ));
+        }
+        // Update existing vote; an apartment's vote passes to the owner casting it
         diesel::update(v::votes.filter(v::id.eq(existing.id)))
-            .set((v::weight_decimal.eq(&weight), v::choice.eq(&payload.choice)))
+            .set((
+                v::user_id.eq(voter_id),
+                v::weight_decimal.eq(&weight),
+                v::choice.eq(&choice),
+                v::cast_by.eq(cast_by),
+                v::proxy_id.eq(proxy_id),
+            ))
             .execute(&mut conn)?;
+        existing.id
     } else {
         // Insert new vote
         diesel::insert_into(v::votes)
             .values((
                 v::proposal_id.eq(proposal_id),
-                v::user_id.eq(user_id),
+                v::user_id.eq(voter_id),
+                v::apartment_id.eq(apartment_id),
                 v::weight_decimal.eq(&weight),
-                v::choice.eq(&payload.choice),
+                v::choice.eq(&choice),
+                v::cast_by.eq(cast_by),
+                v::proxy_id.eq(proxy_id),
             ))
             .execute(&mut conn)?;
+        diesel::select(diesel::dsl::sql::<
+            diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+        >("LAST_INSERT_ID()"))
+        .first(&mut conn)?
+    };
+
+    // Replace the ballot's selections, keeping the preference order as rank
+    if ballot_type.has_options() {
+        use crate::schema::vote_selections::dsl as vs;
+        diesel::delete(vs::vote_selections.filter(vs::vote_id.eq(vote_id))).execute(&mut conn)?;
+        if !payload.option_ids.is_empty() {
+            let rows: Vec<_> = payload
+                .option_ids
+                .iter()
+                .enumerate()
+                .map(|(i, option_id)| {
+                    (
+                        vs::vote_id.eq(vote_id),
+                        vs::option_id.eq(*option_id),
+                        vs::rank.eq(i as i32 + 1),
+                    )
+                })
+                .collect();
+            diesel::insert_into(vs::vote_selections)
+                .values(rows)
+                .execute(&mut conn)?;
+        }
     }
 
-    #[derive(serde::Serialize)]
-    struct VoteResponse {
-        success: bool,
-        choice: String,
+    if let Some(apartment_id) = apartment_id {
+        seats::record_history(
+            proposal_id,
+            apartment_id,
+            voter_id,
+            cast_by,
+            &choice,
+            &payload.option_ids,
+            &mut conn,
+        )?;
     }
 
     Ok(HttpResponse::Ok().json(VoteResponse {
         success: true,
-        choice: payload.choice.clone(),
+        choice,
+        receipt: None,
     }))
 }
 
+/// The vote of `apartment_id` on PerSeat proposals, otherwise `voter_id`'s own vote.
+fn find_vote(
+    proposal_id: u64,
+    voter_id: u64,
+    apartment_id: Option<u64>,
+    conn: &mut MysqlConnection,
+) -> Result<Option<Vote>, AppError> {
+    use crate::schema::votes::dsl as v;
+
+    let query = v::votes
+        .filter(v::proposal_id.eq(proposal_id))
+        .select(Vote::as_select())
+        .into_boxed();
+    let query = match apartment_id {
+        Some(apartment_id) => query.filter(v::apartment_id.eq(apartment_id)),
+        None => query
+            .filter(v::user_id.eq(voter_id))
+            .filter(v::apartment_id.is_null()),
+    };
+    Ok(query.first(conn).optional()?)
+}
+
+/// Weight of `user_id`'s vote under `voting_method`.
+pub(super) fn voter_weight(
+    voting_method: &VotingMethod,
+    user_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<BigDecimal, AppError> {
+    use crate::schema::apartment_owners::dsl as ao;
+    use crate::schema::apartments::dsl as apt;
+
+    Ok(match voting_method {
+        VotingMethod::SimpleMajority | VotingMethod::PerSeat => BigDecimal::from(1),
+        VotingMethod::WeightedArea => {
+            // Weight by total apartment area owned by the voter
+            let areas: Vec<Option<f64>> = ao::apartment_owners
+                .filter(ao::user_id.eq(user_id))
+                .inner_join(apt::apartments.on(apt::id.eq(ao::apartment_id)))
+                .select(apt::size_sq_m)
+                .load(conn)?;
+
+            let total: f64 = areas.into_iter().flatten().sum();
+            BigDecimal::from_f64(total).unwrap_or_default()
+        }
+        VotingMethod::Consensus => BigDecimal::from(1),
+    })
+}
+
+#[derive(serde::Serialize)]
+struct VoteResponse {
+    success: bool,
+    choice: String,
+    /// Receipt of a secret ballot, shown to the voter only once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    receipt: Option<String>,
+}
+
+/// Records participation in `votes` and stores the ballot in `secret_ballots` under a
+/// new receipt, with nothing pointing back at the voter. The weight only goes into the
+/// running total of identical ballots. Secret ballots are final.
+fn cast_secret_ballot(
+    proposal_id: u64,
+    voter_id: u64,
+    apartment_id: Option<u64>,
+    weight: &BigDecimal,
+    choice: &str,
+    option_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<String, AppError> {
+    use crate::schema::secret_ballot_selections::dsl as ss;
+    use crate::schema::secret_ballot_totals::dsl as st;
+    use crate::schema::secret_ballots::dsl as sb;
+    use crate::schema::votes::dsl as v;
+
+    if find_vote(proposal_id, voter_id, apartment_id, conn)?.is_some() {
+        return Err(AppError::BadRequest(
+            "A secret ballot cannot be changed once cast".into(),
+        ));
+    }
+
+    conn.transaction(|conn| {
+        diesel::insert_into(v::votes)
+            .values((
+                v::proposal_id.eq(proposal_id),
+                v::user_id.eq(voter_id),
+                v::apartment_id.eq(apartment_id),
+                v::weight_decimal.eq(weight),
+                v::choice.eq(SECRET_CHOICE),
+            ))
+            .execute(conn)?;
+
+        let receipt = ballots::new_receipt();
+        diesel::insert_into(sb::secret_ballots)
+            .values((
+                sb::receipt.eq(&receipt),
+                sb::proposal_id.eq(proposal_id),
+                sb::choice.eq(choice),
+            ))
+            .execute(conn)?;
+
+        if !option_ids.is_empty() {
+            let rows: Vec<_> = option_ids
+                .iter()
+                .enumerate()
+                .map(|(i, option_id)| {
+                    (
+                        ss::receipt.eq(&receipt),
+                        ss::option_id.eq(*option_id),
+                        ss::rank.eq(i as i32 + 1),
+                    )
+                })
+                .collect();
+            diesel::insert_into(ss::secret_ballot_selections)
+                .values(rows)
+                .execute(conn)?;
+        }
+
+        diesel::insert_into(st::secret_ballot_totals)
+            .values((
+                st::proposal_id.eq(proposal_id),
+                st::choice.eq(choice),
+                st::option_ids.eq(ballots::join_option_ids(option_ids)),
+                st::weight_total.eq(weight),
+                st::ballots.eq(1),
+            ))
+            .on_conflict(diesel::dsl::DuplicatedKeys)
+            .do_update()
+            .set((
+                st::weight_total.eq(st::weight_total + weight),
+                st::ballots.eq(st::ballots + 1),
+            ))
+            .execute(conn)?;
+
+        Ok::<_, AppError>(receipt)
+    })
+}
+
 /// Tally results for a proposal
 ///
 /// Calculates and stores the final results for a proposal. Only Admin or Manager roles
 /// can tally results. This determines whether the proposal passed based on the voting
-/// method and updates the proposal status to "Tallied".
+/// method and updates the proposal status to "Tallied". Multi-option proposals also
+/// record the winning option and the per-option weight of every round.
 #[utoipa::path(
     post,
     path = "/api/v1/proposals/{id}/tally",
@@ -391,9 +856,7 @@ pub async fn tally_results(
     path: web::Path<u64>,
     pool: web::Data<DbPool>,
 ) -> Result<impl Responder, AppError> {
-    use crate::schema::proposal_results::dsl as pr;
     use crate::schema::proposals::dsl as p;
-    use crate::schema::votes::dsl as v;
 
     if !auth.has_any_role(&["Admin", "Manager"]) {
         return Err(AppError::Forbidden);
@@ -410,91 +873,365 @@ pub async fn tally_results(
         .select(Proposal::as_select())
         .first(&mut conn)?;
 
-    // Get all votes
-    let votes: Vec<Vote> = v::votes
-        .filter(v::proposal_id.eq(proposal_id))
-        .select(Vote::as_select())
-        .load(&mut conn)?;
+    let (passed, winning_option_id) = tally_proposal(&proposal, &mut conn)?;
 
-    // Calculate totals
-    let yes_weight: BigDecimal = votes
-        .iter()
-        .filter(|v| v.choice == "Yes")
-        .map(|v| v.weight_decimal.clone())
-        .sum();
+    #[derive(serde::Serialize)]
+    struct TallyResponse {
+        success: bool,
+        passed: bool,
+        winning_option_id: Option<u64>,
+    }
 
-    let no_weight: BigDecimal = votes
-        .iter()
-        .filter(|v| v.choice == "No")
-        .map(|v| v.weight_decimal.clone())
-        .sum();
+    Ok(HttpResponse::Ok().json(TallyResponse {
+        success: true,
+        passed,
+        winning_option_id,
+    }))
+}
 
-    let abstain_weight: BigDecimal = votes
-        .iter()
-        .filter(|v| v.choice == "Abstain")
-        .map(|v| v.weight_decimal.clone())
-        .sum();
+/// Computes and stores the result of `proposal`, replacing an earlier one, and marks
+/// the proposal Tallied, notifying its discussion the first time. Returns whether it passed and the winning option, if any.
+pub fn tally_proposal(
+    proposal: &Proposal,
+    conn: &mut MysqlConnection,
+) -> Result<(bool, Option<u64>), AppError> {
+    use crate::schema::proposal_results::dsl as pr;
+    use crate::schema::proposals::dsl as p;
+    use crate::schema::votes::dsl as v;
 
-    let total_weight = yes_weight.clone() + no_weight.clone() + abstain_weight.clone();
+    // Get all votes and the ballots behind them
+    let votes: Vec<Vote> = v::votes
+        .filter(v::proposal_id.eq(proposal.id))
+        .select(Vote::as_select())
+        .load(conn)?;
+    let cast = load_cast(proposal, &votes, conn)?;
 
     let voting_method = VotingMethod::from_str(&proposal.voting_method).map_err(|_| {
         AppError::Internal(format!("invalid voting method: {}", proposal.voting_method))
     })?;
+    let ballot_type = BallotType::from_str(&proposal.ballot_type).map_err(|_| {
+        AppError::Internal(format!("invalid ballot type: {}", proposal.ballot_type))
+    })?;
 
-    // Determine if passed based on voting method
-    let passed = match voting_method {
-        VotingMethod::SimpleMajority | VotingMethod::WeightedArea | VotingMethod::PerSeat => {
-            yes_weight > no_weight
-        }
-        VotingMethod::Consensus => no_weight.is_zero(),
+    // On option ballots yes_weight holds the winner's final-round weight and
+    // no_weight the rest of the cast weight; the rounds carry the breakdown.
+    let mut winning_option_id = None;
+    let mut rounds = Vec::new();
+    let (passed, yes_weight, no_weight, abstain_weight, total_weight) = if ballot_type.has_options()
+    {
+        let options = load_options(proposal.id, conn)?;
+        let option_ballots: Vec<Ballot> = cast
+            .iter()
+            .map(|c| Ballot {
+                weight: c.weight.clone(),
+                selections: c.selections.clone(),
+            })
+            .collect();
+        let option_ids: Vec<u64> = options.iter().map(|o| o.id).collect();
+        let tally =
+            ballots::tally_options(ballot_type, &voting_method, &option_ids, &option_ballots);
+        winning_option_id = tally.winner;
+        rounds = tally.rounds;
+        (
+            tally.passed,
+            tally.winner_weight,
+            tally.other_weight,
+            tally.abstain_weight,
+            tally.total_weight,
+        )
+    } else {
+        // Calculate totals
+        let yes_weight: BigDecimal = cast
+            .iter()
+            .filter(|c| c.choice == "Yes")
+            .map(|c| c.weight.clone())
+            .sum();
+
+        let no_weight: BigDecimal = cast
+            .iter()
+            .filter(|c| c.choice == "No")
+            .map(|c| c.weight.clone())
+            .sum();
+
+        let abstain_weight: BigDecimal = cast
+            .iter()
+            .filter(|c| c.choice == "Abstain")
+            .map(|c| c.weight.clone())
+            .sum();
+
+        let total_weight = yes_weight.clone() + no_weight.clone() + abstain_weight.clone();
+
+        // Determine if passed based on voting method
+        let passed = match voting_method {
+            VotingMethod::SimpleMajority | VotingMethod::WeightedArea | VotingMethod::PerSeat => {
+                yes_weight > no_weight
+            }
+            VotingMethod::Consensus => no_weight.is_zero(),
+        };
+        (passed, yes_weight, no_weight, abstain_weight, total_weight)
     };
 
     // Check if result already exists
     let existing_result: Option<ProposalResult> = pr::proposal_results
-        .filter(pr::proposal_id.eq(proposal_id))
+        .filter(pr::proposal_id.eq(proposal.id))
         .select(ProposalResult::as_select())
-        .first(&mut conn)
+        .first(conn)
         .ok();
 
-    if let Some(existing) = existing_result {
+    let result_id = if let Some(existing) = existing_result {
         // Update existing result
         diesel::update(pr::proposal_results.filter(pr::id.eq(existing.id)))
             .set((
                 pr::passed.eq(passed),
+                pr::winning_option_id.eq(winning_option_id),
                 pr::yes_weight.eq(&yes_weight),
                 pr::no_weight.eq(&no_weight),
                 pr::abstain_weight.eq(&abstain_weight),
                 pr::total_weight.eq(&total_weight),
             ))
-            .execute(&mut conn)?;
+            .execute(conn)?;
+        existing.id
     } else {
         // Insert new result
         diesel::insert_into(pr::proposal_results)
             .values((
-                pr::proposal_id.eq(proposal_id),
+                pr::proposal_id.eq(proposal.id),
                 pr::passed.eq(passed),
+                pr::winning_option_id.eq(winning_option_id),
                 pr::yes_weight.eq(&yes_weight),
                 pr::no_weight.eq(&no_weight),
                 pr::abstain_weight.eq(&abstain_weight),
                 pr::total_weight.eq(&total_weight),
                 pr::method_applied_version.eq("v1"),
             ))
-            .execute(&mut conn)?;
+            .execute(conn)?;
+        diesel::select(diesel::dsl::sql::<
+            diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+        >("LAST_INSERT_ID()"))
+        .first(conn)?
+    };
+
+    // Store the round-by-round breakdown next to the result
+    {
+        use crate::schema::proposal_result_rounds::dsl as rr;
+        diesel::delete(rr::proposal_result_rounds.filter(rr::result_id.eq(result_id)))
+            .execute(conn)?;
+        if !rounds.is_empty() {
+            let rows: Vec<_> = rounds
+                .into_iter()
+                .map(|r| {
+                    (
+                        rr::result_id.eq(result_id),
+                        rr::round.eq(r.round),
+                        rr::option_id.eq(r.option_id),
+                        rr::weight.eq(r.weight),
+                        rr::eliminated.eq(r.eliminated),
+                    )
+                })
+                .collect();
+            diesel::insert_into(rr::proposal_result_rounds)
+                .values(rows)
+                .execute(conn)?;
+        }
     }
 
+    // Fingerprint what was counted so the audit report can tell if it changes later
+    audit::seal(proposal, result_id, conn)?;
+
     // Update proposal status to Tallied
-    diesel::update(p::proposals.filter(p::id.eq(proposal_id)))
+    diesel::update(p::proposals.filter(p::id.eq(proposal.id)))
         .set(p::status.eq("Tallied"))
-        .execute(&mut conn)?;
+        .execute(conn)?;
 
-    #[derive(serde::Serialize)]
-    struct TallyResponse {
-        success: bool,
-        passed: bool,
+    // A re-tally only corrects the figures; the discussion was told the first time
+    if proposal.status != "Tallied" {
+        discussion::notify_commenters(proposal, conn)?;
     }
 
-    Ok(HttpResponse::Ok().json(TallyResponse {
-        success: true,
-        passed,
-    }))
+    Ok((passed, winning_option_id))
+}
+
+/// List published secret ballots
+///
+/// Once a secret proposal is tallied, returns every counted ballot ordered by receipt
+/// so voters can check that theirs is included. Receipts are not linked to voters.
+#[utoipa::path(
+    get,
+    path = "/api/v1/proposals/{id}/receipts",
+    params(
+        ("id" = u64, Path, description = "Proposal ID")
+    ),
+    responses(
+        (status = 200, description = "Counted ballots", body = Vec<PublishedBallot>),
+        (status = 400, description = "Not a secret proposal or not tallied yet"),
+        (status = 404, description = "Proposal not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_receipts(
+    _auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::proposals::dsl as p;
+
+    let proposal_id = path.into_inner();
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let proposal: Proposal = p::proposals
+        .filter(p::id.eq(proposal_id))
+        .select(Proposal::as_select())
+        .first(&mut conn)?;
+    if !proposal.secret_ballot {
+        return Err(AppError::BadRequest(
+            "Receipts exist only for secret ballots".into(),
+        ));
+    }
+    if proposal.status != "Tallied" {
+        return Err(AppError::BadRequest(
+            "Receipts are published once the proposal is tallied".into(),
+        ));
+    }
+
+    let published: Vec<PublishedBallot> = load_secret_ballots(proposal_id, &mut conn)?
+        .into_iter()
+        .map(|(ballot, option_ids)| PublishedBallot {
+            receipt: ballot.receipt,
+            choice: ballot.choice,
+            option_ids,
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(published))
+}
+
+/// Options of a proposal in display order.
+pub(super) fn load_options(
+    proposal_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<ProposalOption>, AppError> {
+    use crate::schema::proposal_options::dsl as po;
+    Ok(po::proposal_options
+        .filter(po::proposal_id.eq(proposal_id))
+        .select(ProposalOption::as_select())
+        .order((po::position.asc(), po::id.asc()))
+        .load(conn)?)
+}
+
+/// Selections of the given votes, each ballot's first preference first.
+pub(super) fn load_selections(
+    votes: &[Vote],
+    conn: &mut MysqlConnection,
+) -> Result<Vec<VoteSelection>, AppError> {
+    use crate::schema::vote_selections::dsl as vs;
+    let vote_ids: Vec<u64> = votes.iter().map(|v| v.id).collect();
+    if vote_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(vs::vote_selections
+        .filter(vs::vote_id.eq_any(vote_ids))
+        .select(VoteSelection::as_select())
+        .order((vs::vote_id.asc(), vs::rank.asc()))
+        .load(conn)?)
+}
+
+/// A ballot's contents, from `votes` or, on secret proposals, the identical ballots
+/// summed up in `secret_ballot_totals`.
+struct CastBallot {
+    /// The `votes` row; `None` for secret ballots
+    vote_id: Option<u64>,
+    choice: String,
+    weight: BigDecimal,
+    /// Selected option ids, first preference first
+    selections: Vec<u64>,
+    /// Number of ballots this stands for; 1 on open proposals
+    ballots: i64,
+}
+
+/// Ballots cast on a proposal; on secret proposals one per distinct ballot content.
+fn load_cast(
+    proposal: &Proposal,
+    votes: &[Vote],
+    conn: &mut MysqlConnection,
+) -> Result<Vec<CastBallot>, AppError> {
+    if proposal.secret_ballot {
+        return Ok(load_secret_totals(proposal.id, conn)?
+            .into_iter()
+            .map(|total| CastBallot {
+                vote_id: None,
+                selections: ballots::split_option_ids(&total.option_ids),
+                choice: total.choice,
+                weight: total.weight_total,
+                ballots: total.ballots.into(),
+            })
+            .collect());
+    }
+    let selections = load_selections(votes, conn)?;
+    Ok(votes
+        .iter()
+        .map(|vote| CastBallot {
+            vote_id: Some(vote.id),
+            choice: vote.choice.clone(),
+            weight: vote.weight_decimal.clone(),
+            selections: selections
+                .iter()
+                .filter(|s| s.vote_id == vote.id)
+                .map(|s| s.option_id)
+                .collect(),
+            ballots: 1,
+        })
+        .collect())
+}
+
+/// Summed weight of each distinct secret ballot of a proposal, in key order.
+pub(super) fn load_secret_totals(
+    proposal_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<SecretBallotTotal>, AppError> {
+    use crate::schema::secret_ballot_totals::dsl as st;
+    Ok(st::secret_ballot_totals
+        .filter(st::proposal_id.eq(proposal_id))
+        .select(SecretBallotTotal::as_select())
+        .order((st::choice.asc(), st::option_ids.asc()))
+        .load(conn)?)
+}
+
+/// Secret ballots of a proposal ordered by receipt, each with its selected option ids.
+pub(super) fn load_secret_ballots(
+    proposal_id: u64,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<(SecretBallot, Vec<u64>)>, AppError> {
+    use crate::schema::secret_ballot_selections::dsl as ss;
+    use crate::schema::secret_ballots::dsl as sb;
+
+    let ballots: Vec<SecretBallot> = sb::secret_ballots
+        .filter(sb::proposal_id.eq(proposal_id))
+        .select(SecretBallot::as_select())
+        .order(sb::receipt.asc())
+        .load(conn)?;
+    let receipts: Vec<String> = ballots.iter().map(|b| b.receipt.clone()).collect();
+    let selections: Vec<SecretBallotSelection> = if receipts.is_empty() {
+        Vec::new()
+    } else {
+        ss::secret_ballot_selections
+            .filter(ss::receipt.eq_any(receipts))
+            .select(SecretBallotSelection::as_select())
+            .order((ss::receipt.asc(), ss::rank.asc()))
+            .load(conn)?
+    };
+    Ok(ballots
+        .into_iter()
+        .map(|ballot| {
+            let picks = selections
+                .iter()
+                .filter(|s| s.receipt == ballot.receipt)
+                .map(|s| s.option_id)
+                .collect();
+            (ballot, picks)
+        })
+        .collect())
 }
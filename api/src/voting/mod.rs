@@ -1,7 +1,18 @@
+pub mod attachments;
+mod audit;
+mod ballots;
+mod discussion;
 mod handlers;
+mod proxies;
+mod seats;
 mod types;
 
+pub use attachments::*;
+pub use audit::*;
+pub use discussion::*;
 pub use handlers::*;
+pub use proxies::*;
+pub use seats::*;
 pub use types::*;
 
 use actix_web::web;
@@ -11,5 +22,46 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .route("/proposals", web::post().to(create_proposal))
         .route("/proposals/{id}", web::get().to(get_proposal))
         .route("/proposals/{id}/vote", web::post().to(cast_vote))
-        .route("/proposals/{id}/tally", web::post().to(tally_results));
+        .route("/proposals/{id}/tally", web::post().to(tally_results))
+        .route("/proposals/{id}/receipts", web::get().to(list_receipts))
+        .route(
+            "/proposals/{id}/seats/{apartment_id}/history",
+            web::get().to(get_seat_history),
+        )
+        .route(
+            "/proposals/{id}/audit-report",
+            web::get().to(get_audit_report),
+        )
+        .route(
+            "/proposals/{id}/comments",
+            web::get().to(list_proposal_comments),
+        )
+        .route(
+            "/proposals/{id}/comments",
+            web::post().to(create_proposal_comment),
+        )
+        .route(
+            "/proposals/{id}/comments/{comment_id}",
+            web::delete().to(delete_proposal_comment),
+        )
+        .route(
+            "/proposals/{id}/attachments",
+            web::get().to(list_proposal_attachments),
+        )
+        .route(
+            "/proposals/{id}/attachments",
+            web::post().to(upload_proposal_attachment),
+        )
+        .route(
+            "/proposals/{id}/attachments/{att_id}/download",
+            web::get().to(download_proposal_attachment),
+        )
+        .route(
+            "/proposals/{id}/attachments/{att_id}",
+            web::delete().to(delete_proposal_attachment),
+        )
+        .route("/proxies", web::get().to(list_proxies))
+        .route("/proxies", web::post().to(grant_proxy))
+        .route("/proxies/mine", web::get().to(my_proxies))
+        .route("/proxies/{id}", web::delete().to(revoke_proxy));
 }
@@ -0,0 +1,476 @@
+use super::types::{GrantProxyPayload, MyProxies, ProxyListQuery, ProxyWithNames};
+use crate::auth::roles::{get_user_roles, has_any_role};
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::models::{Proposal, VoteProxy};
+use actix_web::{HttpResponse, Responder, web};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::HashMap;
+
+/// Proxies covering `proposal` that `grantee_id` holds, at most one per delegator.
+/// A proxy for the proposal itself wins over a building-wide one.
+pub(super) fn proxies_held(
+    grantee_id: u64,
+    proposal: &Proposal,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<VoteProxy>, AppError> {
+    use crate::schema::vote_proxies::dsl as vp;
+
+    let mut proxies: Vec<VoteProxy> = vp::vote_proxies
+        .filter(vp::grantee_id.eq(grantee_id))
+        .filter(vp::revoked_at.is_null())
+        .select(VoteProxy::as_select())
+        .load(conn)?;
+    proxies.retain(|p| p.covers(proposal));
+    proxies.sort_by_key(|p| (p.grantor_id, p.proposal_id.is_none(), p.id));
+    proxies.dedup_by_key(|p| p.grantor_id);
+    Ok(proxies)
+}
+
+/// Whether `user_id` may vote on `proposal` under its eligible roles.
+pub(super) fn user_is_eligible(
+    user_id: u64,
+    proposal: &Proposal,
+    conn: &mut MysqlConnection,
+) -> bool {
+    let eligible_roles: Vec<&str> = proposal.eligible_roles.split(',').collect();
+    has_any_role(&get_user_roles(user_id, conn), &eligible_roles)
+}
+
+fn parse_datetime(value: Option<&str>, field: &str) -> Result<chrono::NaiveDateTime, AppError> {
+    value
+        .and_then(|v| chrono::NaiveDateTime::parse_from_str(v, "%Y-%m-%dT%H:%M").ok())
+        .ok_or_else(|| AppError::BadRequest(format!("Invalid {} format", field)))
+}
+
+/// Attaches user names and proposal titles to proxies.
+fn with_names(
+    proxies: Vec<VoteProxy>,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<ProxyWithNames>, AppError> {
+    use crate::schema::proposals::dsl as p;
+    use crate::schema::users::dsl as u;
+
+    let mut user_ids: Vec<u64> = proxies
+        .iter()
+        .flat_map(|x| [x.grantor_id, x.grantee_id])
+        .collect();
+    user_ids.sort_unstable();
+    user_ids.dedup();
+    let names: HashMap<u64, String> = u::users
+        .filter(u::id.eq_any(&user_ids))
+        .select((u::id, u::name))
+        .load::<(u64, String)>(conn)?
+        .into_iter()
+        .collect();
+
+    let proposal_ids: Vec<u64> = proxies.iter().filter_map(|x| x.proposal_id).collect();
+    let titles: HashMap<u64, String> = if proposal_ids.is_empty() {
+        HashMap::new()
+    } else {
+        p::proposals
+            .filter(p::id.eq_any(&proposal_ids))
+            .select((p::id, p::title))
+            .load::<(u64, String)>(conn)?
+            .into_iter()
+            .collect()
+    };
+
+    Ok(proxies
+        .into_iter()
+        .map(|proxy| ProxyWithNames {
+            grantor_name: names.get(&proxy.grantor_id).cloned().unwrap_or_default(),
+            grantee_name: names.get(&proxy.grantee_id).cloned().unwrap_or_default(),
+            proposal_title: proxy.proposal_id.and_then(|id| titles.get(&id).cloned()),
+            proxy,
+        })
+        .collect())
+}
+
+/// Grant a proxy
+///
+/// Delegates the caller's vote to another user, either for one proposal or for
+/// every proposal of a building that starts within a date range. Building-wide
+/// proxies require the caller to own an apartment in the building. The caller can
+/// still vote in person until the proposal closes, which overrides the proxy.
+#[utoipa::path(
+    post,
+    path = "/api/v1/proxies",
+    request_body = GrantProxyPayload,
+    responses(
+        (status = 201, description = "Proxy granted", body = ProxyWithNames),
+        (status = 400, description = "Invalid scope, dates, grantee, or an overlapping proxy exists"),
+        (status = 403, description = "Forbidden - not eligible for the proposal or not an owner in the building"),
+        (status = 404, description = "Grantee or proposal not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn grant_proxy(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+    payload: web::Json<GrantProxyPayload>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::apartment_owners::dsl as ao;
+    use crate::schema::apartments::dsl as apt;
+    use crate::schema::proposals::dsl as p;
+    use crate::schema::users::dsl as u;
+    use crate::schema::vote_proxies::dsl as vp;
+
+    let grantor_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    if payload.grantee_id == grantor_id {
+        return Err(AppError::BadRequest(
+            "Cannot grant a proxy to yourself".into(),
+        ));
+    }
+    u::users
+        .filter(u::id.eq(payload.grantee_id))
+        .select(u::id)
+        .first::<u64>(&mut conn)?;
+
+    let existing: Vec<VoteProxy> = vp::vote_proxies
+        .filter(vp::grantor_id.eq(grantor_id))
+        .filter(vp::revoked_at.is_null())
+        .select(VoteProxy::as_select())
+        .load(&mut conn)?;
+
+    let (valid_from, valid_until) = match (payload.proposal_id, payload.building_id) {
+        (Some(proposal_id), None) => {
+            let proposal: Proposal = p::proposals
+                .filter(p::id.eq(proposal_id))
+                .select(Proposal::as_select())
+                .first(&mut conn)?;
+            if proposal.status == "Closed" || proposal.status == "Tallied" {
+                return Err(AppError::BadRequest("Proposal is already closed".into()));
+            }
+            if proposal.secret_ballot {
+                return Err(AppError::BadRequest(
+                    "Proxy voting is not available on secret ballots".into(),
+                ));
+            }
+            if !user_is_eligible(grantor_id, &proposal, &mut conn) {
+                return Err(AppError::Forbidden);
+            }
+            if existing.iter().any(|x| x.proposal_id == Some(proposal_id)) {
+                return Err(AppError::BadRequest(
+                    "A proxy for this proposal already exists; revoke it first".into(),
+                ));
+            }
+            (None, None)
+        }
+        (None, Some(building_id)) => {
+            let from = parse_datetime(payload.valid_from.as_deref(), "valid_from")?;
+            let until = parse_datetime(payload.valid_until.as_deref(), "valid_until")?;
+            if from >= until {
+                return Err(AppError::BadRequest(
+                    "valid_until must be after valid_from".into(),
+                ));
+            }
+            let owns = ao::apartment_owners
+                .inner_join(apt::apartments.on(apt::id.eq(ao::apartment_id)))
+                .filter(ao::user_id.eq(grantor_id))
+                .filter(apt::building_id.eq(building_id))
+                .filter(apt::is_deleted.eq(false))
+                .count()
+                .get_result::<i64>(&mut conn)?;
+            if owns == 0 {
+                return Err(AppError::Forbidden);
+            }
+            let overlaps = existing.iter().any(|x| {
+                x.building_id == Some(building_id)
+                    && x.valid_from.is_some_and(|f| f <= until)
+                    && x.valid_until.is_some_and(|u| u >= from)
+            });
+            if overlaps {
+                return Err(AppError::BadRequest(
+                    "An overlapping proxy for this building already exists; revoke it first".into(),
+                ));
+            }
+            (Some(from), Some(until))
+        }
+        _ => {
+            return Err(AppError::BadRequest(
+                "Specify either proposal_id or building_id".into(),
+            ));
+        }
+    };
+
+    diesel::insert_into(vp::vote_proxies)
+        .values((
+            vp::grantor_id.eq(grantor_id),
+            vp::grantee_id.eq(payload.grantee_id),
+            vp::proposal_id.eq(payload.proposal_id),
+            vp::building_id.eq(payload.building_id),
+            vp::valid_from.eq(valid_from),
+            vp::valid_until.eq(valid_until),
+        ))
+        .execute(&mut conn)?;
+
+    let inserted_id: u64 = diesel::select(diesel::dsl::sql::<
+        diesel::sql_types::Unsigned<diesel::sql_types::BigInt>,
+    >("LAST_INSERT_ID()"))
+    .first(&mut conn)?;
+
+    let proxy: VoteProxy = vp::vote_proxies
+        .filter(vp::id.eq(inserted_id))
+        .select(VoteProxy::as_select())
+        .first(&mut conn)?;
+    let created = with_names(vec![proxy], &mut conn)?.pop();
+
+    Ok(HttpResponse::Created().json(created))
+}
+
+/// List my proxies
+///
+/// Returns the active proxies the caller has granted and those they hold.
+#[utoipa::path(
+    get,
+    path = "/api/v1/proxies/mine",
+    responses(
+        (status = 200, description = "Granted and received proxies", body = MyProxies),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn my_proxies(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::vote_proxies::dsl as vp;
+
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let proxies: Vec<VoteProxy> = vp::vote_proxies
+        .filter(vp::grantor_id.eq(user_id).or(vp::grantee_id.eq(user_id)))
+        .filter(vp::revoked_at.is_null())
+        .select(VoteProxy::as_select())
+        .order(vp::created_at.desc())
+        .load(&mut conn)?;
+
+    let (granted, received): (Vec<_>, Vec<_>) = with_names(proxies, &mut conn)?
+        .into_iter()
+        .partition(|x| x.proxy.grantor_id == user_id);
+
+    Ok(HttpResponse::Ok().json(MyProxies { granted, received }))
+}
+
+/// List proxies (Admin/Manager only)
+///
+/// Returns proxies in the buildings the caller manages, optionally filtered
+/// by proposal or building. Proxies for global proposals are always included.
+#[utoipa::path(
+    get,
+    path = "/api/v1/proxies",
+    params(ProxyListQuery),
+    responses(
+        (status = 200, description = "Proxies", body = Vec<ProxyWithNames>),
+        (status = 403, description = "Forbidden - requires Admin or Manager role"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_proxies(
+    auth: AuthContext,
+    pool: web::Data<DbPool>,
+    query: web::Query<ProxyListQuery>,
+) -> Result<impl Responder, AppError> {
+    use crate::auth::get_user_building_ids;
+    use crate::schema::proposals::dsl as p;
+    use crate::schema::vote_proxies::dsl as vp;
+
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        return Err(AppError::Forbidden);
+    }
+
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+    let building_ids = get_user_building_ids(user_id, auth.has_any_role(&["Admin"]), &mut conn)?;
+
+    let mut q = vp::vote_proxies.into_boxed();
+    if let Some(proposal_id) = query.proposal_id {
+        q = q.filter(vp::proposal_id.eq(proposal_id));
+    }
+    if let Some(building_id) = query.building_id {
+        q = q.filter(vp::building_id.eq(building_id));
+    }
+    if !query.include_revoked.unwrap_or(false) {
+        q = q.filter(vp::revoked_at.is_null());
+    }
+    let mut proxies: Vec<VoteProxy> = q
+        .select(VoteProxy::as_select())
+        .order(vp::created_at.desc())
+        .load(&mut conn)?;
+
+    if let Some(ids) = building_ids {
+        let proposal_ids: Vec<u64> = proxies.iter().filter_map(|x| x.proposal_id).collect();
+        let proposal_buildings: HashMap<u64, Option<u64>> = p::proposals
+            .filter(p::id.eq_any(&proposal_ids))
+            .select((p::id, p::building_id))
+            .load::<(u64, Option<u64>)>(&mut conn)?
+            .into_iter()
+            .collect();
+        proxies.retain(|x| {
+            let building = match x.proposal_id {
+                Some(id) => proposal_buildings.get(&id).copied().flatten(),
+                None => x.building_id,
+            };
+            building.is_none_or(|b| ids.contains(&b))
+        });
+    }
+
+    Ok(HttpResponse::Ok().json(with_names(proxies, &mut conn)?))
+}
+
+/// Revoke a proxy
+///
+/// The grantor, an Admin or a Manager of the proxy's building can revoke a proxy.
+/// Proxies for global proposals can be revoked by any Manager. Votes already cast
+/// through it stay recorded; the grantor can still override them in person.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/proxies/{id}",
+    params(
+        ("id" = u64, Path, description = "Proxy ID")
+    ),
+    responses(
+        (status = 204, description = "Proxy revoked"),
+        (status = 403, description = "Forbidden - not the grantor"),
+        (status = 404, description = "Proxy not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn revoke_proxy(
+    auth: AuthContext,
+    path: web::Path<u64>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::auth::get_user_building_ids;
+    use crate::schema::proposals::dsl as p;
+    use crate::schema::vote_proxies::dsl as vp;
+
+    let id = path.into_inner();
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    let proxy: VoteProxy = vp::vote_proxies
+        .filter(vp::id.eq(id))
+        .select(VoteProxy::as_select())
+        .first(&mut conn)?;
+
+    if proxy.grantor_id != user_id {
+        if !auth.has_any_role(&["Admin", "Manager"]) {
+            return Err(AppError::Forbidden);
+        }
+        // Same scope as list_proxies
+        let building_ids =
+            get_user_building_ids(user_id, auth.has_any_role(&["Admin"]), &mut conn)?;
+        if let Some(ids) = building_ids {
+            let building = match proxy.proposal_id {
+                Some(proposal_id) => p::proposals
+                    .filter(p::id.eq(proposal_id))
+                    .select(p::building_id)
+                    .first::<Option<u64>>(&mut conn)
+                    .optional()?
+                    .flatten(),
+                None => proxy.building_id,
+            };
+            if building.is_some_and(|b| !ids.contains(&b)) {
+                return Err(AppError::Forbidden);
+            }
+        }
+    }
+
+    if proxy.revoked_at.is_none() {
+        diesel::update(vp::vote_proxies.filter(vp::id.eq(id)))
+            .set(vp::revoked_at.eq(chrono::Local::now().naive_local()))
+            .execute(&mut conn)?;
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(day: u32) -> chrono::NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 4, day)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap()
+    }
+
+    fn proposal(id: u64, building_id: Option<u64>, start: u32) -> Proposal {
+        Proposal {
+            id,
+            title: "Roof".into(),
+            description: String::new(),
+            created_by: 1,
+            building_id,
+            start_time: at(start),
+            end_time: at(start + 5),
+            voting_method: "SimpleMajority".into(),
+            ballot_type: "YesNo".into(),
+            max_selections: None,
+            secret_ballot: false,
+            eligible_roles: "Homeowner".into(),
+            status: "Open".into(),
+            created_at: None,
+        }
+    }
+
+    fn proxy(proposal_id: Option<u64>, building_id: Option<u64>) -> VoteProxy {
+        VoteProxy {
+            id: 1,
+            grantor_id: 2,
+            grantee_id: 3,
+            proposal_id,
+            building_id,
+            valid_from: building_id.map(|_| at(1)),
+            valid_until: building_id.map(|_| at(15)),
+            created_at: None,
+            revoked_at: None,
+        }
+    }
+
+    #[test]
+    fn proposal_proxy_covers_only_its_proposal() {
+        let p = proxy(Some(7), None);
+        assert!(p.covers(&proposal(7, Some(1), 3)));
+        assert!(!p.covers(&proposal(8, Some(1), 3)));
+    }
+
+    #[test]
+    fn building_proxy_covers_proposals_starting_in_range() {
+        let p = proxy(None, Some(1));
+        assert!(p.covers(&proposal(7, Some(1), 15)));
+        assert!(!p.covers(&proposal(7, Some(1), 16)));
+        assert!(!p.covers(&proposal(7, Some(2), 3)));
+        assert!(!p.covers(&proposal(7, None, 3)));
+    }
+
+    #[test]
+    fn revoked_proxy_covers_nothing() {
+        let mut p = proxy(Some(7), None);
+        p.revoked_at = Some(at(2));
+        assert!(!p.covers(&proposal(7, Some(1), 3)));
+    }
+}
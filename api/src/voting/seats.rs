@@ -0,0 +1,282 @@
+//! One apartment, one seat.
+//!
+//! On PerSeat proposals every apartment has a single vote, whichever of its owners
+//! casts it. The apartment's `votes` row holds its current vote and is taken over by
+//! the latest owner to vote; each cast is also kept in `apartment_vote_history`, so an
+//! owner overriding a co-owner stays traceable.
+
+use super::types::SeatHistoryEntry;
+use crate::auth::{AppError, AuthContext};
+use crate::db::DbPool;
+use crate::models::{ApartmentVoteHistory, Proposal};
+use actix_web::{HttpResponse, Responder, web};
+use diesel::mysql::MysqlConnection;
+use diesel::prelude::*;
+use std::collections::HashMap;
+
+/// An apartment with a seat on a proposal
+pub(super) struct Seat {
+    pub apartment_id: u64,
+    pub number: String,
+    pub address: String,
+}
+
+impl Seat {
+    pub fn label(&self) -> String {
+        format!("{}, apt. {}", self.address, self.number)
+    }
+}
+
+type SeatRow = (u64, String, String);
+
+fn to_seat((apartment_id, number, address): SeatRow) -> Seat {
+    Seat {
+        apartment_id,
+        number,
+        address,
+    }
+}
+
+/// Apartments `user_id` owns that have a seat on `proposal`: those in its building,
+/// or all of them on proposals for every building.
+pub(super) fn seats_of(
+    user_id: u64,
+    proposal: &Proposal,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<Seat>, AppError> {
+    use crate::schema::apartment_owners::dsl as ao;
+    use crate::schema::apartments::dsl as apt;
+    use crate::schema::buildings::dsl as b;
+
+    let mut query = ao::apartment_owners
+        .inner_join(apt::apartments.on(apt::id.eq(ao::apartment_id)))
+        .inner_join(b::buildings.on(b::id.eq(apt::building_id)))
+        .filter(ao::user_id.eq(user_id))
+        .filter(apt::is_deleted.eq(false))
+        .filter(b::is_deleted.eq(false))
+        .select((apt::id, apt::number, b::address))
+        .into_boxed();
+    if let Some(building_id) = proposal.building_id {
+        query = query.filter(apt::building_id.eq(building_id));
+    }
+    Ok(query
+        .order((b::address.asc(), apt::number.asc()))
+        .load::<SeatRow>(conn)?
+        .into_iter()
+        .map(to_seat)
+        .collect())
+}
+
+/// The seat `voter_id` votes with: the requested apartment, which they must own, or
+/// their only one when they have a single seat.
+pub(super) fn resolve_seat(
+    voter_id: u64,
+    proposal: &Proposal,
+    requested: Option<u64>,
+    conn: &mut MysqlConnection,
+) -> Result<u64, AppError> {
+    let seats = seats_of(voter_id, proposal, conn)?;
+    match (requested, seats.as_slice()) {
+        (Some(apartment_id), _) => seats
+            .iter()
+            .any(|s| s.apartment_id == apartment_id)
+            .then_some(apartment_id)
+            .ok_or(AppError::Forbidden),
+        (None, [seat]) => Ok(seat.apartment_id),
+        (None, []) => Err(AppError::Forbidden),
+        (None, _) => Err(AppError::BadRequest(
+            "Choose the apartment to vote for".into(),
+        )),
+    }
+}
+
+/// Apartments with a seat on `proposal`: those owned by at least one user holding an
+/// eligible role, within the proposal's building if it has one.
+pub(super) fn eligible_seats(
+    proposal: &Proposal,
+    conn: &mut MysqlConnection,
+) -> Result<Vec<Seat>, AppError> {
+    use crate::schema::apartment_owners::dsl as ao;
+    use crate::schema::apartments::dsl as apt;
+    use crate::schema::buildings::dsl as b;
+    use crate::schema::roles::dsl as r;
+    use crate::schema::user_roles::dsl as ur;
+
+    let roles: Vec<&str> = proposal.eligible_roles.split(',').collect();
+    let owners: Vec<u64> = ur::user_roles
+        .inner_join(r::roles.on(r::id.eq(ur::role_id)))
+        .filter(r::name.eq_any(roles))
+        .select(ur::user_id)
+        .distinct()
+        .load(conn)?;
+
+    let mut query = apt::apartments
+        .inner_join(b::buildings.on(b::id.eq(apt::building_id)))
+        .filter(apt::is_deleted.eq(false))
+        .filter(b::is_deleted.eq(false))
+        .filter(
+            apt::id.eq_any(
+                ao::apartment_owners
+                    .filter(ao::user_id.eq_any(owners))
+                    .select(ao::apartment_id),
+            ),
+        )
+        .select((apt::id, apt::number, b::address))
+        .into_boxed();
+    if let Some(building_id) = proposal.building_id {
+        query = query.filter(apt::building_id.eq(building_id));
+    }
+    Ok(query
+        .order((b::address.asc(), apt::number.asc()))
+        .load::<SeatRow>(conn)?
+        .into_iter()
+        .map(to_seat)
+        .collect())
+}
+
+/// Labels of the given apartments, deleted ones included.
+pub(super) fn seat_labels(
+    apartment_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<HashMap<u64, String>, AppError> {
+    use crate::schema::apartments::dsl as apt;
+    use crate::schema::buildings::dsl as b;
+
+    if apartment_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    Ok(apt::apartments
+        .inner_join(b::buildings.on(b::id.eq(apt::building_id)))
+        .filter(apt::id.eq_any(apartment_ids))
+        .select((apt::id, apt::number, b::address))
+        .load::<SeatRow>(conn)?
+        .into_iter()
+        .map(|row| {
+            let seat = to_seat(row);
+            (seat.apartment_id, seat.label())
+        })
+        .collect())
+}
+
+/// Append a cast of an apartment's vote to its history.
+pub(super) fn record_history(
+    proposal_id: u64,
+    apartment_id: u64,
+    voter_id: u64,
+    cast_by: Option<u64>,
+    choice: &str,
+    option_ids: &[u64],
+    conn: &mut MysqlConnection,
+) -> Result<(), AppError> {
+    use crate::schema::apartment_vote_history::dsl as h;
+
+    let option_ids = option_ids
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    diesel::insert_into(h::apartment_vote_history)
+        .values((
+            h::proposal_id.eq(proposal_id),
+            h::apartment_id.eq(apartment_id),
+            h::user_id.eq(voter_id),
+            h::cast_by.eq(cast_by),
+            h::choice.eq(choice),
+            h::option_ids.eq(option_ids),
+            h::created_at.eq(chrono::Local::now().naive_local()),
+        ))
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Vote history of an apartment
+///
+/// Lists every vote cast for an apartment on a PerSeat proposal, oldest first; the
+/// last entry is the apartment's current vote. Available to the apartment's owners
+/// and to Admin or Manager. Secret ballots keep no history, as they cannot be changed.
+#[utoipa::path(
+    get,
+    path = "/api/v1/proposals/{id}/seats/{apartment_id}/history",
+    params(
+        ("id" = u64, Path, description = "Proposal ID"),
+        ("apartment_id" = u64, Path, description = "Apartment ID")
+    ),
+    responses(
+        (status = 200, description = "Votes cast for the apartment", body = Vec<SeatHistoryEntry>),
+        (status = 403, description = "Forbidden - not an owner of the apartment"),
+        (status = 404, description = "Proposal not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Voting",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_seat_history(
+    auth: AuthContext,
+    path: web::Path<(u64, u64)>,
+    pool: web::Data<DbPool>,
+) -> Result<impl Responder, AppError> {
+    use crate::schema::apartment_owners::dsl as ao;
+    use crate::schema::apartment_vote_history::dsl as h;
+    use crate::schema::proposals::dsl as p;
+    use crate::schema::users::dsl as u;
+
+    let (proposal_id, apartment_id) = path.into_inner();
+    let user_id = auth.user_id()?;
+    let mut conn = pool
+        .get()
+        .map_err(|_| AppError::Internal("db_pool".into()))?;
+
+    // 404 for unknown proposals before the ownership check
+    p::proposals
+        .filter(p::id.eq(proposal_id))
+        .select(p::id)
+        .first::<u64>(&mut conn)?;
+
+    if !auth.has_any_role(&["Admin", "Manager"]) {
+        let owns: i64 = ao::apartment_owners
+            .filter(ao::apartment_id.eq(apartment_id))
+            .filter(ao::user_id.eq(user_id))
+            .count()
+            .get_result(&mut conn)?;
+        if owns == 0 {
+            return Err(AppError::Forbidden);
+        }
+    }
+
+    let history: Vec<ApartmentVoteHistory> = h::apartment_vote_history
+        .filter(h::proposal_id.eq(proposal_id))
+        .filter(h::apartment_id.eq(apartment_id))
+        .order((h::created_at.asc(), h::id.asc()))
+        .select(ApartmentVoteHistory::as_select())
+        .load(&mut conn)?;
+
+    let mut user_ids: Vec<u64> = history.iter().map(|e| e.user_id).collect();
+    user_ids.extend(history.iter().filter_map(|e| e.cast_by));
+    let names: HashMap<u64, String> = if user_ids.is_empty() {
+        HashMap::new()
+    } else {
+        u::users
+            .filter(u::id.eq_any(&user_ids))
+            .select((u::id, u::name))
+            .load::<(u64, String)>(&mut conn)?
+            .into_iter()
+            .collect()
+    };
+
+    let entries: Vec<SeatHistoryEntry> = history
+        .into_iter()
+        .map(|e| SeatHistoryEntry {
+            user_name: names.get(&e.user_id).cloned().unwrap_or_default(),
+            cast_by_name: e.cast_by.and_then(|id| names.get(&id).cloned()),
+            choice: e.choice,
+            option_ids: e
+                .option_ids
+                .split(',')
+                .filter_map(|id| id.parse().ok())
+                .collect(),
+            created_at: e.created_at,
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(entries))
+}
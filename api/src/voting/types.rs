@@ -1,8 +1,12 @@
-use crate::models::{Proposal, ProposalResult};
+use crate::models::{
+    BallotType, Proposal, ProposalComment, ProposalOption, ProposalResult, ProposalResultRound,
+    VoteProxy,
+};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
-/// Get a single proposal with vote counts
+/// Get a single proposal with vote counts. On secret proposals the counts stay
+/// at zero until the tally; `total_votes` still reports participation.
 #[derive(Serialize, ToSchema)]
 pub struct ProposalWithVotes {
     #[serde(flatten)]
@@ -14,6 +18,65 @@ pub struct ProposalWithVotes {
     pub user_vote: Option<String>,
     pub user_eligible: bool,
     pub result: Option<ProposalResult>,
+    /// Options of a multi-option ballot, in display order
+    pub options: Vec<OptionWithVotes>,
+    /// The caller's selected option ids, first preference first
+    pub user_selection: Vec<u64>,
+    /// Round-by-round weights once tallied
+    pub rounds: Vec<ProposalResultRound>,
+    /// Whether the caller's vote was cast for them by a proxy holder
+    pub user_vote_by_proxy: bool,
+    /// Voters the caller holds a proxy for on this proposal
+    pub proxies_held: Vec<ProxyVoter>,
+    /// On PerSeat proposals, the apartments the caller owns and their current votes.
+    /// `user_vote` and `user_selection` then describe the seat only when there is one.
+    pub seats: Vec<SeatVote>,
+}
+
+/// An apartment whose vote the caller may cast on a PerSeat proposal
+#[derive(Serialize, ToSchema)]
+pub struct SeatVote {
+    pub apartment_id: u64,
+    pub apartment_number: String,
+    pub building_address: String,
+    /// The apartment's current vote, whichever owner cast it
+    pub vote: Option<String>,
+    /// Owner the current vote was cast by or for
+    pub voted_by: Option<String>,
+    /// Selected option ids of the current vote, first preference first
+    pub selection: Vec<u64>,
+}
+
+/// A vote cast for an apartment, as listed in its history
+#[derive(Serialize, ToSchema)]
+pub struct SeatHistoryEntry {
+    /// Owner whose vote it was
+    pub user_name: String,
+    /// Proxy holder who cast it for the owner
+    pub cast_by_name: Option<String>,
+    pub choice: String,
+    pub option_ids: Vec<u64>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+/// A delegator the caller may vote for
+#[derive(Serialize, ToSchema)]
+pub struct ProxyVoter {
+    pub user_id: u64,
+    pub name: String,
+    /// Choice already recorded for the delegator, by the proxy or in person
+    pub vote: Option<String>,
+    /// Whether the delegator voted in person, which the proxy cannot override
+    pub voted_in_person: bool,
+}
+
+/// A ballot option with the number of ballots that picked it
+/// (as first preference for ranked choice)
+#[derive(Serialize, ToSchema)]
+pub struct OptionWithVotes {
+    #[serde(flatten)]
+    pub option: ProposalOption,
+    pub votes: i64,
 }
 
 /// Create a new proposal (Admin/Manager only)
@@ -29,11 +92,113 @@ pub struct CreateProposalPayload {
     #[schema(example = "SimpleMajority")]
     pub voting_method: String,
     pub eligible_roles: Vec<String>,
+    #[serde(default)]
+    pub ballot_type: BallotType,
+    /// Option labels, required for every ballot type except `YesNo`
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// Cap on approval ballot selections
+    #[serde(default)]
+    pub max_selections: Option<i32>,
+    /// Keep ballots unlinkable to voters; each voter gets a receipt code instead.
+    /// Not available with `WeightedArea`.
+    #[serde(default)]
+    pub secret_ballot: bool,
 }
 
 /// Cast a vote on a proposal
 #[derive(Deserialize, ToSchema)]
 pub struct CastVotePayload {
     #[schema(example = "Yes")]
+    #[serde(default)]
     pub choice: String, // "Yes", "No", "Abstain"
+    /// Vote for this delegator using a proxy they granted the caller
+    #[serde(default)]
+    pub on_behalf_of: Option<u64>,
+    /// Selected option ids for multi-option ballots, in order of preference for
+    /// ranked choice. An empty list abstains.
+    #[serde(default)]
+    pub option_ids: Vec<u64>,
+    /// Apartment to vote for on PerSeat proposals; may be left out by owners of a
+    /// single apartment
+    #[serde(default)]
+    pub apartment_id: Option<u64>,
+}
+
+/// Grant a proxy: either `proposal_id`, or `building_id` with a date range
+#[derive(Deserialize, ToSchema)]
+pub struct GrantProxyPayload {
+    pub grantee_id: u64,
+    pub proposal_id: Option<u64>,
+    pub building_id: Option<u64>,
+    #[schema(example = "2026-04-01T00:00")]
+    pub valid_from: Option<String>,
+    #[schema(example = "2026-06-30T23:59")]
+    pub valid_until: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ProxyWithNames {
+    #[serde(flatten)]
+    pub proxy: VoteProxy,
+    pub grantor_name: String,
+    pub grantee_name: String,
+    pub proposal_title: Option<String>,
+}
+
+/// Proxies the caller granted and holds
+#[derive(Serialize, ToSchema)]
+pub struct MyProxies {
+    pub granted: Vec<ProxyWithNames>,
+    pub received: Vec<ProxyWithNames>,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct ProxyListQuery {
+    pub proposal_id: Option<u64>,
+    pub building_id: Option<u64>,
+    /// Include revoked proxies (default false)
+    pub include_revoked: Option<bool>,
+}
+
+/// File format of the audit report
+#[derive(Deserialize, Clone, Copy, Default, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    #[default]
+    Pdf,
+    Csv,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct AuditReportQuery {
+    /// `pdf` (default) or `csv`
+    pub format: Option<ReportFormat>,
+}
+
+/// A counted secret ballot as published after the tally; weights are only published
+/// as totals, since a voter's weight would identify their ballot
+#[derive(Serialize, ToSchema)]
+pub struct PublishedBallot {
+    #[schema(example = "K7QM-2XDA-4HPW")]
+    pub receipt: String,
+    pub choice: String,
+    /// Selected option ids, first preference first
+    pub option_ids: Vec<u64>,
+}
+
+/// Post to a proposal's discussion
+#[derive(Deserialize, ToSchema)]
+pub struct CreateProposalCommentPayload {
+    pub body: String,
+}
+
+/// A discussion message with its author
+#[derive(Serialize, ToSchema)]
+pub struct ProposalCommentOut {
+    #[serde(flatten)]
+    pub comment: ProposalComment,
+    pub user_name: String,
+    /// Whether the caller may delete the message
+    pub can_delete: bool,
 }
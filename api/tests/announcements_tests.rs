@@ -0,0 +1,1182 @@
+mod common;
+
+use common::{TestServer, TestUser, create_and_login_user};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+async fn create_building(
+    client: &reqwest::Client,
+    base_url: &str,
+    token: &str,
+    address: &str,
+) -> u64 {
+    let response = client
+        .post(format!("{}/buildings", base_url))
+        .bearer_auth(token)
+        .json(&serde_json::json!({
+            "address": address,
+        }))
+        .send()
+        .await
+        .expect("Failed to create building");
+    let building: Value = response.json().await.expect("Failed to parse response");
+    building["id"].as_u64().expect("No building ID")
+}
+
+async fn create_announcement(
+    client: &reqwest::Client,
+    base_url: &str,
+    token: &str,
+    title: &str,
+    building_ids: &[u64],
+) -> Value {
+    let response = client
+        .post(format!("{}/announcements", base_url))
+        .bearer_auth(token)
+        .json(&serde_json::json!({
+            "title": title,
+            "body_md": "Body",
+            "public": true,
+            "pinned": false,
+            "building_ids": building_ids,
+            "comments_enabled": false,
+            "publish_at": "2020-01-01T00:00:00",
+            "expire_at": null,
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    response.json().await.expect("Failed to parse response")
+}
+
+#[tokio::test]
+async fn test_read_receipts_and_acknowledgement() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let homeowner = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let renter =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::renter()).await;
+
+    let response = client
+        .post(format!("{}/announcements", server.base_url))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .json(&serde_json::json!({
+            "title": "Water shutdown",
+            "body_md": "Water will be off on Monday.",
+            "public": false,
+            "pinned": false,
+            "roles": ["Homeowner"],
+            "comments_enabled": false,
+            "requires_ack": true,
+            "publish_at": null,
+            "expire_at": null,
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let ann: Value = response.json().await.unwrap();
+    assert_eq!(ann["requires_ack"], true);
+    let id = ann["id"].as_u64().unwrap();
+
+    let report = |token: String| {
+        let client = client.clone();
+        let url = format!("{}/announcements/{}/receipts", server.base_url, id);
+        async move {
+            let response = client.get(url).bearer_auth(token).send().await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            response.json::<Value>().await.unwrap()
+        }
+    };
+
+    let before = report(admin.token.clone().unwrap()).await;
+    assert_eq!(before["audience_total"], 1);
+    assert_eq!(before["read_count"], 0);
+    assert_eq!(before["pending"][0]["email"], homeowner.email);
+
+    // Outside the target roles
+    let response = client
+        .post(format!("{}/announcements/{}/read", server.base_url, id))
+        .bearer_auth(renter.token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .post(format!("{}/announcements/{}/read", server.base_url, id))
+        .bearer_auth(homeowner.token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Read but not yet acknowledged: still pending
+    let after_read = report(admin.token.clone().unwrap()).await;
+    assert_eq!(after_read["read_count"], 1);
+    assert_eq!(after_read["acknowledged_count"], 0);
+    assert_eq!(after_read["pending"].as_array().unwrap().len(), 1);
+
+    let response = client
+        .post(format!(
+            "{}/announcements/{}/acknowledge",
+            server.base_url, id
+        ))
+        .bearer_auth(homeowner.token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let receipt: Value = response.json().await.unwrap();
+    assert!(receipt["acknowledged_at"].is_string());
+
+    let after_ack = report(admin.token.clone().unwrap()).await;
+    assert_eq!(after_ack["acknowledged_count"], 1);
+    assert!(after_ack["pending"].as_array().unwrap().is_empty());
+
+    let response = client
+        .get(format!("{}/announcements/{}", server.base_url, id))
+        .bearer_auth(homeowner.token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    let own: Value = response.json().await.unwrap();
+    assert!(own["read_at"].is_string());
+    assert!(own["acknowledged_at"].is_string());
+
+    // Residents cannot see the report
+    let response = client
+        .get(format!("{}/announcements/{}/receipts", server.base_url, id))
+        .bearer_auth(homeowner.token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Untargeted announcements address residents only; test users have no apartment
+    let untargeted = create_announcement(
+        &client,
+        &server.base_url,
+        admin.token.as_ref().unwrap(),
+        "General notice",
+        &[],
+    )
+    .await;
+    let response = client
+        .get(format!(
+            "{}/announcements/{}/receipts",
+            server.base_url, untargeted["id"]
+        ))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let untargeted_report: Value = response.json().await.unwrap();
+    assert_eq!(untargeted_report["audience_total"], 0);
+}
+
+#[tokio::test]
+async fn test_audience_filtering_and_pagination() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let homeowner = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let admin_token = admin.token.as_ref().unwrap();
+
+    let north = create_building(&client, &server.base_url, admin_token, "1 North St").await;
+    let south = create_building(&client, &server.base_url, admin_token, "2 South St").await;
+
+    let everyone =
+        create_announcement(&client, &server.base_url, admin_token, "Everyone", &[]).await;
+    assert!(everyone["buildings"].as_array().unwrap().is_empty());
+    let targeted = create_announcement(
+        &client,
+        &server.base_url,
+        admin_token,
+        "North and South",
+        &[north, south],
+    )
+    .await;
+    assert_eq!(targeted["buildings"].as_array().unwrap().len(), 2);
+    create_announcement(&client, &server.base_url, admin_token, "South", &[south]).await;
+
+    // Admin: all three, paginated in the database
+    let page: Value = client
+        .get(format!("{}/announcements?per_page=2", server.base_url))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(page["pagination"]["total"], 3);
+    assert_eq!(page["data"].as_array().unwrap().len(), 2);
+
+    // A homeowner without an apartment in either building only sees the untargeted one
+    let page: Value = client
+        .get(format!("{}/announcements", server.base_url))
+        .bearer_auth(homeowner.token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(page["pagination"]["total"], 1);
+    assert_eq!(page["data"][0]["title"], "Everyone");
+
+    // Narrowing the audience replaces the building targets
+    let id = targeted["id"].as_u64().unwrap();
+    let response = client
+        .put(format!("{}/announcements/{}", server.base_url, id))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({ "building_ids": [north] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated: Value = response.json().await.unwrap();
+    assert_eq!(updated["buildings"][0]["id"], north);
+    assert_eq!(updated["buildings"].as_array().unwrap().len(), 1);
+
+    // Unknown roles are rejected
+    let response = client
+        .put(format!("{}/announcements/{}", server.base_url, id))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({ "building_ids": [999_999] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_revision_history_diff_and_restore() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let homeowner = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let admin_token = admin.token.as_ref().unwrap();
+    let homeowner_token = homeowner.token.as_ref().unwrap();
+
+    let created = create_announcement(&client, &server.base_url, admin_token, "Lift", &[]).await;
+    let id = created["id"].as_u64().unwrap();
+    assert!(created["edited_at"].is_null());
+
+    // Changing only the pin does not create a revision
+    let response = client
+        .put(format!("{}/announcements/{}", server.base_url, id))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({ "pinned": true }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .put(format!("{}/announcements/{}", server.base_url, id))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "title": "Lift repair",
+            "body_md": "Body\nOut of service until Friday",
+        }))
+        .send()
+        .await
+        .unwrap();
+    let updated: Value = response.json().await.unwrap();
+    assert!(updated["edited_at"].is_string());
+
+    let response = client
+        .get(format!(
+            "{}/announcements/{}/revisions",
+            server.base_url, id
+        ))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let revisions: Vec<Value> = response.json().await.unwrap();
+    let numbers: Vec<u64> = revisions
+        .iter()
+        .map(|r| r["revision"].as_u64().unwrap())
+        .collect();
+    assert_eq!(numbers, vec![2, 1]);
+    assert_eq!(revisions[0]["editor_id"], admin.id);
+
+    let response = client
+        .get(format!(
+            "{}/announcements/{}/revisions/diff?from=1&to=2",
+            server.base_url, id
+        ))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    let diff: Value = response.json().await.unwrap();
+    assert_eq!(diff["title_from"], "Lift");
+    assert_eq!(diff["title_to"], "Lift repair");
+    let lines = diff["lines"].as_array().unwrap();
+    assert_eq!(lines[0]["op"], "equal");
+    assert_eq!(lines[1]["op"], "insert");
+    assert_eq!(lines[1]["text"], "Out of service until Friday");
+
+    // Residents cannot restore
+    let response = client
+        .post(format!(
+            "{}/announcements/{}/revisions/1/restore",
+            server.base_url, id
+        ))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .post(format!(
+            "{}/announcements/{}/revisions/1/restore",
+            server.base_url, id
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let restored: Value = response.json().await.unwrap();
+    assert_eq!(restored["title"], "Lift");
+    assert_eq!(restored["body_md"], "Body");
+
+    let response = client
+        .get(format!(
+            "{}/announcements/{}/revisions",
+            server.base_url, id
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    let revisions: Vec<Value> = response.json().await.unwrap();
+    assert_eq!(revisions.len(), 3);
+    assert_eq!(revisions[0]["restored_from"], 1);
+
+    let response = client
+        .post(format!(
+            "{}/announcements/{}/revisions/9/restore",
+            server.base_url, id
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_attachment_images_resolve_to_signed_urls() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let admin_token = admin.token.as_ref().unwrap();
+    let origin = server.base_url.trim_end_matches("/api/v1");
+
+    let created = create_announcement(&client, &server.base_url, admin_token, "Plan", &[]).await;
+    let id = created["id"].as_u64().unwrap();
+
+    let mut png = Vec::new();
+    image::DynamicImage::ImageRgb8(image::RgbImage::new(40, 20))
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .expect("Failed to encode PNG");
+    let form = reqwest::multipart::Form::new().part(
+        "file",
+        reqwest::multipart::Part::bytes(png).file_name("plan.png"),
+    );
+    let response = client
+        .post(format!(
+            "{}/announcements/{}/attachments",
+            server.base_url, id
+        ))
+        .bearer_auth(admin_token)
+        .multipart(form)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let attachment: Value = response.json().await.unwrap();
+    let att_id = attachment["id"].as_u64().unwrap();
+    assert!(attachment["thumbnail_url"].is_string());
+
+    let response = client
+        .put(format!("{}/announcements/{}", server.base_url, id))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "body_md": format!(
+                "![Floor plan](attachment:{})\n\n![Missing](attachment:999999)",
+                att_id
+            ),
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Anonymous readers of the public announcement get a signed image URL
+    let response = client
+        .get(format!("{}/announcements/{}", server.base_url, id))
+        .send()
+        .await
+        .unwrap();
+    let ann: Value = response.json().await.unwrap();
+    let html = ann["body_html"].as_str().unwrap();
+    let url = ann["attachments"][0]["url"].as_str().unwrap();
+    assert!(html.contains(&format!("src=\"{}\"", url)));
+    assert!(!html.contains("attachment:"));
+    assert!(url.contains("?token="));
+
+    let response = client
+        .get(format!("{}{}", origin, url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "image/png"
+    );
+
+    let unsigned = url.split('?').next().unwrap();
+    let response = client
+        .get(format!("{}{}", origin, unsigned))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = client
+        .get(format!("{}{}?token=forged", origin, unsigned))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Deleting the attachment stops the reference from resolving
+    let response = client
+        .delete(format!(
+            "{}/announcements/{}/attachments/{}",
+            server.base_url, id, att_id
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let response = client
+        .get(format!("{}/announcements/{}", server.base_url, id))
+        .send()
+        .await
+        .unwrap();
+    let ann: Value = response.json().await.unwrap();
+    assert!(!ann["body_html"].as_str().unwrap().contains("/attachments/"));
+    assert_eq!(ann["attachments"].as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn test_comment_threads_reactions_and_moderation() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let homeowner = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let renter =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::renter()).await;
+    let admin_token = admin.token.as_ref().unwrap();
+    let homeowner_token = homeowner.token.as_ref().unwrap();
+    let renter_token = renter.token.as_ref().unwrap();
+
+    let response = client
+        .post(format!("{}/announcements", server.base_url))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "title": "Garden party",
+            "body_md": "Saturday at 3pm",
+            "public": true,
+            "pinned": false,
+            "comments_enabled": true,
+            "publish_at": "2020-01-01T00:00:00",
+            "expire_at": null,
+        }))
+        .send()
+        .await
+        .unwrap();
+    let ann: Value = response.json().await.unwrap();
+    let id = ann["id"].as_u64().unwrap();
+    let comments_url = format!("{}/announcements/{}/comments", server.base_url, id);
+
+    let response = client
+        .post(&comments_url)
+        .bearer_auth(homeowner_token)
+        .json(&serde_json::json!({ "body_md": "Can I bring a grill?" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let question: Value = response.json().await.unwrap();
+    let question_id = question["id"].as_u64().unwrap();
+
+    // Reply with a mention
+    let response = client
+        .post(&comments_url)
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "body_md": format!("@{} sure, bring it", homeowner.name),
+            "parent_id": question_id,
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let reply: Value = response.json().await.unwrap();
+    assert_eq!(reply["parent_id"], question_id);
+    assert_eq!(reply["mentions"][0]["user_id"], homeowner.id);
+    assert!(
+        reply["body_html"]
+            .as_str()
+            .unwrap()
+            .contains(&format!("<strong>@{}</strong>", homeowner.name))
+    );
+
+    // Reactions toggle and are limited to the supported set
+    let reactions_url = format!(
+        "{}/announcements/comments/{}/reactions",
+        server.base_url, question_id
+    );
+    for (token, expected) in [(renter_token, 1), (admin_token, 2), (renter_token, 1)] {
+        let response = client
+            .post(&reactions_url)
+            .bearer_auth(token)
+            .json(&serde_json::json!({ "emoji": "👍" }))
+            .send()
+            .await
+            .unwrap();
+        let summary: Value = response.json().await.unwrap();
+        assert_eq!(summary[0]["count"], expected);
+    }
+    let response = client
+        .post(&reactions_url)
+        .bearer_auth(renter_token)
+        .json(&serde_json::json!({ "emoji": "🍕" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Only the author edits
+    let comment_url = format!("{}/announcements/comments/{}", server.base_url, question_id);
+    let response = client
+        .put(&comment_url)
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({ "body_md": "Changed" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = client
+        .put(&comment_url)
+        .bearer_auth(homeowner_token)
+        .json(&serde_json::json!({ "body_md": "Can I bring a gas grill?" }))
+        .send()
+        .await
+        .unwrap();
+    let edited: Value = response.json().await.unwrap();
+    assert!(edited["edited_at"].is_string());
+
+    // Report, then hide from the moderation queue
+    let response = client
+        .post(format!("{}/report", comment_url))
+        .bearer_auth(homeowner_token)
+        .json(&serde_json::json!({ "reason": "mine" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = client
+        .post(format!("{}/report", comment_url))
+        .bearer_auth(renter_token)
+        .json(&serde_json::json!({ "reason": "Grills are not allowed" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let queue_url = format!("{}/announcements/comments/reports", server.base_url);
+    let response = client
+        .get(&queue_url)
+        .bearer_auth(renter_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = client
+        .get(&queue_url)
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    let queue: Value = response.json().await.unwrap();
+    assert_eq!(queue[0]["comment"]["id"], question_id);
+    assert_eq!(queue[0]["reports"][0]["reason"], "Grills are not allowed");
+
+    let response = client
+        .post(format!("{}/hide", comment_url))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({ "reason": "House rules" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(&comments_url)
+        .bearer_auth(renter_token)
+        .send()
+        .await
+        .unwrap();
+    let list: Value = response.json().await.unwrap();
+    assert_eq!(list[0]["is_hidden"], true);
+    assert_eq!(list[0]["hidden_reason"], "House rules");
+    assert_eq!(list[0]["body_md"], "");
+    let response = client
+        .get(&comments_url)
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    let list: Value = response.json().await.unwrap();
+    assert_eq!(list[0]["body_md"], "Can I bring a gas grill?");
+
+    let response = client
+        .get(&queue_url)
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    let queue: Value = response.json().await.unwrap();
+    assert_eq!(queue.as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn test_templates_and_localized_bodies() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let admin_token = admin.token.as_ref().unwrap();
+    let building_id = create_building(&client, &server.base_url, admin_token, "Dlouhá 5").await;
+
+    // A bilingual template with built-in and custom placeholders
+    let response = client
+        .post(format!("{}/announcements/templates", server.base_url))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "name": "Heating season",
+            "language": "en",
+            "title": "Heating starts at {{building_address}}",
+            "body_md": "From {{publish_date}}, contact {{technician}}.",
+            "translations": [{
+                "language": "cs",
+                "title": "Začátek topné sezóny v {{building_address}}",
+                "body_md": "Od {{publish_date}}, kontakt {{technician}}.",
+            }],
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let template: Value = response.json().await.unwrap();
+    let template_id = template["id"].as_u64().unwrap();
+    assert_eq!(
+        template["placeholders"],
+        serde_json::json!(["building_address", "publish_date", "technician"])
+    );
+
+    // Names are unique
+    let response = client
+        .post(format!("{}/announcements/templates", server.base_url))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "name": "Heating season",
+            "title": "Duplicate",
+            "body_md": "",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client
+        .post(format!(
+            "{}/announcements/templates/{}/apply",
+            server.base_url, template_id
+        ))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "building_id": building_id,
+            "publish_at": "2026-10-01T08:00:00",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let draft: Value = response.json().await.unwrap();
+    assert_eq!(draft["title"], "Heating starts at Dlouhá 5");
+    assert_eq!(
+        draft["body_md"],
+        "From 1 October 2026, contact {{technician}}."
+    );
+    assert_eq!(
+        draft["translations"][0]["body_md"],
+        "Od 1. 10. 2026, kontakt {{technician}}."
+    );
+    assert_eq!(draft["missing"], serde_json::json!(["technician"]));
+
+    // Publish the draft as a bilingual announcement
+    let response = client
+        .post(format!("{}/announcements", server.base_url))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "title": draft["title"],
+            "body_md": draft["body_md"],
+            "language": draft["language"],
+            "translations": draft["translations"],
+            "public": true,
+            "pinned": false,
+            "comments_enabled": false,
+            "publish_at": "2020-01-01T00:00:00",
+            "expire_at": null,
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created: Value = response.json().await.unwrap();
+    let id = created["id"].as_u64().unwrap();
+    assert_eq!(created["languages"], serde_json::json!(["en", "cs"]));
+
+    // Readers get the version matching their language, or the default one
+    for (accept, language, title) in [
+        (
+            "cs-CZ,cs;q=0.9,en;q=0.8",
+            "cs",
+            "Začátek topné sezóny v Dlouhá 5",
+        ),
+        ("en-GB", "en", "Heating starts at Dlouhá 5"),
+        ("de", "en", "Heating starts at Dlouhá 5"),
+    ] {
+        let response = client
+            .get(format!("{}/announcements/{}", server.base_url, id))
+            .header("Accept-Language", accept)
+            .send()
+            .await
+            .unwrap();
+        let ann: Value = response.json().await.unwrap();
+        assert_eq!(ann["language"], language, "Accept-Language: {}", accept);
+        assert_eq!(ann["title"], title);
+    }
+    let response = client
+        .get(format!("{}/announcements/public", server.base_url))
+        .header("Accept-Language", "cs")
+        .send()
+        .await
+        .unwrap();
+    let page: Value = response.json().await.unwrap();
+    assert!(
+        page["data"][0]["body_html"]
+            .as_str()
+            .unwrap()
+            .contains("kontakt")
+    );
+
+    // The editor sees every version regardless of its own language
+    let response = client
+        .get(format!(
+            "{}/announcements/{}/translations",
+            server.base_url, id
+        ))
+        .bearer_auth(admin_token)
+        .header("Accept-Language", "cs")
+        .send()
+        .await
+        .unwrap();
+    let versions: Value = response.json().await.unwrap();
+    assert_eq!(versions["language"], "en");
+    assert_eq!(versions["translations"][0]["language"], "cs");
+
+    // A translation in the default language is rejected
+    let response = client
+        .put(format!("{}/announcements/{}", server.base_url, id))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "translations": [{ "language": "en", "title": "Again", "body_md": "" }],
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Dropping the translations leaves only the default version
+    let response = client
+        .put(format!("{}/announcements/{}", server.base_url, id))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({ "translations": [] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = client
+        .get(format!("{}/announcements/{}", server.base_url, id))
+        .header("Accept-Language", "cs")
+        .send()
+        .await
+        .unwrap();
+    let ann: Value = response.json().await.unwrap();
+    assert_eq!(ann["language"], "en");
+    assert_eq!(ann["languages"], serde_json::json!(["en"]));
+}
+
+#[tokio::test]
+async fn test_announcement_feeds() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let homeowner = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let admin_token = admin.token.as_ref().unwrap();
+    let homeowner_token = homeowner.token.as_ref().unwrap();
+
+    let building_a = create_building(&client, &server.base_url, admin_token, "Feed St 1").await;
+    let building_b = create_building(&client, &server.base_url, admin_token, "Feed St 2").await;
+    create_announcement(&client, &server.base_url, admin_token, "For everyone", &[]).await;
+    create_announcement(
+        &client,
+        &server.base_url,
+        admin_token,
+        "Only A",
+        &[building_a],
+    )
+    .await;
+    create_announcement(
+        &client,
+        &server.base_url,
+        admin_token,
+        "Only B",
+        &[building_b],
+    )
+    .await;
+
+    // Public building feed: untargeted and own-building announcements
+    let response = client
+        .get(format!(
+            "{}/feeds/buildings/{}/atom",
+            server.base_url, building_a
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response.headers()["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("application/atom+xml")
+    );
+    let xml = response.text().await.unwrap();
+    assert!(xml.contains("<title>Announcements – Feed St 1</title>"));
+    assert!(xml.contains("<title>For everyone</title>"));
+    assert!(xml.contains("<title>Only A</title>"));
+    assert!(!xml.contains("Only B"));
+
+    let response = client
+        .get(format!("{}/feeds/buildings/999999/atom", server.base_url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let start_time = chrono::Local::now()
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+    let end_time = (chrono::Local::now() + chrono::Duration::days(7))
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+    let response = client
+        .post(format!("{}/proposals", server.base_url))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "title": "New roof",
+            "description": "Replace the roof, gutters included",
+            "start_time": start_time,
+            "end_time": end_time,
+            "voting_method": "SimpleMajority",
+            "eligible_roles": ["Homeowner"],
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // Private feeds need a token first
+    let response = client
+        .get(format!("{}/feeds/token", server.base_url))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    let status: Value = response.json().await.unwrap();
+    assert_eq!(status["active"], false);
+
+    let response = client
+        .post(format!("{}/feeds/token", server.base_url))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let urls: Value = response.json().await.unwrap();
+    let atom_url = urls["atom_url"].as_str().unwrap().to_string();
+    let calendar_url = urls["calendar_url"].as_str().unwrap().to_string();
+
+    // The homeowner has no apartment, so building-targeted announcements stay hidden
+    let response = client.get(&atom_url).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let xml = response.text().await.unwrap();
+    assert!(xml.contains("<title>For everyone</title>"));
+    assert!(!xml.contains("Only A"));
+    assert!(xml.contains("<title>Voting: New roof</title>"));
+
+    let response = client
+        .get(format!("{}?lang=cs", calendar_url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response.headers()["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("text/calendar")
+    );
+    let ics = response.text().await.unwrap();
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.contains("SUMMARY:Hlasování: New roof\r\n"));
+    assert!(ics.contains("DESCRIPTION:Replace the roof\\, gutters included\r\n"));
+
+    let response = client
+        .get(format!("{}/feeds/token", server.base_url))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    let status: Value = response.json().await.unwrap();
+    assert_eq!(status["active"], true);
+    assert!(status["last_used_at"].is_string());
+
+    // Rotating invalidates the old URLs; revoking removes access altogether
+    let response = client
+        .post(format!("{}/feeds/token", server.base_url))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    let rotated: Value = response.json().await.unwrap();
+    assert_ne!(rotated["atom_url"].as_str().unwrap(), atom_url);
+    let response = client.get(&atom_url).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = client
+        .delete(format!("{}/feeds/token", server.base_url))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let response = client
+        .get(rotated["atom_url"].as_str().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_announcement_analytics() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let homeowner = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let renter =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::renter()).await;
+    let admin_token = admin.token.as_ref().unwrap();
+    let homeowner_token = homeowner.token.as_ref().unwrap();
+
+    let response = client
+        .post(format!("{}/announcements", server.base_url))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "title": "Lift maintenance",
+            "body_md": "The lift is out of service on Friday.",
+            "public": false,
+            "pinned": false,
+            "roles": ["Homeowner", "Renter"],
+            "comments_enabled": true,
+            "publish_at": null,
+            "expire_at": null,
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let ann: Value = response.json().await.unwrap();
+    let id = ann["id"].as_u64().unwrap();
+    let response = client
+        .post(format!("{}/announcements/{}/publish", server.base_url, id))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .post(format!("{}/announcements/{}/read", server.base_url, id))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = client
+        .post(format!("{}/announcements/{}/comments", server.base_url, id))
+        .bearer_auth(homeowner_token)
+        .json(&serde_json::json!({ "body_md": "Which hours?" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let analytics_url = format!("{}/announcements/{}/analytics", server.base_url, id);
+    let response = client
+        .get(&analytics_url)
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let analytics: Value = response.json().await.unwrap();
+    assert_eq!(analytics["audience_total"], 2);
+    assert_eq!(analytics["audience_read_count"], 1);
+    assert_eq!(analytics["read_rate"], 0.5);
+    assert_eq!(analytics["comment_count"], 1);
+    assert_eq!(analytics["commenter_count"], 1);
+    assert!(analytics["minutes_to_first_read"].is_number());
+    let over_time = analytics["readers_over_time"].as_array().unwrap();
+    assert_eq!(over_time.len(), 1);
+    assert_eq!(over_time[0]["cumulative"], 1);
+    let roles = analytics["reach_by_role"].as_array().unwrap();
+    let homeowners = roles.iter().find(|r| r["label"] == "Homeowner").unwrap();
+    assert_eq!(homeowners["audience"], 1);
+    assert_eq!(homeowners["readers"], 1);
+    let renters = roles.iter().find(|r| r["label"] == "Renter").unwrap();
+    assert_eq!(renters["readers"], 0);
+    // Test users have no apartment
+    let buildings = analytics["reach_by_building"].as_array().unwrap();
+    assert_eq!(buildings.len(), 1);
+    assert!(buildings[0]["id"].is_null());
+    assert_eq!(buildings[0]["audience"], 2);
+
+    let response = client
+        .get(format!(
+            "{}/announcements/analytics?days=30",
+            server.base_url
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let summary: Value = response.json().await.unwrap();
+    assert_eq!(summary["days"], 30);
+    assert_eq!(summary["announcement_count"], 1);
+    assert_eq!(summary["announcements"][0]["announcement_id"], id);
+    assert_eq!(summary["audience_total"], 2);
+    assert_eq!(summary["audience_read_count"], 1);
+    assert_eq!(summary["comment_count"], 1);
+
+    // Only residents of the building count, and nobody lives there
+    let building_id =
+        create_building(&client, &server.base_url, admin_token, "1 Analytics St").await;
+    let response = client
+        .get(format!(
+            "{}/announcements/analytics?building_id={}",
+            server.base_url, building_id
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    let summary: Value = response.json().await.unwrap();
+    assert_eq!(summary["announcement_count"], 1);
+    assert_eq!(summary["audience_total"], 0);
+    assert_eq!(summary["read_rate"], 0.0);
+
+    let response = client
+        .get(&analytics_url)
+        .bearer_auth(renter.token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
@@ -42,14 +42,54 @@ pub fn clean_database(pool: &DbPool) {
 
     // List of all tables to clean
     let tables = vec![
+        "meeting_attendance",
+        "meeting_agenda_items",
+        "meetings",
+        "notifications",
+        "proposal_comments",
+        "proposal_attachments",
+        "apartment_vote_history",
+        "vote_selections",
         "votes",
+        "vote_proxies",
+        "secret_ballot_selections",
+        "secret_ballots",
+        "proposal_result_rounds",
+        "proposal_options",
+        "poll_answers",
+        "poll_responses",
+        "poll_participants",
+        "poll_options",
+        "poll_questions",
+        "polls",
         "proposal_results",
         "proposals",
-        "announcement_comments",
+        "announcements_comments",
+        "announcements_reads",
+        "announcements_revisions",
+        "announcements_attachments",
+        "announcements_comments_reactions",
+        "announcements_comments_mentions",
+        "announcements_comments_reports",
+        "announcements_audience",
+        "announcements_translations",
+        "announcements_templates_translations",
+        "announcements_templates",
         "announcements",
+        "feed_tokens",
         "maintenance_request_history",
         "maintenance_request_attachments",
+        "maintenance_filter_presets",
+        "maintenance_request_feedback",
         "maintenance_requests",
+        "meter_reading_campaigns",
+        "meter_reading_submissions",
+        "meter_readings",
+        "meters",
+        "webhook_api_key_buildings",
+        "webhook_api_key_meters",
+        "webhook_api_keys",
+        "apartment_renters",
         "apartment_owners",
         "apartments",
         "buildings",
@@ -254,6 +294,9 @@ impl TestServer {
         let jwt_secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "test-secret".to_string());
         let keys = api::auth::JwtKeys::from_secret(&jwt_secret);
         let app_config = api::config::AppConfig::load();
+        let storage =
+            api::storage::from_config(&app_config).expect("Failed to initialise storage backend");
+        let scanner = api::scanning::from_config(&app_config);
 
         // Start server in background
         tokio::spawn(async move {
@@ -262,6 +305,8 @@ impl TestServer {
                     .app_data(web::Data::new(pool_clone.clone()))
                     .app_data(web::Data::new(keys.clone()))
                     .app_data(web::Data::new(app_config.clone()))
+                    .app_data(web::Data::from(storage.clone()))
+                    .app_data(web::Data::from(scanner.clone()))
                     .service(
                         web::scope("/api/v1")
                             .configure(api::auth::configure)
@@ -270,7 +315,12 @@ impl TestServer {
                             .configure(api::apartments::configure)
                             .configure(api::maintenance::configure)
                             .configure(api::announcements::configure)
-                            .configure(api::voting::configure),
+                            .configure(api::voting::configure)
+                            .configure(api::polls::configure)
+                            .configure(api::meetings::configure)
+                            .configure(api::meters::configure)
+                            .configure(api::notifications::configure)
+                            .configure(api::feeds::configure),
                     )
             })
             .bind(&addr)
@@ -0,0 +1,509 @@
+//! Meter reading ingestion over its transports.
+//!
+//! The MQTT test runs against a local broker (e.g. the `mosquitto` service in
+//! docker-compose) when `MQTT_TEST_BROKER` is set to `host:port`, and is skipped
+//! otherwise.
+
+mod common;
+
+use common::{TestServer, TestUser, create_and_login_user};
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+
+/// Active meters with the given serial numbers in a new building; returns their ids.
+fn seed_meters(pool: &api::DbPool, serials: &[&str]) -> Vec<u64> {
+    use api::schema::apartments::dsl as apt;
+    use api::schema::buildings::dsl as b;
+    use api::schema::meters::dsl as m;
+    use diesel::prelude::*;
+
+    let mut conn = pool.get().expect("Failed to get connection");
+    diesel::insert_into(b::buildings)
+        .values(b::address.eq("9 Ingestion Street"))
+        .execute(&mut conn)
+        .expect("Failed to insert building");
+    let building_id: u64 = b::buildings
+        .select(b::id)
+        .order(b::id.desc())
+        .first(&mut conn)
+        .expect("Failed to load building");
+    diesel::insert_into(apt::apartments)
+        .values((apt::building_id.eq(building_id), apt::number.eq("1")))
+        .execute(&mut conn)
+        .expect("Failed to insert apartment");
+    let apartment_id: u64 = apt::apartments
+        .select(apt::id)
+        .order(apt::id.desc())
+        .first(&mut conn)
+        .expect("Failed to load apartment");
+
+    serials
+        .iter()
+        .map(|serial| {
+            diesel::insert_into(m::meters)
+                .values((
+                    m::apartment_id.eq(apartment_id),
+                    m::meter_type.eq("ColdWater"),
+                    m::serial_number.eq(*serial),
+                ))
+                .execute(&mut conn)
+                .expect("Failed to insert meter");
+            m::meters
+                .select(m::id)
+                .order(m::id.desc())
+                .first::<u64>(&mut conn)
+                .expect("Failed to load meter")
+        })
+        .collect()
+}
+
+/// (value, source) of a meter's readings, oldest first
+fn readings(pool: &api::DbPool, meter_id: u64) -> Vec<(String, String)> {
+    use api::schema::meter_readings::dsl as mr;
+    use diesel::prelude::*;
+
+    let mut conn = pool.get().expect("Failed to get connection");
+    mr::meter_readings
+        .filter(mr::meter_id.eq(meter_id))
+        .order(mr::reading_timestamp.asc())
+        .select((mr::reading_value, mr::source))
+        .load::<(bigdecimal::BigDecimal, String)>(&mut conn)
+        .expect("Failed to load readings")
+        .into_iter()
+        .map(|(value, source)| (value.normalized().to_string(), source))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_csv_import_and_webhooks_share_deduplication() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let manager =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::manager()).await;
+    let admin_token = admin.token.as_ref().unwrap();
+    let meters = seed_meters(&server.pool, &["WM-1", "12345678"]);
+
+    let csv = "Datum;Zähler;Stand\n01.03.2026 08:00;WM-1;10,5\n01.04.2026 08:00;WM-1;12,25\n01.04.2026 08:00;WM-404;1\n";
+    let import = |token: &str| {
+        client
+            .post(format!("{}/meters/import/csv", server.base_url))
+            .bearer_auth(token.to_string())
+            .json(&json!({
+                "mapping": {
+                    "delimiter": ";",
+                    "serial_column": "Zähler",
+                    "value_column": "Stand",
+                    "timestamp_column": "Datum",
+                    "unit": "m3",
+                    "timestamp_format": "%d.%m.%Y %H:%M",
+                    "decimal_comma": true,
+                },
+                "content": csv,
+            }))
+            .send()
+    };
+    let response = import(manager.token.as_ref().unwrap())
+        .await
+        .expect("Failed to import");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = import(admin_token).await.expect("Failed to import");
+    assert_eq!(response.status(), StatusCode::OK);
+    let summary: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(summary["received"], 3);
+    assert_eq!(summary["inserted"], 2);
+    assert_eq!(summary["duplicates"], 0);
+    assert_eq!(summary["unknown_meters"], json!(["WM-404"]));
+
+    // Importing the same export again stores nothing new
+    let summary: Value = import(admin_token)
+        .await
+        .expect("Failed to import")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(summary["inserted"], 0);
+    assert_eq!(summary["duplicates"], 2);
+    assert_eq!(
+        readings(&server.pool, meters[0]),
+        vec![
+            ("10.5".to_string(), "Import".to_string()),
+            ("12.25".to_string(), "Import".to_string()),
+        ]
+    );
+
+    // A bad row rejects the file with its line number
+    let response = client
+        .post(format!("{}/meters/import/csv", server.base_url))
+        .bearer_auth(admin_token)
+        .json(&json!({
+            "mapping": {
+                "serial_column": "serial",
+                "value_column": "value",
+                "timestamp_column": "time",
+                "unit": "m3",
+            },
+            "content": "serial,value,time\nWM-1,1,2026-05-01 08:00:00\nWM-1,x,2026-06-01 08:00:00\n",
+        }))
+        .send()
+        .await
+        .expect("Failed to import");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert!(response.text().await.unwrap().contains("line 3"));
+
+    let key: Value = client
+        .post(format!("{}/admin/api-keys", server.base_url))
+        .bearer_auth(admin_token)
+        .json(&json!({ "name": "Gateway" }))
+        .send()
+        .await
+        .expect("Failed to create API key")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let api_key = key["api_key"].as_str().unwrap().to_string();
+
+    // The JSON webhook skips the reading the import already stored
+    let response = client
+        .post(format!("{}/webhooks/meter-reading-batch", server.base_url))
+        .header("X-API-Key", &api_key)
+        .json(&json!({ "readings": [
+            { "serial_number": "WM-1", "reading_value": "12.25", "timestamp": "2026-04-01T08:00:00Z", "unit": "m3" },
+            { "serial_number": "WM-1", "reading_value": "14", "timestamp": "2026-05-01T08:00:00Z", "unit": "m3" },
+        ]}))
+        .send()
+        .await
+        .expect("Failed to post batch");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let summary: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(summary["inserted"], 1);
+    assert_eq!(summary["duplicates"], 1);
+
+    // Single readings stay idempotent, and unknown meters are not found
+    let single = |serial: &str| {
+        client
+            .post(format!("{}/webhooks/meter-reading", server.base_url))
+            .header("X-API-Key", &api_key)
+            .json(&json!({
+                "serial_number": serial,
+                "reading_value": "14",
+                "timestamp": "2026-05-01 08:00:00",
+                "unit": "m3",
+            }))
+            .send()
+    };
+    assert_eq!(
+        single("WM-1").await.expect("Failed to post").status(),
+        StatusCode::CREATED
+    );
+    assert_eq!(
+        single("WM-404").await.expect("Failed to post").status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(readings(&server.pool, meters[0]).len(), 3);
+
+    // Wireless M-Bus telegram of meter 12345678: 12.345 m3 at 2026-03-15 10:30
+    let telegram = "16 44 2D2C 78563412 1B07 78 0413 39300000 046D 1E0A4F33";
+    let wmbus = || {
+        client
+            .post(format!("{}/webhooks/wmbus", server.base_url))
+            .header("X-API-Key", &api_key)
+            .json(&json!({ "telegrams": [telegram] }))
+            .send()
+    };
+    let response = wmbus().await.expect("Failed to post telegram");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let summary: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(summary["inserted"], 1);
+    let summary: Value = wmbus()
+        .await
+        .expect("Failed to post telegram")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(summary["duplicates"], 1);
+    assert_eq!(
+        readings(&server.pool, meters[1]),
+        vec![("12.345".to_string(), "Webhook".to_string())]
+    );
+
+    let response = client
+        .post(format!("{}/webhooks/wmbus", server.base_url))
+        .json(&json!({ "telegrams": [telegram] }))
+        .send()
+        .await
+        .expect("Failed to post telegram");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_mqtt_subscriber_stores_readings() {
+    let Ok(broker) = std::env::var("MQTT_TEST_BROKER") else {
+        eprintln!("MQTT_TEST_BROKER not set, skipping");
+        return;
+    };
+    dotenvy::dotenv().ok();
+    let pool = common::create_test_pool();
+    common::run_migrations(&pool);
+    common::clean_database(&pool);
+    let meters = seed_meters(&pool, &["MQ-1"]);
+
+    let (host, port) = broker.split_once(':').unwrap_or((&broker, "1883"));
+    let topic = format!("test/{}/readings", uuid::Uuid::new_v4());
+    let cfg = api::config::MqttConfig {
+        host: host.to_string(),
+        port: port.parse().expect("Invalid MQTT_TEST_BROKER port"),
+        client_id: format!("house-management-test-{}", uuid::Uuid::new_v4()),
+        topic: topic.clone(),
+        username: None,
+        password: None,
+        payload: api::config::PayloadFormat::Json,
+    };
+    let subscriber = tokio::spawn(api::ingestion::mqtt::run(
+        cfg,
+        std::sync::Arc::new(api::ingestion::JsonParser),
+        pool.clone(),
+    ));
+
+    let options = rumqttc::MqttOptions::new(
+        format!("house-management-publisher-{}", uuid::Uuid::new_v4()),
+        host,
+        port.parse().unwrap(),
+    );
+    let (publisher, mut eventloop) = rumqttc::AsyncClient::new(options, 10);
+    tokio::spawn(async move { while eventloop.poll().await.is_ok() {} });
+    let payload = json!([
+        { "serial_number": "MQ-1", "reading_value": "1.5", "timestamp": "2026-03-01T08:00:00Z", "unit": "m3" },
+        { "serial_number": "MQ-1", "reading_value": "2.5", "timestamp": "2026-04-01T08:00:00Z", "unit": "m3" },
+    ])
+    .to_string();
+
+    // Publish until the subscriber is connected and has stored both readings; the
+    // repeats are deduplicated
+    let mut stored = Vec::new();
+    for _ in 0..20 {
+        publisher
+            .publish(&topic, rumqttc::QoS::AtLeastOnce, false, payload.clone())
+            .await
+            .expect("Failed to publish");
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        stored = readings(&pool, meters[0]);
+        if stored.len() == 2 {
+            break;
+        }
+    }
+    subscriber.abort();
+    assert_eq!(
+        stored,
+        vec![
+            ("1.5".to_string(), "Mqtt".to_string()),
+            ("2.5".to_string(), "Mqtt".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_api_key_scope_rate_limit_and_rotation() {
+    use api::schema::apartments::dsl as apt;
+    use api::schema::meters::dsl as m;
+    use diesel::prelude::*;
+
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let admin_token = admin.token.as_ref().unwrap();
+    let in_scope = seed_meters(&server.pool, &["SC-1"])[0];
+    seed_meters(&server.pool, &["SC-2"]);
+    let building_id: u64 = m::meters
+        .inner_join(apt::apartments)
+        .filter(m::id.eq(in_scope))
+        .select(apt::building_id)
+        .first(&mut server.pool.get().expect("Failed to get connection"))
+        .expect("Failed to load building");
+
+    let create_key = |body: Value| {
+        client
+            .post(format!("{}/admin/api-keys", server.base_url))
+            .bearer_auth(admin_token)
+            .json(&body)
+            .send()
+    };
+    let response = create_key(json!({ "name": "Bad", "building_ids": [building_id + 1000] }))
+        .await
+        .expect("Failed to create API key");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let scoped: Value = create_key(json!({
+        "name": "Building gateway",
+        "building_ids": [building_id],
+        "rate_limit_per_minute": 3,
+    }))
+    .await
+    .expect("Failed to create API key")
+    .json()
+    .await
+    .expect("Failed to parse response");
+    let scoped_key = scoped["api_key"].as_str().unwrap().to_string();
+    assert!(scoped_key.starts_with("hm_"));
+
+    let post_reading = |api_key: &str, serial: &str, minute: u32| {
+        client
+            .post(format!("{}/webhooks/meter-reading", server.base_url))
+            .header("X-API-Key", api_key.to_string())
+            .json(&json!({
+                "serial_number": serial,
+                "reading_value": "1",
+                "timestamp": format!("2026-05-01 08:{:02}:00", minute),
+                "unit": "m3",
+            }))
+            .send()
+    };
+    let list_keys = || async {
+        client
+            .get(format!("{}/admin/api-keys", server.base_url))
+            .bearer_auth(admin_token)
+            .send()
+            .await
+            .expect("Failed to list API keys")
+            .json::<Vec<Value>>()
+            .await
+            .expect("Failed to parse response")
+    };
+    let find_key = |keys: &[Value], id: &Value| {
+        keys.iter()
+            .find(|k| &k["id"] == id)
+            .cloned()
+            .expect("API key not listed")
+    };
+
+    // Meters outside the key's buildings look like unknown meters
+    let status = |r: Result<reqwest::Response, reqwest::Error>| r.expect("Failed to post").status();
+    assert_eq!(
+        status(post_reading(&scoped_key, "SC-1", 0).await),
+        StatusCode::CREATED
+    );
+    assert_eq!(
+        status(post_reading(&scoped_key, "SC-2", 0).await),
+        StatusCode::NOT_FOUND
+    );
+    let listed = find_key(&list_keys().await, &scoped["id"]);
+    assert_eq!(listed["key_prefix"], scoped_key[3..11]);
+    assert_eq!(listed["building_ids"], json!([building_id]));
+    assert_eq!(listed["usage_count"], 2);
+    assert_eq!(listed["last_error"], "not_found");
+    assert!(listed.get("api_key_hash").is_none());
+
+    // Three requests a minute
+    assert_eq!(
+        status(post_reading(&scoped_key, "SC-1", 1).await),
+        StatusCode::CREATED
+    );
+    assert_eq!(
+        status(post_reading(&scoped_key, "SC-1", 2).await),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+    let listed = find_key(&list_keys().await, &scoped["id"]);
+    assert_eq!(listed["usage_count"], 3);
+    assert_eq!(listed["last_error"], "rate_limited");
+
+    // Rotation: the old key keeps working during the overlap
+    let old: Value = create_key(json!({ "name": "Vendor" }))
+        .await
+        .expect("Failed to create API key")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let old_key = old["api_key"].as_str().unwrap().to_string();
+    let response = client
+        .post(format!(
+            "{}/admin/api-keys/{}/rotate",
+            server.base_url, old["id"]
+        ))
+        .bearer_auth(admin_token)
+        .json(&json!({ "overlap_hours": 2 }))
+        .send()
+        .await
+        .expect("Failed to rotate API key");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let new: Value = response.json().await.expect("Failed to parse response");
+    let new_key = new["api_key"].as_str().unwrap().to_string();
+    assert_ne!(new_key, old_key);
+    assert_eq!(new["name"], "Vendor");
+    assert_eq!(
+        status(post_reading(&old_key, "SC-2", 10).await),
+        StatusCode::CREATED
+    );
+    assert_eq!(
+        status(post_reading(&new_key, "SC-2", 11).await),
+        StatusCode::CREATED
+    );
+    let keys = list_keys().await;
+    assert!(find_key(&keys, &old["id"])["expires_at"].is_string());
+    assert_eq!(find_key(&keys, &new["id"])["rotated_from_id"], old["id"]);
+
+    // Once expired, the old key is refused
+    let response = client
+        .put(format!("{}/admin/api-keys/{}", server.base_url, old["id"]))
+        .bearer_auth(admin_token)
+        .json(&json!({ "name": "Vendor (old)", "expires_at": "2026-01-01T00:00:00" }))
+        .send()
+        .await
+        .expect("Failed to update API key");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        status(post_reading(&old_key, "SC-2", 12).await),
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        find_key(&list_keys().await, &old["id"])["last_error"],
+        "key_expired"
+    );
+
+    // A wrong secret behind a valid prefix is refused and noted on the key
+    let forged = format!("{}Wrong", &new_key[..12]);
+    assert_eq!(
+        status(post_reading(&forged, "SC-2", 13).await),
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        find_key(&list_keys().await, &new["id"])["last_error"],
+        "invalid_secret"
+    );
+
+    let response = client
+        .delete(format!("{}/admin/api-keys/{}", server.base_url, new["id"]))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to revoke API key");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(
+        status(post_reading(&new_key, "SC-2", 14).await),
+        StatusCode::UNAUTHORIZED
+    );
+
+    // A key whose meters are all gone is refused, not widened to every meter
+    let meter_key: Value = create_key(json!({ "name": "Meter gateway", "meter_ids": [in_scope] }))
+        .await
+        .expect("Failed to create API key")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    {
+        use api::schema::webhook_api_key_meters::dsl as km;
+        diesel::delete(km::webhook_api_key_meters.filter(km::meter_id.eq(in_scope)))
+            .execute(&mut server.pool.get().expect("Failed to get connection"))
+            .expect("Failed to delete scope");
+    }
+    assert_eq!(
+        status(post_reading(meter_key["api_key"].as_str().unwrap(), "SC-2", 15).await),
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        find_key(&list_keys().await, &meter_key["id"])["last_error"],
+        "scope_deleted"
+    );
+}
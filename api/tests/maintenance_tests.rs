@@ -605,3 +605,302 @@ async fn test_user_cannot_see_other_users_requests() {
     let requests_array = requests.as_array().expect("Expected array");
     assert_eq!(requests_array.len(), 0);
 }
+
+#[tokio::test]
+async fn test_search_filter_and_presets() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let token = admin.token.as_ref().unwrap();
+
+    let (_building_id, apartment_id) =
+        create_test_building_and_apartment(&client, &server.base_url, token).await;
+
+    for (title, priority) in [
+        ("Broken heater", "Urgent"),
+        ("Squeaky door", "Low"),
+        ("Heater noise", "Medium"),
+    ] {
+        client
+            .post(format!("{}/requests", server.base_url))
+            .bearer_auth(token)
+            .json(&serde_json::json!({
+                "apartment_id": apartment_id,
+                "request_type": "General",
+                "title": title,
+                "description": "Reported by resident",
+                "priority": priority,
+            }))
+            .send()
+            .await
+            .expect("Failed to create request");
+    }
+
+    // Search matches title case-insensitively
+    let response = client
+        .get(format!("{}/requests?q=heater", server.base_url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["pagination"]["total"], 2);
+
+    // Words match as prefixes and boolean operators in the input are ignored
+    let response = client
+        .get(format!("{}/requests?q=-heat*", server.base_url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to send request");
+    let body: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["pagination"]["total"], 2);
+
+    // Stopwords and short words are skipped instead of required
+    let response = client
+        .get(format!("{}/requests?q=the%20door", server.base_url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to send request");
+    let body: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["pagination"]["total"], 1);
+
+    // A term with only short words falls back to substring matching
+    let response = client
+        .get(format!("{}/requests?q=ky", server.base_url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to send request");
+    let body: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["pagination"]["total"], 1);
+
+    // Priority set combined with search, sorted by priority
+    let response = client
+        .get(format!(
+            "{}/requests?q=heater&priority=Urgent,Low&sort=priority&order=desc",
+            server.base_url
+        ))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to send request");
+    let body: Value = response.json().await.expect("Failed to parse response");
+    let data = body["data"].as_array().expect("Expected data array");
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[0]["title"], "Broken heater");
+
+    // Unknown sort key is rejected
+    let response = client
+        .get(format!("{}/requests?sort=bogus", server.base_url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Save, list and delete a preset
+    let response = client
+        .post(format!("{}/requests/filter-presets", server.base_url))
+        .bearer_auth(token)
+        .json(&serde_json::json!({
+            "name": "Urgent open",
+            "filters": {"status": "Open", "priority": "Urgent"},
+        }))
+        .send()
+        .await
+        .expect("Failed to save preset");
+    assert_eq!(response.status(), StatusCode::OK);
+    let preset: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(preset["filters"]["priority"], "Urgent");
+    let preset_id = preset["id"].as_u64().expect("No preset ID");
+
+    let response = client
+        .get(format!("{}/requests/filter-presets", server.base_url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to list presets");
+    let presets: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(presets.as_array().expect("Expected array").len(), 1);
+
+    let response = client
+        .delete(format!(
+            "{}/requests/filter-presets/{}",
+            server.base_url, preset_id
+        ))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to delete preset");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_not_fixed_feedback_reopens_request() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let token = admin.token.as_ref().unwrap();
+
+    let (_building_id, apartment_id) =
+        create_test_building_and_apartment(&client, &server.base_url, token).await;
+
+    let response = client
+        .post(format!("{}/requests", server.base_url))
+        .bearer_auth(token)
+        .json(&serde_json::json!({
+            "apartment_id": apartment_id,
+            "request_type": "Plumbing",
+            "title": "Dripping tap",
+            "description": "Bathroom tap drips",
+            "priority": "Low",
+        }))
+        .send()
+        .await
+        .expect("Failed to create request");
+    let created: Value = response.json().await.expect("Failed to parse response");
+    let request_id = created["id"].as_u64().expect("No request ID");
+
+    // Feedback is only accepted once resolved
+    let response = client
+        .post(format!(
+            "{}/requests/{}/feedback",
+            server.base_url, request_id
+        ))
+        .bearer_auth(token)
+        .json(&serde_json::json!({"rating": 2, "is_fixed": false}))
+        .send()
+        .await
+        .expect("Failed to send feedback");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    client
+        .put(format!(
+            "{}/requests/{}/status",
+            server.base_url, request_id
+        ))
+        .bearer_auth(token)
+        .json(&serde_json::json!({"status": "Resolved"}))
+        .send()
+        .await
+        .expect("Failed to update status");
+
+    let response = client
+        .post(format!(
+            "{}/requests/{}/feedback",
+            server.base_url, request_id
+        ))
+        .bearer_auth(token)
+        .json(&serde_json::json!({"rating": 2, "is_fixed": false, "comment": "Still drips"}))
+        .send()
+        .await
+        .expect("Failed to send feedback");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = client
+        .get(format!("{}/requests/{}", server.base_url, request_id))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to get request");
+    let detail: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(detail["status"], "Open");
+
+    let response = client
+        .get(format!("{}/requests/feedback/summary", server.base_url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to get summary");
+    assert_eq!(response.status(), StatusCode::OK);
+    let summary: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(summary["by_building"][0]["ratings"], 1);
+    assert_eq!(summary["by_building"][0]["not_fixed"], 1);
+}
+
+#[tokio::test]
+async fn test_scanned_upload_is_released_from_quarantine() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let token = admin.token.as_ref().unwrap();
+
+    let (_building_id, apartment_id) =
+        create_test_building_and_apartment(&client, &server.base_url, token).await;
+
+    let response = client
+        .post(format!("{}/requests", server.base_url))
+        .bearer_auth(token)
+        .json(&serde_json::json!({
+            "apartment_id": apartment_id,
+            "request_type": "Other",
+            "title": "Cracked tile",
+            "description": "See photo",
+            "priority": "Low",
+        }))
+        .send()
+        .await
+        .expect("Failed to create request");
+    let created: Value = response.json().await.expect("Failed to parse response");
+    let request_id = created["id"].as_u64().expect("No request ID");
+
+    let mut png = Vec::new();
+    image::DynamicImage::ImageRgb8(image::RgbImage::new(40, 20))
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .expect("Failed to encode PNG");
+    let form = reqwest::multipart::Form::new().part(
+        "file",
+        reqwest::multipart::Part::bytes(png).file_name("tile.png"),
+    );
+    let response = client
+        .post(format!(
+            "{}/requests/{}/attachments",
+            server.base_url, request_id
+        ))
+        .bearer_auth(token)
+        .multipart(form)
+        .send()
+        .await
+        .expect("Failed to upload attachment");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // The test server uses the no-op scanner, so the upload is released right away
+    let response = client
+        .get(format!(
+            "{}/requests/{}/attachments",
+            server.base_url, request_id
+        ))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to list attachments");
+    let list: Value = response.json().await.expect("Failed to parse response");
+    let attachment = &list[0];
+    assert_eq!(attachment["scan_status"], "clean");
+    assert_eq!(attachment["has_thumbnail"], true);
+    assert_eq!(
+        attachment["checksum_sha256"].as_str().map(str::len),
+        Some(64)
+    );
+    let att_id = attachment["id"].as_u64().expect("No attachment ID");
+
+    let response = client
+        .get(format!(
+            "{}/requests/{}/attachments/{}/download",
+            server.base_url, request_id, att_id
+        ))
+        .bearer_auth(token)
+        .header("Range", "bytes=0-7")
+        .send()
+        .await
+        .expect("Failed to download attachment");
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    let head = response.bytes().await.expect("Failed to read body");
+    assert_eq!(&head[..], b"\x89PNG\r\n\x1a\n");
+}
@@ -0,0 +1,211 @@
+mod common;
+
+use common::{TestServer, TestUser, create_and_login_user};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+/// A building with apartments of 60 m² and 40 m², owned by the given users.
+fn seed_building(server: &TestServer, owners: [u64; 2]) -> u64 {
+    use api::schema::apartment_owners::dsl as ao;
+    use api::schema::apartments::dsl as apt;
+    use api::schema::buildings::dsl as b;
+    use diesel::prelude::*;
+
+    let mut conn = server.pool.get().expect("Failed to get connection");
+    diesel::insert_into(b::buildings)
+        .values(b::address.eq("1 Assembly Street"))
+        .execute(&mut conn)
+        .expect("Failed to insert building");
+    let building_id: u64 = b::buildings
+        .select(b::id)
+        .order(b::id.desc())
+        .first(&mut conn)
+        .expect("Failed to load building");
+
+    for (number, (size, owner)) in [(60.0, owners[0]), (40.0, owners[1])]
+        .into_iter()
+        .enumerate()
+    {
+        diesel::insert_into(apt::apartments)
+            .values((
+                apt::building_id.eq(building_id),
+                apt::number.eq(format!("{}", number + 1)),
+                apt::size_sq_m.eq(Some(size)),
+            ))
+            .execute(&mut conn)
+            .expect("Failed to insert apartment");
+        let apartment_id: u64 = apt::apartments
+            .select(apt::id)
+            .order(apt::id.desc())
+            .first(&mut conn)
+            .expect("Failed to load apartment");
+        diesel::insert_into(ao::apartment_owners)
+            .values((ao::apartment_id.eq(apartment_id), ao::user_id.eq(owner)))
+            .execute(&mut conn)
+            .expect("Failed to insert owner");
+    }
+    building_id
+}
+
+#[tokio::test]
+async fn test_meeting_quorum_live_voting_and_minutes() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let mut owners = Vec::new();
+    for i in 1..=2 {
+        let mut user = TestUser::homeowner();
+        user.email = format!("assembly{}@test.com", i);
+        owners.push(create_and_login_user(&server.pool, &client, &server.base_url, user).await);
+    }
+    let building_id = seed_building(&server, [owners[0].id, owners[1].id]);
+
+    let later = |days| {
+        (chrono::Local::now() + chrono::Duration::days(days))
+            .naive_local()
+            .format("%Y-%m-%dT%H:%M")
+            .to_string()
+    };
+    let proposal: Value = client
+        .post(format!("{}/proposals", server.base_url))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .json(&serde_json::json!({
+            "title": "New roof",
+            "description": "Replace the roof",
+            "building_id": building_id,
+            "start_time": later(7),
+            "end_time": later(8),
+            "voting_method": "WeightedArea",
+            "eligible_roles": ["Homeowner"],
+        }))
+        .send()
+        .await
+        .expect("Failed to create proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(proposal["status"], "Scheduled");
+    let proposal_id = proposal["id"].as_u64().unwrap();
+
+    let response = client
+        .post(format!("{}/meetings", server.base_url))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .json(&serde_json::json!({
+            "building_id": building_id,
+            "title": "Annual assembly",
+            "scheduled_at": later(7),
+            "location": "Lobby",
+            "quorum_percent": 50,
+            "proposal_ids": [proposal_id],
+        }))
+        .send()
+        .await
+        .expect("Failed to create meeting");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let meeting: Value = response.json().await.expect("Failed to parse response");
+    let meeting_url = format!("{}/meetings/{}", server.base_url, meeting["id"]);
+    assert_eq!(meeting["agenda"][0]["title"], "New roof");
+
+    let response = client
+        .post(format!("{}/start", meeting_url))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to start meeting");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Nobody is registered yet, so the item cannot be opened
+    let open_url = format!("{}/agenda/{}/open", meeting_url, proposal_id);
+    let response = client
+        .post(&open_url)
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to open item");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // The owner of 60 % of the floor area joins online
+    let detail: Value = client
+        .post(format!("{}/attendance", meeting_url))
+        .bearer_auth(owners[0].token.as_ref().unwrap())
+        .json(&serde_json::json!({ "mode": "Online" }))
+        .send()
+        .await
+        .expect("Failed to register attendance")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(detail["quorum"]["reached"], true);
+    assert_eq!(detail["quorum"]["percent"], 60.0);
+    assert_eq!(detail["my_attendance"], "Online");
+
+    let response = client
+        .post(&open_url)
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to open item");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .post(format!(
+            "{}/proposals/{}/vote",
+            server.base_url, proposal_id
+        ))
+        .bearer_auth(owners[0].token.as_ref().unwrap())
+        .json(&serde_json::json!({ "choice": "Yes" }))
+        .send()
+        .await
+        .expect("Failed to vote");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Ending the meeting closes and tallies the open item
+    let detail: Value = client
+        .post(format!("{}/end", meeting_url))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to end meeting")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(detail["status"], "Closed");
+    assert_eq!(detail["agenda"][0]["status"], "Tallied");
+    assert_eq!(detail["agenda"][0]["result"]["passed"], true);
+
+    // Drafts are for managers; residents see the minutes once published
+    let minutes_url = format!("{}/minutes", meeting_url);
+    let response = client
+        .get(&minutes_url)
+        .bearer_auth(owners[1].token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to get minutes");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .post(&minutes_url)
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .json(&serde_json::json!({}))
+        .send()
+        .await
+        .expect("Failed to publish minutes");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let minutes: Value = response.json().await.expect("Failed to parse response");
+    assert!(minutes["announcement_id"].as_u64().is_some());
+    let text = minutes["minutes_md"].as_str().unwrap();
+    assert!(text.contains("### 1. New roof"));
+    assert!(text.contains("**Passed**"));
+
+    let published: Value = client
+        .get(&minutes_url)
+        .bearer_auth(owners[1].token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to get minutes")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(published["minutes_md"], minutes["minutes_md"]);
+}
@@ -0,0 +1,723 @@
+mod common;
+
+use common::{TestServer, TestUser, create_and_login_user};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+/// An apartment with an owner and an active renter, a water meter visible to renters
+/// with a reading of 100 two days ago, and a gas meter that is not.
+/// Returns (apartment_id, visible meter, hidden meter).
+fn seed_meters(server: &TestServer, owner: u64, renter: u64) -> (u64, u64, u64) {
+    use api::schema::apartment_owners::dsl as ao;
+    use api::schema::apartment_renters::dsl as ar;
+    use api::schema::apartments::dsl as apt;
+    use api::schema::buildings::dsl as b;
+    use api::schema::meter_readings::dsl as mr;
+    use api::schema::meters::dsl as m;
+    use diesel::prelude::*;
+
+    let mut conn = server.pool.get().expect("Failed to get connection");
+    diesel::insert_into(b::buildings)
+        .values(b::address.eq("3 Meter Street"))
+        .execute(&mut conn)
+        .expect("Failed to insert building");
+    let building_id: u64 = b::buildings
+        .select(b::id)
+        .order(b::id.desc())
+        .first(&mut conn)
+        .expect("Failed to load building");
+    diesel::insert_into(apt::apartments)
+        .values((apt::building_id.eq(building_id), apt::number.eq("7")))
+        .execute(&mut conn)
+        .expect("Failed to insert apartment");
+    let apartment_id: u64 = apt::apartments
+        .select(apt::id)
+        .order(apt::id.desc())
+        .first(&mut conn)
+        .expect("Failed to load apartment");
+    diesel::insert_into(ao::apartment_owners)
+        .values((ao::apartment_id.eq(apartment_id), ao::user_id.eq(owner)))
+        .execute(&mut conn)
+        .expect("Failed to insert owner");
+    diesel::insert_into(ar::apartment_renters)
+        .values((
+            ar::apartment_id.eq(apartment_id),
+            ar::user_id.eq(renter),
+            ar::is_active.eq(Some(true)),
+        ))
+        .execute(&mut conn)
+        .expect("Failed to insert renter");
+
+    let mut meter_ids = Vec::new();
+    for (meter_type, serial, visible) in [("ColdWater", "CW-1", true), ("Gas", "GAS-1", false)] {
+        diesel::insert_into(m::meters)
+            .values((
+                m::apartment_id.eq(apartment_id),
+                m::meter_type.eq(meter_type),
+                m::serial_number.eq(serial),
+                m::is_visible_to_renters.eq(visible),
+            ))
+            .execute(&mut conn)
+            .expect("Failed to insert meter");
+        meter_ids.push(
+            m::meters
+                .select(m::id)
+                .order(m::id.desc())
+                .first::<u64>(&mut conn)
+                .expect("Failed to load meter"),
+        );
+    }
+    diesel::insert_into(mr::meter_readings)
+        .values((
+            mr::meter_id.eq(meter_ids[0]),
+            mr::reading_value.eq("100".parse::<bigdecimal::BigDecimal>().unwrap()),
+            mr::reading_timestamp.eq((chrono::Utc::now() - chrono::Duration::days(2)).naive_utc()),
+            mr::unit.eq("m3"),
+            mr::source.eq("Webhook"),
+        ))
+        .execute(&mut conn)
+        .expect("Failed to insert reading");
+
+    (apartment_id, meter_ids[0], meter_ids[1])
+}
+
+fn reading_form(value: &str) -> reqwest::multipart::Form {
+    let mut png = Vec::new();
+    image::DynamicImage::ImageRgb8(image::RgbImage::new(40, 20))
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .expect("Failed to encode image");
+    reqwest::multipart::Form::new()
+        .text("reading_value", value.to_string())
+        .text("unit", "m3")
+        .part(
+            "file",
+            reqwest::multipart::Part::bytes(png).file_name("dial.png"),
+        )
+}
+
+fn as_f64(value: &Value) -> f64 {
+    value.as_str().unwrap().parse().unwrap()
+}
+
+#[tokio::test]
+async fn test_resident_reading_submission_and_review() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let manager =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::manager()).await;
+    let owner = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let renter =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::renter()).await;
+    let mut stranger = TestUser::homeowner();
+    stranger.email = "stranger@test.com".to_string();
+    let stranger = create_and_login_user(&server.pool, &client, &server.base_url, stranger).await;
+    let (apartment_id, water, gas) = seed_meters(&server, owner.id, renter.id);
+    let manager_token = manager.token.as_ref().unwrap();
+    let owner_token = owner.token.as_ref().unwrap();
+    let renter_token = renter.token.as_ref().unwrap();
+
+    // Renters only see meters visible to renters
+    let meters: Value = client
+        .get(format!(
+            "{}/apartments/{}/meters",
+            server.base_url, apartment_id
+        ))
+        .bearer_auth(renter_token)
+        .send()
+        .await
+        .expect("Failed to list meters")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let meters = meters.as_array().unwrap();
+    assert_eq!(meters.len(), 1);
+    assert_eq!(meters[0]["id"], water);
+
+    let submit = |meter_id: u64, token: &str, value: &str| {
+        client
+            .post(format!(
+                "{}/meters/{}/submissions",
+                server.base_url, meter_id
+            ))
+            .bearer_auth(token)
+            .multipart(reading_form(value))
+            .send()
+    };
+    let response = submit(gas, renter_token, "5")
+        .await
+        .expect("Failed to submit");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = submit(water, stranger.token.as_ref().unwrap(), "110")
+        .await
+        .expect("Failed to submit");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Meters only count up
+    let response = submit(water, owner_token, "90")
+        .await
+        .expect("Failed to submit");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = submit(water, owner_token, "112.5")
+        .await
+        .expect("Failed to submit");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let submission: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(submission["status"], "Pending");
+    assert_eq!(as_f64(&submission["previous_value"]), 100.0);
+
+    // One submission awaiting review per meter
+    let response = submit(water, renter_token, "115")
+        .await
+        .expect("Failed to submit");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Not counted before approval
+    let readings: Value = client
+        .get(format!("{}/meters/{}/readings", server.base_url, water))
+        .bearer_auth(owner_token)
+        .send()
+        .await
+        .expect("Failed to list readings")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(readings.as_array().unwrap().len(), 1);
+
+    let response = client
+        .get(format!(
+            "{}/meters/{}/submissions/{}/photo",
+            server.base_url, water, submission["id"]
+        ))
+        .bearer_auth(renter_token)
+        .send()
+        .await
+        .expect("Failed to download photo");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/png");
+
+    // The photo's thumbnail is kept as well
+    assert_eq!(submission["photo_has_thumbnail"], true);
+    let response = client
+        .get(format!(
+            "{}/meters/{}/submissions/{}/thumbnail",
+            server.base_url, water, submission["id"]
+        ))
+        .bearer_auth(renter_token)
+        .send()
+        .await
+        .expect("Failed to download thumbnail");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/jpeg");
+
+    // Review queue for managers only
+    let response = client
+        .get(format!("{}/meter-submissions", server.base_url))
+        .bearer_auth(owner_token)
+        .send()
+        .await
+        .expect("Failed to list submissions");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let queue: Value = client
+        .get(format!("{}/meter-submissions", server.base_url))
+        .bearer_auth(manager_token)
+        .send()
+        .await
+        .expect("Failed to list submissions")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let queue = queue.as_array().unwrap();
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue[0]["serial_number"], "CW-1");
+    assert_eq!(queue[0]["submitted_by_name"], "Test Homeowner");
+    assert_eq!(as_f64(&queue[0]["consumption"]), 12.5);
+
+    let approve_url = format!(
+        "{}/meter-submissions/{}/approve",
+        server.base_url, submission["id"]
+    );
+    let response = client
+        .post(&approve_url)
+        .bearer_auth(owner_token)
+        .send()
+        .await
+        .expect("Failed to approve");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = client
+        .post(&approve_url)
+        .bearer_auth(manager_token)
+        .send()
+        .await
+        .expect("Failed to approve");
+    assert_eq!(response.status(), StatusCode::OK);
+    let approved: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(approved["status"], "Approved");
+    assert!(approved["reading_id"].is_u64());
+    let response = client
+        .post(&approve_url)
+        .bearer_auth(manager_token)
+        .send()
+        .await
+        .expect("Failed to approve");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // The approved submission counts as a manual reading
+    let readings: Value = client
+        .get(format!("{}/meters/{}/readings", server.base_url, water))
+        .bearer_auth(owner_token)
+        .send()
+        .await
+        .expect("Failed to list readings")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let readings = readings.as_array().unwrap();
+    assert_eq!(readings.len(), 2);
+    assert_eq!(readings[0]["id"], approved["reading_id"]);
+    assert_eq!(readings[0]["source"], "Manual");
+    assert_eq!(as_f64(&readings[0]["reading_value"]), 112.5);
+
+    let notifications: Value = client
+        .get(format!("{}/notifications", server.base_url))
+        .bearer_auth(owner_token)
+        .send()
+        .await
+        .expect("Failed to list notifications")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(
+        notifications["notifications"][0]["kind"],
+        "MeterReadingApproved"
+    );
+    assert_eq!(
+        notifications["notifications"][0]["link"],
+        format!("/meters/{}", water)
+    );
+
+    // Renters submit for visible meters; a rejection carries its reason
+    let response = submit(water, renter_token, "118")
+        .await
+        .expect("Failed to submit");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let submission: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(as_f64(&submission["previous_value"]), 112.5);
+    let reject_url = format!(
+        "{}/meter-submissions/{}/reject",
+        server.base_url, submission["id"]
+    );
+    let response = client
+        .post(&reject_url)
+        .bearer_auth(manager_token)
+        .json(&serde_json::json!({ "reason": " " }))
+        .send()
+        .await
+        .expect("Failed to reject");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = client
+        .post(&reject_url)
+        .bearer_auth(manager_token)
+        .json(&serde_json::json!({ "reason": "The dial is not readable" }))
+        .send()
+        .await
+        .expect("Failed to reject");
+    assert_eq!(response.status(), StatusCode::OK);
+    let rejected: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(rejected["status"], "Rejected");
+    assert_eq!(rejected["review_note"], "The dial is not readable");
+
+    let history: Value = client
+        .get(format!("{}/meters/{}/submissions", server.base_url, water))
+        .bearer_auth(renter_token)
+        .send()
+        .await
+        .expect("Failed to list submissions")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let history = history.as_array().unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0]["status"], "Rejected");
+    assert_eq!(history[1]["status"], "Approved");
+
+    let notifications: Value = client
+        .get(format!("{}/notifications", server.base_url))
+        .bearer_auth(renter_token)
+        .send()
+        .await
+        .expect("Failed to list notifications")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(
+        notifications["notifications"][0]["kind"],
+        "MeterReadingRejected"
+    );
+}
+
+/// A building with two apartments: the first owned by `owner1` with a water meter
+/// read 10 days ago, the second owned by `owner2` with a water meter last read 40
+/// days ago and a gas meter that was never read.
+/// Returns (building_id, second apartment, meters in that order).
+fn seed_campaign_building(server: &TestServer, owner1: u64, owner2: u64) -> (u64, u64, [u64; 3]) {
+    use api::schema::apartment_owners::dsl as ao;
+    use api::schema::apartments::dsl as apt;
+    use api::schema::buildings::dsl as b;
+    use api::schema::meter_readings::dsl as mr;
+    use api::schema::meters::dsl as m;
+    use diesel::prelude::*;
+
+    let mut conn = server.pool.get().expect("Failed to get connection");
+    diesel::insert_into(b::buildings)
+        .values(b::address.eq("5 Campaign Street"))
+        .execute(&mut conn)
+        .expect("Failed to insert building");
+    let building_id: u64 = b::buildings
+        .select(b::id)
+        .order(b::id.desc())
+        .first(&mut conn)
+        .expect("Failed to load building");
+
+    let mut apartment_ids = Vec::new();
+    for (number, owner) in [("1", owner1), ("2", owner2)] {
+        diesel::insert_into(apt::apartments)
+            .values((apt::building_id.eq(building_id), apt::number.eq(number)))
+            .execute(&mut conn)
+            .expect("Failed to insert apartment");
+        let apartment_id: u64 = apt::apartments
+            .select(apt::id)
+            .order(apt::id.desc())
+            .first(&mut conn)
+            .expect("Failed to load apartment");
+        diesel::insert_into(ao::apartment_owners)
+            .values((ao::apartment_id.eq(apartment_id), ao::user_id.eq(owner)))
+            .execute(&mut conn)
+            .expect("Failed to insert owner");
+        apartment_ids.push(apartment_id);
+    }
+
+    let mut meter_ids = Vec::new();
+    for (apartment_id, meter_type, serial) in [
+        (apartment_ids[0], "ColdWater", "CW-A"),
+        (apartment_ids[1], "ColdWater", "CW-B"),
+        (apartment_ids[1], "Gas", "GAS-C"),
+    ] {
+        diesel::insert_into(m::meters)
+            .values((
+                m::apartment_id.eq(apartment_id),
+                m::meter_type.eq(meter_type),
+                m::serial_number.eq(serial),
+            ))
+            .execute(&mut conn)
+            .expect("Failed to insert meter");
+        meter_ids.push(
+            m::meters
+                .select(m::id)
+                .order(m::id.desc())
+                .first::<u64>(&mut conn)
+                .expect("Failed to load meter"),
+        );
+    }
+
+    let days_ago = |days| (chrono::Utc::now() - chrono::Duration::days(days)).naive_utc();
+    for (meter_id, value, days) in [
+        (meter_ids[0], "50", 40),
+        (meter_ids[0], "60", 10),
+        (meter_ids[1], "100", 100),
+        (meter_ids[1], "200", 40),
+    ] {
+        diesel::insert_into(mr::meter_readings)
+            .values((
+                mr::meter_id.eq(meter_id),
+                mr::reading_value.eq(value.parse::<bigdecimal::BigDecimal>().unwrap()),
+                mr::reading_timestamp.eq(days_ago(days)),
+                mr::unit.eq("m3"),
+                mr::source.eq("Manual"),
+            ))
+            .execute(&mut conn)
+            .expect("Failed to insert reading");
+    }
+
+    (
+        building_id,
+        apartment_ids[1],
+        [meter_ids[0], meter_ids[1], meter_ids[2]],
+    )
+}
+
+#[tokio::test]
+async fn test_reading_campaign_reminders_and_estimates() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let owner1 = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let mut owner2 = TestUser::homeowner();
+    owner2.email = "owner2@test.com".to_string();
+    let owner2 = create_and_login_user(&server.pool, &client, &server.base_url, owner2).await;
+    let (building_id, apartment2, [read, history, never_read]) =
+        seed_campaign_building(&server, owner1.id, owner2.id);
+    let admin_token = admin.token.as_ref().unwrap();
+    let owner1_token = owner1.token.as_ref().unwrap();
+    let owner2_token = owner2.token.as_ref().unwrap();
+    let today = chrono::Utc::now().date_naive();
+
+    let create = |name: &str, start: chrono::NaiveDate, deadline: chrono::NaiveDate| {
+        client
+            .post(format!("{}/reading-campaigns", server.base_url))
+            .bearer_auth(admin_token)
+            .json(&serde_json::json!({
+                "building_id": building_id,
+                "name": name,
+                "start_date": start,
+                "deadline": deadline,
+            }))
+            .send()
+    };
+    let response = create("Backwards", today, today - chrono::Duration::days(1))
+        .await
+        .expect("Failed to create campaign");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = create(
+        "Spring reading",
+        today - chrono::Duration::days(30),
+        today - chrono::Duration::days(1),
+    )
+    .await
+    .expect("Failed to create campaign");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let campaign: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(campaign["status"], "Open");
+    assert_eq!(campaign["total_meters"], 3);
+    assert_eq!(campaign["read"], 1);
+    assert_eq!(campaign["missing"], 2);
+    assert!(campaign["last_reminded_at"].is_string());
+    let statuses: Vec<(u64, String)> = campaign["meters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| {
+            (
+                m["meter_id"].as_u64().unwrap(),
+                m["status"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    assert!(statuses.contains(&(read, "Read".to_string())));
+    assert!(statuses.contains(&(history, "Missing".to_string())));
+
+    // Only residents with a missing reading are reminded
+    let notifications = |token: &str| {
+        client
+            .get(format!("{}/notifications", server.base_url))
+            .bearer_auth(token.to_string())
+            .send()
+    };
+    let owner1_notifications: Value = notifications(owner1_token)
+        .await
+        .expect("Failed to list notifications")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert!(
+        owner1_notifications["notifications"]
+            .as_array()
+            .unwrap()
+            .is_empty()
+    );
+    let owner2_notifications: Value = notifications(owner2_token)
+        .await
+        .expect("Failed to list notifications")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(
+        owner2_notifications["notifications"][0]["kind"],
+        "ReadingCampaignReminder"
+    );
+    assert_eq!(
+        owner2_notifications["notifications"][0]["link"],
+        format!("/apartments/{}/meters", apartment2)
+    );
+
+    let campaign_url = format!("{}/reading-campaigns/{}", server.base_url, campaign["id"]);
+    let response = client
+        .get(&campaign_url)
+        .bearer_auth(owner1_token)
+        .send()
+        .await
+        .expect("Failed to get campaign");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let reminded: Value = client
+        .post(format!("{}/remind", campaign_url))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to remind")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(reminded["notified_users"], 1);
+
+    // Not before the deadline has passed
+    let current: Value = create("Current reading", today - chrono::Duration::days(3), today)
+        .await
+        .expect("Failed to create campaign")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let response = client
+        .post(format!(
+            "{}/reading-campaigns/{}/close",
+            server.base_url, current["id"]
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to close");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client
+        .post(format!("{}/close", campaign_url))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to close");
+    assert_eq!(response.status(), StatusCode::OK);
+    let closed: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(closed["status"], "Closed");
+    assert_eq!(closed["read"], 1);
+    assert_eq!(closed["estimated"], 1);
+    // Without any earlier reading there is nothing to estimate from
+    assert_eq!(closed["missing"], 1);
+    let meters = closed["meters"].as_array().unwrap();
+    let estimated = meters.iter().find(|m| m["meter_id"] == history).unwrap();
+    assert_eq!(estimated["status"], "Estimated");
+    assert!(as_f64(&estimated["reading_value"]) > 200.0);
+    let unread = meters.iter().find(|m| m["meter_id"] == never_read).unwrap();
+    assert_eq!(unread["status"], "Missing");
+
+    let readings: Value = client
+        .get(format!("{}/meters/{}/readings", server.base_url, history))
+        .bearer_auth(owner2_token)
+        .send()
+        .await
+        .expect("Failed to list readings")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(readings[0]["source"], "Estimated");
+
+    let response = client
+        .post(format!("{}/close", campaign_url))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to close");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = client
+        .post(format!("{}/remind", campaign_url))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to remind");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let listed: Value = client
+        .get(format!(
+            "{}/reading-campaigns?building_id={}",
+            server.base_url, building_id
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to list campaigns")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let listed = listed.as_array().unwrap();
+    assert_eq!(listed.len(), 2);
+    // Counts match the campaign details; the estimate falls in both windows
+    let counts = |id: &Value| {
+        let c = listed.iter().find(|c| &c["id"] == id).unwrap();
+        assert_eq!(c["building_address"], "5 Campaign Street");
+        assert_eq!(c["total_meters"], 3);
+        let count = |field: &str| c[field].as_u64().unwrap();
+        (count("read"), count("estimated"), count("missing"))
+    };
+    assert_eq!(counts(&campaign["id"]), (1, 1, 1));
+    assert_eq!(counts(&current["id"]), (0, 1, 2));
+
+    // The schedule reminds campaigns as they start and closes them after the deadline
+    let upcoming: Value = create(
+        "Summer reading",
+        today + chrono::Duration::days(1),
+        today + chrono::Duration::days(10),
+    )
+    .await
+    .expect("Failed to create campaign")
+    .json()
+    .await
+    .expect("Failed to parse response");
+    assert!(upcoming["last_reminded_at"].is_null());
+    let overdue: Value = create(
+        "Overdue reading",
+        today - chrono::Duration::days(10),
+        today - chrono::Duration::days(1),
+    )
+    .await
+    .expect("Failed to create campaign")
+    .json()
+    .await
+    .expect("Failed to parse response");
+    let mut conn = server.pool.get().expect("Failed to get connection");
+    {
+        use api::schema::meter_reading_campaigns::dsl as c;
+        use diesel::prelude::*;
+        diesel::update(
+            c::meter_reading_campaigns.filter(c::id.eq(upcoming["id"].as_u64().unwrap())),
+        )
+        .set(c::start_date.eq(today))
+        .execute(&mut conn)
+        .expect("Failed to move start date");
+    }
+    let run = api::meters::run_due_campaigns(7, &mut conn).expect("Failed to run schedule");
+    assert_eq!(
+        run,
+        api::meters::ScheduleRun {
+            reminded: 1,
+            closed: 1,
+            awaiting_review: 0,
+        }
+    );
+    let run = api::meters::run_due_campaigns(7, &mut conn).expect("Failed to run schedule");
+    assert_eq!(run, api::meters::ScheduleRun::default());
+    let closed: Value = client
+        .get(format!(
+            "{}/reading-campaigns/{}",
+            server.base_url, overdue["id"]
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to get campaign")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(closed["status"], "Closed");
+}
@@ -0,0 +1,291 @@
+mod common;
+
+use common::{TestServer, TestUser, create_and_login_user};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+fn window() -> (String, String) {
+    let now = chrono::Local::now().naive_local();
+    (
+        (now - chrono::Duration::hours(1))
+            .format("%Y-%m-%dT%H:%M")
+            .to_string(),
+        (now + chrono::Duration::days(7))
+            .format("%Y-%m-%dT%H:%M")
+            .to_string(),
+    )
+}
+
+async fn create_poll(
+    client: &reqwest::Client,
+    base_url: &str,
+    token: &str,
+    anonymous: bool,
+    results_visibility: &str,
+) -> Value {
+    let (start_time, end_time) = window();
+    let response = client
+        .post(format!("{}/polls", base_url))
+        .bearer_auth(token)
+        .json(&serde_json::json!({
+            "title": "Cleanup day",
+            "description": "Help us plan the spring cleanup.",
+            "start_time": start_time,
+            "end_time": end_time,
+            "anonymous": anonymous,
+            "results_visibility": results_visibility,
+            "questions": [
+                { "prompt": "Which date suits you?", "kind": "SingleChoice", "options": ["Saturday", "Sunday"] },
+                { "prompt": "Anything else?", "kind": "FreeText", "required": false },
+                { "prompt": "How clean is the yard now?", "kind": "Rating", "rating_max": 5 },
+            ],
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    response.json().await.expect("Failed to parse response")
+}
+
+fn answers(poll: &Value, option: usize, text: &str, rating: i64) -> Value {
+    let questions = poll["questions"].as_array().unwrap();
+    serde_json::json!({
+        "answers": [
+            { "question_id": questions[0]["id"], "option_ids": [questions[0]["options"][option]["id"]] },
+            { "question_id": questions[1]["id"], "text": text },
+            { "question_id": questions[2]["id"], "rating": rating },
+        ]
+    })
+}
+
+#[tokio::test]
+async fn test_homeowner_cannot_create_poll() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let homeowner = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let (start_time, end_time) = window();
+
+    let response = client
+        .post(format!("{}/polls", server.base_url))
+        .bearer_auth(homeowner.token.as_ref().unwrap())
+        .json(&serde_json::json!({
+            "title": "Not allowed",
+            "start_time": start_time,
+            "end_time": end_time,
+            "questions": [{ "prompt": "Why?", "kind": "FreeText" }],
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_poll_responses_results_and_export() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let homeowner = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let admin_token = admin.token.as_ref().unwrap();
+    let homeowner_token = homeowner.token.as_ref().unwrap();
+
+    let poll = create_poll(&client, &server.base_url, admin_token, false, "AfterClose").await;
+    let id = poll["id"].as_u64().unwrap();
+    assert_eq!(poll["status"], "Open");
+    assert_eq!(poll["questions"].as_array().unwrap().len(), 3);
+    assert_eq!(poll["questions"][0]["options"][1]["label"], "Sunday");
+
+    // A single choice question takes one option
+    let questions = poll["questions"].as_array().unwrap();
+    let response = client
+        .post(format!("{}/polls/{}/responses", server.base_url, id))
+        .bearer_auth(homeowner_token)
+        .json(&serde_json::json!({
+            "answers": [{
+                "question_id": questions[0]["id"],
+                "option_ids": [questions[0]["options"][0]["id"], questions[0]["options"][1]["id"]],
+            }]
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client
+        .post(format!("{}/polls/{}/responses", server.base_url, id))
+        .bearer_auth(homeowner_token)
+        .json(&answers(&poll, 1, "Bring gloves, please", 2))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // Only one response per resident
+    let response = client
+        .post(format!("{}/polls/{}/responses", server.base_url, id))
+        .bearer_auth(homeowner_token)
+        .json(&answers(&poll, 0, "", 5))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client
+        .get(format!("{}/polls/{}", server.base_url, id))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    let detail: Value = response.json().await.unwrap();
+    assert_eq!(detail["has_responded"], true);
+    assert_eq!(detail["can_respond"], false);
+    assert_eq!(detail["can_view_results"], false);
+
+    let results_url = format!("{}/polls/{}/results", server.base_url, id);
+    let response = client
+        .get(&results_url)
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .get(&results_url)
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let results: Value = response.json().await.unwrap();
+    assert_eq!(results["response_count"], 1);
+    assert_eq!(results["questions"][0]["options"][1]["count"], 1);
+    assert_eq!(
+        results["questions"][1]["texts"][0]["respondent"],
+        homeowner.name
+    );
+    assert_eq!(results["questions"][2]["average_rating"], 2.0);
+
+    let response = client
+        .get(format!("{}/polls/{}/export", server.base_url, id))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let csv = response.text().await.unwrap();
+    assert!(csv.starts_with("Response,Respondent,Submitted at,Which date suits you?"));
+    assert!(csv.contains(&homeowner.name));
+    assert!(csv.contains("Sunday,\"Bring gloves, please\",2"));
+
+    let response = client
+        .get(format!("{}/polls/{}/export", server.base_url, id))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .post(format!("{}/polls/{}/close", server.base_url, id))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let closed: Value = response.json().await.unwrap();
+    assert_eq!(closed["status"], "Closed");
+
+    // Residents see results once closed, without names
+    let response = client
+        .get(&results_url)
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let results: Value = response.json().await.unwrap();
+    assert!(results["questions"][1]["texts"][0]["respondent"].is_null());
+}
+
+#[tokio::test]
+async fn test_anonymous_poll_keeps_respondents_out_of_results() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let renter =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::renter()).await;
+    let admin_token = admin.token.as_ref().unwrap();
+
+    let poll = create_poll(
+        &client,
+        &server.base_url,
+        admin_token,
+        true,
+        "AfterResponse",
+    )
+    .await;
+    let id = poll["id"].as_u64().unwrap();
+
+    let response = client
+        .post(format!("{}/polls/{}/responses", server.base_url, id))
+        .bearer_auth(renter.token.as_ref().unwrap())
+        .json(&answers(&poll, 0, "Quiet please", 4))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // Visible after responding
+    let response = client
+        .get(format!("{}/polls/{}/results", server.base_url, id))
+        .bearer_auth(renter.token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(format!("{}/polls/{}/results", server.base_url, id))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    let results: Value = response.json().await.unwrap();
+    assert!(results["questions"][1]["texts"][0]["respondent"].is_null());
+
+    let response = client
+        .get(format!("{}/polls/{}/export", server.base_url, id))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    let csv = response.text().await.unwrap();
+    assert!(csv.starts_with("Response,Which date suits you?"));
+    assert!(!csv.contains(&renter.name));
+
+    let response = client
+        .get(format!("{}/polls?page=1&per_page=20", server.base_url))
+        .bearer_auth(renter.token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    let list: Value = response.json().await.unwrap();
+    assert_eq!(list["data"][0]["has_responded"], true);
+    assert_eq!(list["data"][0]["response_count"], 1);
+    assert_eq!(list["data"][0]["question_count"], 3);
+}
@@ -0,0 +1,85 @@
+//! Contract tests for attachment storage backends.
+//!
+//! The S3 test runs against any S3-compatible endpoint (e.g. the `minio` service in
+//! docker-compose) when `S3_TEST_ENDPOINT` is set, and is skipped otherwise.
+
+use api::config::S3Config;
+use api::storage::{LocalStorage, S3Storage, Storage, StorageError};
+use futures_util::TryStreamExt;
+
+async fn exercise_backend(storage: &dyn Storage) {
+    let key = format!("{}/file.bin", uuid::Uuid::new_v4());
+    let data: Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+
+    assert!(!storage.exists(&key).await.unwrap());
+    assert!(matches!(
+        storage.get(&key).await,
+        Err(StorageError::NotFound)
+    ));
+
+    storage.put(&key, data.clone()).await.unwrap();
+    assert!(storage.exists(&key).await.unwrap());
+    assert_eq!(storage.get(&key).await.unwrap(), data);
+    assert_eq!(
+        storage.get_range(&key, 100, 50).await.unwrap(),
+        data[100..150].to_vec()
+    );
+    let streamed: Vec<u8> = storage
+        .get_stream(&key)
+        .await
+        .unwrap()
+        .try_fold(Vec::new(), |mut acc, chunk| async move {
+            acc.extend_from_slice(&chunk);
+            Ok(acc)
+        })
+        .await
+        .unwrap();
+    assert_eq!(streamed, data);
+
+    // Overwrite replaces content
+    storage.put(&key, b"replaced".to_vec()).await.unwrap();
+    assert_eq!(storage.get(&key).await.unwrap(), b"replaced");
+
+    storage.delete(&key).await.unwrap();
+    assert!(!storage.exists(&key).await.unwrap());
+    // Deleting again is a no-op
+    storage.delete(&key).await.unwrap();
+
+    assert!(storage.put("../escape", vec![1]).await.is_err());
+}
+
+#[tokio::test]
+async fn test_local_storage_contract() {
+    let root = std::env::temp_dir().join(format!("hm-storage-{}", uuid::Uuid::new_v4()));
+    let storage = LocalStorage::new(&root);
+
+    exercise_backend(&storage).await;
+
+    let key = "7/abc";
+    storage.put(key, b"x".to_vec()).await.unwrap();
+    assert_eq!(storage.local_path(key), Some(root.join("7/abc")));
+
+    std::fs::remove_dir_all(&root).ok();
+}
+
+#[tokio::test]
+async fn test_s3_storage_contract() {
+    let Ok(endpoint) = std::env::var("S3_TEST_ENDPOINT") else {
+        eprintln!("S3_TEST_ENDPOINT not set, skipping");
+        return;
+    };
+    let cfg = S3Config {
+        bucket: std::env::var("S3_TEST_BUCKET").unwrap_or_else(|_| "attachments-test".into()),
+        region: "us-east-1".into(),
+        endpoint: Some(endpoint),
+        access_key: std::env::var("S3_TEST_ACCESS_KEY_ID").unwrap_or_else(|_| "minioadmin".into()),
+        secret_key: std::env::var("S3_TEST_SECRET_ACCESS_KEY")
+            .unwrap_or_else(|_| "minioadmin".into()),
+        path_style: true,
+        prefix: "contract-tests".into(),
+    };
+    let storage = S3Storage::new(&cfg).expect("Failed to configure S3 storage");
+
+    exercise_backend(&storage).await;
+    assert_eq!(storage.local_path("7/abc"), None);
+}
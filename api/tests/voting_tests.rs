@@ -630,3 +630,1032 @@ async fn test_homeowner_cannot_tally() {
 
     assert_eq!(response.status(), StatusCode::FORBIDDEN);
 }
+
+#[tokio::test]
+async fn test_ranked_choice_tally_stores_rounds() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+
+    let mut voters = Vec::new();
+    for i in 1..=3 {
+        let mut user = TestUser::homeowner();
+        user.email = format!("ranked{}@test.com", i);
+        voters.push(create_and_login_user(&server.pool, &client, &server.base_url, user).await);
+    }
+
+    let start_time = chrono::Local::now()
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+    let end_time = (chrono::Local::now() + chrono::Duration::days(7))
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+
+    let response = client
+        .post(format!("{}/proposals", server.base_url))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .json(&serde_json::json!({
+            "title": "Contractor",
+            "description": "Pick a contractor quote",
+            "start_time": start_time,
+            "end_time": end_time,
+            "voting_method": "SimpleMajority",
+            "eligible_roles": ["Homeowner"],
+            "ballot_type": "RankedChoice",
+            "options": ["Quote A", "Quote B", "Quote C"],
+        }))
+        .send()
+        .await
+        .expect("Failed to create proposal");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let proposal: Value = response.json().await.expect("Failed to parse response");
+    let proposal_id = proposal["id"].as_u64().expect("No proposal ID");
+    assert_eq!(proposal["ballot_type"], "RankedChoice");
+
+    let detail: Value = client
+        .get(format!("{}/proposals/{}", server.base_url, proposal_id))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let ids: Vec<u64> = detail["options"]
+        .as_array()
+        .expect("Expected options")
+        .iter()
+        .map(|o| o["id"].as_u64().unwrap())
+        .collect();
+    assert_eq!(ids.len(), 3);
+    let (a, b, c) = (ids[0], ids[1], ids[2]);
+
+    // Repeated options are rejected
+    let response = client
+        .post(format!(
+            "{}/proposals/{}/vote",
+            server.base_url, proposal_id
+        ))
+        .bearer_auth(voters[0].token.as_ref().unwrap())
+        .json(&serde_json::json!({"option_ids": [a, a]}))
+        .send()
+        .await
+        .expect("Failed to vote");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // One first preference each; C is eliminated first and its ballot moves to B
+    for (voter, ranking) in voters.iter().zip([vec![a], vec![b, a], vec![c, b]]) {
+        let response = client
+            .post(format!(
+                "{}/proposals/{}/vote",
+                server.base_url, proposal_id
+            ))
+            .bearer_auth(voter.token.as_ref().unwrap())
+            .json(&serde_json::json!({"option_ids": ranking}))
+            .send()
+            .await
+            .expect("Failed to vote");
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = client
+        .post(format!(
+            "{}/proposals/{}/tally",
+            server.base_url, proposal_id
+        ))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let tally: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(tally["passed"], true);
+    assert_eq!(tally["winning_option_id"].as_u64(), Some(b));
+
+    let detail: Value = client
+        .get(format!("{}/proposals/{}", server.base_url, proposal_id))
+        .bearer_auth(voters[1].token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(detail["user_selection"], serde_json::json!([b, a]));
+    assert_eq!(detail["result"]["winning_option_id"].as_u64(), Some(b));
+    let rounds = detail["rounds"].as_array().expect("Expected rounds");
+    assert_eq!(rounds.len(), 5);
+    let eliminated: Vec<&Value> = rounds.iter().filter(|r| r["eliminated"] == true).collect();
+    assert_eq!(eliminated.len(), 1);
+    assert_eq!(eliminated[0]["option_id"].as_u64(), Some(c));
+}
+
+#[tokio::test]
+async fn test_proxy_vote_and_in_person_override() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let manager =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::manager()).await;
+    let owner = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let mut holder = TestUser::homeowner();
+    holder.email = "proxy-holder@test.com".into();
+    let holder = create_and_login_user(&server.pool, &client, &server.base_url, holder).await;
+
+    let start_time = chrono::Local::now()
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+    let end_time = (chrono::Local::now() + chrono::Duration::days(7))
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+
+    let proposal: Value = client
+        .post(format!("{}/proposals", server.base_url))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .json(&serde_json::json!({
+            "title": "Proxy Test",
+            "description": "Vote through a proxy",
+            "start_time": start_time,
+            "end_time": end_time,
+            "voting_method": "SimpleMajority",
+            "eligible_roles": ["Homeowner"],
+        }))
+        .send()
+        .await
+        .expect("Failed to create proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let proposal_id = proposal["id"].as_u64().expect("No proposal ID");
+    let vote_url = format!("{}/proposals/{}/vote", server.base_url, proposal_id);
+
+    // Without a proxy the holder cannot vote for the owner
+    let response = client
+        .post(&vote_url)
+        .bearer_auth(holder.token.as_ref().unwrap())
+        .json(&serde_json::json!({"choice": "Yes", "on_behalf_of": owner.id}))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .post(format!("{}/proxies", server.base_url))
+        .bearer_auth(owner.token.as_ref().unwrap())
+        .json(&serde_json::json!({"grantee_id": holder.id, "proposal_id": proposal_id}))
+        .send()
+        .await
+        .expect("Failed to grant proxy");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let detail: Value = client
+        .get(format!("{}/proposals/{}", server.base_url, proposal_id))
+        .bearer_auth(holder.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to get proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(
+        detail["proxies_held"][0]["user_id"].as_u64(),
+        Some(owner.id)
+    );
+
+    let response = client
+        .post(&vote_url)
+        .bearer_auth(holder.token.as_ref().unwrap())
+        .json(&serde_json::json!({"choice": "Yes", "on_behalf_of": owner.id}))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let detail: Value = client
+        .get(format!("{}/proposals/{}", server.base_url, proposal_id))
+        .bearer_auth(owner.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to get proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(detail["user_vote"], "Yes");
+    assert_eq!(detail["user_vote_by_proxy"], true);
+    assert_eq!(detail["total_votes"], 1);
+
+    // The owner overrides in person; the holder can no longer change it
+    let response = client
+        .post(&vote_url)
+        .bearer_auth(owner.token.as_ref().unwrap())
+        .json(&serde_json::json!({"choice": "No"}))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .post(&vote_url)
+        .bearer_auth(holder.token.as_ref().unwrap())
+        .json(&serde_json::json!({"choice": "Yes", "on_behalf_of": owner.id}))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let detail: Value = client
+        .get(format!("{}/proposals/{}", server.base_url, proposal_id))
+        .bearer_auth(owner.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to get proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(detail["user_vote"], "No");
+    assert_eq!(detail["user_vote_by_proxy"], false);
+    assert_eq!(detail["no_count"], 1);
+
+    // Managers see the proxy; homeowners do not
+    let response = client
+        .get(format!(
+            "{}/proxies?proposal_id={}",
+            server.base_url, proposal_id
+        ))
+        .bearer_auth(owner.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let proxies: Value = client
+        .get(format!(
+            "{}/proxies?proposal_id={}",
+            server.base_url, proposal_id
+        ))
+        .bearer_auth(manager.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let proxies = proxies.as_array().expect("Expected proxies");
+    assert_eq!(proxies.len(), 1);
+    assert_eq!(proxies[0]["grantee_id"].as_u64(), Some(holder.id));
+
+    // Any manager can revoke a proxy for a global proposal; other residents cannot
+    let revoke_url = format!("{}/proxies/{}", server.base_url, proxies[0]["id"]);
+    let response = client
+        .delete(&revoke_url)
+        .bearer_auth(holder.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = client
+        .delete(&revoke_url)
+        .bearer_auth(manager.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_secret_ballot_receipts() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let mut voters = Vec::new();
+    for i in 1..=2 {
+        let mut user = TestUser::homeowner();
+        user.email = format!("secret{}@test.com", i);
+        voters.push(create_and_login_user(&server.pool, &client, &server.base_url, user).await);
+    }
+
+    let start_time = chrono::Local::now()
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+    let end_time = (chrono::Local::now() + chrono::Duration::days(7))
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+
+    // A voter's weight would identify their ballot
+    let response = client
+        .post(format!("{}/proposals", server.base_url))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .json(&serde_json::json!({
+            "title": "Weighted secret",
+            "description": "Not allowed",
+            "start_time": start_time,
+            "end_time": end_time,
+            "voting_method": "WeightedArea",
+            "eligible_roles": ["Homeowner"],
+            "secret_ballot": true,
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let proposal: Value = client
+        .post(format!("{}/proposals", server.base_url))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .json(&serde_json::json!({
+            "title": "Board election",
+            "description": "Secret ballot",
+            "start_time": start_time,
+            "end_time": end_time,
+            "voting_method": "SimpleMajority",
+            "eligible_roles": ["Homeowner"],
+            "secret_ballot": true,
+        }))
+        .send()
+        .await
+        .expect("Failed to create proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let proposal_id = proposal["id"].as_u64().expect("No proposal ID");
+    assert_eq!(proposal["secret_ballot"], true);
+    let vote_url = format!("{}/proposals/{}/vote", server.base_url, proposal_id);
+
+    let mut receipts = Vec::new();
+    for (voter, choice) in voters.iter().zip(["Yes", "No"]) {
+        let response = client
+            .post(&vote_url)
+            .bearer_auth(voter.token.as_ref().unwrap())
+            .json(&serde_json::json!({"choice": choice}))
+            .send()
+            .await
+            .expect("Failed to vote");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = response.json().await.expect("Failed to parse response");
+        receipts.push(body["receipt"].as_str().expect("No receipt").to_string());
+    }
+
+    // One ballot per voter; a secret ballot cannot be changed
+    let response = client
+        .post(&vote_url)
+        .bearer_auth(voters[0].token.as_ref().unwrap())
+        .json(&serde_json::json!({"choice": "No"}))
+        .send()
+        .await
+        .expect("Failed to vote");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Counts stay hidden until the tally and votes rows do not hold the choice
+    let detail: Value = client
+        .get(format!("{}/proposals/{}", server.base_url, proposal_id))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to get proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(detail["total_votes"], 2);
+    assert_eq!(detail["yes_count"], 0);
+    assert_eq!(detail["no_count"], 0);
+    {
+        use api::schema::votes::dsl as v;
+        use diesel::prelude::*;
+        let mut conn = server.pool.get().expect("Failed to get connection");
+        let choices: Vec<String> = v::votes
+            .filter(v::proposal_id.eq(proposal_id))
+            .select(v::choice)
+            .load(&mut conn)
+            .expect("Failed to load votes");
+        assert!(choices.iter().all(|c| c == "Secret"));
+    }
+
+    // Receipts are published only after the tally
+    let receipts_url = format!("{}/proposals/{}/receipts", server.base_url, proposal_id);
+    let response = client
+        .get(&receipts_url)
+        .bearer_auth(voters[0].token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client
+        .post(format!(
+            "{}/proposals/{}/tally",
+            server.base_url, proposal_id
+        ))
+        .bearer_auth(admin.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to tally");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let published: Value = client
+        .get(&receipts_url)
+        .bearer_auth(voters[0].token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let published = published.as_array().expect("Expected ballots");
+    assert_eq!(published.len(), 2);
+    let mine = published
+        .iter()
+        .find(|b| b["receipt"] == receipts[0].as_str())
+        .expect("Receipt not published");
+    assert_eq!(mine["choice"], "Yes");
+    // Weights are not published per ballot
+    assert!(mine.get("weight").is_none());
+
+    // The tally works from per-content totals; ballots carry no weight
+    {
+        use api::schema::secret_ballot_totals::dsl as st;
+        use diesel::prelude::*;
+        let mut conn = server.pool.get().expect("Failed to get connection");
+        let totals: Vec<(String, i32)> = st::secret_ballot_totals
+            .filter(st::proposal_id.eq(proposal_id))
+            .order(st::choice.asc())
+            .select((st::choice, st::ballots))
+            .load(&mut conn)
+            .expect("Failed to load totals");
+        assert_eq!(totals, vec![("No".to_string(), 1), ("Yes".to_string(), 1)]);
+    }
+}
+
+#[tokio::test]
+async fn test_proposal_discussion_documents_and_tally_notification() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let homeowner = create_and_login_user(
+        &server.pool,
+        &client,
+        &server.base_url,
+        TestUser::homeowner(),
+    )
+    .await;
+    let admin_token = admin.token.as_ref().unwrap();
+    let homeowner_token = homeowner.token.as_ref().unwrap();
+
+    let start_time = chrono::Local::now()
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+    let end_time = (chrono::Local::now() + chrono::Duration::days(7))
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+    let proposal: Value = client
+        .post(format!("{}/proposals", server.base_url))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "title": "Facade repair",
+            "description": "Repair the facade",
+            "start_time": start_time,
+            "end_time": end_time,
+            "voting_method": "SimpleMajority",
+            "eligible_roles": ["Homeowner"],
+        }))
+        .send()
+        .await
+        .expect("Failed to create proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let proposal_url = format!("{}/proposals/{}", server.base_url, proposal["id"]);
+
+    // Owners discuss the proposal while it is open
+    let response = client
+        .post(format!("{}/comments", proposal_url))
+        .bearer_auth(homeowner_token)
+        .json(&serde_json::json!({ "body": "Is the second quote cheaper?" }))
+        .send()
+        .await
+        .expect("Failed to comment");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let comment: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(comment["user_name"], "Test Homeowner");
+    assert_eq!(comment["can_delete"], true);
+
+    // Managers attach quotes; residents cannot
+    let form = || {
+        reqwest::multipart::Form::new().part(
+            "file",
+            reqwest::multipart::Part::bytes(b"%PDF-1.4\n%quote\n".to_vec()).file_name("quote.pdf"),
+        )
+    };
+    let response = client
+        .post(format!("{}/attachments", proposal_url))
+        .bearer_auth(homeowner_token)
+        .multipart(form())
+        .send()
+        .await
+        .expect("Failed to upload");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .post(format!("{}/attachments", proposal_url))
+        .bearer_auth(admin_token)
+        .multipart(form())
+        .send()
+        .await
+        .expect("Failed to upload");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let attachment: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(attachment["original_filename"], "quote.pdf");
+
+    let documents: Value = client
+        .get(format!("{}/attachments", proposal_url))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .expect("Failed to list documents")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(documents.as_array().unwrap().len(), 1);
+
+    let response = client
+        .get(format!(
+            "{}/attachments/{}/download",
+            proposal_url, attachment["id"]
+        ))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .expect("Failed to download");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Tallying closes the discussion and notifies the commenter
+    let response = client
+        .post(format!("{}/tally", proposal_url))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to tally");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .post(format!("{}/comments", proposal_url))
+        .bearer_auth(homeowner_token)
+        .json(&serde_json::json!({ "body": "Too late" }))
+        .send()
+        .await
+        .expect("Failed to comment");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let notifications: Value = client
+        .get(format!("{}/notifications", server.base_url))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .expect("Failed to list notifications")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(notifications["unread_count"], 1);
+    let notification = &notifications["notifications"][0];
+    assert_eq!(notification["kind"], "ProposalTallied");
+    assert_eq!(notification["subject"], "Facade repair");
+
+    // A re-tally does not notify again
+    client
+        .post(format!("{}/tally", proposal_url))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to tally");
+    let response = client
+        .post(format!(
+            "{}/notifications/{}/read",
+            server.base_url, notification["id"]
+        ))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .expect("Failed to mark read");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let notifications: Value = client
+        .get(format!("{}/notifications", server.base_url))
+        .bearer_auth(homeowner_token)
+        .send()
+        .await
+        .expect("Failed to list notifications")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(notifications["unread_count"], 0);
+    assert_eq!(notifications["notifications"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_audit_report_lists_voters_and_verifies_hash() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let mut owners = Vec::new();
+    for name in ["Alice Owner", "Bob Owner"] {
+        let mut user = TestUser::homeowner();
+        user.email = format!("{}@test.com", name.replace(' ', ".").to_lowercase());
+        user.name = name.to_string();
+        owners.push(create_and_login_user(&server.pool, &client, &server.base_url, user).await);
+    }
+    let admin_token = admin.token.as_ref().unwrap();
+
+    let start_time = chrono::Local::now()
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+    let end_time = (chrono::Local::now() + chrono::Duration::days(7))
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+    let proposal: Value = client
+        .post(format!("{}/proposals", server.base_url))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "title": "Elevator upgrade",
+            "description": "Replace the elevator",
+            "start_time": start_time,
+            "end_time": end_time,
+            "voting_method": "SimpleMajority",
+            "eligible_roles": ["Homeowner"],
+        }))
+        .send()
+        .await
+        .expect("Failed to create proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let proposal_id = proposal["id"].as_u64().expect("No proposal ID");
+    let report_url = format!("{}/proposals/{}/audit-report", server.base_url, proposal_id);
+
+    // Only Alice votes
+    let response = client
+        .post(format!(
+            "{}/proposals/{}/vote",
+            server.base_url, proposal_id
+        ))
+        .bearer_auth(owners[0].token.as_ref().unwrap())
+        .json(&serde_json::json!({"choice": "Yes"}))
+        .send()
+        .await
+        .expect("Failed to vote");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // No report before the tally, and never for residents
+    let response = client
+        .get(&report_url)
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client
+        .post(format!(
+            "{}/proposals/{}/tally",
+            server.base_url, proposal_id
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to tally");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(&report_url)
+        .bearer_auth(owners[0].token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .get(format!("{}?format=csv", report_url))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to get report");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "text/csv");
+    let csv = response.text().await.expect("Failed to read report");
+    assert!(csv.contains("Rule version,v1\n"));
+    assert!(csv.contains("Eligible voters,2\n"));
+    assert!(csv.contains("Voted,1\n"));
+    assert!(csv.contains("Hash verified,Yes\n"));
+    let alice = csv
+        .lines()
+        .find(|l| l.starts_with("Alice Owner,"))
+        .expect("Voter missing");
+    assert!(alice.starts_with("Alice Owner,1.00,Yes,"));
+    assert!(alice.ends_with(",Yes"));
+    assert!(csv.contains("Bob Owner,1.00,No,,,\n"));
+
+    let response = client
+        .get(&report_url)
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to get report");
+    assert_eq!(response.headers()["content-type"], "application/pdf");
+    let pdf = response.bytes().await.expect("Failed to read report");
+    assert!(pdf.starts_with(b"%PDF-1.4"));
+
+    // Changing a counted vote after the tally breaks the hash
+    {
+        use api::schema::votes::dsl as v;
+        use diesel::prelude::*;
+        let mut conn = server.pool.get().expect("Failed to get connection");
+        diesel::update(v::votes.filter(v::proposal_id.eq(proposal_id)))
+            .set(v::choice.eq("No"))
+            .execute(&mut conn)
+            .expect("Failed to update vote");
+    }
+    let csv = client
+        .get(format!("{}?format=csv", report_url))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to get report")
+        .text()
+        .await
+        .expect("Failed to read report");
+    assert!(csv.contains("Hash verified,No\n"));
+}
+
+/// Apartments numbered from 1 in a new building, each owned by the given users.
+fn seed_apartments(server: &TestServer, owners: &[&[u64]]) -> (u64, Vec<u64>) {
+    use api::schema::apartment_owners::dsl as ao;
+    use api::schema::apartments::dsl as apt;
+    use api::schema::buildings::dsl as b;
+    use diesel::prelude::*;
+
+    let mut conn = server.pool.get().expect("Failed to get connection");
+    diesel::insert_into(b::buildings)
+        .values(b::address.eq("5 Seat Street"))
+        .execute(&mut conn)
+        .expect("Failed to insert building");
+    let building_id: u64 = b::buildings
+        .select(b::id)
+        .order(b::id.desc())
+        .first(&mut conn)
+        .expect("Failed to load building");
+
+    let mut apartment_ids = Vec::new();
+    for (number, users) in owners.iter().enumerate() {
+        diesel::insert_into(apt::apartments)
+            .values((
+                apt::building_id.eq(building_id),
+                apt::number.eq(format!("{}", number + 1)),
+            ))
+            .execute(&mut conn)
+            .expect("Failed to insert apartment");
+        let apartment_id: u64 = apt::apartments
+            .select(apt::id)
+            .order(apt::id.desc())
+            .first(&mut conn)
+            .expect("Failed to load apartment");
+        for user_id in users.iter() {
+            diesel::insert_into(ao::apartment_owners)
+                .values((ao::apartment_id.eq(apartment_id), ao::user_id.eq(*user_id)))
+                .execute(&mut conn)
+                .expect("Failed to insert owner");
+        }
+        apartment_ids.push(apartment_id);
+    }
+    (building_id, apartment_ids)
+}
+
+#[tokio::test]
+async fn test_per_seat_votes_are_per_apartment_with_history() {
+    let server = TestServer::start().await;
+    let client = reqwest::Client::new();
+    let admin =
+        create_and_login_user(&server.pool, &client, &server.base_url, TestUser::admin()).await;
+    let mut owners = Vec::new();
+    for name in ["Alice Owner", "Bob Owner", "Carol Owner"] {
+        let mut user = TestUser::homeowner();
+        user.email = format!("{}@test.com", name.replace(' ', ".").to_lowercase());
+        user.name = name.to_string();
+        owners.push(create_and_login_user(&server.pool, &client, &server.base_url, user).await);
+    }
+    let (alice, bob, carol) = (&owners[0], &owners[1], &owners[2]);
+    let admin_token = admin.token.as_ref().unwrap();
+
+    // Alice and Bob share apartment 1; Carol owns apartments 2 and 3
+    let (building_id, apartments) =
+        seed_apartments(&server, &[&[alice.id, bob.id], &[carol.id], &[carol.id]]);
+
+    let start_time = chrono::Local::now()
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+    let end_time = (chrono::Local::now() + chrono::Duration::days(7))
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M")
+        .to_string();
+    let proposal: Value = client
+        .post(format!("{}/proposals", server.base_url))
+        .bearer_auth(admin_token)
+        .json(&serde_json::json!({
+            "title": "Facade paint",
+            "description": "Repaint the facade",
+            "building_id": building_id,
+            "start_time": start_time,
+            "end_time": end_time,
+            "voting_method": "PerSeat",
+            "eligible_roles": ["Homeowner"],
+        }))
+        .send()
+        .await
+        .expect("Failed to create proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let proposal_id = proposal["id"].as_u64().expect("No proposal ID");
+    let vote_url = format!("{}/proposals/{}/vote", server.base_url, proposal_id);
+    let vote = |user: &TestUser, body: Value| {
+        client
+            .post(&vote_url)
+            .bearer_auth(user.token.as_ref().unwrap())
+            .json(&body)
+            .send()
+    };
+
+    // Co-owners share one seat: the latest vote replaces the earlier one
+    let response = vote(alice, serde_json::json!({"choice": "Yes"}))
+        .await
+        .expect("Failed to vote");
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = vote(bob, serde_json::json!({"choice": "No"}))
+        .await
+        .expect("Failed to vote");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let detail: Value = client
+        .get(format!("{}/proposals/{}", server.base_url, proposal_id))
+        .bearer_auth(alice.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to get proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(detail["total_votes"], 1);
+    assert_eq!(detail["user_vote"], "No");
+    assert_eq!(detail["seats"].as_array().unwrap().len(), 1);
+    assert_eq!(detail["seats"][0]["vote"], "No");
+    assert_eq!(detail["seats"][0]["voted_by"], "Bob Owner");
+
+    // Owners of several apartments vote for each one separately
+    let response = vote(carol, serde_json::json!({"choice": "Yes"}))
+        .await
+        .expect("Failed to vote");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = vote(
+        carol,
+        serde_json::json!({"choice": "Yes", "apartment_id": apartments[0]}),
+    )
+    .await
+    .expect("Failed to vote");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    for apartment_id in &apartments[1..] {
+        let response = vote(
+            carol,
+            serde_json::json!({"choice": "Yes", "apartment_id": apartment_id}),
+        )
+        .await
+        .expect("Failed to vote");
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let detail: Value = client
+        .get(format!("{}/proposals/{}", server.base_url, proposal_id))
+        .bearer_auth(carol.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to get proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(detail["total_votes"], 3);
+    assert!(detail["user_vote"].is_null());
+    let seats = detail["seats"].as_array().unwrap();
+    assert_eq!(seats.len(), 2);
+    assert!(seats.iter().all(|s| s["vote"] == "Yes"));
+
+    // The shared apartment's history shows both votes, for its owners only
+    let history_url = format!(
+        "{}/proposals/{}/seats/{}/history",
+        server.base_url, proposal_id, apartments[0]
+    );
+    let history: Value = client
+        .get(&history_url)
+        .bearer_auth(alice.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to get history")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let entries = history.as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["user_name"], "Alice Owner");
+    assert_eq!(entries[0]["choice"], "Yes");
+    assert_eq!(entries[1]["user_name"], "Bob Owner");
+    assert_eq!(entries[1]["choice"], "No");
+
+    let response = client
+        .get(&history_url)
+        .bearer_auth(carol.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // A proxy holder sees the vote of the grantor's seat, whoever cast it
+    let response = client
+        .post(format!("{}/proxies", server.base_url))
+        .bearer_auth(alice.token.as_ref().unwrap())
+        .json(&serde_json::json!({"grantee_id": carol.id, "proposal_id": proposal_id}))
+        .send()
+        .await
+        .expect("Failed to grant proxy");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let detail: Value = client
+        .get(format!("{}/proposals/{}", server.base_url, proposal_id))
+        .bearer_auth(carol.token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to get proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(
+        detail["proxies_held"][0]["user_id"].as_u64(),
+        Some(alice.id)
+    );
+    assert_eq!(detail["proxies_held"][0]["vote"], "No");
+    assert_eq!(detail["proxies_held"][0]["voted_in_person"], true);
+
+    // Two apartments for, one against
+    let response = client
+        .post(format!(
+            "{}/proposals/{}/tally",
+            server.base_url, proposal_id
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to tally");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let detail: Value = client
+        .get(format!("{}/proposals/{}", server.base_url, proposal_id))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to get proposal")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(detail["result"]["passed"], true);
+
+    let csv = client
+        .get(format!(
+            "{}/proposals/{}/audit-report?format=csv",
+            server.base_url, proposal_id
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .expect("Failed to get report")
+        .text()
+        .await
+        .expect("Failed to read report");
+    assert!(csv.contains("Eligible apartments,3\n"));
+    assert!(csv.contains("\"5 Seat Street, apt. 1 (Bob Owner)\",1.00,Yes,"));
+    assert!(csv.contains("Hash verified,Yes\n"));
+}
@@ -22,6 +22,8 @@ nav-manage = Správa
 nav-login = Přihlásit
 nav-maintenance = Údržba
 nav-voting = Hlasování
+nav-polls = Ankety
+nav-meetings = Schůze
 nav-my-properties = Moje nemovitosti
 nav-meters = Měřidla
 nav-navigation = Navigace
@@ -105,6 +107,10 @@ sidebar-user-management = Správa uživatelů
 sidebar-admin-announcements = Oznámení
 sidebar-admin-properties = Nemovitosti
 sidebar-meters = Měřidla
+sidebar-meter-submissions = Zaslané odečty
+sidebar-meter-campaigns = Kampaně odečtů
+sidebar-meter-import = Import odečtů
+sidebar-meter-api-keys = API klíče
 
 ## Stránkování
 pagination-items-total = Celkem { $count } položek
@@ -153,6 +159,17 @@ home-hero-title = Správa domu
 home-hero-subtitle = Spravujte svou nemovitost, údržbu a komunitu — vše na jednom místě.
 home-sign-in-button = Přihlaste se pro začátek
 
+# ============================================================
+# Upozornění
+# ============================================================
+notifications-title = Upozornění
+notifications-empty = Žádná upozornění.
+notifications-mark-all-read = Označit vše jako přečtené
+notifications-proposal-tallied = Výsledky hlasování „{ $subject }“ jsou k dispozici.
+notifications-meter-reading-approved = Váš odečet měřidla { $subject } byl schválen.
+notifications-meter-reading-rejected = Váš odečet měřidla { $subject } byl zamítnut.
+notifications-reading-campaign-reminder = Zašlete prosím odečty svých měřidel pro { $subject }.
+
 # ============================================================
 # Oznámení
 # ============================================================
@@ -198,6 +215,98 @@ announcement-apartment-prefix = Byt:
 announcement-published-prefix = Publikováno:
 announcement-expires-prefix = Vyprší:
 
+## Announcement Read Receipts
+announcement-requires-ack-label = Vyžadovat potvrzení
+announcement-ack-required = Potvrďte prosím, že jste si oznámení přečetli.
+announcement-acknowledge-btn = Přečetl(a) jsem
+announcement-acknowledged-prefix = Potvrzeno:
+announcement-receipts-heading = Potvrzení o přečtení
+announcement-receipts-read = Přečteno { $count } z { $total }
+announcement-receipts-acknowledged = Potvrzeno { $count } z { $total }
+announcement-receipts-pending = Zatím nevyřízeno:
+announcement-receipts-none-pending = Všichni adresáti jsou hotovi.
+announcement-receipts-read-at = přečteno
+announcement-receipts-unread = Nepřečteno
+
+## Analytika oznámení
+announcement-analytics-heading = Zájem o oznámení
+announcement-analytics-audience = Adresáti
+announcement-analytics-read = Přečteno
+announcement-analytics-acknowledged = Potvrzeno
+announcement-analytics-comments = Komentáře
+announcement-analytics-comments-by = { $count } od { $people } lidí
+announcement-analytics-first-read = První přečtení po
+announcement-analytics-median-read = Medián doby do přečtení
+announcement-analytics-outside-audience = Čtenáři mimo adresáty
+announcement-analytics-readers-over-time = Noví čtenáři za den
+announcement-analytics-day-tooltip = { $date }: { $count } nových, celkem { $total }
+announcement-analytics-by-building = Dosah podle budov
+announcement-analytics-by-role = Dosah podle rolí
+announcement-analytics-no-building = Bez bytu
+announcement-engagement-heading = Zájem o oznámení
+announcement-engagement-all-buildings = Všechny budovy
+announcement-engagement-days = Posledních { $days } dní
+announcement-engagement-announcements = Oznámení
+announcement-engagement-median-first-read = Medián doby do prvního přečtení
+announcement-engagement-comments-short = komentářů
+announcement-engagement-empty = V tomto období nebylo zveřejněno žádné oznámení.
+
+## Announcement Revisions
+announcement-edited = upraveno
+announcement-history-show = Historie
+announcement-history-hide = Skrýt historii
+announcement-history-heading = Historie úprav
+announcement-history-revision = Verze { $number }
+announcement-history-by = upravil(a) { $name }
+announcement-history-restored-from = obnoveno z verze { $number }
+announcement-history-compare-from = Od
+announcement-history-compare-to = Do
+announcement-history-compare-btn = Porovnat
+announcement-history-title-changed = Nadpis: { $from } → { $to }
+announcement-history-no-changes = Texty jsou shodné.
+announcement-history-restore-btn = Obnovit
+announcement-history-restore-confirm = Obnovit tuto verzi? Aktuální text zůstane v historii.
+
+## Announcement Attachments
+announcement-attachments-label = Přílohy:
+announcement-attachments-empty = Zatím žádné soubory. Nahrané obrázky lze vložit do textu.
+announcement-attachment-upload = Nahrát soubor
+announcement-attachment-insert = Vložit do textu
+announcement-attachment-delete-confirm = Smazat tuto přílohu? Odkazy na ni v textu přestanou fungovat.
+announcement-attachment-upload-failed = Nepodařilo se nahrát soubor: { $error }
+announcement-attachment-infected = Soubor byl odmítnut, protože pravděpodobně obsahuje malware
+
+## Šablony oznámení
+announcement-languages-label = Jazyky
+announcement-main-language = Hlavní jazyk:
+announcement-translations-empty = Žádné překlady. Čtenáři v jiných jazycích uvidí hlavní text.
+announcement-templates-label = Šablony
+announcement-template-select = Vyberte šablonu…
+announcement-template-save = Uložit jako šablonu
+announcement-template-name-prompt = Název šablony:
+announcement-template-saved = Šablona uložena
+announcement-template-save-failed = Šablonu se nepodařilo uložit
+announcement-template-apply = Použít šablonu
+announcement-template-apply-failed = Šablonu se nepodařilo použít
+announcement-template-missing = Nevyplněno: { $names }
+announcement-template-delete-confirm = Smazat tuto šablonu?
+announcement-template-builtin-hint = {"{{"}building_address{"}}"}, {"{{"}today{"}}"}, {"{{"}publish_date{"}}"} a {"{{"}expire_date{"}}"} se doplní z budovy a termínů výše.
+
+## Kanály
+feeds-heading = Kanály a kalendář
+feeds-description = Sledujte oznámení a termíny hlasování ve čtečce kanálů a přidejte si hlasování do kalendáře. Odkazy fungují bez přihlášení.
+feeds-create = Vytvořit odkazy
+feeds-regenerate = Nové odkazy
+feeds-revoke = Zrušit
+feeds-regenerate-confirm = Vytvořit nové odkazy? Stávající přestanou fungovat.
+feeds-revoke-confirm = Zrušit odkazy na kanály? Odběry, které je používají, se přestanou aktualizovat.
+feeds-create-failed = Odkazy se nepodařilo vytvořit
+feeds-atom-label = Atom
+feeds-calendar-label = Kalendář
+feeds-private-warning = Odkazy nikomu nesdílejte: kdokoli s nimi může číst vaše oznámení. Zobrazují se jen nyní.
+feeds-created-at = Odkazy vytvořeny
+feeds-last-used-at = naposledy použity
+
 ## Komentáře
 comments-heading = Komentáře
 comments-disabled = Komentáře zakázány.
@@ -210,12 +319,37 @@ comment-delete-button = Smazat
 comment-restore-button = Obnovit
 comment-purge-button = Trvale odstranit
 comment-show-deleted-toggle = Zobrazit smazané
+comment-reply-button = Odpovědět
+comment-replying-to = Odpověď pro { $name }
+comment-edit-button = Upravit
+comment-save-button = Uložit
+comment-cancel-button = Zrušit
+comment-edited-marker = (upraveno)
+comment-react-button = Přidat reakci
+comment-mention-hint = Napište @ a zmiňte souseda.
+comment-report-button = Nahlásit
+comment-report-prompt = Proč tento komentář nahlašujete?
+comment-report-sent = Děkujeme, komentář posoudí moderátor.
+comment-hide-button = Skrýt
+comment-hide-prompt = Důvod zobrazený místo komentáře:
+comment-unhide-button = Zobrazit
+comment-hidden-notice = Skryto moderátorem: { $reason }
+
+## Moderace komentářů
+comment-moderation-heading = Nahlášené komentáře ({ $count })
+comment-moderation-report-count = Nahlášení: { $count }
+comment-moderation-dismiss = Zamítnout nahlášení
 
 ## Chyby komentářů
 comment-delete-failed = Smazání selhalo
 comment-restore-failed = Obnovení selhalo
 comment-purge-failed = Trvalé odstranění selhalo
 comment-post-failed = Odeslání komentáře selhalo
+comment-edit-failed = Uložení komentáře selhalo
+comment-react-failed = Reakce selhala
+comment-report-failed = Nahlášení selhalo
+comment-hide-failed = Moderace selhala
+comment-moderation-dismiss-failed = Zamítnutí nahlášení selhalo
 
 # ============================================================
 # Údržba
@@ -256,6 +390,7 @@ maintenance-detail-created-by = Vytvořil:
 maintenance-detail-assigned-to = Přiřazeno:
 maintenance-detail-created = Vytvořeno:
 maintenance-detail-unassigned = Nepřiřazeno
+maintenance-detail-vendor = Dodavatel:
 
 ## Typy údržby
 maintenance-type-general = Obecné
@@ -271,6 +406,7 @@ maintenance-status-all = Vše
 maintenance-status-open = Otevřeno
 maintenance-status-in-progress = Probíhá
 maintenance-status-resolved = Vyřešeno
+maintenance-status-closed = Uzavřeno
 
 ## Priority údržby
 maintenance-priority-low = Nízká
@@ -292,6 +428,22 @@ maintenance-priority-updated = Priorita úspěšně aktualizována
 maintenance-assigned-success = Požadavek úspěšně přiřazen
 maintenance-no-permission-update = Nemáte oprávnění aktualizovat požadavky
 maintenance-no-permission-assign = Nemáte oprávnění přiřazovat požadavky
+maintenance-vendor = Dodavatel
+maintenance-vendor-placeholder = Firma provádějící práci
+maintenance-vendor-btn = Nastavit dodavatele
+maintenance-vendor-updated = Dodavatel úspěšně aktualizován
+
+## Maintenance Feedback
+maintenance-feedback = Vaše hodnocení
+maintenance-feedback-prompt = Požadavek byl označen jako vyřešený. Jak jste spokojeni a je závada opravdu odstraněna?
+maintenance-feedback-comment-placeholder = Volitelný komentář
+maintenance-feedback-confirm-fixed = Ano, je opraveno
+maintenance-feedback-report-not-fixed = Není opraveno
+maintenance-feedback-fixed = Opraveno
+maintenance-feedback-not-fixed = Neopraveno
+maintenance-feedback-rating-required = Nejprve prosím zvolte hodnocení
+maintenance-feedback-thanks = Děkujeme za vaše hodnocení
+maintenance-feedback-reopened = Požadavek byl znovu otevřen
 
 ## Historie údržby
 maintenance-history = Historie
@@ -334,9 +486,12 @@ maintenance-failed-load = Nepodařilo se načíst požadavek: {$error}
 maintenance-failed-post-comment = Nepodařilo se odeslat komentář: {$error}
 maintenance-failed-delete-comment = Nepodařilo se smazat komentář: {$error}
 maintenance-failed-upload = Nepodařilo se nahrát soubor: {$error}
+maintenance-upload-infected = Soubor byl odmítnut, protože pravděpodobně obsahuje malware
 maintenance-failed-update-status = Nepodařilo se aktualizovat stav: {$error}
 maintenance-failed-update-priority = Nepodařilo se aktualizovat prioritu: {$error}
 maintenance-failed-assign = Nepodařilo se přiřadit požadavek: {$error}
+maintenance-failed-update-vendor = Nepodařilo se aktualizovat dodavatele: {$error}
+maintenance-failed-feedback = Nepodařilo se odeslat hodnocení: {$error}
 maintenance-failed-escalate = Nepodařilo se eskalovat: {$error}
 maintenance-cancel = Zrušit
 maintenance-posting = Odesílání...
@@ -475,6 +630,295 @@ voting-not-available = Hlasování není k dispozici.
 voting-proposal-not-found = Návrh nebyl nalezen
 voting-management = Správa
 
+## Hlasovací lístky
+voting-ballot-type = Typ hlasování
+voting-ballot-yes-no-desc = Ano / Ne - schválit nebo zamítnout návrh
+voting-ballot-single-desc = Jedna volba - vyberte jednu možnost
+voting-ballot-approval-desc = Souhlasné hlasování - vyberte všechny přijatelné možnosti
+voting-ballot-ranked-desc = Pořadové hlasování - seřaďte možnosti podle preference (postupné vyřazování)
+voting-ballot-single = Jedna volba
+voting-ballot-approval = Souhlasné
+voting-ballot-ranked = Pořadové
+voting-options = Možnosti
+voting-options-placeholder = Jedna možnost na řádek
+voting-options-required = Zadejte alespoň dvě možnosti.
+voting-max-selections = Maximální počet voleb
+voting-max-selections-help = Ponechte prázdné pro libovolný počet možností.
+voting-ranked-help = Přidávejte možnosti v pořadí podle preference. Pokud je váš favorit vyřazen, hlas přejde na další volbu.
+voting-approval-max = Vyberte nejvýše { $count } možností.
+voting-submit-ballot = Odeslat hlas
+voting-ballot-cast = Váš hlas byl zaznamenán.
+voting-option-counts = Hlasy podle možností
+voting-first-preferences = prvních preferencí
+voting-ballots = hlasů
+voting-rounds = Kola sčítání
+voting-option = Možnost
+voting-round-number = Kolo { $number }
+voting-eliminated = Vyřazeno v tomto kole
+voting-winning-option = Vítězná možnost: { $option }
+
+## Plné moci
+voting-proxy = Plná moc
+voting-proxies-title = Plné moci
+voting-proxy-votes = Hlasy na základě plné moci
+voting-proxy-votes-help = Vlastníci mohou do konce hlasování hlasovat osobně, čímž váš hlas nahradí.
+voting-proxy-voted-in-person = Hlasoval(a) osobně
+voting-proxy-voted-for-you = Za vás hlasoval zmocněnec. Novým hlasováním jeho hlas nahradíte.
+voting-proxy-vote-cast = Hlas v zastoupení byl zaznamenán.
+voting-proxy-grant-help = Zmocněte jinou osobu, aby za vás hlasovala o tomto návrhu.
+voting-proxy-select-holder = Vyberte zmocněnce
+voting-proxy-select-building = Vyberte budovu
+voting-proxy-grant = Udělit plnou moc
+voting-proxy-granted = Plná moc udělena: { $name }.
+voting-proxy-held-by = Vaši plnou moc drží { $name }.
+voting-proxy-revoke = Odvolat
+voting-proxy-revoked = Plná moc byla odvolána.
+voting-proxy-confirm-revoke = Odvolat tuto plnou moc?
+voting-proxy-failed-grant = Nepodařilo se udělit plnou moc: { $error }
+voting-proxy-failed-revoke = Nepodařilo se odvolat plnou moc: { $error }
+voting-proxy-failed-load = Nepodařilo se načíst plné moci: { $error }
+voting-proxy-fill-fields = Vyberte budovu a zmocněnce.
+voting-proxy-not-owner = Plnou moc pro celou budovu můžete udělit jen v budově, kde vlastníte byt.
+voting-proxies-granted = Udělené plné moci
+voting-proxies-received = Plné moci, které držíte
+voting-proxies-none = Žádné plné moci.
+voting-proxies-all = Všechny plné moci
+voting-proxies-include-revoked = Včetně odvolaných
+voting-proxy-building-grant = Plná moc pro celou budovu
+voting-proxy-building-grant-help = Platí pro všechny návrhy v budově, které začínají v daném období.
+voting-proxy-building = Budova
+voting-proxy-holder = Zmocněnec
+voting-proxy-grantor = Vlastník
+voting-proxy-scope = Rozsah
+voting-proxy-created = Uděleno
+voting-proxy-valid-from = Platí od
+voting-proxy-valid-until = Platí do
+voting-proxy-scope-building = Všechny návrhy začínající { $from } – { $until }
+voting-proxy-revoked-at = Odvoláno { $date }
+
+## Tajné hlasování
+voting-secret = Tajné
+voting-secret-ballot = Tajné hlasování
+voting-secret-ballot-help = Hlasovací lístky se ukládají bez hlasujícího. Výsledky zůstávají skryté až do sečtení a hlas nelze změnit ani odevzdat v zastoupení.
+voting-secret-ballot-weighted = Tajné hlasování není u váženého hlasování k dispozici: váha hlasujícího by prozradila jeho hlasovací lístek.
+voting-secret-results-hidden = Jde o tajné hlasování. Výsledky budou zveřejněny po sečtení.
+voting-secret-ballot-cast = Svůj tajný hlas jste již odevzdali.
+voting-secret-receipt = Váš hlas byl zaznamenán. Uložte si níže uvedený kód potvrzení, znovu se již nezobrazí. Po sečtení jím můžete ověřit, že byl váš hlas započítán.
+voting-secret-final = Tajný hlas nelze po odevzdání změnit.
+voting-receipt-check = Ověření potvrzení
+voting-receipt-published = Sečteno hlasovacích lístků: { $count }. Zadejte kód potvrzení a najděte ten svůj.
+voting-receipt-verify = Ověřit
+voting-receipt-counted = Váš hlas byl započítán:
+voting-receipt-not-found = Hlasovací lístek s tímto kódem nebyl nalezen.
+
+## Diskuse
+voting-discussion-title = Diskuse
+voting-discussion-empty = Zatím žádné příspěvky.
+voting-discussion-your-message = Vaše zpráva
+voting-discussion-post = Odeslat
+voting-discussion-closed = Hlasování skončilo, diskuse je uzavřena.
+voting-discussion-delete-confirm = Smazat tento příspěvek?
+voting-discussion-failed-post = Nepodařilo se odeslat příspěvek: { $error }
+
+## Podklady
+voting-documents-title = Podklady
+voting-documents-empty = Žádné přiložené dokumenty.
+voting-documents-upload = Nahrát
+voting-documents-infected = Soubor byl odmítnut, protože obsahuje malware.
+voting-documents-upload-failed = Nahrání selhalo: { $error }
+voting-documents-download-failed = Stažení selhalo: { $error }
+voting-documents-delete-confirm = Smazat tento dokument?
+
+## Protokol o hlasování
+voting-audit-title = Protokol o hlasování
+voting-audit-desc = Oprávnění voliči s váhami hlasů, účast, součty, verze pravidel a otisk výsledků pro kontrolu neporušenosti.
+voting-audit-pdf = PDF
+voting-audit-csv = CSV
+voting-audit-failed = Protokol se nepodařilo stáhnout: { $error }
+
+## Hlasy za byty
+voting-seats-title = Vaše byty
+voting-seat-label = Byt { $number }, { $address }
+voting-seat-not-voted = Nehlasováno
+voting-seat-voted = Hlasováno
+voting-seat-voted-by = Hlasoval(a) { $name }
+voting-seat-history = Historie
+voting-seat-history-by-proxy = (v zastoupení { $name })
+
+# ============================================================
+# Ankety
+# ============================================================
+polls-title = Ankety
+polls-subtitle = Rychlé průzkumy mezi obyvateli, oddělené od formálního hlasování
+polls-new-poll = Nová anketa
+polls-loading = Načítání anket...
+polls-none = Zatím žádné ankety.
+polls-not-found = Anketa nebyla nalezena
+polls-responded = Zodpovězeno
+polls-can-respond = Můžete odpovědět
+polls-anonymous = Anonymní
+polls-question-count = Otázky: { $count }
+polls-response-count = Odpovědi: { $count }
+polls-failed-load = Nepodařilo se načíst ankety: { $error }
+polls-failed-load-poll = Nepodařilo se načíst anketu: { $error }
+
+## Stavy anket
+polls-status-scheduled = Naplánováno
+polls-status-open = Otevřeno
+polls-status-closed = Uzavřeno
+
+## Viditelnost výsledků
+polls-results-visibility = Viditelnost výsledků
+polls-visibility-always = Vždy
+polls-visibility-after-response = Po odpovědi
+polls-visibility-after-close = Po uzavření ankety
+polls-visibility-managers = Pouze správci
+
+## Nová anketa
+polls-new-title = Vytvořit anketu
+polls-new-breadcrumb = Nová
+polls-basic-info = Základní informace
+polls-poll-title = Název
+polls-poll-description = Popis
+polls-building-scope = Budova
+polls-global-scope = Všechny budovy
+polls-settings = Nastavení
+polls-start-time = Začátek
+polls-end-time = Konec
+polls-anonymous-help = Anonymní — odpovědi nejsou spojeny s obyvateli
+polls-questions = Otázky
+polls-question-number = Otázka { $number }
+polls-question-prompt = Otázka
+polls-question-kind = Typ
+polls-question-options = Možnosti (jedna na řádek)
+polls-question-rating-max = Nejvyšší hodnocení
+polls-question-required = Povinná odpověď
+polls-kind-single = Jedna možnost
+polls-kind-multiple = Více možností
+polls-kind-text = Volný text
+polls-kind-rating = Hodnocení
+polls-add-question = Přidat otázku
+polls-create = Vytvořit anketu
+polls-title-required = Zadejte prosím název.
+polls-question-prompt-required = Každá otázka musí mít znění.
+polls-question-options-required = Otázky s výběrem potřebují alespoň dvě možnosti.
+polls-no-permission-create = Nemáte oprávnění vytvářet ankety.
+polls-failed-create = Nepodařilo se vytvořit anketu: { $error }
+
+## Detail ankety
+polls-anonymous-notice = Tato anketa je anonymní. Nikdo nevidí, kdo jak odpověděl.
+polls-your-response = Vaše odpověď
+polls-submit-response = Odeslat odpověď
+polls-response-submitted = Děkujeme, vaše odpověď byla zaznamenána.
+polls-already-responded = Na tuto anketu jste již odpověděli.
+polls-failed-respond = Nepodařilo se odeslat odpověď: { $error }
+polls-results = Výsledky
+polls-results-hidden = Výsledky zatím nejsou viditelné.
+polls-answered-count = Odpovědělo: { $count }
+polls-average-rating = Průměrné hodnocení: { $average }
+polls-export-csv = Exportovat CSV
+polls-failed-export = Nepodařilo se exportovat odpovědi: { $error }
+polls-close = Uzavřít anketu
+polls-close-confirm = Uzavřít anketu nyní? Obyvatelé už nebudou moci odpovídat.
+polls-closed-success = Anketa byla uzavřena.
+polls-failed-close = Nepodařilo se uzavřít anketu: { $error }
+
+# ============================================================
+# Schůze
+# ============================================================
+meetings-title = Schůze
+meetings-subtitle = Shromáždění vlastníků s programem, prezencí a zápisem
+meetings-new-meeting = Nová schůze
+meetings-loading = Načítání schůzí...
+meetings-none = Zatím nejsou naplánovány žádné schůze.
+meetings-not-found = Schůze nenalezena
+meetings-agenda-count = Body programu: { $count }
+meetings-attendee-count = Přítomní: { $count }
+meetings-failed-load = Nepodařilo se načíst schůze: { $error }
+meetings-failed-load-meeting = Nepodařilo se načíst schůzi: { $error }
+
+## Stavy schůzí
+meetings-status-scheduled = Naplánováno
+meetings-status-in-progress = Probíhá
+meetings-status-closed = Ukončeno
+
+## Nová schůze
+meetings-new-title = Naplánovat schůzi
+meetings-new-breadcrumb = Nová
+meetings-basic-info = Základní informace
+meetings-building = Budova
+meetings-select-building = Vyberte budovu
+meetings-meeting-title = Název
+meetings-description = Popis
+meetings-when-where = Datum a místo
+meetings-scheduled-at = Datum a čas
+meetings-location = Místo konání
+meetings-online-url = Odkaz na online připojení
+meetings-quorum-percent = Usnášeníschopnost (% podlahové plochy)
+meetings-agenda-help = Naplánované návrhy budovy v pořadí, v jakém je zaškrtnete.
+meetings-agenda-pick-building = Pro výběr bodů programu vyberte budovu.
+meetings-agenda-no-proposals = Budova nemá žádné naplánované návrhy.
+meetings-create = Naplánovat schůzi
+meetings-building-required = Vyberte prosím budovu.
+meetings-title-location-required = Zadejte prosím název a místo konání.
+meetings-no-permission-create = Nemáte oprávnění plánovat schůze.
+meetings-failed-create = Nepodařilo se naplánovat schůzi: { $error }
+
+## Detail schůze
+meetings-join-online = Připojit se online
+meetings-started-at = Konáno
+meetings-start = Zahájit schůzi
+meetings-started = Schůze zahájena.
+meetings-end = Ukončit schůzi
+meetings-end-confirm = Ukončit schůzi? Otevřené body programu budou uzavřeny a sečteny.
+meetings-ended = Schůze ukončena.
+meetings-delete = Smazat
+meetings-delete-confirm = Smazat tuto schůzi?
+meetings-failed-action = Akce se nezdařila: { $error }
+meetings-quorum = Usnášeníschopnost
+meetings-quorum-represented = Zastoupeno: { $percent } % (požadováno { $required } %)
+meetings-quorum-reached = Schůze je usnášeníschopná
+meetings-quorum-not-reached = Schůze není usnášeníschopná
+meetings-agenda = Program
+meetings-agenda-empty = Program je prázdný.
+meetings-open-item = Zahájit hlasování
+meetings-close-item = Ukončit hlasování
+meetings-close-item-confirm = Ukončit hlasování o tomto bodu a sečíst hlasy?
+meetings-item-opened = Hlasování zahájeno.
+meetings-item-closed = Hlasování ukončeno a sečteno.
+meetings-passed = Přijato
+meetings-rejected = Zamítnuto
+
+## Prezence
+meetings-attendance = Prezence
+meetings-no-attendees = Zatím není nikdo přihlášen.
+meetings-you-attend = Jste přihlášeni: { $mode }
+meetings-withdraw = Odhlásit
+meetings-attend-in-person = Zúčastním se osobně
+meetings-attend-online = Zúčastním se online
+meetings-attendance-registered = Účast zaznamenána.
+meetings-failed-register = Nepodařilo se zaznamenat účast: { $error }
+meetings-mode = Účast
+meetings-mode-in-person = Osobně
+meetings-mode-online = Online
+meetings-mode-proxy = V zastoupení
+meetings-represented-by = zastupuje { $name }
+meetings-register-attendee = Zaznamenat účast vlastníka
+meetings-owner = Vlastník
+meetings-select-user = Vyberte uživatele
+meetings-proxy-holder = Zmocněnec
+meetings-register = Zaznamenat
+meetings-register-user-required = Vyberte prosím vlastníka.
+meetings-register-holder-required = Vyberte prosím zmocněnce.
+
+## Zápis
+meetings-minutes = Zápis
+meetings-minutes-draft = Návrh vytvořený z výsledků; před zveřejněním jej můžete upravit
+meetings-publish-minutes = Zveřejnit zápis
+meetings-minutes-published = Zápis byl zveřejněn jako oznámení.
+meetings-minutes-announced = Zveřejněno obyvatelům jako oznámení.
+meetings-minutes-pending = Zápis bude zveřejněn po skončení schůze.
+
 # ============================================================
 # Měřidla
 # ============================================================
@@ -588,6 +1032,143 @@ meters-calibration-days-before = Dní před termínem
 meters-calibration-check = Zkontrolovat
 meters-calibration-no-meters = V tomto období nebyla nalezena žádná měřidla vyžadující kalibraci.
 
+## Zaslané odečty
+meters-submit-reading = Zaslat odečet
+meters-submission-hint = Vyfoťte číselník měřidla tak, aby byl vidět stav. Odečet se započítá po schválení správcem.
+meters-submission-photo-label = Fotografie číselníku měřidla
+meters-submission-photo-required = Fotografie číselníku měřidla je povinná
+meters-submission-send = Odeslat ke schválení
+meters-submission-success = Odečet byl odeslán ke schválení
+meters-submission-infected = Fotografie byla zamítnuta antivirovou kontrolou.
+meters-submission-failed = Odečet se nepodařilo odeslat: { $error }
+meters-submissions-title = Zaslané odečty
+meters-submission-submitted-by = Zaslal(a)
+meters-submission-status = Stav
+meters-submission-reason = Důvod: { $reason }
+meters-submission-view-photo = Fotografie
+meters-submission-pending = Čeká na schválení
+meters-submission-approved = Schváleno
+meters-submission-rejected = Zamítnuto
+meters-submissions-review-title = Zaslané odečty měřidel
+meters-submissions-empty = Žádné zaslané odečty.
+meters-submission-apartment = byt { $number }
+meters-submission-previous = Předchozí odečet
+meters-submission-no-previous = žádný
+meters-submission-consumption = Spotřeba
+meters-submission-approve = Schválit
+meters-submission-reject = Zamítnout
+meters-submission-reject-prompt = Důvod zamítnutí odečtu (uvidí jej obyvatel):
+meters-submission-approved-success = Odečet byl schválen a zaznamenán
+meters-submission-rejected-success = Odečet byl zamítnut
+meters-submission-review-failed = Posouzení se nezdařilo: { $error }
+
+## Kampaně odečtů
+meters-campaigns-title = Kampaně odečtů
+meters-campaigns-hint = Získejte do termínu odečet všech měřidel v budově. Obyvatelům s chybějícími odečty se posílají připomínky a měřidlům, která nikdo neodečetl, se při uzavření kampaně dopočítá odhad.
+meters-campaigns-empty = Zatím žádné kampaně odečtů.
+meters-campaign-new = Nová kampaň
+meters-campaign-building = Budova
+meters-campaign-select-building = Vyberte budovu
+meters-campaign-building-required = Vyberte prosím budovu
+meters-campaign-name = Název
+meters-campaign-name-placeholder = např. Roční odečet vody 2026
+meters-campaign-name-required = Název je povinný
+meters-campaign-start-date = Začátek
+meters-campaign-deadline = Termín
+meters-campaign-start = Zahájit kampaň
+meters-campaign-create-failed = Kampaň se nepodařilo zahájit: { $error }
+meters-campaign-window = Období
+meters-campaign-progress = Průběh
+meters-campaign-progress-count = { $done } z { $total } měřidel
+meters-campaign-open = Probíhá
+meters-campaign-closed = Uzavřena
+meters-campaign-total = Měřidla
+meters-campaign-meter-read = Odečteno
+meters-campaign-meter-submitted = Čeká na schválení
+meters-campaign-meter-estimated = Odhad
+meters-campaign-meter-missing = Chybí
+meters-campaign-apartment = Byt
+meters-campaign-last-reminded = Poslední připomínka obyvatelům { $date }
+meters-campaign-remind = Připomenout obyvatelům
+meters-campaign-reminded = Připomínka odeslána { $count } obyvatelům
+meters-campaign-close = Uzavřít kampaň
+meters-campaign-close-confirm = Uzavřít kampaň? Měřidlům bez odečtu se dopočítá odhad z jejich dosavadní spotřeby.
+meters-campaign-closed-success = Kampaň byla uzavřena a chybějící odečty byly odhadnuty
+meters-campaign-action-failed = Akce se nezdařila: { $error }
+meters-campaign-review-first = Před uzavřením kampaně je nutné posoudit zaslané odečty.
+
+## Import odečtů
+meters-import-title = Import odečtů
+meters-import-hint = Importujte CSV export od dodavatele. Řádky se přiřadí k měřidlům podle výrobního čísla; odečty, které už měřidlo ve stejný čas má, se přeskočí, takže stejný soubor lze importovat znovu.
+meters-import-file = Soubor CSV
+meters-import-file-required = Vyberte soubor CSV
+meters-import-read-failed = Soubor se nepodařilo načíst
+meters-import-delimiter = Oddělovač
+meters-import-has-header = První řádek je záhlaví
+meters-import-decimal-comma = Hodnoty používají desetinnou čárku (12,5)
+meters-import-columns = Sloupce
+meters-import-columns-hint = Zadejte název sloupce ze záhlaví, nebo jeho pořadí počítané od 1.
+meters-import-timestamp = Čas odečtu
+meters-import-unit-column = Sloupec jednotky
+meters-import-unit = Jednotka
+meters-import-unit-hint = Použije se, pokud soubor nemá sloupec jednotky
+meters-import-unit-required = Zadejte jednotku nebo sloupec jednotky
+meters-import-timestamp-format = Formát času
+meters-import-timestamp-format-hint = Ponechte prázdné pro časy ve formátu ISO, např. 2026-03-01 08:00:00
+meters-import-submit = Importovat
+meters-import-failed = Import se nezdařil: { $error }
+meters-import-summary = Načteno řádků: { $received }, importováno odečtů: { $inserted }, již existujících: { $duplicates }.
+meters-import-unknown-meters = Žádné aktivní měřidlo s těmito výrobními čísly:
+
+## Webhookové API klíče
+meters-api-keys-title = Webhookové API klíče
+meters-api-keys-hint = Klíče umožňují bránám a systémům dodavatelů posílat odečty měřidel. Klíč lze omezit na budovy nebo jednotlivá měřidla a nastavit mu platnost a limit požadavků; rotace vydá nový klíč a starý ještě nějakou dobu funguje.
+meters-api-keys-new = Nový klíč
+meters-api-keys-edit = Upravit klíč
+meters-api-keys-name = Název
+meters-api-keys-name-placeholder = např. LoRa brána, budova A
+meters-api-keys-name-required = Zadejte prosím název
+meters-api-keys-expires-on = Platí do
+meters-api-keys-expires-on-hint = Nechte prázdné pro klíč bez omezení platnosti
+meters-api-keys-rate-limit = Limit požadavků
+meters-api-keys-rate-limit-hint = Požadavků za minutu; nechte prázdné bez limitu
+meters-api-keys-rate-limit-invalid = Limit požadavků musí být kladné číslo
+meters-api-keys-scope = Rozsah
+meters-api-keys-scope-hint = Odečty jsou přijaty jen pro měřidla vybraných budov a vybraná měřidla. Když nic nevyberete, jsou povolena všechna měřidla.
+meters-api-keys-scope-all = Všechna měřidla
+meters-api-keys-buildings = Budovy
+meters-api-keys-meters = Měřidla
+meters-api-keys-save = Uložit
+meters-api-keys-save-failed = Klíč se nepodařilo uložit: { $error }
+meters-api-keys-updated = Klíč byl upraven
+meters-api-keys-issued = Nový klíč pro { $name }
+meters-api-keys-issued-hint = Klíč si nyní zkopírujte; znovu už zobrazen nebude.
+meters-api-keys-empty = Zatím žádné API klíče.
+meters-api-keys-prefix = Klíč
+meters-api-keys-legacy = Starý formát
+meters-api-keys-legacy-hint = Vytvořen před zavedením prefixů; po rotaci bude ověření rychlejší
+meters-api-keys-status = Stav
+meters-api-keys-active = Aktivní
+meters-api-keys-expires = Platí do { $date }
+meters-api-keys-expired = Propadlý
+meters-api-keys-revoked = Zrušený
+meters-api-keys-per-minute = { $count } / min
+meters-api-keys-unlimited = Bez limitu
+meters-api-keys-usage = Požadavky
+meters-api-keys-last-used = Naposledy použit: { $date }
+meters-api-keys-last-error = Poslední chyba
+meters-api-keys-rotated-from = Nahrazuje klíč #{ $id }
+meters-api-keys-rotate = Rotovat klíč
+meters-api-keys-rotate-hint = Pro { $name } bude vydán nový klíč se stejným rozsahem a limity. Současný klíč funguje ještě po dobu překryvu, aby bylo možné nový klíč nasadit.
+meters-api-keys-overlap-hours = Překryv (hodiny)
+meters-api-keys-overlap-hours-hint = 0 ukončí současný klíč hned, nejvýše 720
+meters-api-keys-overlap-invalid = Překryv musí být celý počet hodin
+meters-api-keys-rotate-failed = Klíč se nepodařilo rotovat: { $error }
+meters-api-keys-revoke = Zrušit klíč
+meters-api-keys-revoke-confirm = Zařízení s tímto klíčem už nebudou moci posílat odečty. Opravdu klíč zrušit?
+meters-api-keys-revoked-success = Klíč byl zrušen
+meters-api-keys-revoke-failed = Klíč se nepodařilo zrušit: { $error }
+
 # ============================================================
 # Nemovitosti
 # ============================================================
@@ -883,6 +1464,10 @@ nav-management = Správa
 nav-users = Uživatelé
 nav-announcements = Oznámení
 nav-admin-meters = Měřiče
+nav-admin-meter-submissions = Zaslané odečty
+nav-admin-meter-campaigns = Kampaně odečtů
+nav-admin-meter-import = Import odečtů
+nav-admin-meter-api-keys = Webhookové API klíče
 
 # Chybové upozornění
 error-prefix = Chyba:
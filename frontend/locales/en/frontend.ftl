@@ -22,6 +22,8 @@ nav-manage = Manage
 nav-login = Login
 nav-maintenance = Maintenance
 nav-voting = Voting
+nav-polls = Polls
+nav-meetings = Meetings
 nav-my-properties = My Properties
 nav-meters = Meters
 nav-navigation = Navigation
@@ -105,6 +107,10 @@ sidebar-user-management = User management
 sidebar-admin-announcements = Announcements
 sidebar-admin-properties = Properties
 sidebar-meters = Meters
+sidebar-meter-submissions = Submitted readings
+sidebar-meter-campaigns = Reading campaigns
+sidebar-meter-import = Import readings
+sidebar-meter-api-keys = API keys
 
 ## Pagination
 pagination-items-total = { $count } items total
@@ -153,6 +159,17 @@ home-hero-title = House Management
 home-hero-subtitle = Manage your property, maintenance, and community — all in one place.
 home-sign-in-button = Sign In to Get Started
 
+# ============================================================
+# Notifications
+# ============================================================
+notifications-title = Notifications
+notifications-empty = No notifications.
+notifications-mark-all-read = Mark all read
+notifications-proposal-tallied = Results of "{ $subject }" are available.
+notifications-meter-reading-approved = Your reading of meter { $subject } was approved.
+notifications-meter-reading-rejected = Your reading of meter { $subject } was rejected.
+notifications-reading-campaign-reminder = Please submit your meter readings for { $subject }.
+
 # ============================================================
 # Announcements
 # ============================================================
@@ -198,6 +215,98 @@ announcement-apartment-prefix = Apt:
 announcement-published-prefix = Published:
 announcement-expires-prefix = Expires:
 
+## Announcement Read Receipts
+announcement-requires-ack-label = Require acknowledgement
+announcement-ack-required = Please confirm that you have read this announcement.
+announcement-acknowledge-btn = I have read this
+announcement-acknowledged-prefix = Acknowledged:
+announcement-receipts-heading = Read receipts
+announcement-receipts-read = Read by { $count } of { $total }
+announcement-receipts-acknowledged = Acknowledged by { $count } of { $total }
+announcement-receipts-pending = Still pending:
+announcement-receipts-none-pending = Everyone in the audience is done.
+announcement-receipts-read-at = read
+announcement-receipts-unread = Unread
+
+## Announcement Analytics
+announcement-analytics-heading = Engagement
+announcement-analytics-audience = Audience
+announcement-analytics-read = Read
+announcement-analytics-acknowledged = Acknowledged
+announcement-analytics-comments = Comments
+announcement-analytics-comments-by = { $count } by { $people } people
+announcement-analytics-first-read = First read after
+announcement-analytics-median-read = Median time to read
+announcement-analytics-outside-audience = Readers outside the audience
+announcement-analytics-readers-over-time = New readers per day
+announcement-analytics-day-tooltip = { $date }: { $count } new, { $total } in total
+announcement-analytics-by-building = Reach by building
+announcement-analytics-by-role = Reach by role
+announcement-analytics-no-building = No apartment
+announcement-engagement-heading = Announcement engagement
+announcement-engagement-all-buildings = All buildings
+announcement-engagement-days = Last { $days } days
+announcement-engagement-announcements = Announcements
+announcement-engagement-median-first-read = Median time to first read
+announcement-engagement-comments-short = comments
+announcement-engagement-empty = No announcements were published in this period.
+
+## Announcement Revisions
+announcement-edited = edited
+announcement-history-show = History
+announcement-history-hide = Hide history
+announcement-history-heading = Revision history
+announcement-history-revision = Revision { $number }
+announcement-history-by = by { $name }
+announcement-history-restored-from = restored from revision { $number }
+announcement-history-compare-from = From
+announcement-history-compare-to = To
+announcement-history-compare-btn = Compare
+announcement-history-title-changed = Title: { $from } → { $to }
+announcement-history-no-changes = The bodies are identical.
+announcement-history-restore-btn = Restore
+announcement-history-restore-confirm = Restore this revision? The current text will stay in the history.
+
+## Announcement Attachments
+announcement-attachments-label = Attachments:
+announcement-attachments-empty = No files yet. Uploaded images can be embedded in the text.
+announcement-attachment-upload = Upload file
+announcement-attachment-insert = Insert into text
+announcement-attachment-delete-confirm = Delete this attachment? References to it in the text will stop working.
+announcement-attachment-upload-failed = Failed to upload file: { $error }
+announcement-attachment-infected = The file was rejected because it appears to contain malware
+
+## Announcement Templates
+announcement-languages-label = Languages
+announcement-main-language = Main language:
+announcement-translations-empty = No translations. Readers in other languages see the main text.
+announcement-templates-label = Templates
+announcement-template-select = Choose a template…
+announcement-template-save = Save as template
+announcement-template-name-prompt = Template name:
+announcement-template-saved = Template saved
+announcement-template-save-failed = Failed to save template
+announcement-template-apply = Use template
+announcement-template-apply-failed = Failed to apply template
+announcement-template-missing = Left unfilled: { $names }
+announcement-template-delete-confirm = Delete this template?
+announcement-template-builtin-hint = {"{{"}building_address{"}}"}, {"{{"}today{"}}"}, {"{{"}publish_date{"}}"} and {"{{"}expire_date{"}}"} are filled in from the building and dates above.
+
+## Feeds
+feeds-heading = Feeds and calendar
+feeds-description = Follow announcements and voting windows in a feed reader, and add the votes to your calendar. The links work without signing in.
+feeds-create = Create links
+feeds-regenerate = New links
+feeds-revoke = Revoke
+feeds-regenerate-confirm = Create new links? The current ones will stop working.
+feeds-revoke-confirm = Revoke the feed links? Subscriptions using them will stop updating.
+feeds-create-failed = Failed to create feed links
+feeds-atom-label = Atom
+feeds-calendar-label = Calendar
+feeds-private-warning = Keep these links private: anyone with them can read your announcements. They are shown only now.
+feeds-created-at = Links created
+feeds-last-used-at = last used
+
 ## Comments
 comments-heading = Comments
 comments-disabled = Comments disabled.
@@ -210,12 +319,37 @@ comment-delete-button = Del
 comment-restore-button = Restore
 comment-purge-button = Purge
 comment-show-deleted-toggle = Show deleted
+comment-reply-button = Reply
+comment-replying-to = Replying to { $name }
+comment-edit-button = Edit
+comment-save-button = Save
+comment-cancel-button = Cancel
+comment-edited-marker = (edited)
+comment-react-button = Add reaction
+comment-mention-hint = Type @ to mention a neighbour.
+comment-report-button = Report
+comment-report-prompt = Why are you reporting this comment?
+comment-report-sent = Thanks, a moderator will review the comment.
+comment-hide-button = Hide
+comment-hide-prompt = Reason shown in place of the comment:
+comment-unhide-button = Unhide
+comment-hidden-notice = Hidden by a moderator: { $reason }
+
+## Comment Moderation
+comment-moderation-heading = Reported comments ({ $count })
+comment-moderation-report-count = Reports: { $count }
+comment-moderation-dismiss = Dismiss reports
 
 ## Comment Errors
 comment-delete-failed = Delete failed
 comment-restore-failed = Restore failed
 comment-purge-failed = Purge failed
 comment-post-failed = Failed to post
+comment-edit-failed = Saving the comment failed
+comment-react-failed = Reaction failed
+comment-report-failed = Reporting failed
+comment-hide-failed = Moderation failed
+comment-moderation-dismiss-failed = Dismissing the reports failed
 
 # ============================================================
 # Maintenance
@@ -256,6 +390,7 @@ maintenance-detail-created-by = Created by:
 maintenance-detail-assigned-to = Assigned to:
 maintenance-detail-created = Created:
 maintenance-detail-unassigned = Unassigned
+maintenance-detail-vendor = Vendor:
 
 ## Maintenance Types
 maintenance-type-general = General
@@ -271,6 +406,7 @@ maintenance-status-all = All
 maintenance-status-open = Open
 maintenance-status-in-progress = In Progress
 maintenance-status-resolved = Resolved
+maintenance-status-closed = Closed
 
 ## Maintenance Priorities
 maintenance-priority-low = Low
@@ -292,6 +428,22 @@ maintenance-priority-updated = Priority updated successfully
 maintenance-assigned-success = Request assigned successfully
 maintenance-no-permission-update = You don't have permission to update requests
 maintenance-no-permission-assign = You don't have permission to assign requests
+maintenance-vendor = Vendor
+maintenance-vendor-placeholder = Contractor doing the work
+maintenance-vendor-btn = Set Vendor
+maintenance-vendor-updated = Vendor updated successfully
+
+## Maintenance Feedback
+maintenance-feedback = Your Feedback
+maintenance-feedback-prompt = This request was marked as resolved. How satisfied are you, and is the issue actually fixed?
+maintenance-feedback-comment-placeholder = Optional comment
+maintenance-feedback-confirm-fixed = Yes, it's fixed
+maintenance-feedback-report-not-fixed = Not fixed
+maintenance-feedback-fixed = Fixed
+maintenance-feedback-not-fixed = Not fixed
+maintenance-feedback-rating-required = Please choose a rating first
+maintenance-feedback-thanks = Thank you for your feedback
+maintenance-feedback-reopened = The request has been reopened
 
 ## Maintenance History
 maintenance-history = History
@@ -334,9 +486,12 @@ maintenance-failed-load = Failed to load request: {$error}
 maintenance-failed-post-comment = Failed to post comment: {$error}
 maintenance-failed-delete-comment = Failed to delete comment: {$error}
 maintenance-failed-upload = Failed to upload file: {$error}
+maintenance-upload-infected = The file was rejected because it appears to contain malware
 maintenance-failed-update-status = Failed to update status: {$error}
 maintenance-failed-update-priority = Failed to update priority: {$error}
 maintenance-failed-assign = Failed to assign request: {$error}
+maintenance-failed-update-vendor = Failed to update vendor: {$error}
+maintenance-failed-feedback = Failed to submit feedback: {$error}
 maintenance-failed-escalate = Failed to escalate: {$error}
 maintenance-cancel = Cancel
 maintenance-posting = Posting...
@@ -475,6 +630,295 @@ voting-not-available = Voting is not available.
 voting-proposal-not-found = Proposal not found
 voting-management = Management
 
+## Voting Ballots
+voting-ballot-type = Ballot type
+voting-ballot-yes-no-desc = Yes / No - approve or reject the proposal
+voting-ballot-single-desc = Single choice - pick one option
+voting-ballot-approval-desc = Approval - select every acceptable option
+voting-ballot-ranked-desc = Ranked choice - order options by preference (instant runoff)
+voting-ballot-single = Single choice
+voting-ballot-approval = Approval
+voting-ballot-ranked = Ranked choice
+voting-options = Options
+voting-options-placeholder = One option per line
+voting-options-required = Enter at least two options.
+voting-max-selections = Maximum selections
+voting-max-selections-help = Leave empty to allow any number of options.
+voting-ranked-help = Add options in order of preference. Your ballot moves to your next choice if your favourite is eliminated.
+voting-approval-max = Select up to { $count } options.
+voting-submit-ballot = Submit ballot
+voting-ballot-cast = Your ballot has been recorded.
+voting-option-counts = Ballots per option
+voting-first-preferences = first preferences
+voting-ballots = ballots
+voting-rounds = Tally rounds
+voting-option = Option
+voting-round-number = Round { $number }
+voting-eliminated = Eliminated in this round
+voting-winning-option = Winning option: { $option }
+
+## Voting Proxies
+voting-proxy = Proxy
+voting-proxies-title = Proxies
+voting-proxy-votes = Votes you hold by proxy
+voting-proxy-votes-help = Owners can still vote in person until voting closes, which replaces your vote.
+voting-proxy-voted-in-person = Voted in person
+voting-proxy-voted-for-you = Your vote was cast by your proxy holder. Voting again replaces it.
+voting-proxy-vote-cast = The proxy vote has been recorded.
+voting-proxy-grant-help = Let someone else vote for you on this proposal.
+voting-proxy-select-holder = Select proxy holder
+voting-proxy-select-building = Select building
+voting-proxy-grant = Grant proxy
+voting-proxy-granted = Proxy granted to { $name }.
+voting-proxy-held-by = Your proxy is held by { $name }.
+voting-proxy-revoke = Revoke
+voting-proxy-revoked = Proxy revoked.
+voting-proxy-confirm-revoke = Revoke this proxy?
+voting-proxy-failed-grant = Failed to grant proxy: { $error }
+voting-proxy-failed-revoke = Failed to revoke proxy: { $error }
+voting-proxy-failed-load = Failed to load proxies: { $error }
+voting-proxy-fill-fields = Select a building and a proxy holder.
+voting-proxy-not-owner = You can only grant building-wide proxies for buildings where you own an apartment.
+voting-proxies-granted = Proxies you granted
+voting-proxies-received = Proxies you hold
+voting-proxies-none = No proxies.
+voting-proxies-all = All proxies
+voting-proxies-include-revoked = Include revoked
+voting-proxy-building-grant = Building-wide proxy
+voting-proxy-building-grant-help = Covers every proposal in the building that starts within the date range.
+voting-proxy-building = Building
+voting-proxy-holder = Proxy holder
+voting-proxy-grantor = Owner
+voting-proxy-scope = Scope
+voting-proxy-created = Granted
+voting-proxy-valid-from = Valid from
+voting-proxy-valid-until = Valid until
+voting-proxy-scope-building = All proposals starting { $from } – { $until }
+voting-proxy-revoked-at = Revoked { $date }
+
+## Secret Ballots
+voting-secret = Secret
+voting-secret-ballot = Secret ballot
+voting-secret-ballot-help = Ballots are stored without the voter. Results stay hidden until the tally and votes cannot be changed or cast by proxy.
+voting-secret-ballot-weighted = Secret ballots are not available for weighted voting: a voter's weight would identify their ballot.
+voting-secret-results-hidden = This is a secret ballot. Results are published after the tally.
+voting-secret-ballot-cast = You have cast your secret ballot.
+voting-secret-receipt = Your ballot was recorded. Save the receipt code below now, it will not be shown again. After the tally you can use it to check that your ballot was counted.
+voting-secret-final = Secret ballots cannot be changed once cast.
+voting-receipt-check = Check your receipt
+voting-receipt-published = { $count } ballots were counted. Enter your receipt code to find yours.
+voting-receipt-verify = Verify
+voting-receipt-counted = Your ballot was counted:
+voting-receipt-not-found = No ballot with this receipt code was found.
+
+## Discussion
+voting-discussion-title = Discussion
+voting-discussion-empty = No messages yet.
+voting-discussion-your-message = Your message
+voting-discussion-post = Post
+voting-discussion-closed = Voting has ended, the discussion is closed.
+voting-discussion-delete-confirm = Delete this message?
+voting-discussion-failed-post = Failed to post message: { $error }
+
+## Supporting Documents
+voting-documents-title = Documents
+voting-documents-empty = No documents attached.
+voting-documents-upload = Upload
+voting-documents-infected = The file was rejected because it contains malware.
+voting-documents-upload-failed = Upload failed: { $error }
+voting-documents-download-failed = Download failed: { $error }
+voting-documents-delete-confirm = Delete this document?
+
+## Audit Report
+voting-audit-title = Audit report
+voting-audit-desc = Eligible voters with weights, participation, totals, rule version and the results hash for tamper evidence.
+voting-audit-pdf = PDF
+voting-audit-csv = CSV
+voting-audit-failed = Failed to download the audit report: { $error }
+
+## Apartment Seats
+voting-seats-title = Your apartments
+voting-seat-label = Apt. { $number }, { $address }
+voting-seat-not-voted = Not voted
+voting-seat-voted = Voted
+voting-seat-voted-by = Voted by { $name }
+voting-seat-history = History
+voting-seat-history-by-proxy = (by proxy { $name })
+
+# ============================================================
+# Polls
+# ============================================================
+polls-title = Polls
+polls-subtitle = Quick surveys for residents, separate from formal voting
+polls-new-poll = New Poll
+polls-loading = Loading polls...
+polls-none = No polls yet.
+polls-not-found = Poll not found
+polls-responded = Responded
+polls-can-respond = Open for you
+polls-anonymous = Anonymous
+polls-question-count = Questions: { $count }
+polls-response-count = Responses: { $count }
+polls-failed-load = Failed to load polls: { $error }
+polls-failed-load-poll = Failed to load poll: { $error }
+
+## Poll Statuses
+polls-status-scheduled = Scheduled
+polls-status-open = Open
+polls-status-closed = Closed
+
+## Poll Results Visibility
+polls-results-visibility = Results visible
+polls-visibility-always = Always
+polls-visibility-after-response = After responding
+polls-visibility-after-close = After the poll closes
+polls-visibility-managers = Managers only
+
+## Poll New
+polls-new-title = Create Poll
+polls-new-breadcrumb = New
+polls-basic-info = Basic information
+polls-poll-title = Title
+polls-poll-description = Description
+polls-building-scope = Building
+polls-global-scope = All buildings
+polls-settings = Settings
+polls-start-time = Start time
+polls-end-time = End time
+polls-anonymous-help = Anonymous — responses are not linked to residents
+polls-questions = Questions
+polls-question-number = Question { $number }
+polls-question-prompt = Question
+polls-question-kind = Type
+polls-question-options = Options (one per line)
+polls-question-rating-max = Highest rating
+polls-question-required = Answer required
+polls-kind-single = Single choice
+polls-kind-multiple = Multiple choice
+polls-kind-text = Free text
+polls-kind-rating = Rating
+polls-add-question = Add question
+polls-create = Create Poll
+polls-title-required = Please enter a title.
+polls-question-prompt-required = Every question needs a prompt.
+polls-question-options-required = Choice questions need at least two options.
+polls-no-permission-create = You do not have permission to create polls.
+polls-failed-create = Failed to create poll: { $error }
+
+## Poll Detail
+polls-anonymous-notice = This poll is anonymous. Nobody can see who gave which answer.
+polls-your-response = Your response
+polls-submit-response = Submit response
+polls-response-submitted = Thank you, your response was recorded.
+polls-already-responded = You have already responded to this poll.
+polls-failed-respond = Failed to submit response: { $error }
+polls-results = Results
+polls-results-hidden = Results are not visible yet.
+polls-answered-count = Answered: { $count }
+polls-average-rating = Average rating: { $average }
+polls-export-csv = Export CSV
+polls-failed-export = Failed to export responses: { $error }
+polls-close = Close poll
+polls-close-confirm = Close this poll now? Residents will no longer be able to respond.
+polls-closed-success = Poll closed.
+polls-failed-close = Failed to close poll: { $error }
+
+# ============================================================
+# Meetings
+# ============================================================
+meetings-title = Meetings
+meetings-subtitle = General assemblies of owners with agenda, attendance and minutes
+meetings-new-meeting = New Meeting
+meetings-loading = Loading meetings...
+meetings-none = No meetings scheduled yet.
+meetings-not-found = Meeting not found
+meetings-agenda-count = Agenda items: { $count }
+meetings-attendee-count = Attendees: { $count }
+meetings-failed-load = Failed to load meetings: { $error }
+meetings-failed-load-meeting = Failed to load meeting: { $error }
+
+## Meeting Statuses
+meetings-status-scheduled = Scheduled
+meetings-status-in-progress = In progress
+meetings-status-closed = Closed
+
+## Meeting New
+meetings-new-title = Schedule Meeting
+meetings-new-breadcrumb = New
+meetings-basic-info = Basic information
+meetings-building = Building
+meetings-select-building = Select a building
+meetings-meeting-title = Title
+meetings-description = Description
+meetings-when-where = Date and place
+meetings-scheduled-at = Date and time
+meetings-location = Location
+meetings-online-url = Online meeting link
+meetings-quorum-percent = Quorum (% of floor area)
+meetings-agenda-help = Scheduled proposals of the building, in the order they are ticked.
+meetings-agenda-pick-building = Select a building to choose agenda items.
+meetings-agenda-no-proposals = The building has no scheduled proposals.
+meetings-create = Schedule Meeting
+meetings-building-required = Please select a building.
+meetings-title-location-required = Please enter a title and a location.
+meetings-no-permission-create = You do not have permission to schedule meetings.
+meetings-failed-create = Failed to schedule meeting: { $error }
+
+## Meeting Detail
+meetings-join-online = Join online
+meetings-started-at = Held
+meetings-start = Start meeting
+meetings-started = Meeting started.
+meetings-end = End meeting
+meetings-end-confirm = End the meeting? Open agenda items will be closed and counted.
+meetings-ended = Meeting ended.
+meetings-delete = Delete
+meetings-delete-confirm = Delete this meeting?
+meetings-failed-action = Action failed: { $error }
+meetings-quorum = Quorum
+meetings-quorum-represented = Represented: { $percent } % (required { $required } %)
+meetings-quorum-reached = Quorum reached
+meetings-quorum-not-reached = Quorum not reached
+meetings-agenda = Agenda
+meetings-agenda-empty = The agenda is empty.
+meetings-open-item = Open voting
+meetings-close-item = Close voting
+meetings-close-item-confirm = Close voting on this item and count the votes?
+meetings-item-opened = Voting opened.
+meetings-item-closed = Voting closed and counted.
+meetings-passed = Passed
+meetings-rejected = Rejected
+
+## Meeting Attendance
+meetings-attendance = Attendance
+meetings-no-attendees = Nobody is registered yet.
+meetings-you-attend = You are registered: { $mode }
+meetings-withdraw = Withdraw
+meetings-attend-in-person = I will attend in person
+meetings-attend-online = I will attend online
+meetings-attendance-registered = Attendance registered.
+meetings-failed-register = Failed to register attendance: { $error }
+meetings-mode = Attendance
+meetings-mode-in-person = In person
+meetings-mode-online = Online
+meetings-mode-proxy = By proxy
+meetings-represented-by = represented by { $name }
+meetings-register-attendee = Register an owner
+meetings-owner = Owner
+meetings-select-user = Select a user
+meetings-proxy-holder = Proxy holder
+meetings-register = Register
+meetings-register-user-required = Please select an owner.
+meetings-register-holder-required = Please select the proxy holder.
+
+## Meeting Minutes
+meetings-minutes = Minutes
+meetings-minutes-draft = Draft generated from the results; edit before publishing
+meetings-publish-minutes = Publish minutes
+meetings-minutes-published = Minutes published as an announcement.
+meetings-minutes-announced = Published to residents as an announcement.
+meetings-minutes-pending = Minutes will be published after the meeting.
+
 # ============================================================
 # Meters
 # ============================================================
@@ -588,6 +1032,143 @@ meters-calibration-days-before = Days before due date
 meters-calibration-check = Check
 meters-calibration-no-meters = No meters found requiring calibration in this period.
 
+## Meter Reading Submissions
+meters-submit-reading = Submit Reading
+meters-submission-hint = Take a photo of the meter dial showing the value. The reading counts once a manager approves it.
+meters-submission-photo-label = Photo of the meter dial
+meters-submission-photo-required = A photo of the meter dial is required
+meters-submission-send = Send for Approval
+meters-submission-success = Reading sent for approval
+meters-submission-infected = The photo was rejected by the malware scan.
+meters-submission-failed = Failed to submit reading: { $error }
+meters-submissions-title = Submitted Readings
+meters-submission-submitted-by = Submitted by
+meters-submission-status = Status
+meters-submission-reason = Reason: { $reason }
+meters-submission-view-photo = Photo
+meters-submission-pending = Pending
+meters-submission-approved = Approved
+meters-submission-rejected = Rejected
+meters-submissions-review-title = Submitted Meter Readings
+meters-submissions-empty = No submitted readings.
+meters-submission-apartment = apt. { $number }
+meters-submission-previous = Previous reading
+meters-submission-no-previous = none
+meters-submission-consumption = Consumption
+meters-submission-approve = Approve
+meters-submission-reject = Reject
+meters-submission-reject-prompt = Reason for rejecting the reading (shown to the resident):
+meters-submission-approved-success = Reading approved and recorded
+meters-submission-rejected-success = Reading rejected
+meters-submission-review-failed = Review failed: { $error }
+
+## Reading Campaigns
+meters-campaigns-title = Reading campaigns
+meters-campaigns-hint = Collect a reading of every meter in a building by a deadline. Residents with missing readings are reminded, and meters nobody read get an estimated reading when the campaign is closed.
+meters-campaigns-empty = No reading campaigns yet.
+meters-campaign-new = New campaign
+meters-campaign-building = Building
+meters-campaign-select-building = Select a building
+meters-campaign-building-required = Please select a building
+meters-campaign-name = Name
+meters-campaign-name-placeholder = e.g. Annual water reading 2026
+meters-campaign-name-required = Name is required
+meters-campaign-start-date = Start date
+meters-campaign-deadline = Deadline
+meters-campaign-start = Start campaign
+meters-campaign-create-failed = Failed to start the campaign: { $error }
+meters-campaign-window = Period
+meters-campaign-progress = Progress
+meters-campaign-progress-count = { $done } of { $total } meters
+meters-campaign-open = Open
+meters-campaign-closed = Closed
+meters-campaign-total = Meters
+meters-campaign-meter-read = Read
+meters-campaign-meter-submitted = Awaiting review
+meters-campaign-meter-estimated = Estimated
+meters-campaign-meter-missing = Missing
+meters-campaign-apartment = Apartment
+meters-campaign-last-reminded = Residents last reminded { $date }
+meters-campaign-remind = Remind residents
+meters-campaign-reminded = Reminder sent to { $count } residents
+meters-campaign-close = Close campaign
+meters-campaign-close-confirm = Close the campaign? Meters without a reading will get an estimated reading from their past consumption.
+meters-campaign-closed-success = The campaign was closed and missing readings were estimated
+meters-campaign-action-failed = Action failed: { $error }
+meters-campaign-review-first = Submitted readings must be reviewed before the campaign can be closed.
+
+## Reading Import
+meters-import-title = Import readings
+meters-import-hint = Import a vendor's CSV export. Rows are matched to meters by serial number; readings a meter already has at the same time are skipped, so the same file can be imported again.
+meters-import-file = CSV file
+meters-import-file-required = Please choose a CSV file
+meters-import-read-failed = The file could not be read
+meters-import-delimiter = Delimiter
+meters-import-has-header = First line is a header
+meters-import-decimal-comma = Values use a decimal comma (12,5)
+meters-import-columns = Columns
+meters-import-columns-hint = Enter the header name of each column, or its position counted from 1.
+meters-import-timestamp = Timestamp
+meters-import-unit-column = Unit column
+meters-import-unit = Unit
+meters-import-unit-hint = Used when there is no unit column
+meters-import-unit-required = Enter a unit or a unit column
+meters-import-timestamp-format = Timestamp format
+meters-import-timestamp-format-hint = Leave empty for ISO timestamps such as 2026-03-01 08:00:00
+meters-import-submit = Import
+meters-import-failed = Import failed: { $error }
+meters-import-summary = { $received } rows read, { $inserted } readings imported, { $duplicates } already present.
+meters-import-unknown-meters = No active meter with these serial numbers:
+
+## Webhook API Keys
+meters-api-keys-title = Webhook API keys
+meters-api-keys-hint = Keys let gateways and vendor systems send meter readings. A key can be limited to buildings or single meters, given an expiry and a rate limit; rotating it issues a new key while the old one keeps working for a while.
+meters-api-keys-new = New key
+meters-api-keys-edit = Edit key
+meters-api-keys-name = Name
+meters-api-keys-name-placeholder = e.g. LoRa gateway, building A
+meters-api-keys-name-required = Please enter a name
+meters-api-keys-expires-on = Expires on
+meters-api-keys-expires-on-hint = Leave empty for a key that does not expire
+meters-api-keys-rate-limit = Rate limit
+meters-api-keys-rate-limit-hint = Requests per minute; leave empty for no limit
+meters-api-keys-rate-limit-invalid = The rate limit must be a positive number
+meters-api-keys-scope = Scope
+meters-api-keys-scope-hint = Readings are accepted only for meters of the selected buildings and the selected meters. Select nothing to allow all meters.
+meters-api-keys-scope-all = All meters
+meters-api-keys-buildings = Buildings
+meters-api-keys-meters = Meters
+meters-api-keys-save = Save
+meters-api-keys-save-failed = Failed to save the key: { $error }
+meters-api-keys-updated = Key updated
+meters-api-keys-issued = New key for { $name }
+meters-api-keys-issued-hint = Copy the key now; it is not shown again.
+meters-api-keys-empty = No API keys yet.
+meters-api-keys-prefix = Key
+meters-api-keys-legacy = Legacy
+meters-api-keys-legacy-hint = Created before key prefixes; rotate it to speed up authentication
+meters-api-keys-status = Status
+meters-api-keys-active = Active
+meters-api-keys-expires = Expires { $date }
+meters-api-keys-expired = Expired
+meters-api-keys-revoked = Revoked
+meters-api-keys-per-minute = { $count } / min
+meters-api-keys-unlimited = Unlimited
+meters-api-keys-usage = Requests
+meters-api-keys-last-used = Last used: { $date }
+meters-api-keys-last-error = Last error
+meters-api-keys-rotated-from = Replaces key #{ $id }
+meters-api-keys-rotate = Rotate key
+meters-api-keys-rotate-hint = A new key is issued for { $name } with the same scope and limits. The current key keeps working for the overlap, so the new one can be rolled out.
+meters-api-keys-overlap-hours = Overlap (hours)
+meters-api-keys-overlap-hours-hint = 0 to stop the current key right away, at most 720
+meters-api-keys-overlap-invalid = The overlap must be a whole number of hours
+meters-api-keys-rotate-failed = Failed to rotate the key: { $error }
+meters-api-keys-revoke = Revoke key
+meters-api-keys-revoke-confirm = Devices using this key will no longer be able to send readings. Revoke it?
+meters-api-keys-revoked-success = Key revoked
+meters-api-keys-revoke-failed = Failed to revoke the key: { $error }
+
 # ============================================================
 # Properties
 # ============================================================
@@ -883,6 +1464,10 @@ nav-management = Management
 nav-users = Users
 nav-announcements = Announcements
 nav-admin-meters = Meters
+nav-admin-meter-submissions = Submitted readings
+nav-admin-meter-campaigns = Reading campaigns
+nav-admin-meter-import = Import readings
+nav-admin-meter-api-keys = Webhook API keys
 
 # Error Alert
 error-prefix = Error:
@@ -17,12 +17,16 @@ use frontend::pages::login::LoginPage;
 use frontend::pages::maintenance::{
     MaintenanceDetailPage, MaintenanceListPage, MaintenanceNewPage,
 };
+use frontend::pages::meetings::{MeetingDetailPage, MeetingListPage, MeetingNewPage};
 use frontend::pages::meters::{
-    MeterCalibrationPage, MeterDetailPage, MeterListPage, MeterManagementPage, MeterNewPage,
+    MeterApiKeysPage, MeterCalibrationPage, MeterCampaignDetailPage, MeterCampaignsPage,
+    MeterDetailPage, MeterImportPage, MeterListPage, MeterManagementPage, MeterNewPage,
+    MeterSubmissionsPage,
 };
 use frontend::pages::my_properties::MyProperties;
 use frontend::pages::my_property_detail::MyPropertyDetailPage;
-use frontend::pages::voting::{VotingDetailPage, VotingListPage, VotingNewPage};
+use frontend::pages::polls::{PollDetailPage, PollListPage, PollNewPage};
+use frontend::pages::voting::{VotingDetailPage, VotingListPage, VotingNewPage, VotingProxiesPage};
 use frontend::routes::Route;
 
 #[function_component(AppContent)]
@@ -71,12 +75,24 @@ fn app_content() -> Html {
                             Route::MaintenanceDetail { id } => html!{<MaintenanceDetailPage id={id} />},
                             Route::Voting => html!{<VotingListPage />},
                             Route::VotingNew => html!{<VotingNewPage />},
+                            Route::VotingProxies => html!{<VotingProxiesPage />},
                             Route::VotingDetail { id } => html!{<VotingDetailPage id={id} />},
+                            Route::Polls => html!{<PollListPage />},
+                            Route::PollNew => html!{<PollNewPage />},
+                            Route::PollDetail { id } => html!{<PollDetailPage id={id} />},
+                            Route::Meetings => html!{<MeetingListPage />},
+                            Route::MeetingNew => html!{<MeetingNewPage />},
+                            Route::MeetingDetail { id } => html!{<MeetingDetailPage id={id} />},
                             Route::ApartmentMeters { apartment_id } => html!{<MeterListPage apartment_id={apartment_id} />},
                             Route::MeterDetail { id } => html!{<MeterDetailPage id={id} />},
                             Route::MeterManagement => html!{<MeterManagementPage />},
                             Route::MeterNew => html!{<MeterNewPage />},
                             Route::MeterCalibration => html!{<MeterCalibrationPage />},
+                            Route::MeterSubmissions => html!{<MeterSubmissionsPage />},
+                            Route::MeterCampaigns => html!{<MeterCampaignsPage />},
+                            Route::MeterCampaignDetail { id } => html!{<MeterCampaignDetailPage id={id} />},
+                            Route::MeterImport => html!{<MeterImportPage />},
+                            Route::MeterApiKeys => html!{<MeterApiKeysPage />},
                             Route::MyProperties => html!{<MyProperties />},
                             Route::MyPropertyDetail { apartment_id } => html!{<MyPropertyDetailPage apartment_id={apartment_id} />},
                             _ => html!{<div>{t("page-not-found")}</div>},
@@ -43,6 +43,12 @@ pub fn admin_sidebar(props: &AdminSidebarProps) -> Html {
                     <Link<Route> to={Route::AdminAnnouncements} classes={nav_link_classes(&Route::AdminAnnouncements)}>{ t("sidebar-admin-announcements") }</Link<Route>>
                     <Link<Route> to={Route::AdminProperties} classes={nav_link_classes(&Route::AdminProperties)}>{ t("sidebar-admin-properties") }</Link<Route>>
                     <Link<Route> to={Route::MeterManagement} classes={nav_link_classes(&Route::MeterManagement)}>{ t("sidebar-meters") }</Link<Route>>
+                    <Link<Route> to={Route::MeterSubmissions} classes={nav_link_classes(&Route::MeterSubmissions)}>{ t("sidebar-meter-submissions") }</Link<Route>>
+                    <Link<Route> to={Route::MeterCampaigns} classes={nav_link_classes(&Route::MeterCampaigns)}>{ t("sidebar-meter-campaigns") }</Link<Route>>
+                }
+                if is_admin {
+                    <Link<Route> to={Route::MeterImport} classes={nav_link_classes(&Route::MeterImport)}>{ t("sidebar-meter-import") }</Link<Route>>
+                    <Link<Route> to={Route::MeterApiKeys} classes={nav_link_classes(&Route::MeterApiKeys)}>{ t("sidebar-meter-api-keys") }</Link<Route>>
                 }
             </div>
         </div>
@@ -1,6 +1,11 @@
 use crate::components::announcement_editor_form::AnnouncementEditorForm;
+use crate::components::announcement_list::{AttachmentDto, AudienceApartment, AudienceBuilding};
+use crate::components::announcements::{
+    AnnouncementAttachments, AnnouncementTranslations, AnnouncementVersions, LocalizedText,
+    TemplateDraft, TemplatePicker,
+};
 use crate::contexts::AuthContext;
-use crate::i18n::t;
+use crate::i18n::{current_language, t};
 use crate::services::api::{PaginatedResponse, api_client};
 use serde::{Deserialize, Serialize};
 use yew::prelude::*;
@@ -13,12 +18,19 @@ pub struct AnnouncementFull {
     pub body_html: String,
     pub pinned: bool,
     pub public: bool,
-    pub roles_csv: Option<String>,
-    pub building_id: Option<u64>,
-    pub apartment_id: Option<u64>,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub buildings: Vec<AudienceBuilding>,
+    #[serde(default)]
+    pub apartments: Vec<AudienceApartment>,
     pub comments_enabled: bool,
+    #[serde(default)]
+    pub requires_ack: bool,
     pub publish_at: Option<String>,
     pub expire_at: Option<String>,
+    #[serde(default)]
+    pub attachments: Vec<AttachmentDto>,
 }
 
 #[derive(Serialize)]
@@ -27,12 +39,15 @@ struct CreatePayload {
     body_md: String,
     public: bool,
     pinned: bool,
-    roles_csv: Option<String>,
-    building_id: Option<u64>,
-    apartment_id: Option<u64>,
+    roles: Vec<String>,
+    building_ids: Vec<u64>,
+    apartment_ids: Vec<u64>,
     comments_enabled: bool,
+    requires_ack: bool,
     publish_at: Option<String>,
     expire_at: Option<String>,
+    language: String,
+    translations: Vec<LocalizedText>,
 }
 
 #[derive(Deserialize)]
@@ -74,15 +89,19 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
     let public_flag = use_state(|| true);
     let pinned_flag = use_state(|| false);
     let comments_enabled = use_state(|| false);
+    let requires_ack = use_state(|| false);
     let publish_at = use_state(String::default);
     let expire_at = use_state(String::default);
-    let selected_roles = use_state(|| Vec::<String>::new());
+    let selected_roles = use_state(Vec::<String>::new);
     let selected_building = use_state(|| None::<u64>);
     let selected_apartment = use_state(|| None::<u64>);
+    let attachments = use_state(Vec::<AttachmentDto>::new);
+    let language = use_state(current_language);
+    let translations = use_state(Vec::<LocalizedText>::new);
 
     // Data state
-    let buildings = use_state(|| Vec::<(u64, String)>::new());
-    let apartments = use_state(|| Vec::<(u64, u64, String)>::new());
+    let buildings = use_state(Vec::<(u64, String)>::new);
+    let apartments = use_state(Vec::<(u64, u64, String)>::new);
 
     // UI state
     let saving = use_state(|| false);
@@ -91,10 +110,17 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
     // Memoized preview HTML
     let preview_html = {
         let md = (*body_md).clone();
-        use_memo(md.clone(), move |current_md| {
+        use_memo((md, (*attachments).clone()), move |(current_md, files)| {
             if current_md.is_empty() {
                 return format!("<em class='text-muted'>{}</em>", t("preview-empty"));
             }
+            // Show attachment references the way the server resolves them
+            let mut current_md = current_md.clone();
+            for f in files {
+                current_md =
+                    current_md.replace(&format!("(attachment:{})", f.id), &format!("({})", f.url));
+            }
+            let current_md = &current_md;
             let mut buf = String::new();
             let parser =
                 pulldown_cmark::Parser::new_ext(current_md, pulldown_cmark::Options::all());
@@ -115,34 +141,54 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
         let public_state = public_flag.clone();
         let pinned_state = pinned_flag.clone();
         let comments_state = comments_enabled.clone();
+        let ack_state = requires_ack.clone();
         let publish_state = publish_at.clone();
         let expire_state = expire_at.clone();
         let selected_roles_state = selected_roles.clone();
         let selected_building_state = selected_building.clone();
         let selected_apartment_state = selected_apartment.clone();
+        let attachments_state = attachments.clone();
+        let language_state = language.clone();
+        let translations_state = translations.clone();
+        let token = token.clone();
 
         use_effect_with(props.existing.clone(), move |ex| {
             if let Some(a) = ex {
+                // The announcement itself comes localized; fetch every language version
+                let id = a.id;
+                let fetched_title = title_state.clone();
+                let fetched_body = body_state.clone();
+                let language_state = language_state.clone();
+                let translations_state = translations_state.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let client = api_client(token.as_deref());
+                    let endpoint = format!("/announcements/{}/translations", id);
+                    if let Ok(v) = client.get::<AnnouncementVersions>(&endpoint).await {
+                        fetched_title.set(v.title);
+                        fetched_body.set(v.body_md);
+                        language_state.set(v.language);
+                        translations_state.set(v.translations);
+                    }
+                });
+                attachments_state.set(a.attachments.clone());
                 title_state.set(a.title.clone());
                 body_state.set(a.body_md.clone());
                 public_state.set(a.public);
                 pinned_state.set(a.pinned);
                 comments_state.set(a.comments_enabled);
+                ack_state.set(a.requires_ack);
                 publish_state.set(a.publish_at.clone().unwrap_or_default().trim().to_string());
                 expire_state.set(a.expire_at.clone().unwrap_or_default().trim().to_string());
-                selected_roles_state.set(
-                    a.roles_csv
-                        .clone()
-                        .map(|csv| {
-                            csv.split(',')
-                                .map(|s| s.trim().to_string())
-                                .filter(|s| !s.is_empty())
-                                .collect()
-                        })
-                        .unwrap_or_default(),
+                selected_roles_state.set(a.roles.clone());
+                // The form targets a single building/apartment
+                let apartment = a.apartments.first();
+                selected_building_state.set(
+                    a.buildings
+                        .first()
+                        .map(|b| b.id)
+                        .or(apartment.map(|apt| apt.building_id)),
                 );
-                selected_building_state.set(a.building_id);
-                selected_apartment_state.set(a.apartment_id);
+                selected_apartment_state.set(apartment.map(|apt| apt.id));
             } else {
                 // Reset for new announcement
                 title_state.set(String::new());
@@ -150,11 +196,15 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
                 public_state.set(true);
                 pinned_state.set(false);
                 comments_state.set(false);
+                ack_state.set(false);
                 publish_state.set(String::new());
                 expire_state.set(String::new());
                 selected_roles_state.set(Vec::new());
                 selected_building_state.set(None);
                 selected_apartment_state.set(None);
+                attachments_state.set(Vec::new());
+                language_state.set(current_language());
+                translations_state.set(Vec::new());
             }
         });
     }
@@ -230,6 +280,11 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
         Callback::from(move |value: bool| comments_enabled.set(value))
     };
 
+    let on_requires_ack_change = {
+        let requires_ack = requires_ack.clone();
+        Callback::from(move |value: bool| requires_ack.set(value))
+    };
+
     let on_publish_at_change = {
         let publish_at = publish_at.clone();
         Callback::from(move |value: String| publish_at.set(value))
@@ -255,6 +310,29 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
         Callback::from(move |value: Option<u64>| selected_apartment.set(value))
     };
 
+    let on_language_change = {
+        let language = language.clone();
+        Callback::from(move |value: String| language.set(value))
+    };
+
+    let on_translations_change = {
+        let translations = translations.clone();
+        Callback::from(move |value: Vec<LocalizedText>| translations.set(value))
+    };
+
+    let on_template_apply = {
+        let title = title.clone();
+        let body_md = body_md.clone();
+        let language = language.clone();
+        let translations = translations.clone();
+        Callback::from(move |draft: TemplateDraft| {
+            title.set(draft.title);
+            body_md.set(draft.body_md);
+            language.set(draft.language);
+            translations.set(draft.translations);
+        })
+    };
+
     // Submit handler
     let on_submit = {
         let title = title.clone();
@@ -262,6 +340,7 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
         let public_flag = public_flag.clone();
         let pinned_flag = pinned_flag.clone();
         let comments_enabled = comments_enabled.clone();
+        let requires_ack = requires_ack.clone();
         let publish_at = publish_at.clone();
         let expire_at = expire_at.clone();
         let saving = saving.clone();
@@ -272,6 +351,8 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
         let selected_roles = selected_roles.clone();
         let selected_building = selected_building.clone();
         let selected_apartment = selected_apartment.clone();
+        let language = language.clone();
+        let translations = translations.clone();
         let token = token.clone();
 
         Callback::from(move |e: SubmitEvent| {
@@ -293,31 +374,32 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
             let public_val = *public_flag;
             let pinned_val = *pinned_flag;
             let comments_val = *comments_enabled;
+            let requires_ack_val = *requires_ack;
             let publish_at_val = (*publish_at).clone();
             let expire_at_val = (*expire_at).clone();
             let roles_val = (*selected_roles).clone();
             let building_val = *selected_building;
             let apartment_val = *selected_apartment;
+            let language_val = (*language).clone();
+            let translations_val = (*translations).clone();
 
             if let Some(ex) = &existing {
                 // Update existing
                 let id = ex.id;
-                let roles_string = if roles_val.is_empty() {
-                    None
-                } else {
-                    Some(roles_val.join(","))
-                };
                 let payload = serde_json::json!({
                     "title": title_val,
                     "body_md": body_md_val,
                     "public": public_val,
                     "pinned": pinned_val,
-                    "roles_csv": roles_string.map(serde_json::Value::String).unwrap_or(serde_json::Value::Null),
+                    "roles": roles_val,
                     "comments_enabled": comments_val,
+                    "requires_ack": requires_ack_val,
                     "publish_at": if publish_at_val.trim().is_empty() { serde_json::Value::Null } else { serde_json::Value::String(datetime_local_to_naive(&publish_at_val)) },
                     "expire_at": if expire_at_val.trim().is_empty() { serde_json::Value::Null } else { serde_json::Value::String(datetime_local_to_naive(&expire_at_val)) },
-                    "building_id": building_val.map(serde_json::Value::from).unwrap_or(serde_json::Value::Null),
-                    "apartment_id": apartment_val.map(serde_json::Value::from).unwrap_or(serde_json::Value::Null),
+                    "building_ids": building_val.into_iter().collect::<Vec<_>>(),
+                    "apartment_ids": apartment_val.into_iter().collect::<Vec<_>>(),
+                    "language": language_val,
+                    "translations": translations_val,
                 });
                 let saving2 = saving.clone();
                 let error2 = error.clone();
@@ -336,20 +418,16 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
                 });
             } else {
                 // Create new
-                let roles_csv_opt = if roles_val.is_empty() {
-                    None
-                } else {
-                    Some(roles_val.join(","))
-                };
                 let payload = CreatePayload {
                     title: title_val,
                     body_md: body_md_val,
                     public: public_val,
                     pinned: pinned_val,
-                    roles_csv: roles_csv_opt,
-                    building_id: building_val,
-                    apartment_id: apartment_val,
+                    roles: roles_val,
+                    building_ids: building_val.into_iter().collect(),
+                    apartment_ids: apartment_val.into_iter().collect(),
                     comments_enabled: comments_val,
+                    requires_ack: requires_ack_val,
                     publish_at: if publish_at_val.trim().is_empty() {
                         None
                     } else {
@@ -360,6 +438,8 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
                     } else {
                         Some(datetime_local_to_naive(&expire_at_val))
                     },
+                    language: language_val,
+                    translations: translations_val,
                 };
                 let saving2 = saving.clone();
                 let error2 = error.clone();
@@ -414,13 +494,51 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
         })
     };
 
+    let on_attachments_change = {
+        let attachments = attachments.clone();
+        Callback::from(move |list: Vec<AttachmentDto>| attachments.set(list))
+    };
+
+    let on_insert_reference = {
+        let body_md = body_md.clone();
+        Callback::from(move |reference: String| {
+            let mut md = (*body_md).clone();
+            if !md.is_empty() && !md.ends_with('\n') {
+                md.push('\n');
+            }
+            md.push_str(&reference);
+            body_md.set(md);
+        })
+    };
+
+    let naive_or_none = |val: &str| {
+        if val.trim().is_empty() {
+            None
+        } else {
+            Some(datetime_local_to_naive(val))
+        }
+    };
+
     html! {
+        <>
+        <TemplatePicker
+            token={token.clone()}
+            building_id={*selected_building}
+            publish_at={naive_or_none(&publish_at)}
+            expire_at={naive_or_none(&expire_at)}
+            language={(*language).clone()}
+            title={(*title).clone()}
+            body_md={(*body_md).clone()}
+            translations={(*translations).clone()}
+            on_apply={on_template_apply}
+        />
         <AnnouncementEditorForm
             title={(*title).clone()}
             body_md={(*body_md).clone()}
             public_flag={*public_flag}
             pinned_flag={*pinned_flag}
             comments_enabled={*comments_enabled}
+            requires_ack={*requires_ack}
             publish_at={(*publish_at).clone()}
             expire_at={(*expire_at).clone()}
             selected_roles={(*selected_roles).clone()}
@@ -438,6 +556,7 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
             on_public_change={on_public_change}
             on_pinned_change={on_pinned_change}
             on_comments_change={on_comments_change}
+            on_requires_ack_change={on_requires_ack_change}
             on_publish_at_change={on_publish_at_change}
             on_expire_at_change={on_expire_at_change}
             on_roles_change={on_roles_change}
@@ -447,6 +566,22 @@ pub fn announcement_editor(props: &EditorProps) -> Html {
             on_publish_now={on_publish_now}
             on_cancel={props.on_cancel.clone()}
         />
+        <AnnouncementTranslations
+            language={(*language).clone()}
+            translations={(*translations).clone()}
+            on_language_change={on_language_change}
+            on_change={on_translations_change}
+        />
+        if let Some(ex) = &props.existing {
+            <AnnouncementAttachments
+                announcement_id={ex.id}
+                token={token.clone()}
+                attachments={(*attachments).clone()}
+                on_change={on_attachments_change}
+                on_insert={on_insert_reference}
+            />
+        }
+        </>
     }
 }
 
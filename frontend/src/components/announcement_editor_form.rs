@@ -10,6 +10,7 @@ pub struct AnnouncementEditorFormProps {
     pub public_flag: bool,
     pub pinned_flag: bool,
     pub comments_enabled: bool,
+    pub requires_ack: bool,
     pub publish_at: String,
     pub expire_at: String,
     pub selected_roles: Vec<String>,
@@ -31,6 +32,7 @@ pub struct AnnouncementEditorFormProps {
     pub on_public_change: Callback<bool>,
     pub on_pinned_change: Callback<bool>,
     pub on_comments_change: Callback<bool>,
+    pub on_requires_ack_change: Callback<bool>,
     pub on_publish_at_change: Callback<String>,
     pub on_expire_at_change: Callback<String>,
     pub on_roles_change: Callback<Vec<String>>,
@@ -350,6 +352,24 @@ pub fn announcement_editor_form(props: &AnnouncementEditorFormProps) -> Html {
                                     { t("announcement-comments-enabled-label") }
                                 </label>
                             </div>
+                            <div class="form-check">
+                                <input
+                                    class="form-check-input"
+                                    type="checkbox"
+                                    checked={props.requires_ack}
+                                    onchange={{
+                                        let cb = props.on_requires_ack_change.clone();
+                                        Callback::from(move |e: Event| {
+                                            let i: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                            cb.emit(i.checked());
+                                        })
+                                    }}
+                                    id="annRequiresAck"
+                                />
+                                <label class="form-check-label small" for="annRequiresAck">
+                                    { t("announcement-requires-ack-label") }
+                                </label>
+                            </div>
                         </div>
                     </div>
 
@@ -1,4 +1,5 @@
 use crate::components::spinner::Spinner;
+use crate::contexts::AuthContext;
 use crate::i18n::t;
 use crate::services::api::{PaginatedResponse, api_client};
 use crate::utils::datetime::format_dt_local;
@@ -17,31 +18,87 @@ pub struct AnnouncementDto {
     pub public: bool,
     pub publish_at: Option<String>,
     pub expire_at: Option<String>,
-    pub roles_csv: Option<String>,
-    pub building_id: Option<u64>,
-    pub building_address: Option<String>,
-    pub apartment_id: Option<u64>,
-    pub apartment_number: Option<String>,
+    /// Roles, buildings and apartments it is targeted at; empty means everyone
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub buildings: Vec<AudienceBuilding>,
+    #[serde(default)]
+    pub apartments: Vec<AudienceApartment>,
     pub comments_enabled: bool,
+    #[serde(default)]
+    pub requires_ack: bool,
+    /// Last change to title or body, when edited after creation
+    #[serde(default)]
+    pub edited_at: Option<String>,
+    /// Current user's read receipt (only on authenticated listings)
+    #[serde(default)]
+    pub read_at: Option<String>,
+    #[serde(default)]
+    pub acknowledged_at: Option<String>,
+    /// Released files and images, with signed download URLs
+    #[serde(default)]
+    pub attachments: Vec<AttachmentDto>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct AttachmentDto {
+    pub id: u64,
+    pub original_filename: String,
+    pub mime_type: String,
+    pub size_bytes: u64,
+    pub url: String,
+    #[serde(default)]
+    pub thumbnail_url: Option<String>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct AudienceBuilding {
+    pub id: u64,
+    pub address: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct AudienceApartment {
+    pub id: u64,
+    pub building_id: u64,
+    pub number: String,
+}
+
+#[derive(Deserialize)]
+struct ReceiptDto {
+    read_at: String,
+    acknowledged_at: Option<String>,
 }
 
 #[function_component(AnnouncementList)]
 pub fn announcement_list() -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
     let announcements = use_state(|| None::<Vec<AnnouncementDto>>);
     let loading = use_state(|| false);
-    let expanded = use_state(|| Vec::<u64>::new());
+    let expanded = use_state(Vec::<u64>::new);
+    let history_open = use_state(Vec::<u64>::new);
+    let acknowledging = use_state(|| None::<u64>);
+    let token = auth.token().map(|t| t.to_string());
 
     {
         let announcements = announcements.clone();
         let loading = loading.clone();
 
-        use_effect_with((), move |_| {
+        use_effect_with(token.clone(), move |token| {
+            let token = token.clone();
             loading.set(true);
 
             wasm_bindgen_futures::spawn_local(async move {
-                let client = api_client(None); // Public endpoint, no auth needed
+                // Signed-in users get their own audience and read receipts
+                let client = api_client(token.as_deref());
+                let endpoint = if token.is_some() {
+                    "/announcements"
+                } else {
+                    "/announcements/public"
+                };
                 match client
-                    .get::<PaginatedResponse<AnnouncementDto>>("/announcements/public")
+                    .get::<PaginatedResponse<AnnouncementDto>>(endpoint)
                     .await
                     .map(|r| r.data)
                 {
@@ -58,7 +115,22 @@ pub fn announcement_list() -> Html {
                                 a
                             })
                             .collect();
+                        let unread: Vec<u64> = mapped
+                            .iter()
+                            .filter(|a| a.read_at.is_none())
+                            .map(|a| a.id)
+                            .collect();
                         announcements.set(Some(mapped));
+                        if token.is_some() {
+                            for id in unread {
+                                let _ = client
+                                    .post_empty::<serde_json::Value>(&format!(
+                                        "/announcements/{}/read",
+                                        id
+                                    ))
+                                    .await;
+                            }
+                        }
                     }
                     Err(_) => {
                         // Silent fail for public announcements
@@ -70,6 +142,39 @@ pub fn announcement_list() -> Html {
         });
     }
 
+    let on_acknowledge = {
+        let announcements = announcements.clone();
+        let acknowledging = acknowledging.clone();
+        let token = token.clone();
+        Callback::from(move |id: u64| {
+            let announcements = announcements.clone();
+            let acknowledging = acknowledging.clone();
+            let token = token.clone();
+            acknowledging.set(Some(id));
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(receipt) = client
+                    .post_empty::<ReceiptDto>(&format!("/announcements/{}/acknowledge", id))
+                    .await
+                    && let Some(list) = (*announcements).clone()
+                {
+                    let list = list
+                        .into_iter()
+                        .map(|mut a| {
+                            if a.id == id {
+                                a.read_at = Some(receipt.read_at.clone());
+                                a.acknowledged_at = receipt.acknowledged_at.clone();
+                            }
+                            a
+                        })
+                        .collect();
+                    announcements.set(Some(list));
+                }
+                acknowledging.set(None);
+            });
+        })
+    };
+
     if *loading {
         return html! {<Spinner center={true} />};
     }
@@ -96,23 +201,23 @@ pub fn announcement_list() -> Html {
                         </span>
                     });
                 }
-                if let Some(p) = &a.publish_at {
-                    if p > &now_str {
-                        badges.push(html! {
-                            <span class="badge bg-info text-dark me-2">
-                                { t("announcement-status-scheduled") }
-                            </span>
-                        });
-                    }
+                if let Some(p) = &a.publish_at
+                    && p > &now_str
+                {
+                    badges.push(html! {
+                        <span class="badge bg-info text-dark me-2">
+                            { t("announcement-status-scheduled") }
+                        </span>
+                    });
                 }
-                if let Some(e) = &a.expire_at {
-                    if e < &now_str {
-                        badges.push(html! {
-                            <span class="badge bg-dark me-2">
-                                { t("announcement-status-expired") }
-                            </span>
-                        });
-                    }
+                if let Some(e) = &a.expire_at
+                    && e < &now_str
+                {
+                    badges.push(html! {
+                        <span class="badge bg-dark me-2">
+                            { t("announcement-status-expired") }
+                        </span>
+                    });
                 }
                 html! {<>{ for badges }</>}
             };
@@ -132,24 +237,22 @@ pub fn announcement_list() -> Html {
                         </span>
                     });
                 }
-                if let Some(csv) = &a.roles_csv {
-                    for role in csv.split(',').map(|r| r.trim()).filter(|r| !r.is_empty()) {
-                        badges.push(html! {
-                            <span class="badge bg-primary me-1">{role}</span>
-                        });
-                    }
+                for role in &a.roles {
+                    badges.push(html! {
+                        <span class="badge bg-primary me-1">{role}</span>
+                    });
                 }
-                if let Some(addr) = &a.building_address {
+                for b in &a.buildings {
                     badges.push(html! {
                         <span class="badge bg-info text-dark me-1">
-                            {format!("{} {}", t("announcement-building-prefix"), addr)}
+                            {format!("{} {}", t("announcement-building-prefix"), b.address)}
                         </span>
                     });
                 }
-                if let Some(num) = &a.apartment_number {
+                for apt in &a.apartments {
                     badges.push(html! {
                         <span class="badge bg-warning text-dark me-1">
-                            {format!("{} {}", t("announcement-apartment-prefix"), num)}
+                            {format!("{} {}", t("announcement-apartment-prefix"), apt.number)}
                         </span>
                     });
                 }
@@ -171,12 +274,74 @@ pub fn announcement_list() -> Html {
                 })
             };
 
+            let is_history_open = history_open.contains(&a.id);
+            let toggle_history = {
+                let history_open = history_open.clone();
+                let id = a.id;
+                Callback::from(move |_| {
+                    let mut vv = (*history_open).clone();
+                    if let Some(pos) = vv.iter().position(|x| *x == id) {
+                        vv.remove(pos);
+                    } else {
+                        vv.push(id);
+                    }
+                    history_open.set(vv);
+                })
+            };
+            let edited: Html = match &a.edited_at {
+                Some(at) => html! {
+                    <span class="small text-muted fst-italic" title={format_dt_local(at)}>
+                        { format!("({})", t("announcement-edited")) }
+                        if token.is_some() {
+                            <button class="btn btn-link btn-sm p-0 ms-1 align-baseline" onclick={toggle_history}>
+                                { if is_history_open {
+                                    t("announcement-history-hide")
+                                } else {
+                                    t("announcement-history-show")
+                                } }
+                            </button>
+                        }
+                    </span>
+                },
+                None => html! {},
+            };
+
+            let acknowledgement: Html = if !a.requires_ack {
+                html! {}
+            } else if let Some(at) = &a.acknowledged_at {
+                html! {
+                    <div class="mt-2">
+                        <span class="badge bg-success">
+                            {format!("{} {}", t("announcement-acknowledged-prefix"), format_dt_local(at))}
+                        </span>
+                    </div>
+                }
+            } else if token.is_some() {
+                let on_acknowledge = on_acknowledge.clone();
+                let id = a.id;
+                html! {
+                    <div class="alert alert-warning d-flex align-items-center justify-content-between gap-2 mt-2 mb-0 py-2">
+                        <span class="small">{ t("announcement-ack-required") }</span>
+                        <button
+                            class="btn btn-sm btn-warning"
+                            disabled={*acknowledging == Some(id)}
+                            onclick={Callback::from(move |_| on_acknowledge.emit(id))}
+                        >
+                            { t("announcement-acknowledge-btn") }
+                        </button>
+                    </div>
+                }
+            } else {
+                html! {}
+            };
+
             v.push(html! {
                 <div class="card mb-3 border-secondary" key={a.id}>
                     <div class="card-header">
                         <div class="d-flex align-items-center gap-2 flex-wrap">
                             {status_badges}
                             <h5 class="mb-0 fw-bold">{ &a.title }</h5>
+                            { edited }
                         </div>
                         <div class="small text-muted">
                             {format!("{} {}", t("announcement-by-prefix"), a.author_name)}
@@ -187,6 +352,19 @@ pub fn announcement_list() -> Html {
                         <div class="announcement-body">
                             { Html::from_html_unchecked(a.body_html.clone().into()) }
                         </div>
+                        if !a.attachments.is_empty() {
+                            <ul class="list-inline small mt-2 mb-0">
+                                <li class="list-inline-item text-muted">{ t("announcement-attachments-label") }</li>
+                                { for a.attachments.iter().map(|f| html! {
+                                    <li class="list-inline-item" key={f.id}>
+                                        <a href={f.url.clone()} target="_blank" rel="noopener">
+                                            <i class="bi bi-paperclip"></i>
+                                            { format!(" {}", f.original_filename) }
+                                        </a>
+                                    </li>
+                                }) }
+                            </ul>
+                        }
                         <div class="mt-2 small text-muted">
                             { if let Some(p) = a.publish_at.clone() {
                                 html!{
@@ -207,6 +385,17 @@ pub fn announcement_list() -> Html {
                                 html!{<span>{ t("announcement-no-expiry") }</span>}
                             } }
                         </div>
+                        { if is_history_open {
+                            html!{
+                                <crate::components::announcements::RevisionHistory
+                                    announcement_id={a.id}
+                                    token={token.clone()}
+                                />
+                            }
+                        } else {
+                            html!{}
+                        } }
+                        { acknowledgement }
                         { if a.comments_enabled {
                             html!{
                                 <div class="mt-2">
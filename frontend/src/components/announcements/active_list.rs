@@ -1,3 +1,4 @@
+use crate::components::announcement_list::{AttachmentDto, AudienceApartment, AudienceBuilding};
 use crate::components::spinner::Spinner;
 use crate::i18n::t;
 use yew::prelude::*;
@@ -12,15 +13,15 @@ pub struct AnnouncementItem {
     pub author_name: String,
     pub pinned: bool,
     pub public: bool,
-    pub roles_csv: Option<String>,
-    pub building_id: Option<u64>,
-    pub building_address: Option<String>,
-    pub apartment_id: Option<u64>,
-    pub apartment_number: Option<String>,
+    pub roles: Vec<String>,
+    pub buildings: Vec<AudienceBuilding>,
+    pub apartments: Vec<AudienceApartment>,
     pub comments_enabled: bool,
+    pub requires_ack: bool,
     pub publish_at: Option<String>,
     pub expire_at: Option<String>,
     pub is_deleted: bool,
+    pub attachments: Vec<AttachmentDto>,
 }
 
 #[derive(Properties, PartialEq)]
@@ -53,13 +54,12 @@ pub fn active_announcements_list(props: &ActiveAnnouncementsListProps) -> Html {
             } else {
                 html!{
                     <div>
-                        { for props.announcements.iter().cloned().map(|a| {
+                        { for props.announcements.iter().map(|a| {
                             let item_id = a.id;
                             let item_pinned = a.pinned;
                             let item_title = a.title.clone();
                             let item_publish_at = a.publish_at.clone();
                             let item_public = a.public;
-                            let item_roles = a.roles_csv.clone();
                             let item_comments = a.comments_enabled;
                             let item_expire_at = a.expire_at.clone();
                             let item_author_name = a.author_name.clone();
@@ -70,16 +70,14 @@ pub fn active_announcements_list(props: &ActiveAnnouncementsListProps) -> Html {
                                 if item_pinned {
                                     nodes.push(html!{<span class="badge bg-warning text-dark me-1">{t("announcement-status-pinned")}</span>});
                                 }
-                                if let Some(p) = &item_publish_at {
-                                    if p > &now_iso {
+                                if let Some(p) = &item_publish_at
+                                    && p > &now_iso {
                                         nodes.push(html!{<span class="badge bg-info text-dark me-1">{t("announcement-status-scheduled")}</span>});
                                     }
-                                }
-                                if let Some(e) = &item_expire_at {
-                                    if e < &now_iso {
+                                if let Some(e) = &item_expire_at
+                                    && e < &now_iso {
                                         nodes.push(html!{<span class="badge bg-dark me-1">{t("announcement-status-expired")}</span>});
                                     }
-                                }
                                 html!{<>{ for nodes }</>}
                             };
 
@@ -91,16 +89,14 @@ pub fn active_announcements_list(props: &ActiveAnnouncementsListProps) -> Html {
                                 } else {
                                     badges.push(html!{<span class="badge bg-secondary me-1">{t("announcement-private-label")}</span>});
                                 }
-                                if let Some(csv) = &item_roles {
-                                    for role in csv.split(',').map(|r| r.trim()).filter(|r| !r.is_empty()) {
-                                        badges.push(html!{<span class="badge bg-primary me-1">{role}</span>});
-                                    }
+                                for role in &a.roles {
+                                    badges.push(html!{<span class="badge bg-primary me-1">{role}</span>});
                                 }
-                                if let Some(addr) = a.building_address.clone() {
-                                    badges.push(html!{<span class="badge bg-info text-dark me-1">{format!("Bldg: {}", addr)}</span>});
+                                for b in &a.buildings {
+                                    badges.push(html!{<span class="badge bg-info text-dark me-1">{format!("Bldg: {}", b.address)}</span>});
                                 }
-                                if let Some(num) = a.apartment_number.clone() {
-                                    badges.push(html!{<span class="badge bg-warning text-dark me-1">{format!("Apt: {}", num)}</span>});
+                                for apt in &a.apartments {
+                                    badges.push(html!{<span class="badge bg-warning text-dark me-1">{format!("Apt: {}", apt.number)}</span>});
                                 }
                                 html!{<div class="mt-1">{ for badges }</div>}
                             };
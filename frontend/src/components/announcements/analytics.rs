@@ -0,0 +1,373 @@
+use crate::i18n::{t, t_with_args};
+use crate::services::api::{PaginatedResponse, api_client};
+use crate::utils::datetime::format_dt_local;
+use serde::Deserialize;
+use yew::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct DailyReads {
+    date: String,
+    readers: usize,
+    cumulative: usize,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct ReachGroup {
+    id: Option<u64>,
+    label: String,
+    audience: usize,
+    readers: usize,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct AnnouncementAnalytics {
+    audience_total: usize,
+    audience_read_count: usize,
+    reader_count: usize,
+    acknowledged_count: usize,
+    read_rate: f64,
+    comment_count: usize,
+    commenter_count: usize,
+    minutes_to_first_read: Option<i64>,
+    median_minutes_to_read: Option<i64>,
+    readers_over_time: Vec<DailyReads>,
+    reach_by_building: Vec<ReachGroup>,
+    reach_by_role: Vec<ReachGroup>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct AnnouncementEngagement {
+    announcement_id: u64,
+    title: String,
+    publish_at: Option<String>,
+    audience_total: usize,
+    audience_read_count: usize,
+    read_rate: f64,
+    comment_count: usize,
+    minutes_to_first_read: Option<i64>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct EngagementSummary {
+    announcement_count: usize,
+    audience_total: usize,
+    audience_read_count: usize,
+    read_rate: f64,
+    comment_count: usize,
+    median_minutes_to_first_read: Option<i64>,
+    announcements: Vec<AnnouncementEngagement>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct Building {
+    id: u64,
+    address: String,
+}
+
+fn percent(rate: f64) -> String {
+    format!("{:.0}%", rate * 100.0)
+}
+
+/// Minutes as a short duration, e.g. "45 min", "3 h", "2 d".
+fn duration(minutes: Option<i64>) -> String {
+    match minutes {
+        None => "–".to_string(),
+        Some(m) if m < 60 => format!("{} min", m),
+        Some(m) if m < 48 * 60 => format!("{} h", m / 60),
+        Some(m) => format!("{} d", m / (24 * 60)),
+    }
+}
+
+fn stat(label: String, value: String) -> Html {
+    html! {
+        <div class="col">
+            <div class="text-muted">{ label }</div>
+            <div class="fw-semibold">{ value }</div>
+        </div>
+    }
+}
+
+fn reach_table(heading: String, groups: &[ReachGroup]) -> Html {
+    if groups.is_empty() {
+        return html! {};
+    }
+    html! {
+        <div class="col-md-6">
+            <div class="text-muted mb-1">{ heading }</div>
+            <table class="table table-sm mb-0">
+                <tbody>
+                    { for groups.iter().map(|g| {
+                        let rate = if g.audience == 0 { 0.0 } else { g.readers as f64 / g.audience as f64 };
+                        html! {
+                            <tr key={format!("{:?}-{}", g.id, g.label)}>
+                                <td>
+                                    { if g.label.is_empty() { t("announcement-analytics-no-building") } else { g.label.clone() } }
+                                </td>
+                                <td class="text-end">{ format!("{} / {}", g.readers, g.audience) }</td>
+                                <td class="text-end">{ percent(rate) }</td>
+                            </tr>
+                        }
+                    }) }
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct AnnouncementAnalyticsPanelProps {
+    pub announcement_id: u64,
+    pub token: Option<String>,
+}
+
+/// Engagement with one announcement: readers over time, comments, time to read
+/// and reach by building and role.
+#[function_component(AnnouncementAnalyticsPanel)]
+pub fn announcement_analytics_panel(props: &AnnouncementAnalyticsPanelProps) -> Html {
+    let analytics = use_state(|| None::<AnnouncementAnalytics>);
+    let error = use_state(|| None::<String>);
+
+    {
+        let analytics = analytics.clone();
+        let error = error.clone();
+        let token = props.token.clone();
+
+        use_effect_with(props.announcement_id, move |id| {
+            let id = *id;
+            analytics.set(None);
+            error.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get::<AnnouncementAnalytics>(&format!("/announcements/{}/analytics", id))
+                    .await
+                {
+                    Ok(a) => analytics.set(Some(a)),
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-load-failed"), e))),
+                }
+            });
+            || ()
+        });
+    }
+
+    if let Some(err) = &*error {
+        return html! {<div class="alert alert-warning py-1 small my-2">{err}</div>};
+    }
+    let Some(a) = &*analytics else {
+        return html! {};
+    };
+
+    let peak = a
+        .readers_over_time
+        .iter()
+        .map(|d| d.readers)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    html! {
+        <div class="card my-3">
+            <div class="card-header py-2">
+                <strong class="small">{ t("announcement-analytics-heading") }</strong>
+            </div>
+            <div class="card-body py-2 small">
+                <div class="row row-cols-2 row-cols-md-4 g-2 mb-3">
+                    { stat(t("announcement-analytics-audience"), a.audience_total.to_string()) }
+                    { stat(
+                        t("announcement-analytics-read"),
+                        format!("{} ({})", a.audience_read_count, percent(a.read_rate)),
+                    ) }
+                    { stat(t("announcement-analytics-acknowledged"), a.acknowledged_count.to_string()) }
+                    { stat(
+                        t("announcement-analytics-comments"),
+                        t_with_args("announcement-analytics-comments-by", &[
+                            ("count", &a.comment_count.to_string()),
+                            ("people", &a.commenter_count.to_string()),
+                        ]),
+                    ) }
+                    { stat(t("announcement-analytics-first-read"), duration(a.minutes_to_first_read)) }
+                    { stat(t("announcement-analytics-median-read"), duration(a.median_minutes_to_read)) }
+                    if a.reader_count > a.audience_read_count {
+                        { stat(
+                            t("announcement-analytics-outside-audience"),
+                            (a.reader_count - a.audience_read_count).to_string(),
+                        ) }
+                    }
+                </div>
+                if !a.readers_over_time.is_empty() {
+                    <div class="text-muted mb-1">{ t("announcement-analytics-readers-over-time") }</div>
+                    <div class="d-flex align-items-end gap-1 mb-3" style="height: 80px;">
+                        { for a.readers_over_time.iter().map(|d| {
+                            let height = d.readers * 100 / peak;
+                            let title = t_with_args("announcement-analytics-day-tooltip", &[
+                                ("date", &d.date),
+                                ("count", &d.readers.to_string()),
+                                ("total", &d.cumulative.to_string()),
+                            ]);
+                            html! {
+                                <div
+                                    key={d.date.clone()}
+                                    class="bg-primary flex-fill"
+                                    style={format!("height: {}%; min-height: 1px;", height)}
+                                    title={title}
+                                />
+                            }
+                        }) }
+                    </div>
+                }
+                <div class="row g-3">
+                    { reach_table(t("announcement-analytics-by-building"), &a.reach_by_building) }
+                    { reach_table(t("announcement-analytics-by-role"), &a.reach_by_role) }
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct EngagementSummaryCardProps {
+    pub token: Option<String>,
+}
+
+/// Read rates and comments of recent announcements, overall or for one building.
+#[function_component(EngagementSummaryCard)]
+pub fn engagement_summary_card(props: &EngagementSummaryCardProps) -> Html {
+    let summary = use_state(|| None::<EngagementSummary>);
+    let buildings = use_state(Vec::<Building>::new);
+    let building_id = use_state(|| None::<u64>);
+    let days = use_state(|| 90u32);
+    let error = use_state(|| None::<String>);
+
+    {
+        let buildings = buildings.clone();
+        let token = props.token.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client
+                    .get::<PaginatedResponse<Building>>("/buildings")
+                    .await
+                    .map(|r| r.data)
+                {
+                    buildings.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    {
+        let summary = summary.clone();
+        let error = error.clone();
+        let token = props.token.clone();
+        use_effect_with((*building_id, *days), move |(building_id, days)| {
+            let mut path = format!("/announcements/analytics?days={}", days);
+            if let Some(id) = building_id {
+                path.push_str(&format!("&building_id={}", id));
+            }
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client.get::<EngagementSummary>(&path).await {
+                    Ok(s) => {
+                        error.set(None);
+                        summary.set(Some(s));
+                    }
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-load-failed"), e))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_building = {
+        let building_id = building_id.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            building_id.set(select.value().parse().ok());
+        })
+    };
+    let on_days = {
+        let days = days.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(d) = select.value().parse() {
+                days.set(d);
+            }
+        })
+    };
+
+    html! {
+        <div class="card mb-3">
+            <div class="card-header d-flex justify-content-between align-items-center flex-wrap gap-2">
+                <h6 class="mb-0">{ t("announcement-engagement-heading") }</h6>
+                <div class="d-flex gap-2">
+                    <select class="form-select form-select-sm" onchange={on_building}>
+                        <option value="" selected={building_id.is_none()}>
+                            { t("announcement-engagement-all-buildings") }
+                        </option>
+                        { for buildings.iter().map(|b| html! {
+                            <option value={b.id.to_string()} selected={*building_id == Some(b.id)}>
+                                { &b.address }
+                            </option>
+                        }) }
+                    </select>
+                    <select class="form-select form-select-sm" onchange={on_days}>
+                        { for [30u32, 90, 365].iter().map(|d| html! {
+                            <option value={d.to_string()} selected={*days == *d}>
+                                { t_with_args("announcement-engagement-days", &[("days", &d.to_string())]) }
+                            </option>
+                        }) }
+                    </select>
+                </div>
+            </div>
+            <div class="card-body small">
+                if let Some(err) = &*error {
+                    <div class="alert alert-warning py-1">{ err }</div>
+                }
+                if let Some(s) = &*summary {
+                    <div class="row row-cols-2 row-cols-md-4 g-2 mb-3">
+                        { stat(t("announcement-engagement-announcements"), s.announcement_count.to_string()) }
+                        { stat(
+                            t("announcement-analytics-read"),
+                            format!("{} / {} ({})", s.audience_read_count, s.audience_total, percent(s.read_rate)),
+                        ) }
+                        { stat(t("announcement-analytics-comments"), s.comment_count.to_string()) }
+                        { stat(
+                            t("announcement-engagement-median-first-read"),
+                            duration(s.median_minutes_to_first_read),
+                        ) }
+                    </div>
+                    if s.announcements.is_empty() {
+                        <div class="text-muted">{ t("announcement-engagement-empty") }</div>
+                    } else {
+                        <ul class="list-unstyled mb-0">
+                            { for s.announcements.iter().map(|a| html! {
+                                <li key={a.announcement_id} class="mb-2">
+                                    <div class="d-flex justify-content-between">
+                                        <span class="text-truncate me-2">
+                                            { &a.title }
+                                            { a.publish_at.as_deref().map(|p| html! {
+                                                <span class="text-muted ms-2">{ format_dt_local(p) }</span>
+                                            }).unwrap_or_default() }
+                                        </span>
+                                        <span class="text-nowrap text-muted">
+                                            { format!("{} / {}", a.audience_read_count, a.audience_total) }
+                                            { format!(" • {} {}", a.comment_count, t("announcement-engagement-comments-short")) }
+                                            { format!(" • {}", duration(a.minutes_to_first_read)) }
+                                        </span>
+                                    </div>
+                                    <div class="progress" style="height: 6px;">
+                                        <div
+                                            class="progress-bar"
+                                            role="progressbar"
+                                            style={format!("width: {:.0}%;", a.read_rate * 100.0)}
+                                        />
+                                    </div>
+                                </li>
+                            }) }
+                        </ul>
+                    }
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,198 @@
+use crate::components::announcement_editor::AnnouncementFull;
+use crate::components::announcement_list::AttachmentDto;
+use crate::i18n::{t, t_with_args};
+use crate::services::{ApiError, api_client};
+use web_sys::{FormData, HtmlInputElement};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct AnnouncementAttachmentsProps {
+    pub announcement_id: u64,
+    pub token: Option<String>,
+    pub attachments: Vec<AttachmentDto>,
+    /// Called with the refreshed list after an upload or delete
+    pub on_change: Callback<Vec<AttachmentDto>>,
+    /// Called with a Markdown reference to insert into the body
+    pub on_insert: Callback<String>,
+}
+
+/// Upload, reference and remove files of an announcement being edited.
+#[function_component(AnnouncementAttachments)]
+pub fn announcement_attachments(props: &AnnouncementAttachmentsProps) -> Html {
+    let file_input_ref = use_node_ref();
+    let uploading = use_state(|| false);
+    let error = use_state(|| None::<String>);
+
+    // The upload endpoint returns the new file; reload the announcement so the
+    // list stays in the server's order with fresh signed URLs.
+    let refresh = {
+        let token = props.token.clone();
+        let on_change = props.on_change.clone();
+        let id = props.announcement_id;
+        move || {
+            let token = token.clone();
+            let on_change = on_change.clone();
+            async move {
+                let client = api_client(token.as_deref());
+                if let Ok(full) = client
+                    .get::<AnnouncementFull>(&format!("/announcements/{}", id))
+                    .await
+                {
+                    on_change.emit(full.attachments);
+                }
+            }
+        }
+    };
+
+    let on_file_change = {
+        let file_input_ref = file_input_ref.clone();
+        let uploading = uploading.clone();
+        let error = error.clone();
+        let token = props.token.clone();
+        let refresh = refresh.clone();
+        let id = props.announcement_id;
+        Callback::from(move |_: Event| {
+            let Some(input) = file_input_ref.cast::<HtmlInputElement>() else {
+                return;
+            };
+            let Some(file) = input.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            let form_data = FormData::new().unwrap();
+            form_data.append_with_blob("file", &file).unwrap();
+            input.set_value("");
+
+            let uploading = uploading.clone();
+            let error = error.clone();
+            let token = token.clone();
+            let refresh = refresh.clone();
+            uploading.set(true);
+            error.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post_multipart(&format!("/announcements/{}/attachments", id), &form_data)
+                    .await
+                {
+                    Ok(_) => refresh().await,
+                    Err(ApiError::BadRequest(msg)) if msg.contains("malware_detected") => {
+                        error.set(Some(t("announcement-attachment-infected")));
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "announcement-attachment-upload-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                uploading.set(false);
+            });
+        })
+    };
+
+    let on_upload_click = {
+        let file_input_ref = file_input_ref.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(input) = file_input_ref.cast::<HtmlInputElement>() {
+                input.click();
+            }
+        })
+    };
+
+    let on_delete = {
+        let error = error.clone();
+        let token = props.token.clone();
+        let refresh = refresh.clone();
+        let id = props.announcement_id;
+        Callback::from(move |att_id: u64| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(&t("announcement-attachment-delete-confirm"))
+                        .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let error = error.clone();
+            let token = token.clone();
+            let refresh = refresh.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .delete_no_response(&format!("/announcements/{}/attachments/{}", id, att_id))
+                    .await
+                {
+                    Ok(_) => refresh().await,
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-delete-failed"), e))),
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="card my-3">
+            <div class="card-header py-2 d-flex justify-content-between align-items-center">
+                <strong class="small">{ t("announcement-attachments-label") }</strong>
+                <input
+                    type="file"
+                    ref={file_input_ref}
+                    style="display: none;"
+                    onchange={on_file_change}
+                    accept="image/*,application/pdf"
+                />
+                <button
+                    type="button"
+                    class="btn btn-sm btn-outline-primary"
+                    onclick={on_upload_click}
+                    disabled={*uploading}
+                >
+                    <i class="bi bi-upload"></i>
+                    { format!(" {}", t("announcement-attachment-upload")) }
+                </button>
+            </div>
+            <div class="card-body py-2 small">
+                if let Some(err) = &*error {
+                    <div class="alert alert-warning py-1 mb-2">{err}</div>
+                }
+                if props.attachments.is_empty() {
+                    <div class="text-muted">{ t("announcement-attachments-empty") }</div>
+                } else {
+                    <ul class="list-unstyled mb-0">
+                        { for props.attachments.iter().map(|f| {
+                            // Images are embedded, anything else becomes a link
+                            let prefix = if f.mime_type.starts_with("image/") { "!" } else { "" };
+                            let reference = format!("{}[{}](attachment:{})", prefix, f.original_filename, f.id);
+                            let on_insert = props.on_insert.clone();
+                            let on_delete = on_delete.clone();
+                            let att_id = f.id;
+                            html! {
+                                <li key={f.id} class="d-flex align-items-center gap-2 mb-1">
+                                    if let Some(thumb) = &f.thumbnail_url {
+                                        <img src={thumb.clone()} alt="" style="height: 2rem;" class="rounded" />
+                                    }
+                                    <a href={f.url.clone()} target="_blank" rel="noopener" class="text-truncate">
+                                        { &f.original_filename }
+                                    </a>
+                                    <code class="text-muted">{ format!("attachment:{}", f.id) }</code>
+                                    <button
+                                        type="button"
+                                        class="btn btn-sm btn-outline-secondary ms-auto py-0"
+                                        onclick={Callback::from(move |_| on_insert.emit(reference.clone()))}
+                                    >
+                                        { t("announcement-attachment-insert") }
+                                    </button>
+                                    <button
+                                        type="button"
+                                        class="btn btn-sm btn-outline-danger py-0"
+                                        onclick={Callback::from(move |_| on_delete.emit(att_id))}
+                                    >
+                                        <i class="bi bi-trash"></i>
+                                    </button>
+                                </li>
+                            }
+                        }) }
+                    </ul>
+                }
+            </div>
+        </div>
+    }
+}
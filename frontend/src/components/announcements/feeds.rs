@@ -0,0 +1,164 @@
+use crate::i18n::t;
+use crate::services::api_client;
+use crate::utils::datetime::format_dt_local;
+use serde::Deserialize;
+use yew::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct FeedTokenStatus {
+    active: bool,
+    created_at: Option<String>,
+    last_used_at: Option<String>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct FeedUrls {
+    atom_url: String,
+    calendar_url: String,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct FeedSubscriptionsProps {
+    pub token: Option<String>,
+}
+
+/// Private Atom and calendar URLs for feed readers and calendar apps.
+/// The URLs are only shown right after they are created.
+#[function_component(FeedSubscriptions)]
+pub fn feed_subscriptions(props: &FeedSubscriptionsProps) -> Html {
+    let status = use_state(|| None::<FeedTokenStatus>);
+    let urls = use_state(|| None::<FeedUrls>);
+    let error = use_state(|| None::<String>);
+    let reload = use_state(|| 0u32);
+
+    {
+        let status = status.clone();
+        let token = props.token.clone();
+        use_effect_with(*reload, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(s) = client.get::<FeedTokenStatus>("/feeds/token").await {
+                    status.set(Some(s));
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_create = {
+        let urls = urls.clone();
+        let error = error.clone();
+        let reload = reload.clone();
+        let active = status.as_ref().is_some_and(|s| s.active);
+        let token = props.token.clone();
+        Callback::from(move |_: MouseEvent| {
+            if active {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message(&t("feeds-regenerate-confirm")).ok())
+                    .unwrap_or(false);
+                if !confirmed {
+                    return;
+                }
+            }
+            let urls = urls.clone();
+            let error = error.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client.post_empty::<FeedUrls>("/feeds/token").await {
+                    Ok(created) => {
+                        error.set(None);
+                        urls.set(Some(created));
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error.set(Some(format!("{}: {}", t("feeds-create-failed"), e))),
+                }
+            });
+        })
+    };
+
+    let on_revoke = {
+        let urls = urls.clone();
+        let error = error.clone();
+        let reload = reload.clone();
+        let token = props.token.clone();
+        Callback::from(move |_: MouseEvent| {
+            let confirmed = web_sys::window()
+                .and_then(|w| w.confirm_with_message(&t("feeds-revoke-confirm")).ok())
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let urls = urls.clone();
+            let error = error.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client.delete_no_response("/feeds/token").await {
+                    Ok(_) => {
+                        urls.set(None);
+                        reload.set(*reload + 1);
+                    }
+                    Err(_) => error.set(Some(t("error-delete-failed"))),
+                }
+            });
+        })
+    };
+
+    let url_field = |label: String, url: &str| {
+        html! {
+            <div class="input-group input-group-sm mb-2">
+                <span class="input-group-text">{ label }</span>
+                <input
+                    class="form-control font-monospace"
+                    readonly=true
+                    value={url.to_string()}
+                    onfocus={Callback::from(|e: FocusEvent| {
+                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                        input.select();
+                    })}
+                />
+            </div>
+        }
+    };
+
+    let Some(current) = (*status).clone() else {
+        return html! {};
+    };
+
+    html! {
+        <div class="card mt-3">
+            <div class="card-header d-flex justify-content-between align-items-center">
+                <h6 class="mb-0"><i class="bi bi-rss me-1"></i>{ t("feeds-heading") }</h6>
+                <div class="d-flex gap-1">
+                    <button class="btn btn-sm btn-outline-primary" onclick={on_create}>
+                        { if current.active { t("feeds-regenerate") } else { t("feeds-create") } }
+                    </button>
+                    if current.active {
+                        <button class="btn btn-sm btn-outline-danger" onclick={on_revoke}>
+                            { t("feeds-revoke") }
+                        </button>
+                    }
+                </div>
+            </div>
+            <div class="card-body small">
+                if let Some(err) = &*error {
+                    <div class="alert alert-warning py-1">{ err }</div>
+                }
+                <p class="text-muted">{ t("feeds-description") }</p>
+                if let Some(u) = &*urls {
+                    { url_field(t("feeds-atom-label"), &u.atom_url) }
+                    { url_field(t("feeds-calendar-label"), &u.calendar_url) }
+                    <div class="text-warning">{ t("feeds-private-warning") }</div>
+                } else if current.active {
+                    <div class="text-muted">
+                        { current.created_at.as_deref().map(|s| format!("{} {}", t("feeds-created-at"), format_dt_local(s))).unwrap_or_default() }
+                        { current.last_used_at.as_deref().map(|s| format!(" • {} {}", t("feeds-last-used-at"), format_dt_local(s))).unwrap_or_default() }
+                    </div>
+                }
+            </div>
+        </div>
+    }
+}
@@ -1,5 +1,7 @@
 use super::{
-    ActiveAnnouncementsList, AnnouncementItem, DeletedAnnouncement, DeletedAnnouncementsList,
+    ActiveAnnouncementsList, AnnouncementAnalyticsPanel, AnnouncementItem, CommentModerationQueue,
+    DeletedAnnouncement, DeletedAnnouncementsList, EngagementSummaryCard, ReceiptReportPanel,
+    RevisionHistory,
 };
 use crate::components::announcement_editor::AnnouncementEditor;
 use crate::components::announcement_editor::AnnouncementFull;
@@ -15,8 +17,8 @@ pub fn announcements_manage() -> Html {
     let token = auth.token().map(|t| t.to_string());
 
     let loading = use_state(|| false);
-    let list = use_state(|| Vec::<AnnouncementItem>::new());
-    let deleted = use_state(|| Vec::<DeletedAnnouncement>::new());
+    let list = use_state(Vec::<AnnouncementItem>::new);
+    let deleted = use_state(Vec::<DeletedAnnouncement>::new);
     let show_deleted = use_state(|| false);
     let selected = use_state(|| None::<u64>);
     let error = use_state(|| None::<String>);
@@ -54,11 +56,15 @@ pub fn announcements_manage() -> Html {
                                     author_name: x.get("author_name")?.as_str()?.to_string(),
                                     pinned: x.get("pinned")?.as_bool()?,
                                     public: x.get("public")?.as_bool()?,
-                                    roles_csv: x
-                                        .get("roles_csv")
-                                        .and_then(|r| r.as_str())
-                                        .map(|s| s.to_string()),
+                                    roles: x
+                                        .get("roles")
+                                        .and_then(|r| serde_json::from_value(r.clone()).ok())
+                                        .unwrap_or_default(),
                                     comments_enabled: x.get("comments_enabled")?.as_bool()?,
+                                    requires_ack: x
+                                        .get("requires_ack")
+                                        .and_then(|r| r.as_bool())
+                                        .unwrap_or(false),
                                     publish_at: x
                                         .get("publish_at")
                                         .and_then(|r| r.as_str())
@@ -68,16 +74,18 @@ pub fn announcements_manage() -> Html {
                                         .and_then(|r| r.as_str())
                                         .map(|s| s.to_string()),
                                     is_deleted: x.get("is_deleted")?.as_bool()?,
-                                    building_id: x.get("building_id").and_then(|r| r.as_u64()),
-                                    building_address: x
-                                        .get("building_address")
-                                        .and_then(|r| r.as_str())
-                                        .map(|s| s.to_string()),
-                                    apartment_id: x.get("apartment_id").and_then(|r| r.as_u64()),
-                                    apartment_number: x
-                                        .get("apartment_number")
-                                        .and_then(|r| r.as_str())
-                                        .map(|s| s.to_string()),
+                                    buildings: x
+                                        .get("buildings")
+                                        .and_then(|r| serde_json::from_value(r.clone()).ok())
+                                        .unwrap_or_default(),
+                                    apartments: x
+                                        .get("apartments")
+                                        .and_then(|r| serde_json::from_value(r.clone()).ok())
+                                        .unwrap_or_default(),
+                                    attachments: x
+                                        .get("attachments")
+                                        .and_then(|r| serde_json::from_value(r.clone()).ok())
+                                        .unwrap_or_default(),
                                 })
                             })
                             .collect();
@@ -180,9 +188,10 @@ pub fn announcements_manage() -> Html {
             let token = token.clone();
             wasm_bindgen_futures::spawn_local(async move {
                 let client = api_client(token.as_deref());
-                if let Ok(_) = client
+                if client
                     .post_empty::<serde_json::Value>(&format!("/announcements/{}/pin", id))
                     .await
+                    .is_ok()
                 {
                     fetch_active2.emit(());
                 }
@@ -198,9 +207,10 @@ pub fn announcements_manage() -> Html {
             let token = token.clone();
             wasm_bindgen_futures::spawn_local(async move {
                 let client = api_client(token.as_deref());
-                if let Ok(_) = client
+                if client
                     .delete_no_response(&format!("/announcements/{}", id))
                     .await
+                    .is_ok()
                 {
                     fetch_active2.emit(());
                 }
@@ -218,9 +228,10 @@ pub fn announcements_manage() -> Html {
             let token = token.clone();
             wasm_bindgen_futures::spawn_local(async move {
                 let client = api_client(token.as_deref());
-                if let Ok(_) = client
+                if client
                     .post_empty::<serde_json::Value>(&format!("/announcements/{}/restore", id))
                     .await
+                    .is_ok()
                 {
                     fetch_active2.emit(());
                     fetch_deleted2.emit(());
@@ -239,15 +250,16 @@ pub fn announcements_manage() -> Html {
             let token = token.clone();
             wasm_bindgen_futures::spawn_local(async move {
                 let client = api_client(token.as_deref());
-                if let Ok(_) = client
+                if client
                     .delete_no_response(&format!("/announcements/{}/purge", id))
                     .await
+                    .is_ok()
                 {
                     deleted_state2.set(
                         deleted_state2
                             .iter()
+                            .filter(|&a| a.id != id)
                             .cloned()
-                            .filter(|a| a.id != id)
                             .collect(),
                     );
                     fetch_deleted2.emit(());
@@ -351,12 +363,14 @@ pub fn announcements_manage() -> Html {
         body_html: a.body_html,
         pinned: a.pinned,
         public: a.public,
-        roles_csv: a.roles_csv,
-        building_id: a.building_id,
-        apartment_id: a.apartment_id,
+        roles: a.roles,
+        buildings: a.buildings,
+        apartments: a.apartments,
         comments_enabled: a.comments_enabled,
+        requires_ack: a.requires_ack,
         publish_at: a.publish_at,
         expire_at: a.expire_at,
+        attachments: a.attachments,
     });
 
     html! {
@@ -397,6 +411,9 @@ pub fn announcements_manage() -> Html {
                 </div>
             </div>
 
+            <CommentModerationQueue token={token.clone()} />
+            <EngagementSummaryCard token={token.clone()} />
+
             // Create new editor
             { if *creating_new {
                 html! {
@@ -486,16 +503,14 @@ pub fn announcements_manage() -> Html {
                             if item.pinned {
                                 nodes.push(html!{<span class="badge bg-warning text-dark me-1">{t("announcement-status-pinned")}</span>});
                             }
-                            if let Some(p) = &item.publish_at {
-                                if p > &now_iso {
+                            if let Some(p) = &item.publish_at
+                                && p > &now_iso {
                                     nodes.push(html!{<span class="badge bg-info text-dark me-1">{t("announcement-status-scheduled")}</span>});
                                 }
-                            }
-                            if let Some(e) = &item.expire_at {
-                                if e < &now_iso {
+                            if let Some(e) = &item.expire_at
+                                && e < &now_iso {
                                     nodes.push(html!{<span class="badge bg-dark me-1">{t("announcement-status-expired")}</span>});
                                 }
-                            }
                             html!{<>{ for nodes }</>}
                         }}
                         <h5 class="fw-bold mb-1">{item.title.clone()}</h5>
@@ -507,16 +522,14 @@ pub fn announcements_manage() -> Html {
                             } else {
                                 badges.push(html!{<span class="badge bg-secondary me-1">{t("announcement-private-label")}</span>});
                             }
-                            if let Some(csv) = &item.roles_csv {
-                                for role in csv.split(',').map(|r| r.trim()).filter(|r| !r.is_empty()) {
-                                    badges.push(html!{<span class="badge bg-primary me-1">{role}</span>});
-                                }
+                            for role in &item.roles {
+                                badges.push(html!{<span class="badge bg-primary me-1">{role}</span>});
                             }
-                            if let Some(addr) = item.building_address.clone() {
-                                badges.push(html!{<span class="badge bg-info text-dark me-1">{format!("Bldg: {}", addr)}</span>});
+                            for b in &item.buildings {
+                                badges.push(html!{<span class="badge bg-info text-dark me-1">{format!("Bldg: {}", b.address)}</span>});
                             }
-                            if let Some(num) = item.apartment_number.clone() {
-                                badges.push(html!{<span class="badge bg-warning text-dark me-1">{format!("Apt: {}", num)}</span>});
+                            for apt in &item.apartments {
+                                badges.push(html!{<span class="badge bg-warning text-dark me-1">{format!("Apt: {}", apt.number)}</span>});
                             }
                             html!{<div class="mb-2">{ for badges }</div>}
                         }}
@@ -531,6 +544,14 @@ pub fn announcements_manage() -> Html {
                                 { if item.comments_enabled { "Disable Comments" } else { "Enable Comments" } }
                             </button>
                         </div>
+                        <ReceiptReportPanel announcement_id={item.id} token={token.clone()} />
+                        <AnnouncementAnalyticsPanel announcement_id={item.id} token={token.clone()} />
+                        <RevisionHistory
+                            announcement_id={item.id}
+                            token={token.clone()}
+                            can_restore={true}
+                            on_restored={fetch_active.reform(|_| ())}
+                        />
                         <CommentList announcement_id={item.id} comments_enabled={item.comments_enabled} />
                     </div>
                 }
@@ -1,7 +1,23 @@
 mod active_list;
+mod analytics;
+mod attachments;
 mod deleted_list;
+mod feeds;
 mod manage;
+mod moderation;
+mod receipts;
+mod revisions;
+mod templates;
+mod translations;
 
 pub use active_list::{ActiveAnnouncementsList, AnnouncementItem};
+pub use analytics::{AnnouncementAnalyticsPanel, EngagementSummaryCard};
+pub use attachments::AnnouncementAttachments;
 pub use deleted_list::{DeletedAnnouncement, DeletedAnnouncementsList};
+pub use feeds::FeedSubscriptions;
 pub use manage::AnnouncementsManage;
+pub use moderation::CommentModerationQueue;
+pub use receipts::ReceiptReportPanel;
+pub use revisions::RevisionHistory;
+pub use templates::{TemplateDraft, TemplatePicker};
+pub use translations::{AnnouncementTranslations, AnnouncementVersions, LocalizedText};
@@ -0,0 +1,186 @@
+use crate::components::comment_list::CommentDto;
+use crate::i18n::{t, t_with_args};
+use crate::services::api_client;
+use crate::utils::datetime::format_dt_local;
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct CommentReport {
+    pub id: u64,
+    pub reporter_id: u64,
+    pub reporter_name: String,
+    pub reason: String,
+    pub status: String,
+    pub created_at: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct ReportedComment {
+    pub announcement_title: String,
+    pub comment: CommentDto,
+    pub reports: Vec<CommentReport>,
+}
+
+#[derive(Serialize)]
+struct HideRequest {
+    reason: String,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CommentModerationQueueProps {
+    pub token: Option<String>,
+}
+
+/// Comments reported by residents that still await a moderator decision.
+/// Renders nothing while the queue is empty.
+#[function_component(CommentModerationQueue)]
+pub fn comment_moderation_queue(props: &CommentModerationQueueProps) -> Html {
+    let queue = use_state(Vec::<ReportedComment>::new);
+    let error = use_state(|| None::<String>);
+    let reload = use_state(|| 0u32);
+
+    {
+        let queue = queue.clone();
+        let error = error.clone();
+        let token = props.token.clone();
+
+        use_effect_with(*reload, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get::<Vec<ReportedComment>>("/announcements/comments/reports")
+                    .await
+                {
+                    Ok(list) => queue.set(list),
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-load-failed"), e))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_hide = {
+        let error = error.clone();
+        let reload = reload.clone();
+        let token = props.token.clone();
+        Callback::from(move |(comment_id, suggested): (u64, String)| {
+            let Some(reason) = web_sys::window()
+                .and_then(|w| {
+                    w.prompt_with_message_and_default(&t("comment-hide-prompt"), &suggested)
+                        .ok()
+                })
+                .flatten()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+            else {
+                return;
+            };
+            let error = error.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, serde_json::Value>(
+                        &format!("/announcements/comments/{}/hide", comment_id),
+                        &HideRequest { reason },
+                    )
+                    .await
+                {
+                    Ok(_) => reload.set(*reload + 1),
+                    Err(_) => error.set(Some(t("comment-hide-failed"))),
+                }
+            });
+        })
+    };
+
+    let on_dismiss = {
+        let error = error.clone();
+        let reload = reload.clone();
+        let token = props.token.clone();
+        Callback::from(move |comment_id: u64| {
+            let error = error.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post_no_response(
+                        &format!("/announcements/comments/{}/reports/dismiss", comment_id),
+                        &serde_json::json!({}),
+                    )
+                    .await
+                {
+                    Ok(_) => reload.set(*reload + 1),
+                    Err(_) => error.set(Some(t("comment-moderation-dismiss-failed"))),
+                }
+            });
+        })
+    };
+
+    if let Some(err) = &*error {
+        return html! {<div class="alert alert-warning py-1 small my-2">{err}</div>};
+    }
+    if queue.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div class="card border-warning mb-3">
+            <div class="card-header py-2">
+                <strong class="small">
+                    { t_with_args("comment-moderation-heading", &[("count", &queue.len().to_string())]) }
+                </strong>
+            </div>
+            <ul class="list-group list-group-flush small">
+                { for queue.iter().map(|item| {
+                    let c = &item.comment;
+                    let comment_id = c.id;
+                    let suggested = item.reports.first().map(|r| r.reason.clone()).unwrap_or_default();
+                    let on_hide = on_hide.clone();
+                    let on_dismiss = on_dismiss.clone();
+                    html! {
+                        <li key={comment_id} class="list-group-item">
+                            <div class="text-muted mb-1">
+                                { format!("{} • {}", item.announcement_title, c.user_name) }
+                                { c.created_at.as_deref().map(|s| format!(" • {}", format_dt_local(s))).unwrap_or_default() }
+                            </div>
+                            <div class="p-2 border rounded bg-light mb-2">
+                                { Html::from_html_unchecked(c.body_html.clone().into()) }
+                            </div>
+                            <div class="mb-2">
+                                <span class="badge bg-warning text-dark me-1">
+                                    { t_with_args("comment-moderation-report-count", &[("count", &item.reports.len().to_string())]) }
+                                </span>
+                                <ul class="mb-0">
+                                    { for item.reports.iter().map(|r| html! {
+                                        <li key={r.id}>
+                                            <strong>{ &r.reporter_name }</strong>
+                                            { format!(": {} ", r.reason) }
+                                            <span class="text-muted">{ format_dt_local(&r.created_at) }</span>
+                                        </li>
+                                    }) }
+                                </ul>
+                            </div>
+                            <div class="d-flex gap-1">
+                                <button
+                                    class="btn btn-sm btn-outline-danger"
+                                    onclick={Callback::from(move |_| on_hide.emit((comment_id, suggested.clone())))}
+                                >
+                                    { t("comment-hide-button") }
+                                </button>
+                                <button
+                                    class="btn btn-sm btn-outline-secondary"
+                                    onclick={Callback::from(move |_| on_dismiss.emit(comment_id))}
+                                >
+                                    { t("comment-moderation-dismiss") }
+                                </button>
+                            </div>
+                        </li>
+                    }
+                }) }
+            </ul>
+        </div>
+    }
+}
@@ -0,0 +1,115 @@
+use crate::i18n::{t, t_with_args};
+use crate::services::api_client;
+use crate::utils::datetime::format_dt_local;
+use serde::Deserialize;
+use yew::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct ReceiptEntry {
+    pub user_id: u64,
+    pub name: String,
+    pub email: String,
+    pub read_at: Option<String>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct ReceiptReport {
+    pub requires_ack: bool,
+    pub audience_total: usize,
+    pub read_count: usize,
+    pub acknowledged_count: usize,
+    pub pending: Vec<ReceiptEntry>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReceiptReportPanelProps {
+    pub announcement_id: u64,
+    pub token: Option<String>,
+}
+
+/// Read/acknowledge progress of an announcement's audience, for managers.
+/// Lists the residents who still have to read or confirm it.
+#[function_component(ReceiptReportPanel)]
+pub fn receipt_report_panel(props: &ReceiptReportPanelProps) -> Html {
+    let report = use_state(|| None::<ReceiptReport>);
+    let error = use_state(|| None::<String>);
+
+    {
+        let report = report.clone();
+        let error = error.clone();
+        let token = props.token.clone();
+
+        use_effect_with(props.announcement_id, move |id| {
+            let id = *id;
+            report.set(None);
+            error.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get::<ReceiptReport>(&format!("/announcements/{}/receipts", id))
+                    .await
+                {
+                    Ok(r) => report.set(Some(r)),
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-load-failed"), e))),
+                }
+            });
+            || ()
+        });
+    }
+
+    if let Some(err) = &*error {
+        return html! {<div class="alert alert-warning py-1 small my-2">{err}</div>};
+    }
+    let Some(r) = &*report else {
+        return html! {};
+    };
+
+    let total = r.audience_total.to_string();
+    html! {
+        <div class="card my-3">
+            <div class="card-header py-2">
+                <strong class="small">{ t("announcement-receipts-heading") }</strong>
+            </div>
+            <div class="card-body py-2 small">
+                <div class="mb-2">
+                    <span class="me-3">
+                        { t_with_args("announcement-receipts-read", &[
+                            ("count", &r.read_count.to_string()),
+                            ("total", &total),
+                        ]) }
+                    </span>
+                    if r.requires_ack {
+                        <span>
+                            { t_with_args("announcement-receipts-acknowledged", &[
+                                ("count", &r.acknowledged_count.to_string()),
+                                ("total", &total),
+                            ]) }
+                        </span>
+                    }
+                </div>
+                if r.pending.is_empty() {
+                    <div class="text-success">{ t("announcement-receipts-none-pending") }</div>
+                } else {
+                    <div class="text-muted mb-1">{ t("announcement-receipts-pending") }</div>
+                    <ul class="list-unstyled mb-0">
+                        { for r.pending.iter().map(|p| html! {
+                            <li key={p.user_id}>
+                                { format!("{} <{}>", p.name, p.email) }
+                                { match &p.read_at {
+                                    Some(at) => html! {
+                                        <span class="text-muted ms-2">
+                                            { format!("{} {}", t("announcement-receipts-read-at"), format_dt_local(at)) }
+                                        </span>
+                                    },
+                                    None => html! {
+                                        <span class="badge bg-secondary ms-2">{ t("announcement-receipts-unread") }</span>
+                                    },
+                                } }
+                            </li>
+                        }) }
+                    </ul>
+                }
+            </div>
+        </div>
+    }
+}
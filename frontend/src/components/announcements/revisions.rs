@@ -0,0 +1,261 @@
+use crate::i18n::{t, t_with_args};
+use crate::services::api_client;
+use crate::utils::datetime::format_dt_local;
+use serde::Deserialize;
+use yew::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct Revision {
+    pub revision: u32,
+    pub title: String,
+    pub editor_name: String,
+    pub restored_from: Option<u32>,
+    pub created_at: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct DiffLine {
+    pub op: String,
+    pub text: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct RevisionDiff {
+    pub title_from: String,
+    pub title_to: String,
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct RevisionHistoryProps {
+    pub announcement_id: u64,
+    pub token: Option<String>,
+    /// Show restore buttons (Admin/Manager or author)
+    #[prop_or(false)]
+    pub can_restore: bool,
+    #[prop_or_default]
+    pub on_restored: Option<Callback<u64>>,
+}
+
+/// Revision list of an announcement with a line diff between any two versions.
+#[function_component(RevisionHistory)]
+pub fn revision_history(props: &RevisionHistoryProps) -> Html {
+    let revisions = use_state(|| None::<Vec<Revision>>);
+    let from = use_state(|| None::<u32>);
+    let to = use_state(|| None::<u32>);
+    let diff = use_state(|| None::<RevisionDiff>);
+    let error = use_state(|| None::<String>);
+    let reload = use_state(|| 0u32);
+
+    {
+        let revisions = revisions.clone();
+        let from = from.clone();
+        let to = to.clone();
+        let diff = diff.clone();
+        let error = error.clone();
+        let token = props.token.clone();
+
+        use_effect_with((props.announcement_id, *reload), move |(id, _)| {
+            let id = *id;
+            error.set(None);
+            diff.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get::<Vec<Revision>>(&format!("/announcements/{}/revisions", id))
+                    .await
+                {
+                    Ok(list) => {
+                        // Newest first: compare the previous version with the current one
+                        to.set(list.first().map(|r| r.revision));
+                        from.set(list.get(1).or(list.first()).map(|r| r.revision));
+                        revisions.set(Some(list));
+                    }
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-load-failed"), e))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_compare = {
+        let from = from.clone();
+        let to = to.clone();
+        let diff = diff.clone();
+        let error = error.clone();
+        let token = props.token.clone();
+        let id = props.announcement_id;
+        Callback::from(move |_: MouseEvent| {
+            let (Some(older), Some(newer)) = (*from, *to) else {
+                return;
+            };
+            let diff = diff.clone();
+            let error = error.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get::<RevisionDiff>(&format!(
+                        "/announcements/{}/revisions/diff?from={}&to={}",
+                        id, older, newer
+                    ))
+                    .await
+                {
+                    Ok(d) => diff.set(Some(d)),
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-load-failed"), e))),
+                }
+            });
+        })
+    };
+
+    let on_restore = {
+        let reload = reload.clone();
+        let error = error.clone();
+        let token = props.token.clone();
+        let on_restored = props.on_restored.clone();
+        let id = props.announcement_id;
+        Callback::from(move |revision: u32| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(&t("announcement-history-restore-confirm"))
+                        .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let reload = reload.clone();
+            let error = error.clone();
+            let token = token.clone();
+            let on_restored = on_restored.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post_empty::<serde_json::Value>(&format!(
+                        "/announcements/{}/revisions/{}/restore",
+                        id, revision
+                    ))
+                    .await
+                {
+                    Ok(_) => {
+                        reload.set(*reload + 1);
+                        if let Some(cb) = on_restored {
+                            cb.emit(id);
+                        }
+                    }
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-restore-failed"), e))),
+                }
+            });
+        })
+    };
+
+    let select_for = |state: UseStateHandle<Option<u32>>| {
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            state.set(select.value().parse::<u32>().ok());
+        })
+    };
+
+    let Some(list) = &*revisions else {
+        return match &*error {
+            Some(err) => html! {<div class="alert alert-warning py-1 small my-2">{err}</div>},
+            None => html! {},
+        };
+    };
+    let latest = list.first().map(|r| r.revision);
+    let options = |selected: Option<u32>| -> Html {
+        html! {
+            { for list.iter().map(|r| html! {
+                <option value={r.revision.to_string()} selected={selected == Some(r.revision)}>
+                    { format!("#{}", r.revision) }
+                </option>
+            }) }
+        }
+    };
+
+    html! {
+        <div class="card my-3">
+            <div class="card-header py-2">
+                <strong class="small">{ t("announcement-history-heading") }</strong>
+            </div>
+            <div class="card-body py-2 small">
+                if let Some(err) = &*error {
+                    <div class="alert alert-warning py-1 mb-2">{err}</div>
+                }
+                <ul class="list-unstyled mb-2">
+                    { for list.iter().map(|r| {
+                        let revision = r.revision;
+                        let on_restore = on_restore.clone();
+                        html! {
+                            <li key={revision} class="d-flex align-items-center gap-2 mb-1">
+                                <span class="fw-semibold">
+                                    { t_with_args("announcement-history-revision", &[("number", &revision.to_string())]) }
+                                </span>
+                                <span class="text-muted">
+                                    { format!(
+                                        "{} · {}",
+                                        format_dt_local(&r.created_at),
+                                        t_with_args("announcement-history-by", &[("name", &r.editor_name)])
+                                    ) }
+                                </span>
+                                if let Some(n) = r.restored_from {
+                                    <span class="badge bg-light text-dark border">
+                                        { t_with_args("announcement-history-restored-from", &[("number", &n.to_string())]) }
+                                    </span>
+                                }
+                                <span class="text-truncate">{ &r.title }</span>
+                                if props.can_restore && Some(revision) != latest {
+                                    <button
+                                        class="btn btn-sm btn-outline-secondary ms-auto py-0"
+                                        onclick={Callback::from(move |_| on_restore.emit(revision))}
+                                    >
+                                        { t("announcement-history-restore-btn") }
+                                    </button>
+                                }
+                            </li>
+                        }
+                    }) }
+                </ul>
+                if list.len() > 1 {
+                    <div class="d-flex align-items-center gap-2 mb-2">
+                        <label class="mb-0">{ t("announcement-history-compare-from") }</label>
+                        <select class="form-select form-select-sm w-auto" onchange={select_for(from.clone())}>
+                            { options(*from) }
+                        </select>
+                        <label class="mb-0">{ t("announcement-history-compare-to") }</label>
+                        <select class="form-select form-select-sm w-auto" onchange={select_for(to.clone())}>
+                            { options(*to) }
+                        </select>
+                        <button class="btn btn-sm btn-outline-primary" onclick={on_compare}>
+                            { t("announcement-history-compare-btn") }
+                        </button>
+                    </div>
+                }
+                if let Some(d) = &*diff {
+                    if d.title_from != d.title_to {
+                        <div class="mb-1">
+                            { t_with_args("announcement-history-title-changed", &[
+                                ("from", &d.title_from),
+                                ("to", &d.title_to),
+                            ]) }
+                        </div>
+                    }
+                    if d.lines.iter().all(|l| l.op == "equal") {
+                        <div class="text-muted">{ t("announcement-history-no-changes") }</div>
+                    } else {
+                        <pre class="border rounded p-2 mb-0 bg-light">
+                            { for d.lines.iter().map(|l| {
+                                let (class, marker) = match l.op.as_str() {
+                                    "insert" => ("text-success", "+ "),
+                                    "delete" => ("text-danger text-decoration-line-through", "- "),
+                                    _ => ("text-muted", "  "),
+                                };
+                                html! { <div class={class}>{ format!("{}{}", marker, l.text) }</div> }
+                            }) }
+                        </pre>
+                    }
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,333 @@
+use super::translations::LocalizedText;
+use crate::i18n::{t, t_with_args};
+use crate::services::api_client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use yew::prelude::*;
+
+/// Placeholders the API fills in by itself.
+const BUILTIN_PLACEHOLDERS: [&str; 4] =
+    ["building_address", "today", "publish_date", "expire_date"];
+
+#[derive(Clone, PartialEq, Deserialize)]
+pub struct TemplateDto {
+    pub id: u64,
+    pub name: String,
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
+    #[serde(default)]
+    pub translations: Vec<LocalizedText>,
+    #[serde(default)]
+    pub placeholders: Vec<String>,
+}
+
+/// A template with its placeholders filled in.
+#[derive(Clone, PartialEq, Deserialize)]
+pub struct TemplateDraft {
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
+    #[serde(default)]
+    pub translations: Vec<LocalizedText>,
+    #[serde(default)]
+    pub missing: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ApplyRequest {
+    building_id: Option<u64>,
+    publish_at: Option<String>,
+    expire_at: Option<String>,
+    values: HashMap<String, String>,
+}
+
+#[derive(Serialize)]
+struct SaveTemplateRequest {
+    name: String,
+    language: String,
+    title: String,
+    body_md: String,
+    translations: Vec<LocalizedText>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TemplatePickerProps {
+    pub token: Option<String>,
+    /// Fills `{{building_address}}`
+    pub building_id: Option<u64>,
+    /// Naive date-times filling `{{publish_date}}` and `{{expire_date}}`
+    pub publish_at: Option<String>,
+    pub expire_at: Option<String>,
+    /// Current editor content, for "save as template"
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
+    pub translations: Vec<LocalizedText>,
+    pub on_apply: Callback<TemplateDraft>,
+}
+
+/// Start an announcement from a saved template, or save the current text as one.
+#[function_component(TemplatePicker)]
+pub fn template_picker(props: &TemplatePickerProps) -> Html {
+    let templates = use_state(Vec::<TemplateDto>::new);
+    let selected = use_state(|| None::<u64>);
+    let values = use_state(HashMap::<String, String>::new);
+    let message = use_state(|| None::<(String, &'static str)>);
+    let reload = use_state(|| 0u32);
+
+    {
+        let templates = templates.clone();
+        let token = props.token.clone();
+        use_effect_with(*reload, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client
+                    .get::<Vec<TemplateDto>>("/announcements/templates")
+                    .await
+                {
+                    templates.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    let current = selected.and_then(|id| templates.iter().find(|tpl| tpl.id == id).cloned());
+
+    let on_select = {
+        let selected = selected.clone();
+        let values = values.clone();
+        let message = message.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            selected.set(select.value().parse::<u64>().ok());
+            values.set(HashMap::new());
+            message.set(None);
+        })
+    };
+
+    let on_apply = {
+        let selected = selected.clone();
+        let values = values.clone();
+        let message = message.clone();
+        let token = props.token.clone();
+        let building_id = props.building_id;
+        let publish_at = props.publish_at.clone();
+        let expire_at = props.expire_at.clone();
+        let on_apply = props.on_apply.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(id) = *selected else {
+                return;
+            };
+            let payload = ApplyRequest {
+                building_id,
+                publish_at: publish_at.clone(),
+                expire_at: expire_at.clone(),
+                values: (*values)
+                    .iter()
+                    .filter(|(_, v)| !v.trim().is_empty())
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect(),
+            };
+            let message = message.clone();
+            let on_apply = on_apply.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, TemplateDraft>(
+                        &format!("/announcements/templates/{}/apply", id),
+                        &payload,
+                    )
+                    .await
+                {
+                    Ok(draft) => {
+                        if draft.missing.is_empty() {
+                            message.set(None);
+                        } else {
+                            let names = draft
+                                .missing
+                                .iter()
+                                .map(|n| format!("{{{{{}}}}}", n))
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            message.set(Some((
+                                t_with_args("announcement-template-missing", &[("names", &names)]),
+                                "warning",
+                            )));
+                        }
+                        on_apply.emit(draft);
+                    }
+                    Err(e) => message.set(Some((
+                        format!("{}: {}", t("announcement-template-apply-failed"), e),
+                        "danger",
+                    ))),
+                }
+            });
+        })
+    };
+
+    let on_save = {
+        let message = message.clone();
+        let reload = reload.clone();
+        let selected = selected.clone();
+        let token = props.token.clone();
+        let language = props.language.clone();
+        let title = props.title.clone();
+        let body_md = props.body_md.clone();
+        let translations = props.translations.clone();
+        Callback::from(move |_: MouseEvent| {
+            if title.trim().is_empty() {
+                message.set(Some((t("announcement-title-label"), "warning")));
+                return;
+            }
+            let name = web_sys::window()
+                .and_then(|w| {
+                    w.prompt_with_message(&t("announcement-template-name-prompt"))
+                        .ok()
+                })
+                .flatten()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty());
+            let Some(name) = name else {
+                return;
+            };
+            let payload = SaveTemplateRequest {
+                name,
+                language: language.clone(),
+                title: title.clone(),
+                body_md: body_md.clone(),
+                translations: translations.clone(),
+            };
+            let message = message.clone();
+            let reload = reload.clone();
+            let selected = selected.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, TemplateDto>("/announcements/templates", &payload)
+                    .await
+                {
+                    Ok(saved) => {
+                        selected.set(Some(saved.id));
+                        message.set(Some((t("announcement-template-saved"), "success")));
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => message.set(Some((
+                        format!("{}: {}", t("announcement-template-save-failed"), e),
+                        "danger",
+                    ))),
+                }
+            });
+        })
+    };
+
+    let on_delete = {
+        let message = message.clone();
+        let reload = reload.clone();
+        let selected = selected.clone();
+        let token = props.token.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(id) = *selected else {
+                return;
+            };
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(&t("announcement-template-delete-confirm"))
+                        .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let message = message.clone();
+            let reload = reload.clone();
+            let selected = selected.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .delete_no_response(&format!("/announcements/templates/{}", id))
+                    .await
+                {
+                    Ok(_) => {
+                        selected.set(None);
+                        reload.set(*reload + 1);
+                    }
+                    Err(_) => message.set(Some((t("error-delete-failed"), "danger"))),
+                }
+            });
+        })
+    };
+
+    // Placeholders the user has to fill in themselves
+    let custom: Vec<String> = current
+        .as_ref()
+        .map(|tpl| {
+            tpl.placeholders
+                .iter()
+                .filter(|p| !BUILTIN_PLACEHOLDERS.contains(&p.as_str()))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    html! {
+        <div class="card mb-3">
+            <div class="card-header py-2 d-flex justify-content-between align-items-center gap-2">
+                <strong class="small">{ t("announcement-templates-label") }</strong>
+                <div class="d-flex gap-1">
+                    <select class="form-select form-select-sm w-auto" onchange={on_select}>
+                        <option value="" selected={selected.is_none()}>{ t("announcement-template-select") }</option>
+                        { for templates.iter().map(|tpl| html! {
+                            <option value={tpl.id.to_string()} selected={*selected == Some(tpl.id)}>{ &tpl.name }</option>
+                        }) }
+                    </select>
+                    <button type="button" class="btn btn-sm btn-outline-secondary" onclick={on_save}>
+                        { t("announcement-template-save") }
+                    </button>
+                </div>
+            </div>
+            if current.is_some() || message.is_some() {
+                <div class="card-body py-2 small">
+                    if let Some((text, level)) = &*message {
+                        <div class={classes!("alert", format!("alert-{}", level), "py-1", "mb-2")}>{ text }</div>
+                    }
+                    if current.is_some() {
+                        { for custom.into_iter().map(|name| {
+                            let values = values.clone();
+                            let value = values.get(&name).cloned().unwrap_or_default();
+                            let key = name.clone();
+                            html! {
+                                <div class="input-group input-group-sm mb-1">
+                                    <span class="input-group-text"><code>{ format!("{{{{{}}}}}", name) }</code></span>
+                                    <input
+                                        class="form-control"
+                                        value={value}
+                                        oninput={Callback::from(move |e: InputEvent| {
+                                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                            let mut map = (*values).clone();
+                                            map.insert(key.clone(), input.value());
+                                            values.set(map);
+                                        })}
+                                    />
+                                </div>
+                            }
+                        }) }
+                        <div class="text-muted mb-2">{ t("announcement-template-builtin-hint") }</div>
+                        <div class="d-flex gap-1">
+                            <button type="button" class="btn btn-sm btn-primary" onclick={on_apply}>
+                                { t("announcement-template-apply") }
+                            </button>
+                            <button type="button" class="btn btn-sm btn-outline-danger" onclick={on_delete}>
+                                <i class="bi bi-trash"></i>
+                            </button>
+                        </div>
+                    }
+                </div>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,160 @@
+use crate::i18n::{available_languages, t};
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+/// Title and body in one language.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocalizedText {
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
+}
+
+/// Every language version of an announcement, as returned for editing.
+#[derive(Clone, PartialEq, Deserialize)]
+pub struct AnnouncementVersions {
+    pub language: String,
+    pub title: String,
+    pub body_md: String,
+    #[serde(default)]
+    pub translations: Vec<LocalizedText>,
+}
+
+pub fn language_label(lang: &str) -> String {
+    t(&format!("ui-language-{}", lang))
+}
+
+#[derive(Properties, PartialEq)]
+pub struct AnnouncementTranslationsProps {
+    /// Language of the main title and body
+    pub language: String,
+    pub translations: Vec<LocalizedText>,
+    pub on_language_change: Callback<String>,
+    pub on_change: Callback<Vec<LocalizedText>>,
+}
+
+/// Language of the main text plus versions in the other languages.
+/// Readers see the version matching their language, or the main one.
+#[function_component(AnnouncementTranslations)]
+pub fn announcement_translations(props: &AnnouncementTranslationsProps) -> Html {
+    let languages = available_languages();
+    let missing: Vec<String> = languages
+        .iter()
+        .filter(|l| **l != props.language && !props.translations.iter().any(|t| &t.language == *l))
+        .cloned()
+        .collect();
+
+    // Replace the translation at `idx` with the result of `edit`
+    let update = {
+        let translations = props.translations.clone();
+        let on_change = props.on_change.clone();
+        move |idx: usize, edit: Box<dyn FnOnce(&mut LocalizedText)>| {
+            let mut list = translations.clone();
+            if let Some(tr) = list.get_mut(idx) {
+                edit(tr);
+            }
+            on_change.emit(list);
+        }
+    };
+
+    let on_language = {
+        let cb = props.on_language_change.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            cb.emit(select.value());
+        })
+    };
+
+    html! {
+        <div class="card my-3">
+            <div class="card-header py-2 d-flex justify-content-between align-items-center">
+                <strong class="small">{ t("announcement-languages-label") }</strong>
+                <div class="d-flex align-items-center gap-2 small">
+                    <label class="mb-0" for="announcementLanguage">{ t("announcement-main-language") }</label>
+                    <select id="announcementLanguage" class="form-select form-select-sm w-auto" onchange={on_language}>
+                        // A language that already has a translation can't also be the main one
+                        { for languages.iter().filter(|l| !props.translations.iter().any(|t| &t.language == *l)).map(|l| html! {
+                            <option value={l.clone()} selected={*l == props.language}>{ language_label(l) }</option>
+                        }) }
+                    </select>
+                </div>
+            </div>
+            <div class="card-body py-2 small">
+                { for props.translations.iter().enumerate().map(|(idx, tr)| {
+                    let on_title = {
+                        let update = update.clone();
+                        Callback::from(move |e: InputEvent| {
+                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                            update(idx, Box::new(move |tr| tr.title = input.value()));
+                        })
+                    };
+                    let on_body = {
+                        let update = update.clone();
+                        Callback::from(move |e: InputEvent| {
+                            let area: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+                            update(idx, Box::new(move |tr| tr.body_md = area.value()));
+                        })
+                    };
+                    let on_remove = {
+                        let translations = props.translations.clone();
+                        let on_change = props.on_change.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            let mut list = translations.clone();
+                            list.remove(idx);
+                            on_change.emit(list);
+                        })
+                    };
+                    html! {
+                        <div key={tr.language.clone()} class="border rounded p-2 mb-2">
+                            <div class="d-flex justify-content-between align-items-center mb-1">
+                                <span class="badge bg-secondary">{ language_label(&tr.language) }</span>
+                                <button type="button" class="btn btn-sm btn-outline-danger py-0" onclick={on_remove}>
+                                    <i class="bi bi-trash"></i>
+                                </button>
+                            </div>
+                            <input
+                                class="form-control form-control-sm mb-1"
+                                placeholder={ t("announcement-title-label") }
+                                value={tr.title.clone()}
+                                oninput={on_title}
+                            />
+                            <textarea
+                                class="form-control form-control-sm"
+                                rows=5
+                                placeholder={ t("announcement-body-label") }
+                                value={tr.body_md.clone()}
+                                oninput={on_body}
+                            />
+                        </div>
+                    }
+                }) }
+                if props.translations.is_empty() {
+                    <div class="text-muted mb-2">{ t("announcement-translations-empty") }</div>
+                }
+                { for missing.into_iter().map(|lang| {
+                    let translations = props.translations.clone();
+                    let on_change = props.on_change.clone();
+                    let label = language_label(&lang);
+                    html! {
+                        <button
+                            type="button"
+                            class="btn btn-sm btn-outline-primary me-1"
+                            onclick={Callback::from(move |_: MouseEvent| {
+                                let mut list = translations.clone();
+                                list.push(LocalizedText {
+                                    language: lang.clone(),
+                                    title: String::new(),
+                                    body_md: String::new(),
+                                });
+                                on_change.emit(list);
+                            })}
+                        >
+                            <i class="bi bi-plus"></i>
+                            { format!(" {}", label) }
+                        </button>
+                    }
+                }) }
+            </div>
+        </div>
+    }
+}
@@ -1,6 +1,6 @@
 use crate::contexts::{AuthContext, User};
 use crate::services::{ApiError, api_client};
-use base64::{Engine as _, engine::general_purpose};
+use crate::utils::auth::decode_token;
 use serde::{Deserialize, Serialize};
 use yew::prelude::*;
 
@@ -9,16 +9,6 @@ struct LoginResponse {
     token: String,
 }
 
-#[derive(Deserialize, Clone, Debug)]
-struct JwtClaims {
-    sub: String,
-    email: String,
-    name: String,
-    roles: Vec<String>,
-    #[allow(dead_code)]
-    exp: usize,
-}
-
 #[derive(Serialize)]
 struct LoginRequest {
     email: String,
@@ -99,7 +89,7 @@ pub fn auth_dropdown() -> Html {
                             {
                                 Ok(resp) => {
                                     // Decode JWT to get user info
-                                    if let Some(user) = decode_jwt_claims(&resp.token) {
+                                    if let Some(user) = decode_token(&resp.token).map(User::from) {
                                         auth.login.emit((resp.token, user));
                                         if let Some(w) = web_sys::window() {
                                             let _ = w.location().reload();
@@ -134,7 +124,7 @@ pub fn auth_dropdown() -> Html {
                     {
                         Ok(resp) => {
                             // Decode JWT to get user info
-                            if let Some(user) = decode_jwt_claims(&resp.token) {
+                            if let Some(user) = decode_token(&resp.token).map(User::from) {
                                 auth.login.emit((resp.token, user));
                                 if let Some(w) = web_sys::window() {
                                     let _ = w.location().reload();
@@ -231,49 +221,3 @@ pub fn auth_dropdown() -> Html {
         </form>
     }
 }
-
-// Helper function to decode JWT and extract user info
-fn decode_jwt_claims(token: &str) -> Option<User> {
-    // JWT format: header.payload.signature
-    let parts: Vec<&str> = token.split('.').collect();
-    if parts.len() != 3 {
-        return None;
-    }
-
-    // Decode the payload (second part)
-    let payload = parts[1];
-
-    // Add padding if needed (base64url requires it)
-    let padding = match payload.len() % 4 {
-        0 => "",
-        2 => "==",
-        3 => "=",
-        _ => return None,
-    };
-    let padded = format!("{}{}", payload, padding);
-
-    // Decode from base64url (replace - with + and _ with /)
-    let normalized = padded.replace('-', "+").replace('_', "/");
-
-    let decoded = match general_purpose::STANDARD.decode(&normalized) {
-        Ok(d) => d,
-        Err(_) => return None,
-    };
-
-    let json_str = match String::from_utf8(decoded) {
-        Ok(s) => s,
-        Err(_) => return None,
-    };
-
-    let claims: JwtClaims = match serde_json::from_str(&json_str) {
-        Ok(c) => c,
-        Err(_) => return None,
-    };
-
-    Some(User {
-        id: claims.sub.parse().unwrap_or(0),
-        email: claims.email,
-        name: claims.name,
-        roles: claims.roles,
-    })
-}
@@ -26,7 +26,7 @@ pub fn breadcrumb(props: &BreadcrumbProps) -> Html {
                     let is_last = idx == props.items.len() - 1;
 
                     html! {
-                        <li class={classes!("breadcrumb-item", is_last.then(|| "active"))}>
+                        <li class={classes!("breadcrumb-item", is_last.then_some("active"))}>
                             if let Some(route) = &item.route {
                                 <Link<Route> to={route.clone()}>
                                     {&item.label}
@@ -1,26 +1,130 @@
 use crate::components::spinner::Spinner;
 use crate::contexts::AuthContext;
-use crate::i18n::t;
+use crate::i18n::{t, t_with_args};
 use crate::services::api_client;
 use crate::utils::datetime::format_dt_local;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use yew::prelude::*;
 
+/// Reactions offered by the API, in display order.
+const REACTIONS: [&str; 6] = ["👍", "❤️", "😂", "😮", "😢", "🎉"];
+
+/// Replies deeper than this are shown at the same indentation.
+const MAX_INDENT: usize = 3;
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct ReactionDto {
+    pub emoji: String,
+    pub count: i64,
+    pub reacted: bool,
+}
+
 #[derive(Deserialize, Clone, PartialEq, Debug)]
 pub struct CommentDto {
     pub id: u64,
     pub announcement_id: u64,
+    #[serde(default)]
+    pub parent_id: Option<u64>,
     pub user_id: u64,
     pub user_name: String,
     pub body_html: String,
     pub body_md: String,
     pub created_at: Option<String>,
     pub is_deleted: bool,
+    #[serde(default)]
+    pub is_hidden: bool,
+    #[serde(default)]
+    pub hidden_reason: Option<String>,
+    #[serde(default)]
+    pub edited_at: Option<String>,
+    #[serde(default)]
+    pub reactions: Vec<ReactionDto>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct MentionableUser {
+    user_id: u64,
+    name: String,
 }
 
 #[derive(Serialize)]
 struct PostCommentRequest {
     body_md: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct UpdateCommentRequest {
+    body_md: String,
+}
+
+#[derive(Serialize)]
+struct ReactionRequest {
+    emoji: String,
+}
+
+#[derive(Serialize)]
+struct ReasonRequest {
+    reason: String,
+}
+
+/// Everything that can be done to an existing comment; each reloads the list on success.
+enum CommentAction {
+    Edit(String),
+    React(String),
+    Report(String),
+    Hide(String),
+    Unhide,
+    Delete,
+    Restore,
+    Purge,
+}
+
+/// Comments in thread order (each reply right after its parent) with their depth.
+/// Replies whose parent isn't in the list are shown as top-level comments.
+fn thread_order(comments: &[CommentDto]) -> Vec<(CommentDto, usize)> {
+    let ids: HashSet<u64> = comments.iter().map(|c| c.id).collect();
+    let mut children: HashMap<Option<u64>, Vec<&CommentDto>> = HashMap::new();
+    for c in comments {
+        let parent = c.parent_id.filter(|p| ids.contains(p));
+        children.entry(parent).or_default().push(c);
+    }
+    let mut out = Vec::with_capacity(comments.len());
+    let mut stack: Vec<(&CommentDto, usize)> = children
+        .get(&None)
+        .map(|roots| roots.iter().rev().map(|c| (*c, 0)).collect())
+        .unwrap_or_default();
+    while let Some((c, depth)) = stack.pop() {
+        out.push((c.clone(), depth));
+        if let Some(replies) = children.get(&Some(c.id)) {
+            stack.extend(replies.iter().rev().map(|r| (*r, depth + 1)));
+        }
+    }
+    out
+}
+
+/// The `@query` being typed at the end of `text`, if any.
+fn mention_query(text: &str) -> Option<&str> {
+    let at = text.rfind('@')?;
+    if text[..at]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric())
+    {
+        return None;
+    }
+    let query = &text[at + 1..];
+    (!query.contains('\n') && query.chars().count() <= 30).then_some(query)
+}
+
+fn prompt(message: &str, default: &str) -> Option<String> {
+    web_sys::window()
+        .and_then(|w| w.prompt_with_message_and_default(message, default).ok())
+        .flatten()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
 }
 
 #[derive(Properties, PartialEq)]
@@ -37,16 +141,23 @@ pub fn comment_list(props: &CommentListProps) -> Html {
 
     let auth = use_context::<AuthContext>().expect("AuthContext not found");
     let loading = use_state(|| false);
-    let comments = use_state(|| Vec::<CommentDto>::new());
+    let comments = use_state(Vec::<CommentDto>::new);
     let error = use_state(|| None::<String>);
+    let notice = use_state(|| None::<String>);
     let posting = use_state(|| false);
     let new_md = use_state(String::default);
     let show_deleted = use_state(|| false);
+    let reload = use_state(|| 0u32);
+    let reply_to = use_state(|| None::<CommentDto>);
+    let editing = use_state(|| None::<(u64, String)>);
+    let picker_for = use_state(|| None::<u64>);
+    let mentionable = use_state(Vec::<MentionableUser>::new);
     let can_post = auth.is_authenticated();
     let is_manager = auth.is_admin_or_manager();
+    let current_user_id = auth.user().map(|u| u.id);
     let token = auth.token().map(|t| t.to_string());
 
-    // Load comments when announcement_id or show_deleted changes
+    // Load comments when announcement_id or show_deleted changes, or after an action
     {
         let loading = loading.clone();
         let comments_state = comments.clone();
@@ -55,7 +166,7 @@ pub fn comment_list(props: &CommentListProps) -> Html {
         let show_deleted_val = *show_deleted;
         let token = token.clone();
 
-        use_effect_with((props.announcement_id, *show_deleted), move |_| {
+        use_effect_with((props.announcement_id, *show_deleted, *reload), move |_| {
             loading.set(true);
             error_state.set(None);
 
@@ -80,15 +191,39 @@ pub fn comment_list(props: &CommentListProps) -> Html {
         });
     }
 
-    // Post comment
+    // Load the people that can be @mentioned
+    {
+        let mentionable = mentionable.clone();
+        let token = token.clone();
+        use_effect_with((props.announcement_id, can_post), move |(id, can_post)| {
+            let id = *id;
+            if *can_post {
+                wasm_bindgen_futures::spawn_local(async move {
+                    let client = api_client(token.as_deref());
+                    if let Ok(list) = client
+                        .get::<Vec<MentionableUser>>(&format!(
+                            "/announcements/{}/comments/mentionable",
+                            id
+                        ))
+                        .await
+                    {
+                        mentionable.set(list);
+                    }
+                });
+            }
+            || ()
+        });
+    }
+
+    // Post comment or reply
     let on_post = {
         let new_md = new_md.clone();
-        let comments_state = comments.clone();
+        let reply_to = reply_to.clone();
+        let reload = reload.clone();
         let posting_state = posting.clone();
         let error_state = error.clone();
         let ann_id = props.announcement_id;
         let token = token.clone();
-        let show_deleted_val = *show_deleted;
 
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
@@ -100,16 +235,19 @@ pub fn comment_list(props: &CommentListProps) -> Html {
             posting_state.set(true);
             error_state.set(None);
 
-            let body_val = (*new_md).clone();
+            let payload = PostCommentRequest {
+                body_md: (*new_md).clone(),
+                parent_id: (*reply_to).as_ref().map(|c| c.id),
+            };
             let new_md2 = new_md.clone();
-            let comments_state2 = comments_state.clone();
+            let reply_to2 = reply_to.clone();
+            let reload2 = reload.clone();
             let posting_state2 = posting_state.clone();
             let error_state2 = error_state.clone();
             let token2 = token.clone();
 
             wasm_bindgen_futures::spawn_local(async move {
                 let client = api_client(token2.as_deref());
-                let payload = PostCommentRequest { body_md: body_val };
                 let endpoint = format!("/announcements/{}/comments", ann_id);
 
                 match client
@@ -117,17 +255,9 @@ pub fn comment_list(props: &CommentListProps) -> Html {
                     .await
                 {
                     Ok(_) => {
-                        // Reload comments after successful post
-                        let reload_endpoint = if show_deleted_val {
-                            format!("/announcements/{}/comments?include_deleted=true", ann_id)
-                        } else {
-                            format!("/announcements/{}/comments", ann_id)
-                        };
-
-                        if let Ok(list) = client.get::<Vec<CommentDto>>(&reload_endpoint).await {
-                            comments_state2.set(list);
-                        }
                         new_md2.set(String::new());
+                        reply_to2.set(None);
+                        reload2.set(*reload2 + 1);
                     }
                     Err(_) => error_state2.set(Some(t("comment-post-failed"))),
                 }
@@ -136,186 +266,319 @@ pub fn comment_list(props: &CommentListProps) -> Html {
         })
     };
 
-    // Delete comment
-    let delete_comment = {
-        let comments_state = comments.clone();
+    let on_action = {
+        let reload = reload.clone();
+        let editing = editing.clone();
         let error_state = error.clone();
-        let ann_id_for_delete = props.announcement_id;
+        let notice = notice.clone();
         let token = token.clone();
-        let show_deleted_val = *show_deleted;
 
-        Callback::from(move |comment_id: u64| {
-            let comments_state2 = comments_state.clone();
-            let error_state2 = error_state.clone();
-            let token2 = token.clone();
+        Callback::from(move |(comment_id, action): (u64, CommentAction)| {
+            let reload = reload.clone();
+            let editing = editing.clone();
+            let error_state = error_state.clone();
+            let notice = notice.clone();
+            let token = token.clone();
+            error_state.set(None);
+            notice.set(None);
 
             wasm_bindgen_futures::spawn_local(async move {
-                let client = api_client(token2.as_deref());
-                let delete_endpoint = format!("/announcements/comments/{}", comment_id);
-
-                match client.delete_no_response(&delete_endpoint).await {
-                    Ok(_) => {}
-                    Err(_) => {
-                        error_state2.set(Some(t("comment-delete-failed")));
-                        return;
-                    }
-                }
-
-                // Reload comments
-                let reload_endpoint = if show_deleted_val {
-                    format!(
-                        "/announcements/{}/comments?include_deleted=true",
-                        ann_id_for_delete
-                    )
-                } else {
-                    format!("/announcements/{}/comments", ann_id_for_delete)
+                let client = api_client(token.as_deref());
+                let base = format!("/announcements/comments/{}", comment_id);
+                let is_report = matches!(action, CommentAction::Report(_));
+                let is_edit = matches!(action, CommentAction::Edit(_));
+                let (result, failed_key) = match action {
+                    CommentAction::Edit(body_md) => (
+                        client
+                            .put::<_, serde_json::Value>(&base, &UpdateCommentRequest { body_md })
+                            .await
+                            .map(|_| ()),
+                        "comment-edit-failed",
+                    ),
+                    CommentAction::React(emoji) => (
+                        client
+                            .post::<_, serde_json::Value>(
+                                &format!("{}/reactions", base),
+                                &ReactionRequest { emoji },
+                            )
+                            .await
+                            .map(|_| ()),
+                        "comment-react-failed",
+                    ),
+                    CommentAction::Report(reason) => (
+                        client
+                            .post::<_, serde_json::Value>(
+                                &format!("{}/report", base),
+                                &ReasonRequest { reason },
+                            )
+                            .await
+                            .map(|_| ()),
+                        "comment-report-failed",
+                    ),
+                    CommentAction::Hide(reason) => (
+                        client
+                            .post::<_, serde_json::Value>(
+                                &format!("{}/hide", base),
+                                &ReasonRequest { reason },
+                            )
+                            .await
+                            .map(|_| ()),
+                        "comment-hide-failed",
+                    ),
+                    CommentAction::Unhide => (
+                        client
+                            .post_empty::<serde_json::Value>(&format!("{}/unhide", base))
+                            .await
+                            .map(|_| ()),
+                        "comment-hide-failed",
+                    ),
+                    CommentAction::Delete => (
+                        client.delete_no_response(&base).await,
+                        "comment-delete-failed",
+                    ),
+                    CommentAction::Restore => (
+                        client
+                            .post_empty::<serde_json::Value>(&format!("{}/restore", base))
+                            .await
+                            .map(|_| ()),
+                        "comment-restore-failed",
+                    ),
+                    CommentAction::Purge => (
+                        client.delete_no_response(&format!("{}/purge", base)).await,
+                        "comment-purge-failed",
+                    ),
                 };
-
-                if let Ok(list) = client.get::<Vec<CommentDto>>(&reload_endpoint).await {
-                    comments_state2.set(list);
-                }
-            });
-        })
-    };
-
-    // Restore comment
-    let restore_comment = {
-        let comments_state = comments.clone();
-        let error_state = error.clone();
-        let ann_id_for_restore = props.announcement_id;
-        let token = token.clone();
-
-        Callback::from(move |comment_id: u64| {
-            let comments_state2 = comments_state.clone();
-            let error_state2 = error_state.clone();
-            let token2 = token.clone();
-
-            wasm_bindgen_futures::spawn_local(async move {
-                let client = api_client(token2.as_deref());
-                let restore_endpoint = format!("/announcements/comments/{}/restore", comment_id);
-
-                match client
-                    .post_empty::<serde_json::Value>(&restore_endpoint)
-                    .await
-                {
-                    Ok(_) => {}
-                    Err(_) => {
-                        error_state2.set(Some(t("comment-restore-failed")));
-                        return;
-                    }
-                }
-
-                // Reload comments with deleted items visible
-                let reload_endpoint = format!(
-                    "/announcements/{}/comments?include_deleted=true",
-                    ann_id_for_restore
-                );
-
-                if let Ok(list) = client.get::<Vec<CommentDto>>(&reload_endpoint).await {
-                    comments_state2.set(list);
-                }
-            });
-        })
-    };
-
-    // Purge comment
-    let purge_comment = {
-        let comments_state = comments.clone();
-        let error_state = error.clone();
-        let ann_id_for_purge = props.announcement_id;
-        let token = token.clone();
-
-        Callback::from(move |comment_id: u64| {
-            let comments_state2 = comments_state.clone();
-            let error_state2 = error_state.clone();
-            let token2 = token.clone();
-
-            wasm_bindgen_futures::spawn_local(async move {
-                let client = api_client(token2.as_deref());
-                let purge_endpoint = format!("/announcements/comments/{}/purge", comment_id);
-
-                match client.delete_no_response(&purge_endpoint).await {
-                    Ok(_) => {}
-                    Err(_) => {
-                        error_state2.set(Some(t("comment-purge-failed")));
-                        return;
+                match result {
+                    Ok(_) => {
+                        if is_report {
+                            notice.set(Some(t("comment-report-sent")));
+                        }
+                        if is_edit {
+                            editing.set(None);
+                        }
+                        reload.set(*reload + 1);
                     }
-                }
-
-                // Reload comments with deleted items visible
-                let reload_endpoint = format!(
-                    "/announcements/{}/comments?include_deleted=true",
-                    ann_id_for_purge
-                );
-
-                if let Ok(list) = client.get::<Vec<CommentDto>>(&reload_endpoint).await {
-                    comments_state2.set(list);
+                    Err(_) => error_state.set(Some(t(failed_key))),
                 }
             });
         })
     };
 
-    let comments_view: Html = if *loading {
+    let comments_view: Html = if *loading && comments.is_empty() {
         html! {<Spinner center={true} />}
     } else if comments.is_empty() {
         html! {<div class="text-muted small">{ t("comments-empty") }</div>}
     } else {
-        let rendered: Vec<Html> = comments.iter().cloned().map(|c| {
-            let del_cb = delete_comment.clone();
-            let res_cb = restore_comment.clone();
-            let pur_cb = purge_comment.clone();
-            html! {<div class="mb-2">
+        let rendered: Vec<Html> = thread_order(&comments).into_iter().map(|(c, depth)| {
+            let id = c.id;
+            let is_author = current_user_id == Some(c.user_id);
+            let indent = format!("margin-left: {}rem;", depth.min(MAX_INDENT) * 2);
+            let act = |action: fn() -> CommentAction| {
+                let on_action = on_action.clone();
+                Callback::from(move |_: MouseEvent| on_action.emit((id, action())))
+            };
+
+            let body = if c.is_hidden {
+                html!{<>
+                    <div class="text-muted fst-italic small">
+                        <i class="bi bi-eye-slash me-1"></i>
+                        { t_with_args("comment-hidden-notice", &[
+                            ("reason", c.hidden_reason.as_deref().unwrap_or_default()),
+                        ]) }
+                    </div>
+                    if !c.body_html.is_empty() {
+                        <div class="opacity-50">{ Html::from_html_unchecked(c.body_html.clone().into()) }</div>
+                    }
+                </>}
+            } else if (*editing).as_ref().is_some_and(|(eid, _)| *eid == id) {
+                let draft = (*editing).as_ref().map(|(_, md)| md.clone()).unwrap_or_default();
+                let on_input = {
+                    let editing = editing.clone();
+                    Callback::from(move |e: InputEvent| {
+                        let i: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+                        editing.set(Some((id, i.value())));
+                    })
+                };
+                let on_save = {
+                    let on_action = on_action.clone();
+                    let draft = draft.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        if !draft.trim().is_empty() {
+                            on_action.emit((id, CommentAction::Edit(draft.clone())));
+                        }
+                    })
+                };
+                let on_cancel = {
+                    let editing = editing.clone();
+                    Callback::from(move |_: MouseEvent| editing.set(None))
+                };
+                html!{<>
+                    <textarea class="form-control form-control-sm" rows=3 value={draft} oninput={on_input} />
+                    <div class="mt-1 d-flex gap-1">
+                        <button class="btn btn-sm btn-primary" onclick={on_save}>{ t("comment-save-button") }</button>
+                        <button class="btn btn-sm btn-outline-secondary" onclick={on_cancel}>{ t("comment-cancel-button") }</button>
+                    </div>
+                </>}
+            } else {
+                Html::from_html_unchecked(c.body_html.clone().into())
+            };
+
+            let reactions = {
+                let can_react = can_post && !c.is_deleted && !c.is_hidden;
+                let picker_open = *picker_for == Some(id);
+                let toggle_picker = {
+                    let picker_for = picker_for.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        picker_for.set(if picker_open { None } else { Some(id) });
+                    })
+                };
+                let react = |emoji: &str| {
+                    let on_action = on_action.clone();
+                    let picker_for = picker_for.clone();
+                    let emoji = emoji.to_string();
+                    Callback::from(move |_: MouseEvent| {
+                        picker_for.set(None);
+                        on_action.emit((id, CommentAction::React(emoji.clone())));
+                    })
+                };
+                html!{
+                    <div class="d-flex flex-wrap align-items-center gap-1 mt-1">
+                        { for c.reactions.iter().map(|r| html!{
+                            <button
+                                class={classes!("btn", "btn-sm", "py-0", if r.reacted {"btn-primary"} else {"btn-outline-secondary"})}
+                                disabled={!can_react}
+                                onclick={react(&r.emoji)}
+                            >
+                                { format!("{} {}", r.emoji, r.count) }
+                            </button>
+                        }) }
+                        if can_react {
+                            <button
+                                class="btn btn-sm btn-link py-0 text-muted"
+                                title={ t("comment-react-button") }
+                                onclick={toggle_picker}
+                            >
+                                <i class="bi bi-emoji-smile"></i>
+                            </button>
+                            if picker_open {
+                                <span class="border rounded px-1">
+                                    { for REACTIONS.iter().map(|e| html!{
+                                        <button class="btn btn-sm btn-link py-0 text-decoration-none" onclick={react(e)}>
+                                            { *e }
+                                        </button>
+                                    }) }
+                                </span>
+                            }
+                        }
+                    </div>
+                }
+            };
+
+            let on_reply = {
+                let reply_to = reply_to.clone();
+                let c = c.clone();
+                Callback::from(move |_: MouseEvent| reply_to.set(Some(c.clone())))
+            };
+            let on_edit = {
+                let editing = editing.clone();
+                let md = c.body_md.clone();
+                Callback::from(move |_: MouseEvent| editing.set(Some((id, md.clone()))))
+            };
+            let on_report = {
+                let on_action = on_action.clone();
+                Callback::from(move |_: MouseEvent| {
+                    if let Some(reason) = prompt(&t("comment-report-prompt"), "") {
+                        on_action.emit((id, CommentAction::Report(reason)));
+                    }
+                })
+            };
+            let on_hide = {
+                let on_action = on_action.clone();
+                Callback::from(move |_: MouseEvent| {
+                    if let Some(reason) = prompt(&t("comment-hide-prompt"), "") {
+                        on_action.emit((id, CommentAction::Hide(reason)));
+                    }
+                })
+            };
+            let active = !c.is_deleted;
+
+            html!{<div key={id} class="mb-2" style={indent}>
                 <div class={classes!("p-2","border","rounded", if c.is_deleted {"bg-light"} else {"bg-white"})}>
-                    { Html::from_html_unchecked(c.body_html.clone().into()) }
+                    { body }
                     { if c.is_deleted { html!{<span class="badge bg-danger ms-2">{ t("comment-deleted-badge") }</span>} } else { html!{} } }
+                    { reactions }
                 </div>
                 <div class="d-flex justify-content-between small text-muted">
                     <span>
                         {c.created_at.clone().map(|s| format_dt_local(&s)).unwrap_or_default()}
                         {" • "}
                         {c.user_name.clone()}
+                        if let Some(edited) = &c.edited_at {
+                            <span class="ms-1" title={format_dt_local(edited)}>{ t("comment-edited-marker") }</span>
+                        }
                     </span>
-                    { if is_manager {
-                        html!{
-                            <div class="btn-group btn-group-sm">
-                                { if !c.is_deleted {
-                                    html!{
-                                        <button
-                                            class="btn btn-outline-danger"
-                                            onclick={Callback::from(move |_| del_cb.emit(c.id))}
-                                        >
-                                            { t("comment-delete-button") }
-                                        </button>
-                                    }
-                                } else {
-                                    html!{
-                                        <>
-                                            <button
-                                                class="btn btn-outline-success"
-                                                onclick={Callback::from(move |_| res_cb.emit(c.id))}
-                                            >
-                                                { t("comment-restore-button") }
-                                            </button>
-                                            <button
-                                                class="btn btn-outline-danger"
-                                                onclick={Callback::from(move |_| pur_cb.emit(c.id))}
-                                            >
-                                                { t("comment-purge-button") }
-                                            </button>
-                                        </>
-                                    }
-                                } }
-                            </div>
+                    <div class="btn-group btn-group-sm">
+                        if can_post && active && !c.is_hidden {
+                            <button class="btn btn-link btn-sm py-0" onclick={on_reply}>
+                                { t("comment-reply-button") }
+                            </button>
                         }
-                    } else {
-                        html!{<></>}
-                    } }
+                        if is_author && active && !c.is_hidden {
+                            <button class="btn btn-link btn-sm py-0" onclick={on_edit}>
+                                { t("comment-edit-button") }
+                            </button>
+                        }
+                        if can_post && !is_author && active && !c.is_hidden {
+                            <button class="btn btn-link btn-sm py-0 text-muted" onclick={on_report}>
+                                { t("comment-report-button") }
+                            </button>
+                        }
+                        if is_manager && active {
+                            if c.is_hidden {
+                                <button class="btn btn-outline-secondary" onclick={act(|| CommentAction::Unhide)}>
+                                    { t("comment-unhide-button") }
+                                </button>
+                            } else {
+                                <button class="btn btn-outline-secondary" onclick={on_hide}>
+                                    { t("comment-hide-button") }
+                                </button>
+                            }
+                        }
+                        if is_manager {
+                            if active {
+                                <button class="btn btn-outline-danger" onclick={act(|| CommentAction::Delete)}>
+                                    { t("comment-delete-button") }
+                                </button>
+                            } else {
+                                <button class="btn btn-outline-success" onclick={act(|| CommentAction::Restore)}>
+                                    { t("comment-restore-button") }
+                                </button>
+                                <button class="btn btn-outline-danger" onclick={act(|| CommentAction::Purge)}>
+                                    { t("comment-purge-button") }
+                                </button>
+                            }
+                        }
+                    </div>
                 </div>
             </div>}
         }).collect();
         html! { { for rendered } }
     };
 
+    // Names matching an `@` being typed in the new comment
+    let suggestions: Vec<MentionableUser> = mention_query(&new_md)
+        .map(|q| {
+            let q = q.to_lowercase();
+            mentionable
+                .iter()
+                .filter(|u| Some(u.user_id) != current_user_id)
+                .filter(|u| u.name.to_lowercase().starts_with(&q))
+                .take(5)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
     html! {
         <div class="comment-section mt-3">
             <h6 class="border-bottom pb-1">{ t("comments-heading") }</h6>
@@ -325,6 +588,9 @@ pub fn comment_list(props: &CommentListProps) -> Html {
             } else {
                 html!{<></>}
             } }
+            if let Some(msg) = &*notice {
+                <div class="alert alert-info py-1">{msg}</div>
+            }
 
             { if is_manager {
                 let sd=show_deleted.clone();
@@ -355,6 +621,21 @@ pub fn comment_list(props: &CommentListProps) -> Html {
                 let s=new_md.clone();
                 html!{
                     <form class="mt-2" onsubmit={on_post}>
+                        if let Some(parent) = &*reply_to {
+                            <div class="small text-muted mb-1">
+                                { t_with_args("comment-replying-to", &[("name", &parent.user_name)]) }
+                                <button
+                                    type="button"
+                                    class="btn btn-link btn-sm py-0"
+                                    onclick={{
+                                        let reply_to = reply_to.clone();
+                                        Callback::from(move |_: MouseEvent| reply_to.set(None))
+                                    }}
+                                >
+                                    { t("comment-cancel-button") }
+                                </button>
+                            </div>
+                        }
                         <textarea
                             class="form-control form-control-sm"
                             rows=3
@@ -365,7 +646,29 @@ pub fn comment_list(props: &CommentListProps) -> Html {
                                 s.set(i.value());
                             })}
                         />
-                        <div class="mt-1">
+                        if !suggestions.is_empty() {
+                            <div class="list-group list-group-flush border rounded small">
+                                { for suggestions.into_iter().map(|u| {
+                                    let new_md = new_md.clone();
+                                    let name = u.name.clone();
+                                    html!{
+                                        <button
+                                            type="button"
+                                            key={u.user_id}
+                                            class="list-group-item list-group-item-action py-1"
+                                            onclick={Callback::from(move |_: MouseEvent| {
+                                                if let Some(at) = new_md.rfind('@') {
+                                                    new_md.set(format!("{}@{} ", &new_md[..at], name));
+                                                }
+                                            })}
+                                        >
+                                            { format!("@{}", u.name) }
+                                        </button>
+                                    }
+                                }) }
+                            </div>
+                        }
+                        <div class="mt-1 d-flex align-items-center gap-2">
                             <button class="btn btn-sm btn-secondary" disabled={*posting} type="submit">
                                 { if *posting {
                                     html!{<Spinner small={true} color={"light"} />}
@@ -373,6 +676,7 @@ pub fn comment_list(props: &CommentListProps) -> Html {
                                     html!{ t("comment-post-button") }
                                 } }
                             </button>
+                            <span class="small text-muted">{ t("comment-mention-hint") }</span>
                         </div>
                     </form>
                 }
@@ -43,7 +43,7 @@ pub struct CheckboxProps {
 /// Reusable checkbox component with label, validation, and switch variant
 ///
 /// # Example
-/// ```rust
+/// ```ignore
 /// html! {
 ///     <Checkbox
 ///         id="checkbox-homeowner"
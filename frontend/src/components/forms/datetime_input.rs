@@ -53,7 +53,7 @@ pub struct DateTimeInputProps {
 /// Reusable date/time input component with label, validation, and help text
 ///
 /// # Example
-/// ```rust
+/// ```ignore
 /// html! {
 ///     <DateTimeInput
 ///         label="Start Time"
@@ -67,7 +67,7 @@ pub struct DateTimeInputProps {
 /// ```
 ///
 /// # Helper function for default datetime
-/// ```rust
+/// ```ignore
 /// use yew::prelude::*;
 ///
 /// // Get current datetime as string
@@ -25,7 +25,7 @@ pub struct FormGroupProps {
 /// Wrapper component for grouping related form fields with consistent spacing
 ///
 /// # Example
-/// ```rust
+/// ```ignore
 /// html! {
 ///     <form>
 ///         <FormGroup
@@ -57,7 +57,7 @@ pub struct NumberInputProps {
 /// Reusable number input component with label, validation, and constraints
 ///
 /// # Example
-/// ```rust
+/// ```ignore
 /// html! {
 ///     <NumberInput
 ///         label="Construction Year"
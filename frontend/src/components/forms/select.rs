@@ -70,7 +70,7 @@ pub struct SelectProps {
 /// Reusable select dropdown component with label, validation, and help text
 ///
 /// # Example
-/// ```rust
+/// ```ignore
 /// let options = vec![
 ///     SelectOption::new("simple", "Simple Majority"),
 ///     SelectOption::new("weighted", "Weighted by Area"),
@@ -49,7 +49,7 @@ pub struct TextInputProps {
 /// Reusable text input component with label, validation, and help text
 ///
 /// # Example
-/// ```rust
+/// ```ignore
 /// html! {
 ///     <TextInput
 ///         label="Email Address"
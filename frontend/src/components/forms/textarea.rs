@@ -57,7 +57,7 @@ pub struct TextareaProps {
 /// Reusable textarea component with label, validation, and character counter
 ///
 /// # Example
-/// ```rust
+/// ```ignore
 /// html! {
 ///     <Textarea
 ///         label="Description"
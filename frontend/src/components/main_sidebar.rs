@@ -51,6 +51,16 @@ pub fn main_sidebar(props: &MainSidebarProps) -> Html {
                         <i class="bi bi-check2-square me-2"></i>{t("nav-voting")}
                     </Link<Route>>
                 </li>
+                <li class="nav-item">
+                    <Link<Route> to={Route::Polls} classes={classes!("nav-link", is_active(&Route::Polls))}>
+                        <i class="bi bi-ui-checks me-2"></i>{t("nav-polls")}
+                    </Link<Route>>
+                </li>
+                <li class="nav-item">
+                    <Link<Route> to={Route::Meetings} classes={classes!("nav-link", is_active(&Route::Meetings))}>
+                        <i class="bi bi-calendar-event me-2"></i>{t("nav-meetings")}
+                    </Link<Route>>
+                </li>
                 <li class="nav-item">
                     <Link<Route> to={Route::MyProperties} classes={classes!("nav-link", is_active(&Route::MyProperties))}>
                         <i class="bi bi-house me-2"></i>{t("nav-my-properties")}
@@ -86,6 +96,28 @@ pub fn main_sidebar(props: &MainSidebarProps) -> Html {
                             <i class="bi bi-speedometer2 me-2"></i>{t("nav-admin-meters")}
                         </Link<Route>>
                     </li>
+                    <li class="nav-item">
+                        <Link<Route> to={Route::MeterSubmissions} classes={classes!("nav-link", is_active(&Route::MeterSubmissions))}>
+                            <i class="bi bi-camera me-2"></i>{t("nav-admin-meter-submissions")}
+                        </Link<Route>>
+                    </li>
+                    <li class="nav-item">
+                        <Link<Route> to={Route::MeterCampaigns} classes={classes!("nav-link", is_active(&Route::MeterCampaigns))}>
+                            <i class="bi bi-calendar-check me-2"></i>{t("nav-admin-meter-campaigns")}
+                        </Link<Route>>
+                    </li>
+                    if is_admin {
+                        <li class="nav-item">
+                            <Link<Route> to={Route::MeterImport} classes={classes!("nav-link", is_active(&Route::MeterImport))}>
+                                <i class="bi bi-file-earmark-arrow-up me-2"></i>{t("nav-admin-meter-import")}
+                            </Link<Route>>
+                        </li>
+                        <li class="nav-item">
+                            <Link<Route> to={Route::MeterApiKeys} classes={classes!("nav-link", is_active(&Route::MeterApiKeys))}>
+                                <i class="bi bi-key me-2"></i>{t("nav-admin-meter-api-keys")}
+                            </Link<Route>>
+                        </li>
+                    }
                 </ul>
             }
         </div>
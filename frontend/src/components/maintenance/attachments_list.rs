@@ -1,6 +1,9 @@
 use crate::i18n::t;
+use crate::services::api_client;
 use crate::utils::datetime::format_dt_option;
 use serde::Deserialize;
+use std::cell::RefCell;
+use std::rc::Rc;
 use web_sys::{FormData, HtmlInputElement};
 use yew::prelude::*;
 
@@ -12,6 +15,8 @@ pub struct Attachment {
     pub stored_filename: String,
     pub mime_type: String,
     pub size_bytes: u64,
+    #[serde(default)]
+    pub has_thumbnail: bool,
     pub is_deleted: bool,
     pub created_at: Option<String>,
 }
@@ -26,6 +31,64 @@ fn format_file_size(bytes: u64) -> String {
     }
 }
 
+#[derive(Properties, PartialEq)]
+struct ThumbnailProps {
+    request_id: u64,
+    attachment_id: u64,
+    alt: String,
+    token: Option<String>,
+}
+
+/// Inline preview loaded through the authenticated thumbnail endpoint
+#[function_component(Thumbnail)]
+fn thumbnail(props: &ThumbnailProps) -> Html {
+    let src = use_state(|| None::<String>);
+
+    {
+        let src = src.clone();
+        let token = props.token.clone();
+        let endpoint = format!(
+            "/requests/{}/attachments/{}/thumbnail",
+            props.request_id, props.attachment_id
+        );
+
+        use_effect_with(endpoint, move |endpoint| {
+            let endpoint = endpoint.clone();
+            // Track the object URL outside of state so cleanup sees the latest value
+            let created = Rc::new(RefCell::new(None::<String>));
+            {
+                let created = created.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let client = api_client(token.as_deref());
+                    if let Ok(url) = client.get_object_url(&endpoint).await {
+                        *created.borrow_mut() = Some(url.clone());
+                        src.set(Some(url));
+                    }
+                });
+            }
+            move || {
+                if let Some(url) = created.borrow_mut().take() {
+                    let _ = web_sys::Url::revoke_object_url(&url);
+                }
+            }
+        });
+    }
+
+    match (*src).clone() {
+        Some(url) => html! {
+            <img
+                src={url}
+                alt={props.alt.clone()}
+                class="rounded me-2"
+                style="width: 48px; height: 48px; object-fit: cover;"
+            />
+        },
+        None => html! {
+            <i class="bi bi-file-image text-success me-2" style="font-size: 1.25rem;"></i>
+        },
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct AttachmentsListProps {
     pub attachments: Vec<Attachment>,
@@ -37,6 +100,8 @@ pub struct AttachmentsListProps {
     pub on_upload: Callback<FormData>,
     #[prop_or(false)]
     pub uploading: bool,
+    #[prop_or_default]
+    pub token: Option<String>,
 }
 
 #[function_component(AttachmentsList)]
@@ -48,15 +113,14 @@ pub fn attachments_list(props: &AttachmentsListProps) -> Html {
         let file_input_ref = file_input_ref.clone();
 
         Callback::from(move |_: Event| {
-            if let Some(input) = file_input_ref.cast::<HtmlInputElement>() {
-                if let Some(files) = input.files() {
-                    if let Some(file) = files.get(0) {
-                        let form_data = FormData::new().unwrap();
-                        form_data.append_with_blob("file", &file).unwrap();
-                        on_upload.emit(form_data);
-                        input.set_value("");
-                    }
-                }
+            if let Some(input) = file_input_ref.cast::<HtmlInputElement>()
+                && let Some(files) = input.files()
+                && let Some(file) = files.get(0)
+            {
+                let form_data = FormData::new().unwrap();
+                form_data.append_with_blob("file", &file).unwrap();
+                on_upload.emit(form_data);
+                input.set_value("");
             }
         })
     };
@@ -126,7 +190,16 @@ pub fn attachments_list(props: &AttachmentsListProps) -> Html {
                                         class="list-group-item list-group-item-action d-flex justify-content-between align-items-center"
                                     >
                                         <div class="d-flex align-items-center">
-                                            <i class={classes!(icon_class, "me-2")} style="font-size: 1.25rem;"></i>
+                                            if att.has_thumbnail {
+                                                <Thumbnail
+                                                    request_id={props.request_id}
+                                                    attachment_id={att.id}
+                                                    alt={att.original_filename.clone()}
+                                                    token={props.token.clone()}
+                                                />
+                                            } else {
+                                                <i class={classes!(icon_class, "me-2")} style="font-size: 1.25rem;"></i>
+                                            }
                                             <div>
                                                 <div class="fw-medium">{&att.original_filename}</div>
                                                 <small class="text-muted">
@@ -30,7 +30,7 @@ pub struct CommentSectionProps {
 /// CommentSection component for displaying and adding comments
 #[function_component(CommentSection)]
 pub fn comment_section(props: &CommentSectionProps) -> Html {
-    let comment_text = use_state(|| String::new());
+    let comment_text = use_state(String::new);
     let submitting = use_state(|| false);
 
     let on_text_change = {
@@ -1,12 +1,12 @@
 use crate::components::maintenance::{
-    Attachment, AttachmentsList, Comment, CommentSection, EscalationPanel, HistoryEntry,
-    HistoryTimeline, ManagementPanel, ManagementRequest, UserInfo,
+    Attachment, AttachmentsList, Comment, CommentSection, EscalationPanel, FeedbackPanel,
+    HistoryEntry, HistoryTimeline, ManagementPanel, ManagementRequest, UserInfo,
 };
 use crate::components::{ErrorAlert, SuccessAlert};
 use crate::contexts::AuthContext;
 use crate::i18n::{t, t_with_args};
 use crate::routes::Route;
-use crate::services::api_client;
+use crate::services::{ApiError, api_client};
 use crate::utils::datetime::format_dt_local;
 use serde::Deserialize;
 use web_sys::FormData;
@@ -30,6 +30,7 @@ struct MaintenanceRequest {
     created_by_name: String,
     assigned_to: Option<u64>,
     assigned_to_name: Option<String>,
+    vendor: Option<String>,
     created_at: String,
     updated_at: String,
 }
@@ -45,10 +46,10 @@ pub fn maintenance_detail_content(props: &Props) -> Html {
     let navigator = use_navigator().unwrap();
 
     let request = use_state(|| None::<MaintenanceRequest>);
-    let history = use_state(|| Vec::<HistoryEntry>::new());
-    let attachments = use_state(|| Vec::<Attachment>::new());
-    let comments = use_state(|| Vec::<Comment>::new());
-    let users = use_state(|| Vec::<UserInfo>::new());
+    let history = use_state(Vec::<HistoryEntry>::new);
+    let attachments = use_state(Vec::<Attachment>::new);
+    let comments = use_state(Vec::<Comment>::new);
+    let users = use_state(Vec::<UserInfo>::new);
 
     let loading = use_state(|| true);
     let loading_history = use_state(|| false);
@@ -90,23 +91,14 @@ pub fn maintenance_detail_content(props: &Props) -> Html {
                         if let Ok(owners) = client
                             .get::<Vec<OwnerInfo>>(&format!("/apartments/{}/owners", apartment_id))
                             .await
+                            && let Some(window) = web_sys::window()
+                            && let Ok(Some(storage)) = window.local_storage()
+                            && let Ok(Some(user_json)) = storage.get_item("auth.user")
+                            && let Ok(user) = serde_json::from_str::<serde_json::Value>(&user_json)
+                            && let Some(user_id) = user.get("id").and_then(|v| v.as_u64())
                         {
-                            if let Some(window) = web_sys::window() {
-                                if let Ok(Some(storage)) = window.local_storage() {
-                                    if let Ok(Some(user_json)) = storage.get_item("auth.user") {
-                                        if let Ok(user) =
-                                            serde_json::from_str::<serde_json::Value>(&user_json)
-                                        {
-                                            if let Some(user_id) =
-                                                user.get("id").and_then(|v| v.as_u64())
-                                            {
-                                                let owns = owners.iter().any(|o| o.id == user_id);
-                                                is_apartment_owner.set(owns);
-                                            }
-                                        }
-                                    }
-                                }
-                            }
+                            let owns = owners.iter().any(|o| o.id == user_id);
+                            is_apartment_owner.set(owns);
                         }
                         loading.set(false);
                     }
@@ -379,6 +371,9 @@ pub fn maintenance_detail_content(props: &Props) -> Html {
                             attachments.set(list);
                         }
                     }
+                    Err(ApiError::BadRequest(msg)) if msg.contains("malware_detected") => {
+                        error.set(Some(t("maintenance-upload-infected")));
+                    }
                     Err(e) => {
                         error.set(Some(t_with_args(
                             "maintenance-failed-upload",
@@ -422,6 +417,7 @@ pub fn maintenance_detail_content(props: &Props) -> Html {
         "Open" => "bg-primary",
         "InProgress" => "bg-warning text-dark",
         "Resolved" => "bg-success",
+        "Closed" => "bg-dark",
         _ => "bg-secondary",
     };
 
@@ -486,8 +482,13 @@ pub fn maintenance_detail_content(props: &Props) -> Html {
                                     </div>
                                     <div class="col-md-6 mb-2">
                                         <strong>{t("maintenance-detail-assigned-to")}</strong>{" "}
-                                        {req.assigned_to_name.as_ref().map(|name| name.clone()).unwrap_or_else(|| t("maintenance-detail-unassigned"))}
+                                        {req.assigned_to_name.clone().unwrap_or_else(|| t("maintenance-detail-unassigned"))}
                                     </div>
+                                    if let Some(vendor) = &req.vendor {
+                                        <div class="col-md-6 mb-2">
+                                            <strong>{t("maintenance-detail-vendor")}</strong>{" "}{vendor}
+                                        </div>
+                                    }
                                     <div class="col-md-6 mb-2">
                                         <strong>{t("maintenance-detail-created")}</strong>{" "}{format_dt_local(&req.created_at)}
                                     </div>
@@ -495,6 +496,17 @@ pub fn maintenance_detail_content(props: &Props) -> Html {
                             </div>
                         </div>
 
+                        // Satisfaction feedback (prompted for the creator once resolved)
+                        <FeedbackPanel
+                            request_id={request_id}
+                            status={req.status.clone()}
+                            is_creator={req.created_by == auth.user().map(|u| u.id).unwrap_or(0)}
+                            token={token.clone()}
+                            on_submitted={on_update.clone()}
+                            on_error={on_error.clone()}
+                            on_success={on_success.clone()}
+                        />
+
                         // History component
                         <HistoryTimeline
                             history={(*history).clone()}
@@ -514,6 +526,7 @@ pub fn maintenance_detail_content(props: &Props) -> Html {
                             }
                             on_upload={on_upload.clone()}
                             uploading={*uploading}
+                            token={token.clone()}
                         />
 
                         // Comments component
@@ -537,6 +550,7 @@ pub fn maintenance_detail_content(props: &Props) -> Html {
                                     status: req.status.clone(),
                                     priority: req.priority.clone(),
                                     assigned_to: req.assigned_to,
+                                    vendor: req.vendor.clone(),
                                 }}
                                 users={(*users).clone()}
                                 token={token.clone()}
@@ -21,7 +21,7 @@ pub struct EscalationPanelProps {
 
 #[function_component(EscalationPanel)]
 pub fn escalation_panel(props: &EscalationPanelProps) -> Html {
-    let managers = use_state(|| Vec::<Manager>::new());
+    let managers = use_state(Vec::<Manager>::new);
     let loading_managers = use_state(|| false);
     let escalating = use_state(|| false);
     let show_panel = use_state(|| false);
@@ -0,0 +1,223 @@
+use crate::i18n::{t, t_with_args};
+use crate::services::api_client;
+use crate::utils::datetime::format_dt_local;
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct Feedback {
+    pub id: u64,
+    pub rating: u8,
+    pub is_fixed: bool,
+    pub comment: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Serialize)]
+struct NewFeedback {
+    rating: u8,
+    is_fixed: bool,
+    comment: Option<String>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct FeedbackPanelProps {
+    pub request_id: u64,
+    pub status: String,
+    /// Only the creator is asked to rate the outcome
+    pub is_creator: bool,
+    pub token: Option<String>,
+    pub on_submitted: Callback<()>,
+    pub on_error: Callback<String>,
+    pub on_success: Callback<String>,
+}
+
+fn stars(rating: u8) -> String {
+    (1..=5)
+        .map(|i| if i <= rating { '★' } else { '☆' })
+        .collect()
+}
+
+/// Satisfaction feedback for resolved requests.
+/// Asks the creator to rate the outcome and confirm the fix; shows earlier feedback to everyone else.
+#[function_component(FeedbackPanel)]
+pub fn feedback_panel(props: &FeedbackPanelProps) -> Html {
+    let entries = use_state(Vec::<Feedback>::new);
+    let rating = use_state(|| 0u8);
+    let comment = use_state(String::default);
+    let submitting = use_state(|| false);
+
+    {
+        let entries = entries.clone();
+        let token = props.token.clone();
+        let request_id = props.request_id;
+
+        use_effect_with((request_id, props.status.clone()), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client
+                    .get::<Vec<Feedback>>(&format!("/requests/{}/feedback", request_id))
+                    .await
+                {
+                    entries.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    let submit = {
+        let rating = rating.clone();
+        let comment = comment.clone();
+        let submitting = submitting.clone();
+        let token = props.token.clone();
+        let request_id = props.request_id;
+        let on_submitted = props.on_submitted.clone();
+        let on_error = props.on_error.clone();
+        let on_success = props.on_success.clone();
+
+        Callback::from(move |is_fixed: bool| {
+            let rating = rating.clone();
+            let comment = comment.clone();
+            let submitting = submitting.clone();
+            let token = token.clone();
+            let on_submitted = on_submitted.clone();
+            let on_error = on_error.clone();
+            let on_success = on_success.clone();
+
+            if *rating == 0 {
+                on_error.emit(t("maintenance-feedback-rating-required"));
+                return;
+            }
+
+            submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                let body = NewFeedback {
+                    rating: *rating,
+                    is_fixed,
+                    comment: Some((*comment).clone()).filter(|c| !c.trim().is_empty()),
+                };
+                match client
+                    .post::<_, serde_json::Value>(
+                        &format!("/requests/{}/feedback", request_id),
+                        &body,
+                    )
+                    .await
+                {
+                    Ok(_) => {
+                        on_success.emit(if is_fixed {
+                            t("maintenance-feedback-thanks")
+                        } else {
+                            t("maintenance-feedback-reopened")
+                        });
+                        rating.set(0);
+                        comment.set(String::new());
+                        on_submitted.emit(());
+                    }
+                    Err(e) => {
+                        on_error.emit(t_with_args(
+                            "maintenance-failed-feedback",
+                            &[("error", &e.to_string())],
+                        ));
+                    }
+                }
+                submitting.set(false);
+            });
+        })
+    };
+
+    let resolved = props.status == "Resolved" || props.status == "Closed";
+    let confirmed = entries.last().is_some_and(|f| f.is_fixed);
+    let can_rate = props.is_creator && resolved && !confirmed;
+
+    if entries.is_empty() && !can_rate {
+        return html! {};
+    }
+
+    html! {
+        <div class="card mt-3">
+            <div class="card-header">
+                <h5 class="mb-0">{t("maintenance-feedback")}</h5>
+            </div>
+            <div class="card-body">
+                {
+                    for entries.iter().map(|f| html! {
+                        <div class="mb-2 pb-2 border-bottom small" key={f.id}>
+                            <span class="text-warning me-2">{stars(f.rating)}</span>
+                            if f.is_fixed {
+                                <span class="badge bg-success">{t("maintenance-feedback-fixed")}</span>
+                            } else {
+                                <span class="badge bg-danger">{t("maintenance-feedback-not-fixed")}</span>
+                            }
+                            <span class="text-muted ms-2">
+                                {f.created_at.as_ref().map(|dt| format_dt_local(dt)).unwrap_or_default()}
+                            </span>
+                            if let Some(c) = &f.comment {
+                                <p class="mb-0 mt-1">{c}</p>
+                            }
+                        </div>
+                    })
+                }
+
+                if can_rate {
+                    <p class="small mb-2">{t("maintenance-feedback-prompt")}</p>
+                    <div class="mb-2">
+                        {
+                            for (1..=5u8).map(|i| {
+                                let rating = rating.clone();
+                                let active = i <= *rating;
+                                html! {
+                                    <button
+                                        type="button"
+                                        class={classes!("btn", "btn-sm", "btn-link", "p-0", "me-1", "fs-4", if active { "text-warning" } else { "text-muted" })}
+                                        disabled={*submitting}
+                                        onclick={Callback::from(move |_| rating.set(i))}
+                                    >
+                                        {if active { "★" } else { "☆" }}
+                                    </button>
+                                }
+                            })
+                        }
+                    </div>
+                    <textarea
+                        class="form-control form-control-sm mb-2"
+                        rows="2"
+                        placeholder={t("maintenance-feedback-comment-placeholder")}
+                        value={(*comment).clone()}
+                        disabled={*submitting}
+                        oninput={{
+                            let comment = comment.clone();
+                            Callback::from(move |e: InputEvent| {
+                                let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+                                comment.set(input.value());
+                            })
+                        }}
+                    />
+                    <div class="d-flex gap-2">
+                        <button
+                            class="btn btn-sm btn-success"
+                            disabled={*submitting}
+                            onclick={{
+                                let submit = submit.clone();
+                                Callback::from(move |_| submit.emit(true))
+                            }}
+                        >
+                            {t("maintenance-feedback-confirm-fixed")}
+                        </button>
+                        <button
+                            class="btn btn-sm btn-outline-danger"
+                            disabled={*submitting}
+                            onclick={{
+                                let submit = submit.clone();
+                                Callback::from(move |_| submit.emit(false))
+                            }}
+                        >
+                            {t("maintenance-feedback-report-not-fixed")}
+                        </button>
+                    </div>
+                }
+            </div>
+        </div>
+    }
+}
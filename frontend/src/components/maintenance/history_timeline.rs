@@ -54,9 +54,9 @@ pub fn history_timeline(props: &HistoryTimelineProps) -> Html {
                                         <p class="mb-0 small">
                                             {
                                                 // Check if this is a status change or other type of change
-                                                if entry.from_status.is_none() && entry.note.is_some() {
+                                                if let (None, Some(note)) = (&entry.from_status, &entry.note) {
                                                     // Priority or assignment change - just show the note
-                                                    html! { <>{entry.note.as_ref().unwrap()}</> }
+                                                    html! { <>{note}</> }
                                                 } else {
                                                     // Status change - show from/to format
                                                     html! {
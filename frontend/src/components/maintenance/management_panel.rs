@@ -9,6 +9,7 @@ pub struct MaintenanceRequest {
     pub status: String,
     pub priority: String,
     pub assigned_to: Option<u64>,
+    pub vendor: Option<String>,
 }
 
 #[derive(Deserialize, Clone, PartialEq)]
@@ -23,6 +24,7 @@ struct UpdateRequest {
     status: Option<String>,
     priority: Option<String>,
     assigned_to: Option<u64>,
+    vendor: Option<String>,
 }
 
 #[derive(Properties, PartialEq)]
@@ -35,7 +37,7 @@ pub struct ManagementPanelProps {
     pub on_success: Callback<String>,
 }
 
-/// Management panel for updating request status, priority, and assignment
+/// Management panel for updating request status, priority, assignment, and vendor
 /// Only displayed for Admin/Manager roles
 #[function_component(ManagementPanel)]
 pub fn management_panel(props: &ManagementPanelProps) -> Html {
@@ -43,6 +45,7 @@ pub fn management_panel(props: &ManagementPanelProps) -> Html {
     let new_status = use_state(|| None::<String>);
     let new_priority = use_state(|| None::<String>);
     let new_assigned = use_state(|| None::<u64>);
+    let new_vendor = use_state(|| None::<String>);
 
     let on_update_status = {
         let request_id = props.request.id;
@@ -70,6 +73,7 @@ pub fn management_panel(props: &ManagementPanelProps) -> Html {
                         status: Some(status),
                         priority: None,
                         assigned_to: None,
+                        vendor: None,
                     };
 
                     match client
@@ -123,6 +127,7 @@ pub fn management_panel(props: &ManagementPanelProps) -> Html {
                         status: None,
                         priority: Some(priority),
                         assigned_to: None,
+                        vendor: None,
                     };
 
                     match client
@@ -176,6 +181,7 @@ pub fn management_panel(props: &ManagementPanelProps) -> Html {
                         status: None,
                         priority: None,
                         assigned_to: Some(user_id),
+                        vendor: None,
                     };
 
                     match client
@@ -203,6 +209,61 @@ pub fn management_panel(props: &ManagementPanelProps) -> Html {
         })
     };
 
+    let on_set_vendor = {
+        let request_id = props.request.id;
+        let new_vendor = new_vendor.clone();
+        let updating = updating.clone();
+        let on_error = props.on_error.clone();
+        let on_success = props.on_success.clone();
+        let on_update = props.on_update.clone();
+        let token = props.token.clone();
+
+        Callback::from(move |_| {
+            if let Some(vendor) = (*new_vendor).clone() {
+                let updating = updating.clone();
+                let on_error = on_error.clone();
+                let on_success = on_success.clone();
+                let on_update = on_update.clone();
+                let token = token.clone();
+                let new_vendor = new_vendor.clone();
+
+                updating.set(true);
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    let client = api_client(token.as_deref());
+                    // An empty vendor clears it on the server
+                    let update = UpdateRequest {
+                        status: None,
+                        priority: None,
+                        assigned_to: None,
+                        vendor: Some(vendor),
+                    };
+
+                    match client
+                        .put::<_, serde_json::Value>(&format!("/requests/{}", request_id), &update)
+                        .await
+                    {
+                        Ok(_) => {
+                            on_success.emit(t("maintenance-vendor-updated"));
+                            on_update.emit(());
+                            new_vendor.set(None);
+                        }
+                        Err(ApiError::Forbidden) => {
+                            on_error.emit(t("maintenance-no-permission-update"));
+                        }
+                        Err(e) => {
+                            on_error.emit(t_with_args(
+                                "maintenance-failed-update-vendor",
+                                &[("error", &e.to_string())],
+                            ));
+                        }
+                    }
+                    updating.set(false);
+                });
+            }
+        })
+    };
+
     html! {
         <div class="card">
             <div class="card-header">
@@ -230,6 +291,7 @@ pub fn management_panel(props: &ManagementPanelProps) -> Html {
                         <option value="Open" selected={props.request.status == "Open"}>{t("maintenance-status-open")}</option>
                         <option value="InProgress" selected={props.request.status == "InProgress"}>{t("maintenance-status-in-progress")}</option>
                         <option value="Resolved" selected={props.request.status == "Resolved"}>{t("maintenance-status-resolved")}</option>
+                        <option value="Closed" selected={props.request.status == "Closed"}>{t("maintenance-status-closed")}</option>
                     </select>
                     <button
                         class="btn btn-sm btn-primary w-100"
@@ -298,11 +360,10 @@ pub fn management_panel(props: &ManagementPanelProps) -> Html {
                             Callback::from(move |e: Event| {
                                 let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
                                 let value = select.value();
-                                if !value.is_empty() {
-                                    if let Ok(id) = value.parse::<u64>() {
+                                if !value.is_empty()
+                                    && let Ok(id) = value.parse::<u64>() {
                                         new_assigned.set(Some(id));
                                     }
-                                }
                             })
                         }}
                     >
@@ -329,6 +390,42 @@ pub fn management_panel(props: &ManagementPanelProps) -> Html {
                         {t("maintenance-assign-request-btn")}
                     </button>
                 </div>
+
+                <hr />
+
+                // Vendor
+                <div class="mb-3">
+                    <label class="form-label small fw-semibold">{t("maintenance-vendor")}</label>
+                    <input
+                        type="text"
+                        class="form-control form-control-sm mb-2"
+                        maxlength="128"
+                        disabled={*updating}
+                        placeholder={t("maintenance-vendor-placeholder")}
+                        value={
+                            (*new_vendor).clone()
+                                .or_else(|| props.request.vendor.clone())
+                                .unwrap_or_default()
+                        }
+                        oninput={{
+                            let new_vendor = new_vendor.clone();
+                            Callback::from(move |e: InputEvent| {
+                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                new_vendor.set(Some(input.value()));
+                            })
+                        }}
+                    />
+                    <button
+                        class="btn btn-sm btn-primary w-100"
+                        disabled={new_vendor.is_none() || *updating}
+                        onclick={on_set_vendor}
+                    >
+                        if *updating {
+                            <span class="spinner-border spinner-border-sm me-1"></span>
+                        }
+                        {t("maintenance-vendor-btn")}
+                    </button>
+                </div>
             </div>
         </div>
     }
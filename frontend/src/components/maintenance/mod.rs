@@ -2,6 +2,7 @@ mod attachments_list;
 mod comment_section;
 mod detail_content;
 mod escalation_panel;
+mod feedback_panel;
 mod history_timeline;
 mod management_panel;
 
@@ -9,5 +10,6 @@ pub use attachments_list::{Attachment, AttachmentsList};
 pub use comment_section::{Comment, CommentSection};
 pub use detail_content::MaintenanceDetailContent;
 pub use escalation_panel::EscalationPanel;
+pub use feedback_panel::FeedbackPanel;
 pub use history_timeline::{HistoryEntry, HistoryTimeline};
 pub use management_panel::{MaintenanceRequest as ManagementRequest, ManagementPanel, UserInfo};
@@ -49,7 +49,7 @@ pub struct MeterListProps {
 pub fn meter_list(props: &MeterListProps) -> Html {
     let navigator = use_navigator().unwrap();
 
-    let all_meters = use_state(|| Vec::<MeterWithApartment>::new());
+    let all_meters = use_state(Vec::<MeterWithApartment>::new);
     let loading = use_state(|| false);
     let search_query = use_state(String::default);
     let filter_building = use_state(|| "".to_string());
@@ -199,7 +199,7 @@ pub fn meter_list(props: &MeterListProps) -> Html {
                                     let diff_ms = due_ms - today_ms;
                                     let days_until =
                                         (diff_ms / (1000.0 * 60.0 * 60.0 * 24.0)).floor() as i32;
-                                    days_until >= 0 && days_until <= 30
+                                    (0..=30).contains(&days_until)
                                 } else {
                                     false
                                 }
@@ -359,7 +359,7 @@ pub fn meter_list(props: &MeterListProps) -> Html {
 
                                         // Format last reading
                                         let last_reading_display = if let Some(ref value) = meter.last_reading_value {
-                                            let unit = meter.last_reading_unit.as_ref().map(|u| u.as_str()).unwrap_or("");
+                                            let unit = meter.last_reading_unit.as_deref().unwrap_or("");
                                             format!("{} {}", value, unit)
                                         } else {
                                             "No readings".to_string()
@@ -2,10 +2,16 @@ mod list;
 mod meter_edit_form;
 mod reading_entry_form;
 mod reading_history;
+mod reading_submission_form;
 mod register_form;
+mod submission_list;
 
 pub use list::{Building as MeterBuilding, MeterList, MeterWithApartment};
 pub use meter_edit_form::{Meter, MeterEditForm};
 pub use reading_entry_form::ReadingEntryForm;
 pub use reading_history::{MeterReading, ReadingHistory};
+pub use reading_submission_form::ReadingSubmissionForm;
 pub use register_form::MeterRegisterForm;
+pub use submission_list::{
+    MeterSubmission, SubmissionList, SubmissionPhoto, status_badge as submission_status_badge,
+};
@@ -54,7 +54,12 @@ pub fn reading_history(props: &ReadingHistoryProps) -> Html {
                                         <td>{&reading.reading_value}</td>
                                         <td>{&reading.unit}</td>
                                         <td>
-                                            <span class={if reading.source == "Webhook" { "badge bg-info" } else { "badge bg-secondary" }}>
+                                            <span class={match reading.source.as_str() {
+                                                "Webhook" | "Mqtt" => "badge bg-info",
+                                                "Import" => "badge bg-primary",
+                                                "Estimated" => "badge bg-warning text-dark",
+                                                _ => "badge bg-secondary",
+                                            }}>
                                                 {&reading.source}
                                             </span>
                                         </td>
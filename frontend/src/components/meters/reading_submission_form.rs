@@ -0,0 +1,166 @@
+use crate::i18n::{t, t_with_args};
+use crate::services::{ApiError, api_client};
+use web_sys::{FormData, HtmlInputElement};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ReadingSubmissionFormProps {
+    pub meter_id: u64,
+    pub token: Option<String>,
+    pub on_success: Callback<String>,
+    pub on_error: Callback<String>,
+    pub on_cancel: Callback<()>,
+}
+
+/// Form for residents to submit their own reading with a photo of the meter dial.
+/// The reading counts once a manager approves it.
+#[function_component(ReadingSubmissionForm)]
+pub fn reading_submission_form(props: &ReadingSubmissionFormProps) -> Html {
+    let entry_value = use_state(String::default);
+    let entry_unit = use_state(|| "m3".to_string());
+    let file_input_ref = use_node_ref();
+    let submitting = use_state(|| false);
+
+    let on_value_change = {
+        let entry_value = entry_value.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            entry_value.set(input.value());
+        })
+    };
+
+    let on_unit_change = {
+        let entry_unit = entry_unit.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            entry_unit.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let entry_value = entry_value.clone();
+        let entry_unit = entry_unit.clone();
+        let file_input_ref = file_input_ref.clone();
+        let submitting = submitting.clone();
+        let on_success = props.on_success.clone();
+        let on_error = props.on_error.clone();
+        let token = props.token.clone();
+        let meter_id = props.meter_id;
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            if entry_value.trim().is_empty() {
+                on_error.emit(t("meters-reading-required"));
+                return;
+            }
+            let Some(file) = file_input_ref
+                .cast::<HtmlInputElement>()
+                .and_then(|input| input.files())
+                .and_then(|files| files.get(0))
+            else {
+                on_error.emit(t("meters-submission-photo-required"));
+                return;
+            };
+
+            // The reading is taken now; the API reads the values sent before the photo
+            let form_data = FormData::new().unwrap();
+            form_data
+                .append_with_str("reading_value", entry_value.trim())
+                .unwrap();
+            form_data.append_with_str("unit", &entry_unit).unwrap();
+            form_data.append_with_blob("file", &file).unwrap();
+
+            submitting.set(true);
+
+            let token = token.clone();
+            let on_success = on_success.clone();
+            let on_error = on_error.clone();
+            let submitting = submitting.clone();
+            let entry_value = entry_value.clone();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post_multipart(&format!("/meters/{}/submissions", meter_id), &form_data)
+                    .await
+                {
+                    Ok(_) => {
+                        on_success.emit(t("meters-submission-success"));
+                        entry_value.set(String::default());
+                    }
+                    Err(ApiError::Forbidden) => {
+                        on_error.emit(t("meters-permission-denied"));
+                    }
+                    Err(ApiError::BadRequest(msg)) if msg.contains("malware_detected") => {
+                        on_error.emit(t("meters-submission-infected"));
+                    }
+                    Err(e) => {
+                        on_error.emit(t_with_args(
+                            "meters-submission-failed",
+                            &[("error", &e.to_string())],
+                        ));
+                    }
+                }
+                submitting.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="card mb-4">
+            <div class="card-header">
+                <h5>{t("meters-submit-reading")}</h5>
+            </div>
+            <div class="card-body">
+                <p class="text-muted small">{t("meters-submission-hint")}</p>
+                <form onsubmit={on_submit}>
+                    <div class="row">
+                        <div class="col-md-6 mb-3">
+                            <label class="form-label">{t("meters-reading-value-label")}</label>
+                            <input
+                                type="text"
+                                inputmode="decimal"
+                                class="form-control"
+                                value={(*entry_value).clone()}
+                                oninput={on_value_change}
+                                placeholder={t("meters-reading-value-placeholder")}
+                                required=true
+                            />
+                        </div>
+                        <div class="col-md-6 mb-3">
+                            <label class="form-label">{t("meters-reading-unit-label")}</label>
+                            <select class="form-select" value={(*entry_unit).clone()} onchange={on_unit_change}>
+                                <option value="m3">{t("meters-unit-m3")}</option>
+                                <option value="kWh">{t("meters-unit-kwh")}</option>
+                                <option value="L">{t("meters-unit-liters")}</option>
+                            </select>
+                        </div>
+                    </div>
+                    <div class="mb-3">
+                        <label class="form-label">{t("meters-submission-photo-label")}</label>
+                        <input
+                            type="file"
+                            class="form-control"
+                            ref={file_input_ref}
+                            accept="image/*"
+                            capture="environment"
+                            required=true
+                        />
+                    </div>
+                    <div class="d-flex gap-2">
+                        <button type="submit" class="btn btn-primary" disabled={*submitting}>
+                            if *submitting {
+                                <span class="spinner-border spinner-border-sm me-2"></span>
+                            }
+                            {t("meters-submission-send")}
+                        </button>
+                        <button type="button" class="btn btn-secondary" onclick={props.on_cancel.reform(|_| ())}>
+                            {t("button-cancel")}
+                        </button>
+                    </div>
+                </form>
+            </div>
+        </div>
+    }
+}
@@ -40,8 +40,8 @@ pub struct MeterRegisterFormProps {
 
 #[function_component(MeterRegisterForm)]
 pub fn meter_register_form(props: &MeterRegisterFormProps) -> Html {
-    let buildings = use_state(|| Vec::<Building>::new());
-    let apartments = use_state(|| Vec::<Apartment>::new());
+    let buildings = use_state(Vec::<Building>::new);
+    let apartments = use_state(Vec::<Apartment>::new);
     let selected_building = use_state(|| "".to_string());
     let selected_apartment = use_state(|| "".to_string());
     let meter_type = use_state(|| "ColdWater".to_string());
@@ -229,7 +229,7 @@ pub fn meter_register_form(props: &MeterRegisterFormProps) -> Html {
 
     // Build options
     let building_options = {
-        let mut options = vec![SelectOption::new("", &t("meters-select-building"))];
+        let mut options = vec![SelectOption::new("", t("meters-select-building"))];
         for building in buildings.iter() {
             options.push(SelectOption::new(
                 building.id.to_string(),
@@ -240,7 +240,7 @@ pub fn meter_register_form(props: &MeterRegisterFormProps) -> Html {
     };
 
     let apartment_options = {
-        let mut options = vec![SelectOption::new("", &t("meters-select-apartment"))];
+        let mut options = vec![SelectOption::new("", t("meters-select-apartment"))];
         for apartment in apartments.iter() {
             options.push(SelectOption::new(
                 apartment.id.to_string(),
@@ -251,10 +251,10 @@ pub fn meter_register_form(props: &MeterRegisterFormProps) -> Html {
     };
 
     let meter_type_options = vec![
-        SelectOption::new("ColdWater", &t("meters-cold-water")),
-        SelectOption::new("HotWater", &t("meters-hot-water")),
-        SelectOption::new("Gas", &t("meters-gas")),
-        SelectOption::new("Electricity", &t("meters-electricity")),
+        SelectOption::new("ColdWater", t("meters-cold-water")),
+        SelectOption::new("HotWater", t("meters-hot-water")),
+        SelectOption::new("Gas", t("meters-gas")),
+        SelectOption::new("Electricity", t("meters-electricity")),
     ];
 
     html! {
@@ -0,0 +1,209 @@
+use crate::i18n::{t, t_with_args};
+use crate::services::api_client;
+use crate::utils::datetime::format_dt_local;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// A reading submitted by a resident, with its meter and review status
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct MeterSubmission {
+    pub id: u64,
+    pub meter_id: u64,
+    pub reading_value: String,
+    pub reading_timestamp: String,
+    pub unit: String,
+    pub previous_value: Option<String>,
+    pub previous_timestamp: Option<String>,
+    #[serde(default)]
+    pub photo_has_thumbnail: bool,
+    pub status: String,
+    pub review_note: Option<String>,
+    pub reviewed_at: Option<String>,
+    pub created_at: Option<String>,
+    pub meter_type: String,
+    pub serial_number: String,
+    pub apartment_id: u64,
+    pub apartment_number: Option<String>,
+    pub building_address: Option<String>,
+    pub submitted_by_name: Option<String>,
+    pub consumption: Option<String>,
+}
+
+/// Badge for a submission's review status
+pub fn status_badge(status: &str) -> Html {
+    let (class, label) = match status {
+        "Approved" => ("badge bg-success", t("meters-submission-approved")),
+        "Rejected" => ("badge bg-danger", t("meters-submission-rejected")),
+        _ => ("badge bg-warning text-dark", t("meters-submission-pending")),
+    };
+    html! { <span class={class}>{label}</span> }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SubmissionPhotoProps {
+    pub meter_id: u64,
+    pub submission_id: u64,
+    /// Show the thumbnail until the photo is clicked
+    #[prop_or(false)]
+    pub has_thumbnail: bool,
+    pub token: Option<String>,
+}
+
+/// Photo of the meter dial sent with a submission
+#[function_component(SubmissionPhoto)]
+pub fn submission_photo(props: &SubmissionPhotoProps) -> Html {
+    let url = use_state(|| None::<String>);
+    let full = use_state(|| !props.has_thumbnail);
+
+    {
+        let url = url.clone();
+        let token = props.token.clone();
+        use_effect_with(
+            (props.meter_id, props.submission_id, *full),
+            move |(meter_id, submission_id, full)| {
+                let file = if *full { "photo" } else { "thumbnail" };
+                let endpoint = format!(
+                    "/meters/{}/submissions/{}/{}",
+                    meter_id, submission_id, file
+                );
+                // Revoked on unmount; the state handle only sees the value of its render
+                let created = Rc::new(RefCell::new(None::<String>));
+                {
+                    let created = created.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let client = api_client(token.as_deref());
+                        if let Ok(object_url) = client.get_object_url(&endpoint).await {
+                            created.replace(Some(object_url.clone()));
+                            url.set(Some(object_url));
+                        }
+                    });
+                }
+                move || {
+                    if let Some(object_url) = created.take() {
+                        let _ = web_sys::Url::revoke_object_url(&object_url);
+                    }
+                }
+            },
+        );
+    }
+
+    match &*url {
+        Some(src) if *full => html! {
+            <a href={src.clone()} target="_blank">
+                <img src={src.clone()} class="img-thumbnail" style="max-height: 200px;" alt={t("meters-submission-photo-label")} />
+            </a>
+        },
+        Some(src) => {
+            let show_full = {
+                let full = full.clone();
+                Callback::from(move |_: MouseEvent| full.set(true))
+            };
+            html! {
+                <img src={src.clone()} class="img-thumbnail" style="cursor: zoom-in;" alt={t("meters-submission-photo-label")} onclick={show_full} />
+            }
+        }
+        None => html! {
+            <div class="spinner-border spinner-border-sm text-secondary" role="status"></div>
+        },
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SubmissionListProps {
+    pub meter_id: u64,
+    pub token: Option<String>,
+    /// Bumped by the parent to reload after a new submission
+    #[prop_or(0)]
+    pub reload: u32,
+}
+
+/// Readings residents submitted for a meter, with their review status
+#[function_component(SubmissionList)]
+pub fn submission_list(props: &SubmissionListProps) -> Html {
+    let submissions = use_state(Vec::<MeterSubmission>::new);
+    let shown_photo = use_state(|| None::<u64>);
+
+    {
+        let submissions = submissions.clone();
+        let token = props.token.clone();
+        use_effect_with((props.meter_id, props.reload), move |(id, _)| {
+            let id = *id;
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client
+                    .get::<Vec<MeterSubmission>>(&format!("/meters/{}/submissions", id))
+                    .await
+                {
+                    submissions.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    if submissions.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div class="card mb-4">
+            <div class="card-header">
+                <h5 class="mb-0">{t("meters-submissions-title")}</h5>
+            </div>
+            <div class="card-body">
+                <div class="table-responsive">
+                    <table class="table table-sm align-middle">
+                        <thead>
+                            <tr>
+                                <th>{t("meters-reading-timestamp")}</th>
+                                <th>{t("meters-reading-value")}</th>
+                                <th>{t("meters-submission-submitted-by")}</th>
+                                <th>{t("meters-submission-status")}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for submissions.iter().map(|s| {
+                                let id = s.id;
+                                let shown = *shown_photo == Some(id);
+                                let toggle = {
+                                    let shown_photo = shown_photo.clone();
+                                    Callback::from(move |_: MouseEvent| {
+                                        shown_photo.set(if shown { None } else { Some(id) });
+                                    })
+                                };
+                                html! {
+                                    <tr key={id}>
+                                        <td>{format_dt_local(&s.reading_timestamp)}</td>
+                                        <td>{&s.reading_value}{" "}{&s.unit}</td>
+                                        <td>{s.submitted_by_name.clone().unwrap_or_default()}</td>
+                                        <td>
+                                            {status_badge(&s.status)}
+                                            if let Some(note) = &s.review_note {
+                                                <div class="small text-muted">
+                                                    {t_with_args("meters-submission-reason", &[("reason", note)])}
+                                                </div>
+                                            }
+                                        </td>
+                                        <td class="text-end">
+                                            <button type="button" class="btn btn-sm btn-outline-secondary" onclick={toggle}>
+                                                <i class="bi bi-image"></i>{" "}{t("meters-submission-view-photo")}
+                                            </button>
+                                            if shown {
+                                                <div class="mt-2">
+                                                    <SubmissionPhoto meter_id={s.meter_id} submission_id={id} has_thumbnail={s.photo_has_thumbnail} token={props.token.clone()} />
+                                                </div>
+                                            }
+                                        </td>
+                                    </tr>
+                                }
+                            }) }
+                        </tbody>
+                    </table>
+                </div>
+            </div>
+        </div>
+    }
+}
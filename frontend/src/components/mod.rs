@@ -16,11 +16,13 @@ pub mod main_sidebar;
 pub mod maintenance;
 pub mod meters; // meters management components
 pub mod navbar;
+pub mod notification_menu;
 pub mod pagination;
 pub mod properties; // properties management components
 pub mod search_input;
 pub mod spinner; // exported for reuse // maintenance request components
 pub mod toast;
+pub mod voting;
 
 pub use app_layout::AppLayout;
 pub use breadcrumb::{Breadcrumb, BreadcrumbItem};
@@ -2,6 +2,7 @@ use yew::prelude::*;
 use yew_router::prelude::*;
 
 use crate::components::language_switcher::LanguageSwitcher;
+use crate::components::notification_menu::NotificationMenu;
 use crate::contexts::AuthContext;
 use crate::i18n::t;
 use crate::routes::Route;
@@ -36,6 +37,7 @@ pub fn navbar() -> Html {
                         <LanguageSwitcher />
                     </div>
                     if auth.is_authenticated() {
+                        <NotificationMenu />
                         if let Some(u) = auth.user() {
                             <div class="dropdown" data-bs-auto-close="outside">
                                 <button class="btn btn-sm btn-outline-light dropdown-toggle" type="button" data-bs-toggle="dropdown" aria-expanded="false">
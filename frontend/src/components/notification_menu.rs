@@ -0,0 +1,160 @@
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::routes::Route;
+use crate::services::api_client;
+use crate::utils::datetime::format_dt_local;
+use gloo_timers::callback::Interval;
+use serde::Deserialize;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+/// How often the unread count is refreshed
+const POLL_INTERVAL_MS: u32 = 60_000;
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct Notification {
+    id: u64,
+    kind: String,
+    subject: String,
+    link: Option<String>,
+    read_at: Option<String>,
+    created_at: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Default)]
+struct NotificationList {
+    unread_count: i64,
+    notifications: Vec<Notification>,
+}
+
+/// Words a notification from its kind; the subject is the title of what it is about.
+fn message(n: &Notification) -> String {
+    match n.kind.as_str() {
+        "ProposalTallied" => {
+            t_with_args("notifications-proposal-tallied", &[("subject", &n.subject)])
+        }
+        "MeterReadingApproved" => t_with_args(
+            "notifications-meter-reading-approved",
+            &[("subject", &n.subject)],
+        ),
+        "MeterReadingRejected" => t_with_args(
+            "notifications-meter-reading-rejected",
+            &[("subject", &n.subject)],
+        ),
+        "ReadingCampaignReminder" => t_with_args(
+            "notifications-reading-campaign-reminder",
+            &[("subject", &n.subject)],
+        ),
+        _ => n.subject.clone(),
+    }
+}
+
+/// Bell in the navbar with the user's latest notifications
+#[function_component(NotificationMenu)]
+pub fn notification_menu() -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+    let navigator = use_navigator().unwrap();
+    let list = use_state(NotificationList::default);
+    let reload = use_state(|| 0u32);
+
+    let token = auth.token().map(|t| t.to_string());
+
+    {
+        let list = list.clone();
+        let token = token.clone();
+        use_effect_with((token.clone(), *reload), move |_| {
+            let fetch = move || {
+                let list = list.clone();
+                let token = token.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let client = api_client(token.as_deref());
+                    if let Ok(resp) = client.get::<NotificationList>("/notifications").await {
+                        list.set(resp);
+                    }
+                });
+            };
+            fetch();
+            let interval = Interval::new(POLL_INTERVAL_MS, fetch);
+            move || drop(interval)
+        });
+    }
+
+    let on_open = {
+        let reload = reload.clone();
+        let token = token.clone();
+        let navigator = navigator.clone();
+        Callback::from(move |n: Notification| {
+            let reload = reload.clone();
+            let token = token.clone();
+            if n.read_at.is_none() {
+                wasm_bindgen_futures::spawn_local(async move {
+                    let client = api_client(token.as_deref());
+                    let _ = client
+                        .post_empty::<()>(&format!("/notifications/{}/read", n.id))
+                        .await;
+                    reload.set(*reload + 1);
+                });
+            }
+            if let Some(route) = n.link.as_deref().and_then(Route::recognize) {
+                navigator.push(&route);
+            }
+        })
+    };
+
+    let on_mark_all = {
+        let reload = reload.clone();
+        let token = token.clone();
+        Callback::from(move |_: MouseEvent| {
+            let reload = reload.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                let _ = client.post_empty::<()>("/notifications/read-all").await;
+                reload.set(*reload + 1);
+            });
+        })
+    };
+
+    html! {
+        <div class="dropdown me-2">
+            <button class="btn btn-sm btn-outline-light position-relative" type="button"
+                    data-bs-toggle="dropdown" aria-expanded="false" title={t("notifications-title")}>
+                <i class="bi bi-bell"></i>
+                if list.unread_count > 0 {
+                    <span class="position-absolute top-0 start-100 translate-middle badge rounded-pill bg-danger">
+                        {list.unread_count}
+                    </span>
+                }
+            </button>
+            <div class="dropdown-menu dropdown-menu-end p-0" style="min-width: 320px; max-height: 400px; overflow-y: auto;">
+                <div class="d-flex justify-content-between align-items-center px-3 py-2 border-bottom">
+                    <strong class="small">{t("notifications-title")}</strong>
+                    if list.unread_count > 0 {
+                        <button type="button" class="btn btn-sm btn-link p-0" onclick={on_mark_all}>
+                            {t("notifications-mark-all-read")}
+                        </button>
+                    }
+                </div>
+                if list.notifications.is_empty() {
+                    <div class="px-3 py-2 small text-muted">{t("notifications-empty")}</div>
+                } else {
+                    { for list.notifications.iter().map(|n| {
+                        let on_open = on_open.clone();
+                        let item = n.clone();
+                        html! {
+                            <button
+                                key={n.id}
+                                type="button"
+                                class={classes!("dropdown-item", "small", "text-wrap", n.read_at.is_none().then_some("fw-semibold"))}
+                                onclick={Callback::from(move |_| on_open.emit(item.clone()))}
+                            >
+                                <div>{message(n)}</div>
+                                <div class="text-muted">{format_dt_local(&n.created_at)}</div>
+                            </button>
+                        }
+                    }) }
+                }
+            </div>
+        </div>
+    }
+}
@@ -37,8 +37,8 @@ fn map_renters(list: Vec<RenterWithUser>) -> Vec<RenterInfo> {
 pub fn admin_properties_data() -> Html {
     let auth = use_context::<AuthContext>().expect("AuthContext not found");
 
-    let buildings = use_state(|| Vec::<Building>::new());
-    let apartments = use_state(|| Vec::<Apartment>::new());
+    let buildings = use_state(Vec::<Building>::new);
+    let apartments = use_state(Vec::<Apartment>::new);
     let selected_building = use_state(|| None::<u64>);
     let selected_apartment = use_state(|| None::<u64>);
 
@@ -47,10 +47,10 @@ pub fn admin_properties_data() -> Html {
     let apt_number = use_state(String::default);
     let apt_size = use_state(String::default);
 
-    let apartment_owners = use_state(|| Vec::<UserInfo>::new());
-    let apartment_renters = use_state(|| Vec::<RenterInfo>::new());
-    let apartment_invitations = use_state(|| Vec::<InvitationInfo>::new());
-    let all_users = use_state(|| Vec::<UserInfo>::new());
+    let apartment_owners = use_state(Vec::<UserInfo>::new);
+    let apartment_renters = use_state(Vec::<RenterInfo>::new);
+    let apartment_invitations = use_state(Vec::<InvitationInfo>::new);
+    let all_users = use_state(Vec::<UserInfo>::new);
     let user_query = use_state(String::default);
     let renter_query = use_state(String::default);
 
@@ -427,13 +427,12 @@ pub fn admin_properties_data() -> Html {
                     .await
                 {
                     Ok(_) => {
-                        if let Some(bid) = *selected_building {
-                            if let Ok(list) = client
+                        if let Some(bid) = *selected_building
+                            && let Ok(list) = client
                                 .get::<Vec<Apartment>>(&format!("/buildings/{}/apartments", bid))
                                 .await
-                            {
-                                apartments.set(list);
-                            }
+                        {
+                            apartments.set(list);
                         }
                         if *selected_apartment == Some(id) {
                             selected_apartment.set(None);
@@ -754,16 +753,15 @@ pub fn admin_properties_data() -> Html {
                                     .unwrap_or_else(|| t("properties-invitation-processed"));
                                 success.set(Some(message));
 
-                                if status == "pending" {
-                                    if let Ok(list) = client
+                                if status == "pending"
+                                    && let Ok(list) = client
                                         .get::<Vec<InvitationInfo>>(&format!(
                                             "/apartments/{}/invitations",
                                             aid
                                         ))
                                         .await
-                                    {
-                                        apartment_invitations.set(list);
-                                    }
+                                {
+                                    apartment_invitations.set(list);
                                 }
                             }
                             Err(e) => error.set(Some(t_with_args(
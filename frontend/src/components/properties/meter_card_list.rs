@@ -102,43 +102,43 @@ pub fn meter_card_list(props: &MeterCardListProps) -> Html {
 fn calibration_status(calibration_due_date: &Option<String>) -> (&'static str, String) {
     if let Some(due_date) = calibration_due_date {
         let parts: Vec<&str> = due_date.split('-').collect();
-        if parts.len() == 3 {
-            if let (Ok(year), Ok(month), Ok(day)) = (
+        if parts.len() == 3
+            && let (Ok(year), Ok(month), Ok(day)) = (
                 parts[0].parse::<i32>(),
                 parts[1].parse::<u32>(),
                 parts[2].parse::<u32>(),
-            ) {
-                let now = js_sys::Date::new_0();
-                let today_ms = now.get_time();
+            )
+        {
+            let now = js_sys::Date::new_0();
+            let today_ms = now.get_time();
 
-                let due_date_js = js_sys::Date::new_0();
-                due_date_js.set_full_year(year as u32);
-                due_date_js.set_month(month - 1);
-                due_date_js.set_date(day);
-                let due_ms = due_date_js.get_time();
+            let due_date_js = js_sys::Date::new_0();
+            due_date_js.set_full_year(year as u32);
+            due_date_js.set_month(month - 1);
+            due_date_js.set_date(day);
+            let due_ms = due_date_js.get_time();
 
-                let diff_ms = due_ms - today_ms;
-                let days_until = (diff_ms / (1000.0 * 60.0 * 60.0 * 24.0)).floor() as i32;
+            let diff_ms = due_ms - today_ms;
+            let days_until = (diff_ms / (1000.0 * 60.0 * 60.0 * 24.0)).floor() as i32;
 
-                if days_until < 0 {
-                    return (
-                        "badge bg-danger",
-                        t_with_args(
-                            "meters-overdue-by-days",
-                            &[("days", &(-days_until).to_string())],
-                        ),
-                    );
-                } else if days_until <= 30 {
-                    return (
-                        "badge bg-warning text-dark",
-                        t_with_args("meters-due-in-days", &[("days", &days_until.to_string())]),
-                    );
-                } else {
-                    return (
-                        "badge bg-success",
-                        t_with_args("meters-valid-days", &[("days", &days_until.to_string())]),
-                    );
-                }
+            if days_until < 0 {
+                return (
+                    "badge bg-danger",
+                    t_with_args(
+                        "meters-overdue-by-days",
+                        &[("days", &(-days_until).to_string())],
+                    ),
+                );
+            } else if days_until <= 30 {
+                return (
+                    "badge bg-warning text-dark",
+                    t_with_args("meters-due-in-days", &[("days", &days_until.to_string())]),
+                );
+            } else {
+                return (
+                    "badge bg-success",
+                    t_with_args("meters-valid-days", &[("days", &days_until.to_string())]),
+                );
             }
         }
         ("badge bg-secondary", t("meters-unknown"))
@@ -59,10 +59,10 @@ pub fn property_detail_content(props: &Props) -> Html {
 
     let apartment = use_state(|| None::<ApartmentDetail>);
     let permissions = use_state(|| None::<ApartmentPermissions>);
-    let meters = use_state(|| Vec::<MeterWithLastReading>::new());
-    let renters = use_state(|| Vec::<RenterInfo>::new());
-    let invitations = use_state(|| Vec::<InvitationInfo>::new());
-    let all_users = use_state(|| Vec::<UserInfo>::new());
+    let meters = use_state(Vec::<MeterWithLastReading>::new);
+    let renters = use_state(Vec::<RenterInfo>::new);
+    let invitations = use_state(Vec::<InvitationInfo>::new);
+    let all_users = use_state(Vec::<UserInfo>::new);
     let user_query = use_state(String::default);
 
     let active_tab = use_state(|| Tab::Meters);
@@ -475,16 +475,15 @@ pub fn property_detail_content(props: &Props) -> Html {
                                 .unwrap_or_else(|| t("properties-invitation-sent"));
                             success.set(Some(message));
 
-                            if status == "pending" {
-                                if let Ok(list) = client
+                            if status == "pending"
+                                && let Ok(list) = client
                                     .get::<Vec<InvitationInfo>>(&format!(
                                         "/apartments/{}/invitations",
                                         apartment_id
                                     ))
                                     .await
-                                {
-                                    invitations.set(list);
-                                }
+                            {
+                                invitations.set(list);
                             }
                         }
                         Err(e) => error.set(Some(t_with_args(
@@ -28,7 +28,7 @@ pub struct Props {
 #[function_component(PropertyHistoryTimeline)]
 pub fn property_history_timeline(props: &Props) -> Html {
     let auth = use_context::<AuthContext>().expect("AuthContext not found");
-    let events = use_state(|| Vec::<PropertyHistoryEvent>::new());
+    let events = use_state(Vec::<PropertyHistoryEvent>::new);
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
 
@@ -84,7 +84,7 @@ pub fn property_history_timeline(props: &Props) -> Html {
                 </div>
             } else {
                 <div class="timeline">
-                    { for events.iter().map(|event| render_event(event)) }
+                    { for events.iter().map(render_event) }
                 </div>
             }
         </div>
@@ -141,35 +141,35 @@ fn render_event(event: &PropertyHistoryEvent) -> Html {
 
 fn render_metadata(metadata_json: &str) -> Html {
     // Try to parse and pretty-print the metadata
-    if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(metadata_json) {
-        if let Some(obj) = parsed.as_object() {
-            return html! {
-                <ul class="list-unstyled mb-0 mt-1">
-                    { for obj.iter().map(|(key, value)| {
-                        let formatted_value = match value {
-                            serde_json::Value::String(s) => {
-                                if s == "null" {
-                                    t("properties-not-set")
-                                } else {
-                                    s.clone()
-                                }
-                            },
-                            serde_json::Value::Bool(b) => b.to_string(),
-                            serde_json::Value::Null => t("properties-not-set"),
-                            _ => value.to_string().trim_matches('"').to_string(),
-                        };
-
-                        html! {
-                            <li key={key.clone()}>
-                                <strong>{format!("{}:", key.replace('_', " "))}</strong>
-                                {" "}
-                                {formatted_value}
-                            </li>
-                        }
-                    }) }
-                </ul>
-            };
-        }
+    if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(metadata_json)
+        && let Some(obj) = parsed.as_object()
+    {
+        return html! {
+            <ul class="list-unstyled mb-0 mt-1">
+                { for obj.iter().map(|(key, value)| {
+                    let formatted_value = match value {
+                        serde_json::Value::String(s) => {
+                            if s == "null" {
+                                t("properties-not-set")
+                            } else {
+                                s.clone()
+                            }
+                        },
+                        serde_json::Value::Bool(b) => b.to_string(),
+                        serde_json::Value::Null => t("properties-not-set"),
+                        _ => value.to_string().trim_matches('"').to_string(),
+                    };
+
+                    html! {
+                        <li key={key.clone()}>
+                            <strong>{format!("{}:", key.replace('_', " "))}</strong>
+                            {" "}
+                            {formatted_value}
+                        </li>
+                    }
+                }) }
+            </ul>
+        };
     }
 
     html! {
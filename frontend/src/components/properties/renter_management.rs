@@ -16,6 +16,9 @@ pub struct RenterInfo {
     pub user_email: String,
 }
 
+/// Email, start date and end date of a renter invitation
+pub type RenterInvite = (String, Option<String>, Option<String>);
+
 #[derive(Properties, PartialEq)]
 pub struct RenterManagementProps {
     pub renters: Vec<RenterInfo>,
@@ -30,7 +33,7 @@ pub struct RenterManagementProps {
     #[prop_or_default]
     pub invitations: Vec<InvitationInfo>,
     #[prop_or_default]
-    pub on_invite: Option<Callback<(String, Option<String>, Option<String>)>>,
+    pub on_invite: Option<Callback<RenterInvite>>,
     #[prop_or_default]
     pub on_cancel_invitation: Option<Callback<u64>>,
 }
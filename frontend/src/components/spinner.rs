@@ -30,7 +30,7 @@ pub fn spinner(props: &SpinnerProps) -> Html {
     let color_class = format!(" text-{}", props.color);
     let mut classes = format!("spinner-border{}{}", size_class, color_class);
     if !props.class.is_empty() {
-        classes.push_str(" ");
+        classes.push(' ');
         classes.push_str(&props.class.to_string());
     }
     let spinner = html! {<div class={classes} role="status"><span class="visually-hidden">{props.label.clone()}</span></div>};
@@ -0,0 +1,78 @@
+use super::documents::download;
+use crate::i18n::{t, t_with_args};
+use crate::services::api_client;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct AuditReportProps {
+    pub proposal_id: u64,
+    pub token: Option<String>,
+}
+
+/// Downloads of the audit report of a tallied proposal (Admin/Manager)
+#[function_component(AuditReport)]
+pub fn audit_report(props: &AuditReportProps) -> Html {
+    let loading = use_state(|| false);
+    let error = use_state(|| None::<String>);
+
+    let on_download = {
+        let loading = loading.clone();
+        let error = error.clone();
+        let token = props.token.clone();
+        let id = props.proposal_id;
+        Callback::from(move |format: &'static str| {
+            let loading = loading.clone();
+            let error = error.clone();
+            let token = token.clone();
+            loading.set(true);
+            error.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get_object_url(&format!("/proposals/{}/audit-report?format={}", id, format))
+                    .await
+                {
+                    Ok(url) => {
+                        download(&url, &format!("proposal_{}_audit.{}", id, format));
+                        let _ = web_sys::Url::revoke_object_url(&url);
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "voting-audit-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="card mb-3">
+            <div class="card-header">
+                <h5 class="mb-0">{t("voting-audit-title")}</h5>
+            </div>
+            <div class="card-body">
+                <p class="small text-muted">{t("voting-audit-desc")}</p>
+                if let Some(err) = &*error {
+                    <div class="alert alert-warning py-1 small">{err}</div>
+                }
+                <div class="d-flex gap-2">
+                    <button
+                        class="btn btn-outline-primary btn-sm"
+                        disabled={*loading}
+                        onclick={{ let on_download = on_download.clone(); Callback::from(move |_| on_download.emit("pdf")) }}
+                    >
+                        <i class="bi bi-file-earmark-pdf me-1"></i>{t("voting-audit-pdf")}
+                    </button>
+                    <button
+                        class="btn btn-outline-secondary btn-sm"
+                        disabled={*loading}
+                        onclick={{ let on_download = on_download.clone(); Callback::from(move |_| on_download.emit("csv")) }}
+                    >
+                        <i class="bi bi-filetype-csv me-1"></i>{t("voting-audit-csv")}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,193 @@
+use crate::components::Textarea;
+use crate::i18n::{t, t_with_args};
+use crate::services::{ApiError, api_client};
+use crate::utils::datetime::format_dt_local;
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+/// A message in a proposal's discussion
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct ProposalComment {
+    pub id: u64,
+    pub body: String,
+    pub created_at: String,
+    pub user_name: String,
+    pub can_delete: bool,
+}
+
+#[derive(Serialize)]
+struct CreateCommentPayload {
+    body: String,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ProposalDiscussionProps {
+    pub proposal_id: u64,
+    pub token: Option<String>,
+    /// Whether the proposal still takes messages (Scheduled or Open)
+    pub open: bool,
+}
+
+/// Discussion thread of a proposal; read-only once voting has ended
+#[function_component(ProposalDiscussion)]
+pub fn proposal_discussion(props: &ProposalDiscussionProps) -> Html {
+    let comments = use_state(Vec::<ProposalComment>::new);
+    let draft = use_state(String::new);
+    let posting = use_state(|| false);
+    let error = use_state(|| None::<String>);
+    let reload = use_state(|| 0u32);
+
+    {
+        let comments = comments.clone();
+        let token = props.token.clone();
+
+        use_effect_with((props.proposal_id, *reload), move |(id, _)| {
+            let id = *id;
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client
+                    .get::<Vec<ProposalComment>>(&format!("/proposals/{}/comments", id))
+                    .await
+                {
+                    comments.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_post = {
+        let draft = draft.clone();
+        let posting = posting.clone();
+        let error = error.clone();
+        let reload = reload.clone();
+        let token = props.token.clone();
+        let id = props.proposal_id;
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let body = draft.trim().to_string();
+            if body.is_empty() {
+                return;
+            }
+            let draft = draft.clone();
+            let posting = posting.clone();
+            let error = error.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            posting.set(true);
+            error.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, ProposalComment>(
+                        &format!("/proposals/{}/comments", id),
+                        &CreateCommentPayload { body },
+                    )
+                    .await
+                {
+                    Ok(_) => {
+                        draft.set(String::new());
+                        reload.set(*reload + 1);
+                    }
+                    Err(ApiError::BadRequest(msg)) => error.set(Some(msg)),
+                    Err(e) => error.set(Some(t_with_args(
+                        "voting-discussion-failed-post",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                posting.set(false);
+            });
+        })
+    };
+
+    let on_delete = {
+        let error = error.clone();
+        let reload = reload.clone();
+        let token = props.token.clone();
+        let id = props.proposal_id;
+        Callback::from(move |comment_id: u64| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(&t("voting-discussion-delete-confirm"))
+                        .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let error = error.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .delete_no_response(&format!("/proposals/{}/comments/{}", id, comment_id))
+                    .await
+                {
+                    Ok(_) => reload.set(*reload + 1),
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-delete-failed"), e))),
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="card mb-3">
+            <div class="card-header">
+                <h5 class="mb-0">{t("voting-discussion-title")}</h5>
+            </div>
+            <div class="card-body">
+                if let Some(err) = &*error {
+                    <div class="alert alert-warning py-1">{err}</div>
+                }
+                if comments.is_empty() {
+                    <p class="text-muted">{t("voting-discussion-empty")}</p>
+                } else {
+                    <ul class="list-unstyled">
+                        { for comments.iter().map(|c| {
+                            let on_delete = on_delete.clone();
+                            let comment_id = c.id;
+                            html! {
+                                <li key={c.id} class="border-bottom pb-2 mb-2">
+                                    <div class="d-flex align-items-center small text-muted mb-1">
+                                        <strong class="me-2 text-body">{&c.user_name}</strong>
+                                        {format_dt_local(&c.created_at)}
+                                        if c.can_delete {
+                                            <button
+                                                type="button"
+                                                class="btn btn-sm btn-link text-danger ms-auto py-0"
+                                                title={t("button-delete")}
+                                                onclick={Callback::from(move |_| on_delete.emit(comment_id))}
+                                            >
+                                                <i class="bi bi-trash"></i>
+                                            </button>
+                                        }
+                                    </div>
+                                    <div style="white-space: pre-wrap;">{&c.body}</div>
+                                </li>
+                            }
+                        }) }
+                    </ul>
+                }
+                if props.open {
+                    <form onsubmit={on_post}>
+                        <Textarea
+                            label={t("voting-discussion-your-message")}
+                            value={(*draft).clone()}
+                            on_change={{ let draft = draft.clone(); Callback::from(move |v: String| draft.set(v)) }}
+                            rows={3}
+                            disabled={*posting}
+                        />
+                        <div class="d-flex justify-content-end">
+                            <button type="submit" class="btn btn-primary btn-sm" disabled={*posting || draft.trim().is_empty()}>
+                                {t("voting-discussion-post")}
+                            </button>
+                        </div>
+                    </form>
+                } else {
+                    <p class="text-muted small mb-0">{t("voting-discussion-closed")}</p>
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,276 @@
+use crate::i18n::{t, t_with_args};
+use crate::services::{ApiError, api_client};
+use serde::Deserialize;
+use wasm_bindgen::JsCast;
+use web_sys::{FormData, HtmlInputElement};
+use yew::prelude::*;
+
+/// A supporting document attached to a proposal
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct ProposalDocument {
+    pub id: u64,
+    pub original_filename: String,
+    pub mime_type: String,
+    pub size_bytes: u64,
+}
+
+fn format_file_size(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+/// Saves the blob behind `object_url` under `filename` through a temporary link.
+pub(super) fn download(object_url: &str, filename: &str) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    if let Some(anchor) = document
+        .create_element("a")
+        .ok()
+        .and_then(|el| el.dyn_into::<web_sys::HtmlAnchorElement>().ok())
+    {
+        anchor.set_href(object_url);
+        anchor.set_download(filename);
+        anchor.click();
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ProposalDocumentsProps {
+    pub proposal_id: u64,
+    pub token: Option<String>,
+    /// Managers can add documents until voting ends
+    #[prop_or(false)]
+    pub can_upload: bool,
+    #[prop_or(false)]
+    pub can_delete: bool,
+}
+
+/// Supporting documents of a proposal (quotes, drawings)
+#[function_component(ProposalDocuments)]
+pub fn proposal_documents(props: &ProposalDocumentsProps) -> Html {
+    let documents = use_state(Vec::<ProposalDocument>::new);
+    let file_input_ref = use_node_ref();
+    let uploading = use_state(|| false);
+    let error = use_state(|| None::<String>);
+    let reload = use_state(|| 0u32);
+
+    {
+        let documents = documents.clone();
+        let token = props.token.clone();
+
+        use_effect_with((props.proposal_id, *reload), move |(id, _)| {
+            let id = *id;
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client
+                    .get::<Vec<ProposalDocument>>(&format!("/proposals/{}/attachments", id))
+                    .await
+                {
+                    documents.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_file_change = {
+        let file_input_ref = file_input_ref.clone();
+        let uploading = uploading.clone();
+        let error = error.clone();
+        let reload = reload.clone();
+        let token = props.token.clone();
+        let id = props.proposal_id;
+        Callback::from(move |_: Event| {
+            let Some(input) = file_input_ref.cast::<HtmlInputElement>() else {
+                return;
+            };
+            let Some(file) = input.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            let form_data = FormData::new().unwrap();
+            form_data.append_with_blob("file", &file).unwrap();
+            input.set_value("");
+
+            let uploading = uploading.clone();
+            let error = error.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            uploading.set(true);
+            error.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post_multipart(&format!("/proposals/{}/attachments", id), &form_data)
+                    .await
+                {
+                    Ok(_) => reload.set(*reload + 1),
+                    Err(ApiError::BadRequest(msg)) if msg.contains("malware_detected") => {
+                        error.set(Some(t("voting-documents-infected")));
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "voting-documents-upload-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                uploading.set(false);
+            });
+        })
+    };
+
+    let on_upload_click = {
+        let file_input_ref = file_input_ref.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(input) = file_input_ref.cast::<HtmlInputElement>() {
+                input.click();
+            }
+        })
+    };
+
+    let on_download = {
+        let error = error.clone();
+        let token = props.token.clone();
+        let id = props.proposal_id;
+        Callback::from(move |doc: ProposalDocument| {
+            let error = error.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get_object_url(&format!(
+                        "/proposals/{}/attachments/{}/download",
+                        id, doc.id
+                    ))
+                    .await
+                {
+                    Ok(url) => {
+                        download(&url, &doc.original_filename);
+                        let _ = web_sys::Url::revoke_object_url(&url);
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "voting-documents-download-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+            });
+        })
+    };
+
+    let on_delete = {
+        let error = error.clone();
+        let reload = reload.clone();
+        let token = props.token.clone();
+        let id = props.proposal_id;
+        Callback::from(move |att_id: u64| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(&t("voting-documents-delete-confirm"))
+                        .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let error = error.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .delete_no_response(&format!("/proposals/{}/attachments/{}", id, att_id))
+                    .await
+                {
+                    Ok(_) => reload.set(*reload + 1),
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-delete-failed"), e))),
+                }
+            });
+        })
+    };
+
+    // Nothing to show residents on proposals without documents
+    if documents.is_empty() && !props.can_upload {
+        return html! {};
+    }
+
+    html! {
+        <div class="card mb-3">
+            <div class="card-header d-flex justify-content-between align-items-center">
+                <h5 class="mb-0">{t("voting-documents-title")}</h5>
+                if props.can_upload {
+                    <input
+                        type="file"
+                        ref={file_input_ref}
+                        style="display: none;"
+                        onchange={on_file_change}
+                        accept="image/*,application/pdf"
+                    />
+                    <button
+                        type="button"
+                        class="btn btn-sm btn-outline-primary"
+                        onclick={on_upload_click}
+                        disabled={*uploading}
+                    >
+                        if *uploading {
+                            <span class="spinner-border spinner-border-sm me-1"></span>
+                        } else {
+                            <i class="bi bi-upload me-1"></i>
+                        }
+                        {t("voting-documents-upload")}
+                    </button>
+                }
+            </div>
+            <div class="card-body small">
+                if let Some(err) = &*error {
+                    <div class="alert alert-warning py-1 mb-2">{err}</div>
+                }
+                if documents.is_empty() {
+                    <div class="text-muted">{t("voting-documents-empty")}</div>
+                } else {
+                    <ul class="list-unstyled mb-0">
+                        { for documents.iter().map(|doc| {
+                            let icon = if doc.mime_type == "application/pdf" {
+                                "bi bi-file-earmark-pdf text-danger"
+                            } else {
+                                "bi bi-file-image text-success"
+                            };
+                            let on_download = on_download.clone();
+                            let on_delete = on_delete.clone();
+                            let att_id = doc.id;
+                            let item = doc.clone();
+                            html! {
+                                <li key={doc.id} class="d-flex align-items-center gap-2 mb-1">
+                                    <i class={icon}></i>
+                                    <a
+                                        href="#"
+                                        class="text-truncate"
+                                        onclick={Callback::from(move |e: MouseEvent| {
+                                            e.prevent_default();
+                                            on_download.emit(item.clone());
+                                        })}
+                                    >
+                                        {&doc.original_filename}
+                                    </a>
+                                    <span class="text-muted">{format_file_size(doc.size_bytes)}</span>
+                                    if props.can_delete {
+                                        <button
+                                            type="button"
+                                            class="btn btn-sm btn-outline-danger ms-auto py-0"
+                                            onclick={Callback::from(move |_| on_delete.emit(att_id))}
+                                        >
+                                            <i class="bi bi-trash"></i>
+                                        </button>
+                                    }
+                                </li>
+                            }
+                        }) }
+                    </ul>
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,17 @@
+mod audit_report;
+mod discussion;
+mod documents;
+mod option_ballot;
+mod option_results;
+mod proxy_panel;
+mod receipt_check;
+mod seat_votes;
+
+pub use audit_report::AuditReport;
+pub use discussion::{ProposalComment, ProposalDiscussion};
+pub use documents::{ProposalDocument, ProposalDocuments};
+pub use option_ballot::{OptionBallot, ProposalOption};
+pub use option_results::{OptionResults, ResultRound};
+pub use proxy_panel::{MyProxies, ProxyBallot, ProxyGrant, ProxyVoter, ProxyVotes, ProxyWithNames};
+pub use receipt_check::{PublishedBallot, ReceiptCheck};
+pub use seat_votes::{SeatVote, SeatVotes};
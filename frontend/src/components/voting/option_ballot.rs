@@ -0,0 +1,202 @@
+use crate::i18n::{t, t_with_args};
+use serde::Deserialize;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+/// A change to the selection, applied by the ballot's `update`
+type SelectionEdit = Box<dyn Fn(&mut Vec<u64>)>;
+
+/// An option of a multi-option proposal, with the ballots that picked it
+/// (first preferences for ranked choice)
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct ProposalOption {
+    pub id: u64,
+    pub label: String,
+    pub votes: i64,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct OptionBallotProps {
+    /// `SingleChoice`, `Approval` or `RankedChoice`
+    pub ballot_type: String,
+    #[prop_or_default]
+    pub max_selections: Option<i32>,
+    pub options: Vec<ProposalOption>,
+    /// The caller's current selection, first preference first
+    #[prop_or_default]
+    pub user_selection: Vec<u64>,
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// Emits the selected option ids; an empty list abstains
+    pub on_submit: Callback<Vec<u64>>,
+    /// Name of the radio group, unique when several ballots share a page
+    #[prop_or(AttrValue::Static("ballot-option"))]
+    pub input_name: AttrValue,
+}
+
+/// Ballot for single choice, approval and ranked-choice proposals
+#[function_component(OptionBallot)]
+pub fn option_ballot(props: &OptionBallotProps) -> Html {
+    let selection = use_state(|| props.user_selection.clone());
+
+    {
+        let selection = selection.clone();
+        use_effect_with(props.user_selection.clone(), move |current| {
+            selection.set(current.clone());
+            || ()
+        });
+    }
+
+    let label_of = |id: u64| {
+        props
+            .options
+            .iter()
+            .find(|o| o.id == id)
+            .map(|o| o.label.clone())
+            .unwrap_or_default()
+    };
+
+    let update = {
+        let selection = selection.clone();
+        move |f: SelectionEdit| {
+            let mut next = (*selection).clone();
+            f(&mut next);
+            selection.set(next);
+        }
+    };
+
+    let on_submit = {
+        let selection = selection.clone();
+        let cb = props.on_submit.clone();
+        Callback::from(move |_: MouseEvent| cb.emit((*selection).clone()))
+    };
+    let on_abstain = {
+        let cb = props.on_submit.clone();
+        Callback::from(move |_: MouseEvent| cb.emit(Vec::new()))
+    };
+
+    let at_max = props
+        .max_selections
+        .is_some_and(|max| selection.len() >= max as usize);
+
+    let body = match props.ballot_type.as_str() {
+        "RankedChoice" => {
+            let ranked = (*selection).clone();
+            let count = ranked.len();
+            html! {
+                <>
+                    <div class="small text-muted mb-2">{t("voting-ranked-help")}</div>
+                    if !ranked.is_empty() {
+                        <ol class="list-group list-group-numbered mb-2">
+                            { for ranked.iter().enumerate().map(|(i, id)| {
+                                let id = *id;
+                                let up = {
+                                    let update = update.clone();
+                                    Callback::from(move |_: MouseEvent| update(Box::new(move |s: &mut Vec<u64>| s.swap(i - 1, i))))
+                                };
+                                let down = {
+                                    let update = update.clone();
+                                    Callback::from(move |_: MouseEvent| update(Box::new(move |s: &mut Vec<u64>| s.swap(i, i + 1))))
+                                };
+                                let remove = {
+                                    let update = update.clone();
+                                    Callback::from(move |_: MouseEvent| update(Box::new(move |s: &mut Vec<u64>| s.retain(|o| *o != id))))
+                                };
+                                html! {
+                                    <li class="list-group-item d-flex justify-content-between align-items-center" key={id}>
+                                        <span class="ms-2 me-auto">{label_of(id)}</span>
+                                        <div class="btn-group btn-group-sm">
+                                            <button type="button" class="btn btn-outline-secondary" disabled={props.disabled || i == 0} onclick={up}>
+                                                <i class="bi bi-arrow-up"></i>
+                                            </button>
+                                            <button type="button" class="btn btn-outline-secondary" disabled={props.disabled || i + 1 == count} onclick={down}>
+                                                <i class="bi bi-arrow-down"></i>
+                                            </button>
+                                            <button type="button" class="btn btn-outline-danger" disabled={props.disabled} onclick={remove}>
+                                                <i class="bi bi-x"></i>
+                                            </button>
+                                        </div>
+                                    </li>
+                                }
+                            }) }
+                        </ol>
+                    }
+                    <div class="d-flex flex-wrap gap-1 mb-2">
+                        { for props.options.iter().filter(|o| !ranked.contains(&o.id)).map(|o| {
+                            let id = o.id;
+                            let add = {
+                                let update = update.clone();
+                                Callback::from(move |_: MouseEvent| update(Box::new(move |s: &mut Vec<u64>| s.push(id))))
+                            };
+                            html! {
+                                <button type="button" class="btn btn-sm btn-outline-primary" key={o.id} disabled={props.disabled} onclick={add}>
+                                    <i class="bi bi-plus me-1"></i>{&o.label}
+                                </button>
+                            }
+                        }) }
+                    </div>
+                </>
+            }
+        }
+        kind => {
+            let multiple = kind == "Approval";
+            html! {
+                <>
+                    if let Some(max) = props.max_selections.filter(|_| multiple) {
+                        <div class="small text-muted mb-2">
+                            {t_with_args("voting-approval-max", &[("count", &max.to_string())])}
+                        </div>
+                    }
+                    { for props.options.iter().map(|o| {
+                        let id = o.id;
+                        let checked = selection.contains(&id);
+                        let onchange = {
+                            let update = update.clone();
+                            Callback::from(move |e: Event| {
+                                let on = e.target_unchecked_into::<HtmlInputElement>().checked();
+                                update(Box::new(move |s: &mut Vec<u64>| {
+                                    if !multiple {
+                                        s.clear();
+                                    }
+                                    s.retain(|o| *o != id);
+                                    if on {
+                                        s.push(id);
+                                    }
+                                }))
+                            })
+                        };
+                        let input_id = format!("{}-{}", props.input_name, id);
+                        html! {
+                            <div class="form-check" key={id}>
+                                <input
+                                    class="form-check-input"
+                                    type={if multiple { "checkbox" } else { "radio" }}
+                                    name={props.input_name.clone()}
+                                    id={input_id.clone()}
+                                    {checked}
+                                    disabled={props.disabled || (multiple && at_max && !checked)}
+                                    {onchange}
+                                />
+                                <label class="form-check-label" for={input_id}>{&o.label}</label>
+                            </div>
+                        }
+                    }) }
+                </>
+            }
+        }
+    };
+
+    html! {
+        <div>
+            {body}
+            <div class="d-grid gap-2 mt-3">
+                <button class="btn btn-primary" disabled={props.disabled || selection.is_empty()} onclick={on_submit}>
+                    {t("voting-submit-ballot")}
+                </button>
+                <button class="btn btn-outline-secondary" disabled={props.disabled} onclick={on_abstain}>
+                    {t("voting-vote-abstain")}
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,99 @@
+use super::ProposalOption;
+use crate::i18n::{t, t_with_args};
+use serde::Deserialize;
+use yew::prelude::*;
+
+/// One option's weight in one tally round
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct ResultRound {
+    pub round: i32,
+    pub option_id: u64,
+    pub weight: String,
+    pub eliminated: bool,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct OptionResultsProps {
+    pub ballot_type: String,
+    pub options: Vec<ProposalOption>,
+    #[prop_or_default]
+    pub rounds: Vec<ResultRound>,
+    #[prop_or_default]
+    pub winning_option_id: Option<u64>,
+}
+
+/// Live option counts and, once tallied, the weight of every option per round
+#[function_component(OptionResults)]
+pub fn option_results(props: &OptionResultsProps) -> Html {
+    let total: i64 = props.options.iter().map(|o| o.votes).sum();
+    let round_count = props.rounds.iter().map(|r| r.round).max().unwrap_or(0);
+    let count_label = if props.ballot_type == "RankedChoice" {
+        t("voting-first-preferences")
+    } else {
+        t("voting-ballots")
+    };
+
+    html! {
+        <div>
+            <h5>{t("voting-option-counts")}</h5>
+            { for props.options.iter().map(|o| {
+                let pct = if total > 0 { o.votes as f64 * 100.0 / total as f64 } else { 0.0 };
+                let winner = props.winning_option_id == Some(o.id);
+                html! {
+                    <div class="mb-2" key={o.id}>
+                        <div class="d-flex justify-content-between small">
+                            <span>
+                                if winner {
+                                    <i class="bi bi-trophy-fill text-warning me-1"></i>
+                                }
+                                {&o.label}
+                            </span>
+                            <span>{format!("{} {}", o.votes, count_label)}</span>
+                        </div>
+                        <div class="progress" style="height: 8px;">
+                            <div
+                                class={classes!("progress-bar", winner.then_some("bg-success"))}
+                                role="progressbar"
+                                style={format!("width: {:.1}%", pct)}
+                            ></div>
+                        </div>
+                    </div>
+                }
+            }) }
+
+            if round_count > 0 {
+                <h6 class="mt-3">{t("voting-rounds")}</h6>
+                <div class="table-responsive">
+                    <table class="table table-sm small align-middle">
+                        <thead>
+                            <tr>
+                                <th>{t("voting-option")}</th>
+                                { for (1..=round_count).map(|r| html! {
+                                    <th class="text-end">{t_with_args("voting-round-number", &[("number", &r.to_string())])}</th>
+                                }) }
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for props.options.iter().map(|o| html! {
+                                <tr key={o.id}>
+                                    <td>{&o.label}</td>
+                                    { for (1..=round_count).map(|r| {
+                                        match props.rounds.iter().find(|e| e.round == r && e.option_id == o.id) {
+                                            Some(e) if e.eliminated => html! {
+                                                <td class="text-end text-danger" title={t("voting-eliminated")}>
+                                                    <s>{&e.weight}</s>
+                                                </td>
+                                            },
+                                            Some(e) => html! { <td class="text-end">{&e.weight}</td> },
+                                            None => html! { <td class="text-end text-muted">{"—"}</td> },
+                                        }
+                                    }) }
+                                </tr>
+                            }) }
+                        </tbody>
+                    </table>
+                </div>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,323 @@
+use super::{OptionBallot, ProposalOption};
+use crate::components::{Select, SelectOption};
+use crate::i18n::{t, t_with_args};
+use crate::services::{ApiError, api_client};
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+/// A delegator the current user holds a proxy for on a proposal
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct ProxyVoter {
+    pub user_id: u64,
+    pub name: String,
+    pub vote: Option<String>,
+    pub voted_in_person: bool,
+}
+
+/// A granted proxy with the names of both sides
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct ProxyWithNames {
+    pub id: u64,
+    pub grantor_id: u64,
+    pub grantee_id: u64,
+    pub proposal_id: Option<u64>,
+    pub building_id: Option<u64>,
+    pub valid_from: Option<String>,
+    pub valid_until: Option<String>,
+    pub created_at: Option<String>,
+    pub revoked_at: Option<String>,
+    pub grantor_name: String,
+    pub grantee_name: String,
+    pub proposal_title: Option<String>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct MyProxies {
+    pub granted: Vec<ProxyWithNames>,
+    pub received: Vec<ProxyWithNames>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct UserInfo {
+    id: u64,
+    name: String,
+    email: String,
+}
+
+#[derive(Serialize)]
+struct GrantProxyPayload {
+    grantee_id: u64,
+    proposal_id: Option<u64>,
+}
+
+/// A vote cast for a delegator; `option_ids` is used on option ballots
+#[derive(Clone, PartialEq, Debug)]
+pub struct ProxyBallot {
+    pub on_behalf_of: u64,
+    pub choice: String,
+    pub option_ids: Vec<u64>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ProxyVotesProps {
+    pub proxies: Vec<ProxyVoter>,
+    pub ballot_type: String,
+    #[prop_or_default]
+    pub max_selections: Option<i32>,
+    #[prop_or_default]
+    pub options: Vec<ProposalOption>,
+    #[prop_or(false)]
+    pub disabled: bool,
+    pub on_vote: Callback<ProxyBallot>,
+}
+
+/// Votes the current user can cast for owners who delegated to them
+#[function_component(ProxyVotes)]
+pub fn proxy_votes(props: &ProxyVotesProps) -> Html {
+    html! {
+        <div class="card mt-3">
+            <div class="card-header">
+                <h5 class="mb-0">{t("voting-proxy-votes")}</h5>
+            </div>
+            <ul class="list-group list-group-flush">
+                { for props.proxies.iter().map(|voter| {
+                    let grantor = voter.user_id;
+                    let vote_choice = |choice: &'static str| {
+                        let on_vote = props.on_vote.clone();
+                        Callback::from(move |_: MouseEvent| on_vote.emit(ProxyBallot {
+                            on_behalf_of: grantor,
+                            choice: choice.to_string(),
+                            option_ids: Vec::new(),
+                        }))
+                    };
+                    html! {
+                        <li class="list-group-item" key={grantor}>
+                            <div class="d-flex justify-content-between align-items-center mb-2">
+                                <strong>{&voter.name}</strong>
+                                if let Some(vote) = &voter.vote {
+                                    <span class="badge bg-light text-dark">{vote}</span>
+                                }
+                            </div>
+                            if voter.voted_in_person {
+                                <div class="small text-muted">{t("voting-proxy-voted-in-person")}</div>
+                            } else if props.ballot_type != "YesNo" {
+                                <OptionBallot
+                                    ballot_type={props.ballot_type.clone()}
+                                    max_selections={props.max_selections}
+                                    options={props.options.clone()}
+                                    disabled={props.disabled}
+                                    input_name={AttrValue::from(format!("proxy-{}-option", grantor))}
+                                    on_submit={{
+                                        let on_vote = props.on_vote.clone();
+                                        Callback::from(move |option_ids: Vec<u64>| on_vote.emit(ProxyBallot {
+                                            on_behalf_of: grantor,
+                                            choice: if option_ids.is_empty() { "Abstain".into() } else { String::new() },
+                                            option_ids,
+                                        }))
+                                    }}
+                                />
+                            } else {
+                                <div class="btn-group btn-group-sm w-100">
+                                    <button class="btn btn-outline-success" disabled={props.disabled} onclick={vote_choice("Yes")}>
+                                        {t("voting-vote-yes")}
+                                    </button>
+                                    <button class="btn btn-outline-danger" disabled={props.disabled} onclick={vote_choice("No")}>
+                                        {t("voting-vote-no")}
+                                    </button>
+                                    <button class="btn btn-outline-secondary" disabled={props.disabled} onclick={vote_choice("Abstain")}>
+                                        {t("voting-vote-abstain")}
+                                    </button>
+                                </div>
+                            }
+                        </li>
+                    }
+                }) }
+            </ul>
+            <div class="card-footer small text-muted">{t("voting-proxy-votes-help")}</div>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ProxyGrantProps {
+    pub proposal_id: u64,
+    pub token: Option<String>,
+    pub on_error: Callback<String>,
+    pub on_success: Callback<String>,
+}
+
+/// Lets an eligible voter delegate their vote on one proposal, or revoke that proxy
+#[function_component(ProxyGrant)]
+pub fn proxy_grant(props: &ProxyGrantProps) -> Html {
+    let users = use_state(Vec::<UserInfo>::new);
+    let mine = use_state(MyProxies::default);
+    let grantee = use_state(String::new);
+    let busy = use_state(|| false);
+    let reload = use_state(|| 0u32);
+
+    {
+        let users = users.clone();
+        let mine = mine.clone();
+        let token = props.token.clone();
+
+        use_effect_with(*reload, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client.get::<MyProxies>("/proxies/mine").await {
+                    mine.set(list);
+                }
+                if let Ok(list) = client.get::<Vec<UserInfo>>("/users/public").await {
+                    users.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    let current = mine
+        .granted
+        .iter()
+        .find(|p| p.proposal_id == Some(props.proposal_id))
+        .cloned();
+
+    let on_grant = {
+        let grantee = grantee.clone();
+        let busy = busy.clone();
+        let reload = reload.clone();
+        let proposal_id = props.proposal_id;
+        let token = props.token.clone();
+        let on_error = props.on_error.clone();
+        let on_success = props.on_success.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let Ok(grantee_id) = grantee.parse::<u64>() else {
+                return;
+            };
+            let busy = busy.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            let on_error = on_error.clone();
+            let on_success = on_success.clone();
+
+            busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                let payload = GrantProxyPayload {
+                    grantee_id,
+                    proposal_id: Some(proposal_id),
+                };
+                match client.post::<_, ProxyWithNames>("/proxies", &payload).await {
+                    Ok(proxy) => {
+                        on_success.emit(t_with_args(
+                            "voting-proxy-granted",
+                            &[("name", &proxy.grantee_name)],
+                        ));
+                        reload.set(*reload + 1);
+                    }
+                    Err(ApiError::BadRequest(msg)) => on_error.emit(msg),
+                    Err(e) => on_error.emit(t_with_args(
+                        "voting-proxy-failed-grant",
+                        &[("error", &e.to_string())],
+                    )),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_revoke = {
+        let busy = busy.clone();
+        let reload = reload.clone();
+        let token = props.token.clone();
+        let on_error = props.on_error.clone();
+        let on_success = props.on_success.clone();
+
+        Callback::from(move |id: u64| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(&t("voting-proxy-confirm-revoke"))
+                        .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let busy = busy.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            let on_error = on_error.clone();
+            let on_success = on_success.clone();
+
+            busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client.delete_no_response(&format!("/proxies/{}", id)).await {
+                    Ok(()) => {
+                        on_success.emit(t("voting-proxy-revoked"));
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => on_error.emit(t_with_args(
+                        "voting-proxy-failed-revoke",
+                        &[("error", &e.to_string())],
+                    )),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let user_options = {
+        let mut options = vec![SelectOption::new("", t("voting-proxy-select-holder"))];
+        for user in users.iter() {
+            options.push(SelectOption::new(
+                user.id.to_string(),
+                format!("{} ({})", user.name, user.email),
+            ));
+        }
+        options
+    };
+
+    html! {
+        <div class="card mt-3">
+            <div class="card-header">
+                <h5 class="mb-0">{t("voting-proxy")}</h5>
+            </div>
+            <div class="card-body">
+                if let Some(proxy) = current {
+                    <p class="small mb-2">
+                        {t_with_args("voting-proxy-held-by", &[("name", &proxy.grantee_name)])}
+                    </p>
+                    <button
+                        class="btn btn-outline-danger btn-sm w-100"
+                        disabled={*busy}
+                        onclick={{
+                            let on_revoke = on_revoke.clone();
+                            let id = proxy.id;
+                            Callback::from(move |_: MouseEvent| on_revoke.emit(id))
+                        }}
+                    >
+                        {t("voting-proxy-revoke")}
+                    </button>
+                } else {
+                    <p class="small text-muted">{t("voting-proxy-grant-help")}</p>
+                    <Select
+                        value={(*grantee).clone()}
+                        on_change={{
+                            let grantee = grantee.clone();
+                            Callback::from(move |value: String| grantee.set(value))
+                        }}
+                        options={user_options}
+                        disabled={*busy}
+                    />
+                    <button
+                        class="btn btn-outline-primary btn-sm w-100"
+                        disabled={*busy || grantee.is_empty()}
+                        onclick={on_grant}
+                    >
+                        {t("voting-proxy-grant")}
+                    </button>
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,121 @@
+use super::ProposalOption;
+use crate::components::TextInput;
+use crate::i18n::{t, t_with_args};
+use crate::services::api_client;
+use serde::Deserialize;
+use yew::prelude::*;
+
+/// A counted secret ballot as published after the tally
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct PublishedBallot {
+    pub receipt: String,
+    pub choice: String,
+    #[serde(default)]
+    pub option_ids: Vec<u64>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReceiptCheckProps {
+    pub proposal_id: u64,
+    pub token: Option<String>,
+    #[prop_or_default]
+    pub options: Vec<ProposalOption>,
+}
+
+/// Looks up a secret ballot receipt in the published ballots of a tallied proposal
+#[function_component(ReceiptCheck)]
+pub fn receipt_check(props: &ReceiptCheckProps) -> Html {
+    let ballots = use_state(|| None::<Vec<PublishedBallot>>);
+    let code = use_state(String::new);
+    let checked = use_state(|| None::<String>);
+
+    {
+        let ballots = ballots.clone();
+        let token = props.token.clone();
+
+        use_effect_with(props.proposal_id, move |id| {
+            let id = *id;
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client
+                    .get::<Vec<PublishedBallot>>(&format!("/proposals/{}/receipts", id))
+                    .await
+                {
+                    ballots.set(Some(list));
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_check = {
+        let code = code.clone();
+        let checked = checked.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            checked.set(Some(code.trim().to_uppercase()));
+        })
+    };
+
+    let label_of = |id: &u64| {
+        props
+            .options
+            .iter()
+            .find(|o| o.id == *id)
+            .map(|o| o.label.clone())
+            .unwrap_or_default()
+    };
+
+    let Some(list) = (*ballots).clone() else {
+        return html! {};
+    };
+    let found = (*checked)
+        .as_ref()
+        .map(|c| list.iter().find(|b| &b.receipt == c).cloned());
+
+    html! {
+        <div class="card mt-3">
+            <div class="card-header">
+                <h5 class="mb-0">{t("voting-receipt-check")}</h5>
+            </div>
+            <div class="card-body">
+                <p class="small text-muted">
+                    {t_with_args("voting-receipt-published", &[("count", &list.len().to_string())])}
+                </p>
+                <form onsubmit={on_check}>
+                    <TextInput
+                        value={(*code).clone()}
+                        on_change={{
+                            let code = code.clone();
+                            Callback::from(move |value: String| code.set(value))
+                        }}
+                        placeholder="XXXX-XXXX-XXXX"
+                    />
+                    <button type="submit" class="btn btn-outline-primary btn-sm w-100" disabled={code.trim().is_empty()}>
+                        {t("voting-receipt-verify")}
+                    </button>
+                </form>
+                {
+                    match found {
+                        Some(Some(ballot)) => html! {
+                            <div class="alert alert-success small mt-3 mb-0">
+                                <div><strong>{t("voting-receipt-counted")}</strong></div>
+                                if ballot.option_ids.is_empty() {
+                                    <div>{&ballot.choice}</div>
+                                } else {
+                                    <ol class="mb-0 ps-3">
+                                        { for ballot.option_ids.iter().map(|id| html! { <li>{label_of(id)}</li> }) }
+                                    </ol>
+                                }
+                            </div>
+                        },
+                        Some(None) => html! {
+                            <div class="alert alert-danger small mt-3 mb-0">{t("voting-receipt-not-found")}</div>
+                        },
+                        None => html! {},
+                    }
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,152 @@
+use crate::i18n::{t, t_with_args};
+use crate::services::api_client;
+use crate::utils::datetime::format_dt_local;
+use serde::Deserialize;
+use yew::prelude::*;
+
+/// An apartment the user votes for on a PerSeat proposal
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct SeatVote {
+    pub apartment_id: u64,
+    pub apartment_number: String,
+    pub building_address: String,
+    pub vote: Option<String>,
+    pub voted_by: Option<String>,
+    #[serde(default)]
+    pub selection: Vec<u64>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+struct SeatHistoryEntry {
+    user_name: String,
+    cast_by_name: Option<String>,
+    choice: String,
+    created_at: String,
+}
+
+/// Yes/No/Abstain as voted; option ballots and secret ballots only as "voted"
+fn describe_vote(choice: &str) -> String {
+    match choice {
+        "Yes" => t("voting-vote-yes"),
+        "No" => t("voting-vote-no"),
+        "Abstain" => t("voting-vote-abstain"),
+        _ => t("voting-seat-voted"),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SeatVotesProps {
+    pub proposal_id: u64,
+    pub token: Option<String>,
+    pub seats: Vec<SeatVote>,
+    /// Apartment the ballot below is cast for
+    pub selected: u64,
+    pub on_select: Callback<u64>,
+    /// Secret ballots keep no history
+    #[prop_or(false)]
+    pub secret: bool,
+}
+
+/// The user's apartments on a PerSeat proposal, each with its current vote; one vote
+/// per apartment, cast by any of its owners
+#[function_component(SeatVotes)]
+pub fn seat_votes(props: &SeatVotesProps) -> Html {
+    let history = use_state(|| None::<(u64, Vec<SeatHistoryEntry>)>);
+
+    let on_history = {
+        let history = history.clone();
+        let token = props.token.clone();
+        let id = props.proposal_id;
+        Callback::from(move |apartment_id: u64| {
+            if history
+                .as_ref()
+                .is_some_and(|(shown, _)| *shown == apartment_id)
+            {
+                history.set(None);
+                return;
+            }
+            let history = history.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(entries) = client
+                    .get::<Vec<SeatHistoryEntry>>(&format!(
+                        "/proposals/{}/seats/{}/history",
+                        id, apartment_id
+                    ))
+                    .await
+                {
+                    history.set(Some((apartment_id, entries)));
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="mb-3">
+            <div class="small fw-semibold mb-1">{t("voting-seats-title")}</div>
+            <div class="list-group small">
+                { for props.seats.iter().map(|seat| {
+                    let apartment_id = seat.apartment_id;
+                    let selected = apartment_id == props.selected;
+                    let on_select = props.on_select.clone();
+                    let on_history = on_history.clone();
+                    let shown = history
+                        .as_ref()
+                        .filter(|(shown, _)| *shown == apartment_id)
+                        .map(|(_, entries)| entries.clone());
+                    html! {
+                        <div
+                            key={apartment_id}
+                            class={classes!("list-group-item", "list-group-item-action", selected.then_some("active"))}
+                            role="button"
+                            onclick={Callback::from(move |_| on_select.emit(apartment_id))}
+                        >
+                            <div class="d-flex justify-content-between align-items-center">
+                                <span>
+                                    {t_with_args("voting-seat-label", &[
+                                        ("number", &seat.apartment_number),
+                                        ("address", &seat.building_address),
+                                    ])}
+                                </span>
+                                <span class={classes!("badge", if seat.vote.is_some() { "bg-success" } else { "bg-secondary" })}>
+                                    {seat.vote.as_deref().map(describe_vote).unwrap_or_else(|| t("voting-seat-not-voted"))}
+                                </span>
+                            </div>
+                            if let Some(name) = &seat.voted_by {
+                                <div class={classes!(if selected { "text-white-50" } else { "text-muted" })}>
+                                    {t_with_args("voting-seat-voted-by", &[("name", name)])}
+                                </div>
+                            }
+                            if !props.secret && seat.vote.is_some() {
+                                <button
+                                    type="button"
+                                    class={classes!("btn", "btn-sm", "btn-link", "p-0", selected.then_some("text-white"))}
+                                    onclick={Callback::from(move |e: MouseEvent| {
+                                        e.stop_propagation();
+                                        on_history.emit(apartment_id);
+                                    })}
+                                >
+                                    {t("voting-seat-history")}
+                                </button>
+                            }
+                            if let Some(entries) = shown {
+                                <ul class="list-unstyled mb-0 mt-1">
+                                    { for entries.iter().map(|e| html! {
+                                        <li>
+                                            {format_dt_local(&e.created_at)}{" - "}{&e.user_name}
+                                            if let Some(proxy) = &e.cast_by_name {
+                                                {" "}{t_with_args("voting-seat-history-by-proxy", &[("name", proxy)])}
+                                            }
+                                            {": "}{describe_vote(&e.choice)}
+                                        </li>
+                                    }) }
+                                </ul>
+                            }
+                        </div>
+                    }
+                }) }
+            </div>
+        </div>
+    }
+}
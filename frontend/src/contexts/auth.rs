@@ -1,4 +1,4 @@
-use base64::{Engine as _, engine::general_purpose};
+use crate::utils::auth::{Claims, decode_token};
 use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 use web_sys::window;
@@ -12,6 +12,17 @@ pub struct User {
     pub roles: Vec<String>,
 }
 
+impl From<Claims> for User {
+    fn from(claims: Claims) -> Self {
+        User {
+            id: claims.sub.parse().unwrap_or(0),
+            email: claims.email,
+            name: claims.name,
+            roles: claims.roles,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct AuthState {
     pub token: Option<String>,
@@ -150,15 +161,15 @@ fn load_auth_from_storage() -> AuthState {
     let user_json = storage.get_item("auth.user").ok().flatten();
 
     // Validate token expiry before loading
-    if let Some(ref token_str) = token {
-        if !is_token_valid(token_str) {
-            // Token is expired or invalid, clear storage
-            clear_auth_from_storage();
-            return AuthState {
-                token: None,
-                user: None,
-            };
-        }
+    if let Some(ref token_str) = token
+        && !is_token_valid(token_str)
+    {
+        // Token is expired or invalid, clear storage
+        clear_auth_from_storage();
+        return AuthState {
+            token: None,
+            user: None,
+        };
     }
 
     let user = user_json.and_then(|json| serde_json::from_str::<User>(&json).ok());
@@ -167,71 +178,29 @@ fn load_auth_from_storage() -> AuthState {
 }
 
 fn save_auth_to_storage(token: &str, user: &User) {
-    if let Some(window) = window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            let _ = storage.set_item("auth.token", token);
-            if let Ok(user_json) = serde_json::to_string(user) {
-                let _ = storage.set_item("auth.user", &user_json);
-            }
+    if let Some(window) = window()
+        && let Ok(Some(storage)) = window.local_storage()
+    {
+        let _ = storage.set_item("auth.token", token);
+        if let Ok(user_json) = serde_json::to_string(user) {
+            let _ = storage.set_item("auth.user", &user_json);
         }
     }
 }
 
 fn clear_auth_from_storage() {
-    if let Some(window) = window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            let _ = storage.remove_item("auth.token");
-            let _ = storage.remove_item("auth.user");
-        }
+    if let Some(window) = window()
+        && let Ok(Some(storage)) = window.local_storage()
+    {
+        let _ = storage.remove_item("auth.token");
+        let _ = storage.remove_item("auth.user");
     }
 }
 
 /// Checks if a JWT token is valid (not expired)
 /// Returns false if token is malformed or expired
 fn is_token_valid(token: &str) -> bool {
-    // JWT format: header.payload.signature
-    let parts: Vec<&str> = token.split('.').collect();
-    if parts.len() != 3 {
-        return false;
-    }
-
-    // Decode the payload (second part)
-    let payload = parts[1];
-
-    // Add padding if needed (base64url requires it)
-    let padding = match payload.len() % 4 {
-        0 => "",
-        2 => "==",
-        3 => "=",
-        _ => return false,
-    };
-    let padded = format!("{}{}", payload, padding);
-
-    // Decode from base64url (replace - with + and _ with /)
-    let normalized = padded.replace('-', "+").replace('_', "/");
-
-    let decoded = match general_purpose::STANDARD.decode(&normalized) {
-        Ok(d) => d,
-        Err(_) => return false,
-    };
-
-    let payload_str = match String::from_utf8(decoded) {
-        Ok(s) => s,
-        Err(_) => return false,
-    };
-
-    // Parse as JSON and check expiry
-    #[derive(Deserialize)]
-    struct TokenPayload {
-        exp: u64,
-    }
-
-    let payload_data: TokenPayload = match serde_json::from_str(&payload_str) {
-        Ok(p) => p,
-        Err(_) => return false,
-    };
-
     // Check if token is expired (exp is in seconds since epoch)
     let current_time = (js_sys::Date::now() / 1000.0) as u64;
-    payload_data.exp > current_time
+    decode_token(token).is_some_and(|claims| claims.exp > current_time)
 }
@@ -28,7 +28,7 @@ pub struct LanguageProviderProps {
 /// trigger re-renders of all descendant components that use `t()`.
 #[function_component(LanguageProvider)]
 pub fn language_provider(props: &LanguageProviderProps) -> Html {
-    let language = use_state(|| current_language());
+    let language = use_state(current_language);
 
     let set_lang = {
         let language = language.clone();
@@ -27,25 +27,22 @@ thread_local! {
 pub fn t(key: &str) -> String {
     BUNDLES.with(|bundles| {
         let lang = CURRENT_LANG_CODE.with(|l| l.borrow().clone());
-        if let Some(bundle) = bundles.get(&lang) {
-            if let Some(msg) = bundle.get_message(key) {
-                if let Some(pattern) = msg.value() {
-                    let mut errors = vec![];
-                    let value = bundle.format_pattern(pattern, None, &mut errors);
-                    return value.to_string();
-                }
-            }
+        if let Some(bundle) = bundles.get(&lang)
+            && let Some(msg) = bundle.get_message(key)
+            && let Some(pattern) = msg.value()
+        {
+            let mut errors = vec![];
+            let value = bundle.format_pattern(pattern, None, &mut errors);
+            return value.to_string();
         }
-        if lang != "en" {
-            if let Some(bundle) = bundles.get("en") {
-                if let Some(msg) = bundle.get_message(key) {
-                    if let Some(pattern) = msg.value() {
-                        let mut errors = vec![];
-                        let value = bundle.format_pattern(pattern, None, &mut errors);
-                        return value.to_string();
-                    }
-                }
-            }
+        if lang != "en"
+            && let Some(bundle) = bundles.get("en")
+            && let Some(msg) = bundle.get_message(key)
+            && let Some(pattern) = msg.value()
+        {
+            let mut errors = vec![];
+            let value = bundle.format_pattern(pattern, None, &mut errors);
+            return value.to_string();
         }
         key.to_string()
     })
@@ -59,25 +56,22 @@ pub fn t_with_args(key: &str, args: &[(&str, &str)]) -> String {
         for (k, v) in args {
             fluent_args.set(*k, fluent_bundle::FluentValue::from(*v));
         }
-        if let Some(bundle) = bundles.get(&lang) {
-            if let Some(msg) = bundle.get_message(key) {
-                if let Some(pattern) = msg.value() {
-                    let mut errors = vec![];
-                    let value = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
-                    return value.to_string();
-                }
-            }
+        if let Some(bundle) = bundles.get(&lang)
+            && let Some(msg) = bundle.get_message(key)
+            && let Some(pattern) = msg.value()
+        {
+            let mut errors = vec![];
+            let value = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+            return value.to_string();
         }
-        if lang != "en" {
-            if let Some(bundle) = bundles.get("en") {
-                if let Some(msg) = bundle.get_message(key) {
-                    if let Some(pattern) = msg.value() {
-                        let mut errors = vec![];
-                        let value = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
-                        return value.to_string();
-                    }
-                }
-            }
+        if lang != "en"
+            && let Some(bundle) = bundles.get("en")
+            && let Some(msg) = bundle.get_message(key)
+            && let Some(pattern) = msg.value()
+        {
+            let mut errors = vec![];
+            let value = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+            return value.to_string();
         }
         key.to_string()
     })
@@ -85,12 +79,11 @@ pub fn t_with_args(key: &str, args: &[(&str, &str)]) -> String {
 
 pub fn init_translations() {
     BUNDLES.with(|_| {
-        if let Some(win) = window() {
-            if let Ok(Some(storage)) = win.local_storage() {
-                if let Ok(Some(lang)) = storage.get_item("app.lang") {
-                    set_language(&lang);
-                }
-            }
+        if let Some(win) = window()
+            && let Ok(Some(storage)) = win.local_storage()
+            && let Ok(Some(lang)) = storage.get_item("app.lang")
+        {
+            set_language(&lang);
         }
     });
 }
@@ -99,10 +92,10 @@ pub fn set_language(lang: &str) {
     BUNDLES.with(|bundles| {
         if bundles.contains_key(lang) {
             CURRENT_LANG_CODE.with(|c| *c.borrow_mut() = lang.to_string());
-            if let Some(win) = window() {
-                if let Ok(Some(storage)) = win.local_storage() {
-                    let _ = storage.set_item("app.lang", lang);
-                }
+            if let Some(win) = window()
+                && let Ok(Some(storage)) = win.local_storage()
+            {
+                let _ = storage.set_item("app.lang", lang);
             }
         }
     });
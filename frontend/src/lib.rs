@@ -1,4 +1,5 @@
 pub mod utils {
+    pub mod auth;
     pub mod datetime; // added for localized formatting
 }
 pub mod components;
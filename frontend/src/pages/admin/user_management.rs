@@ -24,7 +24,7 @@ const ALL_ROLES: &[&str] = &["Admin", "Manager", "Homeowner", "Renter", "HOA Mem
 pub fn admin_page() -> Html {
     let auth = use_context::<AuthContext>().expect("AuthContext not found");
 
-    let users = use_state(|| Vec::<UserWithRoles>::new());
+    let users = use_state(Vec::<UserWithRoles>::new);
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
     let success = use_state(|| None::<String>);
@@ -33,7 +33,7 @@ pub fn building_apartments_page() -> Html {
     };
 
     let building = use_state(|| None::<Building>);
-    let apartments = use_state(|| Vec::<Apartment>::new());
+    let apartments = use_state(Vec::<Apartment>::new);
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
 
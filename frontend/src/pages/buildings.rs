@@ -20,7 +20,7 @@ struct Building {
 pub fn buildings_page() -> Html {
     let auth = use_context::<AuthContext>().expect("AuthContext not found");
 
-    let buildings = use_state(|| Vec::<Building>::new());
+    let buildings = use_state(Vec::<Building>::new);
     let search_query = use_state(String::default);
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
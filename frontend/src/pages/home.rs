@@ -1,5 +1,6 @@
 use crate::components::ErrorAlert;
 use crate::components::announcement_list::AnnouncementList;
+use crate::components::announcements::FeedSubscriptions;
 use crate::contexts::AuthContext;
 use crate::i18n::t;
 use crate::routes::Route;
@@ -212,6 +213,7 @@ pub fn home() -> Html {
                             <AnnouncementList />
                         </div>
                     </div>
+                    <FeedSubscriptions token={auth.token().map(|t| t.to_string())} />
                 </div>
             </div>
         </div>
@@ -1,7 +1,7 @@
 use crate::contexts::{AuthContext, User};
 use crate::i18n::t;
 use crate::services::{ApiError, api_client};
-use base64::{Engine as _, engine::general_purpose};
+use crate::utils::auth::decode_token;
 use serde::{Deserialize, Serialize};
 use yew::prelude::*;
 
@@ -10,16 +10,6 @@ struct LoginResponse {
     token: String,
 }
 
-#[derive(Deserialize, Clone)]
-struct JwtClaims {
-    sub: String,
-    email: String,
-    name: String,
-    roles: Vec<String>,
-    #[allow(dead_code)]
-    exp: usize,
-}
-
 #[derive(Serialize)]
 struct LoginRequest {
     email: String,
@@ -33,31 +23,6 @@ struct RegisterRequest {
     password: String,
 }
 
-fn decode_jwt_claims(token: &str) -> Option<User> {
-    let parts: Vec<&str> = token.split('.').collect();
-    if parts.len() != 3 {
-        return None;
-    }
-    let payload = parts[1];
-    let padding = match payload.len() % 4 {
-        0 => "",
-        2 => "==",
-        3 => "=",
-        _ => return None,
-    };
-    let padded = format!("{}{}", payload, padding);
-    let normalized = padded.replace('-', "+").replace('_', "/");
-    let decoded = general_purpose::STANDARD.decode(&normalized).ok()?;
-    let json_str = String::from_utf8(decoded).ok()?;
-    let claims: JwtClaims = serde_json::from_str(&json_str).ok()?;
-    Some(User {
-        id: claims.sub.parse().unwrap_or(0),
-        email: claims.email,
-        name: claims.name,
-        roles: claims.roles,
-    })
-}
-
 #[function_component(LoginPage)]
 pub fn login_page() -> Html {
     let auth = use_context::<AuthContext>().expect("AuthContext not found");
@@ -132,7 +97,7 @@ pub fn login_page() -> Html {
                                 .await
                             {
                                 Ok(resp) => {
-                                    if let Some(user) = decode_jwt_claims(&resp.token) {
+                                    if let Some(user) = decode_token(&resp.token).map(User::from) {
                                         auth.login.emit((resp.token, user));
                                         if let Some(w) = web_sys::window() {
                                             let _ = w.location().set_href("/");
@@ -169,7 +134,7 @@ pub fn login_page() -> Html {
                         .await
                     {
                         Ok(resp) => {
-                            if let Some(user) = decode_jwt_claims(&resp.token) {
+                            if let Some(user) = decode_token(&resp.token).map(User::from) {
                                 auth.login.emit((resp.token, user));
                                 if let Some(w) = web_sys::window() {
                                     let _ = w.location().set_href("/");
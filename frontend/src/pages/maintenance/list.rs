@@ -32,7 +32,7 @@ pub fn maintenance_list_page() -> Html {
     let auth = use_context::<AuthContext>().expect("AuthContext not found");
     let navigator = use_navigator().unwrap();
 
-    let requests = use_state(|| Vec::<MaintenanceRequest>::new());
+    let requests = use_state(Vec::<MaintenanceRequest>::new);
     let pagination_meta = use_state(|| None::<PaginationMeta>);
     let current_page = use_state(|| 1i64);
     let filter_status = use_state(|| "All".to_string());
@@ -47,12 +47,21 @@ pub fn maintenance_list_page() -> Html {
         let error = error.clone();
         let token = auth.token().map(|t| t.to_string());
         let page = *current_page;
+        let status = (*filter_status).clone();
+        let query = search_query.trim().to_string();
 
-        use_effect_with(page, move |_| {
+        use_effect_with((page, status, query), move |(page, status, query)| {
+            let mut url = format!("/requests?page={}&per_page=20", page);
+            if status != "All" {
+                url.push_str(&format!("&status={}", status));
+            }
+            if !query.is_empty() {
+                let encoded = String::from(js_sys::encode_uri_component(query));
+                url.push_str(&format!("&q={}", encoded));
+            }
             wasm_bindgen_futures::spawn_local(async move {
                 loading.set(true);
                 let client = api_client(token.as_deref());
-                let url = format!("/requests?page={}&per_page=20", page);
                 match client
                     .get::<PaginatedResponse<MaintenanceRequest>>(&url)
                     .await
@@ -93,22 +102,15 @@ pub fn maintenance_list_page() -> Html {
 
     let on_search_change = {
         let search_query = search_query.clone();
-        Callback::from(move |val: String| search_query.set(val))
+        let current_page = current_page.clone();
+        Callback::from(move |val: String| {
+            search_query.set(val);
+            current_page.set(1);
+        })
     };
 
-    let query_lower = search_query.to_lowercase();
-    let filtered_requests: Vec<MaintenanceRequest> = requests
-        .iter()
-        .filter(|r| *filter_status == "All" || r.status == *filter_status)
-        .filter(|r| {
-            query_lower.is_empty()
-                || r.title.to_lowercase().contains(&query_lower)
-                || r.description.to_lowercase().contains(&query_lower)
-                || r.apartment_number.to_lowercase().contains(&query_lower)
-                || r.building_address.to_lowercase().contains(&query_lower)
-        })
-        .cloned()
-        .collect();
+    // Status and search are applied server-side so they cover all pages.
+    let filtered_requests: Vec<MaintenanceRequest> = (*requests).clone();
 
     let mut grouped_requests: HashMap<u64, (String, Vec<MaintenanceRequest>)> = HashMap::new();
     for req in filtered_requests.iter() {
@@ -161,6 +163,7 @@ pub fn maintenance_list_page() -> Html {
                     for ["All", "Open", "InProgress", "Resolved"].iter().map(|status| {
                         let status_str = status.to_string();
                         let filter_status = filter_status.clone();
+                        let current_page = current_page.clone();
                         let is_active = *filter_status == status_str;
                         let display = match *status {
                             "All" => t("maintenance-status-all"),
@@ -175,7 +178,10 @@ pub fn maintenance_list_page() -> Html {
                                 class={classes!("btn", "btn-sm", if is_active { "btn-primary" } else { "btn-outline-primary" })}
                                 onclick={{
                                     let status_str = status_str.clone();
-                                    Callback::from(move |_| filter_status.set(status_str.clone()))
+                                    Callback::from(move |_| {
+                                        filter_status.set(status_str.clone());
+                                        current_page.set(1);
+                                    })
                                 }}
                             >
                                 {display}
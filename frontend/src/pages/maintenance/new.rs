@@ -37,7 +37,7 @@ pub fn maintenance_new_page() -> Html {
     let auth = use_context::<AuthContext>().expect("AuthContext not found");
     let navigator = use_navigator().unwrap();
 
-    let apartments = use_state(|| Vec::<ApartmentWithBuilding>::new());
+    let apartments = use_state(Vec::<ApartmentWithBuilding>::new);
     let loading_apartments = use_state(|| true);
 
     let apartment_id = use_state(|| "".to_string());
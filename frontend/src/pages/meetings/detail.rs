@@ -0,0 +1,735 @@
+use super::list::{status_class, status_label};
+use crate::components::breadcrumb::BreadcrumbItem;
+use crate::components::{Breadcrumb, ErrorAlert, Select, SelectOption, SuccessAlert, Textarea};
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::routes::Route;
+use crate::services::{ApiError, api_client};
+use crate::utils::datetime::format_dt_local;
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct ProposalResult {
+    passed: bool,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct AgendaItem {
+    proposal_id: u64,
+    position: i32,
+    title: String,
+    status: String,
+    result: Option<ProposalResult>,
+    winning_option: Option<String>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct Attendee {
+    user_id: u64,
+    mode: String,
+    name: String,
+    proxy_holder_name: Option<String>,
+    represented_area: f64,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct Quorum {
+    percent: f64,
+    required_percent: i32,
+    reached: bool,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct MeetingDetail {
+    id: u64,
+    title: String,
+    description: String,
+    scheduled_at: String,
+    location: String,
+    online_url: Option<String>,
+    status: String,
+    started_at: Option<String>,
+    ended_at: Option<String>,
+    minutes_md: Option<String>,
+    building_address: String,
+    agenda: Vec<AgendaItem>,
+    attendance: Vec<Attendee>,
+    quorum: Quorum,
+    my_attendance: Option<String>,
+    can_attend: bool,
+    can_manage: bool,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct Minutes {
+    minutes_md: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct UserInfo {
+    id: u64,
+    name: String,
+    email: String,
+}
+
+#[derive(Serialize)]
+struct RegisterAttendancePayload {
+    user_id: Option<u64>,
+    mode: String,
+    proxy_holder_id: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct PublishMinutesPayload {
+    minutes_md: Option<String>,
+}
+
+fn mode_label(mode: &str) -> String {
+    match mode {
+        "InPerson" => t("meetings-mode-in-person"),
+        "Online" => t("meetings-mode-online"),
+        "Proxy" => t("meetings-mode-proxy"),
+        other => other.to_string(),
+    }
+}
+
+fn item_status_class(status: &str) -> &'static str {
+    match status {
+        "Open" => "bg-success",
+        "Closed" => "bg-warning text-dark",
+        "Tallied" => "bg-info text-dark",
+        _ => "bg-secondary",
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub id: u64,
+}
+
+#[function_component(MeetingDetailPage)]
+pub fn meeting_detail_page(props: &Props) -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+    let navigator = use_navigator().unwrap();
+
+    let meeting = use_state(|| None::<MeetingDetail>);
+    let users = use_state(Vec::<UserInfo>::new);
+    let draft = use_state(String::new);
+    let register_user = use_state(String::new);
+    let register_mode = use_state(|| "InPerson".to_string());
+    let register_holder = use_state(String::new);
+    let loading = use_state(|| true);
+    let busy = use_state(|| false);
+    let reload = use_state(|| 0u32);
+
+    let error = use_state(|| None::<String>);
+    let success = use_state(|| None::<String>);
+
+    let meeting_id = props.id;
+    let token = auth.token().map(|t| t.to_string());
+
+    {
+        let meeting = meeting.clone();
+        let users = users.clone();
+        let draft = draft.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let token = token.clone();
+
+        use_effect_with((meeting_id, *reload), move |(id, _)| {
+            let id = *id;
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get::<MeetingDetail>(&format!("/meetings/{}", id))
+                    .await
+                {
+                    Ok(m) => {
+                        if m.can_manage {
+                            if users.is_empty()
+                                && let Ok(list) = client.get::<Vec<UserInfo>>("/users/public").await
+                            {
+                                users.set(list);
+                            }
+                            if m.status == "Closed"
+                                && m.minutes_md.is_none()
+                                && let Ok(minutes) = client
+                                    .get::<Minutes>(&format!("/meetings/{}/minutes", id))
+                                    .await
+                            {
+                                draft.set(minutes.minutes_md);
+                            }
+                        }
+                        meeting.set(Some(m));
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "meetings-failed-load-meeting",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    // Posts to a meeting action endpoint and reloads on success
+    let action = {
+        let busy = busy.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let reload = reload.clone();
+        let token = token.clone();
+        move |endpoint: String, confirm_key: Option<&'static str>, success_key: &'static str| {
+            let busy = busy.clone();
+            let error = error.clone();
+            let success = success.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            Callback::from(move |_: MouseEvent| {
+                if let Some(key) = confirm_key {
+                    let confirmed = web_sys::window()
+                        .and_then(|w| w.confirm_with_message(&t(key)).ok())
+                        .unwrap_or(false);
+                    if !confirmed {
+                        return;
+                    }
+                }
+                let endpoint = endpoint.clone();
+                let busy = busy.clone();
+                let error = error.clone();
+                let success = success.clone();
+                let reload = reload.clone();
+                let token = token.clone();
+                busy.set(true);
+                error.set(None);
+                success.set(None);
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    let client = api_client(token.as_deref());
+                    match client.post_empty::<serde_json::Value>(&endpoint).await {
+                        Ok(_) => {
+                            success.set(Some(t(success_key)));
+                            reload.set(*reload + 1);
+                        }
+                        Err(ApiError::BadRequest(msg)) => error.set(Some(msg)),
+                        Err(e) => error.set(Some(t_with_args(
+                            "meetings-failed-action",
+                            &[("error", &e.to_string())],
+                        ))),
+                    }
+                    busy.set(false);
+                });
+            })
+        }
+    };
+
+    // Registers `user_id` (the caller when `None`) with the given mode
+    let register = {
+        let busy = busy.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let reload = reload.clone();
+        let token = token.clone();
+        move |payload: RegisterAttendancePayload| {
+            let busy = busy.clone();
+            let error = error.clone();
+            let success = success.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            busy.set(true);
+            error.set(None);
+            success.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, serde_json::Value>(
+                        &format!("/meetings/{}/attendance", meeting_id),
+                        &payload,
+                    )
+                    .await
+                {
+                    Ok(_) => {
+                        success.set(Some(t("meetings-attendance-registered")));
+                        reload.set(*reload + 1);
+                    }
+                    Err(ApiError::BadRequest(msg)) => error.set(Some(msg)),
+                    Err(e) => error.set(Some(t_with_args(
+                        "meetings-failed-register",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let remove_attendance = {
+        let busy = busy.clone();
+        let error = error.clone();
+        let reload = reload.clone();
+        let token = token.clone();
+        move |user_id: u64| {
+            let busy = busy.clone();
+            let error = error.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            Callback::from(move |_: MouseEvent| {
+                let busy = busy.clone();
+                let error = error.clone();
+                let reload = reload.clone();
+                let token = token.clone();
+                busy.set(true);
+                error.set(None);
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    let client = api_client(token.as_deref());
+                    match client
+                        .delete_no_response(&format!(
+                            "/meetings/{}/attendance/{}",
+                            meeting_id, user_id
+                        ))
+                        .await
+                    {
+                        Ok(_) => reload.set(*reload + 1),
+                        Err(ApiError::BadRequest(msg)) => error.set(Some(msg)),
+                        Err(e) => error.set(Some(t_with_args(
+                            "meetings-failed-action",
+                            &[("error", &e.to_string())],
+                        ))),
+                    }
+                    busy.set(false);
+                });
+            })
+        }
+    };
+
+    let on_register_other = {
+        let register = register.clone();
+        let register_user = register_user.clone();
+        let register_mode = register_mode.clone();
+        let register_holder = register_holder.clone();
+        let error = error.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let Ok(user_id) = register_user.parse::<u64>() else {
+                error.set(Some(t("meetings-register-user-required")));
+                return;
+            };
+            let proxy_holder_id = register_holder.parse::<u64>().ok();
+            if *register_mode == "Proxy" && proxy_holder_id.is_none() {
+                error.set(Some(t("meetings-register-holder-required")));
+                return;
+            }
+            register(RegisterAttendancePayload {
+                user_id: Some(user_id),
+                mode: (*register_mode).clone(),
+                proxy_holder_id: proxy_holder_id.filter(|_| *register_mode == "Proxy"),
+            });
+        })
+    };
+
+    let on_delete = {
+        let busy = busy.clone();
+        let error = error.clone();
+        let navigator = navigator.clone();
+        let token = token.clone();
+        Callback::from(move |_: MouseEvent| {
+            let confirmed = web_sys::window()
+                .and_then(|w| w.confirm_with_message(&t("meetings-delete-confirm")).ok())
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let busy = busy.clone();
+            let error = error.clone();
+            let navigator = navigator.clone();
+            let token = token.clone();
+            busy.set(true);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .delete_no_response(&format!("/meetings/{}", meeting_id))
+                    .await
+                {
+                    Ok(_) => navigator.push(&Route::Meetings),
+                    Err(e) => {
+                        error.set(Some(t_with_args(
+                            "meetings-failed-action",
+                            &[("error", &e.to_string())],
+                        )));
+                        busy.set(false);
+                    }
+                }
+            });
+        })
+    };
+
+    let on_publish = {
+        let draft = draft.clone();
+        let busy = busy.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let reload = reload.clone();
+        let token = token.clone();
+        Callback::from(move |_: MouseEvent| {
+            let payload = PublishMinutesPayload {
+                minutes_md: Some((*draft).clone()).filter(|m| !m.trim().is_empty()),
+            };
+            let busy = busy.clone();
+            let error = error.clone();
+            let success = success.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            busy.set(true);
+            error.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, serde_json::Value>(
+                        &format!("/meetings/{}/minutes", meeting_id),
+                        &payload,
+                    )
+                    .await
+                {
+                    Ok(_) => {
+                        success.set(Some(t("meetings-minutes-published")));
+                        reload.set(*reload + 1);
+                    }
+                    Err(ApiError::BadRequest(msg)) => error.set(Some(msg)),
+                    Err(e) => error.set(Some(t_with_args(
+                        "meetings-failed-action",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+    let clear_success = {
+        let success = success.clone();
+        Callback::from(move |_| success.set(None))
+    };
+
+    let breadcrumb = |title: String| {
+        html! {
+            <Breadcrumb items={vec![
+                BreadcrumbItem { label: t("meetings-title"), route: Some(Route::Meetings) },
+                BreadcrumbItem { label: title, route: None },
+            ]} />
+        }
+    };
+
+    if *loading {
+        return html! {
+            <div class="container mt-4 text-center py-5">
+                <div class="spinner-border" role="status">
+                    <span class="visually-hidden">{t("meetings-loading")}</span>
+                </div>
+            </div>
+        };
+    }
+
+    let Some(m) = (*meeting).clone() else {
+        return html! {
+            <div class="container mt-4">
+                {breadcrumb(t("meetings-not-found"))}
+                if let Some(err) = (*error).clone() {
+                    <ErrorAlert message={err} on_close={clear_error.clone()} />
+                } else {
+                    <div class="alert alert-warning">{t("meetings-not-found")}</div>
+                }
+            </div>
+        };
+    };
+
+    let base = format!("/meetings/{}", m.id);
+    let in_progress = m.status == "InProgress";
+    let user_options = {
+        let mut options = vec![SelectOption::new("", t("meetings-select-user"))];
+        for user in users.iter() {
+            options.push(SelectOption::new(
+                user.id.to_string(),
+                format!("{} ({})", user.name, user.email),
+            ));
+        }
+        options
+    };
+    let mode_options = vec![
+        SelectOption::new("InPerson", t("meetings-mode-in-person")),
+        SelectOption::new("Online", t("meetings-mode-online")),
+        SelectOption::new("Proxy", t("meetings-mode-proxy")),
+    ];
+    let self_register = |mode: &'static str| {
+        let register = register.clone();
+        Callback::from(move |_: MouseEvent| {
+            register(RegisterAttendancePayload {
+                user_id: None,
+                mode: mode.to_string(),
+                proxy_holder_id: None,
+            })
+        })
+    };
+    let quorum_class = if m.quorum.reached {
+        "bg-success"
+    } else {
+        "bg-warning"
+    };
+
+    html! {
+        <div class="container mt-4">
+            {breadcrumb(m.title.clone())}
+
+            if let Some(err) = (*error).clone() {
+                <ErrorAlert message={err} on_close={clear_error.clone()} />
+            }
+            if let Some(msg) = (*success).clone() {
+                <SuccessAlert message={msg} on_close={clear_success.clone()} />
+            }
+
+            <div class="card mb-3">
+                <div class="card-body">
+                    <div class="d-flex justify-content-between align-items-start mb-2">
+                        <h3 class="mb-0">{&m.title}</h3>
+                        <span class={classes!("badge", status_class(&m.status))}>
+                            {status_label(&m.status)}
+                        </span>
+                    </div>
+                    if !m.description.is_empty() {
+                        <p style="white-space: pre-wrap;">{&m.description}</p>
+                    }
+                    <div class="small text-muted">
+                        <div><i class="bi bi-building me-1"></i>{&m.building_address}</div>
+                        <div><i class="bi bi-calendar-event me-1"></i>{format_dt_local(&m.scheduled_at)}</div>
+                        <div><i class="bi bi-geo-alt me-1"></i>{&m.location}</div>
+                        if let Some(url) = &m.online_url {
+                            <div>
+                                <i class="bi bi-camera-video me-1"></i>
+                                <a href={url.clone()} target="_blank" rel="noopener noreferrer">{t("meetings-join-online")}</a>
+                            </div>
+                        }
+                        if let Some(started) = &m.started_at {
+                            <div>
+                                {t("meetings-started-at")}{": "}{format_dt_local(started)}
+                                if let Some(ended) = &m.ended_at {
+                                    {" — "}{format_dt_local(ended)}
+                                }
+                            </div>
+                        }
+                    </div>
+                    if m.can_manage {
+                        <div class="d-flex gap-2 mt-3">
+                            if m.status == "Scheduled" {
+                                <button class="btn btn-sm btn-success" disabled={*busy}
+                                    onclick={action(format!("{}/start", base), None, "meetings-started")}>
+                                    <i class="bi bi-play-fill me-1"></i>{t("meetings-start")}
+                                </button>
+                                <button class="btn btn-sm btn-outline-danger" disabled={*busy} onclick={on_delete}>
+                                    {t("meetings-delete")}
+                                </button>
+                            } else if in_progress {
+                                <button class="btn btn-sm btn-outline-danger" disabled={*busy}
+                                    onclick={action(format!("{}/end", base), Some("meetings-end-confirm"), "meetings-ended")}>
+                                    <i class="bi bi-stop-fill me-1"></i>{t("meetings-end")}
+                                </button>
+                            }
+                        </div>
+                    }
+                </div>
+            </div>
+
+            <div class="card mb-3">
+                <div class="card-header"><h5 class="mb-0">{t("meetings-quorum")}</h5></div>
+                <div class="card-body">
+                    <div class="d-flex justify-content-between small mb-1">
+                        <span>
+                            {t_with_args("meetings-quorum-represented", &[
+                                ("percent", &format!("{:.1}", m.quorum.percent)),
+                                ("required", &m.quorum.required_percent.to_string()),
+                            ])}
+                        </span>
+                        if m.quorum.reached {
+                            <span class="badge bg-success">{t("meetings-quorum-reached")}</span>
+                        } else {
+                            <span class="badge bg-warning text-dark">{t("meetings-quorum-not-reached")}</span>
+                        }
+                    </div>
+                    <div class="progress" style="height: 10px;">
+                        <div class={classes!("progress-bar", quorum_class)} role="progressbar"
+                            style={format!("width: {:.1}%", m.quorum.percent.min(100.0))}></div>
+                    </div>
+                </div>
+            </div>
+
+            <div class="card mb-3">
+                <div class="card-header"><h5 class="mb-0">{t("meetings-agenda")}</h5></div>
+                if m.agenda.is_empty() {
+                    <div class="card-body text-muted">{t("meetings-agenda-empty")}</div>
+                } else {
+                    <ul class="list-group list-group-flush">
+                        { for m.agenda.iter().map(|item| {
+                            let id = item.proposal_id;
+                            html! {
+                                <li class="list-group-item d-flex justify-content-between align-items-center" key={item.proposal_id}>
+                                    <div>
+                                        <span class="text-muted me-2">{format!("{}.", item.position)}</span>
+                                        <Link<Route> to={Route::VotingDetail { id }}>{&item.title}</Link<Route>>
+                                        if let Some(result) = &item.result {
+                                            if let Some(winner) = &item.winning_option {
+                                                <span class="badge bg-success ms-2">{winner}</span>
+                                            } else if result.passed {
+                                                <span class="badge bg-success ms-2">{t("meetings-passed")}</span>
+                                            } else {
+                                                <span class="badge bg-danger ms-2">{t("meetings-rejected")}</span>
+                                            }
+                                        }
+                                    </div>
+                                    <div class="d-flex gap-2 align-items-center">
+                                        <span class={classes!("badge", item_status_class(&item.status))}>{&item.status}</span>
+                                        if m.can_manage && in_progress && item.status == "Scheduled" {
+                                            <button class="btn btn-sm btn-outline-success" disabled={*busy || !m.quorum.reached}
+                                                onclick={action(format!("{}/agenda/{}/open", base, id), None, "meetings-item-opened")}>
+                                                {t("meetings-open-item")}
+                                            </button>
+                                        }
+                                        if m.can_manage && in_progress && item.status == "Open" {
+                                            <button class="btn btn-sm btn-outline-warning" disabled={*busy}
+                                                onclick={action(format!("{}/agenda/{}/close", base, id), Some("meetings-close-item-confirm"), "meetings-item-closed")}>
+                                                {t("meetings-close-item")}
+                                            </button>
+                                        }
+                                    </div>
+                                </li>
+                            }
+                        }) }
+                    </ul>
+                }
+            </div>
+
+            <div class="card mb-3">
+                <div class="card-header">
+                    <h5 class="mb-0">
+                        {t("meetings-attendance")}
+                        <span class="text-muted small ms-2">
+                            {t_with_args("meetings-attendee-count", &[("count", &m.attendance.len().to_string())])}
+                        </span>
+                    </h5>
+                </div>
+                <div class="card-body">
+                    if m.can_attend && m.status != "Closed" {
+                        <div class="d-flex gap-2 align-items-center mb-3">
+                            if let Some(mode) = &m.my_attendance {
+                                <span>{t_with_args("meetings-you-attend", &[("mode", &mode_label(mode))])}</span>
+                                <button class="btn btn-sm btn-outline-secondary" disabled={*busy}
+                                    onclick={remove_attendance(auth.user().map(|u| u.id).unwrap_or(0))}>
+                                    {t("meetings-withdraw")}
+                                </button>
+                            } else {
+                                <button class="btn btn-sm btn-primary" disabled={*busy} onclick={self_register("InPerson")}>
+                                    {t("meetings-attend-in-person")}
+                                </button>
+                                <button class="btn btn-sm btn-outline-primary" disabled={*busy} onclick={self_register("Online")}>
+                                    {t("meetings-attend-online")}
+                                </button>
+                            }
+                        </div>
+                    }
+
+                    if m.attendance.is_empty() {
+                        <div class="text-muted">{t("meetings-no-attendees")}</div>
+                    } else {
+                        <ul class="list-group list-group-flush mb-3">
+                            { for m.attendance.iter().map(|a| html! {
+                                <li class="list-group-item px-0 d-flex justify-content-between align-items-center" key={a.user_id}>
+                                    <div>
+                                        {&a.name}
+                                        <span class="badge bg-light text-dark ms-2">{mode_label(&a.mode)}</span>
+                                        if let Some(holder) = &a.proxy_holder_name {
+                                            <span class="small text-muted ms-2">
+                                                {t_with_args("meetings-represented-by", &[("name", holder)])}
+                                            </span>
+                                        }
+                                    </div>
+                                    <div class="d-flex gap-2 align-items-center">
+                                        <span class="small text-muted">{format!("{:.1} m²", a.represented_area)}</span>
+                                        if m.can_manage && m.status != "Closed" {
+                                            <button class="btn btn-sm btn-outline-danger" disabled={*busy}
+                                                onclick={remove_attendance(a.user_id)}>
+                                                <i class="bi bi-x"></i>
+                                            </button>
+                                        }
+                                    </div>
+                                </li>
+                            }) }
+                        </ul>
+                    }
+
+                    if m.can_manage && m.status != "Closed" {
+                        <form class="border rounded p-2" onsubmit={on_register_other}>
+                            <h6>{t("meetings-register-attendee")}</h6>
+                            <Select
+                                label={t("meetings-owner")}
+                                value={(*register_user).clone()}
+                                on_change={{ let u = register_user.clone(); Callback::from(move |v: String| u.set(v)) }}
+                                options={user_options.clone()}
+                                disabled={*busy}
+                            />
+                            <Select
+                                label={t("meetings-mode")}
+                                value={(*register_mode).clone()}
+                                on_change={{ let r = register_mode.clone(); Callback::from(move |v: String| r.set(v)) }}
+                                options={mode_options}
+                                disabled={*busy}
+                            />
+                            if *register_mode == "Proxy" {
+                                <Select
+                                    label={t("meetings-proxy-holder")}
+                                    value={(*register_holder).clone()}
+                                    on_change={{ let h = register_holder.clone(); Callback::from(move |v: String| h.set(v)) }}
+                                    options={user_options}
+                                    disabled={*busy}
+                                />
+                            }
+                            <button type="submit" class="btn btn-sm btn-primary" disabled={*busy}>
+                                {t("meetings-register")}
+                            </button>
+                        </form>
+                    }
+                </div>
+            </div>
+
+            <div class="card mb-3">
+                <div class="card-header"><h5 class="mb-0">{t("meetings-minutes")}</h5></div>
+                <div class="card-body">
+                    if let Some(minutes) = &m.minutes_md {
+                        <div style="white-space: pre-wrap;">{minutes}</div>
+                        <div class="small text-muted mt-2">{t("meetings-minutes-announced")}</div>
+                    } else if m.can_manage && m.status == "Closed" {
+                        <Textarea
+                            label={t("meetings-minutes-draft")}
+                            value={(*draft).clone()}
+                            on_change={{ let d = draft.clone(); Callback::from(move |v: String| d.set(v)) }}
+                            rows={16}
+                            disabled={*busy}
+                        />
+                        <button class="btn btn-primary" disabled={*busy} onclick={on_publish}>
+                            <i class="bi bi-megaphone me-1"></i>{t("meetings-publish-minutes")}
+                        </button>
+                    } else {
+                        <div class="text-muted">{t("meetings-minutes-pending")}</div>
+                    }
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,175 @@
+use crate::components::ErrorAlert;
+use crate::components::pagination::Pagination;
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::routes::Route;
+use crate::services::api::{PaginatedResponse, PaginationMeta, api_client};
+use crate::utils::datetime::format_dt_local;
+use serde::Deserialize;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct MeetingSummary {
+    id: u64,
+    title: String,
+    building_address: String,
+    scheduled_at: String,
+    location: String,
+    status: String,
+    agenda_count: i64,
+    attendee_count: i64,
+}
+
+pub(super) fn status_class(status: &str) -> &'static str {
+    match status {
+        "Scheduled" => "bg-secondary",
+        "InProgress" => "bg-success",
+        "Closed" => "bg-warning text-dark",
+        _ => "bg-light text-dark",
+    }
+}
+
+pub(super) fn status_label(status: &str) -> String {
+    match status {
+        "Scheduled" => t("meetings-status-scheduled"),
+        "InProgress" => t("meetings-status-in-progress"),
+        "Closed" => t("meetings-status-closed"),
+        other => other.to_string(),
+    }
+}
+
+#[function_component(MeetingListPage)]
+pub fn meeting_list_page() -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+    let navigator = use_navigator().unwrap();
+
+    let meetings = use_state(Vec::<MeetingSummary>::new);
+    let pagination_meta = use_state(|| None::<PaginationMeta>);
+    let current_page = use_state(|| 1i64);
+    let loading = use_state(|| true);
+    let error = use_state(|| None::<String>);
+
+    let token = auth.token().map(|t| t.to_string());
+
+    {
+        let meetings = meetings.clone();
+        let pagination_meta = pagination_meta.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let token = token.clone();
+        let page = *current_page;
+
+        use_effect_with(page, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                loading.set(true);
+                let client = api_client(token.as_deref());
+                let url = format!("/meetings?page={}&per_page=20", page);
+                match client.get::<PaginatedResponse<MeetingSummary>>(&url).await {
+                    Ok(resp) => {
+                        pagination_meta.set(Some(resp.pagination));
+                        meetings.set(resp.data);
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "meetings-failed-load",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let on_new_meeting = {
+        let navigator = navigator.clone();
+        Callback::from(move |_| navigator.push(&Route::MeetingNew))
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+
+    let on_page_change = {
+        let current_page = current_page.clone();
+        Callback::from(move |page: i64| current_page.set(page))
+    };
+
+    html! {
+        <div class="container mt-4">
+            <div class="d-flex justify-content-between align-items-center mb-3">
+                <div>
+                    <h2 class="mb-0">{t("meetings-title")}</h2>
+                    <div class="text-muted small">{t("meetings-subtitle")}</div>
+                </div>
+                if auth.is_admin_or_manager() {
+                    <button class="btn btn-primary" onclick={on_new_meeting}>
+                        {t("meetings-new-meeting")}
+                    </button>
+                }
+            </div>
+
+            if let Some(err) = (*error).clone() {
+                <ErrorAlert message={err} on_close={clear_error.clone()} />
+            }
+
+            if *loading {
+                <div class="text-center py-5">
+                    <div class="spinner-border" role="status">
+                        <span class="visually-hidden">{t("meetings-loading")}</span>
+                    </div>
+                </div>
+            } else if meetings.is_empty() {
+                <div class="alert alert-info">{t("meetings-none")}</div>
+            } else {
+                <div class="row">
+                    { for meetings.iter().map(|meeting| {
+                        let id = meeting.id;
+                        let navigator = navigator.clone();
+                        html! {
+                            <div class="col-md-6 col-lg-4 mb-3" key={meeting.id}>
+                                <div
+                                    class="card h-100"
+                                    style="cursor: pointer;"
+                                    onclick={Callback::from(move |_| navigator.push(&Route::MeetingDetail { id }))}
+                                >
+                                    <div class="card-body">
+                                        <div class="d-flex justify-content-between align-items-start mb-2">
+                                            <h5 class="card-title mb-0">{&meeting.title}</h5>
+                                            <span class={classes!("badge", status_class(&meeting.status))}>
+                                                {status_label(&meeting.status)}
+                                            </span>
+                                        </div>
+                                        <p class="card-text text-muted small mb-2">{&meeting.building_address}</p>
+                                        <div class="mb-2 d-flex gap-1">
+                                            <span class="badge bg-light text-dark">
+                                                {t_with_args("meetings-agenda-count", &[("count", &meeting.agenda_count.to_string())])}
+                                            </span>
+                                            <span class="badge bg-light text-dark">
+                                                {t_with_args("meetings-attendee-count", &[("count", &meeting.attendee_count.to_string())])}
+                                            </span>
+                                        </div>
+                                        <div class="small text-muted">
+                                            <div><i class="bi bi-calendar-event me-1"></i>{format_dt_local(&meeting.scheduled_at)}</div>
+                                            <div><i class="bi bi-geo-alt me-1"></i>{&meeting.location}</div>
+                                        </div>
+                                    </div>
+                                </div>
+                            </div>
+                        }
+                    }) }
+                </div>
+
+                if let Some(ref m) = *pagination_meta {
+                    <Pagination
+                        current_page={m.page}
+                        total_pages={m.total_pages}
+                        total_items={m.total}
+                        on_page_change={on_page_change.clone()}
+                    />
+                }
+            }
+        </div>
+    }
+}
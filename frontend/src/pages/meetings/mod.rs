@@ -0,0 +1,7 @@
+mod detail;
+mod list;
+mod new;
+
+pub use detail::MeetingDetailPage;
+pub use list::MeetingListPage;
+pub use new::MeetingNewPage;
@@ -0,0 +1,342 @@
+use crate::components::breadcrumb::BreadcrumbItem;
+use crate::components::{
+    Breadcrumb, Checkbox, DateTimeInput, ErrorAlert, FormGroup, Select, SelectOption, TextInput,
+    Textarea,
+};
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::routes::Route;
+use crate::services::api::PaginatedResponse;
+use crate::services::{ApiError, api_client};
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Serialize)]
+struct CreateMeetingPayload {
+    building_id: u64,
+    title: String,
+    description: String,
+    scheduled_at: String,
+    location: String,
+    online_url: Option<String>,
+    quorum_percent: i32,
+    proposal_ids: Vec<u64>,
+}
+
+#[derive(Deserialize)]
+struct CreatedMeeting {
+    id: u64,
+}
+
+#[derive(Deserialize, Clone)]
+struct Building {
+    id: u64,
+    address: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct Proposal {
+    id: u64,
+    title: String,
+    building_id: Option<u64>,
+    status: String,
+}
+
+fn datetime_plus_days(days: f64) -> String {
+    let now = js_sys::Date::new_0();
+    now.set_date((now.get_date() as f64 + days) as u32);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}",
+        now.get_full_year(),
+        now.get_month() + 1,
+        now.get_date(),
+        now.get_hours(),
+        now.get_minutes()
+    )
+}
+
+#[function_component(MeetingNewPage)]
+pub fn meeting_new_page() -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+    let navigator = use_navigator().unwrap();
+
+    let buildings = use_state(Vec::<Building>::new);
+    let proposals = use_state(Vec::<Proposal>::new);
+    let selected_building = use_state(String::new);
+    let title = use_state(String::default);
+    let description = use_state(String::default);
+    let scheduled_at = use_state(|| datetime_plus_days(14.0));
+    let location = use_state(String::default);
+    let online_url = use_state(String::default);
+    let quorum_percent = use_state(|| "50".to_string());
+    let agenda = use_state(Vec::<u64>::new);
+    let submitting = use_state(|| false);
+    let error = use_state(|| None::<String>);
+
+    let token = auth.token().map(|t| t.to_string());
+
+    {
+        let buildings = buildings.clone();
+        let proposals = proposals.clone();
+        let token = token.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client.get::<Vec<Building>>("/buildings/my").await {
+                    buildings.set(list);
+                }
+                if let Ok(resp) = client
+                    .get::<PaginatedResponse<Proposal>>("/proposals?per_page=100")
+                    .await
+                {
+                    proposals.set(
+                        resp.data
+                            .into_iter()
+                            .filter(|p| p.status == "Scheduled")
+                            .collect(),
+                    );
+                }
+            });
+            || ()
+        });
+    }
+
+    if !auth.is_admin_or_manager() {
+        return html! {
+            <div class="container mt-4">
+                <div class="alert alert-danger">{t("meetings-no-permission-create")}</div>
+            </div>
+        };
+    }
+
+    let building_id = selected_building.parse::<u64>().ok();
+    let candidates: Vec<Proposal> = proposals
+        .iter()
+        .filter(|p| building_id.is_some() && p.building_id == building_id)
+        .cloned()
+        .collect();
+
+    let on_building = {
+        let selected_building = selected_building.clone();
+        let agenda = agenda.clone();
+        Callback::from(move |v: String| {
+            selected_building.set(v);
+            agenda.set(Vec::new());
+        })
+    };
+
+    let on_submit = {
+        let title = title.clone();
+        let description = description.clone();
+        let scheduled_at = scheduled_at.clone();
+        let location = location.clone();
+        let online_url = online_url.clone();
+        let quorum_percent = quorum_percent.clone();
+        let agenda = agenda.clone();
+        let submitting = submitting.clone();
+        let error = error.clone();
+        let navigator = navigator.clone();
+        let token = token.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let Some(building_id) = building_id else {
+                error.set(Some(t("meetings-building-required")));
+                return;
+            };
+            if title.trim().is_empty() || location.trim().is_empty() {
+                error.set(Some(t("meetings-title-location-required")));
+                return;
+            }
+
+            let payload = CreateMeetingPayload {
+                building_id,
+                title: (*title).clone(),
+                description: (*description).clone(),
+                scheduled_at: (*scheduled_at).clone(),
+                location: (*location).clone(),
+                online_url: Some(online_url.trim().to_string()).filter(|u| !u.is_empty()),
+                quorum_percent: quorum_percent.parse().unwrap_or(50),
+                proposal_ids: (*agenda).clone(),
+            };
+            let submitting = submitting.clone();
+            let error = error.clone();
+            let navigator = navigator.clone();
+            let token = token.clone();
+            submitting.set(true);
+            error.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, CreatedMeeting>("/meetings", &payload)
+                    .await
+                {
+                    Ok(created) => navigator.push(&Route::MeetingDetail { id: created.id }),
+                    Err(ApiError::Forbidden) => {
+                        error.set(Some(t("meetings-no-permission-create")));
+                        submitting.set(false);
+                    }
+                    Err(ApiError::BadRequest(msg)) => {
+                        error.set(Some(msg));
+                        submitting.set(false);
+                    }
+                    Err(e) => {
+                        error.set(Some(t_with_args(
+                            "meetings-failed-create",
+                            &[("error", &e.to_string())],
+                        )));
+                        submitting.set(false);
+                    }
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let navigator = navigator.clone();
+        Callback::from(move |_| navigator.push(&Route::Meetings))
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+
+    let building_options = {
+        let mut options = vec![SelectOption::new("", t("meetings-select-building"))];
+        for building in buildings.iter() {
+            options.push(SelectOption::new(
+                building.id.to_string(),
+                &building.address,
+            ));
+        }
+        options
+    };
+
+    html! {
+        <div class="container mt-4">
+            <Breadcrumb items={vec![
+                BreadcrumbItem { label: t("meetings-title"), route: Some(Route::Meetings) },
+                BreadcrumbItem { label: t("meetings-new-breadcrumb"), route: None },
+            ]} />
+            <div class="row justify-content-center">
+                <div class="col-md-10 col-lg-8">
+                    <div class="card">
+                        <div class="card-header">
+                            <h4 class="mb-0">{t("meetings-new-title")}</h4>
+                        </div>
+                        <div class="card-body">
+                            if let Some(err) = (*error).clone() {
+                                <ErrorAlert message={err} on_close={clear_error.clone()} />
+                            }
+
+                            <form onsubmit={on_submit}>
+                                <FormGroup title={t("meetings-basic-info")}>
+                                    <Select
+                                        label={t("meetings-building")}
+                                        value={(*selected_building).clone()}
+                                        on_change={on_building}
+                                        options={building_options}
+                                        disabled={*submitting}
+                                        required=true
+                                    />
+                                    <TextInput
+                                        label={t("meetings-meeting-title")}
+                                        value={(*title).clone()}
+                                        on_change={{ let title = title.clone(); Callback::from(move |v: String| title.set(v)) }}
+                                        disabled={*submitting}
+                                        required=true
+                                    />
+                                    <Textarea
+                                        label={t("meetings-description")}
+                                        value={(*description).clone()}
+                                        on_change={{ let description = description.clone(); Callback::from(move |v: String| description.set(v)) }}
+                                        rows={3}
+                                        disabled={*submitting}
+                                    />
+                                </FormGroup>
+
+                                <FormGroup title={t("meetings-when-where")}>
+                                    <DateTimeInput
+                                        label={t("meetings-scheduled-at")}
+                                        value={(*scheduled_at).clone()}
+                                        on_change={{ let s = scheduled_at.clone(); Callback::from(move |v: String| s.set(v)) }}
+                                        input_type="datetime-local"
+                                        disabled={*submitting}
+                                        required=true
+                                    />
+                                    <TextInput
+                                        label={t("meetings-location")}
+                                        value={(*location).clone()}
+                                        on_change={{ let l = location.clone(); Callback::from(move |v: String| l.set(v)) }}
+                                        disabled={*submitting}
+                                        required=true
+                                    />
+                                    <TextInput
+                                        label={t("meetings-online-url")}
+                                        value={(*online_url).clone()}
+                                        on_change={{ let u = online_url.clone(); Callback::from(move |v: String| u.set(v)) }}
+                                        input_type="url"
+                                        disabled={*submitting}
+                                    />
+                                    <TextInput
+                                        label={t("meetings-quorum-percent")}
+                                        value={(*quorum_percent).clone()}
+                                        on_change={{ let q = quorum_percent.clone(); Callback::from(move |v: String| q.set(v)) }}
+                                        input_type="number"
+                                        disabled={*submitting}
+                                    />
+                                </FormGroup>
+
+                                <FormGroup title={t("meetings-agenda")}>
+                                    if building_id.is_none() {
+                                        <div class="text-muted small">{t("meetings-agenda-pick-building")}</div>
+                                    } else if candidates.is_empty() {
+                                        <div class="text-muted small">{t("meetings-agenda-no-proposals")}</div>
+                                    } else {
+                                        <div class="text-muted small mb-2">{t("meetings-agenda-help")}</div>
+                                        { for candidates.iter().map(|p| {
+                                            let proposal_id = p.id;
+                                            let on_change = {
+                                                let agenda = agenda.clone();
+                                                Callback::from(move |checked: bool| {
+                                                    let mut list = (*agenda).clone();
+                                                    list.retain(|id| *id != proposal_id);
+                                                    if checked {
+                                                        list.push(proposal_id);
+                                                    }
+                                                    agenda.set(list);
+                                                })
+                                            };
+                                            html! {
+                                                <Checkbox
+                                                    key={p.id}
+                                                    id={format!("meeting-agenda-{}", p.id)}
+                                                    label={p.title.clone()}
+                                                    checked={agenda.contains(&p.id)}
+                                                    {on_change}
+                                                    disabled={*submitting}
+                                                />
+                                            }
+                                        }) }
+                                    }
+                                </FormGroup>
+
+                                <div class="d-flex justify-content-end gap-2">
+                                    <button type="button" class="btn btn-secondary" disabled={*submitting} onclick={on_cancel}>
+                                        {t("button-cancel")}
+                                    </button>
+                                    <button type="submit" class="btn btn-primary" disabled={*submitting}>
+                                        {t("meetings-create")}
+                                    </button>
+                                </div>
+                            </form>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,710 @@
+use crate::components::{
+    Checkbox, ConfirmModal, DateTimeInput, ErrorAlert, FormGroup, NumberInput, SuccessAlert,
+    TextInput,
+};
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::services::api::PaginatedResponse;
+use crate::services::{ApiError, api_client};
+use crate::utils::datetime::{format_dt_local, format_dt_option};
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+/// A webhook API key as listed by the API; the secret is never returned again
+#[derive(Deserialize, Clone, PartialEq)]
+struct ApiKey {
+    id: u64,
+    name: String,
+    key_prefix: Option<String>,
+    is_active: bool,
+    expires_at: Option<String>,
+    rate_limit_per_minute: Option<u32>,
+    usage_count: u64,
+    last_used_at: Option<String>,
+    last_error: Option<String>,
+    last_error_at: Option<String>,
+    rotated_from_id: Option<u64>,
+    building_ids: Vec<u64>,
+    meter_ids: Vec<u64>,
+}
+
+/// A newly issued key, shown once
+#[derive(Deserialize, Clone, PartialEq)]
+struct IssuedKey {
+    name: String,
+    api_key: String,
+}
+
+#[derive(Serialize)]
+struct ApiKeyPayload {
+    name: String,
+    expires_at: Option<String>,
+    rate_limit_per_minute: Option<u32>,
+    building_ids: Vec<u64>,
+    meter_ids: Vec<u64>,
+}
+
+#[derive(Serialize)]
+struct RotatePayload {
+    overlap_hours: Option<u32>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct Building {
+    id: u64,
+    address: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct Meter {
+    id: u64,
+    serial_number: String,
+    building_id: Option<u64>,
+    building_address: Option<String>,
+}
+
+/// Current UTC time in the API's "YYYY-MM-DDTHH:MM:SS" form, for comparing expiries
+fn now_utc() -> String {
+    js_sys::Date::new_0()
+        .to_iso_string()
+        .as_string()
+        .unwrap_or_default()
+        .chars()
+        .take(19)
+        .collect()
+}
+
+fn status_badge(key: &ApiKey, now: &str) -> Html {
+    match &key.expires_at {
+        _ if !key.is_active => {
+            html! { <span class="badge bg-secondary">{t("meters-api-keys-revoked")}</span> }
+        }
+        Some(at) if at.as_str() <= now => {
+            html! { <span class="badge bg-danger">{t("meters-api-keys-expired")}</span> }
+        }
+        Some(at) => html! {
+            <span class="badge bg-warning text-dark">
+                {t_with_args("meters-api-keys-expires", &[("date", &format_dt_local(at))])}
+            </span>
+        },
+        None => html! { <span class="badge bg-success">{t("meters-api-keys-active")}</span> },
+    }
+}
+
+fn toggle_id(ids: &UseStateHandle<Vec<u64>>, id: u64, checked: bool) {
+    let mut list = (**ids).clone();
+    list.retain(|&i| i != id);
+    if checked {
+        list.push(id);
+    }
+    ids.set(list);
+}
+
+/// Webhook API keys of gateways and vendors: their scope, expiry, rate limit and
+/// usage, with creation, rotation and revocation
+#[function_component(MeterApiKeysPage)]
+pub fn meter_api_keys_page() -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+
+    let token = auth.token().map(|t| t.to_string());
+    let keys = use_state(Vec::<ApiKey>::new);
+    let buildings = use_state(Vec::<Building>::new);
+    let meters = use_state(Vec::<Meter>::new);
+    let loading = use_state(|| true);
+    let reload = use_state(|| 0u32);
+    let error = use_state(|| None::<String>);
+    let success = use_state(|| None::<String>);
+    let issued = use_state(|| None::<IssuedKey>);
+    let show_form = use_state(|| false);
+    // Key being edited; `None` while creating a new one
+    let editing = use_state(|| None::<u64>);
+    let name = use_state(String::new);
+    let expires_on = use_state(String::new);
+    let rate_limit = use_state(String::new);
+    let building_ids = use_state(Vec::<u64>::new);
+    let meter_ids = use_state(Vec::<u64>::new);
+    let submitting = use_state(|| false);
+    let rotate_target = use_state(|| None::<u64>);
+    let overlap_hours = use_state(|| "24".to_string());
+    let revoke_target = use_state(|| None::<u64>);
+
+    {
+        let keys = keys.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let token = token.clone();
+        use_effect_with(*reload, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client.get::<Vec<ApiKey>>("/admin/api-keys").await {
+                    Ok(list) => keys.set(list),
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-load-failed"), e))),
+                }
+                loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    {
+        let buildings = buildings.clone();
+        let meters = meters.clone();
+        let token = token.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client
+                    .get::<PaginatedResponse<Building>>("/buildings")
+                    .await
+                {
+                    buildings.set(list.data);
+                }
+                if let Ok(list) = client.get::<Vec<Meter>>("/meters").await {
+                    meters.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    if !auth.has_role("Admin") {
+        return html! {
+            <div class="container mt-4">
+                <div class="alert alert-danger">
+                    {t("meters-access-denied")}
+                </div>
+            </div>
+        };
+    }
+
+    let open_form = {
+        let show_form = show_form.clone();
+        let editing = editing.clone();
+        let name = name.clone();
+        let expires_on = expires_on.clone();
+        let rate_limit = rate_limit.clone();
+        let building_ids = building_ids.clone();
+        let meter_ids = meter_ids.clone();
+        Callback::from(move |key: Option<ApiKey>| {
+            editing.set(key.as_ref().map(|k| k.id));
+            name.set(key.as_ref().map(|k| k.name.clone()).unwrap_or_default());
+            expires_on.set(
+                key.as_ref()
+                    .and_then(|k| k.expires_at.as_ref())
+                    .map(|at| at.chars().take(10).collect())
+                    .unwrap_or_default(),
+            );
+            rate_limit.set(
+                key.as_ref()
+                    .and_then(|k| k.rate_limit_per_minute)
+                    .map(|limit| limit.to_string())
+                    .unwrap_or_default(),
+            );
+            building_ids.set(
+                key.as_ref()
+                    .map(|k| k.building_ids.clone())
+                    .unwrap_or_default(),
+            );
+            meter_ids.set(key.map(|k| k.meter_ids).unwrap_or_default());
+            show_form.set(true);
+        })
+    };
+
+    let on_submit = {
+        let editing = editing.clone();
+        let name = name.clone();
+        let expires_on = expires_on.clone();
+        let rate_limit = rate_limit.clone();
+        let building_ids = building_ids.clone();
+        let meter_ids = meter_ids.clone();
+        let submitting = submitting.clone();
+        let show_form = show_form.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let issued = issued.clone();
+        let reload = reload.clone();
+        let token = token.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if name.trim().is_empty() {
+                error.set(Some(t("meters-api-keys-name-required")));
+                return;
+            }
+            let rate_limit_per_minute = match rate_limit.trim() {
+                "" => None,
+                value => match value.parse::<u32>() {
+                    Ok(limit) if limit > 0 => Some(limit),
+                    _ => {
+                        error.set(Some(t("meters-api-keys-rate-limit-invalid")));
+                        return;
+                    }
+                },
+            };
+            let payload = ApiKeyPayload {
+                name: name.trim().to_string(),
+                // A key expires at the end of the chosen day
+                expires_at: (!expires_on.is_empty()).then(|| format!("{}T23:59:59", *expires_on)),
+                rate_limit_per_minute,
+                building_ids: (*building_ids).clone(),
+                meter_ids: (*meter_ids).clone(),
+            };
+            let editing = *editing;
+            let submitting = submitting.clone();
+            let show_form = show_form.clone();
+            let error = error.clone();
+            let success = success.clone();
+            let issued = issued.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            submitting.set(true);
+            error.set(None);
+            success.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                let result = match editing {
+                    Some(id) => client
+                        .put::<_, serde_json::Value>(&format!("/admin/api-keys/{}", id), &payload)
+                        .await
+                        .map(|_| success.set(Some(t("meters-api-keys-updated")))),
+                    None => client
+                        .post::<_, IssuedKey>("/admin/api-keys", &payload)
+                        .await
+                        .map(|key| issued.set(Some(key))),
+                };
+                match result {
+                    Ok(()) => {
+                        show_form.set(false);
+                        reload.set(*reload + 1);
+                    }
+                    Err(ApiError::BadRequest(msg)) => error.set(Some(msg)),
+                    Err(e) => error.set(Some(t_with_args(
+                        "meters-api-keys-save-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                submitting.set(false);
+            });
+        })
+    };
+
+    let on_rotate = {
+        let rotate_target = rotate_target.clone();
+        let overlap_hours = overlap_hours.clone();
+        let submitting = submitting.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let issued = issued.clone();
+        let reload = reload.clone();
+        let token = token.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(id) = *rotate_target else {
+                return;
+            };
+            let overlap = match overlap_hours.trim().parse::<u32>() {
+                Ok(hours) => hours,
+                Err(_) => {
+                    error.set(Some(t("meters-api-keys-overlap-invalid")));
+                    return;
+                }
+            };
+            let rotate_target = rotate_target.clone();
+            let submitting = submitting.clone();
+            let error = error.clone();
+            let issued = issued.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            submitting.set(true);
+            error.set(None);
+            success.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, IssuedKey>(
+                        &format!("/admin/api-keys/{}/rotate", id),
+                        &RotatePayload {
+                            overlap_hours: Some(overlap),
+                        },
+                    )
+                    .await
+                {
+                    Ok(key) => {
+                        issued.set(Some(key));
+                        rotate_target.set(None);
+                        reload.set(*reload + 1);
+                    }
+                    Err(ApiError::BadRequest(msg)) => error.set(Some(msg)),
+                    Err(e) => error.set(Some(t_with_args(
+                        "meters-api-keys-rotate-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                submitting.set(false);
+            });
+        })
+    };
+
+    let on_revoke = {
+        let revoke_target = revoke_target.clone();
+        let submitting = submitting.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let reload = reload.clone();
+        let token = token.clone();
+        Callback::from(move |_| {
+            let Some(id) = *revoke_target else {
+                return;
+            };
+            let revoke_target = revoke_target.clone();
+            let submitting = submitting.clone();
+            let error = error.clone();
+            let success = success.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            submitting.set(true);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .delete_no_response(&format!("/admin/api-keys/{}", id))
+                    .await
+                {
+                    Ok(()) => {
+                        success.set(Some(t("meters-api-keys-revoked-success")));
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "meters-api-keys-revoke-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                revoke_target.set(None);
+                submitting.set(false);
+            });
+        })
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+    let clear_success = {
+        let success = success.clone();
+        Callback::from(move |_| success.set(None))
+    };
+    let clear_issued = {
+        let issued = issued.clone();
+        Callback::from(move |_| issued.set(None))
+    };
+
+    let building_address = |id: u64| {
+        buildings
+            .iter()
+            .find(|b| b.id == id)
+            .map(|b| b.address.clone())
+            .unwrap_or_else(|| format!("#{}", id))
+    };
+    let meter_serial = |id: u64| {
+        meters
+            .iter()
+            .find(|m| m.id == id)
+            .map(|m| m.serial_number.clone())
+            .unwrap_or_else(|| format!("#{}", id))
+    };
+
+    let now = now_utc();
+
+    html! {
+        <>
+            <div class="d-flex justify-content-between align-items-center mb-3">
+                <h2 class="mb-0">{t("meters-api-keys-title")}</h2>
+                <button type="button" class="btn btn-primary" onclick={{ let open_form = open_form.clone(); Callback::from(move |_| open_form.emit(None)) }}>
+                    <i class="bi bi-plus-lg"></i>{" "}{t("meters-api-keys-new")}
+                </button>
+            </div>
+            <p class="text-muted">{t("meters-api-keys-hint")}</p>
+
+            if let Some(err) = (*error).clone() {
+                <ErrorAlert message={err} on_close={clear_error} />
+            }
+            if let Some(msg) = (*success).clone() {
+                <SuccessAlert message={msg} on_close={clear_success} />
+            }
+
+            if let Some(key) = (*issued).clone() {
+                <div class="alert alert-warning">
+                    <div class="d-flex justify-content-between align-items-start">
+                        <div>
+                            <strong>{t_with_args("meters-api-keys-issued", &[("name", &key.name)])}</strong>
+                            <div class="small mb-2">{t("meters-api-keys-issued-hint")}</div>
+                            <code class="user-select-all">{key.api_key}</code>
+                        </div>
+                        <button type="button" class="btn-close" onclick={clear_issued}></button>
+                    </div>
+                </div>
+            }
+
+            if *show_form {
+                <div class="card mb-4">
+                    <div class="card-body">
+                        <form onsubmit={on_submit}>
+                            <FormGroup title={if editing.is_some() { t("meters-api-keys-edit") } else { t("meters-api-keys-new") }}>
+                                <TextInput
+                                    label={t("meters-api-keys-name")}
+                                    value={(*name).clone()}
+                                    on_change={{ let n = name.clone(); Callback::from(move |v: String| n.set(v)) }}
+                                    placeholder={t("meters-api-keys-name-placeholder")}
+                                    disabled={*submitting}
+                                    required=true
+                                />
+                                <div class="row">
+                                    <div class="col-md-6">
+                                        <DateTimeInput
+                                            label={t("meters-api-keys-expires-on")}
+                                            value={(*expires_on).clone()}
+                                            on_change={{ let x = expires_on.clone(); Callback::from(move |v: String| x.set(v)) }}
+                                            input_type="date"
+                                            help_text={t("meters-api-keys-expires-on-hint")}
+                                            disabled={*submitting}
+                                        />
+                                    </div>
+                                    <div class="col-md-6">
+                                        <NumberInput
+                                            label={t("meters-api-keys-rate-limit")}
+                                            value={(*rate_limit).clone()}
+                                            on_change={{ let r = rate_limit.clone(); Callback::from(move |v: String| r.set(v)) }}
+                                            help_text={t("meters-api-keys-rate-limit-hint")}
+                                            min={1}
+                                            disabled={*submitting}
+                                        />
+                                    </div>
+                                </div>
+                            </FormGroup>
+                            <FormGroup title={t("meters-api-keys-scope")} description={t("meters-api-keys-scope-hint")}>
+                                <div class="row">
+                                    <div class="col-md-6">
+                                        <h6>{t("meters-api-keys-buildings")}</h6>
+                                        <div class="border rounded p-2 mb-3" style="max-height: 240px; overflow-y: auto;">
+                                            { for buildings.iter().map(|building| {
+                                                let id = building.id;
+                                                let ids = building_ids.clone();
+                                                html! {
+                                                    <Checkbox
+                                                        id={format!("api-key-building-{}", id)}
+                                                        label={building.address.clone()}
+                                                        checked={building_ids.contains(&id)}
+                                                        on_change={Callback::from(move |v: bool| toggle_id(&ids, id, v))}
+                                                        disabled={*submitting}
+                                                    />
+                                                }
+                                            }) }
+                                        </div>
+                                    </div>
+                                    <div class="col-md-6">
+                                        <h6>{t("meters-api-keys-meters")}</h6>
+                                        <div class="border rounded p-2 mb-3" style="max-height: 240px; overflow-y: auto;">
+                                            { for meters.iter().map(|meter| {
+                                                let id = meter.id;
+                                                let ids = meter_ids.clone();
+                                                // Meters of a selected building are covered already
+                                                let covered = meter.building_id.is_some_and(|b| building_ids.contains(&b));
+                                                let label = match &meter.building_address {
+                                                    Some(address) => format!("{} ({})", meter.serial_number, address),
+                                                    None => meter.serial_number.clone(),
+                                                };
+                                                html! {
+                                                    <Checkbox
+                                                        id={format!("api-key-meter-{}", id)}
+                                                        label={label}
+                                                        checked={covered || meter_ids.contains(&id)}
+                                                        on_change={Callback::from(move |v: bool| toggle_id(&ids, id, v))}
+                                                        disabled={*submitting || covered}
+                                                    />
+                                                }
+                                            }) }
+                                        </div>
+                                    </div>
+                                </div>
+                            </FormGroup>
+                            <button type="submit" class="btn btn-primary" disabled={*submitting}>
+                                if *submitting {
+                                    <span class="spinner-border spinner-border-sm me-2"></span>
+                                }
+                                {t("meters-api-keys-save")}
+                            </button>
+                            <button type="button" class="btn btn-outline-secondary ms-2" disabled={*submitting}
+                                onclick={{ let s = show_form.clone(); Callback::from(move |_| s.set(false)) }}>
+                                {t("button-cancel")}
+                            </button>
+                        </form>
+                    </div>
+                </div>
+            }
+
+            if let Some(id) = *rotate_target {
+                <div class="card mb-4 border-primary">
+                    <div class="card-body">
+                        <h5 class="card-title">{t("meters-api-keys-rotate")}</h5>
+                        <p class="text-muted">
+                            {t_with_args("meters-api-keys-rotate-hint", &[(
+                                "name",
+                                &keys.iter().find(|k| k.id == id).map(|k| k.name.clone()).unwrap_or_default(),
+                            )])}
+                        </p>
+                        <div class="row align-items-end">
+                            <div class="col-md-4">
+                                <NumberInput
+                                    label={t("meters-api-keys-overlap-hours")}
+                                    value={(*overlap_hours).clone()}
+                                    on_change={{ let o = overlap_hours.clone(); Callback::from(move |v: String| o.set(v)) }}
+                                    help_text={t("meters-api-keys-overlap-hours-hint")}
+                                    min={0}
+                                    max={720}
+                                    disabled={*submitting}
+                                />
+                            </div>
+                            <div class="col-md-8 mb-3">
+                                <button type="button" class="btn btn-primary" onclick={on_rotate} disabled={*submitting}>
+                                    <i class="bi bi-arrow-repeat"></i>{" "}{t("meters-api-keys-rotate")}
+                                </button>
+                                <button type="button" class="btn btn-outline-secondary ms-2" disabled={*submitting}
+                                    onclick={{ let r = rotate_target.clone(); Callback::from(move |_| r.set(None)) }}>
+                                    {t("button-cancel")}
+                                </button>
+                            </div>
+                        </div>
+                    </div>
+                </div>
+            }
+
+            if *loading {
+                <div class="text-center py-4">
+                    <div class="spinner-border" role="status"></div>
+                </div>
+            } else if keys.is_empty() {
+                <div class="alert alert-info">{t("meters-api-keys-empty")}</div>
+            } else {
+                <div class="table-responsive">
+                    <table class="table table-hover align-middle">
+                        <thead>
+                            <tr>
+                                <th>{t("meters-api-keys-name")}</th>
+                                <th>{t("meters-api-keys-prefix")}</th>
+                                <th>{t("meters-api-keys-status")}</th>
+                                <th>{t("meters-api-keys-scope")}</th>
+                                <th>{t("meters-api-keys-rate-limit")}</th>
+                                <th>{t("meters-api-keys-usage")}</th>
+                                <th>{t("meters-api-keys-last-error")}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for keys.iter().map(|key| {
+                                let scope = if key.building_ids.is_empty() && key.meter_ids.is_empty() {
+                                    html! { <span class="text-muted">{t("meters-api-keys-scope-all")}</span> }
+                                } else {
+                                    html! {
+                                        <>
+                                            { for key.building_ids.iter().map(|&id| html! {
+                                                <span class="badge bg-light text-dark border me-1">
+                                                    <i class="bi bi-building"></i>{" "}{building_address(id)}
+                                                </span>
+                                            }) }
+                                            { for key.meter_ids.iter().map(|&id| html! {
+                                                <span class="badge bg-light text-dark border me-1">
+                                                    <i class="bi bi-speedometer2"></i>{" "}{meter_serial(id)}
+                                                </span>
+                                            }) }
+                                        </>
+                                    }
+                                };
+                                let on_edit = {
+                                    let open_form = open_form.clone();
+                                    let key = key.clone();
+                                    Callback::from(move |_| open_form.emit(Some(key.clone())))
+                                };
+                                let on_rotate_click = {
+                                    let rotate_target = rotate_target.clone();
+                                    let id = key.id;
+                                    Callback::from(move |_| rotate_target.set(Some(id)))
+                                };
+                                let on_revoke_click = {
+                                    let revoke_target = revoke_target.clone();
+                                    let id = key.id;
+                                    Callback::from(move |_| revoke_target.set(Some(id)))
+                                };
+                                html! {
+                                    <tr key={key.id} class={classes!((!key.is_active).then_some("text-muted"))}>
+                                        <td>
+                                            {&key.name}
+                                            if let Some(from) = key.rotated_from_id {
+                                                <div class="small text-muted">
+                                                    {t_with_args("meters-api-keys-rotated-from", &[("id", &from.to_string())])}
+                                                </div>
+                                            }
+                                        </td>
+                                        <td>
+                                            if let Some(prefix) = &key.key_prefix {
+                                                <code>{format!("hm_{}_…", prefix)}</code>
+                                            } else {
+                                                <span class="badge bg-warning text-dark" title={t("meters-api-keys-legacy-hint")}>
+                                                    {t("meters-api-keys-legacy")}
+                                                </span>
+                                            }
+                                        </td>
+                                        <td>{status_badge(key, &now)}</td>
+                                        <td>{scope}</td>
+                                        <td>
+                                            {match key.rate_limit_per_minute {
+                                                Some(limit) => t_with_args("meters-api-keys-per-minute", &[("count", &limit.to_string())]),
+                                                None => t("meters-api-keys-unlimited"),
+                                            }}
+                                        </td>
+                                        <td>
+                                            {key.usage_count}
+                                            <div class="small text-muted">
+                                                {t_with_args("meters-api-keys-last-used", &[("date", &format_dt_option(key.last_used_at.as_ref()))])}
+                                            </div>
+                                        </td>
+                                        <td>
+                                            if let Some(err) = &key.last_error {
+                                                <code class="text-danger">{err}</code>
+                                                <div class="small text-muted">{format_dt_option(key.last_error_at.as_ref())}</div>
+                                            }
+                                        </td>
+                                        <td class="text-end text-nowrap">
+                                            if key.is_active {
+                                                <button type="button" class="btn btn-sm btn-outline-primary me-1" title={t("meters-api-keys-edit")} onclick={on_edit}>
+                                                    <i class="bi bi-pencil"></i>
+                                                </button>
+                                                <button type="button" class="btn btn-sm btn-outline-secondary me-1" title={t("meters-api-keys-rotate")} onclick={on_rotate_click}>
+                                                    <i class="bi bi-arrow-repeat"></i>
+                                                </button>
+                                                <button type="button" class="btn btn-sm btn-outline-danger" title={t("meters-api-keys-revoke")} onclick={on_revoke_click}>
+                                                    <i class="bi bi-x-circle"></i>
+                                                </button>
+                                            }
+                                        </td>
+                                    </tr>
+                                }
+                            }) }
+                        </tbody>
+                    </table>
+                </div>
+            }
+
+            <ConfirmModal
+                show={revoke_target.is_some()}
+                title={t("meters-api-keys-revoke")}
+                message={t("meters-api-keys-revoke-confirm")}
+                on_confirm={on_revoke}
+                on_cancel={{ let r = revoke_target.clone(); Callback::from(move |_| r.set(None)) }}
+                confirm_label={t("meters-api-keys-revoke")}
+                cancel_label={t("button-cancel")}
+                loading={*submitting}
+            />
+        </>
+    }
+}
@@ -35,7 +35,7 @@ pub fn meter_calibration_page() -> Html {
         };
     }
 
-    let meters = use_state(|| Vec::<Meter>::new());
+    let meters = use_state(Vec::<Meter>::new);
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
     let success = use_state(|| None::<String>);
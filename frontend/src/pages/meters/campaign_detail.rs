@@ -0,0 +1,317 @@
+use super::campaigns::{CampaignSummary, campaign_status_badge};
+use crate::components::breadcrumb::BreadcrumbItem;
+use crate::components::{Breadcrumb, ErrorAlert, SuccessAlert};
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::routes::Route;
+use crate::services::api_client;
+use crate::utils::datetime::format_dt_local;
+use serde::Deserialize;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct CampaignMeter {
+    meter_id: u64,
+    meter_type: String,
+    serial_number: String,
+    apartment_number: String,
+    status: String,
+    reading_value: Option<String>,
+    reading_timestamp: Option<String>,
+    unit: Option<String>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct CampaignDetail {
+    #[serde(flatten)]
+    summary: CampaignSummary,
+    meters: Vec<CampaignMeter>,
+}
+
+#[derive(Deserialize)]
+struct ReminderResult {
+    notified_users: usize,
+}
+
+fn meter_status_badge(status: &str) -> Html {
+    let (class, label) = match status {
+        "Read" => ("badge bg-success", t("meters-campaign-meter-read")),
+        "Submitted" => (
+            "badge bg-warning text-dark",
+            t("meters-campaign-meter-submitted"),
+        ),
+        "Estimated" => ("badge bg-info", t("meters-campaign-meter-estimated")),
+        _ => ("badge bg-danger", t("meters-campaign-meter-missing")),
+    };
+    html! { <span class={class}>{label}</span> }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct MeterCampaignDetailPageProps {
+    pub id: u64,
+}
+
+/// Completion dashboard of a reading campaign: which meters of the building were
+/// read, and reminding residents or closing the campaign after its deadline
+#[function_component(MeterCampaignDetailPage)]
+pub fn meter_campaign_detail_page(props: &MeterCampaignDetailPageProps) -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+
+    let token = auth.token().map(|t| t.to_string());
+    let campaign = use_state(|| None::<CampaignDetail>);
+    let loading = use_state(|| true);
+    let busy = use_state(|| false);
+    let error = use_state(|| None::<String>);
+    let success = use_state(|| None::<String>);
+
+    {
+        let campaign = campaign.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let token = token.clone();
+        use_effect_with(props.id, move |id| {
+            let id = *id;
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get::<CampaignDetail>(&format!("/reading-campaigns/{}", id))
+                    .await
+                {
+                    Ok(detail) => campaign.set(Some(detail)),
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-load-failed"), e))),
+                }
+                loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    if !auth.is_admin_or_manager() {
+        return html! {
+            <div class="container mt-4">
+                <div class="alert alert-danger">
+                    {t("meters-access-denied")}
+                </div>
+            </div>
+        };
+    }
+
+    let on_remind = {
+        let busy = busy.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let token = token.clone();
+        let id = props.id;
+        Callback::from(move |_| {
+            let busy = busy.clone();
+            let error = error.clone();
+            let success = success.clone();
+            let token = token.clone();
+            busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post_empty::<ReminderResult>(&format!("/reading-campaigns/{}/remind", id))
+                    .await
+                {
+                    Ok(result) => success.set(Some(t_with_args(
+                        "meters-campaign-reminded",
+                        &[("count", &result.notified_users.to_string())],
+                    ))),
+                    Err(e) => error.set(Some(t_with_args(
+                        "meters-campaign-action-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_close = {
+        let campaign = campaign.clone();
+        let busy = busy.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let token = token.clone();
+        let id = props.id;
+        Callback::from(move |_| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(&t("meters-campaign-close-confirm"))
+                        .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let campaign = campaign.clone();
+            let busy = busy.clone();
+            let error = error.clone();
+            let success = success.clone();
+            let token = token.clone();
+            busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post_empty::<CampaignDetail>(&format!("/reading-campaigns/{}/close", id))
+                    .await
+                {
+                    Ok(detail) => {
+                        campaign.set(Some(detail));
+                        success.set(Some(t("meters-campaign-closed-success")));
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "meters-campaign-action-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+
+    let clear_success = {
+        let success = success.clone();
+        Callback::from(move |_| success.set(None))
+    };
+
+    if *loading {
+        return html! {
+            <div class="text-center py-5">
+                <div class="spinner-border" role="status">
+                    <span class="visually-hidden">{t("loading")}</span>
+                </div>
+            </div>
+        };
+    }
+
+    let Some(detail) = (*campaign).clone() else {
+        return html! {
+            <>
+                if let Some(err) = (*error).clone() {
+                    <ErrorAlert message={err} on_close={clear_error.clone()} />
+                }
+            </>
+        };
+    };
+    let summary = &detail.summary;
+    let open = summary.status == "Open";
+
+    html! {
+        <>
+            <Breadcrumb items={vec![
+                BreadcrumbItem { label: t("meters-campaigns-title"), route: Some(Route::MeterCampaigns) },
+                BreadcrumbItem { label: summary.name.clone(), route: None },
+            ]} />
+            <div class="d-flex justify-content-between align-items-start mb-3">
+                <div>
+                    <h2 class="mb-1">{&summary.name}{" "}{campaign_status_badge(&summary.status)}</h2>
+                    <div class="text-muted">
+                        {summary.building_address.clone().unwrap_or_default()}
+                        {" · "}{&summary.start_date}{" – "}{&summary.deadline}
+                    </div>
+                    if let Some(at) = &summary.last_reminded_at {
+                        <div class="small text-muted">
+                            {t_with_args("meters-campaign-last-reminded", &[("date", &format_dt_local(at))])}
+                        </div>
+                    }
+                </div>
+                if open {
+                    <div class="d-flex gap-2">
+                        <button type="button" class="btn btn-outline-primary" onclick={on_remind} disabled={*busy || summary.missing == 0}>
+                            <i class="bi bi-bell"></i>{" "}{t("meters-campaign-remind")}
+                        </button>
+                        <button type="button" class="btn btn-outline-secondary" onclick={on_close} disabled={*busy}>
+                            <i class="bi bi-lock"></i>{" "}{t("meters-campaign-close")}
+                        </button>
+                    </div>
+                }
+            </div>
+
+            if let Some(err) = (*error).clone() {
+                <ErrorAlert message={err} on_close={clear_error.clone()} />
+            }
+
+            if let Some(msg) = (*success).clone() {
+                <SuccessAlert message={msg} on_close={clear_success.clone()} />
+            }
+
+            <div class="card mb-4">
+                <div class="card-body">
+                    <div class="progress mb-3" style="height: 10px;">
+                        <div class="progress-bar bg-success" style={format!("width: {}%;", summary.percent_done())}></div>
+                    </div>
+                    <div class="row text-center">
+                        <div class="col">
+                            <div class="fs-4">{summary.total_meters}</div>
+                            <div class="small text-muted">{t("meters-campaign-total")}</div>
+                        </div>
+                        <div class="col">
+                            <div class="fs-4 text-success">{summary.read}</div>
+                            <div class="small text-muted">{t("meters-campaign-meter-read")}</div>
+                        </div>
+                        <div class="col">
+                            <div class="fs-4 text-warning">{summary.submitted}</div>
+                            <div class="small text-muted">{t("meters-campaign-meter-submitted")}</div>
+                        </div>
+                        <div class="col">
+                            <div class="fs-4 text-info">{summary.estimated}</div>
+                            <div class="small text-muted">{t("meters-campaign-meter-estimated")}</div>
+                        </div>
+                        <div class="col">
+                            <div class="fs-4 text-danger">{summary.missing}</div>
+                            <div class="small text-muted">{t("meters-campaign-meter-missing")}</div>
+                        </div>
+                    </div>
+                    if open && summary.submitted > 0 {
+                        <div class="small text-muted mt-3">
+                            {t("meters-campaign-review-first")}{" "}
+                            <Link<Route> to={Route::MeterSubmissions}>{t("meters-submissions-review-title")}</Link<Route>>
+                        </div>
+                    }
+                </div>
+            </div>
+
+            <div class="table-responsive">
+                <table class="table table-sm align-middle">
+                    <thead>
+                        <tr>
+                            <th>{t("meters-campaign-apartment")}</th>
+                            <th>{t("meters-type")}</th>
+                            <th>{t("meters-serial-number")}</th>
+                            <th>{t("meters-reading-value")}</th>
+                            <th>{t("meters-submission-status")}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for detail.meters.iter().map(|m| html! {
+                            <tr key={m.meter_id}>
+                                <td>{&m.apartment_number}</td>
+                                <td>{&m.meter_type}</td>
+                                <td>
+                                    <Link<Route> to={Route::MeterDetail { id: m.meter_id }}>{&m.serial_number}</Link<Route>>
+                                </td>
+                                <td>
+                                    if let Some(value) = &m.reading_value {
+                                        {value}{" "}{m.unit.clone().unwrap_or_default()}
+                                        if let Some(at) = &m.reading_timestamp {
+                                            <span class="small text-muted">{" ("}{format_dt_local(at)}{")"}</span>
+                                        }
+                                    }
+                                </td>
+                                <td>{meter_status_badge(&m.status)}</td>
+                            </tr>
+                        }) }
+                    </tbody>
+                </table>
+            </div>
+        </>
+    }
+}
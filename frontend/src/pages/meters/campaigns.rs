@@ -0,0 +1,320 @@
+use crate::components::{DateTimeInput, ErrorAlert, FormGroup, Select, SelectOption, TextInput};
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::routes::Route;
+use crate::services::{ApiError, api_client};
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Serialize)]
+struct CreateCampaignPayload {
+    building_id: u64,
+    name: String,
+    start_date: String,
+    deadline: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct Building {
+    id: u64,
+    address: String,
+}
+
+/// A reading campaign with how many of its meters were read
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct CampaignSummary {
+    pub id: u64,
+    pub building_id: u64,
+    pub name: String,
+    pub start_date: String,
+    pub deadline: String,
+    pub status: String,
+    pub last_reminded_at: Option<String>,
+    pub closed_at: Option<String>,
+    pub building_address: Option<String>,
+    pub total_meters: usize,
+    pub read: usize,
+    pub submitted: usize,
+    pub estimated: usize,
+    pub missing: usize,
+}
+
+impl CampaignSummary {
+    /// Share of meters with a reading, estimated ones included
+    pub fn percent_done(&self) -> usize {
+        if self.total_meters == 0 {
+            return 100;
+        }
+        (self.read + self.estimated) * 100 / self.total_meters
+    }
+}
+
+/// Badge for a campaign's status
+pub fn campaign_status_badge(status: &str) -> Html {
+    match status {
+        "Closed" => html! { <span class="badge bg-secondary">{t("meters-campaign-closed")}</span> },
+        _ => html! { <span class="badge bg-primary">{t("meters-campaign-open")}</span> },
+    }
+}
+
+fn date_plus_days(days: f64) -> String {
+    let date = js_sys::Date::new_0();
+    date.set_date((date.get_date() as f64 + days) as u32);
+    format!(
+        "{:04}-{:02}-{:02}",
+        date.get_full_year(),
+        date.get_month() + 1,
+        date.get_date()
+    )
+}
+
+/// Reading campaigns of the manager's buildings, with a form to start a new one
+#[function_component(MeterCampaignsPage)]
+pub fn meter_campaigns_page() -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+    let navigator = use_navigator().unwrap();
+
+    let token = auth.token().map(|t| t.to_string());
+    let campaigns = use_state(Vec::<CampaignSummary>::new);
+    let buildings = use_state(Vec::<Building>::new);
+    let loading = use_state(|| true);
+    let error = use_state(|| None::<String>);
+    let show_form = use_state(|| false);
+    let selected_building = use_state(String::new);
+    let name = use_state(String::default);
+    let start_date = use_state(|| date_plus_days(0.0));
+    let deadline = use_state(|| date_plus_days(14.0));
+    let submitting = use_state(|| false);
+
+    {
+        let campaigns = campaigns.clone();
+        let buildings = buildings.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let token = token.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get::<Vec<CampaignSummary>>("/reading-campaigns")
+                    .await
+                {
+                    Ok(list) => campaigns.set(list),
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-load-failed"), e))),
+                }
+                if let Ok(list) = client.get::<Vec<Building>>("/buildings/my").await {
+                    buildings.set(list);
+                }
+                loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    if !auth.is_admin_or_manager() {
+        return html! {
+            <div class="container mt-4">
+                <div class="alert alert-danger">
+                    {t("meters-access-denied")}
+                </div>
+            </div>
+        };
+    }
+
+    let on_submit = {
+        let selected_building = selected_building.clone();
+        let name = name.clone();
+        let start_date = start_date.clone();
+        let deadline = deadline.clone();
+        let submitting = submitting.clone();
+        let error = error.clone();
+        let navigator = navigator.clone();
+        let token = token.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let Ok(building_id) = selected_building.parse::<u64>() else {
+                error.set(Some(t("meters-campaign-building-required")));
+                return;
+            };
+            if name.trim().is_empty() {
+                error.set(Some(t("meters-campaign-name-required")));
+                return;
+            }
+            let payload = CreateCampaignPayload {
+                building_id,
+                name: name.trim().to_string(),
+                start_date: (*start_date).clone(),
+                deadline: (*deadline).clone(),
+            };
+            let submitting = submitting.clone();
+            let error = error.clone();
+            let navigator = navigator.clone();
+            let token = token.clone();
+            submitting.set(true);
+            error.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, CampaignSummary>("/reading-campaigns", &payload)
+                    .await
+                {
+                    Ok(created) => navigator.push(&Route::MeterCampaignDetail { id: created.id }),
+                    Err(ApiError::BadRequest(msg)) => {
+                        error.set(Some(msg));
+                        submitting.set(false);
+                    }
+                    Err(e) => {
+                        error.set(Some(t_with_args(
+                            "meters-campaign-create-failed",
+                            &[("error", &e.to_string())],
+                        )));
+                        submitting.set(false);
+                    }
+                }
+            });
+        })
+    };
+
+    let toggle_form = {
+        let show_form = show_form.clone();
+        Callback::from(move |_| show_form.set(!*show_form))
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+
+    let building_options = {
+        let mut options = vec![SelectOption::new("", t("meters-campaign-select-building"))];
+        for building in buildings.iter() {
+            options.push(SelectOption::new(
+                building.id.to_string(),
+                &building.address,
+            ));
+        }
+        options
+    };
+
+    html! {
+        <>
+            <div class="d-flex justify-content-between align-items-center mb-3">
+                <h2 class="mb-0">{t("meters-campaigns-title")}</h2>
+                <button type="button" class="btn btn-primary" onclick={toggle_form}>
+                    <i class="bi bi-plus-lg"></i>{" "}{t("meters-campaign-new")}
+                </button>
+            </div>
+            <p class="text-muted">{t("meters-campaigns-hint")}</p>
+
+            if let Some(err) = (*error).clone() {
+                <ErrorAlert message={err} on_close={clear_error.clone()} />
+            }
+
+            if *show_form {
+                <div class="card mb-4">
+                    <div class="card-body">
+                        <form onsubmit={on_submit}>
+                            <FormGroup title={t("meters-campaign-new")}>
+                                <Select
+                                    label={t("meters-campaign-building")}
+                                    value={(*selected_building).clone()}
+                                    on_change={{ let b = selected_building.clone(); Callback::from(move |v: String| b.set(v)) }}
+                                    options={building_options}
+                                    disabled={*submitting}
+                                    required=true
+                                />
+                                <TextInput
+                                    label={t("meters-campaign-name")}
+                                    value={(*name).clone()}
+                                    on_change={{ let n = name.clone(); Callback::from(move |v: String| n.set(v)) }}
+                                    placeholder={t("meters-campaign-name-placeholder")}
+                                    disabled={*submitting}
+                                    required=true
+                                />
+                                <div class="row">
+                                    <div class="col-md-6">
+                                        <DateTimeInput
+                                            label={t("meters-campaign-start-date")}
+                                            value={(*start_date).clone()}
+                                            on_change={{ let s = start_date.clone(); Callback::from(move |v: String| s.set(v)) }}
+                                            input_type="date"
+                                            disabled={*submitting}
+                                            required=true
+                                        />
+                                    </div>
+                                    <div class="col-md-6">
+                                        <DateTimeInput
+                                            label={t("meters-campaign-deadline")}
+                                            value={(*deadline).clone()}
+                                            on_change={{ let d = deadline.clone(); Callback::from(move |v: String| d.set(v)) }}
+                                            input_type="date"
+                                            min={Some((*start_date).clone())}
+                                            disabled={*submitting}
+                                            required=true
+                                        />
+                                    </div>
+                                </div>
+                            </FormGroup>
+                            <button type="submit" class="btn btn-primary" disabled={*submitting}>
+                                if *submitting {
+                                    <span class="spinner-border spinner-border-sm me-2"></span>
+                                }
+                                {t("meters-campaign-start")}
+                            </button>
+                        </form>
+                    </div>
+                </div>
+            }
+
+            if *loading {
+                <div class="text-center py-5">
+                    <div class="spinner-border" role="status">
+                        <span class="visually-hidden">{t("loading")}</span>
+                    </div>
+                </div>
+            } else if campaigns.is_empty() {
+                <div class="alert alert-info">{t("meters-campaigns-empty")}</div>
+            } else {
+                <div class="table-responsive">
+                    <table class="table table-hover align-middle">
+                        <thead>
+                            <tr>
+                                <th>{t("meters-campaign-name")}</th>
+                                <th>{t("meters-campaign-building")}</th>
+                                <th>{t("meters-campaign-window")}</th>
+                                <th>{t("meters-campaign-progress")}</th>
+                                <th>{t("meters-submission-status")}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for campaigns.iter().map(|c| html! {
+                                <tr key={c.id}>
+                                    <td>
+                                        <Link<Route> to={Route::MeterCampaignDetail { id: c.id }}>{&c.name}</Link<Route>>
+                                    </td>
+                                    <td>{c.building_address.clone().unwrap_or_default()}</td>
+                                    <td>{&c.start_date}{" – "}{&c.deadline}</td>
+                                    <td style="min-width: 160px;">
+                                        <div class="progress" style="height: 6px;">
+                                            <div class="progress-bar bg-success" style={format!("width: {}%;", c.percent_done())}></div>
+                                        </div>
+                                        <div class="small text-muted">
+                                            {t_with_args("meters-campaign-progress-count", &[
+                                                ("done", &(c.read + c.estimated).to_string()),
+                                                ("total", &c.total_meters.to_string()),
+                                            ])}
+                                        </div>
+                                    </td>
+                                    <td>{campaign_status_badge(&c.status)}</td>
+                                </tr>
+                            }) }
+                        </tbody>
+                    </table>
+                </div>
+            }
+        </>
+    }
+}
@@ -1,6 +1,7 @@
 use crate::components::breadcrumb::BreadcrumbItem;
 use crate::components::meters::{
     Meter as MeterComponent, MeterEditForm, MeterReading, ReadingEntryForm, ReadingHistory,
+    ReadingSubmissionForm, SubmissionList,
 };
 use crate::components::{Breadcrumb, ErrorAlert, SuccessAlert};
 use crate::contexts::AuthContext;
@@ -19,7 +20,7 @@ pub fn meter_detail_page(props: &Props) -> Html {
     let auth = use_context::<AuthContext>().expect("AuthContext not found");
 
     let meter = use_state(|| None::<MeterComponent>);
-    let readings = use_state(|| Vec::<MeterReading>::new());
+    let readings = use_state(Vec::<MeterReading>::new);
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
     let success = use_state(|| None::<String>);
@@ -27,6 +28,8 @@ pub fn meter_detail_page(props: &Props) -> Html {
     // Form visibility state
     let show_entry_form = use_state(|| false);
     let show_edit_form = use_state(|| false);
+    let show_submission_form = use_state(|| false);
+    let submissions_reload = use_state(|| 0u32);
 
     let meter_id = props.id;
     let token = auth.token().map(|t| t.to_string());
@@ -89,6 +92,29 @@ pub fn meter_detail_page(props: &Props) -> Html {
         Callback::from(move |_: web_sys::MouseEvent| show_edit_form.set(!*show_edit_form))
     };
 
+    let toggle_submission_form = {
+        let show_submission_form = show_submission_form.clone();
+        Callback::from(move |_: web_sys::MouseEvent| {
+            show_submission_form.set(!*show_submission_form)
+        })
+    };
+
+    let cancel_submission_form = {
+        let show_submission_form = show_submission_form.clone();
+        Callback::from(move |_| show_submission_form.set(false))
+    };
+
+    let on_submission_success = {
+        let success = success.clone();
+        let show_submission_form = show_submission_form.clone();
+        let submissions_reload = submissions_reload.clone();
+        Callback::from(move |msg: String| {
+            success.set(Some(msg));
+            show_submission_form.set(false);
+            submissions_reload.set(*submissions_reload + 1);
+        })
+    };
+
     let cancel_entry_form = {
         let show_entry_form = show_entry_form.clone();
         Callback::from(move |_| show_entry_form.set(false))
@@ -215,6 +241,10 @@ pub fn meter_detail_page(props: &Props) -> Html {
                                 <i class="bi bi-pencil"></i> {t("meters-edit-replace")}
                             </button>
                         </>
+                    } else {
+                        <button class="btn btn-success me-2" onclick={toggle_submission_form}>
+                            <i class="bi bi-camera"></i> {t("meters-submit-reading")}
+                        </button>
                     }
                 </div>
             </div>
@@ -278,6 +308,17 @@ pub fn meter_detail_page(props: &Props) -> Html {
                         />
                     }
 
+                    // Resident submission with a photo, pending manager review
+                    if *show_submission_form && !is_admin_or_manager {
+                        <ReadingSubmissionForm
+                            meter_id={meter_id}
+                            token={token.clone()}
+                            on_success={on_submission_success}
+                            on_error={on_error.clone()}
+                            on_cancel={cancel_submission_form}
+                        />
+                    }
+
                     // Edit/Replace meter form
                     if *show_edit_form && is_admin_or_manager {
                         <MeterEditForm
@@ -289,6 +330,12 @@ pub fn meter_detail_page(props: &Props) -> Html {
                         />
                     }
 
+                    <SubmissionList
+                        meter_id={meter_id}
+                        token={token.clone()}
+                        reload={*submissions_reload}
+                    />
+
                     // Readings table component
                     <ReadingHistory
                         meter_id={meter_id}
@@ -0,0 +1,294 @@
+use crate::components::{Checkbox, ErrorAlert, FormGroup, TextInput};
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::services::{ApiError, api_client};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Serialize)]
+struct CsvMapping {
+    delimiter: String,
+    has_header: bool,
+    serial_column: String,
+    value_column: String,
+    timestamp_column: String,
+    unit_column: Option<String>,
+    unit: Option<String>,
+    timestamp_format: Option<String>,
+    decimal_comma: bool,
+}
+
+#[derive(Serialize)]
+struct CsvImportPayload {
+    mapping: CsvMapping,
+    content: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct IngestSummary {
+    received: usize,
+    inserted: usize,
+    duplicates: usize,
+    unknown_meters: Vec<String>,
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Import of meter readings from a vendor's CSV export, with the mapping of its
+/// columns to serial number, value, timestamp and unit
+#[function_component(MeterImportPage)]
+pub fn meter_import_page() -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+
+    let token = auth.token().map(|t| t.to_string());
+    let file_input_ref = use_node_ref();
+    let delimiter = use_state(|| ";".to_string());
+    let has_header = use_state(|| true);
+    let serial_column = use_state(String::new);
+    let value_column = use_state(String::new);
+    let timestamp_column = use_state(String::new);
+    let unit_column = use_state(String::new);
+    let unit = use_state(|| "m3".to_string());
+    let timestamp_format = use_state(String::new);
+    let decimal_comma = use_state(|| false);
+    let submitting = use_state(|| false);
+    let error = use_state(|| None::<String>);
+    let summary = use_state(|| None::<IngestSummary>);
+
+    if !auth.has_role("Admin") {
+        return html! {
+            <div class="container mt-4">
+                <div class="alert alert-danger">
+                    {t("meters-access-denied")}
+                </div>
+            </div>
+        };
+    }
+
+    let on_submit = {
+        let file_input_ref = file_input_ref.clone();
+        let delimiter = delimiter.clone();
+        let has_header = has_header.clone();
+        let serial_column = serial_column.clone();
+        let value_column = value_column.clone();
+        let timestamp_column = timestamp_column.clone();
+        let unit_column = unit_column.clone();
+        let unit = unit.clone();
+        let timestamp_format = timestamp_format.clone();
+        let decimal_comma = decimal_comma.clone();
+        let submitting = submitting.clone();
+        let error = error.clone();
+        let summary = summary.clone();
+        let token = token.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let Some(file) = file_input_ref
+                .cast::<HtmlInputElement>()
+                .and_then(|input| input.files())
+                .and_then(|files| files.get(0))
+            else {
+                error.set(Some(t("meters-import-file-required")));
+                return;
+            };
+            if non_empty(&unit_column).is_none() && non_empty(&unit).is_none() {
+                error.set(Some(t("meters-import-unit-required")));
+                return;
+            }
+            let mapping = CsvMapping {
+                delimiter: (*delimiter).clone(),
+                has_header: *has_header,
+                serial_column: serial_column.trim().to_string(),
+                value_column: value_column.trim().to_string(),
+                timestamp_column: timestamp_column.trim().to_string(),
+                unit_column: non_empty(&unit_column),
+                unit: non_empty(&unit),
+                timestamp_format: non_empty(&timestamp_format),
+                decimal_comma: *decimal_comma,
+            };
+            let submitting = submitting.clone();
+            let error = error.clone();
+            let summary = summary.clone();
+            let token = token.clone();
+            submitting.set(true);
+            error.set(None);
+            summary.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let content = match JsFuture::from(file.text()).await {
+                    Ok(text) => text.as_string().unwrap_or_default(),
+                    Err(_) => {
+                        error.set(Some(t("meters-import-read-failed")));
+                        submitting.set(false);
+                        return;
+                    }
+                };
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, IngestSummary>(
+                        "/meters/import/csv",
+                        &CsvImportPayload { mapping, content },
+                    )
+                    .await
+                {
+                    Ok(result) => summary.set(Some(result)),
+                    Err(ApiError::BadRequest(msg)) => error.set(Some(msg)),
+                    Err(e) => error.set(Some(t_with_args(
+                        "meters-import-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                submitting.set(false);
+            });
+        })
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+
+    let text_setter = |state: &UseStateHandle<String>| {
+        let state = state.clone();
+        Callback::from(move |v: String| state.set(v))
+    };
+
+    html! {
+        <>
+            <h2 class="mb-3">{t("meters-import-title")}</h2>
+            <p class="text-muted">{t("meters-import-hint")}</p>
+
+            if let Some(err) = (*error).clone() {
+                <ErrorAlert message={err} on_close={clear_error.clone()} />
+            }
+
+            if let Some(result) = (*summary).clone() {
+                <div class="alert alert-success">
+                    <div>
+                        {t_with_args("meters-import-summary", &[
+                            ("received", &result.received.to_string()),
+                            ("inserted", &result.inserted.to_string()),
+                            ("duplicates", &result.duplicates.to_string()),
+                        ])}
+                    </div>
+                    if !result.unknown_meters.is_empty() {
+                        <div class="mt-2">
+                            {t("meters-import-unknown-meters")}{" "}
+                            <code>{result.unknown_meters.join(", ")}</code>
+                        </div>
+                    }
+                </div>
+            }
+
+            <div class="card mb-4">
+                <div class="card-body">
+                    <form onsubmit={on_submit}>
+                        <FormGroup title={t("meters-import-file")}>
+                            <div class="mb-3">
+                                <input ref={file_input_ref} type="file" class="form-control" accept=".csv,text/csv,text/plain" disabled={*submitting} required=true />
+                            </div>
+                            <div class="row">
+                                <div class="col-md-4">
+                                    <TextInput
+                                        label={t("meters-import-delimiter")}
+                                        value={(*delimiter).clone()}
+                                        on_change={text_setter(&delimiter)}
+                                        disabled={*submitting}
+                                        required=true
+                                    />
+                                </div>
+                                <div class="col-md-8 d-flex flex-column justify-content-center">
+                                    <Checkbox
+                                        id="meters-import-has-header"
+                                        label={t("meters-import-has-header")}
+                                        checked={*has_header}
+                                        on_change={{ let h = has_header.clone(); Callback::from(move |v: bool| h.set(v)) }}
+                                        disabled={*submitting}
+                                    />
+                                    <Checkbox
+                                        id="meters-import-decimal-comma"
+                                        label={t("meters-import-decimal-comma")}
+                                        checked={*decimal_comma}
+                                        on_change={{ let d = decimal_comma.clone(); Callback::from(move |v: bool| d.set(v)) }}
+                                        disabled={*submitting}
+                                    />
+                                </div>
+                            </div>
+                        </FormGroup>
+                        <FormGroup title={t("meters-import-columns")} description={t("meters-import-columns-hint")}>
+                            <div class="row">
+                                <div class="col-md-4">
+                                    <TextInput
+                                        label={t("meters-serial-number")}
+                                        value={(*serial_column).clone()}
+                                        on_change={text_setter(&serial_column)}
+                                        disabled={*submitting}
+                                        required=true
+                                    />
+                                </div>
+                                <div class="col-md-4">
+                                    <TextInput
+                                        label={t("meters-reading-value")}
+                                        value={(*value_column).clone()}
+                                        on_change={text_setter(&value_column)}
+                                        disabled={*submitting}
+                                        required=true
+                                    />
+                                </div>
+                                <div class="col-md-4">
+                                    <TextInput
+                                        label={t("meters-import-timestamp")}
+                                        value={(*timestamp_column).clone()}
+                                        on_change={text_setter(&timestamp_column)}
+                                        disabled={*submitting}
+                                        required=true
+                                    />
+                                </div>
+                            </div>
+                            <div class="row">
+                                <div class="col-md-4">
+                                    <TextInput
+                                        label={t("meters-import-unit-column")}
+                                        value={(*unit_column).clone()}
+                                        on_change={text_setter(&unit_column)}
+                                        disabled={*submitting}
+                                    />
+                                </div>
+                                <div class="col-md-4">
+                                    <TextInput
+                                        label={t("meters-import-unit")}
+                                        value={(*unit).clone()}
+                                        on_change={text_setter(&unit)}
+                                        help_text={t("meters-import-unit-hint")}
+                                        disabled={*submitting}
+                                    />
+                                </div>
+                                <div class="col-md-4">
+                                    <TextInput
+                                        label={t("meters-import-timestamp-format")}
+                                        value={(*timestamp_format).clone()}
+                                        on_change={text_setter(&timestamp_format)}
+                                        placeholder="%d.%m.%Y %H:%M"
+                                        help_text={t("meters-import-timestamp-format-hint")}
+                                        disabled={*submitting}
+                                    />
+                                </div>
+                            </div>
+                        </FormGroup>
+                        <button type="submit" class="btn btn-primary" disabled={*submitting}>
+                            if *submitting {
+                                <span class="spinner-border spinner-border-sm me-2"></span>
+                            }
+                            {t("meters-import-submit")}
+                        </button>
+                    </form>
+                </div>
+            </div>
+        </>
+    }
+}
@@ -34,7 +34,7 @@ pub fn meter_list_page(props: &Props) -> Html {
     let auth = use_context::<AuthContext>().expect("AuthContext not found");
     let navigator = use_navigator().unwrap();
 
-    let meters = use_state(|| Vec::<MeterWithLastReading>::new());
+    let meters = use_state(Vec::<MeterWithLastReading>::new);
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
     let success = use_state(|| None::<String>);
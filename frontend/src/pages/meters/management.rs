@@ -30,7 +30,7 @@ pub fn meter_management_page() -> Html {
 
     let error = use_state(|| None::<String>);
     let success = use_state(|| None::<String>);
-    let buildings = use_state(|| Vec::<MeterBuilding>::new());
+    let buildings = use_state(Vec::<MeterBuilding>::new);
     let reload_trigger = use_state(|| 0u32);
 
     // Load buildings for both tabs
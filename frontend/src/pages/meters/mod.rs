@@ -1,11 +1,21 @@
+mod api_keys;
 mod calibration;
+mod campaign_detail;
+mod campaigns;
 mod detail;
+mod import;
 mod list;
 mod management;
 mod new;
+mod submissions;
 
+pub use api_keys::MeterApiKeysPage;
 pub use calibration::MeterCalibrationPage;
+pub use campaign_detail::MeterCampaignDetailPage;
+pub use campaigns::MeterCampaignsPage;
 pub use detail::MeterDetailPage;
+pub use import::MeterImportPage;
 pub use list::MeterListPage;
 pub use management::MeterManagementPage;
 pub use new::MeterNewPage;
+pub use submissions::MeterSubmissionsPage;
@@ -55,8 +55,8 @@ pub fn meter_new_page() -> Html {
     }
 
     // Form state
-    let buildings = use_state(|| Vec::<Building>::new());
-    let apartments = use_state(|| Vec::<Apartment>::new());
+    let buildings = use_state(Vec::<Building>::new);
+    let apartments = use_state(Vec::<Apartment>::new);
     let selected_building = use_state(|| 0u64);
     let apartment_id = use_state(|| 0u64);
     let meter_type = use_state(|| "ColdWater".to_string());
@@ -0,0 +1,288 @@
+use crate::components::meters::{MeterSubmission, SubmissionPhoto, submission_status_badge};
+use crate::components::{ErrorAlert, SuccessAlert};
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::routes::Route;
+use crate::services::api_client;
+use crate::utils::datetime::format_dt_local;
+use serde::Serialize;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Serialize)]
+struct RejectRequest {
+    reason: String,
+}
+
+const STATUSES: [&str; 3] = ["Pending", "Approved", "Rejected"];
+
+fn status_tab_label(status: &str) -> String {
+    match status {
+        "Approved" => t("meters-submission-approved"),
+        "Rejected" => t("meters-submission-rejected"),
+        _ => t("meters-submission-pending"),
+    }
+}
+
+/// Readings submitted by residents, for managers to approve or reject against the
+/// photo and the previous reading
+#[function_component(MeterSubmissionsPage)]
+pub fn meter_submissions_page() -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+
+    if !auth.is_admin_or_manager() {
+        return html! {
+            <div class="container mt-4">
+                <div class="alert alert-danger">
+                    {t("meters-access-denied")}
+                </div>
+            </div>
+        };
+    }
+
+    let token = auth.token().map(|t| t.to_string());
+    let status = use_state(|| "Pending");
+    let submissions = use_state(Vec::<MeterSubmission>::new);
+    let loading = use_state(|| true);
+    let error = use_state(|| None::<String>);
+    let success = use_state(|| None::<String>);
+    let reload = use_state(|| 0u32);
+
+    {
+        let submissions = submissions.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let token = token.clone();
+        use_effect_with((*status, *reload), move |(status, _)| {
+            let status = *status;
+            loading.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get::<Vec<MeterSubmission>>(&format!("/meter-submissions?status={}", status))
+                    .await
+                {
+                    Ok(list) => submissions.set(list),
+                    Err(e) => error.set(Some(format!("{}: {}", t("error-load-failed"), e))),
+                }
+                loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let on_approve = {
+        let error = error.clone();
+        let success = success.clone();
+        let reload = reload.clone();
+        let token = token.clone();
+        Callback::from(move |submission_id: u64| {
+            let error = error.clone();
+            let success = success.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post_empty::<serde_json::Value>(&format!(
+                        "/meter-submissions/{}/approve",
+                        submission_id
+                    ))
+                    .await
+                {
+                    Ok(_) => {
+                        success.set(Some(t("meters-submission-approved-success")));
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "meters-submission-review-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+            });
+        })
+    };
+
+    let on_reject = {
+        let error = error.clone();
+        let success = success.clone();
+        let reload = reload.clone();
+        let token = token.clone();
+        Callback::from(move |submission_id: u64| {
+            let Some(reason) = web_sys::window()
+                .and_then(|w| {
+                    w.prompt_with_message(&t("meters-submission-reject-prompt"))
+                        .ok()
+                })
+                .flatten()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+            else {
+                return;
+            };
+            let error = error.clone();
+            let success = success.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, serde_json::Value>(
+                        &format!("/meter-submissions/{}/reject", submission_id),
+                        &RejectRequest { reason },
+                    )
+                    .await
+                {
+                    Ok(_) => {
+                        success.set(Some(t("meters-submission-rejected-success")));
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "meters-submission-review-failed",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+            });
+        })
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+
+    let clear_success = {
+        let success = success.clone();
+        Callback::from(move |_| success.set(None))
+    };
+
+    let pending = *status == "Pending";
+
+    html! {
+        <>
+            <h2 class="mb-3">{t("meters-submissions-review-title")}</h2>
+            <ul class="nav nav-tabs mb-3">
+                { for STATUSES.iter().map(|s| {
+                    let active = *status == *s;
+                    let onclick = {
+                        let status = status.clone();
+                        let value = *s;
+                        Callback::from(move |_| status.set(value))
+                    };
+                    html! {
+                        <li class="nav-item">
+                            <a
+                                class={if active { "nav-link active" } else { "nav-link" }}
+                                style="cursor: pointer;"
+                                {onclick}
+                            >
+                                {status_tab_label(s)}
+                            </a>
+                        </li>
+                    }
+                }) }
+            </ul>
+
+            if let Some(err) = (*error).clone() {
+                <ErrorAlert message={err} on_close={clear_error.clone()} />
+            }
+
+            if let Some(msg) = (*success).clone() {
+                <SuccessAlert message={msg} on_close={clear_success.clone()} />
+            }
+
+            if *loading {
+                <div class="text-center py-5">
+                    <div class="spinner-border" role="status">
+                        <span class="visually-hidden">{t("loading")}</span>
+                    </div>
+                </div>
+            } else if submissions.is_empty() {
+                <div class="alert alert-info">{t("meters-submissions-empty")}</div>
+            } else {
+                <div class="row g-3">
+                    { for submissions.iter().map(|s| {
+                        let id = s.id;
+                        let on_approve = on_approve.clone();
+                        let on_reject = on_reject.clone();
+                        html! {
+                            <div class="col-lg-6" key={id}>
+                                <div class="card h-100">
+                                    <div class="card-header d-flex justify-content-between align-items-center">
+                                        <Link<Route> to={Route::MeterDetail { id: s.meter_id }}>
+                                            {&s.meter_type}{" "}{&s.serial_number}
+                                        </Link<Route>>
+                                        {submission_status_badge(&s.status)}
+                                    </div>
+                                    <div class="card-body small">
+                                        <div class="row">
+                                            <div class="col-sm-7">
+                                                <p class="mb-1">
+                                                    {s.building_address.clone().unwrap_or_default()}
+                                                    {", "}
+                                                    {t_with_args("meters-submission-apartment", &[("number", &s.apartment_number.clone().unwrap_or_default())])}
+                                                </p>
+                                                <p class="mb-1">
+                                                    <strong>{t("meters-submission-submitted-by")}{": "}</strong>
+                                                    {s.submitted_by_name.clone().unwrap_or_default()}
+                                                </p>
+                                                <p class="mb-1">
+                                                    <strong>{t("meters-reading-value")}{": "}</strong>
+                                                    {&s.reading_value}{" "}{&s.unit}
+                                                    <span class="text-muted">{" ("}{format_dt_local(&s.reading_timestamp)}{")"}</span>
+                                                </p>
+                                                <p class="mb-1">
+                                                    <strong>{t("meters-submission-previous")}{": "}</strong>
+                                                    if let Some(previous) = &s.previous_value {
+                                                        {previous}{" "}{&s.unit}
+                                                        if let Some(at) = &s.previous_timestamp {
+                                                            <span class="text-muted">{" ("}{format_dt_local(at)}{")"}</span>
+                                                        }
+                                                    } else {
+                                                        <span class="text-muted">{t("meters-submission-no-previous")}</span>
+                                                    }
+                                                </p>
+                                                if let Some(consumption) = &s.consumption {
+                                                    <p class="mb-1">
+                                                        <strong>{t("meters-submission-consumption")}{": "}</strong>
+                                                        {consumption}{" "}{&s.unit}
+                                                    </p>
+                                                }
+                                                if let Some(note) = &s.review_note {
+                                                    <p class="mb-1 text-muted">
+                                                        {t_with_args("meters-submission-reason", &[("reason", note)])}
+                                                    </p>
+                                                }
+                                            </div>
+                                            <div class="col-sm-5 text-sm-end">
+                                                <SubmissionPhoto meter_id={s.meter_id} submission_id={id} has_thumbnail={s.photo_has_thumbnail} token={token.clone()} />
+                                            </div>
+                                        </div>
+                                    </div>
+                                    if pending {
+                                        <div class="card-footer d-flex gap-2">
+                                            <button
+                                                type="button"
+                                                class="btn btn-sm btn-success"
+                                                onclick={Callback::from(move |_| on_approve.emit(id))}
+                                            >
+                                                <i class="bi bi-check-lg"></i>{" "}{t("meters-submission-approve")}
+                                            </button>
+                                            <button
+                                                type="button"
+                                                class="btn btn-sm btn-outline-danger"
+                                                onclick={Callback::from(move |_| on_reject.emit(id))}
+                                            >
+                                                <i class="bi bi-x-lg"></i>{" "}{t("meters-submission-reject")}
+                                            </button>
+                                        </div>
+                                    }
+                                </div>
+                            </div>
+                        }
+                    }) }
+                </div>
+            }
+        </>
+    }
+}
@@ -5,7 +5,9 @@ pub mod health;
 pub mod home;
 pub mod login;
 pub mod maintenance;
+pub mod meetings;
 pub mod meters;
 pub mod my_properties;
 pub mod my_property_detail;
+pub mod polls;
 pub mod voting;
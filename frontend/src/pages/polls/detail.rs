@@ -0,0 +1,588 @@
+use super::list::{status_class, status_label};
+use crate::components::breadcrumb::BreadcrumbItem;
+use crate::components::{Breadcrumb, ErrorAlert, SuccessAlert};
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::routes::Route;
+use crate::services::{ApiError, api_client};
+use crate::utils::datetime::format_dt_local;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct PollOption {
+    id: u64,
+    label: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct Question {
+    id: u64,
+    prompt: String,
+    kind: String,
+    required: bool,
+    rating_max: Option<i32>,
+    options: Vec<PollOption>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct PollDetail {
+    id: u64,
+    title: String,
+    description: String,
+    start_time: String,
+    end_time: String,
+    anonymous: bool,
+    results_visibility: String,
+    status: String,
+    questions: Vec<Question>,
+    response_count: i64,
+    has_responded: bool,
+    can_respond: bool,
+    can_view_results: bool,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct OptionCount {
+    label: String,
+    count: usize,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct RatingCount {
+    value: i32,
+    count: usize,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct TextAnswer {
+    text: String,
+    respondent: Option<String>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct QuestionResults {
+    question_id: u64,
+    prompt: String,
+    kind: String,
+    answered: usize,
+    options: Vec<OptionCount>,
+    ratings: Vec<RatingCount>,
+    average_rating: Option<f64>,
+    texts: Vec<TextAnswer>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct PollResults {
+    response_count: usize,
+    questions: Vec<QuestionResults>,
+}
+
+#[derive(Serialize, Clone, Default, PartialEq)]
+struct AnswerPayload {
+    question_id: u64,
+    option_ids: Vec<u64>,
+    text: Option<String>,
+    rating: Option<i32>,
+}
+
+#[derive(Serialize)]
+struct SubmitResponsePayload {
+    answers: Vec<AnswerPayload>,
+}
+
+fn visibility_label(visibility: &str) -> String {
+    match visibility {
+        "Always" => t("polls-visibility-always"),
+        "AfterResponse" => t("polls-visibility-after-response"),
+        "AfterClose" => t("polls-visibility-after-close"),
+        "Managers" => t("polls-visibility-managers"),
+        other => other.to_string(),
+    }
+}
+
+fn percent(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 * 100.0 / total as f64
+    }
+}
+
+/// Saves the blob behind `object_url` under `filename` through a temporary link.
+fn download(object_url: &str, filename: &str) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    if let Some(anchor) = document
+        .create_element("a")
+        .ok()
+        .and_then(|el| el.dyn_into::<web_sys::HtmlAnchorElement>().ok())
+    {
+        anchor.set_href(object_url);
+        anchor.set_download(filename);
+        anchor.click();
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub id: u64,
+}
+
+#[function_component(PollDetailPage)]
+pub fn poll_detail_page(props: &Props) -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+
+    let poll = use_state(|| None::<PollDetail>);
+    let results = use_state(|| None::<PollResults>);
+    let answers = use_state(HashMap::<u64, AnswerPayload>::new);
+    let loading = use_state(|| true);
+    let busy = use_state(|| false);
+    let reload = use_state(|| 0u32);
+
+    let error = use_state(|| None::<String>);
+    let success = use_state(|| None::<String>);
+
+    let poll_id = props.id;
+    let token = auth.token().map(|t| t.to_string());
+
+    {
+        let poll = poll.clone();
+        let results = results.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let token = token.clone();
+
+        use_effect_with((poll_id, *reload), move |(id, _)| {
+            let id = *id;
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client.get::<PollDetail>(&format!("/polls/{}", id)).await {
+                    Ok(p) => {
+                        if p.can_view_results {
+                            results.set(
+                                client
+                                    .get::<PollResults>(&format!("/polls/{}/results", id))
+                                    .await
+                                    .ok(),
+                            );
+                        }
+                        poll.set(Some(p));
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "polls-failed-load-poll",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let set_answer = {
+        let answers = answers.clone();
+        Callback::from(move |answer: AnswerPayload| {
+            let mut map = (*answers).clone();
+            map.insert(answer.question_id, answer);
+            answers.set(map);
+        })
+    };
+
+    let on_submit = {
+        let answers = answers.clone();
+        let busy = busy.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let reload = reload.clone();
+        let token = token.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let payload = SubmitResponsePayload {
+                answers: answers.values().cloned().collect(),
+            };
+            let busy = busy.clone();
+            let error = error.clone();
+            let success = success.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            busy.set(true);
+            error.set(None);
+            success.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post::<_, serde_json::Value>(
+                        &format!("/polls/{}/responses", poll_id),
+                        &payload,
+                    )
+                    .await
+                {
+                    Ok(_) => {
+                        success.set(Some(t("polls-response-submitted")));
+                        reload.set(*reload + 1);
+                    }
+                    Err(ApiError::BadRequest(msg)) => error.set(Some(msg)),
+                    Err(e) => error.set(Some(t_with_args(
+                        "polls-failed-respond",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_close_poll = {
+        let busy = busy.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let reload = reload.clone();
+        let token = token.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let confirmed = web_sys::window()
+                .and_then(|w| w.confirm_with_message(&t("polls-close-confirm")).ok())
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let busy = busy.clone();
+            let error = error.clone();
+            let success = success.clone();
+            let reload = reload.clone();
+            let token = token.clone();
+            busy.set(true);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .post_empty::<serde_json::Value>(&format!("/polls/{}/close", poll_id))
+                    .await
+                {
+                    Ok(_) => {
+                        success.set(Some(t("polls-closed-success")));
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "polls-failed-close",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_export = {
+        let error = error.clone();
+        let token = token.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let error = error.clone();
+            let token = token.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client
+                    .get_object_url(&format!("/polls/{}/export", poll_id))
+                    .await
+                {
+                    Ok(url) => {
+                        download(&url, &format!("poll_{}_responses.csv", poll_id));
+                        let _ = web_sys::Url::revoke_object_url(&url);
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "polls-failed-export",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+            });
+        })
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+    let clear_success = {
+        let success = success.clone();
+        Callback::from(move |_| success.set(None))
+    };
+
+    let breadcrumb = |title: String| {
+        html! {
+            <Breadcrumb items={vec![
+                BreadcrumbItem { label: t("polls-title"), route: Some(Route::Polls) },
+                BreadcrumbItem { label: title, route: None },
+            ]} />
+        }
+    };
+
+    if *loading {
+        return html! {
+            <div class="container mt-4 text-center py-5">
+                <div class="spinner-border" role="status">
+                    <span class="visually-hidden">{t("polls-loading")}</span>
+                </div>
+            </div>
+        };
+    }
+
+    let Some(p) = (*poll).clone() else {
+        return html! {
+            <div class="container mt-4">
+                {breadcrumb(t("polls-not-found"))}
+                if let Some(err) = (*error).clone() {
+                    <ErrorAlert message={err} on_close={clear_error.clone()} />
+                } else {
+                    <div class="alert alert-warning">{t("polls-not-found")}</div>
+                }
+            </div>
+        };
+    };
+
+    let render_input = |q: &Question| -> Html {
+        let current = answers.get(&q.id).cloned().unwrap_or(AnswerPayload {
+            question_id: q.id,
+            ..Default::default()
+        });
+        match q.kind.as_str() {
+            "SingleChoice" | "MultipleChoice" => {
+                let multiple = q.kind == "MultipleChoice";
+                html! {
+                    { for q.options.iter().map(|o| {
+                        let option_id = o.id;
+                        let input_id = format!("poll-q{}-o{}", q.id, o.id);
+                        let onchange = {
+                            let set_answer = set_answer.clone();
+                            let current = current.clone();
+                            Callback::from(move |e: Event| {
+                                let checked = e.target_unchecked_into::<HtmlInputElement>().checked();
+                                let mut next = current.clone();
+                                if !multiple {
+                                    next.option_ids.clear();
+                                }
+                                next.option_ids.retain(|id| *id != option_id);
+                                if checked {
+                                    next.option_ids.push(option_id);
+                                }
+                                set_answer.emit(next);
+                            })
+                        };
+                        html! {
+                            <div class="form-check" key={o.id}>
+                                <input
+                                    class="form-check-input"
+                                    type={if multiple { "checkbox" } else { "radio" }}
+                                    name={format!("poll-q{}", q.id)}
+                                    id={input_id.clone()}
+                                    checked={current.option_ids.contains(&o.id)}
+                                    disabled={*busy}
+                                    {onchange}
+                                />
+                                <label class="form-check-label" for={input_id}>{&o.label}</label>
+                            </div>
+                        }
+                    }) }
+                }
+            }
+            "FreeText" => {
+                let oninput = {
+                    let set_answer = set_answer.clone();
+                    let current = current.clone();
+                    Callback::from(move |e: InputEvent| {
+                        let value = e
+                            .target_unchecked_into::<web_sys::HtmlTextAreaElement>()
+                            .value();
+                        let mut next = current.clone();
+                        next.text = Some(value);
+                        set_answer.emit(next);
+                    })
+                };
+                html! {
+                    <textarea
+                        class="form-control"
+                        rows="3"
+                        value={current.text.clone().unwrap_or_default()}
+                        disabled={*busy}
+                        {oninput}
+                    />
+                }
+            }
+            "Rating" => html! {
+                <div class="btn-group" role="group">
+                    { for (1..=q.rating_max.unwrap_or(5)).map(|value| {
+                        let onclick = {
+                            let set_answer = set_answer.clone();
+                            let current = current.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                let mut next = current.clone();
+                                next.rating = Some(value);
+                                set_answer.emit(next);
+                            })
+                        };
+                        let class = if current.rating == Some(value) {
+                            "btn btn-primary"
+                        } else {
+                            "btn btn-outline-primary"
+                        };
+                        html! {
+                            <button type="button" {class} disabled={*busy} {onclick}>
+                                {value}
+                            </button>
+                        }
+                    }) }
+                </div>
+            },
+            _ => html! {},
+        }
+    };
+
+    let render_results = |r: &QuestionResults| -> Html {
+        html! {
+            <div class="mb-4" key={r.question_id}>
+                <h6>{&r.prompt}</h6>
+                <div class="small text-muted mb-2">
+                    {t_with_args("polls-answered-count", &[("count", &r.answered.to_string())])}
+                </div>
+                { for r.options.iter().map(|o| {
+                    let pct = percent(o.count, r.answered);
+                    html! {
+                        <div class="mb-2">
+                            <div class="d-flex justify-content-between small">
+                                <span>{&o.label}</span>
+                                <span>{format!("{} ({:.0} %)", o.count, pct)}</span>
+                            </div>
+                            <div class="progress" style="height: 8px;">
+                                <div class="progress-bar" role="progressbar" style={format!("width: {:.1}%", pct)}></div>
+                            </div>
+                        </div>
+                    }
+                }) }
+                if r.kind == "Rating" {
+                    if let Some(avg) = r.average_rating {
+                        <div class="mb-2">
+                            {t_with_args("polls-average-rating", &[("average", &format!("{:.1}", avg))])}
+                        </div>
+                    }
+                    <div class="d-flex gap-2 flex-wrap">
+                        { for r.ratings.iter().map(|rc| html! {
+                            <span class="badge bg-light text-dark">{format!("{} ★ × {}", rc.value, rc.count)}</span>
+                        }) }
+                    </div>
+                }
+                if !r.texts.is_empty() {
+                    <ul class="list-group list-group-flush">
+                        { for r.texts.iter().map(|ta| html! {
+                            <li class="list-group-item px-0">
+                                <div style="white-space: pre-wrap;">{&ta.text}</div>
+                                if let Some(name) = &ta.respondent {
+                                    <div class="small text-muted">{name}</div>
+                                }
+                            </li>
+                        }) }
+                    </ul>
+                }
+            </div>
+        }
+    };
+
+    let is_manager = auth.is_admin_or_manager();
+
+    html! {
+        <div class="container mt-4">
+            {breadcrumb(p.title.clone())}
+
+            if let Some(err) = (*error).clone() {
+                <ErrorAlert message={err} on_close={clear_error.clone()} />
+            }
+            if let Some(msg) = (*success).clone() {
+                <SuccessAlert message={msg} on_close={clear_success.clone()} />
+            }
+
+            <div class="card mb-3">
+                <div class="card-body">
+                    <div class="d-flex justify-content-between align-items-start mb-2">
+                        <h3 class="mb-0">{&p.title}</h3>
+                        <span class={classes!("badge", status_class(&p.status))}>
+                            {status_label(&p.status)}
+                        </span>
+                    </div>
+                    if !p.description.is_empty() {
+                        <p style="white-space: pre-wrap;">{&p.description}</p>
+                    }
+                    <div class="small text-muted">
+                        <div>{format_dt_local(&p.start_time)}{" — "}{format_dt_local(&p.end_time)}</div>
+                        <div>{t_with_args("polls-response-count", &[("count", &p.response_count.to_string())])}</div>
+                        <div>{t("polls-results-visibility")}{": "}{visibility_label(&p.results_visibility)}</div>
+                        if p.anonymous {
+                            <div><i class="bi bi-incognito me-1"></i>{t("polls-anonymous-notice")}</div>
+                        }
+                    </div>
+                    if is_manager {
+                        <div class="d-flex gap-2 mt-3">
+                            <button class="btn btn-sm btn-outline-secondary" onclick={on_export}>
+                                <i class="bi bi-download me-1"></i>{t("polls-export-csv")}
+                            </button>
+                            if p.status != "Closed" {
+                                <button class="btn btn-sm btn-outline-danger" disabled={*busy} onclick={on_close_poll}>
+                                    {t("polls-close")}
+                                </button>
+                            }
+                        </div>
+                    }
+                </div>
+            </div>
+
+            if p.can_respond {
+                <div class="card mb-3">
+                    <div class="card-header"><h5 class="mb-0">{t("polls-your-response")}</h5></div>
+                    <div class="card-body">
+                        <form onsubmit={on_submit}>
+                            { for p.questions.iter().map(|q| html! {
+                                <div class="mb-4" key={q.id}>
+                                    <label class="form-label fw-semibold">
+                                        {&q.prompt}
+                                        if q.required {
+                                            <span class="text-danger ms-1">{"*"}</span>
+                                        }
+                                    </label>
+                                    {render_input(q)}
+                                </div>
+                            }) }
+                            <button type="submit" class="btn btn-primary" disabled={*busy}>
+                                {t("polls-submit-response")}
+                            </button>
+                        </form>
+                    </div>
+                </div>
+            } else if p.has_responded {
+                <div class="alert alert-info">{t("polls-already-responded")}</div>
+            }
+
+            if let Some(r) = (*results).clone() {
+                <div class="card mb-3">
+                    <div class="card-header">
+                        <h5 class="mb-0">
+                            {t("polls-results")}
+                            <span class="text-muted small ms-2">
+                                {t_with_args("polls-response-count", &[("count", &r.response_count.to_string())])}
+                            </span>
+                        </h5>
+                    </div>
+                    <div class="card-body">
+                        { for r.questions.iter().map(render_results) }
+                    </div>
+                </div>
+            } else if !p.can_view_results {
+                <div class="alert alert-secondary">{t("polls-results-hidden")}</div>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,184 @@
+use crate::components::ErrorAlert;
+use crate::components::pagination::Pagination;
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::routes::Route;
+use crate::services::api::{PaginatedResponse, PaginationMeta, api_client};
+use crate::utils::datetime::format_dt_local;
+use serde::Deserialize;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct PollSummary {
+    id: u64,
+    title: String,
+    description: String,
+    start_time: String,
+    end_time: String,
+    anonymous: bool,
+    status: String,
+    question_count: usize,
+    response_count: i64,
+    has_responded: bool,
+}
+
+pub(super) fn status_class(status: &str) -> &'static str {
+    match status {
+        "Scheduled" => "bg-secondary",
+        "Open" => "bg-success",
+        "Closed" => "bg-warning text-dark",
+        _ => "bg-light text-dark",
+    }
+}
+
+pub(super) fn status_label(status: &str) -> String {
+    match status {
+        "Scheduled" => t("polls-status-scheduled"),
+        "Open" => t("polls-status-open"),
+        "Closed" => t("polls-status-closed"),
+        other => other.to_string(),
+    }
+}
+
+#[function_component(PollListPage)]
+pub fn poll_list_page() -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+    let navigator = use_navigator().unwrap();
+
+    let polls = use_state(Vec::<PollSummary>::new);
+    let pagination_meta = use_state(|| None::<PaginationMeta>);
+    let current_page = use_state(|| 1i64);
+    let loading = use_state(|| true);
+    let error = use_state(|| None::<String>);
+
+    let token = auth.token().map(|t| t.to_string());
+
+    {
+        let polls = polls.clone();
+        let pagination_meta = pagination_meta.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let token = token.clone();
+        let page = *current_page;
+
+        use_effect_with(page, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                loading.set(true);
+                let client = api_client(token.as_deref());
+                let url = format!("/polls?page={}&per_page=20", page);
+                match client.get::<PaginatedResponse<PollSummary>>(&url).await {
+                    Ok(resp) => {
+                        pagination_meta.set(Some(resp.pagination));
+                        polls.set(resp.data);
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "polls-failed-load",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let on_new_poll = {
+        let navigator = navigator.clone();
+        Callback::from(move |_| navigator.push(&Route::PollNew))
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+
+    let on_page_change = {
+        let current_page = current_page.clone();
+        Callback::from(move |page: i64| current_page.set(page))
+    };
+
+    html! {
+        <div class="container mt-4">
+            <div class="d-flex justify-content-between align-items-center mb-3">
+                <div>
+                    <h2 class="mb-0">{t("polls-title")}</h2>
+                    <div class="text-muted small">{t("polls-subtitle")}</div>
+                </div>
+                if auth.is_admin_or_manager() {
+                    <button class="btn btn-primary" onclick={on_new_poll}>
+                        {t("polls-new-poll")}
+                    </button>
+                }
+            </div>
+
+            if let Some(err) = (*error).clone() {
+                <ErrorAlert message={err} on_close={clear_error.clone()} />
+            }
+
+            if *loading {
+                <div class="text-center py-5">
+                    <div class="spinner-border" role="status">
+                        <span class="visually-hidden">{t("polls-loading")}</span>
+                    </div>
+                </div>
+            } else if polls.is_empty() {
+                <div class="alert alert-info">{t("polls-none")}</div>
+            } else {
+                <div class="row">
+                    { for polls.iter().map(|poll| {
+                        let id = poll.id;
+                        let navigator = navigator.clone();
+                        html! {
+                            <div class="col-md-6 col-lg-4 mb-3" key={poll.id}>
+                                <div
+                                    class="card h-100"
+                                    style="cursor: pointer;"
+                                    onclick={Callback::from(move |_| navigator.push(&Route::PollDetail { id }))}
+                                >
+                                    <div class="card-body">
+                                        <div class="d-flex justify-content-between align-items-start mb-2">
+                                            <h5 class="card-title mb-0">{&poll.title}</h5>
+                                            <div class="d-flex gap-1">
+                                                if poll.has_responded {
+                                                    <span class="badge bg-info text-dark">{t("polls-responded")}</span>
+                                                } else if poll.status == "Open" {
+                                                    <span class="badge bg-success">{t("polls-can-respond")}</span>
+                                                }
+                                                <span class={classes!("badge", status_class(&poll.status))}>
+                                                    {status_label(&poll.status)}
+                                                </span>
+                                            </div>
+                                        </div>
+                                        <p class="card-text text-muted small mb-2">{&poll.description}</p>
+                                        <div class="mb-2 d-flex gap-1">
+                                            <span class="badge bg-light text-dark">
+                                                {t_with_args("polls-question-count", &[("count", &poll.question_count.to_string())])}
+                                            </span>
+                                            if poll.anonymous {
+                                                <span class="badge bg-light text-dark">{t("polls-anonymous")}</span>
+                                            }
+                                        </div>
+                                        <div class="small text-muted">
+                                            <div>{format_dt_local(&poll.start_time)}{" — "}{format_dt_local(&poll.end_time)}</div>
+                                            <div>{t_with_args("polls-response-count", &[("count", &poll.response_count.to_string())])}</div>
+                                        </div>
+                                    </div>
+                                </div>
+                            </div>
+                        }
+                    }) }
+                </div>
+
+                if let Some(ref m) = *pagination_meta {
+                    <Pagination
+                        current_page={m.page}
+                        total_pages={m.total_pages}
+                        total_items={m.total}
+                        on_page_change={on_page_change.clone()}
+                    />
+                }
+            }
+        </div>
+    }
+}
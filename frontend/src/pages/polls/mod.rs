@@ -0,0 +1,7 @@
+mod detail;
+mod list;
+mod new;
+
+pub use detail::PollDetailPage;
+pub use list::PollListPage;
+pub use new::PollNewPage;
@@ -0,0 +1,441 @@
+use crate::components::breadcrumb::BreadcrumbItem;
+use crate::components::{
+    Breadcrumb, Checkbox, DateTimeInput, ErrorAlert, FormGroup, Select, SelectOption, TextInput,
+    Textarea,
+};
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::routes::Route;
+use crate::services::{ApiError, api_client};
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Serialize)]
+struct QuestionPayload {
+    prompt: String,
+    kind: String,
+    required: bool,
+    options: Vec<String>,
+    rating_max: Option<i32>,
+}
+
+#[derive(Serialize)]
+struct CreatePollPayload {
+    title: String,
+    description: String,
+    building_id: Option<u64>,
+    start_time: String,
+    end_time: String,
+    anonymous: bool,
+    results_visibility: String,
+    questions: Vec<QuestionPayload>,
+}
+
+#[derive(Deserialize)]
+struct CreatedPoll {
+    id: u64,
+}
+
+#[derive(Deserialize, Clone)]
+struct Building {
+    id: u64,
+    address: String,
+}
+
+#[derive(Clone, PartialEq)]
+struct QuestionDraft {
+    prompt: String,
+    kind: String,
+    required: bool,
+    /// One option per line
+    options: String,
+    rating_max: i32,
+}
+
+impl Default for QuestionDraft {
+    fn default() -> Self {
+        Self {
+            prompt: String::new(),
+            kind: "SingleChoice".into(),
+            required: true,
+            options: String::new(),
+            rating_max: 5,
+        }
+    }
+}
+
+impl QuestionDraft {
+    fn has_options(&self) -> bool {
+        self.kind == "SingleChoice" || self.kind == "MultipleChoice"
+    }
+
+    fn payload(&self) -> QuestionPayload {
+        QuestionPayload {
+            prompt: self.prompt.trim().to_string(),
+            kind: self.kind.clone(),
+            required: self.required,
+            options: if self.has_options() {
+                self.options
+                    .lines()
+                    .map(str::trim)
+                    .filter(|l| !l.is_empty())
+                    .map(String::from)
+                    .collect()
+            } else {
+                Vec::new()
+            },
+            rating_max: (self.kind == "Rating").then_some(self.rating_max),
+        }
+    }
+}
+
+fn datetime_plus_days(days: f64) -> String {
+    let now = js_sys::Date::new_0();
+    now.set_date((now.get_date() as f64 + days) as u32);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}",
+        now.get_full_year(),
+        now.get_month() + 1,
+        now.get_date(),
+        now.get_hours(),
+        now.get_minutes()
+    )
+}
+
+#[function_component(PollNewPage)]
+pub fn poll_new_page() -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+    let navigator = use_navigator().unwrap();
+
+    let buildings = use_state(Vec::<Building>::new);
+    let selected_building = use_state(String::new);
+    let title = use_state(String::default);
+    let description = use_state(String::default);
+    let start_time = use_state(|| datetime_plus_days(0.0));
+    let end_time = use_state(|| datetime_plus_days(7.0));
+    let anonymous = use_state(|| false);
+    let results_visibility = use_state(|| "AfterResponse".to_string());
+    let questions = use_state(|| vec![QuestionDraft::default()]);
+    let submitting = use_state(|| false);
+    let error = use_state(|| None::<String>);
+
+    let token = auth.token().map(|t| t.to_string());
+
+    {
+        let buildings = buildings.clone();
+        let token = token.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client.get::<Vec<Building>>("/buildings/my").await {
+                    buildings.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    if !auth.is_admin_or_manager() {
+        return html! {
+            <div class="container mt-4">
+                <div class="alert alert-danger">{t("polls-no-permission-create")}</div>
+            </div>
+        };
+    }
+
+    // Applies `f` to question `index`
+    let update_question = {
+        let questions = questions.clone();
+        move |index: usize, f: fn(&mut QuestionDraft, String)| {
+            let questions = questions.clone();
+            Callback::from(move |value: String| {
+                let mut list = (*questions).clone();
+                if let Some(q) = list.get_mut(index) {
+                    f(q, value);
+                }
+                questions.set(list);
+            })
+        }
+    };
+
+    let on_add_question = {
+        let questions = questions.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut list = (*questions).clone();
+            list.push(QuestionDraft::default());
+            questions.set(list);
+        })
+    };
+
+    let on_submit = {
+        let title = title.clone();
+        let description = description.clone();
+        let selected_building = selected_building.clone();
+        let start_time = start_time.clone();
+        let end_time = end_time.clone();
+        let anonymous = anonymous.clone();
+        let results_visibility = results_visibility.clone();
+        let questions = questions.clone();
+        let submitting = submitting.clone();
+        let error = error.clone();
+        let navigator = navigator.clone();
+        let token = token.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if title.trim().is_empty() {
+                error.set(Some(t("polls-title-required")));
+                return;
+            }
+            if questions.iter().any(|q| q.prompt.trim().is_empty()) {
+                error.set(Some(t("polls-question-prompt-required")));
+                return;
+            }
+            if questions
+                .iter()
+                .any(|q| q.has_options() && q.payload().options.len() < 2)
+            {
+                error.set(Some(t("polls-question-options-required")));
+                return;
+            }
+
+            let payload = CreatePollPayload {
+                title: (*title).clone(),
+                description: (*description).clone(),
+                building_id: selected_building.parse().ok(),
+                start_time: (*start_time).clone(),
+                end_time: (*end_time).clone(),
+                anonymous: *anonymous,
+                results_visibility: (*results_visibility).clone(),
+                questions: questions.iter().map(QuestionDraft::payload).collect(),
+            };
+            let submitting = submitting.clone();
+            let error = error.clone();
+            let navigator = navigator.clone();
+            let token = token.clone();
+            submitting.set(true);
+            error.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client.post::<_, CreatedPoll>("/polls", &payload).await {
+                    Ok(created) => navigator.push(&Route::PollDetail { id: created.id }),
+                    Err(ApiError::Forbidden) => {
+                        error.set(Some(t("polls-no-permission-create")));
+                        submitting.set(false);
+                    }
+                    Err(e) => {
+                        error.set(Some(t_with_args(
+                            "polls-failed-create",
+                            &[("error", &e.to_string())],
+                        )));
+                        submitting.set(false);
+                    }
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let navigator = navigator.clone();
+        Callback::from(move |_| navigator.push(&Route::Polls))
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+
+    let building_options = {
+        let mut options = vec![SelectOption::new("", t("polls-global-scope"))];
+        for building in buildings.iter() {
+            options.push(SelectOption::new(
+                building.id.to_string(),
+                &building.address,
+            ));
+        }
+        options
+    };
+    let visibility_options = vec![
+        SelectOption::new("Always", t("polls-visibility-always")),
+        SelectOption::new("AfterResponse", t("polls-visibility-after-response")),
+        SelectOption::new("AfterClose", t("polls-visibility-after-close")),
+        SelectOption::new("Managers", t("polls-visibility-managers")),
+    ];
+    let kind_options = vec![
+        SelectOption::new("SingleChoice", t("polls-kind-single")),
+        SelectOption::new("MultipleChoice", t("polls-kind-multiple")),
+        SelectOption::new("FreeText", t("polls-kind-text")),
+        SelectOption::new("Rating", t("polls-kind-rating")),
+    ];
+
+    html! {
+        <div class="container mt-4">
+            <Breadcrumb items={vec![
+                BreadcrumbItem { label: t("polls-title"), route: Some(Route::Polls) },
+                BreadcrumbItem { label: t("polls-new-breadcrumb"), route: None },
+            ]} />
+            <div class="row justify-content-center">
+                <div class="col-md-10 col-lg-8">
+                    <div class="card">
+                        <div class="card-header">
+                            <h4 class="mb-0">{t("polls-new-title")}</h4>
+                        </div>
+                        <div class="card-body">
+                            if let Some(err) = (*error).clone() {
+                                <ErrorAlert message={err} on_close={clear_error.clone()} />
+                            }
+
+                            <form onsubmit={on_submit}>
+                                <FormGroup title={t("polls-basic-info")}>
+                                    <TextInput
+                                        label={t("polls-poll-title")}
+                                        value={(*title).clone()}
+                                        on_change={{ let title = title.clone(); Callback::from(move |v: String| title.set(v)) }}
+                                        disabled={*submitting}
+                                        required=true
+                                    />
+                                    <Textarea
+                                        label={t("polls-poll-description")}
+                                        value={(*description).clone()}
+                                        on_change={{ let description = description.clone(); Callback::from(move |v: String| description.set(v)) }}
+                                        rows={3}
+                                        disabled={*submitting}
+                                    />
+                                    <Select
+                                        label={t("polls-building-scope")}
+                                        value={(*selected_building).clone()}
+                                        on_change={{ let b = selected_building.clone(); Callback::from(move |v: String| b.set(v)) }}
+                                        options={building_options}
+                                        disabled={*submitting}
+                                    />
+                                </FormGroup>
+
+                                <FormGroup title={t("polls-settings")}>
+                                    <DateTimeInput
+                                        label={t("polls-start-time")}
+                                        value={(*start_time).clone()}
+                                        on_change={{ let s = start_time.clone(); Callback::from(move |v: String| s.set(v)) }}
+                                        input_type="datetime-local"
+                                        disabled={*submitting}
+                                        required=true
+                                    />
+                                    <DateTimeInput
+                                        label={t("polls-end-time")}
+                                        value={(*end_time).clone()}
+                                        on_change={{ let s = end_time.clone(); Callback::from(move |v: String| s.set(v)) }}
+                                        input_type="datetime-local"
+                                        min={Some((*start_time).clone())}
+                                        disabled={*submitting}
+                                        required=true
+                                    />
+                                    <Select
+                                        label={t("polls-results-visibility")}
+                                        value={(*results_visibility).clone()}
+                                        on_change={{ let r = results_visibility.clone(); Callback::from(move |v: String| r.set(v)) }}
+                                        options={visibility_options}
+                                        disabled={*submitting}
+                                    />
+                                    <Checkbox
+                                        id="poll-anonymous"
+                                        label={t("polls-anonymous-help")}
+                                        checked={*anonymous}
+                                        on_change={{ let a = anonymous.clone(); Callback::from(move |v: bool| a.set(v)) }}
+                                        disabled={*submitting}
+                                    />
+                                </FormGroup>
+
+                                <FormGroup title={t("polls-questions")}>
+                                    { for questions.iter().enumerate().map(|(i, q)| {
+                                        let on_remove = {
+                                            let questions = questions.clone();
+                                            Callback::from(move |_: MouseEvent| {
+                                                let mut list = (*questions).clone();
+                                                list.remove(i);
+                                                questions.set(list);
+                                            })
+                                        };
+                                        let on_required = {
+                                            let questions = questions.clone();
+                                            Callback::from(move |checked: bool| {
+                                                let mut list = (*questions).clone();
+                                                list[i].required = checked;
+                                                questions.set(list);
+                                            })
+                                        };
+                                        html! {
+                                            <div class="border rounded p-2 mb-2" key={i}>
+                                                <div class="d-flex justify-content-between align-items-center mb-1">
+                                                    <strong class="small">
+                                                        {t_with_args("polls-question-number", &[("number", &(i + 1).to_string())])}
+                                                    </strong>
+                                                    if questions.len() > 1 {
+                                                        <button type="button" class="btn btn-sm btn-outline-danger" onclick={on_remove}>
+                                                            <i class="bi bi-x"></i>
+                                                        </button>
+                                                    }
+                                                </div>
+                                                <TextInput
+                                                    label={t("polls-question-prompt")}
+                                                    value={q.prompt.clone()}
+                                                    on_change={update_question(i, |q, v| q.prompt = v)}
+                                                    disabled={*submitting}
+                                                    required=true
+                                                />
+                                                <Select
+                                                    label={t("polls-question-kind")}
+                                                    value={q.kind.clone()}
+                                                    on_change={update_question(i, |q, v| q.kind = v)}
+                                                    options={kind_options.clone()}
+                                                    disabled={*submitting}
+                                                />
+                                                if q.has_options() {
+                                                    <Textarea
+                                                        label={t("polls-question-options")}
+                                                        value={q.options.clone()}
+                                                        on_change={update_question(i, |q, v| q.options = v)}
+                                                        rows={3}
+                                                        disabled={*submitting}
+                                                    />
+                                                }
+                                                if q.kind == "Rating" {
+                                                    <TextInput
+                                                        label={t("polls-question-rating-max")}
+                                                        value={q.rating_max.to_string()}
+                                                        on_change={update_question(i, |q, v| q.rating_max = v.parse().unwrap_or(5))}
+                                                        input_type="number"
+                                                        disabled={*submitting}
+                                                    />
+                                                }
+                                                <Checkbox
+                                                    id={format!("poll-question-required-{}", i)}
+                                                    label={t("polls-question-required")}
+                                                    checked={q.required}
+                                                    on_change={on_required}
+                                                    disabled={*submitting}
+                                                />
+                                            </div>
+                                        }
+                                    }) }
+                                    <button type="button" class="btn btn-sm btn-outline-primary" onclick={on_add_question}>
+                                        <i class="bi bi-plus me-1"></i>{t("polls-add-question")}
+                                    </button>
+                                </FormGroup>
+
+                                <div class="d-flex justify-content-end gap-2">
+                                    <button type="button" class="btn btn-secondary" disabled={*submitting} onclick={on_cancel}>
+                                        {t("button-cancel")}
+                                    </button>
+                                    <button type="submit" class="btn btn-primary" disabled={*submitting}>
+                                        {t("polls-create")}
+                                    </button>
+                                </div>
+                            </form>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
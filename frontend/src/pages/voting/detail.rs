@@ -1,4 +1,9 @@
 use crate::components::breadcrumb::BreadcrumbItem;
+use crate::components::voting::{
+    AuditReport, OptionBallot, OptionResults, ProposalDiscussion, ProposalDocuments,
+    ProposalOption, ProxyBallot, ProxyGrant, ProxyVoter, ProxyVotes, ReceiptCheck, ResultRound,
+    SeatVote, SeatVotes,
+};
 use crate::components::{Breadcrumb, ErrorAlert, SuccessAlert};
 use crate::contexts::AuthContext;
 use crate::i18n::{t, t_with_args};
@@ -37,6 +42,23 @@ struct ProposalWithVotes {
     user_vote: Option<String>,
     user_eligible: bool,
     result: Option<ProposalResult>,
+    ballot_type: String,
+    max_selections: Option<i32>,
+    #[serde(default)]
+    secret_ballot: bool,
+    #[serde(default)]
+    options: Vec<ProposalOption>,
+    #[serde(default)]
+    user_selection: Vec<u64>,
+    #[serde(default)]
+    rounds: Vec<ResultRound>,
+    #[serde(default)]
+    user_vote_by_proxy: bool,
+    #[serde(default)]
+    proxies_held: Vec<ProxyVoter>,
+    /// Apartments the user votes for on PerSeat proposals
+    #[serde(default)]
+    seats: Vec<SeatVote>,
 }
 
 #[derive(Deserialize, Clone, PartialEq)]
@@ -44,6 +66,7 @@ struct ProposalResult {
     id: u64,
     proposal_id: u64,
     passed: bool,
+    winning_option_id: Option<u64>,
     yes_weight: String,
     no_weight: String,
     abstain_weight: String,
@@ -55,6 +78,52 @@ struct ProposalResult {
 #[derive(Serialize)]
 struct CastVotePayload {
     choice: String,
+    option_ids: Vec<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    on_behalf_of: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    apartment_id: Option<u64>,
+}
+
+impl CastVotePayload {
+    fn choice(choice: &str) -> Self {
+        Self {
+            choice: choice.to_string(),
+            option_ids: Vec::new(),
+            on_behalf_of: None,
+            apartment_id: None,
+        }
+    }
+
+    /// Option ballot; an empty selection abstains
+    fn options(option_ids: Vec<u64>) -> Self {
+        let choice = if option_ids.is_empty() { "Abstain" } else { "" };
+        Self {
+            choice: choice.to_string(),
+            option_ids,
+            on_behalf_of: None,
+            apartment_id: None,
+        }
+    }
+
+    /// Cast for one of the user's apartments on PerSeat proposals
+    fn for_seat(self, apartment_id: Option<u64>) -> Self {
+        Self {
+            apartment_id,
+            ..self
+        }
+    }
+}
+
+impl From<ProxyBallot> for CastVotePayload {
+    fn from(ballot: ProxyBallot) -> Self {
+        Self {
+            choice: ballot.choice,
+            option_ids: ballot.option_ids,
+            on_behalf_of: Some(ballot.on_behalf_of),
+            apartment_id: None,
+        }
+    }
 }
 
 #[derive(Properties, PartialEq)]
@@ -70,6 +139,10 @@ pub fn voting_detail_page(props: &Props) -> Html {
     let loading = use_state(|| true);
     let voting = use_state(|| false);
     let tallying = use_state(|| false);
+    // Apartment the ballot is cast for on PerSeat proposals; the first one by default
+    let selected_seat = use_state(|| None::<u64>);
+    // Receipt of the secret ballot just cast; the server does not keep it for the voter
+    let receipt = use_state(|| None::<String>);
 
     let error = use_state(|| None::<String>);
     let success = use_state(|| None::<String>);
@@ -111,13 +184,15 @@ pub fn voting_detail_page(props: &Props) -> Html {
 
     let on_vote = {
         let proposal = proposal.clone();
+        let receipt = receipt.clone();
         let voting = voting.clone();
         let error = error.clone();
         let success = success.clone();
         let token = token.clone();
 
-        Callback::from(move |choice: String| {
+        Callback::from(move |payload: CastVotePayload| {
             let proposal = proposal.clone();
+            let receipt = receipt.clone();
             let voting = voting.clone();
             let error = error.clone();
             let success = success.clone();
@@ -130,10 +205,6 @@ pub fn voting_detail_page(props: &Props) -> Html {
 
                 wasm_bindgen_futures::spawn_local(async move {
                     let client = api_client(token.as_deref());
-                    let payload = CastVotePayload {
-                        choice: choice.clone(),
-                    };
-
                     match client
                         .post::<_, serde_json::Value>(
                             &format!("/proposals/{}/vote", p.id),
@@ -141,11 +212,20 @@ pub fn voting_detail_page(props: &Props) -> Html {
                         )
                         .await
                     {
-                        Ok(_) => {
-                            success.set(Some(t_with_args(
-                                "voting-vote-cast-choice",
-                                &[("choice", &choice)],
-                            )));
+                        Ok(body) => {
+                            if let Some(code) = body["receipt"].as_str() {
+                                receipt.set(Some(code.to_string()));
+                            }
+                            success.set(Some(if payload.on_behalf_of.is_some() {
+                                t("voting-proxy-vote-cast")
+                            } else if payload.option_ids.is_empty() {
+                                t_with_args(
+                                    "voting-vote-cast-choice",
+                                    &[("choice", &payload.choice)],
+                                )
+                            } else {
+                                t("voting-ballot-cast")
+                            }));
                             // Reload proposal to get updated counts
                             if let Ok(updated) = client
                                 .get::<ProposalWithVotes>(&format!("/proposals/{}", p.id))
@@ -235,6 +315,21 @@ pub fn voting_detail_page(props: &Props) -> Html {
         Callback::from(move |_| success.set(None))
     };
 
+    // On PerSeat proposals the ballot is for the selected apartment and shows its vote
+    let seat = (*proposal).as_ref().and_then(|p| {
+        p.seats
+            .iter()
+            .find(|s| Some(s.apartment_id) == *selected_seat)
+            .or(p.seats.first())
+            .cloned()
+    });
+    let seat_id = seat.as_ref().map(|s| s.apartment_id);
+    let (ballot_vote, ballot_selection) = match (&seat, &*proposal) {
+        (Some(s), _) => (s.vote.clone(), s.selection.clone()),
+        (None, Some(p)) => (p.user_vote.clone(), p.user_selection.clone()),
+        (None, None) => (None, Vec::new()),
+    };
+
     // Status badge color
     let status_class = |status: &str| match status {
         "Scheduled" => "bg-secondary",
@@ -300,41 +395,64 @@ pub fn voting_detail_page(props: &Props) -> Html {
 
                                 <hr />
 
-                                <h5>{t("voting-vote-counts")}</h5>
-                                <div class="row text-center mb-3">
-                                    <div class="col-4">
-                                        <div class="border rounded p-2 bg-success-subtle">
-                                            <div class="fs-4 fw-bold text-success">{p.yes_count}</div>
-                                            <div class="small">{t("voting-yes-votes")}</div>
-                                        </div>
+                                if p.secret_ballot && p.status != "Tallied" {
+                                    <div class="alert alert-light border small">
+                                        <i class="bi bi-incognito me-1"></i>
+                                        {t("voting-secret-results-hidden")}
                                     </div>
-                                    <div class="col-4">
-                                        <div class="border rounded p-2 bg-danger-subtle">
-                                            <div class="fs-4 fw-bold text-danger">{p.no_count}</div>
-                                            <div class="small">{t("voting-no-votes")}</div>
+                                } else if p.ballot_type != "YesNo" {
+                                    <OptionResults
+                                        ballot_type={p.ballot_type.clone()}
+                                        options={p.options.clone()}
+                                        rounds={p.rounds.clone()}
+                                        winning_option_id={p.result.as_ref().and_then(|r| r.winning_option_id)}
+                                    />
+                                } else {
+                                    <h5>{t("voting-vote-counts")}</h5>
+                                    <div class="row text-center mb-3">
+                                        <div class="col-4">
+                                            <div class="border rounded p-2 bg-success-subtle">
+                                                <div class="fs-4 fw-bold text-success">{p.yes_count}</div>
+                                                <div class="small">{t("voting-yes-votes")}</div>
+                                            </div>
                                         </div>
-                                    </div>
-                                    <div class="col-4">
-                                        <div class="border rounded p-2 bg-secondary-subtle">
-                                            <div class="fs-4 fw-bold text-secondary">{p.abstain_count}</div>
-                                            <div class="small">{t("voting-abstain-votes")}</div>
+                                        <div class="col-4">
+                                            <div class="border rounded p-2 bg-danger-subtle">
+                                                <div class="fs-4 fw-bold text-danger">{p.no_count}</div>
+                                                <div class="small">{t("voting-no-votes")}</div>
+                                            </div>
+                                        </div>
+                                        <div class="col-4">
+                                            <div class="border rounded p-2 bg-secondary-subtle">
+                                                <div class="fs-4 fw-bold text-secondary">{p.abstain_count}</div>
+                                                <div class="small">{t("voting-abstain-votes")}</div>
+                                            </div>
                                         </div>
                                     </div>
-                                </div>
+                                }
                                 <div class="text-center text-muted small">
                                     {t_with_args("voting-total-votes-label", &[("count", &p.total_votes.to_string())])}
                                 </div>
 
-                                {
-                                    if let Some(user_vote) = &p.user_vote {
-                                        html! {
-                                            <div class="alert alert-info mt-3">
-                                                {t_with_args("voting-your-current-vote", &[("vote", user_vote)])}
-                                            </div>
-                                        }
-                                    } else {
-                                        html! {}
-                                    }
+                                if let Some(code) = (*receipt).clone() {
+                                    <div class="alert alert-warning mt-3">
+                                        <div>{t("voting-secret-receipt")}</div>
+                                        <div class="fs-4 fw-bold font-monospace text-center my-2">{code}</div>
+                                    </div>
+                                }
+                                if p.secret_ballot && p.user_vote.is_some() {
+                                    <div class="alert alert-info mt-3">
+                                        {t("voting-secret-ballot-cast")}
+                                    </div>
+                                } else if let Some(user_vote) = p.user_vote.as_ref().filter(|_| p.ballot_type == "YesNo") {
+                                    <div class="alert alert-info mt-3">
+                                        {t_with_args("voting-your-current-vote", &[("vote", user_vote)])}
+                                    </div>
+                                }
+                                if p.user_vote_by_proxy {
+                                    <div class="alert alert-secondary small mt-2">
+                                        {t("voting-proxy-voted-for-you")}
+                                    </div>
                                 }
 
                                 {
@@ -348,6 +466,11 @@ pub fn voting_detail_page(props: &Props) -> Html {
                                                         {if result.passed { t("voting-proposal-passed") } else { t("voting-proposal-failed") }}
                                                     </strong>
                                                 </div>
+                                                if let Some(winner) = result.winning_option_id.and_then(|id| p.options.iter().find(|o| o.id == id)) {
+                                                    <div class="mb-2">
+                                                        {t_with_args("voting-winning-option", &[("option", &winner.label)])}
+                                                    </div>
+                                                }
                                                 <div class="small">
                                                     <div>{t("voting-yes-weight")}{" "}{&result.yes_weight}</div>
                                                     <div>{t("voting-no-weight")}{" "}{&result.no_weight}</div>
@@ -365,6 +488,14 @@ pub fn voting_detail_page(props: &Props) -> Html {
                                 }
                             </div>
                         </div>
+
+                        <div class="mt-3">
+                            <ProposalDiscussion
+                                proposal_id={p.id}
+                                token={token.clone()}
+                                open={p.status == "Scheduled" || p.status == "Open"}
+                            />
+                        </div>
                     </div>
 
                     // Voting panel
@@ -377,49 +508,82 @@ pub fn voting_detail_page(props: &Props) -> Html {
                                             <h5 class="mb-0">{t("voting-cast-vote")}</h5>
                                         </div>
                                         <div class="card-body">
-                                            <div class="d-grid gap-2">
-                                                <button
-                                                    class="btn btn-success"
-                                                    disabled={*voting}
-                                                    onclick={{
-                                                        let on_vote = on_vote.clone();
-                                                        Callback::from(move |_| on_vote.emit("Yes".to_string()))
+                                            if let Some(selected) = seat_id {
+                                                <SeatVotes
+                                                    proposal_id={p.id}
+                                                    token={token.clone()}
+                                                    seats={p.seats.clone()}
+                                                    {selected}
+                                                    on_select={{
+                                                        let selected_seat = selected_seat.clone();
+                                                        Callback::from(move |id: u64| selected_seat.set(Some(id)))
                                                     }}
-                                                >
-                                                    if *voting {
-                                                        <span class="spinner-border spinner-border-sm me-1"></span>
-                                                    }
-                                                    {t("voting-vote-yes")}
-                                                </button>
-                                                <button
-                                                    class="btn btn-danger"
+                                                    secret={p.secret_ballot}
+                                                />
+                                            }
+                                            if p.secret_ballot && ballot_vote.is_some() {
+                                                <p class="mb-0">{t("voting-secret-ballot-cast")}</p>
+                                            } else if p.ballot_type != "YesNo" {
+                                                <OptionBallot
+                                                    ballot_type={p.ballot_type.clone()}
+                                                    max_selections={p.max_selections}
+                                                    options={p.options.clone()}
+                                                    user_selection={ballot_selection.clone()}
                                                     disabled={*voting}
-                                                    onclick={{
+                                                    on_submit={{
                                                         let on_vote = on_vote.clone();
-                                                        Callback::from(move |_| on_vote.emit("No".to_string()))
+                                                        Callback::from(move |ids: Vec<u64>| on_vote.emit(CastVotePayload::options(ids).for_seat(seat_id)))
                                                     }}
-                                                >
-                                                    if *voting {
-                                                        <span class="spinner-border spinner-border-sm me-1"></span>
-                                                    }
-                                                    {t("voting-vote-no")}
-                                                </button>
-                                                <button
-                                                    class="btn btn-secondary"
-                                                    disabled={*voting}
-                                                    onclick={{
-                                                        let on_vote = on_vote.clone();
-                                                        Callback::from(move |_| on_vote.emit("Abstain".to_string()))
-                                                    }}
-                                                >
-                                                    if *voting {
-                                                        <span class="spinner-border spinner-border-sm me-1"></span>
-                                                    }
-                                                    {t("voting-vote-abstain")}
-                                                </button>
-                                            </div>
+                                                />
+                                            } else {
+                                                <div class="d-grid gap-2">
+                                                    <button
+                                                        class="btn btn-success"
+                                                        disabled={*voting}
+                                                        onclick={{
+                                                            let on_vote = on_vote.clone();
+                                                            Callback::from(move |_| on_vote.emit(CastVotePayload::choice("Yes").for_seat(seat_id)))
+                                                        }}
+                                                    >
+                                                        if *voting {
+                                                            <span class="spinner-border spinner-border-sm me-1"></span>
+                                                        }
+                                                        {t("voting-vote-yes")}
+                                                    </button>
+                                                    <button
+                                                        class="btn btn-danger"
+                                                        disabled={*voting}
+                                                        onclick={{
+                                                            let on_vote = on_vote.clone();
+                                                            Callback::from(move |_| on_vote.emit(CastVotePayload::choice("No").for_seat(seat_id)))
+                                                        }}
+                                                    >
+                                                        if *voting {
+                                                            <span class="spinner-border spinner-border-sm me-1"></span>
+                                                        }
+                                                        {t("voting-vote-no")}
+                                                    </button>
+                                                    <button
+                                                        class="btn btn-secondary"
+                                                        disabled={*voting}
+                                                        onclick={{
+                                                            let on_vote = on_vote.clone();
+                                                            Callback::from(move |_| on_vote.emit(CastVotePayload::choice("Abstain").for_seat(seat_id)))
+                                                        }}
+                                                    >
+                                                        if *voting {
+                                                            <span class="spinner-border spinner-border-sm me-1"></span>
+                                                        }
+                                                        {t("voting-vote-abstain")}
+                                                    </button>
+                                                </div>
+                                            }
                                             <div class="text-muted small mt-3">
-                                                {t("voting-can-change-vote")}
+                                                if p.secret_ballot {
+                                                    {t("voting-secret-final")}
+                                                } else {
+                                                    {t("voting-can-change-vote")}
+                                                }
                                             </div>
                                         </div>
                                     </div>
@@ -482,6 +646,54 @@ pub fn voting_detail_page(props: &Props) -> Html {
                                 }
                             }
                         }
+
+                        if p.status == "Open" && !p.secret_ballot && !p.proxies_held.is_empty() {
+                            <ProxyVotes
+                                proxies={p.proxies_held.clone()}
+                                ballot_type={p.ballot_type.clone()}
+                                max_selections={p.max_selections}
+                                options={p.options.clone()}
+                                disabled={*voting}
+                                on_vote={{
+                                    let on_vote = on_vote.clone();
+                                    Callback::from(move |ballot: ProxyBallot| on_vote.emit(ballot.into()))
+                                }}
+                            />
+                        }
+
+                        if (p.status == "Open" || p.status == "Scheduled") && p.user_eligible && !p.secret_ballot {
+                            <ProxyGrant
+                                proposal_id={p.id}
+                                token={token.clone()}
+                                on_error={{
+                                    let error = error.clone();
+                                    Callback::from(move |msg: String| error.set(Some(msg)))
+                                }}
+                                on_success={{
+                                    let success = success.clone();
+                                    Callback::from(move |msg: String| success.set(Some(msg)))
+                                }}
+                            />
+                        }
+
+                        if p.secret_ballot && p.status == "Tallied" {
+                            <ReceiptCheck
+                                proposal_id={p.id}
+                                token={token.clone()}
+                                options={p.options.clone()}
+                            />
+                        }
+
+                        if p.result.is_some() && auth.is_admin_or_manager() {
+                            <AuditReport proposal_id={p.id} token={token.clone()} />
+                        }
+
+                        <ProposalDocuments
+                            proposal_id={p.id}
+                            token={token.clone()}
+                            can_upload={auth.is_admin_or_manager() && (p.status == "Scheduled" || p.status == "Open")}
+                            can_delete={auth.is_admin_or_manager()}
+                        />
                     </div>
                 </div>
             } else {
@@ -10,6 +10,15 @@ use serde::Deserialize;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+fn friendly_ballot_type(ballot_type: &str) -> String {
+    match ballot_type {
+        "SingleChoice" => t("voting-ballot-single"),
+        "Approval" => t("voting-ballot-approval"),
+        "RankedChoice" => t("voting-ballot-ranked"),
+        other => other.to_string(),
+    }
+}
+
 fn friendly_voting_method(method: &str) -> String {
     match method {
         "SimpleMajority" => t("voting-method-simple"),
@@ -29,6 +38,9 @@ struct Proposal {
     start_time: String,
     end_time: String,
     voting_method: String,
+    ballot_type: String,
+    #[serde(default)]
+    secret_ballot: bool,
     eligible_roles: String,
     status: String,
     created_at: Option<String>,
@@ -46,7 +58,7 @@ pub fn voting_list_page() -> Html {
     let auth = use_context::<AuthContext>().expect("AuthContext not found");
     let navigator = use_navigator().unwrap();
 
-    let proposals = use_state(|| Vec::<Proposal>::new());
+    let proposals = use_state(Vec::<Proposal>::new);
     let pagination_meta = use_state(|| None::<PaginationMeta>);
     let current_page = use_state(|| 1i64);
     let search_query = use_state(String::default);
@@ -139,11 +151,16 @@ pub fn voting_list_page() -> Html {
         <div class="container mt-4">
             <div class="d-flex justify-content-between align-items-center mb-3">
                 <h2>{t("voting-title")}</h2>
-                { if auth.is_admin_or_manager() { html!{
-                    <button class="btn btn-primary" onclick={on_new_proposal}>
-                        {t("voting-new-proposal")}
-                    </button>
-                } } else { html!{} } }
+                <div class="d-flex gap-2">
+                    <Link<Route> to={Route::VotingProxies} classes="btn btn-outline-secondary">
+                        <i class="bi bi-person-check me-1"></i>{t("voting-proxies-title")}
+                    </Link<Route>>
+                    { if auth.is_admin_or_manager() { html!{
+                        <button class="btn btn-primary" onclick={on_new_proposal}>
+                            {t("voting-new-proposal")}
+                        </button>
+                    } } else { html!{} } }
+                </div>
             </div>
 
             <div class="mb-3">
@@ -231,6 +248,16 @@ pub fn voting_list_page() -> Html {
                                                 <span class="badge bg-light text-dark">
                                                     {friendly_voting_method(&proposal.voting_method)}
                                                 </span>
+                                                if proposal.ballot_type != "YesNo" {
+                                                    <span class="badge bg-info text-dark ms-1">
+                                                        {friendly_ballot_type(&proposal.ballot_type)}
+                                                    </span>
+                                                }
+                                                if proposal.secret_ballot {
+                                                    <span class="badge bg-dark ms-1">
+                                                        <i class="bi bi-incognito me-1"></i>{t("voting-secret")}
+                                                    </span>
+                                                }
                                             </div>
                                             <div class="small text-muted">
                                                 <div>{t("voting-voting-label")}{" "}{format_dt_local(&proposal.start_time)}{" — "}{format_dt_local(&proposal.end_time)}</div>
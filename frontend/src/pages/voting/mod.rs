@@ -1,7 +1,9 @@
 mod detail;
 mod list;
 mod new;
+mod proxies;
 
 pub use detail::VotingDetailPage;
 pub use list::VotingListPage;
 pub use new::VotingNewPage;
+pub use proxies::VotingProxiesPage;
@@ -20,6 +20,10 @@ struct CreateProposalPayload {
     end_time: String,
     voting_method: String,
     eligible_roles: Vec<String>,
+    ballot_type: String,
+    options: Vec<String>,
+    max_selections: Option<i32>,
+    secret_ballot: bool,
 }
 
 #[derive(Deserialize, Clone)]
@@ -72,7 +76,7 @@ pub fn voting_new_page() -> Html {
         };
     }
 
-    let buildings = use_state(|| Vec::<Building>::new());
+    let buildings = use_state(Vec::<Building>::new);
     let selected_building = use_state(|| "".to_string());
 
     let title = use_state(String::default);
@@ -80,6 +84,11 @@ pub fn voting_new_page() -> Html {
     let start_time = use_state(now_datetime);
     let end_time = use_state(|| datetime_plus_days(7.0));
     let voting_method = use_state(|| "SimpleMajority".to_string());
+    let ballot_type = use_state(|| "YesNo".to_string());
+    // One option per line
+    let options_text = use_state(String::default);
+    let max_selections = use_state(String::default);
+    let secret_ballot = use_state(|| false);
 
     let role_admin = use_state(|| false);
     let role_manager = use_state(|| false);
@@ -116,6 +125,10 @@ pub fn voting_new_page() -> Html {
         let start_time = start_time.clone();
         let end_time = end_time.clone();
         let voting_method = voting_method.clone();
+        let ballot_type = ballot_type.clone();
+        let options_text = options_text.clone();
+        let max_selections = max_selections.clone();
+        let secret_ballot = secret_ballot.clone();
         let role_admin = role_admin.clone();
         let role_manager = role_manager.clone();
         let role_homeowner = role_homeowner.clone();
@@ -170,12 +183,34 @@ pub fn voting_new_page() -> Html {
                 return;
             }
 
+            let options: Vec<String> = if *ballot_type == "YesNo" {
+                Vec::new()
+            } else {
+                options_text
+                    .lines()
+                    .map(str::trim)
+                    .filter(|l| !l.is_empty())
+                    .map(String::from)
+                    .collect()
+            };
+            if *ballot_type != "YesNo" && options.len() < 2 {
+                error.set(Some(t("voting-options-required")));
+                return;
+            }
+            let max_selections = if *ballot_type == "Approval" {
+                max_selections.trim().parse().ok()
+            } else {
+                None
+            };
+            let secret_ballot = *secret_ballot && *voting_method != "WeightedArea";
+
             let title = title.clone();
             let description = description.clone();
             let selected_building = selected_building.clone();
             let start_time = start_time.clone();
             let end_time = end_time.clone();
             let voting_method = voting_method.clone();
+            let ballot_type = ballot_type.clone();
             let submitting = submitting.clone();
             let error = error.clone();
             let success = success.clone();
@@ -204,6 +239,10 @@ pub fn voting_new_page() -> Html {
                     end_time: (*end_time).clone(),
                     voting_method: (*voting_method).clone(),
                     eligible_roles,
+                    ballot_type: (*ballot_type).clone(),
+                    options,
+                    max_selections,
+                    secret_ballot,
                 };
 
                 match client
@@ -271,6 +310,21 @@ pub fn voting_new_page() -> Html {
         Callback::from(move |value: String| voting_method.set(value))
     };
 
+    let on_ballot_type_change = {
+        let ballot_type = ballot_type.clone();
+        Callback::from(move |value: String| ballot_type.set(value))
+    };
+
+    let on_options_change = {
+        let options_text = options_text.clone();
+        Callback::from(move |value: String| options_text.set(value))
+    };
+
+    let on_max_selections_change = {
+        let max_selections = max_selections.clone();
+        Callback::from(move |value: String| max_selections.set(value))
+    };
+
     let on_start_change = {
         let start_time = start_time.clone();
         Callback::from(move |value: String| start_time.set(value))
@@ -301,6 +355,11 @@ pub fn voting_new_page() -> Html {
         Callback::from(move |checked: bool| role_renter.set(checked))
     };
 
+    let on_secret_change = {
+        let secret_ballot = secret_ballot.clone();
+        Callback::from(move |checked: bool| secret_ballot.set(checked))
+    };
+
     let on_hoa_change = {
         let role_hoa = role_hoa.clone();
         Callback::from(move |checked: bool| role_hoa.set(checked))
@@ -326,6 +385,14 @@ pub fn voting_new_page() -> Html {
         SelectOption::new("Consensus", t("voting-method-consensus-desc")),
     ];
 
+    // Build ballot type options
+    let ballot_type_options = vec![
+        SelectOption::new("YesNo", t("voting-ballot-yes-no-desc")),
+        SelectOption::new("SingleChoice", t("voting-ballot-single-desc")),
+        SelectOption::new("Approval", t("voting-ballot-approval-desc")),
+        SelectOption::new("RankedChoice", t("voting-ballot-ranked-desc")),
+    ];
+
     html! {
         <div class="container mt-4">
             <Breadcrumb items={vec![
@@ -391,6 +458,50 @@ pub fn voting_new_page() -> Html {
                                         required=true
                                     />
 
+                                    <Select
+                                        label={t("voting-ballot-type")}
+                                        value={(*ballot_type).clone()}
+                                        on_change={on_ballot_type_change}
+                                        options={ballot_type_options}
+                                        disabled={*submitting}
+                                    />
+
+                                    if *ballot_type != "YesNo" {
+                                        <Textarea
+                                            label={t("voting-options")}
+                                            value={(*options_text).clone()}
+                                            on_change={on_options_change}
+                                            placeholder={t("voting-options-placeholder")}
+                                            rows={4}
+                                            disabled={*submitting}
+                                            required=true
+                                        />
+                                    }
+
+                                    if *ballot_type == "Approval" {
+                                        <TextInput
+                                            label={t("voting-max-selections")}
+                                            value={(*max_selections).clone()}
+                                            on_change={on_max_selections_change}
+                                            input_type="number"
+                                            help_text={t("voting-max-selections-help")}
+                                            disabled={*submitting}
+                                        />
+                                    }
+
+                                    <Checkbox
+                                        id="secret-ballot"
+                                        label={t("voting-secret-ballot")}
+                                        checked={*secret_ballot && *voting_method != "WeightedArea"}
+                                        on_change={on_secret_change}
+                                        help_text={if *voting_method == "WeightedArea" {
+                                            t("voting-secret-ballot-weighted")
+                                        } else {
+                                            t("voting-secret-ballot-help")
+                                        }}
+                                        disabled={*submitting || *voting_method == "WeightedArea"}
+                                    />
+
                                     <DateTimeInput
                                         label={t("voting-start-time")}
                                         value={(*start_time).clone()}
@@ -0,0 +1,433 @@
+use crate::components::breadcrumb::BreadcrumbItem;
+use crate::components::voting::{MyProxies, ProxyWithNames};
+use crate::components::{
+    Breadcrumb, Checkbox, DateTimeInput, ErrorAlert, Select, SelectOption, SuccessAlert,
+};
+use crate::contexts::AuthContext;
+use crate::i18n::{t, t_with_args};
+use crate::routes::Route;
+use crate::services::{ApiError, api_client};
+use crate::utils::datetime::format_dt_local;
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct Building {
+    id: u64,
+    address: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+struct UserInfo {
+    id: u64,
+    name: String,
+    email: String,
+}
+
+#[derive(Serialize)]
+struct GrantProxyPayload {
+    grantee_id: u64,
+    building_id: Option<u64>,
+    valid_from: String,
+    valid_until: String,
+}
+
+/// Scope of a proxy: a proposal title or the building-wide date range
+fn proxy_scope(proxy: &ProxyWithNames) -> String {
+    match (&proxy.proposal_title, &proxy.valid_from, &proxy.valid_until) {
+        (Some(title), _, _) => title.clone(),
+        (None, Some(from), Some(until)) => t_with_args(
+            "voting-proxy-scope-building",
+            &[
+                ("from", &format_dt_local(from)),
+                ("until", &format_dt_local(until)),
+            ],
+        ),
+        _ => "—".to_string(),
+    }
+}
+
+#[function_component(VotingProxiesPage)]
+pub fn voting_proxies_page() -> Html {
+    let auth = use_context::<AuthContext>().expect("AuthContext not found");
+    let is_manager = auth.is_admin_or_manager();
+
+    let mine = use_state(MyProxies::default);
+    let all = use_state(Vec::<ProxyWithNames>::new);
+    let include_revoked = use_state(|| false);
+    let buildings = use_state(Vec::<Building>::new);
+    let users = use_state(Vec::<UserInfo>::new);
+
+    let building = use_state(String::new);
+    let grantee = use_state(String::new);
+    let valid_from = use_state(String::new);
+    let valid_until = use_state(String::new);
+
+    let busy = use_state(|| false);
+    let reload = use_state(|| 0u32);
+    let error = use_state(|| None::<String>);
+    let success = use_state(|| None::<String>);
+
+    let token = auth.token().map(|t| t.to_string());
+
+    {
+        let buildings = buildings.clone();
+        let users = users.clone();
+        let token = token.clone();
+
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                if let Ok(list) = client.get::<Vec<Building>>("/buildings/my").await {
+                    buildings.set(list);
+                }
+                if let Ok(list) = client.get::<Vec<UserInfo>>("/users/public").await {
+                    users.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    {
+        let mine = mine.clone();
+        let all = all.clone();
+        let error = error.clone();
+        let token = token.clone();
+
+        use_effect_with((*reload, *include_revoked), move |(_, revoked)| {
+            let revoked = *revoked;
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client.get::<MyProxies>("/proxies/mine").await {
+                    Ok(list) => mine.set(list),
+                    Err(e) => error.set(Some(t_with_args(
+                        "voting-proxy-failed-load",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                if is_manager {
+                    let endpoint = format!("/proxies?include_revoked={}", revoked);
+                    if let Ok(list) = client.get::<Vec<ProxyWithNames>>(&endpoint).await {
+                        all.set(list);
+                    }
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_grant = {
+        let building = building.clone();
+        let grantee = grantee.clone();
+        let valid_from = valid_from.clone();
+        let valid_until = valid_until.clone();
+        let busy = busy.clone();
+        let reload = reload.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let token = token.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let (Ok(building_id), Ok(grantee_id)) =
+                (building.parse::<u64>(), grantee.parse::<u64>())
+            else {
+                error.set(Some(t("voting-proxy-fill-fields")));
+                return;
+            };
+            let payload = GrantProxyPayload {
+                grantee_id,
+                building_id: Some(building_id),
+                valid_from: (*valid_from).clone(),
+                valid_until: (*valid_until).clone(),
+            };
+            let busy = busy.clone();
+            let reload = reload.clone();
+            let error = error.clone();
+            let success = success.clone();
+            let token = token.clone();
+
+            busy.set(true);
+            error.set(None);
+            success.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client.post::<_, ProxyWithNames>("/proxies", &payload).await {
+                    Ok(proxy) => {
+                        success.set(Some(t_with_args(
+                            "voting-proxy-granted",
+                            &[("name", &proxy.grantee_name)],
+                        )));
+                        reload.set(*reload + 1);
+                    }
+                    Err(ApiError::Forbidden) => {
+                        error.set(Some(t("voting-proxy-not-owner")));
+                    }
+                    Err(ApiError::BadRequest(msg)) => error.set(Some(msg)),
+                    Err(e) => error.set(Some(t_with_args(
+                        "voting-proxy-failed-grant",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_revoke = {
+        let reload = reload.clone();
+        let error = error.clone();
+        let success = success.clone();
+        let token = token.clone();
+
+        Callback::from(move |id: u64| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(&t("voting-proxy-confirm-revoke"))
+                        .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let reload = reload.clone();
+            let error = error.clone();
+            let success = success.clone();
+            let token = token.clone();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = api_client(token.as_deref());
+                match client.delete_no_response(&format!("/proxies/{}", id)).await {
+                    Ok(()) => {
+                        success.set(Some(t("voting-proxy-revoked")));
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error.set(Some(t_with_args(
+                        "voting-proxy-failed-revoke",
+                        &[("error", &e.to_string())],
+                    ))),
+                }
+            });
+        })
+    };
+
+    let clear_error = {
+        let error = error.clone();
+        Callback::from(move |_| error.set(None))
+    };
+
+    let clear_success = {
+        let success = success.clone();
+        Callback::from(move |_| success.set(None))
+    };
+
+    let building_options = {
+        let mut options = vec![SelectOption::new("", t("voting-proxy-select-building"))];
+        for b in buildings.iter() {
+            options.push(SelectOption::new(b.id.to_string(), &b.address));
+        }
+        options
+    };
+
+    let user_options = {
+        let mut options = vec![SelectOption::new("", t("voting-proxy-select-holder"))];
+        for user in users.iter() {
+            options.push(SelectOption::new(
+                user.id.to_string(),
+                format!("{} ({})", user.name, user.email),
+            ));
+        }
+        options
+    };
+
+    let revoke_button = |proxy: &ProxyWithNames| {
+        let id = proxy.id;
+        let on_revoke = on_revoke.clone();
+        html! {
+            <button
+                class="btn btn-sm btn-outline-danger"
+                onclick={Callback::from(move |_: MouseEvent| on_revoke.emit(id))}
+            >
+                {t("voting-proxy-revoke")}
+            </button>
+        }
+    };
+
+    html! {
+        <div class="container mt-4">
+            <Breadcrumb items={vec![
+                BreadcrumbItem { label: t("voting-title"), route: Some(Route::Voting) },
+                BreadcrumbItem { label: t("voting-proxies-title"), route: None },
+            ]} />
+
+            <h2 class="mb-3">{t("voting-proxies-title")}</h2>
+
+            if let Some(err) = (*error).clone() {
+                <ErrorAlert message={err} on_close={clear_error.clone()} />
+            }
+
+            if let Some(msg) = (*success).clone() {
+                <SuccessAlert message={msg} on_close={clear_success.clone()} />
+            }
+
+            <div class="row">
+                <div class="col-lg-7 mb-3">
+                    <div class="card mb-3">
+                        <div class="card-header">
+                            <h5 class="mb-0">{t("voting-proxies-granted")}</h5>
+                        </div>
+                        if mine.granted.is_empty() {
+                            <div class="card-body text-muted small">{t("voting-proxies-none")}</div>
+                        } else {
+                            <ul class="list-group list-group-flush">
+                                { for mine.granted.iter().map(|proxy| html! {
+                                    <li class="list-group-item d-flex justify-content-between align-items-center" key={proxy.id}>
+                                        <div>
+                                            <div><strong>{&proxy.grantee_name}</strong></div>
+                                            <div class="small text-muted">{proxy_scope(proxy)}</div>
+                                        </div>
+                                        {revoke_button(proxy)}
+                                    </li>
+                                }) }
+                            </ul>
+                        }
+                    </div>
+
+                    <div class="card">
+                        <div class="card-header">
+                            <h5 class="mb-0">{t("voting-proxies-received")}</h5>
+                        </div>
+                        if mine.received.is_empty() {
+                            <div class="card-body text-muted small">{t("voting-proxies-none")}</div>
+                        } else {
+                            <ul class="list-group list-group-flush">
+                                { for mine.received.iter().map(|proxy| html! {
+                                    <li class="list-group-item" key={proxy.id}>
+                                        <div><strong>{&proxy.grantor_name}</strong></div>
+                                        <div class="small text-muted">{proxy_scope(proxy)}</div>
+                                    </li>
+                                }) }
+                            </ul>
+                        }
+                    </div>
+                </div>
+
+                <div class="col-lg-5 mb-3">
+                    <div class="card">
+                        <div class="card-header">
+                            <h5 class="mb-0">{t("voting-proxy-building-grant")}</h5>
+                        </div>
+                        <div class="card-body">
+                            <p class="small text-muted">{t("voting-proxy-building-grant-help")}</p>
+                            <form onsubmit={on_grant}>
+                                <Select
+                                    label={t("voting-proxy-building")}
+                                    value={(*building).clone()}
+                                    on_change={{
+                                        let building = building.clone();
+                                        Callback::from(move |value: String| building.set(value))
+                                    }}
+                                    options={building_options}
+                                    disabled={*busy}
+                                    required=true
+                                />
+                                <Select
+                                    label={t("voting-proxy-holder")}
+                                    value={(*grantee).clone()}
+                                    on_change={{
+                                        let grantee = grantee.clone();
+                                        Callback::from(move |value: String| grantee.set(value))
+                                    }}
+                                    options={user_options}
+                                    disabled={*busy}
+                                    required=true
+                                />
+                                <DateTimeInput
+                                    label={t("voting-proxy-valid-from")}
+                                    value={(*valid_from).clone()}
+                                    on_change={{
+                                        let valid_from = valid_from.clone();
+                                        Callback::from(move |value: String| valid_from.set(value))
+                                    }}
+                                    input_type="datetime-local"
+                                    disabled={*busy}
+                                    required=true
+                                />
+                                <DateTimeInput
+                                    label={t("voting-proxy-valid-until")}
+                                    value={(*valid_until).clone()}
+                                    on_change={{
+                                        let valid_until = valid_until.clone();
+                                        Callback::from(move |value: String| valid_until.set(value))
+                                    }}
+                                    input_type="datetime-local"
+                                    min={Some((*valid_from).clone())}
+                                    disabled={*busy}
+                                    required=true
+                                />
+                                <button type="submit" class="btn btn-primary w-100" disabled={*busy}>
+                                    {t("voting-proxy-grant")}
+                                </button>
+                            </form>
+                        </div>
+                    </div>
+                </div>
+            </div>
+
+            if is_manager {
+                <div class="card mb-4">
+                    <div class="card-header d-flex justify-content-between align-items-center">
+                        <h5 class="mb-0">{t("voting-proxies-all")}</h5>
+                        <Checkbox
+                            id="proxies-include-revoked"
+                            label={t("voting-proxies-include-revoked")}
+                            checked={*include_revoked}
+                            on_change={{
+                                let include_revoked = include_revoked.clone();
+                                Callback::from(move |checked: bool| include_revoked.set(checked))
+                            }}
+                            switch=true
+                        />
+                    </div>
+                    if all.is_empty() {
+                        <div class="card-body text-muted small">{t("voting-proxies-none")}</div>
+                    } else {
+                        <div class="table-responsive">
+                            <table class="table table-sm align-middle mb-0">
+                                <thead>
+                                    <tr>
+                                        <th>{t("voting-proxy-grantor")}</th>
+                                        <th>{t("voting-proxy-holder")}</th>
+                                        <th>{t("voting-proxy-scope")}</th>
+                                        <th>{t("voting-proxy-created")}</th>
+                                        <th></th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    { for all.iter().map(|proxy| html! {
+                                        <tr key={proxy.id}>
+                                            <td>{&proxy.grantor_name}</td>
+                                            <td>{&proxy.grantee_name}</td>
+                                            <td class="small">{proxy_scope(proxy)}</td>
+                                            <td class="small">{proxy.created_at.as_deref().map(format_dt_local).unwrap_or_default()}</td>
+                                            <td class="text-end">
+                                                if let Some(revoked) = &proxy.revoked_at {
+                                                    <span class="badge bg-secondary">
+                                                        {t_with_args("voting-proxy-revoked-at", &[("date", &format_dt_local(revoked))])}
+                                                    </span>
+                                                } else {
+                                                    {revoke_button(proxy)}
+                                                }
+                                            </td>
+                                        </tr>
+                                    }) }
+                                </tbody>
+                            </table>
+                        </div>
+                    }
+                </div>
+            }
+        </div>
+    }
+}
@@ -28,8 +28,22 @@ pub enum Route {
     Voting,
     #[at("/voting/new")]
     VotingNew,
+    #[at("/voting/proxies")]
+    VotingProxies,
     #[at("/voting/:id")]
     VotingDetail { id: u64 },
+    #[at("/polls")]
+    Polls,
+    #[at("/polls/new")]
+    PollNew,
+    #[at("/polls/:id")]
+    PollDetail { id: u64 },
+    #[at("/meetings")]
+    Meetings,
+    #[at("/meetings/new")]
+    MeetingNew,
+    #[at("/meetings/:id")]
+    MeetingDetail { id: u64 },
     #[at("/apartments/:apartment_id/meters")]
     ApartmentMeters { apartment_id: u64 },
     #[at("/meters/:id")]
@@ -40,6 +54,16 @@ pub enum Route {
     MeterNew,
     #[at("/admin/meters/calibration")]
     MeterCalibration,
+    #[at("/admin/meters/submissions")]
+    MeterSubmissions,
+    #[at("/admin/meters/campaigns")]
+    MeterCampaigns,
+    #[at("/admin/meters/campaigns/:id")]
+    MeterCampaignDetail { id: u64 },
+    #[at("/admin/meters/import")]
+    MeterImport,
+    #[at("/admin/meters/api-keys")]
+    MeterApiKeys,
     #[at("/my-properties")]
     MyProperties,
     #[at("/my-properties/:apartment_id")]
@@ -1,3 +1,4 @@
+use crate::i18n::current_language;
 use reqwasm::http::{Request, Response};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -61,6 +62,15 @@ impl ApiClient {
         self
     }
 
+    /// Bearer token, if any, and the UI language so the API can localise content.
+    fn with_headers(&self, mut request: Request) -> Request {
+        request = request.header("Accept-Language", &current_language());
+        if let Some(token) = &self.token {
+            request = request.header("Authorization", &format!("Bearer {}", token));
+        }
+        request
+    }
+
     pub async fn get<T>(&self, endpoint: &str) -> ApiResult<T>
     where
         T: for<'de> Deserialize<'de>,
@@ -68,9 +78,7 @@ impl ApiClient {
         let url = format!("{}{}", self.base_url, endpoint);
         let mut request = Request::get(&url);
 
-        if let Some(token) = &self.token {
-            request = request.header("Authorization", &format!("Bearer {}", token));
-        }
+        request = self.with_headers(request);
 
         let response = request
             .send()
@@ -93,9 +101,7 @@ impl ApiClient {
             .header("Content-Type", "application/json")
             .body(body_json);
 
-        if let Some(token) = &self.token {
-            request = request.header("Authorization", &format!("Bearer {}", token));
-        }
+        request = self.with_headers(request);
 
         let response = request
             .send()
@@ -113,9 +119,7 @@ impl ApiClient {
 
         let mut request = Request::post(&url);
 
-        if let Some(token) = &self.token {
-            request = request.header("Authorization", &format!("Bearer {}", token));
-        }
+        request = self.with_headers(request);
 
         let response = request
             .send()
@@ -138,9 +142,7 @@ impl ApiClient {
             .header("Content-Type", "application/json")
             .body(body_json);
 
-        if let Some(token) = &self.token {
-            request = request.header("Authorization", &format!("Bearer {}", token));
-        }
+        request = self.with_headers(request);
 
         let response = request
             .send()
@@ -158,9 +160,7 @@ impl ApiClient {
 
         let mut request = Request::delete(&url);
 
-        if let Some(token) = &self.token {
-            request = request.header("Authorization", &format!("Bearer {}", token));
-        }
+        request = self.with_headers(request);
 
         let response = request
             .send()
@@ -180,9 +180,7 @@ impl ApiClient {
 
         let mut request = Request::post(&url).header("Content-Type", "application/json");
 
-        if let Some(token) = &self.token {
-            request = request.header("Authorization", &format!("Bearer {}", token));
-        }
+        request = self.with_headers(request);
 
         let response = request
             .body(body_json)
@@ -198,9 +196,7 @@ impl ApiClient {
 
         let mut request = Request::delete(&url);
 
-        if let Some(token) = &self.token {
-            request = request.header("Authorization", &format!("Bearer {}", token));
-        }
+        request = self.with_headers(request);
 
         let response = request
             .send()
@@ -210,6 +206,42 @@ impl ApiClient {
         Self::handle_empty_response(response).await
     }
 
+    /// Fetch a binary resource with the auth header and expose it as an object URL,
+    /// e.g. for `<img src>` on endpoints that require a bearer token.
+    /// Release it with `web_sys::Url::revoke_object_url` once no longer shown.
+    pub async fn get_object_url(&self, endpoint: &str) -> ApiResult<String> {
+        let url = format!("{}{}", self.base_url, endpoint);
+
+        let mut request = Request::get(&url);
+
+        request = self.with_headers(request);
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+        match response.status() {
+            200..=299 => {
+                let bytes = response
+                    .binary()
+                    .await
+                    .map_err(|e| ApiError::ParseError(e.to_string()))?;
+                let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes.as_slice()));
+                let blob = web_sys::Blob::new_with_u8_array_sequence(&parts)
+                    .map_err(|_| ApiError::ParseError("blob".into()))?;
+                web_sys::Url::create_object_url_with_blob(&blob)
+                    .map_err(|_| ApiError::ParseError("object_url".into()))
+            }
+            status => Err(Self::handle_empty_response(response)
+                .await
+                .err()
+                .unwrap_or_else(|| {
+                    ApiError::ServerError(format!("Unexpected status: {}", status))
+                })),
+        }
+    }
+
     async fn handle_response<T>(response: Response) -> ApiResult<T>
     where
         T: for<'de> Deserialize<'de>,
@@ -287,9 +319,7 @@ impl ApiClient {
 
         let mut request = Request::post(&url);
 
-        if let Some(token) = &self.token {
-            request = request.header("Authorization", &format!("Bearer {}", token));
-        }
+        request = self.with_headers(request);
 
         let response = request
             .body(form_data.clone())
@@ -304,11 +334,11 @@ impl ApiClient {
 /// Clears authentication data from localStorage
 /// Called automatically when a 401 Unauthorized response is received
 fn clear_auth_storage() {
-    if let Some(window) = web_sys::window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            let _ = storage.remove_item("auth.token");
-            let _ = storage.remove_item("auth.user");
-        }
+    if let Some(window) = web_sys::window()
+        && let Ok(Some(storage)) = window.local_storage()
+    {
+        let _ = storage.remove_item("auth.token");
+        let _ = storage.remove_item("auth.user");
     }
 }
 
@@ -0,0 +1,39 @@
+use base64::{Engine as _, engine::general_purpose};
+use serde::Deserialize;
+
+/// Claims carried in the payload of an API token
+#[derive(Deserialize, Clone, Debug)]
+pub struct Claims {
+    pub sub: String,
+    pub email: String,
+    pub name: String,
+    pub roles: Vec<String>,
+    /// Expiry in seconds since the epoch
+    pub exp: u64,
+}
+
+/// Reads the claims of a JWT without checking its signature, which only the API can do.
+/// Returns `None` if the token is malformed.
+pub fn decode_token(token: &str) -> Option<Claims> {
+    // JWT format: header.payload.signature
+    let parts: Vec<&str> = token.split('.').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+
+    // Add padding if needed (base64url requires it)
+    let payload = parts[1];
+    let padding = match payload.len() % 4 {
+        0 => "",
+        2 => "==",
+        3 => "=",
+        _ => return None,
+    };
+    let padded = format!("{}{}", payload, padding);
+
+    // Decode from base64url (replace - with + and _ with /)
+    let normalized = padded.replace('-', "+").replace('_', "/");
+    let decoded = general_purpose::STANDARD.decode(&normalized).ok()?;
+    let json_str = String::from_utf8(decoded).ok()?;
+    serde_json::from_str(&json_str).ok()
+}